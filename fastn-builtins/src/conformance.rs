@@ -0,0 +1,149 @@
+//! Runs the cases in `conformance.json` against the evalexpr builtins and the
+//! ones in `form-conformance.json` against `fastn_builtins::form`. The JS
+//! runtime runs the same files against `ftd.js` (see `fastn-js/src/ssr.rs`), so
+//! both give the same results.
+
+use fastn_resolved::evalexpr::{ContextWithMutableVariables, Value};
+
+fn to_evalexpr_value(value: &serde_json::Value) -> Value {
+    match value {
+        serde_json::Value::Null => Value::Empty,
+        serde_json::Value::Bool(b) => Value::Boolean(*b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Value::Int(i),
+            None => Value::Float(n.as_f64().unwrap()),
+        },
+        serde_json::Value::String(s) => Value::String(s.to_string()),
+        serde_json::Value::Array(a) => Value::Tuple(a.iter().map(to_evalexpr_value).collect()),
+        serde_json::Value::Object(o) => fastn_builtins::map_to_evalexpr_value(
+            o.iter()
+                .map(|(k, v)| (k.to_string(), to_evalexpr_value(v)))
+                .collect(),
+        ),
+    }
+}
+
+#[test]
+fn conformance() {
+    let cases: Vec<serde_json::Value> =
+        serde_json::from_str(include_str!("../conformance.json")).unwrap();
+    for case in cases {
+        let function = case["function"].as_str().unwrap();
+        let args = case["args"].as_array().unwrap();
+        let mut context = fastn_builtins::default_context().unwrap();
+        let mut names = vec![];
+        for (i, arg) in args.iter().enumerate() {
+            let name = format!("a{i}");
+            context
+                .set_value(name.clone(), to_evalexpr_value(arg))
+                .unwrap();
+            names.push(name);
+        }
+        let expression = format!("ftd.{}({})", function, names.join(", "));
+        let result = fastn_resolved::evalexpr::eval_with_context_mut(&expression, &mut context)
+            .unwrap_or_else(|e| panic!("{case}: {e}"));
+        assert_eq!(result, to_evalexpr_value(&case["result"]), "{case}");
+    }
+}
+
+#[test]
+fn form_conformance() {
+    let cases: Vec<serde_json::Value> =
+        serde_json::from_str(include_str!("../form-conformance.json")).unwrap();
+    for case in cases {
+        let field = &case["field"];
+        let rules = fastn_builtins::form::FieldRules {
+            name: field["name"].as_str().unwrap().to_string(),
+            required: field["required"].as_bool().unwrap_or_default(),
+            min_length: field["min_length"].as_i64(),
+            max_length: field["max_length"].as_i64(),
+            min: field["min"].as_f64(),
+            max: field["max"].as_f64(),
+            pattern: field["pattern"].as_str().map(ToString::to_string),
+            message: field["message"].as_str().map(ToString::to_string),
+        };
+        assert_eq!(
+            rules.validate(case["value"].as_str()).as_deref(),
+            case["error"].as_str(),
+            "{case}"
+        );
+    }
+}
+
+/// `ftd.js` works on `fastn.mutableMap` instead, so the map builtins are not
+/// part of `conformance.json`.
+#[test]
+fn map_builtins() {
+    let mut context = fastn_builtins::default_context().unwrap();
+    context
+        .set_value(
+            "m".to_string(),
+            to_evalexpr_value(&serde_json::json!({"alice": 10, "bob": 20})),
+        )
+        .unwrap();
+    let eval = |expression: &str, context: &mut fastn_resolved::evalexpr::HashMapContext| {
+        fastn_resolved::evalexpr::eval_with_context_mut(expression, context)
+            .unwrap_or_else(|e| panic!("{expression}: {e}"))
+    };
+
+    assert_eq!(eval("ftd.get(m, \"bob\")", &mut context), Value::Int(20));
+    assert_eq!(eval("ftd.get(m, \"carol\")", &mut context), Value::Empty);
+    assert_eq!(
+        eval("ftd.keys(m)", &mut context),
+        to_evalexpr_value(&serde_json::json!(["alice", "bob"]))
+    );
+    assert_eq!(
+        eval("ftd.insert(m, \"carol\", 30)", &mut context),
+        to_evalexpr_value(&serde_json::json!({"alice": 10, "bob": 20, "carol": 30}))
+    );
+    assert_eq!(
+        eval("ftd.insert(m, \"alice\", 11)", &mut context),
+        to_evalexpr_value(&serde_json::json!({"alice": 11, "bob": 20}))
+    );
+    assert_eq!(
+        eval("ftd.remove(m, \"alice\")", &mut context),
+        to_evalexpr_value(&serde_json::json!({"bob": 20}))
+    );
+}
+
+/// `ftd.js` checks `fastn.orTypeValueClass` instead, so `ftd.is_variant` is
+/// not part of `conformance.json`.
+#[test]
+fn is_variant() {
+    let mut context = fastn_builtins::default_context().unwrap();
+    context
+        .set_value(
+            "failure".to_string(),
+            fastn_builtins::or_type_to_evalexpr_value("error", Value::from("timeout")),
+        )
+        .unwrap();
+    context
+        .set_value(
+            "record".to_string(),
+            to_evalexpr_value(&serde_json::json!({"variant": "error", "value": "timeout"})),
+        )
+        .unwrap();
+    let eval = |expression: &str, context: &mut fastn_resolved::evalexpr::HashMapContext| {
+        fastn_resolved::evalexpr::eval_with_context_mut(expression, context)
+            .unwrap_or_else(|e| panic!("{expression}: {e}"))
+    };
+
+    assert_eq!(
+        eval("ftd.is_variant(failure, \"error\")", &mut context),
+        Value::Boolean(true)
+    );
+    assert_eq!(
+        eval("ftd.is_variant(failure, \"ready\")", &mut context),
+        Value::Boolean(false)
+    );
+    // constant variants are passed as their value, and records are not
+    // or-type values
+    assert_eq!(
+        eval("ftd.is_variant(\"loading\", \"loading\")", &mut context),
+        Value::Boolean(false)
+    );
+    assert_eq!(
+        eval("ftd.is_variant(record, \"error\")", &mut context),
+        Value::Boolean(false)
+    );
+}
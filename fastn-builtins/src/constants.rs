@@ -120,6 +120,13 @@ pub const FTD_IMAGE_SRC: &str = "ftd#image-src";
 pub const FTD_IMAGE_SRC_LIGHT: &str = "ftd#image-src.light";
 pub const FTD_IMAGE_SRC_DARK: &str = "ftd#image-src.dark";
 
+pub const FTD_SELECT_OPTION: &str = "ftd#select-option";
+pub const FTD_SELECT_OPTION_LABEL: &str = "ftd#select-option.label";
+
+pub const FTD_FORM_FIELD: &str = "ftd#form-field";
+
+pub const FTD_TABLE_COLUMN: &str = "ftd#table-column";
+
 pub const FTD_IMAGE_FIT: &str = "ftd#image-fit";
 pub const FTD_IMAGE_FIT_NONE: &str = "ftd#image-fit.none";
 pub const FTD_IMAGE_FIT_COVER: &str = "ftd#image-fit.cover";
@@ -251,6 +258,8 @@ pub const FTD_TEXT_INPUT_TYPE_MONTH: &str = "ftd#text-input-type.month";
 pub const FTD_TEXT_INPUT_TYPE_WEEK: &str = "ftd#text-input-type.week";
 pub const FTD_TEXT_INPUT_TYPE_COLOR: &str = "ftd#text-input-type.color";
 pub const FTD_TEXT_INPUT_TYPE_FILE: &str = "ftd#text-input-type.file";
+pub const FTD_TEXT_INPUT_TYPE_NUMBER: &str = "ftd#text-input-type.number";
+pub const FTD_TEXT_INPUT_TYPE_SUBMIT: &str = "ftd#text-input-type.submit";
 
 pub const FTD_REGION: &str = "ftd#region";
 pub const FTD_REGION_H1: &str = "ftd#region.h1";
@@ -285,8 +294,50 @@ pub const FTD_LOADING: &str = "ftd#loading";
 pub const FTD_LOADING_EAGER: &str = "ftd#loading.eager";
 pub const FTD_LOADING_LAZY: &str = "ftd#loading.lazy";
 
+pub const FTD_FORM_ENCODING: &str = "ftd#form-encoding";
+pub const FTD_FORM_ENCODING_JSON: &str = "ftd#form-encoding.json";
+pub const FTD_FORM_ENCODING_URLENCODED: &str = "ftd#form-encoding.urlencoded";
+
+pub const FTD_GRID_TEMPLATE: &str = "ftd#grid-template";
+pub const FTD_GRID_TEMPLATE_FIXED: &str = "ftd#grid-template.fixed";
+pub const FTD_GRID_TEMPLATE_COUNT: &str = "ftd#grid-template.count";
+pub const FTD_GRID_TEMPLATE_RESPONSIVE: &str = "ftd#grid-template.responsive";
+
+pub const FTD_RESPONSIVE_GRID_TEMPLATE: &str = "ftd#responsive-grid-template";
+pub const FTD_RESPONSIVE_GRID_TEMPLATE_DESKTOP: &str = "ftd#responsive-grid-template.desktop";
+
+pub const FTD_GRID_VALUE: &str = "ftd#grid-value";
+pub const FTD_GRID_VALUE_FIXED: &str = "ftd#grid-value.fixed";
+pub const FTD_GRID_VALUE_SPAN: &str = "ftd#grid-value.span";
+pub const FTD_GRID_VALUE_RESPONSIVE: &str = "ftd#grid-value.responsive";
+
+pub const FTD_RESPONSIVE_GRID_VALUE: &str = "ftd#responsive-grid-value";
+pub const FTD_RESPONSIVE_GRID_VALUE_DESKTOP: &str = "ftd#responsive-grid-value.desktop";
+
+pub const FTD_GRID_AUTO_FLOW: &str = "ftd#grid-auto-flow";
+pub const FTD_GRID_AUTO_FLOW_ROW: &str = "ftd#grid-auto-flow.row";
+pub const FTD_GRID_AUTO_FLOW_COLUMN: &str = "ftd#grid-auto-flow.column";
+pub const FTD_GRID_AUTO_FLOW_DENSE: &str = "ftd#grid-auto-flow.dense";
+pub const FTD_GRID_AUTO_FLOW_ROW_DENSE: &str = "ftd#grid-auto-flow.row-dense";
+pub const FTD_GRID_AUTO_FLOW_COLUMN_DENSE: &str = "ftd#grid-auto-flow.column-dense";
+
+pub const FTD_EASING: &str = "ftd#easing";
+pub const FTD_EASING_LINEAR: &str = "ftd#easing.linear";
+pub const FTD_EASING_EASE: &str = "ftd#easing.ease";
+pub const FTD_EASING_EASE_IN: &str = "ftd#easing.ease-in";
+pub const FTD_EASING_EASE_OUT: &str = "ftd#easing.ease-out";
+pub const FTD_EASING_EASE_IN_OUT: &str = "ftd#easing.ease-in-out";
+pub const FTD_EASING_CUBIC_BEZIER: &str = "ftd#easing.cubic-bezier";
+pub const FTD_EASING_STEPS: &str = "ftd#easing.steps";
+
+pub const FTD_TRANSITION: &str = "ftd#transition";
+pub const FTD_KEYFRAME: &str = "ftd#keyframe";
+pub const FTD_KEYFRAMES: &str = "ftd#keyframes";
+pub const FTD_ANIMATION: &str = "ftd#animation";
+
 pub const FTD_SPECIAL_VALUE: &str = "$VALUE";
 pub const FTD_SPECIAL_CHECKED: &str = "$CHECKED";
+pub const FTD_SPECIAL_VALUES: &str = "$VALUES";
 pub const FTD_INHERITED: &str = "inherited";
 pub const FTD_LOOP_COUNTER: &str = "LOOP.COUNTER";
 pub const FTD_DEFAULT_TYPES: &str = "default-types";
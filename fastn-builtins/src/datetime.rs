@@ -0,0 +1,264 @@
+//! Helpers for the `datetime` and `date` kinds.
+//!
+//! Both kinds are represented as milliseconds since the unix epoch (UTC), so
+//! that comparison and arithmetic work the same way in `evalexpr` and in the JS
+//! runtime, where they are plain numbers too. `date` values are always at UTC
+//! midnight.
+
+pub const MILLIS_PER_SECOND: i64 = 1000;
+pub const MILLIS_PER_DAY: i64 = 86_400_000;
+
+const DATETIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+];
+
+/// Parses an ISO-8601 datetime. Values without an offset are taken as UTC and
+/// a plain date is taken as midnight of that day.
+pub fn parse_datetime(s: &str) -> Option<i64> {
+    parse_datetime_(s.trim()).or_else(|| parse_date(s))
+}
+
+fn parse_datetime_(s: &str) -> Option<i64> {
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+        return Some(dt.timestamp_millis());
+    }
+    DATETIME_FORMATS.iter().find_map(|format| {
+        chrono::NaiveDateTime::parse_from_str(s, format)
+            .ok()
+            .map(|dt| dt.and_utc().timestamp_millis())
+    })
+}
+
+/// Parses an ISO-8601 date (`2024-01-31`). A full datetime is accepted too and
+/// truncated to the start of its day.
+pub fn parse_date(s: &str) -> Option<i64> {
+    let s = s.trim();
+    match chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        Ok(date) => Some(date.and_hms_opt(0, 0, 0)?.and_utc().timestamp_millis()),
+        Err(_) => parse_datetime_(s).map(start_of_day),
+    }
+}
+
+pub fn start_of_day(millis: i64) -> i64 {
+    millis.div_euclid(MILLIS_PER_DAY) * MILLIS_PER_DAY
+}
+
+pub fn to_iso_datetime(millis: i64) -> Option<String> {
+    Some(
+        chrono::DateTime::from_timestamp_millis(millis)?
+            .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true),
+    )
+}
+
+pub fn to_iso_date(millis: i64) -> Option<String> {
+    Some(
+        chrono::DateTime::from_timestamp_millis(millis)?
+            .format("%Y-%m-%d")
+            .to_string(),
+    )
+}
+
+/// Formats `millis` using a strftime style `pattern` (`%d %B %Y`). Month and
+/// weekday names follow `lang`, a two letter language code.
+pub fn format(millis: i64, pattern: &str, lang: &str) -> Option<String> {
+    use std::fmt::Write;
+
+    let dt = chrono::DateTime::from_timestamp_millis(millis)?;
+    let mut output = String::new();
+    write!(output, "{}", dt.format_localized(pattern, locale(lang))).ok()?;
+    Some(output)
+}
+
+fn locale(lang: &str) -> chrono::Locale {
+    chrono::Locale::try_from(language(lang).to_locale().as_str()).unwrap_or(chrono::Locale::en_US)
+}
+
+/// `lang` is a two letter language code, optionally followed by a region
+/// (`fr-CA`). Unknown codes are taken as English.
+fn language(lang: &str) -> fastn_lang::Language {
+    let code = lang.split(['-', '_']).next().unwrap_or(lang);
+    fastn_lang::Language::from_2_letter_code(code).unwrap_or_default()
+}
+
+/// Describes `millis` relative to `now` in `lang`, e.g. `3 days ago` or
+/// `il y a 3 jours`. The JS runtime uses `Intl.RelativeTimeFormat` with the
+/// same units, which produces the same text for the languages in
+/// `relative_phrase`. Other languages fall back to English here.
+pub fn relative(millis: i64, now: i64, lang: &str) -> String {
+    let diff = (millis - now) / MILLIS_PER_SECOND;
+    let seconds = diff.abs();
+    let (value, unit) = if seconds < 60 {
+        (seconds, Unit::Second)
+    } else if seconds < 60 * 60 {
+        (seconds / 60, Unit::Minute)
+    } else if seconds < 60 * 60 * 24 {
+        (seconds / (60 * 60), Unit::Hour)
+    } else if seconds < 60 * 60 * 24 * 30 {
+        (seconds / (60 * 60 * 24), Unit::Day)
+    } else if seconds < 60 * 60 * 24 * 365 {
+        (seconds / (60 * 60 * 24 * 30), Unit::Month)
+    } else {
+        (seconds / (60 * 60 * 24 * 365), Unit::Year)
+    };
+    relative_phrase(language(lang), value, unit, diff < 0)
+}
+
+#[derive(Clone, Copy)]
+enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+fn relative_phrase(language: fastn_lang::Language, value: i64, unit: Unit, past: bool) -> String {
+    use fastn_lang::Language;
+
+    let one = value == 1;
+    match language {
+        Language::French => {
+            let unit = match unit {
+                Unit::Second => "seconde",
+                Unit::Minute => "minute",
+                Unit::Hour => "heure",
+                Unit::Day => "jour",
+                Unit::Month => "mois",
+                Unit::Year => "an",
+            };
+            let s = if one || unit.ends_with('s') { "" } else { "s" };
+            if past {
+                format!("il y a {value} {unit}{s}")
+            } else {
+                format!("dans {value} {unit}{s}")
+            }
+        }
+        Language::German => {
+            // `vor 3 Tagen` and `in 3 Tagen` both take the dative plural
+            let (singular, plural) = match unit {
+                Unit::Second => ("Sekunde", "Sekunden"),
+                Unit::Minute => ("Minute", "Minuten"),
+                Unit::Hour => ("Stunde", "Stunden"),
+                Unit::Day => ("Tag", "Tagen"),
+                Unit::Month => ("Monat", "Monaten"),
+                Unit::Year => ("Jahr", "Jahren"),
+            };
+            let unit = if one { singular } else { plural };
+            if past {
+                format!("vor {value} {unit}")
+            } else {
+                format!("in {value} {unit}")
+            }
+        }
+        Language::Spanish => {
+            let unit = match (unit, one) {
+                (Unit::Second, true) => "segundo",
+                (Unit::Second, false) => "segundos",
+                (Unit::Minute, true) => "minuto",
+                (Unit::Minute, false) => "minutos",
+                (Unit::Hour, true) => "hora",
+                (Unit::Hour, false) => "horas",
+                (Unit::Day, true) => "día",
+                (Unit::Day, false) => "días",
+                (Unit::Month, true) => "mes",
+                (Unit::Month, false) => "meses",
+                (Unit::Year, true) => "año",
+                (Unit::Year, false) => "años",
+            };
+            if past {
+                format!("hace {value} {unit}")
+            } else {
+                format!("dentro de {value} {unit}")
+            }
+        }
+        _ => {
+            let unit = match unit {
+                Unit::Second => "second",
+                Unit::Minute => "minute",
+                Unit::Hour => "hour",
+                Unit::Day => "day",
+                Unit::Month => "month",
+                Unit::Year => "year",
+            };
+            let s = if one { "" } else { "s" };
+            if past {
+                format!("{value} {unit}{s} ago")
+            } else {
+                format!("in {value} {unit}{s}")
+            }
+        }
+    }
+}
+
+pub fn now() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn parse() {
+        assert_eq!(
+            super::parse_datetime("2024-01-31T10:20:30Z"),
+            Some(1_706_696_430_000)
+        );
+        assert_eq!(
+            super::parse_datetime("2024-01-31T15:50:30+05:30"),
+            Some(1_706_696_430_000)
+        );
+        assert_eq!(
+            super::parse_datetime("2024-01-31 10:20:30"),
+            Some(1_706_696_430_000)
+        );
+        assert_eq!(super::parse_date("2024-01-31"), Some(1_706_659_200_000));
+        assert_eq!(
+            super::parse_date("2024-01-31T10:20:30Z"),
+            Some(1_706_659_200_000)
+        );
+        assert_eq!(super::parse_datetime("31/01/2024"), None);
+    }
+
+    #[test]
+    fn format() {
+        let millis = super::parse_datetime("2024-01-31T10:20:30Z").unwrap();
+        assert_eq!(
+            super::format(millis, "%d %B %Y", "en").as_deref(),
+            Some("31 January 2024")
+        );
+        assert_eq!(
+            super::format(millis, "%d %B %Y", "fr").as_deref(),
+            Some("31 janvier 2024")
+        );
+        assert_eq!(
+            super::to_iso_datetime(millis).as_deref(),
+            Some("2024-01-31T10:20:30Z")
+        );
+        assert_eq!(super::to_iso_date(millis).as_deref(), Some("2024-01-31"));
+    }
+
+    #[test]
+    fn relative() {
+        let now = super::parse_datetime("2024-01-31T10:20:30Z").unwrap();
+        let three_days_ago = now - 3 * super::MILLIS_PER_DAY;
+        let in_two_hours = now + 2 * 60 * 60 * 1000;
+        let a_minute_ago = now - 60 * 1000;
+        assert_eq!(super::relative(three_days_ago, now, "en"), "3 days ago");
+        assert_eq!(super::relative(in_two_hours, now, "en"), "in 2 hours");
+        assert_eq!(super::relative(a_minute_ago, now, "en"), "1 minute ago");
+        assert_eq!(super::relative(three_days_ago, now, "fr"), "il y a 3 jours");
+        assert_eq!(super::relative(in_two_hours, now, "fr-CA"), "dans 2 heures");
+        assert_eq!(super::relative(three_days_ago, now, "de"), "vor 3 Tagen");
+        assert_eq!(super::relative(a_minute_ago, now, "de"), "vor 1 Minute");
+        assert_eq!(super::relative(three_days_ago, now, "es"), "hace 3 días");
+        assert_eq!(
+            super::relative(in_two_hours, now, "es"),
+            "dentro de 2 horas"
+        );
+        // no phrases for Hindi yet, English is used
+        assert_eq!(super::relative(three_days_ago, now, "hi"), "3 days ago");
+    }
+}
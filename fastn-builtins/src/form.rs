@@ -0,0 +1,88 @@
+//! Validation rules of `ftd.form-field`.
+//!
+//! `ftd.js` checks the same rules in the browser before `ftd.form` is
+//! submitted (`ftd.validate_field`) and the `form-fields` processor checks
+//! them on the server, so a form posted without JS gets the same errors. Both
+//! are run against `form-conformance.json`.
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FieldRules {
+    pub name: String,
+    pub required: bool,
+    pub min_length: Option<i64>,
+    pub max_length: Option<i64>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub pattern: Option<String>,
+    /// Replaces the error message of every rule.
+    pub message: Option<String>,
+}
+
+impl FieldRules {
+    /// Returns the error for `value`, `None` when it passes all the rules.
+    /// Empty values only fail `required`, the other rules are skipped for them.
+    pub fn validate(&self, value: Option<&str>) -> Option<String> {
+        let value = value.unwrap_or_default();
+        if value.trim().is_empty() {
+            return self
+                .required
+                .then(|| self.error("This field is required".to_string()));
+        }
+
+        let length = value.chars().count() as i64;
+        if let Some(min_length) = self.min_length {
+            if length < min_length {
+                return Some(self.error(format!("Must be at least {min_length} characters")));
+            }
+        }
+        if let Some(max_length) = self.max_length {
+            if length > max_length {
+                return Some(self.error(format!("Must be at most {max_length} characters")));
+            }
+        }
+
+        if self.min.is_some() || self.max.is_some() {
+            let number = match parse_number(value) {
+                Some(number) => number,
+                None => return Some(self.error("Must be a number".to_string())),
+            };
+            if let Some(min) = self.min {
+                if number < min {
+                    return Some(self.error(format!("Must be at least {min}")));
+                }
+            }
+            if let Some(max) = self.max {
+                if number > max {
+                    return Some(self.error(format!("Must be at most {max}")));
+                }
+            }
+        }
+
+        if let Some(ref pattern) = self.pattern {
+            // The pattern has to match the whole value, like the `pattern`
+            // attribute of html inputs. An invalid pattern never matches.
+            let matches = regex::Regex::new(format!("^(?:{pattern})$").as_str())
+                .map(|re| re.is_match(value))
+                .unwrap_or(false);
+            if !matches {
+                return Some(self.error("Invalid format".to_string()));
+            }
+        }
+
+        None
+    }
+
+    fn error(&self, default: String) -> String {
+        self.message.clone().unwrap_or(default)
+    }
+}
+
+fn parse_number(value: &str) -> Option<f64> {
+    static NUMBER: std::sync::LazyLock<regex::Regex> =
+        std::sync::LazyLock::new(|| regex::Regex::new(r"^[+-]?(\d+\.?\d*|\.\d+)$").unwrap());
+    let value = value.trim();
+    if !NUMBER.is_match(value) {
+        return None;
+    }
+    value.parse().ok()
+}
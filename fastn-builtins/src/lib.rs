@@ -4,6 +4,11 @@
 extern crate self as fastn_builtins;
 
 pub mod constants;
+pub mod datetime;
+pub mod form;
+
+#[cfg(test)]
+mod conformance;
 
 pub type Map<T> = std::collections::BTreeMap<String, T>;
 use fastn_resolved::evalexpr::ContextWithMutableFunctions;
@@ -37,6 +42,26 @@ enable light mode in the application.
 4. `enable_system_mode` - This function takes no arguments and returns an empty value. It is used to
 enable system mode in the application, which means the application will use the system's default
 color scheme.
+
+5. `get`, `insert`, `remove` and `keys` - These functions work on `map` values: `get` returns the
+value stored for a key (or empty), `insert` and `remove` return the updated map and `keys` returns
+the list of keys.
+
+6. `format_datetime`, `relative_time`, `add_days`, `add_seconds` and `diff_days` - These
+functions work on `datetime` and `date` values, which are milliseconds since the unix epoch. See
+the `datetime` module. `format_datetime` and `relative_time` take an optional language, which is
+English here and the language of the request in `default_context_with_language`.
+
+7. `split`, `join`, `replace`, `contains`, `starts_with`, `ends_with`, `slice`, `pad_start`,
+`pad_end` and `format_number` - String helpers. `slice` and `contains` work on lists too.
+
+8. `sort`, `sort_by`, `filter`, `map`, `sum` and `unique` - List helpers. `sort_by`, `filter` and
+`map` take the name of a record field. The JS runtime (`ftd.js`) implements the same functions and
+`conformance.json` has the cases both are tested against.
+
+9. `is_variant` - This function takes an or-type value and the name of a variant, and returns
+whether the value holds that variant. `match` checks the variants carrying a value with it, see
+`or_type_to_evalexpr_value`.
 */
 pub fn default_functions() -> Map<fastn_resolved::evalexpr::Function> {
     use fastn_resolved::evalexpr::*;
@@ -122,6 +147,280 @@ pub fn default_functions() -> Map<fastn_resolved::evalexpr::Function> {
                 }
             }),
         ),
+        (
+            "ftd.get".to_string(),
+            Function::new(|argument| {
+                let (map, key) = map_and_key(argument, 2)?;
+                Ok(map
+                    .into_iter()
+                    .find(|(k, _)| k.eq(&key))
+                    .map(|(_, v)| v)
+                    .unwrap_or(Value::Empty))
+            }),
+        ),
+        (
+            "ftd.is_variant".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 2, 2)?;
+                let variant = arguments[1].as_string()?;
+                Ok(Value::Boolean(
+                    or_type_variant(&arguments[0]).is_some_and(|v| v.eq(&variant)),
+                ))
+            }),
+        ),
+        (
+            "ftd.insert".to_string(),
+            Function::new(|argument| {
+                let (mut map, key) = map_and_key(argument, 3)?;
+                let value = argument.as_tuple()?.pop().unwrap();
+                match map.iter_mut().find(|(k, _)| k.eq(&key)) {
+                    Some(entry) => entry.1 = value,
+                    None => map.push((key, value)),
+                }
+                Ok(map_to_evalexpr_value(map))
+            }),
+        ),
+        (
+            "ftd.remove".to_string(),
+            Function::new(|argument| {
+                let (mut map, key) = map_and_key(argument, 2)?;
+                map.retain(|(k, _)| k.ne(&key));
+                Ok(map_to_evalexpr_value(map))
+            }),
+        ),
+        (
+            "ftd.keys".to_string(),
+            Function::new(|argument| {
+                Ok(Value::Tuple(
+                    map_entries(argument)?
+                        .into_iter()
+                        .map(|(k, _)| Value::String(k))
+                        .collect(),
+                ))
+            }),
+        ),
+        (
+            "ftd.add_days".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 2, 2)?;
+                Ok(Value::Int(
+                    arguments[0].as_int()?
+                        + arguments[1].as_int()? * fastn_builtins::datetime::MILLIS_PER_DAY,
+                ))
+            }),
+        ),
+        (
+            "ftd.add_seconds".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 2, 2)?;
+                Ok(Value::Int(
+                    arguments[0].as_int()?
+                        + arguments[1].as_int()? * fastn_builtins::datetime::MILLIS_PER_SECOND,
+                ))
+            }),
+        ),
+        (
+            "ftd.diff_days".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 2, 2)?;
+                Ok(Value::Int(
+                    (arguments[0].as_int()? - arguments[1].as_int()?)
+                        / fastn_builtins::datetime::MILLIS_PER_DAY,
+                ))
+            }),
+        ),
+        (
+            "ftd.split".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 2, 2)?;
+                let (s, separator) = (arguments[0].as_string()?, arguments[1].as_string()?);
+                Ok(Value::Tuple(if separator.is_empty() {
+                    s.chars().map(|c| Value::String(c.to_string())).collect()
+                } else {
+                    s.split(separator.as_str())
+                        .map(|v| Value::String(v.to_string()))
+                        .collect()
+                }))
+            }),
+        ),
+        (
+            "ftd.join".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 2, 2)?;
+                let separator = arguments[1].as_string()?;
+                Ok(Value::String(
+                    list_items(&arguments[0])?
+                        .iter()
+                        .map(value_to_string)
+                        .collect::<Vec<_>>()
+                        .join(separator.as_str()),
+                ))
+            }),
+        ),
+        (
+            "ftd.replace".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 3, 3)?;
+                let s = arguments[0].as_string()?;
+                let (from, to) = (arguments[1].as_string()?, arguments[2].as_string()?);
+                if from.is_empty() {
+                    return Ok(Value::String(s));
+                }
+                Ok(Value::String(s.replace(from.as_str(), to.as_str())))
+            }),
+        ),
+        (
+            "ftd.contains".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 2, 2)?;
+                Ok(Value::Boolean(match &arguments[0] {
+                    Value::String(s) => s.contains(arguments[1].as_string()?.as_str()),
+                    t => list_items(t)?.contains(&arguments[1]),
+                }))
+            }),
+        ),
+        (
+            "ftd.starts_with".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 2, 2)?;
+                Ok(Value::Boolean(
+                    arguments[0]
+                        .as_string()?
+                        .starts_with(arguments[1].as_string()?.as_str()),
+                ))
+            }),
+        ),
+        (
+            "ftd.ends_with".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 2, 2)?;
+                Ok(Value::Boolean(
+                    arguments[0]
+                        .as_string()?
+                        .ends_with(arguments[1].as_string()?.as_str()),
+                ))
+            }),
+        ),
+        (
+            "ftd.slice".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 2, 3)?;
+                let start = arguments[1].as_int()?;
+                let end = arguments.get(2).map(|v| v.as_int()).transpose()?;
+                match &arguments[0] {
+                    Value::String(s) => {
+                        let chars = s.chars().collect::<Vec<_>>();
+                        let (start, end) = slice_range(chars.len(), start, end);
+                        Ok(Value::String(chars[start..end].iter().collect()))
+                    }
+                    t => {
+                        let items = list_items(t)?;
+                        let (start, end) = slice_range(items.len(), start, end);
+                        Ok(Value::Tuple(items[start..end].to_vec()))
+                    }
+                }
+            }),
+        ),
+        (
+            "ftd.pad_start".to_string(),
+            Function::new(|argument| pad(argument, true)),
+        ),
+        (
+            "ftd.pad_end".to_string(),
+            Function::new(|argument| pad(argument, false)),
+        ),
+        (
+            "ftd.format_number".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 1, 2)?;
+                let decimals = match arguments.get(1) {
+                    Some(decimals) => decimals.as_int()?.clamp(0, 20) as usize,
+                    None => 0,
+                };
+                Ok(Value::String(format_number(
+                    arguments[0].as_number()?,
+                    decimals,
+                )))
+            }),
+        ),
+        (
+            "ftd.sort".to_string(),
+            Function::new(|argument| {
+                let mut items = list_items(argument)?;
+                items.sort_by(compare_values);
+                Ok(Value::Tuple(items))
+            }),
+        ),
+        (
+            "ftd.sort_by".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 2, 2)?;
+                let field = arguments[1].as_string()?;
+                let mut items = list_items(&arguments[0])?
+                    .into_iter()
+                    .map(|item| Ok((field_value(&item, field.as_str())?, item)))
+                    .collect::<EvalexprResult<Vec<_>>>()?;
+                items.sort_by(|(a, _), (b, _)| compare_values(a, b));
+                Ok(Value::Tuple(items.into_iter().map(|(_, v)| v).collect()))
+            }),
+        ),
+        (
+            "ftd.filter".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 3, 3)?;
+                let field = arguments[1].as_string()?;
+                let mut items = vec![];
+                for item in list_items(&arguments[0])? {
+                    if field_value(&item, field.as_str())?.eq(&arguments[2]) {
+                        items.push(item);
+                    }
+                }
+                Ok(Value::Tuple(items))
+            }),
+        ),
+        (
+            "ftd.map".to_string(),
+            Function::new(|argument| {
+                let arguments = function_arguments(argument, 2, 2)?;
+                let field = arguments[1].as_string()?;
+                Ok(Value::Tuple(
+                    list_items(&arguments[0])?
+                        .iter()
+                        .map(|item| field_value(item, field.as_str()))
+                        .collect::<EvalexprResult<Vec<_>>>()?,
+                ))
+            }),
+        ),
+        (
+            "ftd.sum".to_string(),
+            Function::new(|argument| {
+                let items = list_items(argument)?;
+                if items.iter().all(|v| v.is_int()) {
+                    let mut sum: IntType = 0;
+                    for item in items {
+                        sum += item.as_int()?;
+                    }
+                    return Ok(Value::Int(sum));
+                }
+                let mut sum: FloatType = 0.0;
+                for item in items {
+                    sum += item.as_number()?;
+                }
+                Ok(Value::Float(sum))
+            }),
+        ),
+        (
+            "ftd.unique".to_string(),
+            Function::new(|argument| {
+                let mut items: Vec<Value> = vec![];
+                for item in list_items(argument)? {
+                    if !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+                Ok(Value::Tuple(items))
+            }),
+        ),
         (
             "enable_dark_mode".to_string(),
             Function::new(|_| Ok(Value::Empty)),
@@ -135,9 +434,299 @@ pub fn default_functions() -> Map<fastn_resolved::evalexpr::Function> {
             Function::new(|_| Ok(Value::Empty)),
         ),
     ])
+        .chain(datetime_functions("en"))
+        .collect()
+}
+
+/// `ftd.format_datetime` and `ftd.relative_time`, using `language` when they
+/// are called without one. See `default_context_with_language`.
+fn datetime_functions(
+    language: &str,
+) -> impl Iterator<Item = (String, fastn_resolved::evalexpr::Function)> {
+    use fastn_resolved::evalexpr::*;
+
+    let format_language = language.to_string();
+    let relative_language = language.to_string();
+    std::iter::IntoIterator::into_iter([
+        (
+            "ftd.format_datetime".to_string(),
+            Function::new(move |argument| {
+                let arguments = function_arguments(argument, 2, 3)?;
+                let pattern = arguments[1].as_string()?;
+                let lang = match arguments.get(2) {
+                    Some(lang) => lang.as_string()?,
+                    None => format_language.clone(),
+                };
+                let millis = arguments[0].as_int()?;
+                fastn_builtins::datetime::format(millis, pattern.as_str(), lang.as_str())
+                    .map(Value::String)
+                    .ok_or_else(|| {
+                        fastn_resolved::evalexpr::EvalexprError::CustomMessage(format!(
+                            "Cannot format datetime `{}` with `{}`",
+                            millis, pattern
+                        ))
+                    })
+            }),
+        ),
+        (
+            "ftd.relative_time".to_string(),
+            Function::new(move |argument| {
+                let arguments = function_arguments(argument, 1, 2)?;
+                let lang = match arguments.get(1) {
+                    Some(lang) => lang.as_string()?,
+                    None => relative_language.clone(),
+                };
+                Ok(Value::String(fastn_builtins::datetime::relative(
+                    arguments[0].as_int()?,
+                    fastn_builtins::datetime::now(),
+                    lang.as_str(),
+                )))
+            }),
+        ),
+    ])
+}
+
+/// A `map` is passed to evalexpr as a tuple of `(key, value)` tuples, with the
+/// key always in its string form.
+pub fn map_entries(
+    value: &fastn_resolved::evalexpr::Value,
+) -> fastn_resolved::evalexpr::EvalexprResult<Vec<(String, fastn_resolved::evalexpr::Value)>> {
+    if value.as_empty().is_ok() {
+        return Ok(vec![]);
+    }
+    value
+        .as_tuple()?
+        .into_iter()
+        .map(|entry| {
+            let entry = entry.as_tuple()?;
+            if entry.len() != 2 {
+                return Err(
+                    fastn_resolved::evalexpr::EvalexprError::WrongFunctionArgumentAmount {
+                        expected: 2,
+                        actual: entry.len(),
+                    },
+                );
+            }
+            Ok((map_key(&entry[0])?, entry[1].to_owned()))
+        })
         .collect()
 }
 
+pub fn map_to_evalexpr_value(
+    entries: Vec<(String, fastn_resolved::evalexpr::Value)>,
+) -> fastn_resolved::evalexpr::Value {
+    fastn_resolved::evalexpr::Value::Tuple(
+        entries
+            .into_iter()
+            .map(|(k, v)| {
+                fastn_resolved::evalexpr::Value::Tuple(vec![
+                    fastn_resolved::evalexpr::Value::String(k),
+                    v,
+                ])
+            })
+            .collect(),
+    )
+}
+
+/// A value of an or-type variant carrying a value is passed to evalexpr as
+/// a map of its variant and its value, under keys no record field can have.
+/// Constant variants are passed as their value.
+pub fn or_type_to_evalexpr_value(
+    variant: &str,
+    value: fastn_resolved::evalexpr::Value,
+) -> fastn_resolved::evalexpr::Value {
+    map_to_evalexpr_value(vec![
+        (
+            OR_TYPE_VARIANT.to_string(),
+            fastn_resolved::evalexpr::Value::String(variant.to_string()),
+        ),
+        (OR_TYPE_VALUE.to_string(), value),
+    ])
+}
+
+const OR_TYPE_VARIANT: &str = "$variant";
+const OR_TYPE_VALUE: &str = "$value";
+
+/// The variant of `value` when it is an or-type value, see
+/// `or_type_to_evalexpr_value`.
+fn or_type_variant(value: &fastn_resolved::evalexpr::Value) -> Option<String> {
+    match map_entries(value).ok()?.as_slice() {
+        [(variant, fastn_resolved::evalexpr::Value::String(name)), (v, _)]
+            if variant.eq(OR_TYPE_VARIANT) && v.eq(OR_TYPE_VALUE) =>
+        {
+            Some(name.to_string())
+        }
+        _ => None,
+    }
+}
+
+fn map_key(
+    key: &fastn_resolved::evalexpr::Value,
+) -> fastn_resolved::evalexpr::EvalexprResult<String> {
+    match key {
+        fastn_resolved::evalexpr::Value::String(s) => Ok(s.to_string()),
+        fastn_resolved::evalexpr::Value::Int(i) => Ok(i.to_string()),
+        t => Err(fastn_resolved::evalexpr::EvalexprError::ExpectedString {
+            actual: t.to_owned(),
+        }),
+    }
+}
+
+fn map_and_key(
+    argument: &fastn_resolved::evalexpr::Value,
+    expected: usize,
+) -> fastn_resolved::evalexpr::EvalexprResult<(
+    Vec<(String, fastn_resolved::evalexpr::Value)>,
+    String,
+)> {
+    let arguments = argument.as_tuple()?;
+    if arguments.len() != expected {
+        return Err(
+            fastn_resolved::evalexpr::EvalexprError::WrongFunctionArgumentAmount {
+                expected,
+                actual: arguments.len(),
+            },
+        );
+    }
+    Ok((map_entries(&arguments[0])?, map_key(&arguments[1])?))
+}
+
+fn function_arguments(
+    argument: &fastn_resolved::evalexpr::Value,
+    min: usize,
+    max: usize,
+) -> fastn_resolved::evalexpr::EvalexprResult<Vec<fastn_resolved::evalexpr::Value>> {
+    let arguments = match argument {
+        fastn_resolved::evalexpr::Value::Tuple(arguments) => arguments.to_owned(),
+        t => vec![t.to_owned()],
+    };
+    if arguments.len() < min || arguments.len() > max {
+        return Err(
+            fastn_resolved::evalexpr::EvalexprError::WrongFunctionArgumentAmount {
+                expected: min,
+                actual: arguments.len(),
+            },
+        );
+    }
+    Ok(arguments)
+}
+
+/// Lists are passed to evalexpr as tuples, an empty list may show up as
+/// `Empty` too.
+fn list_items(
+    value: &fastn_resolved::evalexpr::Value,
+) -> fastn_resolved::evalexpr::EvalexprResult<Vec<fastn_resolved::evalexpr::Value>> {
+    if value.as_empty().is_ok() {
+        return Ok(vec![]);
+    }
+    value.as_tuple()
+}
+
+/// Records and maps are both passed as tuples of `(key, value)` tuples, see
+/// `map_entries`. A missing field is `Empty`.
+fn field_value(
+    item: &fastn_resolved::evalexpr::Value,
+    field: &str,
+) -> fastn_resolved::evalexpr::EvalexprResult<fastn_resolved::evalexpr::Value> {
+    Ok(map_entries(item)?
+        .into_iter()
+        .find(|(k, _)| k.eq(field))
+        .map(|(_, v)| v)
+        .unwrap_or(fastn_resolved::evalexpr::Value::Empty))
+}
+
+fn value_to_string(value: &fastn_resolved::evalexpr::Value) -> String {
+    match value {
+        fastn_resolved::evalexpr::Value::String(s) => s.to_string(),
+        fastn_resolved::evalexpr::Value::Empty => "".to_string(),
+        t => t.to_string(),
+    }
+}
+
+/// Numbers compare numerically and strings by code point, values of
+/// different types are treated as equal so that sorting stays stable.
+fn compare_values(
+    a: &fastn_resolved::evalexpr::Value,
+    b: &fastn_resolved::evalexpr::Value,
+) -> std::cmp::Ordering {
+    use fastn_resolved::evalexpr::Value;
+
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
+        (a, b) if a.is_number() && b.is_number() => a
+            .as_number()
+            .unwrap()
+            .partial_cmp(&b.as_number().unwrap())
+            .unwrap_or(std::cmp::Ordering::Equal),
+        _ => std::cmp::Ordering::Equal,
+    }
+}
+
+/// Same rules as JS `Array.prototype.slice`: negative indices count from the
+/// end and both ends are clamped to the length.
+fn slice_range(len: usize, start: i64, end: Option<i64>) -> (usize, usize) {
+    let index = |i: i64| {
+        if i < 0 {
+            (len as i64 + i).max(0) as usize
+        } else {
+            (i as usize).min(len)
+        }
+    };
+    let start = index(start);
+    let end = end.map(index).unwrap_or(len);
+    (start, end.max(start))
+}
+
+fn pad(
+    argument: &fastn_resolved::evalexpr::Value,
+    at_start: bool,
+) -> fastn_resolved::evalexpr::EvalexprResult<fastn_resolved::evalexpr::Value> {
+    let arguments = function_arguments(argument, 2, 3)?;
+    let s = arguments[0].as_string()?;
+    let width = arguments[1].as_int()?.max(0) as usize;
+    let fill = match arguments.get(2) {
+        Some(fill) => fill.as_string()?,
+        None => " ".to_string(),
+    };
+    let len = s.chars().count();
+    if len >= width || fill.is_empty() {
+        return Ok(fastn_resolved::evalexpr::Value::String(s));
+    }
+    let padding = fill.chars().cycle().take(width - len).collect::<String>();
+    Ok(fastn_resolved::evalexpr::Value::String(if at_start {
+        format!("{padding}{s}")
+    } else {
+        format!("{s}{padding}")
+    }))
+}
+
+/// Rounds half away from zero to `decimals` places and groups the integer
+/// part by thousands, `1234.5` with one decimal is `1,234.5`.
+fn format_number(number: f64, decimals: usize) -> String {
+    let factor = 10f64.powi(decimals as i32);
+    let rounded = (number.abs() * factor).round() / factor;
+    let formatted = format!("{:.*}", decimals, rounded);
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+    let mut grouped = String::new();
+    for (i, c) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    if let Some(fraction) = fraction {
+        grouped = format!("{grouped}.{fraction}");
+    }
+    if number < 0.0 && rounded != 0.0 {
+        grouped = format!("-{grouped}");
+    }
+    grouped
+}
+
 pub fn default_context(
 ) -> Result<fastn_resolved::evalexpr::HashMapContext, fastn_resolved::evalexpr::EvalexprError> {
     let mut context = fastn_resolved::evalexpr::HashMapContext::new();
@@ -147,6 +736,19 @@ pub fn default_context(
     Ok(context)
 }
 
+/// The default context, with `ftd.format_datetime` and `ftd.relative_time`
+/// using `language` (a two letter code) when they are not passed one. The
+/// interpreter passes the language of the request, see `ftd#language`.
+pub fn default_context_with_language(
+    language: &str,
+) -> Result<fastn_resolved::evalexpr::HashMapContext, fastn_resolved::evalexpr::EvalexprError> {
+    let mut context = default_context()?;
+    for (key, function) in datetime_functions(language) {
+        context.set_function(key, function)?;
+    }
+    Ok(context)
+}
+
 /**
 The `default_bag` function is a public function that returns a `Map` of `Thing`s.
 
@@ -186,10 +788,38 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
             "ftd#iframe".to_string(),
             fastn_resolved::Definition::Component(iframe_function()),
         ),
+        (
+            "ftd#svg".to_string(),
+            fastn_resolved::Definition::Component(svg_function()),
+        ),
+        (
+            "ftd#icon".to_string(),
+            fastn_resolved::Definition::Component(icon_function()),
+        ),
+        (
+            "ftd#math".to_string(),
+            fastn_resolved::Definition::Component(math_function()),
+        ),
         (
             "ftd#column".to_string(),
             fastn_resolved::Definition::Component(column_function()),
         ),
+        (
+            "ftd#form".to_string(),
+            fastn_resolved::Definition::Component(form_function()),
+        ),
+        (
+            "ftd#grid".to_string(),
+            fastn_resolved::Definition::Component(grid_function()),
+        ),
+        (
+            "ftd#table".to_string(),
+            fastn_resolved::Definition::Component(table_function()),
+        ),
+        (
+            "ftd#table-row".to_string(),
+            fastn_resolved::Definition::Component(table_row_function()),
+        ),
         (
             "ftd#document".to_string(),
             fastn_resolved::Definition::Component(document_function()),
@@ -218,6 +848,22 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
             "ftd#checkbox".to_string(),
             fastn_resolved::Definition::Component(checkbox_function()),
         ),
+        (
+            "ftd#select".to_string(),
+            fastn_resolved::Definition::Component(select_function()),
+        ),
+        (
+            "ftd#radio".to_string(),
+            fastn_resolved::Definition::Component(radio_function()),
+        ),
+        (
+            "ftd#textarea".to_string(),
+            fastn_resolved::Definition::Component(textarea_function()),
+        ),
+        (
+            "ftd#file-input".to_string(),
+            fastn_resolved::Definition::Component(file_input_function()),
+        ),
         (
             "ftd#image".to_string(),
             fastn_resolved::Definition::Component(image_function()),
@@ -248,6 +894,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "input".to_string(),
@@ -256,6 +903,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "value".to_string(),
@@ -264,6 +912,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -294,6 +943,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "input".to_string(),
@@ -302,6 +952,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -332,6 +983,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "input".to_string(),
@@ -340,6 +992,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "value".to_string(),
@@ -348,6 +1001,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -378,6 +1032,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "input".to_string(),
@@ -386,6 +1041,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -416,6 +1072,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "input".to_string(),
@@ -424,6 +1081,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -454,6 +1112,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "input".to_string(),
@@ -462,6 +1121,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -492,6 +1152,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "input".to_string(),
@@ -500,6 +1161,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -534,6 +1196,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -568,6 +1231,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "default".to_string(),
@@ -580,6 +1244,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -614,6 +1279,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "default".to_string(),
@@ -626,6 +1292,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -660,6 +1327,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "default".to_string(),
@@ -672,6 +1340,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -705,6 +1374,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "default".to_string(),
@@ -717,6 +1387,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -751,6 +1422,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -785,6 +1457,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "v".to_string(),
@@ -797,6 +1470,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -831,6 +1505,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -865,6 +1540,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "v".to_string(),
@@ -877,6 +1553,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -977,6 +1654,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "lang".to_string(),
@@ -989,6 +1667,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -1023,6 +1702,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -1057,6 +1737,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "v".to_string(),
@@ -1069,6 +1750,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -1103,6 +1785,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "v".to_string(),
@@ -1115,6 +1798,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -1149,6 +1833,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "v".to_string(),
@@ -1161,6 +1846,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -1195,6 +1881,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Argument {
                         name: "v".to_string(),
@@ -1207,6 +1894,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 expression: vec![
@@ -1232,6 +1920,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "dark".to_string(),
@@ -1248,6 +1937,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         }),
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ])
                     .collect(),
@@ -1255,51 +1945,48 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
             }),
         ),
         (
-            fastn_builtins::constants::FTD_VIDEO_SRC.to_string(),
+            fastn_builtins::constants::FTD_SELECT_OPTION.to_string(),
             fastn_resolved::Definition::Record(fastn_resolved::Record {
-                name: fastn_builtins::constants::FTD_VIDEO_SRC.to_string(),
+                name: fastn_builtins::constants::FTD_SELECT_OPTION.to_string(),
                 fields: std::iter::IntoIterator::into_iter([
                     fastn_resolved::Field {
-                        name: "light".to_string(),
+                        name: "label".to_string(),
                         kind: fastn_resolved::Kind::string().into_kind_data().caption(),
                         mutable: false,
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
-                        name: "dark".to_string(),
+                        name: "value".to_string(),
                         kind: fastn_resolved::Kind::string().into_kind_data(),
                         mutable: false,
                         value: Some(fastn_resolved::PropertyValue::Reference {
-                            name: fastn_builtins::constants::FTD_VIDEO_SRC_LIGHT.to_string(),
+                            name: fastn_builtins::constants::FTD_SELECT_OPTION_LABEL.to_string(),
                             kind: fastn_resolved::Kind::string().into_kind_data(),
                             source: fastn_resolved::PropertyValueSource::Local(
-                                fastn_builtins::constants::FTD_VIDEO_SRC.to_string(),
+                                fastn_builtins::constants::FTD_SELECT_OPTION.to_string(),
                             ),
                             is_mutable: false,
                             line_number: 0,
                         }),
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
-                ])
-                    .collect(),
-                line_number: 0,
-            }),
-        ),
-        (
-            fastn_builtins::constants::FTD_RAW_IMAGE_SRC.to_string(),
-            fastn_resolved::Definition::Record(fastn_resolved::Record {
-                name: fastn_builtins::constants::FTD_RAW_IMAGE_SRC.to_string(),
-                fields: std::iter::IntoIterator::into_iter([
                     fastn_resolved::Field {
-                        name: "src".to_string(),
-                        kind: fastn_resolved::Kind::string().into_kind_data().caption(),
+                        name: "disabled".to_string(),
+                        kind: fastn_resolved::Kind::boolean().into_kind_data(),
                         mutable: false,
-                        value: None,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::Boolean { value: false },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ])
                     .collect(),
@@ -1307,39 +1994,270 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
             }),
         ),
         (
-            fastn_builtins::constants::FTD_COLOR.to_string(),
+            fastn_builtins::constants::FTD_FORM_FIELD.to_string(),
             fastn_resolved::Definition::Record(fastn_resolved::Record {
-                name: fastn_builtins::constants::FTD_COLOR.to_string(),
+                name: fastn_builtins::constants::FTD_FORM_FIELD.to_string(),
                 fields: std::iter::IntoIterator::into_iter([
                     fastn_resolved::Field {
-                        name: "light".to_string(),
-                        kind: fastn_resolved::Kind::string().into_kind_data().caption(),
+                        name: "name".to_string(),
+                        kind: fastn_resolved::Kind::string().into_kind_data(),
                         mutable: false,
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
-                        name: "dark".to_string(),
-                        kind: fastn_resolved::Kind::string().into_kind_data(),
+                        name: "value".to_string(),
+                        kind: fastn_resolved::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
                         mutable: false,
-                        value: Some(fastn_resolved::PropertyValue::Reference {
-                            name: fastn_builtins::constants::FTD_COLOR_LIGHT.to_string(),
-                            kind: fastn_resolved::Kind::string().into_kind_data(),
-                            source: fastn_resolved::PropertyValueSource::Local(
-                                fastn_builtins::constants::FTD_COLOR.to_string(),
-                            ),
-                            is_mutable: false,
-                            line_number: 0,
-                        }),
+                        value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
-                ])
-                    .collect(),
-                line_number: 0,
-            }),
-        ),
+                    fastn_resolved::Field {
+                        name: "error".to_string(),
+                        kind: fastn_resolved::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "required".to_string(),
+                        kind: fastn_resolved::Kind::boolean().into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::Boolean { value: false },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "min-length".to_string(),
+                        kind: fastn_resolved::Kind::integer()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "max-length".to_string(),
+                        kind: fastn_resolved::Kind::integer()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "min".to_string(),
+                        kind: fastn_resolved::Kind::decimal()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "max".to_string(),
+                        kind: fastn_resolved::Kind::decimal()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "pattern".to_string(),
+                        kind: fastn_resolved::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "check".to_string(),
+                        kind: fastn_resolved::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "message".to_string(),
+                        kind: fastn_resolved::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_TABLE_COLUMN.to_string(),
+            fastn_resolved::Definition::Record(fastn_resolved::Record {
+                name: fastn_builtins::constants::FTD_TABLE_COLUMN.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    fastn_resolved::Field {
+                        name: "header".to_string(),
+                        kind: fastn_resolved::Kind::string().into_kind_data().caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "field".to_string(),
+                        kind: fastn_resolved::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "sortable".to_string(),
+                        kind: fastn_resolved::Kind::boolean().into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::Boolean { value: true },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_VIDEO_SRC.to_string(),
+            fastn_resolved::Definition::Record(fastn_resolved::Record {
+                name: fastn_builtins::constants::FTD_VIDEO_SRC.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    fastn_resolved::Field {
+                        name: "light".to_string(),
+                        kind: fastn_resolved::Kind::string().into_kind_data().caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "dark".to_string(),
+                        kind: fastn_resolved::Kind::string().into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Reference {
+                            name: fastn_builtins::constants::FTD_VIDEO_SRC_LIGHT.to_string(),
+                            kind: fastn_resolved::Kind::string().into_kind_data(),
+                            source: fastn_resolved::PropertyValueSource::Local(
+                                fastn_builtins::constants::FTD_VIDEO_SRC.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                ])
+                    .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_RAW_IMAGE_SRC.to_string(),
+            fastn_resolved::Definition::Record(fastn_resolved::Record {
+                name: fastn_builtins::constants::FTD_RAW_IMAGE_SRC.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    fastn_resolved::Field {
+                        name: "src".to_string(),
+                        kind: fastn_resolved::Kind::string().into_kind_data().caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                ])
+                    .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_COLOR.to_string(),
+            fastn_resolved::Definition::Record(fastn_resolved::Record {
+                name: fastn_builtins::constants::FTD_COLOR.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    fastn_resolved::Field {
+                        name: "light".to_string(),
+                        kind: fastn_resolved::Kind::string().into_kind_data().caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "dark".to_string(),
+                        kind: fastn_resolved::Kind::string().into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Reference {
+                            name: fastn_builtins::constants::FTD_COLOR_LIGHT.to_string(),
+                            kind: fastn_resolved::Kind::string().into_kind_data(),
+                            source: fastn_resolved::PropertyValueSource::Local(
+                                fastn_builtins::constants::FTD_COLOR.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                ])
+                    .collect(),
+                line_number: 0,
+            }),
+        ),
         (
             fastn_builtins::constants::FTD_SHADOW.to_string(),
             fastn_resolved::Definition::Record(fastn_resolved::Record {
@@ -1369,6 +2287,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         }),
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "y-offset".to_string(),
@@ -1394,6 +2313,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         }),
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "blur".to_string(),
@@ -1419,6 +2339,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         }),
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "spread".to_string(),
@@ -1444,6 +2365,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         }),
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "color".to_string(),
@@ -1477,6 +2399,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                             line_number: 0,
                         }),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "inset".to_string(),
@@ -1490,6 +2413,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                             line_number: 0,
                         }),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ]).collect(),
                 line_number: 0,
@@ -1574,6 +2498,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -1589,6 +2514,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "brightness".to_string(),
@@ -1598,6 +2524,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "contrast".to_string(),
@@ -1607,6 +2534,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "grayscale".to_string(),
@@ -1616,6 +2544,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "invert".to_string(),
@@ -1625,6 +2554,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "opacity".to_string(),
@@ -1634,6 +2564,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "sepia".to_string(),
@@ -1643,6 +2574,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "saturate".to_string(),
@@ -1652,6 +2584,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ]).collect(),
                 line_number: 0,
@@ -1670,6 +2603,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "y".to_string(),
@@ -1679,6 +2613,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ]).collect(),
                 line_number: 0,
@@ -1697,6 +2632,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "repeat".to_string(),
@@ -1706,6 +2642,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "size".to_string(),
@@ -1715,6 +2652,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "position".to_string(),
@@ -1724,6 +2662,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ]).collect(),
                 line_number: 0,
@@ -1742,6 +2681,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "start".to_string(),
@@ -1751,6 +2691,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "end".to_string(),
@@ -1760,6 +2701,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "stop-position".to_string(),
@@ -1769,6 +2711,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ]).collect(),
                 line_number: 0,
@@ -1877,6 +2820,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -1909,6 +2853,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         }),
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "colors".to_string(),
@@ -1918,6 +2863,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ]).collect(),
                 line_number: 0,
@@ -1955,6 +2901,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -2024,6 +2971,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -2072,6 +3020,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                                 value: None,
                                 access_modifier: Default::default(),
                                 line_number: 0,
+                                is_slot: false,
                             },
                             fastn_resolved::Field {
                                 name: "y".to_string(),
@@ -2081,12 +3030,14 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                                 value: None,
                                 access_modifier: Default::default(),
                                 line_number: 0,
+                                is_slot: false,
                             },
                         ]).collect(),
                         line_number: 0,
                     }),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -2225,6 +3176,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                                 value: None,
                                 access_modifier: Default::default(),
                                 line_number: 0,
+                                is_slot: false,
                             },
                             fastn_resolved::Field {
                                 name: "y".to_string(),
@@ -2234,12 +3186,14 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                                 value: None,
                                 access_modifier: Default::default(),
                                 line_number: 0,
+                                is_slot: false,
                             },
                         ]).collect(),
                         line_number: 0,
                     }),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -2355,6 +3309,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -2402,6 +3357,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -2462,6 +3418,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
 
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -2501,6 +3458,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -2538,6 +3496,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -2587,6 +3546,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -2626,6 +3586,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -2985,6 +3946,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3024,6 +3986,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3073,6 +4036,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3112,6 +4076,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3165,6 +4130,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3234,6 +4200,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3273,6 +4240,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3399,6 +4367,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3415,6 +4384,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "mobile".to_string(),
@@ -3432,6 +4402,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                             line_number: 0,
                         }),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ])
                     .collect(),
@@ -3439,13 +4410,13 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
             }),
         ),
         (
-            fastn_builtins::constants::FTD_FONT_SIZE.to_string(),
+            fastn_builtins::constants::FTD_GRID_TEMPLATE.to_string(),
             fastn_resolved::Definition::OrType(fastn_resolved::OrType {
-                name: fastn_builtins::constants::FTD_FONT_SIZE.to_string(),
+                name: fastn_builtins::constants::FTD_GRID_TEMPLATE.to_string(),
                 variants: vec![
                     fastn_resolved::OrTypeVariant::Regular(fastn_resolved::Field::new(
-                        fastn_builtins::constants::FTD_FONT_SIZE_PX,
-                        fastn_resolved::Kind::integer()
+                        fastn_builtins::constants::FTD_GRID_TEMPLATE_FIXED,
+                        fastn_resolved::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
@@ -3453,8 +4424,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         0,
                     )),
                     fastn_resolved::OrTypeVariant::Regular(fastn_resolved::Field::new(
-                        fastn_builtins::constants::FTD_FONT_SIZE_EM,
-                        fastn_resolved::Kind::decimal()
+                        fastn_builtins::constants::FTD_GRID_TEMPLATE_COUNT,
+                        fastn_resolved::Kind::integer()
                             .into_kind_data()
                             .caption(),
                         false,
@@ -3462,8 +4433,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         0,
                     )),
                     fastn_resolved::OrTypeVariant::Regular(fastn_resolved::Field::new(
-                        fastn_builtins::constants::FTD_FONT_SIZE_REM,
-                        fastn_resolved::Kind::decimal()
+                        fastn_builtins::constants::FTD_GRID_TEMPLATE_RESPONSIVE,
+                        fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_GRID_TEMPLATE)
                             .into_kind_data()
                             .caption(),
                         false,
@@ -3472,21 +4443,610 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
-            fastn_builtins::constants::FTD_REGION.to_string(),
+            fastn_builtins::constants::FTD_RESPONSIVE_GRID_TEMPLATE.to_string(),
+            fastn_resolved::Definition::Record(fastn_resolved::Record {
+                name: fastn_builtins::constants::FTD_RESPONSIVE_GRID_TEMPLATE.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    fastn_resolved::Field {
+                        name: "desktop".to_string(),
+                        kind: fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_TEMPLATE)
+                            .into_kind_data()
+                            .caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "mobile".to_string(),
+                        kind: fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_TEMPLATE)
+                            .into_kind_data(),
+                        mutable: false,
+                        access_modifier: Default::default(),
+                        value: Some(fastn_resolved::PropertyValue::Reference {
+                            name: fastn_builtins::constants::FTD_RESPONSIVE_GRID_TEMPLATE_DESKTOP.to_string(),
+                            kind: fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_TEMPLATE)
+                                .into_kind_data(),
+                            source: fastn_resolved::PropertyValueSource::Local(
+                                fastn_builtins::constants::FTD_RESPONSIVE_GRID_TEMPLATE.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_GRID_VALUE.to_string(),
             fastn_resolved::Definition::OrType(fastn_resolved::OrType {
-                name: fastn_builtins::constants::FTD_REGION.to_string(),
+                name: fastn_builtins::constants::FTD_GRID_VALUE.to_string(),
                 variants: vec![
-                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
-                        fastn_builtins::constants::FTD_REGION_H1,
+                    fastn_resolved::OrTypeVariant::Regular(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_GRID_VALUE_FIXED,
                         fastn_resolved::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(   fastn_resolved::Value::new_string("h1")
-                                    .into_property_value(false, 0),),
+                        None,
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Regular(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_GRID_VALUE_SPAN,
+                        fastn_resolved::Kind::integer()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Regular(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_GRID_VALUE_RESPONSIVE,
+                        fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_GRID_VALUE)
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                ],
+                line_number: 0,
+                matched: false,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_RESPONSIVE_GRID_VALUE.to_string(),
+            fastn_resolved::Definition::Record(fastn_resolved::Record {
+                name: fastn_builtins::constants::FTD_RESPONSIVE_GRID_VALUE.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    fastn_resolved::Field {
+                        name: "desktop".to_string(),
+                        kind: fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_VALUE)
+                            .into_kind_data()
+                            .caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "mobile".to_string(),
+                        kind: fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_VALUE)
+                            .into_kind_data(),
+                        mutable: false,
+                        access_modifier: Default::default(),
+                        value: Some(fastn_resolved::PropertyValue::Reference {
+                            name: fastn_builtins::constants::FTD_RESPONSIVE_GRID_VALUE_DESKTOP.to_string(),
+                            kind: fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_VALUE)
+                                .into_kind_data(),
+                            source: fastn_resolved::PropertyValueSource::Local(
+                                fastn_builtins::constants::FTD_RESPONSIVE_GRID_VALUE.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_GRID_AUTO_FLOW.to_string(),
+            fastn_resolved::Definition::OrType(fastn_resolved::OrType {
+                name: fastn_builtins::constants::FTD_GRID_AUTO_FLOW.to_string(),
+                variants: vec![
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_GRID_AUTO_FLOW_ROW,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("row")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_GRID_AUTO_FLOW_COLUMN,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("column")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_GRID_AUTO_FLOW_DENSE,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("dense")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_GRID_AUTO_FLOW_ROW_DENSE,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("row dense")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_GRID_AUTO_FLOW_COLUMN_DENSE,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("column dense")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                ],
+                line_number: 0,
+                matched: false,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_EASING.to_string(),
+            fastn_resolved::Definition::OrType(fastn_resolved::OrType {
+                name: fastn_builtins::constants::FTD_EASING.to_string(),
+                variants: vec![
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_EASING_LINEAR,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("linear")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_EASING_EASE,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("ease")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_EASING_EASE_IN,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("ease-in")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_EASING_EASE_OUT,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("ease-out")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_EASING_EASE_IN_OUT,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("ease-in-out")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Regular(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_EASING_CUBIC_BEZIER,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Regular(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_EASING_STEPS,
+                        fastn_resolved::Kind::integer()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                ],
+                line_number: 0,
+                matched: false,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_TRANSITION.to_string(),
+            fastn_resolved::Definition::Record(fastn_resolved::Record {
+                name: fastn_builtins::constants::FTD_TRANSITION.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    fastn_resolved::Field {
+                        name: "property".to_string(),
+                        kind: fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::new_string("all"),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "duration".to_string(),
+                        kind: fastn_resolved::Kind::integer()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::Integer { value: 200 },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "easing".to_string(),
+                        kind: fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_EASING)
+                            .into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::OrType {
+                                name: fastn_builtins::constants::FTD_EASING.to_string(),
+                                variant: fastn_builtins::constants::FTD_EASING_EASE.to_string(),
+                                full_variant: fastn_builtins::constants::FTD_EASING_EASE.to_string(),
+                                value: Box::new(
+                                    fastn_resolved::Value::new_string("ease")
+                                        .into_property_value(false, 0),
+                                ),
+                            },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "delay".to_string(),
+                        kind: fastn_resolved::Kind::integer()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::Integer { value: 0 },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_KEYFRAME.to_string(),
+            fastn_resolved::Definition::Record(fastn_resolved::Record {
+                name: fastn_builtins::constants::FTD_KEYFRAME.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    fastn_resolved::Field {
+                        name: "at".to_string(),
+                        kind: fastn_resolved::Kind::integer()
+                            .into_kind_data()
+                            .caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "opacity".to_string(),
+                        kind: fastn_resolved::Kind::decimal()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "scale".to_string(),
+                        kind: fastn_resolved::Kind::decimal()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "rotate".to_string(),
+                        kind: fastn_resolved::Kind::integer()
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "move-x".to_string(),
+                        kind: fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_LENGTH)
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "move-y".to_string(),
+                        kind: fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_LENGTH)
+                            .into_optional()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_KEYFRAMES.to_string(),
+            fastn_resolved::Definition::Record(fastn_resolved::Record {
+                name: fastn_builtins::constants::FTD_KEYFRAMES.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    fastn_resolved::Field {
+                        name: "name".to_string(),
+                        kind: fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "frames".to_string(),
+                        kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_KEYFRAME)
+                            .into_list()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_ANIMATION.to_string(),
+            fastn_resolved::Definition::Record(fastn_resolved::Record {
+                name: fastn_builtins::constants::FTD_ANIMATION.to_string(),
+                fields: std::iter::IntoIterator::into_iter([
+                    fastn_resolved::Field {
+                        name: "keyframes".to_string(),
+                        kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_KEYFRAMES)
+                            .into_kind_data()
+                            .caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "duration".to_string(),
+                        kind: fastn_resolved::Kind::integer()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::Integer { value: 1000 },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "easing".to_string(),
+                        kind: fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_EASING)
+                            .into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::OrType {
+                                name: fastn_builtins::constants::FTD_EASING.to_string(),
+                                variant: fastn_builtins::constants::FTD_EASING_EASE.to_string(),
+                                full_variant: fastn_builtins::constants::FTD_EASING_EASE.to_string(),
+                                value: Box::new(
+                                    fastn_resolved::Value::new_string("ease")
+                                        .into_property_value(false, 0),
+                                ),
+                            },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "delay".to_string(),
+                        kind: fastn_resolved::Kind::integer()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::Integer { value: 0 },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "iterations".to_string(),
+                        kind: fastn_resolved::Kind::integer()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::Integer { value: 1 },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "infinite".to_string(),
+                        kind: fastn_resolved::Kind::boolean()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::Boolean { value: false },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                    fastn_resolved::Field {
+                        name: "alternate".to_string(),
+                        kind: fastn_resolved::Kind::boolean()
+                            .into_kind_data(),
+                        mutable: false,
+                        value: Some(fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::Boolean { value: false },
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                        is_slot: false,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_FONT_SIZE.to_string(),
+            fastn_resolved::Definition::OrType(fastn_resolved::OrType {
+                name: fastn_builtins::constants::FTD_FONT_SIZE.to_string(),
+                variants: vec![
+                    fastn_resolved::OrTypeVariant::Regular(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_FONT_SIZE_PX,
+                        fastn_resolved::Kind::integer()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Regular(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_FONT_SIZE_EM,
+                        fastn_resolved::Kind::decimal()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Regular(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_FONT_SIZE_REM,
+                        fastn_resolved::Kind::decimal()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                ],
+                line_number: 0,
+                matched: false,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_REGION.to_string(),
+            fastn_resolved::Definition::OrType(fastn_resolved::OrType {
+                name: fastn_builtins::constants::FTD_REGION.to_string(),
+                variants: vec![
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_REGION_H1,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(   fastn_resolved::Value::new_string("h1")
+                                    .into_property_value(false, 0),),
                         0,
                     )),
                     fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
@@ -3541,6 +5101,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3658,8 +5219,29 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                             .into_property_value(false, 0)),
                         0,
                     )),
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_TEXT_INPUT_TYPE_NUMBER,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("number")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_TEXT_INPUT_TYPE_SUBMIT,
+                        fastn_resolved::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("submit")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3689,6 +5271,34 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
+            }),
+        ),
+        (
+            fastn_builtins::constants::FTD_FORM_ENCODING.to_string(),
+            fastn_resolved::Definition::OrType(fastn_resolved::OrType {
+                name: fastn_builtins::constants::FTD_FORM_ENCODING.to_string(),
+                variants: vec![
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_FORM_ENCODING_JSON,
+                        fastn_resolved::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(fastn_resolved::Value::new_string("json").into_property_value(false, 0)),
+                        0,
+                    )),
+                    fastn_resolved::OrTypeVariant::Constant(fastn_resolved::Field::new(
+                        fastn_builtins::constants::FTD_FORM_ENCODING_URLENCODED,
+                        fastn_resolved::Kind::string().into_kind_data().caption(),
+                        false,
+                        Some(
+                            fastn_resolved::Value::new_string("urlencoded")
+                                .into_property_value(false, 0),
+                        ),
+                        0,
+                    )),
+                ],
+                line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3778,6 +5388,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3895,6 +5506,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3964,6 +5576,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -3980,6 +5593,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "line-height".to_string(),
@@ -3990,6 +5604,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "letter-spacing".to_string(),
@@ -4000,6 +5615,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "weight".to_string(),
@@ -4010,6 +5626,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "font-family".to_string(),
@@ -4020,6 +5637,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ])
                     .collect(),
@@ -4039,6 +5657,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "mobile".to_string(),
@@ -4057,6 +5676,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                             line_number: 0,
                         }),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ])
                     .collect(),
@@ -4077,6 +5697,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                sse: None,
             }),
         ),
         (
@@ -4093,6 +5714,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                sse: None,
             }),
         ),
         (
@@ -4109,6 +5731,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                sse: None,
             }),
         ),
         (
@@ -4125,6 +5748,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                sse: None,
             }),
         ),
         (
@@ -4141,6 +5765,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                sse: None,
             }),
         ),
         (
@@ -4157,6 +5782,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                sse: None,
             }),
         ),
         (
@@ -4173,6 +5799,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                sse: None,
             }),
         ),
         (
@@ -4235,6 +5862,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "step-1".to_string(),
@@ -4244,6 +5872,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "step-2".to_string(),
@@ -4253,6 +5882,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "overlay".to_string(),
@@ -4262,6 +5892,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "code".to_string(),
@@ -4271,6 +5902,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 line_number: 0,
@@ -4289,6 +5921,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "hover".to_string(),
@@ -4298,6 +5931,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "pressed".to_string(),
@@ -4307,6 +5941,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "disabled".to_string(),
@@ -4316,6 +5951,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "focused".to_string(),
@@ -4325,6 +5961,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "border".to_string(),
@@ -4334,6 +5971,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "border-disabled".to_string(),
@@ -4343,6 +5981,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "text".to_string(),
@@ -4352,6 +5991,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "text-disabled".to_string(),
@@ -4361,6 +6001,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 line_number: 0,
@@ -4379,6 +6020,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "secondary".to_string(),
@@ -4388,6 +6030,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "tertiary".to_string(),
@@ -4397,6 +6040,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 line_number: 0,
@@ -4415,6 +6059,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "text".to_string(),
@@ -4424,6 +6069,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "border".to_string(),
@@ -4433,6 +6079,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 line_number: 0,
@@ -4451,6 +6098,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "two".to_string(),
@@ -4460,6 +6108,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "three".to_string(),
@@ -4469,6 +6118,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "four".to_string(),
@@ -4478,6 +6128,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "five".to_string(),
@@ -4487,6 +6138,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "six".to_string(),
@@ -4496,6 +6148,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "seven".to_string(),
@@ -4505,6 +6158,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "eight".to_string(),
@@ -4514,6 +6168,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "nine".to_string(),
@@ -4523,6 +6178,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "ten".to_string(),
@@ -4532,6 +6188,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 line_number: 0,
@@ -4550,6 +6207,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "border".to_string(),
@@ -4559,6 +6217,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "border-strong".to_string(),
@@ -4568,6 +6227,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "text".to_string(),
@@ -4577,6 +6237,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "text-strong".to_string(),
@@ -4586,6 +6247,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         line_number: 0,
                         access_modifier: Default::default(),
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "shadow".to_string(),
@@ -4595,6 +6257,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         line_number: 0,
                         access_modifier: Default::default(),
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "scrim".to_string(),
@@ -4604,6 +6267,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         line_number: 0,
                         access_modifier: Default::default(),
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "cta-primary".to_string(),
@@ -4612,6 +6276,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         line_number: 0,
                         access_modifier: Default::default(),
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "cta-secondary".to_string(),
@@ -4620,6 +6285,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         line_number: 0,
                         access_modifier: Default::default(),
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "cta-tertiary".to_string(),
@@ -4628,6 +6294,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         line_number: 0,
                         access_modifier: Default::default(),
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "cta-danger".to_string(),
@@ -4636,6 +6303,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         line_number: 0,
                         access_modifier: Default::default(),
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "accent".to_string(),
@@ -4644,6 +6312,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         line_number: 0,
                         access_modifier: Default::default(),
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "error".to_string(),
@@ -4652,6 +6321,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         line_number: 0,
                         access_modifier: Default::default(),
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "success".to_string(),
@@ -4660,6 +6330,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "info".to_string(),
@@ -4668,6 +6339,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "warning".to_string(),
@@ -4676,6 +6348,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "custom".to_string(),
@@ -4684,6 +6357,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ],
                 line_number: 0,
@@ -4700,7 +6374,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "heading-medium".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4708,7 +6383,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "heading-small".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4716,7 +6392,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "heading-hero".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4724,7 +6401,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "heading-tiny".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4732,7 +6410,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "copy-small".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4740,7 +6419,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "copy-regular".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4748,7 +6428,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "copy-large".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4756,7 +6437,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "fine-print".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4764,7 +6446,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "blockquote".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4772,7 +6455,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "source-code".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4780,7 +6464,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "button-small".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4788,7 +6473,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "button-medium".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4797,6 +6483,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     value: None,
                     access_modifier: Default::default(),
                     line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "button-large".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4805,6 +6492,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     value: None,
                     access_modifier: Default::default(),
                     line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "link".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4813,6 +6501,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     value: None,
                     access_modifier: Default::default(),
                     line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "label-large".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4820,7 +6509,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }, fastn_resolved::Field {
                     name: "label-small".to_string(),
                     kind: fastn_resolved::Kind::record(fastn_builtins::constants::FTD_RESPONSIVE_TYPE)
@@ -4828,7 +6518,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 },],
                 line_number: 0
             })
@@ -4846,7 +6537,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 },
                 conditional_value: vec![],
                 line_number: 0,
-                is_static: false
+                is_static: false,
+                sse: None
             })
         ),
         (
@@ -4862,7 +6554,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 },
                 conditional_value: vec![],
                 line_number: 0,
-                is_static: false
+                is_static: false,
+                sse: None
             })
         ),
         (
@@ -4878,7 +6571,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 },
                 conditional_value: vec![],
                 line_number: 0,
-                is_static: false
+                is_static: false,
+                sse: None
             })
         ),
         (
@@ -7640,7 +9334,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 },
                 conditional_value: vec![],
                 line_number: 0,
-                is_static: false
+                is_static: false,
+                sse: None
             })
         ),
         (
@@ -9971,6 +11666,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                sse: None,
             }),
         ),
         (
@@ -9983,7 +11679,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     mutable: false,
                     value: None,
                     access_modifier: Default::default(),
-                    line_number: 0
+                    line_number: 0,
+                    is_slot: false,
                 }],
                 line_number: 0
             })
@@ -10016,7 +11713,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 },
                 conditional_value: vec![],
                 line_number: 0,
-                is_static: false
+                is_static: false,
+                sse: None
             })
         ),
         (
@@ -10045,7 +11743,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         0,
                     )),
                 ],
-                line_number: 0
+                line_number: 0,
+                matched: false,
             })
         ),
         (
@@ -10068,7 +11767,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                 },
                 conditional_value: vec![],
                 line_number: 0,
-                is_static: false
+                is_static: false,
+                sse: None
             })
         ),
         (
@@ -10084,6 +11784,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "linear-gradient".to_string(),
@@ -10094,6 +11795,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "color".to_string(),
@@ -10104,6 +11806,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ]).collect(),
                 line_number: 0,
@@ -10148,6 +11851,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
 
@@ -10218,6 +11922,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -10356,6 +12061,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                                 value: None,
                                 access_modifier: Default::default(),
                                 line_number: 0,
+                                is_slot: false,
                             },
                             fastn_resolved::Field {
                                 name: "y".to_string(),
@@ -10365,12 +12071,14 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                                 value: None,
                                 access_modifier: Default::default(),
                                 line_number: 0,
+                                is_slot: false,
                             },
                         ]).collect(),
                         line_number: 0,
                     }),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -10386,6 +12094,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "size".to_string(),
@@ -10396,6 +12105,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "size-x".to_string(),
@@ -10406,6 +12116,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "size-y".to_string(),
@@ -10416,6 +12127,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "repeat".to_string(),
@@ -10426,6 +12138,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "position".to_string(),
@@ -10436,6 +12149,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ]).collect(),
                 line_number: 0,
@@ -10464,6 +12178,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -10478,6 +12193,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "value".to_string(),
@@ -10492,6 +12208,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         }),
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "error".to_string(),
@@ -10500,6 +12217,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ]).collect(),
                 line_number: 0,
@@ -10517,6 +12235,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "value".to_string(),
@@ -10531,6 +12250,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         }),
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "error".to_string(),
@@ -10539,6 +12259,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ]).collect(),
                 line_number: 0,
@@ -10556,6 +12277,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "value".to_string(),
@@ -10570,6 +12292,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         }),
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "error".to_string(),
@@ -10578,6 +12301,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ]).collect(),
                 line_number: 0,
@@ -10595,6 +12319,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "value".to_string(),
@@ -10609,6 +12334,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         }),
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "error".to_string(),
@@ -10617,6 +12343,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ]).collect(),
                 line_number: 0,
@@ -10649,6 +12376,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
         (
@@ -10688,6 +12416,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, fastn_resolved::Definition> {
                     )),
                 ],
                 line_number: 0,
+                matched: false,
             }),
         ),
     ];
@@ -10742,6 +12471,7 @@ pub fn default_test_bag() -> indexmap::IndexMap<String, fastn_resolved::Definiti
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                     fastn_resolved::Field {
                         name: "value".to_string(),
@@ -10750,6 +12480,7 @@ pub fn default_test_bag() -> indexmap::IndexMap<String, fastn_resolved::Definiti
                         value: None,
                         access_modifier: Default::default(),
                         line_number: 0,
+                        is_slot: false,
                     },
                 ])
                 .collect(),
@@ -11130,6 +12861,7 @@ pub fn checkbox_function() -> fastn_resolved::ComponentDefinition {
         arguments: [
             common_arguments(),
             vec![
+                input_name_argument(),
                 fastn_resolved::Argument::default(
                     "checked",
                     fastn_resolved::Kind::boolean()
@@ -11160,6 +12892,168 @@ pub fn text_input_function() -> fastn_resolved::ComponentDefinition {
             text_arguments(),
             common_arguments(),
             vec![
+                input_name_argument(),
+                fastn_resolved::Argument::default(
+                    "placeholder",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "value",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "default-value",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "multiline",
+                    fastn_resolved::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "enabled",
+                    fastn_resolved::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "max-length",
+                    fastn_resolved::Kind::integer()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "type",
+                    fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_TEXT_INPUT_TYPE)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: fastn_resolved::ComponentInvocation::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+/// `name` of form inputs, `ftd.form` submits the input's value under this name.
+fn input_name_argument() -> fastn_resolved::Argument {
+    fastn_resolved::Argument::default(
+        "name",
+        fastn_resolved::Kind::string()
+            .into_optional()
+            .into_kind_data(),
+    )
+}
+
+fn select_option_list_argument() -> fastn_resolved::Argument {
+    fastn_resolved::Argument::default(
+        "options",
+        fastn_resolved::Kind::record(fastn_builtins::constants::FTD_SELECT_OPTION)
+            .into_list()
+            .into_kind_data(),
+    )
+}
+
+pub fn select_function() -> fastn_resolved::ComponentDefinition {
+    fastn_resolved::ComponentDefinition {
+        name: "ftd#select".to_string(),
+        arguments: [
+            common_arguments(),
+            vec![
+                input_name_argument(),
+                select_option_list_argument(),
+                fastn_resolved::Argument::default(
+                    "value",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "values",
+                    fastn_resolved::Kind::string()
+                        .into_list()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "multiple",
+                    fastn_resolved::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "placeholder",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "enabled",
+                    fastn_resolved::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: fastn_resolved::ComponentInvocation::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn radio_function() -> fastn_resolved::ComponentDefinition {
+    fastn_resolved::ComponentDefinition {
+        name: "ftd#radio".to_string(),
+        arguments: [
+            common_arguments(),
+            vec![
+                input_name_argument(),
+                select_option_list_argument(),
+                fastn_resolved::Argument::default(
+                    "value",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "enabled",
+                    fastn_resolved::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: fastn_resolved::ComponentInvocation::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn textarea_function() -> fastn_resolved::ComponentDefinition {
+    fastn_resolved::ComponentDefinition {
+        name: "ftd#textarea".to_string(),
+        arguments: [
+            text_arguments(),
+            common_arguments(),
+            vec![
+                input_name_argument(),
                 fastn_resolved::Argument::default(
                     "placeholder",
                     fastn_resolved::Kind::string()
@@ -11173,13 +13067,61 @@ pub fn text_input_function() -> fastn_resolved::ComponentDefinition {
                         .into_kind_data(),
                 ),
                 fastn_resolved::Argument::default(
-                    "default-value",
+                    "default-value",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "enabled",
+                    fastn_resolved::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "max-length",
+                    fastn_resolved::Kind::integer()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "rows",
+                    fastn_resolved::Kind::integer()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "auto-resize",
+                    fastn_resolved::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: fastn_resolved::ComponentInvocation::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn file_input_function() -> fastn_resolved::ComponentDefinition {
+    fastn_resolved::ComponentDefinition {
+        name: "ftd#file-input".to_string(),
+        arguments: [
+            common_arguments(),
+            vec![
+                input_name_argument(),
+                fastn_resolved::Argument::default(
+                    "accept",
                     fastn_resolved::Kind::string()
                         .into_optional()
                         .into_kind_data(),
                 ),
                 fastn_resolved::Argument::default(
-                    "multiline",
+                    "multiple",
                     fastn_resolved::Kind::boolean()
                         .into_optional()
                         .into_kind_data(),
@@ -11190,18 +13132,6 @@ pub fn text_input_function() -> fastn_resolved::ComponentDefinition {
                         .into_optional()
                         .into_kind_data(),
                 ),
-                fastn_resolved::Argument::default(
-                    "max-length",
-                    fastn_resolved::Kind::integer()
-                        .into_optional()
-                        .into_kind_data(),
-                ),
-                fastn_resolved::Argument::default(
-                    "type",
-                    fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_TEXT_INPUT_TYPE)
-                        .into_optional()
-                        .into_kind_data(),
-                ),
             ],
         ]
         .concat()
@@ -11376,6 +13306,7 @@ pub fn rive_function() -> fastn_resolved::ComponentDefinition {
                     }),
                     access_modifier: Default::default(),
                     line_number: 0,
+                    is_slot: false,
                 },
                 fastn_resolved::Argument::default(
                     "artboard",
@@ -11468,6 +13399,31 @@ pub fn code_function() -> fastn_resolved::ComponentDefinition {
                     fastn_resolved::Kind::boolean().into_kind_data(),
                     fastn_resolved::Value::Boolean { value: false }.into_property_value(false, 0),
                 ),
+                // Lines to highlight, `3-5,9`
+                fastn_resolved::Argument::default(
+                    "highlight",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                // Lines starting with `+` / `-` are shown as added / removed
+                fastn_resolved::Argument::default_with_value(
+                    "diff",
+                    fastn_resolved::Kind::boolean().into_kind_data(),
+                    fastn_resolved::Value::Boolean { value: false }.into_property_value(false, 0),
+                ),
+                fastn_resolved::Argument::default(
+                    "line-number-start",
+                    fastn_resolved::Kind::integer()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "title",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
             ],
         ]
         .concat()
@@ -11522,6 +13478,102 @@ pub fn iframe_function() -> fastn_resolved::ComponentDefinition {
     }
 }
 
+pub fn svg_function() -> fastn_resolved::ComponentDefinition {
+    fastn_resolved::ComponentDefinition {
+        name: "ftd#svg".to_string(),
+        arguments: [
+            common_arguments(),
+            vec![fastn_resolved::Argument::default(
+                "content",
+                fastn_resolved::Kind::string()
+                    .into_kind_data()
+                    .caption_or_body(),
+            )],
+            svg_color_arguments(),
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: fastn_resolved::ComponentInvocation::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn icon_function() -> fastn_resolved::ComponentDefinition {
+    fastn_resolved::ComponentDefinition {
+        name: "ftd#icon".to_string(),
+        arguments: [
+            common_arguments(),
+            vec![
+                fastn_resolved::Argument::default(
+                    "name",
+                    fastn_resolved::Kind::string().into_kind_data().caption(),
+                ),
+                fastn_resolved::Argument::default(
+                    "set",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                // filled in by fastn from the icon sets of the package
+                fastn_resolved::Argument::default(
+                    "content",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+            svg_color_arguments(),
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: fastn_resolved::ComponentInvocation::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn math_function() -> fastn_resolved::ComponentDefinition {
+    fastn_resolved::ComponentDefinition {
+        name: "ftd#math".to_string(),
+        arguments: [
+            common_arguments(),
+            // LaTeX, replaced by its MathML by fastn
+            vec![fastn_resolved::Argument::default(
+                "text",
+                fastn_resolved::Kind::string()
+                    .into_kind_data()
+                    .caption_or_body(),
+            )],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: fastn_resolved::ComponentInvocation::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+fn svg_color_arguments() -> Vec<fastn_resolved::Argument> {
+    vec![
+        fastn_resolved::Argument::default(
+            "fill",
+            fastn_resolved::Kind::record(fastn_builtins::constants::FTD_COLOR)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        fastn_resolved::Argument::default(
+            "stroke",
+            fastn_resolved::Kind::record(fastn_builtins::constants::FTD_COLOR)
+                .into_optional()
+                .into_kind_data(),
+        ),
+    ]
+}
+
 pub fn column_function() -> fastn_resolved::ComponentDefinition {
     fastn_resolved::ComponentDefinition {
         name: "ftd#column".to_string(),
@@ -11539,6 +13591,171 @@ pub fn column_function() -> fastn_resolved::ComponentDefinition {
     }
 }
 
+pub fn form_function() -> fastn_resolved::ComponentDefinition {
+    fastn_resolved::ComponentDefinition {
+        name: "ftd#form".to_string(),
+        arguments: [
+            container_root_arguments(),
+            container_arguments(),
+            common_arguments(),
+            vec![
+                fastn_resolved::Argument::default(
+                    "fields",
+                    fastn_resolved::Kind::record(fastn_builtins::constants::FTD_FORM_FIELD)
+                        .into_list()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "action",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "method",
+                    fastn_resolved::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "encoding",
+                    fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_FORM_ENCODING)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: fastn_resolved::ComponentInvocation::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn grid_function() -> fastn_resolved::ComponentDefinition {
+    fastn_resolved::ComponentDefinition {
+        name: "ftd#grid".to_string(),
+        arguments: [
+            container_root_arguments(),
+            common_arguments(),
+            vec![
+                fastn_resolved::Argument::default(
+                    "template-columns",
+                    fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_TEMPLATE)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "template-rows",
+                    fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_TEMPLATE)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "template-areas",
+                    fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_VALUE)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "auto-flow",
+                    fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_AUTO_FLOW)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "gap",
+                    fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_LENGTH)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "row-gap",
+                    fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_LENGTH)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "column-gap",
+                    fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_LENGTH)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: fastn_resolved::ComponentInvocation::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn table_function() -> fastn_resolved::ComponentDefinition {
+    fastn_resolved::ComponentDefinition {
+        name: "ftd#table".to_string(),
+        arguments: [
+            container_root_arguments(),
+            common_arguments(),
+            vec![
+                fastn_resolved::Argument::default(
+                    "columns",
+                    fastn_resolved::Kind::record(fastn_builtins::constants::FTD_TABLE_COLUMN)
+                        .into_list()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "data",
+                    fastn_resolved::Kind::object()
+                        .into_list()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "sticky-header",
+                    fastn_resolved::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "sortable",
+                    fastn_resolved::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                fastn_resolved::Argument::default(
+                    "page-size",
+                    fastn_resolved::Kind::integer()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: fastn_resolved::ComponentInvocation::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn table_row_function() -> fastn_resolved::ComponentDefinition {
+    fastn_resolved::ComponentDefinition {
+        name: "ftd#table-row".to_string(),
+        arguments: [container_root_arguments(), common_arguments()]
+            .concat()
+            .into_iter()
+            .collect(),
+        definition: fastn_resolved::ComponentInvocation::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
 pub fn document_function() -> fastn_resolved::ComponentDefinition {
     fastn_resolved::ComponentDefinition {
         name: "ftd#document".to_string(),
@@ -11585,6 +13802,7 @@ pub fn document_function() -> fastn_resolved::ComponentDefinition {
                 }),
                 access_modifier: Default::default(),
                 line_number: 0,
+                is_slot: false,
             },
             fastn_resolved::Argument {
                 name: "twitter-title".to_string(),
@@ -11603,6 +13821,7 @@ pub fn document_function() -> fastn_resolved::ComponentDefinition {
                 }),
                 access_modifier: Default::default(),
                 line_number: 0,
+                is_slot: false,
             },
             fastn_resolved::Argument::default(
                 "description",
@@ -11627,6 +13846,7 @@ pub fn document_function() -> fastn_resolved::ComponentDefinition {
                 }),
                 access_modifier: Default::default(),
                 line_number: 0,
+                is_slot: false,
             },
             fastn_resolved::Argument {
                 name: "twitter-description".to_string(),
@@ -11645,6 +13865,7 @@ pub fn document_function() -> fastn_resolved::ComponentDefinition {
                 }),
                 access_modifier: Default::default(),
                 line_number: 0,
+                is_slot: false,
             },
             fastn_resolved::Argument::default(
                 "og-image",
@@ -11667,6 +13888,7 @@ pub fn document_function() -> fastn_resolved::ComponentDefinition {
                 }),
                 access_modifier: Default::default(),
                 line_number: 0,
+                is_slot: false,
             },
             fastn_resolved::Argument::default(
                 "theme-color",
@@ -12188,6 +14410,36 @@ fn common_arguments() -> Vec<fastn_resolved::Argument> {
                 .into_optional()
                 .into_kind_data(),
         ),
+        fastn_resolved::Argument::default(
+            "grid-column",
+            fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_VALUE)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        fastn_resolved::Argument::default(
+            "grid-row",
+            fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_VALUE)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        fastn_resolved::Argument::default(
+            "grid-area",
+            fastn_resolved::Kind::or_type(fastn_builtins::constants::FTD_GRID_VALUE)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        fastn_resolved::Argument::default(
+            "transition",
+            fastn_resolved::Kind::record(fastn_builtins::constants::FTD_TRANSITION)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        fastn_resolved::Argument::default(
+            "animation",
+            fastn_resolved::Kind::record(fastn_builtins::constants::FTD_ANIMATION)
+                .into_optional()
+                .into_kind_data(),
+        ),
     ]
 }
 
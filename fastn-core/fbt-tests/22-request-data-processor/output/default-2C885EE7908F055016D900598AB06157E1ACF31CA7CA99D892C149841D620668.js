@@ -189,10 +189,23 @@ const fastn = (function (fastn) {
         }
 
         get(key) {
+            if (
+                !fastn_utils.isNull(key) &&
+                this.#value instanceof OrTypeValue
+            ) {
+                // `$status.error` is the value of the `error` variant, and has
+                // to follow `$status` as it switches between variants
+                return fastn.formula([this], () =>
+                    this.#value instanceof OrTypeValue
+                        ? this.#value.get(key)
+                        : null,
+                );
+            }
             if (
                 !fastn_utils.isNull(key) &&
                 (this.#value instanceof RecordInstance ||
                     this.#value instanceof MutableList ||
+                    this.#value instanceof MutableMap ||
                     this.#value instanceof Mutable)
             ) {
                 return this.#value.get(key);
@@ -200,13 +213,13 @@ const fastn = (function (fastn) {
             return this.#value;
         }
 
-        forLoop(root, dom_constructor) {
+        forLoop(root, dom_constructor, key) {
             if ((!this.#value) instanceof MutableList) {
                 throw new Error(
                     "`forLoop` can only run for MutableList type object",
                 );
             }
-            this.#value.forLoop(root, dom_constructor);
+            this.#value.forLoop(root, dom_constructor, key);
         }
 
         setWithoutUpdate(value) {
@@ -222,7 +235,10 @@ const fastn = (function (fastn) {
                 // The `this.#value.replace(value);` will replace the value of
                 // `orange-green` with `{light: red, dark: red}`
                 this.#value = value;
-            } else if (this.#value instanceof MutableList) {
+            } else if (
+                this.#value instanceof MutableList ||
+                this.#value instanceof MutableMap
+            ) {
                 if (value instanceof fastn.mutableClass) {
                     value = value.get();
                 }
@@ -317,6 +333,7 @@ const fastn = (function (fastn) {
                 !!key &&
                 (this.#cached_value instanceof RecordInstance ||
                     this.#cached_value instanceof MutableList ||
+                    this.#cached_value instanceof MutableMap ||
                     this.#cached_value instanceof Mutable)
             ) {
                 return this.#cached_value.get(key);
@@ -360,8 +377,8 @@ const fastn = (function (fastn) {
             );
         }
 
-        forLoop(root, dom_constructor) {
-            let l = fastn_dom.forLoop(root, dom_constructor, this);
+        forLoop(root, dom_constructor, key) {
+            let l = fastn_dom.forLoop(root, dom_constructor, this, key);
             this.#watchers.push(l);
             return l;
         }
@@ -400,12 +417,19 @@ const fastn = (function (fastn) {
                 }
 
                 let list = value.#list;
-                this.#list = [];
-                for (let i in list) {
-                    this.#list.push(list[i]);
+                this.deleteEmptyWatchers();
+                let keys = this.#watchers
+                    .map((watcher) => watcher.getKey())
+                    .filter((key) => !fastn_utils.isNull(key));
+                if (keys.length === 0) {
+                    this.#list = [];
+                    for (let i in list) {
+                        this.#list.push(list[i]);
+                    }
+                } else {
+                    this.#list = this.reuseItems(list, keys);
                 }
 
-                this.deleteEmptyWatchers();
                 for (let i in this.#watchers) {
                     this.#watchers[i].createAllNode();
                 }
@@ -417,6 +441,52 @@ const fastn = (function (fastn) {
             this.#closures.forEach((closure) => closure.update());
         }
 
+        // An item of the new list that has the same keys, in every keyed loop
+        // of the list, as an item of the current list takes its place, with
+        // the new value, so the keyed loops can keep the nodes they created
+        // for it. Items with the same keys are matched in their order.
+        reuseItems(list, keys) {
+            let keyOf = (item) =>
+                JSON.stringify(
+                    keys.map((key) =>
+                        fastn_utils.getFlattenStaticValue(
+                            key(item.item, item.index),
+                        ),
+                    ),
+                );
+            let current = new Map();
+            for (let item of this.#list) {
+                let k = keyOf(item);
+                if (!current.has(k)) {
+                    current.set(k, []);
+                }
+                current.get(k).push(item);
+            }
+            return list.map((item, index) => {
+                let old = current.get(keyOf(item))?.shift();
+                if (old === undefined) {
+                    item.index.set(index);
+                    return item;
+                }
+                if (
+                    !fastn_utils.deepEqual(
+                        fastn_utils.mutableToStaticValue(old.item),
+                        fastn_utils.mutableToStaticValue(item.item),
+                    )
+                ) {
+                    let value = item.item;
+                    if (value instanceof Mutable) {
+                        value = value.get();
+                    }
+                    old.item.set(value);
+                }
+                if (old.index.get() !== index) {
+                    old.index.set(index);
+                }
+                return old;
+            });
+        }
+
         // The watcher sometimes doesn't get deleted when the list is wrapped
         // inside some ancestor DOM with if condition,
         // so when if condition is unsatisfied the DOM gets deleted without removing
@@ -524,6 +594,101 @@ const fastn = (function (fastn) {
         }
     }
 
+    class MutableMap {
+        #map;
+        #closures;
+
+        constructor(obj) {
+            this.#map = {};
+            this.#closures = [];
+            for (let key in obj) {
+                this.#map[key] = fastn.wrapMutable(obj[key]);
+            }
+        }
+
+        addClosure(closure) {
+            this.#closures.push(closure);
+        }
+
+        unlinkNode(node) {
+            this.#closures = this.#closures.filter(
+                (closure) => closure.getNode() !== node,
+            );
+        }
+
+        getMap() {
+            return this.#map;
+        }
+
+        get(key) {
+            if (fastn_utils.isNull(key)) {
+                return this.getMap();
+            }
+            return this.#map[fastn_utils.getFlattenStaticValue(key)];
+        }
+
+        contains(key) {
+            return fastn_utils.getFlattenStaticValue(key) in this.#map;
+        }
+
+        keys() {
+            return Object.keys(this.#map);
+        }
+
+        getLength() {
+            return this.keys().length;
+        }
+
+        set(key, value) {
+            if (value === undefined) {
+                value = key;
+                if (value instanceof fastn.mutableClass) {
+                    value = value.get();
+                }
+                if (!(value instanceof MutableMap)) {
+                    value = new MutableMap(value);
+                }
+                this.#map = Object.assign({}, value.#map);
+            } else {
+                this.insert(key, value);
+                return;
+            }
+            this.#closures.forEach((closure) => closure.update());
+        }
+
+        insert(key, value) {
+            key = fastn_utils.getFlattenStaticValue(key);
+            if (this.#map[key] === undefined) {
+                this.#map[key] = fastn.wrapMutable(value);
+            } else {
+                this.#map[key].set(value);
+            }
+            this.#closures.forEach((closure) => closure.update());
+        }
+
+        remove(key) {
+            key = fastn_utils.getFlattenStaticValue(key);
+            if (!(key in this.#map)) {
+                return;
+            }
+            delete this.#map[key];
+            this.#closures.forEach((closure) => closure.update());
+        }
+
+        clearAll() {
+            this.#map = {};
+            this.#closures.forEach((closure) => closure.update());
+        }
+
+        getClone() {
+            let cloned = {};
+            for (let key in this.#map) {
+                cloned[key] = fastn_utils.clone(this.#map[key]);
+            }
+            return new MutableMap(cloned);
+        }
+    }
+
     fastn.mutable = function (val) {
         return new Mutable(val);
     };
@@ -562,7 +727,8 @@ const fastn = (function (fastn) {
         if (
             !(obj instanceof Mutable) &&
             !(obj instanceof RecordInstance) &&
-            !(obj instanceof MutableList)
+            !(obj instanceof MutableList) &&
+            !(obj instanceof MutableMap)
         ) {
             obj = new Mutable(obj);
         }
@@ -573,6 +739,10 @@ const fastn = (function (fastn) {
         return new MutableList(list);
     };
 
+    fastn.mutableMap = function (obj) {
+        return new MutableMap(obj);
+    };
+
     class RecordInstance {
         #fields;
         #closures;
@@ -688,6 +858,34 @@ const fastn = (function (fastn) {
         }
     }
 
+    class OrTypeValue {
+        #variant;
+        #value;
+
+        constructor(variant, value) {
+            this.#variant = variant;
+            this.#value = value;
+        }
+
+        getVariant() {
+            return this.#variant;
+        }
+
+        getValue() {
+            return this.#value;
+        }
+
+        get(variant) {
+            if (
+                this.#variant === variant ||
+                this.#variant.replace(/-/g, "_") === variant
+            ) {
+                return this.#value;
+            }
+            return null;
+        }
+    }
+
     class Module {
         #name;
         #global;
@@ -710,13 +908,19 @@ const fastn = (function (fastn) {
         return new RecordInstance(obj);
     };
 
+    fastn.orType = function (variant) {
+        return (value) => new OrTypeValue(variant, value);
+    };
+
     fastn.color = function (r, g, b) {
         return `rgb(${r},${g},${b})`;
     };
 
     fastn.mutableClass = Mutable;
     fastn.mutableListClass = MutableList;
+    fastn.mutableMapClass = MutableMap;
     fastn.recordInstanceClass = RecordInstance;
+    fastn.orTypeValueClass = OrTypeValue;
     fastn.module = function (name, global) {
         return new Module(name, global);
     };
@@ -732,6 +936,13 @@ fastn_dom.InternalClass = {
     FT_COLUMN: "ft_column",
     FT_ROW: "ft_row",
     FT_FULL_SIZE: "ft_full_size",
+    FT_RADIO: "ft_radio",
+    FT_TABLE: "ft_table",
+    FT_TABLE_ROW: "ft_table_row",
+    FT_GRID: "ft_grid",
+    FT_SVG: "ft_svg",
+    FT_ICON: "ft_icon",
+    FT_MATH: "ft_math",
 };
 
 fastn_dom.codeData = {
@@ -745,10 +956,18 @@ fastn_dom.externalJs = new Set();
 // Todo: Object (key, value) pair (counter type key)
 fastn_dom.webComponent = [];
 
+fastn_dom.radioGroupCounter = 0;
+
 fastn_dom.commentNode = "comment";
 fastn_dom.wrapperNode = "wrapper";
 fastn_dom.commentMessage = "***FASTN***";
 fastn_dom.webComponentArgument = "args";
+// meta tags added by `ftd.document`, removed on client-side navigation
+fastn_dom.metaTagAttribute = "data-fastn-meta";
+// nodes holding timers or observers, destroyed on client-side navigation
+fastn_dom.disposableNodes = new Set();
+// `$sse$` subscriptions, closed on client-side navigation
+fastn_dom.eventSources = new Set();
 
 fastn_dom.classes = {};
 fastn_dom.unsanitised_classes = {};
@@ -756,6 +975,7 @@ fastn_dom.class_count = 0;
 fastn_dom.propertyMap = {
     "align-items": "ali",
     "align-self": "as",
+    animation: "anm",
     "background-color": "bgc",
     "background-image": "bgi",
     "background-position": "bgp",
@@ -788,10 +1008,20 @@ fastn_dom.propertyMap = {
     cursor: "cur",
     display: "d",
     download: "dw",
+    fill: "fl",
     "flex-wrap": "fw",
     "font-style": "fst",
     "font-weight": "fwt",
     gap: "g",
+    "row-gap": "rg",
+    "column-gap": "cg",
+    "grid-template-columns": "gtc",
+    "grid-template-rows": "gtr",
+    "grid-template-areas": "gta",
+    "grid-auto-flow": "gaf",
+    "grid-column": "gc",
+    "grid-row": "gr",
+    "grid-area": "ga",
     height: "h",
     "justify-content": "jc",
     left: "l",
@@ -825,10 +1055,12 @@ fastn_dom.propertyMap = {
     role: "rl",
     right: "r",
     sticky: "s",
+    stroke: "stk",
     "text-align": "ta",
     "text-decoration": "td",
     "text-transform": "tt",
     top: "t",
+    transition: "trn",
     width: "w",
     "z-index": "z",
     "-webkit-box-orient": "wbo",
@@ -864,6 +1096,9 @@ fastn_dom.getClassesAsStringWithoutStyleTag = function () {
 };
 
 function getClassAsString(className, obj) {
+    if (obj.property === "@keyframes") {
+        return `${className} { ${obj.value} }`;
+    }
     if (typeof obj.value === "object" && obj.value !== null) {
         let value = "";
         for (let key in obj.value) {
@@ -910,6 +1145,17 @@ fastn_dom.ElementKind = {
     },
     Video: 18,
     Audio: 19,
+    Select: 20,
+    Radio: 21,
+    Textarea: 22,
+    FileInput: 23,
+    Form: 24,
+    Table: 25,
+    TableRow: 26,
+    Grid: 27,
+    Svg: 28,
+    Icon: 29,
+    Math: 30,
 };
 
 fastn_dom.PropertyKind = {
@@ -1041,6 +1287,96 @@ fastn_dom.PropertyKind = {
     FetchPriority: 123,
     Download: 124,
     SrcDoc: 125,
+    Options: 126,
+    SelectedValue: 127,
+    SelectedValues: 128,
+    Multiple: 129,
+    Rows: 130,
+    AutoResize: 131,
+    Accept: 132,
+    Name: 133,
+    FormFields: 134,
+    FormAction: 135,
+    FormMethod: 136,
+    FormEncoding: 137,
+    TableColumns: 138,
+    TableData: 139,
+    StickyHeader: 140,
+    Sortable: 141,
+    PageSize: 142,
+    GridTemplateColumns: 143,
+    GridTemplateRows: 144,
+    GridTemplateAreas: 145,
+    GridAutoFlow: 146,
+    Gap: 147,
+    RowGap: 148,
+    ColumnGap: 149,
+    GridColumn: 150,
+    GridRow: 151,
+    GridArea: 152,
+    SvgContent: 153,
+    Fill: 154,
+    Stroke: 155,
+    Transition: 156,
+    Animation: 157,
+    CodeHighlight: 158,
+    CodeDiff: 159,
+    CodeLineNumberStart: 160,
+    CodeTitle: 161,
+    MathContent: 162,
+};
+
+// `count` is a number of equal tracks, like `repeat(3, minmax(0, 1fr))`
+fastn_dom.GridTemplate = {
+    Fixed: (value) => {
+        return value;
+    },
+    Count: (value) => {
+        if (value instanceof fastn.mutableClass) {
+            return fastn.formula([value], function () {
+                return fastn_dom.GridTemplate.Count(value.get());
+            });
+        }
+        return `repeat(${value}, minmax(0, 1fr))`;
+    },
+    Responsive: (value) => fastn_dom.Length.Responsive(value),
+};
+
+fastn_dom.GridValue = {
+    Fixed: (value) => {
+        return value;
+    },
+    Span: (value) => {
+        if (value instanceof fastn.mutableClass) {
+            return fastn.formula([value], function () {
+                return `span ${value.get()}`;
+            });
+        }
+        return `span ${value}`;
+    },
+    Responsive: (value) => fastn_dom.Length.Responsive(value),
+};
+
+fastn_dom.GridAutoFlow = {
+    Row: "row",
+    Column: "column",
+    Dense: "dense",
+    RowDense: "row dense",
+    ColumnDense: "column dense",
+};
+
+fastn_dom.Easing = {
+    Linear: "linear",
+    Ease: "ease",
+    EaseIn: "ease-in",
+    EaseOut: "ease-out",
+    EaseInOut: "ease-in-out",
+    CubicBezier: (value) => {
+        return `cubic-bezier(${value})`;
+    },
+    Steps: (value) => {
+        return `steps(${value})`;
+    },
 };
 
 fastn_dom.Loading = {
@@ -1048,6 +1384,11 @@ fastn_dom.Loading = {
     Eager: "eager",
 };
 
+fastn_dom.FormEncoding = {
+    Json: "json",
+    UrlEncoded: "urlencoded",
+};
+
 fastn_dom.LinkRel = {
     NoFollow: "nofollow",
     Sponsored: "sponsored",
@@ -1066,6 +1407,8 @@ fastn_dom.TextInputType = {
     Week: "week",
     Color: "color",
     File: "file",
+    Number: "number",
+    Submit: "submit",
 };
 
 fastn_dom.AlignContent = {
@@ -1540,6 +1883,21 @@ fastn_dom.Event = {
     Change: 7,
     Blur: 8,
     Focus: 9,
+    Interval: (ms) => {
+        return [10, ms];
+    },
+    Timeout: (ms) => {
+        return [11, ms];
+    },
+    Visible: 12,
+    Hidden: 13,
+    Scroll: (ms) => {
+        return [14, ms];
+    },
+    Submit: 15,
+    KeyDown: (keys) => {
+        return [16, keys];
+    },
 };
 
 class PropertyValueAsClosure {
@@ -1591,6 +1949,7 @@ class Node2 {
 
         this.#mutables = [];
         this.#extraData = {};
+        let node = this.wrapTableCell(parentOrSibiling);
         /*if (!!parent.parent) {
             parent = parent.parent();
         }*/
@@ -1605,12 +1964,32 @@ class Node2 {
         }
         if (sibiling) {
             this.#parent.insertBefore(
-                this.#node,
+                node,
                 fastn_utils.nextSibling(sibiling, this.#parent),
             );
         } else {
-            this.#parent.appendChild(this.#node);
+            this.#parent.appendChild(node);
+        }
+    }
+    // The children of `ftd.table-row` are the cells of the row, each one is
+    // wrapped in a `td`.
+    wrapTableCell(parentOrSibiling) {
+        let owner = parentOrSibiling;
+        while (owner instanceof ParentNodeWithSibiling) {
+            owner = owner.getParent();
         }
+        if (
+            !(owner instanceof Node2) ||
+            owner.#kind !== fastn_dom.ElementKind.TableRow ||
+            this.#kind === fastn_dom.ElementKind.Comment ||
+            fastn_utils.isWrapperNode(this.#tagName)
+        ) {
+            return this.#node;
+        }
+        let cell = fastnVirtual.document.createElement("td");
+        cell.appendChild(this.#node);
+        this.#extraData.tableCell = cell;
+        return cell;
     }
     createNode(kind) {
         if (kind === fastn_dom.ElementKind.Code) {
@@ -1677,6 +2056,216 @@ class Node2 {
             this.attachAttribute("value", this.#rawInnerValue);
         }
     }
+    // Renders the `options` of `ftd.select` and `ftd.radio` along with the
+    // current selection. The options are rendered as html so it works the
+    // same way in ssr and in the browser.
+    updateOptions() {
+        let form = this.#extraData.form;
+        if (fastn_utils.isNull(form) || fastn_utils.isNull(form.options)) {
+            return;
+        }
+        let selected = new Set(form.values.map((v) => `${v}`));
+        if (!fastn_utils.isNull(form.value)) {
+            selected.add(`${form.value}`);
+        }
+        let escape = fastn_utils.escapeHtml;
+        let html = "";
+        if (
+            this.#kind === fastn_dom.ElementKind.Select &&
+            !fastn_utils.isNull(form.placeholder)
+        ) {
+            html += `<option value="" disabled hidden${
+                selected.size === 0 ? " selected" : ""
+            }>${escape(form.placeholder)}</option>`;
+        }
+        for (let option of form.options) {
+            let record = fastn_utils.getStaticValue(option.item);
+            let label = fastn_utils.getStaticValue(record.get("label"));
+            let value = fastn_utils.getStaticValue(record.get("value"));
+            let disabled = fastn_utils.getStaticValue(record.get("disabled"));
+            let isSelected = selected.has(`${value}`);
+            if (this.#kind === fastn_dom.ElementKind.Radio) {
+                html += `<label><input type="radio" name="${
+                    form.name
+                }" value="${escape(value)}"${isSelected ? " checked" : ""}${
+                    disabled ? " disabled" : ""
+                }>${escape(label)}</label>`;
+            } else {
+                html += `<option value="${escape(value)}"${
+                    isSelected ? " selected" : ""
+                }${disabled ? " disabled" : ""}>${escape(label)}</option>`;
+            }
+        }
+        this.#node.innerHTML = html;
+    }
+    // `ftd.table` keeps its header, `data` rows and pager in `thead`, `tbody`
+    // and `tfoot`. `ftd.table-row` children are appended after these.
+    getTableData() {
+        if (fastn_utils.isNull(this.#extraData.table)) {
+            let table = {
+                columns: [],
+                data: null,
+                sortable: false,
+                pageSize: null,
+                sortColumn: null,
+                descending: false,
+                page: 0,
+            };
+            for (let section of ["thead", "tbody", "tfoot"]) {
+                table[section] = fastnVirtual.document.createElement(section);
+                this.#node.appendChild(table[section]);
+            }
+            this.#extraData.table = table;
+            if (!ssr) {
+                this.attachTableListeners();
+            }
+        }
+        return this.#extraData.table;
+    }
+    attachTableListeners() {
+        this.#node.addEventListener("click", (event) => {
+            let target = event.target.closest(
+                "th[data-column], button[data-page]",
+            );
+            if (!target || target.closest("table") !== this.#node) {
+                return;
+            }
+            let table = this.#extraData.table;
+            if (target.tagName === "BUTTON") {
+                table.page += Number(target.dataset.page);
+                this.updateTableRows();
+                return;
+            }
+            let index = Number(target.dataset.column);
+            if (!table.sortable || !table.columns[index].sortable) {
+                return;
+            }
+            table.descending = table.sortColumn === index && !table.descending;
+            table.sortColumn = index;
+            table.page = 0;
+            this.updateTable();
+        });
+        // `ftd.table-row` children are created after the table properties
+        // are set and can change with the list they are looped over
+        let observer = new MutationObserver(() => this.updateTableRows());
+        observer.observe(this.#node, { childList: true });
+        this.#extraData.tableObserver = observer;
+    }
+    updateTable() {
+        let table = this.getTableData();
+        let header = table.columns.map((column, index) => {
+            let attributes = "";
+            if (table.sortable && column.sortable) {
+                let sort = "none";
+                if (table.sortColumn === index) {
+                    sort = table.descending ? "descending" : "ascending";
+                }
+                attributes = ` class="ft_table_sortable" aria-sort="${sort}"`;
+            }
+            return `<th scope="col" data-column="${index}"${attributes}>${
+                fastn_utils.escapeHtml(column.header)
+            }</th>`;
+        });
+        table.thead.innerHTML =
+            header.length > 0 ? `<tr>${header.join("")}</tr>` : "";
+        this.updateTableRows();
+    }
+    // Renders `data` (or orders the `ftd.table-row` children) sorted by the
+    // selected column and shows only the current page.
+    updateTableRows() {
+        let table = this.getTableData();
+        let column = table.columns[table.sortColumn];
+        let compare = (a, b) => {
+            let order = fastn_utils.compareTableValues(a, b);
+            return table.descending ? -order : order;
+        };
+        let pageSize = table.pageSize > 0 ? Number(table.pageSize) : null;
+        let rows;
+        if (!fastn_utils.isNull(table.data)) {
+            rows = table.data.map((row) =>
+                fastn_utils.getStaticValue(row.item),
+            );
+            if (!fastn_utils.isNull(column)) {
+                let key = (row) => fastn_utils.getTableCellValue(row, column);
+                rows = rows.slice().sort((a, b) => compare(key(a), key(b)));
+            }
+        } else if (!ssr) {
+            rows = Array.from(this.#node.children).filter((row) =>
+                row.classList.contains(fastn_dom.InternalClass.FT_TABLE_ROW),
+            );
+            if (!fastn_utils.isNull(column)) {
+                let key = (row) => {
+                    let cell = row.children[table.sortColumn];
+                    if (fastn_utils.isNull(cell)) return null;
+                    if (cell.hasAttribute("data-sort")) {
+                        return cell.getAttribute("data-sort");
+                    }
+                    return cell.textContent.trim();
+                };
+                rows.sort((a, b) => compare(key(a), key(b)));
+                let observer = this.#extraData.tableObserver;
+                observer.disconnect();
+                rows.forEach((row) => this.#node.appendChild(row));
+                observer.observe(this.#node, { childList: true });
+            }
+        } else {
+            return;
+        }
+
+        let pages = pageSize
+            ? Math.max(Math.ceil(rows.length / pageSize), 1)
+            : 1;
+        table.page = Math.min(Math.max(table.page, 0), pages - 1);
+        let start = pageSize ? table.page * pageSize : 0;
+        let end = pageSize ? start + pageSize : rows.length;
+
+        if (!fastn_utils.isNull(table.data)) {
+            table.tbody.innerHTML = rows
+                .slice(start, end)
+                .map((row) => {
+                    let cells = table.columns.map((column) => {
+                        let value = fastn_utils.getTableCellValue(row, column);
+                        return `<td>${fastn_utils.escapeHtml(
+                            fastn_utils.isNull(value) ? "" : value,
+                        )}</td>`;
+                    });
+                    return `<tr>${cells.join("")}</tr>`;
+                })
+                .join("");
+        } else {
+            rows.forEach((row, index) => {
+                row.hidden = index < start || index >= end;
+            });
+        }
+
+        if (pages === 1) {
+            table.tfoot.innerHTML = "";
+            return;
+        }
+        let button = (page, label, disabled) =>
+            `<button type="button" data-page="${page}"${
+                disabled ? " disabled" : ""
+            }>${label}</button>`;
+        table.tfoot.innerHTML =
+            `<tr><td colspan="${Math.max(table.columns.length, 1)}">` +
+            button(-1, "Previous", table.page === 0) +
+            `<span>Page ${table.page + 1} of ${pages}</span>` +
+            button(1, "Next", table.page === pages - 1) +
+            "</td></tr>";
+    }
+    getFormData() {
+        if (fastn_utils.isNull(this.#extraData.form)) {
+            this.#extraData.form = {
+                options: null,
+                value: null,
+                values: [],
+                placeholder: null,
+                // radio inputs of one group share a name
+                name: `ft_radio_${fastn_dom.radioGroupCounter++}`,
+            };
+        }
+        return this.#extraData.form;
+    }
     // for attaching inline attributes
     attachAttribute(property, value) {
         // If the value is null, undefined, or false, the attribute will be removed.
@@ -1687,6 +2276,29 @@ class Node2 {
         }
         this.#node.setAttribute(property, value);
     }
+    // Shows the text of `ftd.code` with its `highlight`, `diff` and
+    // `line-number-start`, these are set before the text.
+    updateCode() {
+        let code = this.#extraData.code;
+        if (code.text === undefined) {
+            return;
+        }
+        let text = code.text;
+        if (!fastn_utils.isNull(text)) {
+            let { modifiedText, highlightedLines, signs, callouts } =
+                fastn_utils.prepareCode(text, code);
+            this.attachAttribute(
+                "data-line",
+                highlightedLines.length !== 0 ? highlightedLines : null,
+            );
+            code.signs = signs;
+            code.callouts = callouts;
+            text = modifiedText;
+        }
+        let codeNode = this.#children[0].getNode();
+        codeNode.innerHTML = fastn_utils.escapeHtmlInCode(text);
+        fastn_utils.highlightCode(codeNode, code);
+    }
     removeAttribute(property) {
         this.#node.removeAttribute(property);
     }
@@ -1766,6 +2378,7 @@ class Node2 {
             const metaTag = window.document.createElement("meta");
             metaTag.setAttribute("name", name);
             metaTag.setAttribute("content", value);
+            metaTag.setAttribute(fastn_dom.metaTagAttribute, "");
             document.head.appendChild(metaTag);
         }
     }
@@ -1778,6 +2391,7 @@ class Node2 {
             const metaTag = window.document.createElement("meta");
             metaTag.setAttribute("property", property);
             metaTag.setAttribute("content", value);
+            metaTag.setAttribute(fastn_dom.metaTagAttribute, "");
             document.head.appendChild(metaTag);
         }
     }
@@ -1918,6 +2532,92 @@ class Node2 {
             );
         }
     }
+    // `body.reduced-motion` is set by `ftd.post_init` when the user prefers
+    // reduced motion, and turns off the transitions and animations.
+    attachReducedMotionCss(property, value) {
+        if (fastn_utils.isNull(value)) {
+            this.attachCss(property, value);
+            return;
+        }
+        let cls = this.attachCss(property, value, true);
+        this.attachCss(property, "none", true, `body.reduced-motion .${cls}`);
+    }
+    attachTransition(value) {
+        if (fastn_utils.isNull(value)) {
+            this.attachReducedMotionCss("transition", value);
+            return;
+        }
+        const duration = fastn_utils.getStaticValue(value.get("duration"));
+        const easing = fastn_utils.getStaticValue(value.get("easing"));
+        const delay = fastn_utils.getStaticValue(value.get("delay"));
+        const transition = fastn_utils
+            .getStaticValue(value.get("property"))
+            .split(",")
+            .map((p) => `${p.trim()} ${duration}ms ${easing} ${delay}ms`)
+            .join(", ");
+        this.attachReducedMotionCss("transition", transition);
+    }
+    attachAnimation(value) {
+        if (fastn_utils.isNull(value)) {
+            this.attachReducedMotionCss("animation", value);
+            return;
+        }
+        const name = this.attachKeyframes(value.get("keyframes").get());
+        const duration = fastn_utils.getStaticValue(value.get("duration"));
+        const easing = fastn_utils.getStaticValue(value.get("easing"));
+        const delay = fastn_utils.getStaticValue(value.get("delay"));
+        const iterations = fastn_utils.getStaticValue(value.get("infinite"))
+            ? "infinite"
+            : fastn_utils.getStaticValue(value.get("iterations"));
+        const direction = fastn_utils.getStaticValue(value.get("alternate"))
+            ? "alternate"
+            : "normal";
+        this.attachReducedMotionCss(
+            "animation",
+            `${name} ${duration}ms ${easing} ${delay}ms ${iterations} ` +
+                `${direction} both`,
+        );
+    }
+    // Keyframes are added to the styles once per name, as `ftd-<name>`.
+    attachKeyframes(keyframes) {
+        const name = `ftd-${fastn_utils
+            .getStaticValue(keyframes.get("name"))
+            .replace(/[^\w-]/g, "-")}`;
+        const frames = keyframes
+            .get("frames")
+            .get()
+            .getList()
+            .map((element) => {
+                const frame = element.item;
+                const value = (key) =>
+                    fastn_utils.getStaticValue(frame.get(key));
+                const moves = ["move_x", "move_y"].some(
+                    (key) => !fastn_utils.isNull(value(key)),
+                );
+                const transform = [
+                    moves
+                        ? `translate(${value("move_x") || 0}, ` +
+                          `${value("move_y") || 0})`
+                        : null,
+                    fastn_utils.isNull(value("scale"))
+                        ? null
+                        : `scale(${value("scale")})`,
+                    fastn_utils.isNull(value("rotate"))
+                        ? null
+                        : `rotate(${value("rotate")}deg)`,
+                ].filter((t) => t !== null);
+                let css = fastn_utils.isNull(value("opacity"))
+                    ? ""
+                    : ` opacity: ${value("opacity")};`;
+                if (transform.length > 0) {
+                    css = `${css} transform: ${transform.join(" ")};`;
+                }
+                return `${value("at")}% {${css} }`;
+            })
+            .join(" ");
+        this.attachCss("@keyframes", frames, true, `@keyframes ${name}`);
+        return name;
+    }
     attachBackdropMultiFilter(value) {
         const filters = {
             blur: fastn_utils.getStaticValue(value.get("blur")),
@@ -2407,7 +3107,10 @@ class Node2 {
             this.attachCss("justify-content", value);
             return;
         }
-        if (node_kind === fastn_dom.ElementKind.Column) {
+        if (
+            node_kind === fastn_dom.ElementKind.Column ||
+            node_kind === fastn_dom.ElementKind.Form
+        ) {
             switch (value) {
                 case "top-left":
                     this.attachCss("justify-content", "start");
@@ -2793,6 +3496,52 @@ class Node2 {
             this.attachCss("border-bottom-style", staticValue);
         } else if (kind === fastn_dom.PropertyKind.ZIndex) {
             this.attachCss("z-index", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridTemplateColumns) {
+            this.attachCss("grid-template-columns", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridTemplateRows) {
+            this.attachCss("grid-template-rows", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridTemplateAreas) {
+            this.attachCss("grid-template-areas", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridAutoFlow) {
+            this.attachCss("grid-auto-flow", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Gap) {
+            this.attachCss("gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.RowGap) {
+            this.attachCss("row-gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.ColumnGap) {
+            this.attachCss("column-gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridColumn) {
+            this.attachCss("grid-column", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridRow) {
+            this.attachCss("grid-row", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridArea) {
+            this.attachCss("grid-area", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.SvgContent) {
+            this.#node.innerHTML = fastn_utils.sanitizeSvg(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MathContent) {
+            // fastn renders the LaTeX to MathML at compile time, LaTeX in a
+            // variable is shown as written
+            let math = fastn_utils.isNull(staticValue) ? "" : `${staticValue}`;
+            this.#node.innerHTML = fastn_utils.private.trustedMarkup.has(math)
+                ? math
+                : fastn_utils.escapeHtml(math);
+        } else if (
+            kind === fastn_dom.PropertyKind.Fill ||
+            kind === fastn_dom.PropertyKind.Stroke
+        ) {
+            let property =
+                kind === fastn_dom.PropertyKind.Fill ? "fill" : "stroke";
+            this.attachColorCss(property, staticValue);
+            // `data-fill` and `data-stroke` make the colour win over the ones
+            // in the svg markup, see ftd-js.css
+            this.attachAttribute(
+                `data-${property}`,
+                fastn_utils.isNull(staticValue) ? null : "",
+            );
+        } else if (kind === fastn_dom.PropertyKind.Transition) {
+            this.attachTransition(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Animation) {
+            this.attachAnimation(staticValue);
         } else if (kind === fastn_dom.PropertyKind.Shadow) {
             this.attachShadow(staticValue);
         } else if (kind === fastn_dom.PropertyKind.TextShadow) {
@@ -3097,8 +3846,119 @@ class Node2 {
             this.updateTextInputValue();
         } else if (kind === fastn_dom.PropertyKind.InputMaxLength) {
             this.attachAttribute("maxlength", staticValue);
+        } else if (
+            kind === fastn_dom.PropertyKind.Placeholder &&
+            this.#kind === fastn_dom.ElementKind.Select
+        ) {
+            // `select` has no placeholder attribute, it is rendered as a
+            // hidden first option
+            this.getFormData().placeholder = staticValue;
+            this.updateOptions();
         } else if (kind === fastn_dom.PropertyKind.Placeholder) {
             this.attachAttribute("placeholder", staticValue);
+        } else if (
+            kind === fastn_dom.PropertyKind.Name &&
+            this.#kind === fastn_dom.ElementKind.Radio
+        ) {
+            // the name is shared by the radio inputs of the group
+            let form = this.getFormData();
+            if (!fastn_utils.isNull(staticValue)) form.name = staticValue;
+            this.updateOptions();
+        } else if (kind === fastn_dom.PropertyKind.Name) {
+            this.attachAttribute("name", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.FormAction) {
+            this.attachAttribute("action", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.FormMethod) {
+            this.attachAttribute("method", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.FormEncoding) {
+            // Without js the browser always posts the form urlencoded, the
+            // encoding is only used when the form is submitted with js
+            this.#extraData.formEncoding = staticValue;
+        } else if (kind === fastn_dom.PropertyKind.FormFields) {
+            // keeping the list itself so the latest fields are validated
+            this.#extraData.formFields = value;
+            if (ssr || this.#extraData.formSubmitListener) {
+                return;
+            }
+            this.#extraData.formSubmitListener = true;
+            this.#node.addEventListener("submit", (event) => {
+                event.preventDefault();
+                ftd.submit_ftd_form(this.#node, this.#extraData.formFields, {
+                    encoding: this.#extraData.formEncoding,
+                });
+            });
+        } else if (kind === fastn_dom.PropertyKind.TableColumns) {
+            this.getTableData().columns = (
+                fastn_utils.isNull(staticValue) ? [] : staticValue
+            ).map((column) => {
+                let record = fastn_utils.getStaticValue(column.item);
+                return {
+                    header: fastn_utils.getStaticValue(record.get("header")),
+                    field: fastn_utils.getStaticValue(record.get("field")),
+                    sortable:
+                        fastn_utils.getStaticValue(record.get("sortable")) !==
+                        false,
+                };
+            });
+            this.updateTable();
+        } else if (kind === fastn_dom.PropertyKind.TableData) {
+            this.getTableData().data = fastn_utils.isNull(staticValue)
+                ? []
+                : staticValue;
+            this.updateTableRows();
+        } else if (kind === fastn_dom.PropertyKind.Sortable) {
+            this.getTableData().sortable =
+                staticValue === true || staticValue === "true";
+            this.updateTable();
+        } else if (kind === fastn_dom.PropertyKind.PageSize) {
+            let table = this.getTableData();
+            table.pageSize = staticValue;
+            table.page = 0;
+            this.updateTableRows();
+        } else if (kind === fastn_dom.PropertyKind.StickyHeader) {
+            this.attachAttribute(
+                "data-sticky-header",
+                staticValue === true || staticValue === "true" ? "" : null,
+            );
+        } else if (kind === fastn_dom.PropertyKind.Options) {
+            this.getFormData().options = fastn_utils.isNull(staticValue)
+                ? []
+                : staticValue;
+            this.updateOptions();
+        } else if (kind === fastn_dom.PropertyKind.SelectedValue) {
+            this.getFormData().value = staticValue;
+            this.updateOptions();
+        } else if (kind === fastn_dom.PropertyKind.SelectedValues) {
+            this.getFormData().values = fastn_utils.isNull(staticValue)
+                ? []
+                : staticValue.map((obj) =>
+                      fastn_utils.getStaticValue(obj.item),
+                  );
+            this.updateOptions();
+        } else if (kind === fastn_dom.PropertyKind.Multiple) {
+            this.attachAttribute(
+                "multiple",
+                staticValue === true || staticValue === "true" ? "" : null,
+            );
+        } else if (kind === fastn_dom.PropertyKind.Rows) {
+            this.attachAttribute("rows", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Accept) {
+            this.attachAttribute("accept", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.AutoResize) {
+            let autoResize = staticValue === true || staticValue === "true";
+            this.#extraData.autoResize = autoResize;
+            if (ssr || !autoResize || this.#extraData.autoResizeListener) {
+                return;
+            }
+            this.#extraData.autoResizeListener = true;
+            let node = this.#node;
+            let resize = () => {
+                if (!this.#extraData.autoResize) return;
+                node.style.height = "auto";
+                node.style.height = `${node.scrollHeight}px`;
+            };
+            node.addEventListener("input", resize);
+            resize();
         } else if (kind === fastn_dom.PropertyKind.Multiline) {
             switch (staticValue) {
                 case "true":
@@ -3291,21 +4151,38 @@ class Node2 {
         } else if (kind === fastn_dom.PropertyKind.Role) {
             this.attachRoleCss(staticValue);
         } else if (kind === fastn_dom.PropertyKind.Code) {
-            if (!fastn_utils.isNull(staticValue)) {
-                let { modifiedText, highlightedLines } =
-                    fastn_utils.findAndRemoveHighlighter(staticValue);
-                if (highlightedLines.length !== 0) {
-                    this.attachAttribute("data-line", highlightedLines);
-                }
-                staticValue = modifiedText;
-            }
-            let codeNode = this.#children[0].getNode();
-            let codeText = fastn_utils.escapeHtmlInCode(staticValue);
-            codeNode.innerHTML = codeText;
             this.#extraData.code = this.#extraData.code
                 ? this.#extraData.code
                 : {};
-            fastn_utils.highlightCode(codeNode, this.#extraData.code);
+            this.#extraData.code.text = staticValue;
+            this.updateCode();
+        } else if (kind === fastn_dom.PropertyKind.CodeHighlight) {
+            this.#extraData.code = this.#extraData.code
+                ? this.#extraData.code
+                : {};
+            this.#extraData.code.highlight = staticValue;
+            this.updateCode();
+        } else if (kind === fastn_dom.PropertyKind.CodeDiff) {
+            this.#extraData.code = this.#extraData.code
+                ? this.#extraData.code
+                : {};
+            this.#extraData.code.diff = staticValue;
+            this.updateCode();
+        } else if (kind === fastn_dom.PropertyKind.CodeLineNumberStart) {
+            this.#extraData.code = this.#extraData.code
+                ? this.#extraData.code
+                : {};
+            this.#extraData.code.start = staticValue;
+            // `data-line-offset` keeps the highlighted lines in the numbers
+            // shown when the line numbers are not shown
+            this.attachAttribute("data-start", staticValue);
+            this.attachAttribute(
+                "data-line-offset",
+                fastn_utils.isNull(staticValue) ? null : staticValue - 1,
+            );
+            this.updateCode();
+        } else if (kind === fastn_dom.PropertyKind.CodeTitle) {
+            this.attachAttribute("data-title", staticValue);
         } else if (kind === fastn_dom.PropertyKind.CodeShowLineNumber) {
             if (staticValue) {
                 this.#node.classList.add("line-numbers");
@@ -3461,6 +4338,14 @@ class Node2 {
                 },
                 inherited,
             );
+        } else if (
+            value instanceof fastn.mutableListClass &&
+            (kind === fastn_dom.PropertyKind.Options ||
+                kind === fastn_dom.PropertyKind.TableColumns ||
+                kind === fastn_dom.PropertyKind.TableData)
+        ) {
+            // re-render the options or rows when the list changes
+            this.setDynamicProperty(kind, [value], () => value, inherited);
         } else if (value instanceof PropertyValueAsClosure) {
             this.setDynamicProperty(
                 kind,
@@ -3538,15 +4423,91 @@ class Node2 {
         } else if (event === fastn_dom.Event.Focus) {
             let onFocusEvents = this.mergeFnCalls(this.#node.onfocus, func);
             this.#node.onfocus = onFocusEvents;
+        } else if (ssr) {
+            // the rest of the events need the browser, they are added when
+            // the page is rendered again in the browser
+        } else if (!!event[0] && event[0] === fastn_dom.Event.Interval()[0]) {
+            this.addTimer(setInterval(func, event[1]), clearInterval);
+        } else if (!!event[0] && event[0] === fastn_dom.Event.Timeout()[0]) {
+            this.addTimer(setTimeout(func, event[1]), clearTimeout);
+        } else if (
+            event === fastn_dom.Event.Visible ||
+            event === fastn_dom.Event.Hidden
+        ) {
+            this.addVisibilityHandler(event === fastn_dom.Event.Visible, func);
+        } else if (!!event[0] && event[0] === fastn_dom.Event.Scroll()[0]) {
+            this.#node.addEventListener(
+                "scroll",
+                fastn_utils.throttle(func, event[1]),
+                { passive: true },
+            );
+        } else if (event === fastn_dom.Event.Submit) {
+            this.#node.addEventListener("submit", (e) => {
+                // a form with an `action` still posts itself
+                if (!e.target.getAttribute("action")) e.preventDefault();
+                func();
+            });
+        } else if (!!event[0] && event[0] === fastn_dom.Event.KeyDown()[0]) {
+            this.#node.addEventListener("keydown", (e) => {
+                if (fastn_utils.isKeyPressed(e, event[1])) func();
+            });
         }
     }
-    destroy() {
+    // `on-interval` and `on-timeout` timers are cleared with the element
+    addTimer(id, clear) {
+        if (fastn_utils.isNull(this.#extraData.timers)) {
+            this.#extraData.timers = [];
+        }
+        this.#extraData.timers.push([id, clear]);
+        fastn_dom.disposableNodes.add(this);
+    }
+    // `on-visible` runs every time the element comes into the viewport, and
+    // `on-hidden` every time it leaves the viewport after being visible
+    addVisibilityHandler(visible, func) {
+        if (typeof IntersectionObserver === "undefined") {
+            return;
+        }
+        let visibility = this.#extraData.visibility;
+        if (fastn_utils.isNull(visibility)) {
+            visibility = { visible: [], hidden: [], isVisible: false };
+            visibility.observer = new IntersectionObserver((entries) => {
+                for (const entry of entries) {
+                    if (entry.isIntersecting === visibility.isVisible) {
+                        continue;
+                    }
+                    visibility.isVisible = entry.isIntersecting;
+                    (entry.isIntersecting
+                        ? visibility.visible
+                        : visibility.hidden
+                    ).forEach((f) => f());
+                }
+            });
+            visibility.observer.observe(this.#node);
+            this.#extraData.visibility = visibility;
+            fastn_dom.disposableNodes.add(this);
+        }
+        (visible ? visibility.visible : visibility.hidden).push(func);
+    }
+    destroy() {
         for (let i = 0; i < this.#mutables.length; i++) {
             this.#mutables[i].unlinkNode(this);
         }
         // Todo: We don't need this condition as after destroying this node
         //  ConditionalDom reset this.#conditionUI to null or some different
         //  value. Not sure why this is still needed.
+        if (!fastn_utils.isNull(this.#extraData.tableObserver)) {
+            this.#extraData.tableObserver.disconnect();
+        }
+        if (!fastn_utils.isNull(this.#extraData.tableCell)) {
+            this.#extraData.tableCell.remove();
+        }
+        if (!fastn_utils.isNull(this.#extraData.timers)) {
+            this.#extraData.timers.forEach(([id, clear]) => clear(id));
+        }
+        if (!fastn_utils.isNull(this.#extraData.visibility)) {
+            this.#extraData.visibility.observer.disconnect();
+        }
+        fastn_dom.disposableNodes.delete(this);
         if (!fastn_utils.isNull(this.#node)) {
             this.#node.remove();
         }
@@ -3657,7 +4618,10 @@ class ForLoop {
     #wrapper;
     #parent;
     #nodes;
-    constructor(parent, node_constructor, list) {
+    #items;
+    #key;
+    #end;
+    constructor(parent, node_constructor, list, key) {
         this.#wrapper = fastn_dom.createKernel(
             parent,
             fastn_dom.ElementKind.Comment,
@@ -3666,12 +4630,28 @@ class ForLoop {
         this.#node_constructor = node_constructor;
         this.#list = list;
         this.#nodes = [];
+        this.#items = [];
+        this.#key = key;
 
         fastn_utils.resetFullHeight();
         for (let idx in list.getList()) {
-            this.createNode(idx, false);
+            this.createNode(parseInt(idx), false);
         }
         fastn_utils.setFullHeight();
+
+        // Keyed loops move the nodes of an item around, the nodes of the last
+        // item are the ones before this marker
+        if (!fastn_utils.isNull(key)) {
+            this.#end = fastn_dom.createKernel(
+                new ParentNodeWithSibiling(
+                    parent,
+                    this.#nodes.length > 0
+                        ? this.#nodes[this.#nodes.length - 1]
+                        : this.#wrapper,
+                ),
+                fastn_dom.ElementKind.Comment,
+            );
+        }
     }
     createNode(index, resizeBodyHeight = true) {
         if (resizeBodyHeight) {
@@ -3690,19 +4670,102 @@ class ForLoop {
         let v = this.#list.get(index);
         let node = this.#node_constructor(parentWithSibiling, v.item, v.index);
         this.#nodes.splice(index, 0, node);
+        this.#items.splice(index, 0, v);
         if (resizeBodyHeight) {
             fastn_utils.setFullHeight();
         }
         return node;
     }
     createAllNode() {
+        if (!fastn_utils.isNull(this.#key)) {
+            this.updateAllNode();
+            return;
+        }
         fastn_utils.resetFullHeight();
         this.deleteAllNode(false);
         for (let idx in this.#list.getList()) {
-            this.createNode(idx, false);
+            this.createNode(parseInt(idx), false);
         }
         fastn_utils.setFullHeight();
     }
+    // Keyed loops: `MutableList.set` keeps the items whose key is still in
+    // the list, the nodes of those items are kept, and moved only if they are
+    // not part of the longest run that is already in order. Nodes are created
+    // for new items and removed for the rest.
+    updateAllNode() {
+        fastn_utils.resetFullHeight();
+        let items = this.#list.getList();
+        let current = new Map(this.#items.map((item, idx) => [item, idx]));
+        let oldIndex = items.map((item) =>
+            current.has(item) ? current.get(item) : -1,
+        );
+        let ranges = this.domRanges();
+
+        let kept = new Set(oldIndex);
+        this.#nodes.forEach((node, idx) => {
+            if (!kept.has(idx)) {
+                fastn_utils.flattenArray(node).forEach((n) => n.destroy());
+                ranges[idx].forEach((n) => n.remove());
+            }
+        });
+
+        let stable = fastn_utils.private.longestIncreasingSubsequence(oldIndex);
+        let nodes = [];
+        let anchor = this.#wrapper.getNode();
+        items.forEach((item, idx) => {
+            let range;
+            if (oldIndex[idx] === -1) {
+                let next = anchor.nextSibling;
+                let sibiling = { getNode: () => anchor };
+                nodes.push(
+                    this.#node_constructor(
+                        new ParentNodeWithSibiling(this.#parent, sibiling),
+                        item.item,
+                        item.index,
+                    ),
+                );
+                range = [];
+                let n = anchor.nextSibling;
+                while (n !== next) {
+                    range.push(n);
+                    n = n.nextSibling;
+                }
+            } else {
+                nodes.push(this.#nodes[oldIndex[idx]]);
+                range = ranges[oldIndex[idx]];
+                if (!stable.has(idx)) {
+                    let next = anchor.nextSibling;
+                    let parent = anchor.parentNode;
+                    range.forEach((n) => parent.insertBefore(n, next));
+                }
+            }
+            if (range.length > 0) {
+                anchor = range[range.length - 1];
+            }
+        });
+        this.#nodes = nodes;
+        this.#items = [...items];
+        fastn_utils.setFullHeight();
+    }
+    // The dom nodes of each item: from the first node of the item up to the
+    // first node of the next one. The first node of an item stays the same
+    // even if the item is a conditional dom whose content changes.
+    domRanges() {
+        let starts = this.#nodes.map((node) => {
+            let first = fastn_utils.flattenArray(node)[0];
+            return first.getExtraData().tableCell ?? first.getNode();
+        });
+        starts.push(this.#end.getNode());
+        return this.#nodes.map((_, idx) => {
+            let range = [];
+            let n = starts[idx];
+            while (!fastn_utils.isNull(n) && n !== starts[idx + 1]) {
+                range.push(n);
+                n = n.nextSibling;
+            }
+            return range;
+        });
+    }
     deleteAllNode(resizeBodyHeight = true) {
         if (resizeBodyHeight) {
             fastn_utils.resetFullHeight();
@@ -3710,6 +4773,7 @@ class ForLoop {
         while (this.#nodes.length > 0) {
             this.#nodes.pop().destroy();
         }
+        this.#items = [];
         if (resizeBodyHeight) {
             fastn_utils.setFullHeight();
         }
@@ -3717,9 +4781,13 @@ class ForLoop {
     getWrapper() {
         return this.#wrapper;
     }
+    getKey() {
+        return this.#key;
+    }
     deleteNode(index) {
         fastn_utils.resetFullHeight();
         let node = this.#nodes.splice(index, 1)[0];
+        this.#items.splice(index, 1);
         node.destroy();
         fastn_utils.setFullHeight();
     }
@@ -3728,8 +4796,8 @@ class ForLoop {
     }
 }
 
-fastn_dom.forLoop = function (parent, node_constructor, list) {
-    return new ForLoop(parent, node_constructor, list);
+fastn_dom.forLoop = function (parent, node_constructor, list, key) {
+    return new ForLoop(parent, node_constructor, list, key);
 };
 let fastn_utils = {
     htmlNode(kind) {
@@ -3766,6 +4834,39 @@ let fastn_utils = {
             attributes["type"] = "checkbox";
         } else if (kind === fastn_dom.ElementKind.TextInput) {
             node = "input";
+        } else if (kind === fastn_dom.ElementKind.Form) {
+            node = "form";
+            css.push(fastn_dom.InternalClass.FT_COLUMN);
+            // the fallback without js posts the form to `action`
+            attributes["method"] = "post";
+        } else if (kind === fastn_dom.ElementKind.Select) {
+            node = "select";
+        } else if (kind === fastn_dom.ElementKind.Radio) {
+            node = "fieldset";
+            css.push(fastn_dom.InternalClass.FT_RADIO);
+            attributes["role"] = "radiogroup";
+        } else if (kind === fastn_dom.ElementKind.Textarea) {
+            node = "textarea";
+        } else if (kind === fastn_dom.ElementKind.FileInput) {
+            node = "input";
+            attributes["type"] = "file";
+        } else if (kind === fastn_dom.ElementKind.Grid) {
+            css.push(fastn_dom.InternalClass.FT_GRID);
+        } else if (kind === fastn_dom.ElementKind.Svg) {
+            css.push(fastn_dom.InternalClass.FT_SVG);
+        } else if (kind === fastn_dom.ElementKind.Icon) {
+            node = "span";
+            css.push(fastn_dom.InternalClass.FT_SVG);
+            css.push(fastn_dom.InternalClass.FT_ICON);
+            attributes["aria-hidden"] = "true";
+        } else if (kind === fastn_dom.ElementKind.Math) {
+            css.push(fastn_dom.InternalClass.FT_MATH);
+        } else if (kind === fastn_dom.ElementKind.Table) {
+            node = "table";
+            css.push(fastn_dom.InternalClass.FT_TABLE);
+        } else if (kind === fastn_dom.ElementKind.TableRow) {
+            node = "tr";
+            css.push(fastn_dom.InternalClass.FT_TABLE_ROW);
         } else if (kind === fastn_dom.ElementKind.Comment) {
             node = fastn_dom.commentNode;
         } else if (kind === fastn_dom.ElementKind.Wrapper) {
@@ -3804,6 +4905,8 @@ let fastn_utils = {
             return this.getStaticValue(obj.get());
         } else if (obj instanceof fastn.mutableListClass) {
             return obj.getList();
+        } else if (obj instanceof fastn.mutableMapClass) {
+            return obj.getMap();
         } /*
         Todo: Make this work
         else if (obj instanceof fastn.recordInstanceClass) {
@@ -3863,6 +4966,7 @@ let fastn_utils = {
         if (
             !(obj instanceof fastn.mutableClass) &&
             !(obj instanceof fastn.mutableListClass) &&
+            !(obj instanceof fastn.mutableMapClass) &&
             !(obj instanceof fastn.recordInstanceClass)
         ) {
             if (Array.isArray(obj)) {
@@ -3893,14 +4997,22 @@ let fastn_utils = {
         } else if (obj instanceof fastn.mutableListClass) {
             let list = obj.getList();
             return list.map((func) => this.mutableToStaticValue(func.item));
-        } else if (obj instanceof fastn.recordInstanceClass) {
-            let fields = obj.getAllFields();
+        } else if (
+            obj instanceof fastn.recordInstanceClass ||
+            obj instanceof fastn.mutableMapClass
+        ) {
+            let fields =
+                obj instanceof fastn.mutableMapClass
+                    ? obj.getMap()
+                    : obj.getAllFields();
             return Object.fromEntries(
                 Object.entries(fields).map(([k, v]) => [
                     k,
                     this.mutableToStaticValue(v),
                 ]),
             );
+        } else if (obj instanceof fastn.orTypeValueClass) {
+            return this.mutableToStaticValue(obj.getValue());
         } else {
             return obj;
         }
@@ -3941,7 +5053,8 @@ let fastn_utils = {
     getterByKey(value, index) {
         if (
             value instanceof fastn.mutableClass ||
-            value instanceof fastn.recordInstanceClass
+            value instanceof fastn.recordInstanceClass ||
+            value instanceof fastn.mutableMapClass
         ) {
             return value.get(index);
         } else if (value instanceof fastn.mutableListClass) {
@@ -3994,7 +5107,8 @@ let fastn_utils = {
         }
         if (
             value instanceof fastn.mutableClass ||
-            value instanceof fastn.mutableListClass
+            value instanceof fastn.mutableListClass ||
+            value instanceof fastn.mutableMapClass
         ) {
             return value.getClone();
         }
@@ -4012,6 +5126,120 @@ let fastn_utils = {
         }
         return value;
     },
+    // `keys` of `on-keydown[ctrl-s]`, the modifiers not in the keys must not
+    // be pressed
+    isKeyPressed(event, keys) {
+        const modifiers = {
+            Control: event.ctrlKey,
+            Alt: event.altKey,
+            Shift: event.shiftKey,
+            Meta: event.metaKey,
+        };
+        const eventKey = fastn_utils.getEventKey(event).toLowerCase();
+        return (
+            Object.entries(modifiers).every(
+                ([key, pressed]) => keys.includes(key) === pressed,
+            ) &&
+            keys
+                .filter((key) => !(key in modifiers))
+                .every((key) => key.toLowerCase() === eventKey)
+        );
+    },
+    // Calls `func` at most once in `ms` milliseconds, the last call in the
+    // window is delayed instead of dropped
+    throttle(func, ms) {
+        let last = 0;
+        let timer = null;
+        return () => {
+            const remaining = last + ms - Date.now();
+            if (remaining <= 0) {
+                clearTimeout(timer);
+                timer = null;
+                last = Date.now();
+                func();
+            } else if (timer === null) {
+                timer = setTimeout(() => {
+                    timer = null;
+                    last = Date.now();
+                    func();
+                }, remaining);
+            }
+        };
+    },
+    // `$sse$` variables: the json in the data of a `message` event is the new
+    // value of the variable, the data of a `patch` event is a json patch
+    // (RFC 6902) applied to the current value
+    subscribe(url, variable) {
+        if (ssr || typeof EventSource === "undefined") {
+            return;
+        }
+        const source = new EventSource(url);
+        const update = (func) => (event) => {
+            let data;
+            try {
+                data = JSON.parse(event.data);
+            } catch (e) {
+                console.log(`[fastn-sse]: invalid json from ${url}`, e);
+                return;
+            }
+            fastn_utils.setStaticValue(variable, func(data));
+        };
+        source.onmessage = update((value) => value);
+        source.addEventListener(
+            "patch",
+            update((patch) =>
+                fastn_utils.applyJsonPatch(
+                    fastn_utils.mutableToStaticValue(variable),
+                    patch,
+                ),
+            ),
+        );
+        fastn_dom.eventSources.add(source);
+    },
+    // Sets a mutable variable to a plain js value, json keys like
+    // `user-name` are the `user_name` field of a record
+    setStaticValue(variable, value) {
+        let mutableValue = fastn_utils.staticToMutables(
+            fastn_utils.private.toFieldNames(value),
+        );
+        if (mutableValue instanceof fastn.mutableClass) {
+            mutableValue = mutableValue.get();
+        }
+        variable.set(mutableValue);
+    },
+    // Supports the `add`, `remove` and `replace` operations, `-` as the last
+    // key of the path is the end of a list
+    applyJsonPatch(value, patch) {
+        for (const operation of patch) {
+            const keys = operation.path
+                .split("/")
+                .slice(1)
+                .map((key) => key.replace(/~1/g, "/").replace(/~0/g, "~"))
+                .map((key) => (key === "-" ? key : key.replace(/-/g, "_")));
+            const opValue = fastn_utils.private.toFieldNames(operation.value);
+            if (keys.length === 0) {
+                value = operation.op === "remove" ? null : opValue;
+                continue;
+            }
+            const last = keys.pop();
+            const parent = keys.reduce((current, key) => current[key], value);
+            if (Array.isArray(parent)) {
+                const index = last === "-" ? parent.length : Number(last);
+                if (operation.op === "add") {
+                    parent.splice(index, 0, opValue);
+                } else if (operation.op === "remove") {
+                    parent.splice(index, 1);
+                } else if (operation.op === "replace") {
+                    parent[index] = opValue;
+                }
+            } else if (operation.op === "remove") {
+                parent[last] = null;
+            } else if (operation.op === "add" || operation.op === "replace") {
+                parent[last] = opValue;
+            }
+        }
+        return value;
+    },
     getEventKey(event) {
         if (65 <= event.keyCode && event.keyCode <= 90) {
             return String.fromCharCode(event.keyCode).toLowerCase();
@@ -4061,7 +5289,7 @@ let fastn_utils = {
         const { space_before, space_after } = fastn_utils.private.spaces(i);
         const o = (() => {
             let g = fastn_utils.private.replace_last_occurrence(
-                marked.parse(i),
+                fastn_utils.private.markdownParser().parse(i),
                 "<p>",
                 "",
             );
@@ -4218,8 +5446,88 @@ let fastn_utils = {
 
         return result;
     },
+    /**
+     * Prepares the text of `ftd.code` for Prism: removes the `;; <hl>`
+     * highlighters, the callouts (`// <1>` at the end of a line) and, with
+     * `diff`, the `+` / `-` at the start of every line.
+     *
+     * @param {string} text - The text of `ftd.code`.
+     * @param {object} code - The `highlight`, `diff` and `start` of the code.
+     * @returns {{ modifiedText: string, highlightedLines: string,
+     *   signs: string[], callouts: string[] }} The highlighted lines are
+     *   numbered from `start`, signs and callouts are indexed by line.
+     */
+    prepareCode(text, code) {
+        const lines = text.split("\n");
+        const highlighter = ";; <hl>";
+        const start = fastn_utils.isNull(code.start) ? 1 : code.start;
+        const result = {
+            modifiedText: "",
+            highlightedLines: "",
+            signs: [],
+            callouts: [],
+        };
+
+        let highlightedLines = fastn_utils.private.lineNumbersOf(
+            code.highlight,
+        );
+        for (let i = 0; i < lines.length; i++) {
+            let line = lines[i];
+            const highlighterIndex = line.indexOf(highlighter);
+            if (highlighterIndex !== -1) {
+                highlightedLines.push(i + start);
+                line =
+                    line.substring(0, highlighterIndex) +
+                    line.substring(highlighterIndex + highlighter.length);
+            }
+            const callout = line.match(fastn_utils.private.codeCallout);
+            if (callout) {
+                result.callouts[i] = callout[1];
+                line = line.substring(0, callout.index);
+            }
+            // the empty line after the last new line is not shown
+            if (code.diff && (line !== "" || i !== lines.length - 1)) {
+                const sign = line[0];
+                result.signs[i] = sign === "+" || sign === "-" ? sign : " ";
+                if (sign === "+" || sign === "-" || sign === " ") {
+                    line = line.substring(1);
+                }
+            }
+            result.modifiedText += line + "\n";
+        }
+
+        highlightedLines = [...new Set(highlightedLines)].sort((a, b) => a - b);
+        result.highlightedLines =
+            fastn_utils.private.mergeNumbers(highlightedLines);
+
+        return result;
+    },
     getNodeValue(node) {
-        return node.getNode().value;
+        let element = node.getNode();
+        if (element.tagName.toLowerCase() === "fieldset") {
+            // `ftd.radio`: value of the checked option
+            let checked = element.querySelector("input:checked");
+            return fastn_utils.isNull(checked) ? null : checked.value;
+        }
+        if (element.type === "file") {
+            return element.files.length > 0 ? element.files[0].name : null;
+        }
+        return element.value;
+    },
+    // All selected values of a multiple `ftd.select`, or the names of the
+    // files picked in `ftd.file-input`.
+    getNodeValues(node) {
+        let element = node.getNode();
+        if (element.type === "file") {
+            return Array.from(element.files).map((file) => file.name);
+        }
+        if (!fastn_utils.isNull(element.selectedOptions)) {
+            return Array.from(element.selectedOptions).map(
+                (option) => option.value,
+            );
+        }
+        let value = fastn_utils.getNodeValue(node);
+        return fastn_utils.isNull(value) ? [] : [value];
     },
     getNodeCheckedState(node) {
         return node.getNode().checked;
@@ -4242,6 +5550,9 @@ let fastn_utils = {
         ) {
             Prism.highlightElement(codeElement);
         }
+        if (!ssr) {
+            fastn_utils.private.decorateCodeLines(codeElement, extraCodeData);
+        }
     },
 
     //Taken from: https://byby.dev/js-slugify-string
@@ -4271,6 +5582,27 @@ let fastn_utils = {
     flattenArray(arr) {
         return fastn_utils.private.flattenArray([arr]);
     },
+    // Compares static values, e.g. the ones returned by
+    // `mutableToStaticValue`
+    deepEqual(a, b) {
+        if (a === b) {
+            return true;
+        }
+        if (
+            typeof a !== "object" ||
+            typeof b !== "object" ||
+            a === null ||
+            b === null ||
+            Array.isArray(a) !== Array.isArray(b)
+        ) {
+            return false;
+        }
+        let keys = Object.keys(a);
+        if (keys.length !== Object.keys(b).length) {
+            return false;
+        }
+        return keys.every((key) => fastn_utils.deepEqual(a[key], b[key]));
+    },
     toSnakeCase(value) {
         return value
             .trim()
@@ -4287,10 +5619,85 @@ let fastn_utils = {
             })
             .join("");
     },
+    // Escapes a value rendered as html text or as a quoted attribute value
+    escapeHtml(value) {
+        return `${value}`
+            .replace(/&/g, "&amp;")
+            .replace(/"/g, "&quot;")
+            .replace(/</g, "&lt;");
+    },
+    // Inline svg markup of `ftd.svg` and `ftd.icon` reduced to the elements
+    // and attributes that draw, see `fastn_js::svg`. On the server the rust
+    // code does it, in the browser the svg is parsed as xml and the same
+    // lists are applied. Svg that is not well-formed is dropped.
+    sanitizeSvg(svg) {
+        if (fastn_utils.isNull(svg)) {
+            return "";
+        }
+        if (typeof __fastn_sanitize_svg__ === "function") {
+            return __fastn_sanitize_svg__(`${svg}`);
+        }
+        let parsed = new DOMParser().parseFromString(
+            `${svg}`,
+            "image/svg+xml",
+        );
+        let root = parsed.documentElement;
+        if (
+            root.nodeName !== "svg" ||
+            parsed.getElementsByTagName("parsererror").length > 0
+        ) {
+            return "";
+        }
+        fastn_utils.private.sanitizeSvgAttributes(root);
+        fastn_utils.private.sanitizeSvgChildren(root);
+        return new XMLSerializer().serializeToString(root);
+    },
+    // The value of the `field` of an `ftd.table` column for a row of `data`.
+    // `field` is the name of a field of the record, nested records are
+    // reached with `.`, like `address.city`.
+    getTableCellValue(row, column) {
+        if (fastn_utils.isNull(column.field)) {
+            return null;
+        }
+        let value = row;
+        for (let name of `${column.field}`.split(".")) {
+            value = fastn_utils.getStaticValue(value);
+            if (!(value instanceof fastn.recordInstanceClass)) {
+                return null;
+            }
+            value = value.get(name.trim().replace(/-/g, "_"));
+        }
+        return fastn_utils.getStaticValue(value);
+    },
+    // Numbers are compared as numbers and everything else as text. Empty
+    // cells go last.
+    compareTableValues(a, b) {
+        let isEmpty = (v) => fastn_utils.isNull(v) || `${v}`.trim() === "";
+        if (isEmpty(a) || isEmpty(b)) {
+            return isEmpty(a) - isEmpty(b);
+        }
+        if (!isNaN(Number(a)) && !isNaN(Number(b))) {
+            return Number(a) - Number(b);
+        }
+        return `${a}`.localeCompare(`${b}`, undefined, { numeric: true });
+    },
     escapeHtmlInCode(str) {
         return str.replace(/[<]/g, "&lt;");
     },
 
+    /**
+     * Lets text show markup fastn rendered at compile time, like the MathML
+     * of math, as is. The document calls it before it is shown, markup in
+     * text which is not exactly one of these is escaped.
+     *
+     * @param {string[]} markup - The markup fastn rendered.
+     */
+    trustMarkup(markup) {
+        for (const m of markup) {
+            fastn_utils.private.trustedMarkup.add(m);
+        }
+    },
+
     escapeHtmlInMarkdown(str) {
         if (typeof str !== "string") {
             return str;
@@ -4311,6 +5718,16 @@ let fastn_utils = {
             if (current === "`") {
                 foundBackTick = !foundBackTick;
             }
+            // The markup fastn rendered, like the MathML of `$...$` math, is
+            // kept as is
+            let markup = foundBackTick
+                ? null
+                : fastn_utils.private.trustedMarkupAt(str, i);
+            if (markup !== null) {
+                result += markup;
+                i += markup.length - 1;
+                continue;
+            }
             // Ignore escaping html inside backtick (as marked function
             // escape html for backtick content):
             // For instance: In `hello <title>`, `<` and `>` should not be
@@ -4369,6 +5786,177 @@ let fastn_utils = {
 };
 
 fastn_utils.private = {
+    // Removes the children of an svg element that are not in
+    // `fastn_svg_allowlist`, and the attributes of the ones that are kept
+    sanitizeSvgChildren(element) {
+        for (let child of Array.from(element.childNodes)) {
+            if (
+                child.nodeType === Node.TEXT_NODE ||
+                child.nodeType === Node.CDATA_SECTION_NODE
+            ) {
+                child.replaceWith(document.createTextNode(child.data));
+            } else if (
+                child.nodeType !== Node.ELEMENT_NODE ||
+                !fastn_svg_allowlist.elements.includes(child.nodeName) ||
+                !fastn_utils.private.sanitizeSvgAttributes(child)
+            ) {
+                child.remove();
+            } else {
+                fastn_utils.private.sanitizeSvgChildren(child);
+            }
+        }
+    },
+
+    // Removes the attributes of an svg element that are not allowed, returns
+    // `false` if the element itself has to go, see `fastn_js::svg`
+    sanitizeSvgAttributes(element) {
+        let clean = (v) =>
+            v.replace(/[\s\x00-\x1f\x7f-\x9f]/g, "").toLowerCase();
+        if (fastn_svg_allowlist.animations.includes(element.nodeName)) {
+            let target = (element.getAttribute("attributeName") ?? "")
+                .trim()
+                .toLowerCase();
+            if (
+                target.endsWith("href") ||
+                target.startsWith("on") ||
+                target === "style"
+            ) {
+                return false;
+            }
+        }
+        for (let attribute of Array.from(element.attributes)) {
+            let name = attribute.name;
+            let value = clean(attribute.value);
+            let allowed = fastn_svg_allowlist.attributes.includes(name);
+            if (allowed && (name === "href" || name === "xlink:href")) {
+                allowed =
+                    value.startsWith("#") ||
+                    (element.nodeName === "image" &&
+                        /^data:image\/(png|jpeg|jpg|gif|webp);/.test(value));
+            } else if (allowed) {
+                allowed =
+                    !value.includes("javascript:") &&
+                    value
+                        .split("url(")
+                        .slice(1)
+                        .every((u) => u.replace(/^["']+/, "").startsWith("#")) &&
+                    (name !== "style" ||
+                        !["expression(", "@import", "\\", "<"].some((s) =>
+                            value.includes(s),
+                        ));
+            }
+            if (!allowed) {
+                element.removeAttribute(name);
+            }
+        }
+        return true;
+    },
+
+    // A callout at the end of a line of code: `// <1>`, `# <2>`, `-- <3>`
+    codeCallout: /\s*(?:\/\/|#|;;|--)\s*<(\d+)>\s*$/,
+
+    // The markup fastn rendered, see `fastn_utils.trustMarkup`
+    trustedMarkup: new Set(),
+
+    // The trusted markup starting at `index` of `str`, or `null`
+    trustedMarkupAt(str, index) {
+        if (str[index] !== "<") {
+            return null;
+        }
+        for (const markup of fastn_utils.private.trustedMarkup) {
+            if (str.startsWith(markup, index)) {
+                return markup;
+            }
+        }
+        return null;
+    },
+
+    // The lines in `3-5,9` of the `highlight` of `ftd.code`: `[3, 4, 5, 9]`
+    lineNumbersOf(ranges) {
+        if (fastn_utils.isNull(ranges)) {
+            return [];
+        }
+        return String(ranges)
+            .split(",")
+            .flatMap((range) => {
+                let [from, to] = range.split("-").map((n) => parseInt(n));
+                if (isNaN(from)) {
+                    return [];
+                }
+                to = isNaN(to) ? from : to;
+                return Array.from(
+                    { length: Math.max(to - from + 1, 0) },
+                    (_, i) => from + i,
+                );
+            });
+    },
+
+    // Splits the html of highlighted code into lines, the `span`s open at
+    // the end of a line are closed, and opened again on the next line.
+    splitHighlightedLines(html) {
+        let open = [];
+        return html.split("\n").map((line) => {
+            const prefix = open.join("");
+            for (const tag of line.matchAll(/<span[^>]*>|<\/span>/g)) {
+                if (tag[0] === "</span>") {
+                    open.pop();
+                } else {
+                    open.push(tag[0]);
+                }
+            }
+            return prefix + line + "</span>".repeat(open.length);
+        });
+    },
+
+    // Wraps the lines of `ftd.code` with a diff sign or a callout. The sign
+    // and the callout number are shown by css, so copying the code does not
+    // copy them.
+    decorateCodeLines(codeElement, code) {
+        const signs = code.signs ?? [];
+        const callouts = code.callouts ?? [];
+        if (signs.length === 0 && callouts.length === 0) {
+            return;
+        }
+        const diffClasses = {
+            "+": "ft_diff_added",
+            "-": "ft_diff_removed",
+            " ": "ft_diff_context",
+        };
+        // the line numbers and highlighted lines added by Prism
+        const extras = Array.from(
+            codeElement.querySelectorAll(
+                ":scope > .line-numbers-rows, :scope > .line-highlight",
+            ),
+        );
+        extras.forEach((extra) => extra.remove());
+        const lines = fastn_utils.private.splitHighlightedLines(
+            codeElement.innerHTML,
+        );
+        codeElement.innerHTML = lines
+            .map((line, i) => {
+                const sign = signs[i];
+                const callout = callouts[i];
+                if (sign === undefined && callout === undefined) {
+                    return line;
+                }
+                const classes = ["ft_code_line"];
+                let attributes = "";
+                if (sign !== undefined) {
+                    classes.push(diffClasses[sign]);
+                    attributes = ` data-sign="${sign}"`;
+                }
+                if (callout !== undefined) {
+                    line +=
+                        '<span class="ft_code_callout" ' +
+                        `data-callout="${callout}"></span>`;
+                }
+                const attribute = `class="${classes.join(" ")}"${attributes}`;
+                return `<span ${attribute}>${line}</span>`;
+            })
+            .join("\n");
+        extras.forEach((extra) => codeElement.appendChild(extra));
+    },
+
     flattenArray(arr) {
         return arr.reduce((acc, item) => {
             return acc.concat(
@@ -4378,6 +5966,280 @@ fastn_utils.private = {
             );
         }, []);
     },
+    /**
+     * Positions of a longest increasing subsequence of `arr`, ignoring the
+     * `-1` entries. Used by keyed loops to find the nodes that can stay where
+     * they are.
+     *
+     * @param {number[]} arr
+     * @returns {Set<number>}
+     */
+    longestIncreasingSubsequence(arr) {
+        // tails[k]: position of the smallest last value of a subsequence of
+        // length k + 1
+        let tails = [];
+        let previous = new Array(arr.length).fill(-1);
+        for (let i = 0; i < arr.length; i++) {
+            if (arr[i] === -1) {
+                continue;
+            }
+            let lo = 0;
+            let hi = tails.length;
+            while (lo < hi) {
+                let mid = (lo + hi) >> 1;
+                if (arr[tails[mid]] < arr[i]) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            if (lo > 0) {
+                previous[i] = tails[lo - 1];
+            }
+            tails[lo] = i;
+        }
+        let result = new Set();
+        let i = tails.length > 0 ? tails[tails.length - 1] : -1;
+        while (i !== -1) {
+            result.add(i);
+            i = previous[i];
+        }
+        return result;
+    },
+    /**
+     * Helper function for `fastn_utils.markdown_inline`, the `marked`
+     * instance with the extensions of `-- fastn.markdown:` in `FASTN.ftd`
+     * (`__fastn_markdown__`) turned on. The html is the one comrak renders
+     * for the same extensions.
+     *
+     * @returns {marked.Marked}
+     */
+    markdownParser() {
+        if (fastn_utils.private.markdown_parser) {
+            return fastn_utils.private.markdown_parser;
+        }
+        let options =
+            typeof __fastn_markdown__ === "undefined" ? {} : __fastn_markdown__;
+        let parser = new marked.Marked();
+        if (options.tables === false) {
+            parser.use({ tokenizer: { table: () => undefined } });
+        }
+        if (options["task-lists"] === false) {
+            parser.use({
+                renderer: {
+                    checkbox: (checked) => (checked ? "[x]" : "[ ]"),
+                },
+            });
+        }
+        if (options["heading-ids"]) {
+            parser.use(fastn_utils.private.markdownHeadingIds());
+        }
+        if (options.footnotes) {
+            parser.use(fastn_utils.private.markdownFootnotes());
+        }
+        if (options.admonitions) {
+            parser.use(fastn_utils.private.markdownAdmonitions());
+        }
+        fastn_utils.private.markdown_parser = parser;
+        return parser;
+    },
+    /**
+     * The id of a heading, the way comrak's `Anchorizer` does it: lowercase,
+     * without punctuation, spaces replaced by `-` and a `-<n>` suffix when
+     * the id is already in `seen`.
+     *
+     * @param {string} title - The text of the heading.
+     * @param {Set<string>} seen - The ids given so far.
+     * @returns {string}
+     */
+    anchorize(title, seen) {
+        let id = title
+            .toLowerCase()
+            .replace(/[^\p{L}\p{M}\p{N}\p{Pc} -]/gu, "")
+            .replace(/ /g, "-");
+        let anchor = id;
+        for (let i = 1; seen.has(anchor); i++) {
+            anchor = `${id}-${i}`;
+        }
+        seen.add(anchor);
+        return anchor;
+    },
+    markdownHeadingIds() {
+        let seen = new Set();
+        return {
+            hooks: {
+                preprocess(src) {
+                    seen.clear();
+                    return src;
+                },
+            },
+            renderer: {
+                heading(text, level, raw) {
+                    let id = fastn_utils.private.anchorize(raw, seen);
+                    return (
+                        `<h${level}><a href="#${id}" aria-hidden="true" ` +
+                        `class="anchor" id="${id}"></a>${text}</h${level}>\n`
+                    );
+                },
+            },
+        };
+    },
+    /*
+        `[^label]` references are numbered in the order they first appear,
+        the `[^label]: text` definitions are listed, in that order, in a
+        section at the end. Definitions nobody refers to are dropped.
+     */
+    markdownFootnotes() {
+        // a definition goes on in the indented lines after it
+        let definition =
+            /^\[\^([^\]\s]+)\]:[ \t]*([^\n]*(?:\n[ \t]+[^\n]*)*)(?:\n|$)/;
+        let numbers = new Map();
+        let definitions = new Map();
+        return {
+            hooks: {
+                preprocess(src) {
+                    numbers.clear();
+                    definitions.clear();
+                    return src;
+                },
+                postprocess(html) {
+                    if (numbers.size === 0) {
+                        return html;
+                    }
+                    let items = [...numbers.keys()].map((label) => {
+                        let n = numbers.get(label);
+                        let backref =
+                            `<a href="#fnref-${label}" ` +
+                            `class="footnote-backref" ` +
+                            `aria-label="Back to reference ${n}">↩</a>`;
+                        let text = definitions.get(label) ?? "";
+                        return (
+                            `<li id="fn-${label}">\n` +
+                            `<p>${text} ${backref}</p>\n</li>\n`
+                        );
+                    });
+                    return (
+                        `${html}<section class="footnotes" data-footnotes>\n` +
+                        `<ol>\n${items.join("")}</ol>\n</section>\n`
+                    );
+                },
+            },
+            extensions: [
+                {
+                    name: "footnote",
+                    level: "block",
+                    start(src) {
+                        return src.match(/^\[\^[^\]\s]+\]:/m)?.index;
+                    },
+                    tokenizer(src) {
+                        let match = definition.exec(src);
+                        if (match) {
+                            return {
+                                type: "footnote",
+                                raw: match[0],
+                                label: match[1],
+                                tokens: this.lexer.inlineTokens(
+                                    match[2].replace(/\n[ \t]+/g, "\n").trim(),
+                                ),
+                            };
+                        }
+                    },
+                    renderer(token) {
+                        definitions.set(
+                            token.label,
+                            this.parser.parseInline(token.tokens),
+                        );
+                        return "";
+                    },
+                },
+                {
+                    name: "footnoteRef",
+                    level: "inline",
+                    start(src) {
+                        return src.match(/\[\^/)?.index;
+                    },
+                    tokenizer(src) {
+                        let match = /^\[\^([^\]\s]+)\](?!:)/.exec(src);
+                        if (match) {
+                            return {
+                                type: "footnoteRef",
+                                raw: match[0],
+                                label: match[1],
+                            };
+                        }
+                    },
+                    renderer(token) {
+                        let label = token.label;
+                        if (!numbers.has(label)) {
+                            numbers.set(label, numbers.size + 1);
+                        }
+                        let n = numbers.get(label);
+                        return (
+                            `<sup class="footnote-ref">` +
+                            `<a href="#fn-${label}" id="fnref-${label}">` +
+                            `${n}</a></sup>`
+                        );
+                    },
+                },
+            ],
+        };
+    },
+    /*
+        GitHub style alerts, a blockquote starting with `[!NOTE]`, `[!TIP]`,
+        `[!IMPORTANT]`, `[!WARNING]` or `[!CAUTION]`, optionally followed by
+        a title. `>` is also matched as `&gt;` as the text of `ftd.text` is
+        escaped before it gets here, see `fastn_utils.escapeHtmlInMarkdown`.
+     */
+    markdownAdmonitions() {
+        let quote = " {0,3}(?:>|&gt;) ?";
+        let alert = new RegExp(
+            `^${quote}\\[!(note|tip|important|warning|caution)\\]` +
+                `[ \\t]*([^\\n]*)(?:\\n|$)((?:${quote}[^\\n]*(?:\\n|$))*)`,
+            "i",
+        );
+        let line = new RegExp(`^${quote}`, "gm");
+        return {
+            extensions: [
+                {
+                    name: "admonition",
+                    level: "block",
+                    start(src) {
+                        return src.match(
+                            new RegExp(`^${quote}\\[!`, "m"),
+                        )?.index;
+                    },
+                    tokenizer(src) {
+                        let match = alert.exec(src);
+                        if (match) {
+                            return {
+                                type: "admonition",
+                                raw: match[0],
+                                kind: match[1].toLowerCase(),
+                                title: this.lexer.inlineTokens(match[2].trim()),
+                                tokens: this.lexer.blockTokens(
+                                    match[3].replace(line, ""),
+                                    [],
+                                ),
+                            };
+                        }
+                    },
+                    renderer(token) {
+                        let title =
+                            token.title.length > 0
+                                ? this.parser.parseInline(token.title)
+                                : token.kind[0].toUpperCase() +
+                                  token.kind.slice(1);
+                        return (
+                            `<div class="markdown-alert ` +
+                            `markdown-alert-${token.kind}">\n` +
+                            `<p class="markdown-alert-title">${title}</p>\n` +
+                            `${this.parser.parse(token.tokens)}</div>\n`
+                        );
+                    },
+                },
+            ],
+        };
+    },
     /**
      * Helper function for `fastn_utils.markdown_inline` to find the number of
      * spaces before and after the content.
@@ -4478,6 +6340,20 @@ fastn_utils.private = {
 
         return mergedRanges.join(",");
     },
+    toFieldNames(value) {
+        if (Array.isArray(value)) {
+            return value.map((v) => fastn_utils.private.toFieldNames(v));
+        }
+        if (value === null || typeof value !== "object") {
+            return value;
+        }
+        return Object.fromEntries(
+            Object.entries(value).map(([key, v]) => [
+                key.replace(/-/g, "_"),
+                fastn_utils.private.toFieldNames(v),
+            ]),
+        );
+    },
     addUnderscoreToStart(text) {
         if (/^\d/.test(text)) {
             return "_" + text;
@@ -4553,6 +6429,7 @@ fastn_utils.private = {
 /*Object.prototype.get = function(index) {
     return this[index];
 }*/
+const fastn_svg_allowlist = { elements: ["svg", "g", "defs", "symbol", "use", "title", "desc", "switch", "view", "path", "rect", "circle", "ellipse", "line", "polyline", "polygon", "text", "tspan", "textPath", "image", "linearGradient", "radialGradient", "stop", "clipPath", "mask", "pattern", "marker", "filter", "feBlend", "feColorMatrix", "feComponentTransfer", "feComposite", "feConvolveMatrix", "feDiffuseLighting", "feDisplacementMap", "feDistantLight", "feDropShadow", "feFlood", "feFuncA", "feFuncB", "feFuncG", "feFuncR", "feGaussianBlur", "feMerge", "feMergeNode", "feMorphology", "feOffset", "fePointLight", "feSpecularLighting", "feSpotLight", "feTile", "feTurbulence", "animate", "animateMotion", "animateTransform", "set", "mpath"], animations: ["animate", "animateMotion", "animateTransform", "set"], attributes: ["xmlns", "xmlns:xlink", "xml:space", "version", "id", "class", "style", "role", "aria-hidden", "aria-label", "aria-labelledby", "aria-describedby", "focusable", "lang", "href", "xlink:href", "viewBox", "preserveAspectRatio", "width", "height", "x", "y", "x1", "y1", "x2", "y2", "cx", "cy", "r", "rx", "ry", "fx", "fy", "fr", "d", "points", "pathLength", "transform", "transform-origin", "fill", "fill-opacity", "fill-rule", "clip-rule", "stroke", "stroke-width", "stroke-linecap", "stroke-linejoin", "stroke-miterlimit", "stroke-dasharray", "stroke-dashoffset", "stroke-opacity", "opacity", "color", "display", "visibility", "overflow", "clip-path", "mask", "filter", "marker-start", "marker-mid", "marker-end", "paint-order", "vector-effect", "shape-rendering", "text-rendering", "image-rendering", "color-interpolation", "color-interpolation-filters", "mix-blend-mode", "isolation", "gradientUnits", "gradientTransform", "spreadMethod", "offset", "stop-color", "stop-opacity", "patternUnits", "patternContentUnits", "patternTransform", "clipPathUnits", "maskUnits", "maskContentUnits", "markerWidth", "markerHeight", "markerUnits", "refX", "refY", "orient", "filterUnits", "primitiveUnits", "in", "in2", "result", "stdDeviation", "dx", "dy", "mode", "type", "values", "operator", "k1", "k2", "k3", "k4", "scale", "xChannelSelector", "yChannelSelector", "flood-color", "flood-opacity", "lighting-color", "radius", "baseFrequency", "numOctaves", "seed", "stitchTiles", "tableValues", "slope", "intercept", "amplitude", "exponent", "kernelMatrix", "order", "divisor", "bias", "targetX", "targetY", "edgeMode", "preserveAlpha", "surfaceScale", "diffuseConstant", "specularConstant", "specularExponent", "azimuth", "elevation", "pointsAtX", "pointsAtY", "pointsAtZ", "limitingConeAngle", "z", "font-family", "font-size", "font-weight", "font-style", "text-anchor", "dominant-baseline", "alignment-baseline", "baseline-shift", "letter-spacing", "word-spacing", "text-decoration", "writing-mode", "textLength", "lengthAdjust", "startOffset", "method", "spacing", "attributeName", "attributeType", "from", "to", "by", "dur", "begin", "end", "repeatCount", "repeatDur", "calcMode", "keyTimes", "keySplines", "keyPoints", "additive", "accumulate", "rotate", "path", "restart", "min", "max"] };
 let fastnVirtual = {};
 
 let id_counter = 0;
@@ -4708,6 +6585,12 @@ function addClosureToBreakpointWidth() {
 
 fastnVirtual.doubleBuffer = function (main) {
     addClosureToBreakpointWidth();
+    fastnVirtual.replaceBody(main);
+};
+
+// Renders `main` off-screen and then swaps it in as the body, also used for
+// the pages of client-side navigation.
+fastnVirtual.replaceBody = function (main) {
     let parent = document.createElement("div");
     let current_device = ftd.get_device();
     ftd.device = fastn.mutable(current_device);
@@ -4859,8 +6742,21 @@ const ftd = (function () {
         return fastn_utils.isNull(value) || value.length === 0;
     };
 
+    // Used by `match:` to check which variant of an or-type a value holds
+    exports.is_variant = (value, variant) => {
+        value = fastn_utils.getStaticValue(value);
+        return (
+            value instanceof fastn.orTypeValueClass &&
+            value.getVariant() === fastn_utils.getStaticValue(variant)
+        );
+    };
+
     exports.len = (data) => {
-        if (!!data && data instanceof fastn.mutableListClass) {
+        if (
+            !!data &&
+            (data instanceof fastn.mutableListClass ||
+                data instanceof fastn.mutableMapClass)
+        ) {
             if (data.getLength) return data.getLength();
             return -1;
         }
@@ -5071,6 +6967,305 @@ const ftd = (function () {
     exports.set_list = function (list, value) {
         list.set(value);
     };
+    exports.insert = function (map, key, value) {
+        map.insert(key, value);
+    };
+    exports.remove = function (map, key) {
+        map.remove(key);
+    };
+    exports.keys = function (map) {
+        return fastn.mutableList(fastn_utils.getter(map).keys());
+    };
+
+    // String and list helpers. These mirror the evalexpr builtins in
+    // `fastn-builtins` and are tested against the same `conformance.json`.
+    const staticValue = (value) => fastn_utils.getStaticValue(value);
+
+    const listItems = (list) => {
+        const value = staticValue(list);
+        if (!Array.isArray(value)) return [];
+        return value.map((item) => fastn_utils.getListItem(item));
+    };
+
+    // Record fields are stored with `-` replaced by `_`.
+    const fieldOf = (item, field) => {
+        item = fastn_utils.getter(item);
+        field = staticValue(field);
+        let value = null;
+        if (
+            item instanceof fastn.recordInstanceClass ||
+            item instanceof fastn.mutableMapClass
+        ) {
+            value = item.get(field);
+            if (value === undefined) {
+                value = item.get(field.split("-").join("_"));
+            }
+        } else if (item !== null && typeof item === "object") {
+            value = item[field];
+        }
+        if (value === undefined) value = null;
+        return fastn_utils.mutableToStaticValue(value);
+    };
+
+    const isEqual = (a, b) =>
+        JSON.stringify(fastn_utils.mutableToStaticValue(a)) ===
+        JSON.stringify(fastn_utils.mutableToStaticValue(b));
+
+    // Strings compare by code point, like Rust, rather than by UTF-16 unit.
+    const compareValues = (a, b) => {
+        a = fastn_utils.mutableToStaticValue(a);
+        b = fastn_utils.mutableToStaticValue(b);
+        if (typeof a === "string" && typeof b === "string") {
+            const [x, y] = [Array.from(a), Array.from(b)];
+            for (let i = 0; i < Math.min(x.length, y.length); i++) {
+                const diff = x[i].codePointAt(0) - y[i].codePointAt(0);
+                if (diff !== 0) return diff;
+            }
+            return x.length - y.length;
+        }
+        if (
+            (typeof a === "number" && typeof b === "number") ||
+            (typeof a === "boolean" && typeof b === "boolean")
+        ) {
+            return a - b;
+        }
+        return 0;
+    };
+
+    const sliceRange = (length, start, end) => {
+        const index = (i) =>
+            i < 0 ? Math.max(length + i, 0) : Math.min(i, length);
+        start = index(start);
+        end = end === undefined || end === null ? length : index(end);
+        return [start, Math.max(end, start)];
+    };
+
+    const pad = (s, width, fill, atStart) => {
+        s = staticValue(s);
+        width = staticValue(width);
+        fill = fill === undefined ? " " : staticValue(fill);
+        const length = Array.from(s).length;
+        if (length >= width || fill === "") return s;
+        const fillChars = Array.from(fill);
+        let padding = "";
+        for (let i = 0; i < width - length; i++) {
+            padding += fillChars[i % fillChars.length];
+        }
+        return atStart ? padding + s : s + padding;
+    };
+
+    exports.split = function (s, separator) {
+        s = staticValue(s);
+        separator = staticValue(separator);
+        return fastn.mutableList(
+            separator === "" ? Array.from(s) : s.split(separator),
+        );
+    };
+
+    exports.join = function (list, separator) {
+        return listItems(list)
+            .map((item) => {
+                item = fastn_utils.mutableToStaticValue(item);
+                return fastn_utils.isNull(item) ? "" : String(item);
+            })
+            .join(staticValue(separator));
+    };
+
+    exports.replace = function (s, from, to) {
+        s = staticValue(s);
+        from = staticValue(from);
+        if (from === "") return s;
+        return s.split(from).join(staticValue(to));
+    };
+
+    exports.contains = function (value, item) {
+        const data = staticValue(value);
+        if (typeof data === "string") return data.includes(staticValue(item));
+        return listItems(value).some((v) => isEqual(v, item));
+    };
+
+    exports.starts_with = function (s, prefix) {
+        return staticValue(s).startsWith(staticValue(prefix));
+    };
+
+    exports.ends_with = function (s, suffix) {
+        return staticValue(s).endsWith(staticValue(suffix));
+    };
+
+    exports.slice = function (value, start, end) {
+        const data = staticValue(value);
+        if (typeof data === "string") {
+            const chars = Array.from(data);
+            const [from, to] = sliceRange(
+                chars.length,
+                staticValue(start),
+                staticValue(end),
+            );
+            return chars.slice(from, to).join("");
+        }
+        const items = listItems(value);
+        const [from, to] = sliceRange(
+            items.length,
+            staticValue(start),
+            staticValue(end),
+        );
+        return fastn.mutableList(
+            items.slice(from, to).map((item) => fastn_utils.clone(item)),
+        );
+    };
+
+    exports.pad_start = function (s, width, fill) {
+        return pad(s, width, fill, true);
+    };
+
+    exports.pad_end = function (s, width, fill) {
+        return pad(s, width, fill, false);
+    };
+
+    exports.format_number = function (number, decimals = 0) {
+        number = staticValue(number);
+        decimals = Math.min(Math.max(staticValue(decimals), 0), 20);
+        const factor = Math.pow(10, decimals);
+        const rounded = Math.round(Math.abs(number) * factor) / factor;
+        const [integer, fraction] = rounded.toFixed(decimals).split(".");
+        let grouped = integer.replace(/\B(?=(\d{3})+(?!\d))/g, ",");
+        if (fraction !== undefined) grouped += "." + fraction;
+        return number < 0 && rounded !== 0 ? "-" + grouped : grouped;
+    };
+
+    exports.sort = function (list) {
+        return fastn.mutableList(
+            listItems(list)
+                .sort(compareValues)
+                .map((item) => fastn_utils.clone(item)),
+        );
+    };
+
+    exports.sort_by = function (list, field) {
+        return fastn.mutableList(
+            listItems(list)
+                .map((item) => [fieldOf(item, field), item])
+                .sort(([a], [b]) => compareValues(a, b))
+                .map(([, item]) => fastn_utils.clone(item)),
+        );
+    };
+
+    exports.filter = function (list, field, value) {
+        return fastn.mutableList(
+            listItems(list)
+                .filter((item) => isEqual(fieldOf(item, field), value))
+                .map((item) => fastn_utils.clone(item)),
+        );
+    };
+
+    exports.map = function (list, field) {
+        return fastn.mutableList(
+            listItems(list).map((item) => fieldOf(item, field)),
+        );
+    };
+
+    exports.sum = function (list) {
+        return listItems(list).reduce(
+            (sum, item) => sum + fastn_utils.mutableToStaticValue(item),
+            0,
+        );
+    };
+
+    exports.unique = function (list) {
+        const items = [];
+        for (const item of listItems(list)) {
+            if (!items.some((v) => isEqual(v, item))) items.push(item);
+        }
+        return fastn.mutableList(items.map((item) => fastn_utils.clone(item)));
+    };
+
+    const MILLIS_PER_DAY = 86400000;
+
+    // `ftd.language` is set by fastn to the language of the page, see
+    // `fastn_core::host_builtins::language`.
+    const pageLanguage = () =>
+        fastn_utils.getStaticValue(exports.language) ?? "en";
+
+    // Supports the same strftime subset that the docs list for
+    // `ftd.format_datetime`. Values are always formatted in UTC.
+    exports.format_datetime = function (datetime, pattern, lang) {
+        datetime = fastn_utils.getStaticValue(datetime);
+        pattern = fastn_utils.getStaticValue(pattern);
+        lang = fastn_utils.getStaticValue(lang) ?? pageLanguage();
+        const date = new Date(datetime);
+        const part = (options) =>
+            new Intl.DateTimeFormat(lang, { timeZone: "UTC", ...options })
+                .format(date);
+        const pad = (n, width = 2) => String(n).padStart(width, "0");
+        const hours = date.getUTCHours();
+        const directives = {
+            Y: () => String(date.getUTCFullYear()),
+            y: () => pad(date.getUTCFullYear() % 100),
+            m: () => pad(date.getUTCMonth() + 1),
+            d: () => pad(date.getUTCDate()),
+            e: () => String(date.getUTCDate()).padStart(2, " "),
+            H: () => pad(hours),
+            I: () => pad(hours % 12 === 0 ? 12 : hours % 12),
+            M: () => pad(date.getUTCMinutes()),
+            S: () => pad(date.getUTCSeconds()),
+            p: () => (hours < 12 ? "AM" : "PM"),
+            B: () => part({ month: "long" }),
+            b: () => part({ month: "short" }),
+            A: () => part({ weekday: "long" }),
+            a: () => part({ weekday: "short" }),
+            "%": () => "%",
+        };
+        return pattern.replace(/%(.)/g, (directive, c) =>
+            directives[c] ? directives[c]() : directive,
+        );
+    };
+
+    exports.relative_time = function (datetime, lang) {
+        datetime = fastn_utils.getStaticValue(datetime);
+        lang = fastn_utils.getStaticValue(lang) ?? pageLanguage();
+        const diff = Math.trunc((datetime - Date.now()) / 1000);
+        const seconds = Math.abs(diff);
+        let value, unit;
+        if (seconds < 60) {
+            [value, unit] = [seconds, "second"];
+        } else if (seconds < 60 * 60) {
+            [value, unit] = [seconds / 60, "minute"];
+        } else if (seconds < 60 * 60 * 24) {
+            [value, unit] = [seconds / (60 * 60), "hour"];
+        } else if (seconds < 60 * 60 * 24 * 30) {
+            [value, unit] = [seconds / (60 * 60 * 24), "day"];
+        } else if (seconds < 60 * 60 * 24 * 365) {
+            [value, unit] = [seconds / (60 * 60 * 24 * 30), "month"];
+        } else {
+            [value, unit] = [seconds / (60 * 60 * 24 * 365), "year"];
+        }
+        value = Math.trunc(value);
+        return new Intl.RelativeTimeFormat(lang, { numeric: "always" }).format(
+            diff < 0 ? -value : value,
+            unit,
+        );
+    };
+
+    exports.add_days = function (datetime, days) {
+        return (
+            fastn_utils.getStaticValue(datetime) +
+            fastn_utils.getStaticValue(days) * MILLIS_PER_DAY
+        );
+    };
+
+    exports.add_seconds = function (datetime, seconds) {
+        return (
+            fastn_utils.getStaticValue(datetime) +
+            fastn_utils.getStaticValue(seconds) * 1000
+        );
+    };
+
+    exports.diff_days = function (a, b) {
+        return Math.trunc(
+            (fastn_utils.getStaticValue(a) - fastn_utils.getStaticValue(b)) /
+                MILLIS_PER_DAY,
+        );
+    };
 
     exports.http = function (url, method, headers, ...body) {
         if (url instanceof fastn.mutableClass) url = url.get();
@@ -5377,20 +7572,45 @@ const ftd = (function () {
             data[name] = fastn_utils.getFlattenStaticValue(obj.get("value"));
         }
 
+        exports.submit_form_data(url, data, arg_map);
+    };
+
+    /**
+     * Sends `data` to `url` and handles the response of the server:
+     * `{"redirect": url}`, `{"reload": true}` or the field errors,
+     * `{"errors": {"<field name>": "message" or ["message", ...]}}`. The
+     * errors are set on the `error` of the records in `arg_map` (keyed by
+     * field name) and, if `form` is passed, the inputs of that name are marked
+     * invalid.
+     */
+    exports.submit_form_data = function (url, data, arg_map, options) {
+        options = options || {};
+        let method = (options.method || "POST").toUpperCase();
         let init = {
-            method: "POST",
+            method,
             redirect: "error",
             // TODO: set credentials?
             credentials: "same-origin",
-            headers: { "Content-Type": "application/json" },
-            body: JSON.stringify(data),
         };
+        if (method === "GET") {
+            let query = new URLSearchParams(formDataEntries(data)).toString();
+            url = `${url}${url.includes("?") ? "&" : "?"}${query}`;
+        } else if (options.encoding === fastn_dom.FormEncoding.UrlEncoded) {
+            init.headers = {
+                "Content-Type": "application/x-www-form-urlencoded",
+            };
+            init.body = new URLSearchParams(formDataEntries(data)).toString();
+        } else {
+            init.headers = { "Content-Type": "application/json" };
+            init.body = JSON.stringify(data);
+        }
 
         console.log(url, data);
 
         fetch(url, init)
             .then((res) => {
-                if (!res.ok) {
+                // field errors are usually sent with a 400 or 422 status
+                if (!res.ok && res.status !== 400 && res.status !== 422) {
                     return new Error("[http_post]: Request failed: " + res);
                 }
                 return res.json();
@@ -5415,6 +7635,9 @@ const ftd = (function () {
                         }
                         // @ts-ignore
                         obj.get("error").set(error);
+                        if (!!options.form) {
+                            markInvalid(options.form, key, error);
+                        }
                     }
                 } else if (!!response.data) {
                     console.error("data not yet implemented");
@@ -5424,6 +7647,143 @@ const ftd = (function () {
             })
             .catch(console.error);
     };
+
+    function formDataEntries(data) {
+        let entries = [];
+        for (let [key, value] of Object.entries(data)) {
+            let values = Array.isArray(value) ? value : [value];
+            for (let v of values) {
+                if (!fastn_utils.isNull(v)) entries.push([key, `${v}`]);
+            }
+        }
+        return entries;
+    }
+
+    function markInvalid(form, name, error) {
+        for (let input of form.querySelectorAll(`[name="${name}"]`)) {
+            if (fastn_utils.isNull(error)) {
+                input.removeAttribute("aria-invalid");
+            } else {
+                input.setAttribute("aria-invalid", "true");
+            }
+        }
+    }
+
+    // Same rules as `fastn_builtins::form::FieldRules::validate`, both are
+    // tested against `fastn-builtins/form-conformance.json`.
+    exports.validate_field = function (field, value) {
+        field = fastn_utils.getStaticValue(field);
+        let get = (key) => fastn_utils.getStaticValue(field.get(key));
+        let error = (message) => {
+            let custom = get("message");
+            return fastn_utils.isNull(custom) ? message : custom;
+        };
+        value = fastn_utils.getStaticValue(value);
+        value = fastn_utils.isNull(value) ? "" : `${value}`;
+        if (value.trim() === "") {
+            return get("required") ? error("This field is required") : null;
+        }
+
+        let length = [...value].length;
+        let min_length = get("min_length");
+        if (!fastn_utils.isNull(min_length) && length < min_length) {
+            return error(`Must be at least ${min_length} characters`);
+        }
+        let max_length = get("max_length");
+        if (!fastn_utils.isNull(max_length) && length > max_length) {
+            return error(`Must be at most ${max_length} characters`);
+        }
+
+        let min = get("min");
+        let max = get("max");
+        if (!fastn_utils.isNull(min) || !fastn_utils.isNull(max)) {
+            let trimmed = value.trim();
+            if (!/^[+-]?(\d+\.?\d*|\.\d+)$/.test(trimmed)) {
+                return error("Must be a number");
+            }
+            let number = Number(trimmed);
+            if (!fastn_utils.isNull(min) && number < min) {
+                return error(`Must be at least ${min}`);
+            }
+            if (!fastn_utils.isNull(max) && number > max) {
+                return error(`Must be at most ${max}`);
+            }
+        }
+
+        let pattern = get("pattern");
+        if (!fastn_utils.isNull(pattern)) {
+            // the pattern has to match the whole value and an invalid
+            // pattern never matches
+            let matches = false;
+            try {
+                matches = new RegExp(`^(?:${pattern})$`, "u").test(value);
+            } catch (e) {
+                matches = false;
+            }
+            if (!matches) {
+                return error("Invalid format");
+            }
+        }
+        return null;
+    };
+
+    /**
+     * Submit handler of `ftd.form`: reads the value of every field from the
+     * form inputs of the same `name` (or the field's own `value` when there
+     * is no such input), validates them and submits the form if all of them
+     * are valid.
+     */
+    exports.submit_ftd_form = function (form, fields, options) {
+        fields = fastn_utils.getStaticValue(fields);
+        let formData = new FormData(form);
+        let data = {};
+        let arg_map = {};
+        let valid = true;
+        for (let obj of fields || []) {
+            let field = fastn_utils.getStaticValue(obj.item);
+            let name = fastn_utils.getStaticValue(field.get("name"));
+            let value = fastn_utils.getStaticValue(field.get("value"));
+            if (formData.has(name)) {
+                let values = formData
+                    .getAll(name)
+                    .map((v) => (v instanceof File ? v.name : v));
+                value = values.length > 1 ? values : values[0];
+                if (!Array.isArray(value)) field.get("value").set(value);
+            }
+            arg_map[name] = field;
+            data[name] = value;
+
+            let error = Array.isArray(value)
+                ? value
+                      .map((v) => exports.validate_field(field, v))
+                      .find((e) => !fastn_utils.isNull(e))
+                : exports.validate_field(field, value);
+            let check = fastn_utils.getStaticValue(field.get("check"));
+            if (fastn_utils.isNull(error) && !fastn_utils.isNull(check)) {
+                // `check` names a js function that returns the error, if any
+                if (typeof window[check] !== "function") {
+                    console.warn(`[ftd.form]: ${check} is not a function`);
+                } else {
+                    error = window[check](value, data);
+                    if (error === "" || error === undefined) error = null;
+                }
+            }
+            field.get("error").set(error);
+            markInvalid(form, name, error);
+            if (!fastn_utils.isNull(error) && valid) {
+                valid = false;
+                let input = form.querySelector(`[name="${name}"]`);
+                if (!fastn_utils.isNull(input)) input.focus();
+            }
+        }
+        if (!valid) return;
+        let url = form.getAttribute("action") || window.location.href;
+        exports.submit_form_data(url, data, arg_map, {
+            form,
+            method: form.getAttribute("method"),
+            encoding: options.encoding,
+        });
+    };
     return exports;
 })();
 
@@ -5470,6 +7830,7 @@ ftd.post_init = function () {
     const COOKIE_DARK_MODE = "dark";
     const COOKIE_LIGHT_MODE = "light";
     const DARK_MODE_CLASS = "dark";
+    const REDUCED_MOTION_CLASS = "reduced-motion";
     let last_device = ftd.device.get();
 
     window.onresize = function () {
@@ -5639,6 +8000,230 @@ ftd.post_init = function () {
             window.matchMedia("(prefers-color-scheme: dark)").matches
         );
     }
+    // `body.reduced-motion` turns off `transition` and `animation`, see
+    // `attachReducedMotionCss` in dom.js
+    function initialise_reduced_motion() {
+        if (!window.matchMedia) {
+            return;
+        }
+        const query = window.matchMedia("(prefers-reduced-motion: reduce)");
+        const update = () =>
+            document.body.classList.toggle(REDUCED_MOTION_CLASS, query.matches);
+        update();
+        query.addEventListener("change", update);
+    }
+    /*
+        Client-side navigation, turned on by `spa: true` in `fastn.package`.
+
+        A click on an internal link fetches the js of the target page from
+        `<page>/index.spa.js`, which calls `ftd.spa_page` with the scripts
+        the page needs and a function declaring its variables and returning
+        its `main`. The new page then replaces the body, and the variables
+        listed in `__fastn_spa__` (`persist` in `fastn.package`) get the
+        value they had on the previous page.
+
+        If the js can not be fetched we fall back to a full page load.
+     */
+    function initialise_spa() {
+        if (typeof __fastn_spa__ === "undefined" || !window.history) {
+            return;
+        }
+        const SPA_PAYLOAD_FILE = "index.spa.js";
+        let current_page = page_of(window.location);
+        let next_page = null;
+        let navigation = 0;
+
+        window.history.scrollRestoration = "manual";
+        ftd.spa_page = function (scripts, page) {
+            next_page = [scripts, page];
+        };
+
+        document.addEventListener("click", function (event) {
+            let link = event.target.closest && event.target.closest("a");
+            if (
+                !link ||
+                event.defaultPrevented ||
+                event.button !== 0 ||
+                event.metaKey ||
+                event.ctrlKey ||
+                event.shiftKey ||
+                event.altKey ||
+                !is_spa_link(link)
+            ) {
+                return;
+            }
+            event.preventDefault();
+            navigate(new URL(link.href), true, null);
+        });
+        window.addEventListener("popstate", function (event) {
+            // going back to an anchor on the same page only scrolls
+            if (page_of(window.location) === current_page) {
+                return;
+            }
+            navigate(new URL(window.location.href), false, event.state);
+        });
+        window.addEventListener(
+            "scroll",
+            fastn_utils.throttle(save_scroll, 200),
+            { passive: true },
+        );
+
+        function page_of(url) {
+            return `${url.pathname}${url.search}`;
+        }
+        function save_scroll() {
+            let state = { ...window.history.state, scroll: window.scrollY };
+            window.history.replaceState(state, "");
+        }
+        function is_spa_link(link) {
+            if (
+                link.origin !== window.location.origin ||
+                (link.target && link.target !== "_self") ||
+                link.hasAttribute("download")
+            ) {
+                return false;
+            }
+            // `/-/` urls are served by fastn itself, and an extension in the
+            // last segment means a file, neither of them is a page
+            let last = link.pathname.split("/").pop();
+            if (link.pathname.startsWith("/-/") || last.includes(".")) {
+                return false;
+            }
+            return !(page_of(link) === current_page && link.hash);
+        }
+        function payload_url(url) {
+            let path = url.pathname.endsWith("/")
+                ? url.pathname
+                : `${url.pathname}/`;
+            return `${path}${SPA_PAYLOAD_FILE}${url.search}`;
+        }
+        async function navigate(url, push, state) {
+            let id = ++navigation;
+            let response = null;
+            try {
+                response = await fetch(payload_url(url));
+            } catch (e) {
+                console.log("[fastn-spa]: fetch failed", e);
+            }
+            let content_type =
+                (response && response.headers.get("content-type")) || "";
+            if (
+                !response ||
+                !response.ok ||
+                response.redirected ||
+                !content_type.includes("javascript")
+            ) {
+                if (push) window.location.assign(url.href);
+                else window.location.reload();
+                return;
+            }
+            let payload = await response.text();
+            if (id !== navigation) {
+                return;
+            }
+            if (push) {
+                save_scroll();
+                window.history.pushState({ scroll: 0 }, "", url.href);
+            }
+            current_page = page_of(url);
+
+            next_page = null;
+            let script = document.createElement("script");
+            script.text = payload;
+            document.head.appendChild(script).remove();
+            if (next_page === null) {
+                window.location.reload();
+                return;
+            }
+            let [scripts, page] = next_page;
+            await load_scripts(scripts);
+            if (id !== navigation) {
+                return;
+            }
+            render(page);
+            restore_scroll(url, state);
+        }
+        // loads the `<script>` and `<link>` tags of the page which are not
+        // already loaded, scripts run in the order they are listed
+        function load_scripts(html) {
+            let template = document.createElement("template");
+            template.innerHTML = html;
+            let loading = [];
+            for (const tag of template.content.children) {
+                let attribute = tag.tagName === "SCRIPT" ? "src" : "href";
+                let url = tag.getAttribute(attribute);
+                let loaded = Array.from(
+                    document.querySelectorAll(tag.tagName),
+                ).some((t) => t.getAttribute(attribute) === url);
+                if (!url || loaded) {
+                    continue;
+                }
+                let element = document.createElement(tag.tagName);
+                for (const { name, value } of tag.attributes) {
+                    element.setAttribute(name, value);
+                }
+                element.async = false;
+                loading.push(
+                    new Promise((resolve) => {
+                        element.onload = resolve;
+                        element.onerror = resolve;
+                    }),
+                );
+                document.head.appendChild(element);
+            }
+            return Promise.all(loading);
+        }
+        function render(page) {
+            let persisted = __fastn_spa__
+                .filter((name) => global[name] !== undefined)
+                .map((name) => [
+                    name,
+                    fastn_utils.mutableToStaticValue(global[name]),
+                ]);
+
+            fastn_dom.disposableNodes.forEach((node) => node.destroy());
+            fastn_dom.eventSources.forEach((source) => source.close());
+            fastn_dom.eventSources.clear();
+            ftd.clickOutsideEvents = [];
+            ftd.globalKeyEvents = [];
+            ftd.globalKeySeqEvents = [];
+            document
+                .querySelectorAll(`meta[${fastn_dom.metaTagAttribute}]`)
+                .forEach((meta) => meta.remove());
+            window.document.title = "";
+
+            // declares the variables of the new page in `global`
+            let main = page();
+            for (const [name, value] of persisted) {
+                let mutable = global[name];
+                if (mutable === undefined || !mutable.set) {
+                    continue;
+                }
+                let mutableValue = fastn_utils.staticToMutables(value);
+                if (mutableValue instanceof fastn.mutableClass) {
+                    mutableValue = mutableValue.get();
+                }
+                mutable.set(mutableValue);
+            }
+            fastnVirtual.replaceBody(main);
+            fastn_utils.resetFullHeight();
+            fastn_utils.setFullHeight();
+        }
+        function restore_scroll(url, state) {
+            if (state && state.scroll) {
+                window.scrollTo(0, state.scroll);
+                return;
+            }
+            let anchor =
+                url.hash &&
+                document.getElementById(decodeURIComponent(url.hash.slice(1)));
+            if (anchor) {
+                anchor.scrollIntoView();
+            } else {
+                window.scrollTo(0, 0);
+            }
+        }
+    }
     function initialise_dark_mode() {
         update_dark_mode();
         start_watching_dark_mode_system_preference();
@@ -5678,8 +8263,10 @@ ftd.post_init = function () {
     }
     initialise_device();
     initialise_dark_mode();
+    initialise_reduced_motion();
     initialise_click_outside_events();
     initialise_global_key_events();
+    initialise_spa();
     fastn_utils.resetFullHeight();
     fastn_utils.setFullHeight();
 };
@@ -0,0 +1,501 @@
+//! Collections are the documents of a package matching a glob, like `blog/*`,
+//! with their metadata, for listings like a blog index or a changelog. The
+//! title and the description of a `.ftd` document are the ones of its
+//! `ftd.document`, and a `.md` page has them, its date and its tags in its
+//! front matter.
+//!
+//! The pages of a listing are the `/-/` suffixes of the document showing it:
+//! `/blog/-/page/2/` is its second page, and `/blog/-/tag/rust/` the documents
+//! with the tag `rust`.
+
+/// A document of a collection, the `collection-item` record of
+/// `fastn/processors`.
+#[derive(serde::Serialize, Debug, Default)]
+pub(crate) struct CollectionItem {
+    /// The file of the document, like `blog/hello.md`.
+    pub id: String,
+    pub url: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub date: Option<String>,
+    pub tags: Vec<String>,
+    pub extra: Vec<fastn_core::library2022::KeyValueData>,
+}
+
+/// A tag of a collection, with the url of its listing.
+#[derive(serde::Serialize, Debug, PartialEq)]
+pub(crate) struct CollectionTag {
+    pub name: String,
+    pub url: String,
+    pub count: usize,
+}
+
+/// The page of a listing, the `collection-pagination` record of
+/// `fastn/processors`.
+#[derive(serde::Serialize, Debug, PartialEq)]
+pub(crate) struct Pagination {
+    pub page: usize,
+    pub pages: usize,
+    /// The number of documents on all the pages.
+    pub total: usize,
+    pub previous: Option<String>,
+    pub next: Option<String>,
+    /// The tag of the listing, from the `tag` header or the url.
+    pub tag: Option<String>,
+    pub tags: Vec<CollectionTag>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Sort {
+    Date,
+    Title,
+    Url,
+}
+
+impl std::str::FromStr for Sort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Sort, String> {
+        match s {
+            "date" => Ok(Sort::Date),
+            "title" => Ok(Sort::Title),
+            "url" => Ok(Sort::Url),
+            t => Err(format!(
+                "unknown sort `{t}`, expected `date`, `title` or `url`"
+            )),
+        }
+    }
+}
+
+/// What a `collection` processor lists.
+#[derive(Debug)]
+pub(crate) struct Query {
+    /// The glob of the documents, `*` matches in a folder and `**` any number
+    /// of folders.
+    pub folder: String,
+    pub tag: Option<String>,
+    pub sort: Sort,
+    pub descending: bool,
+    pub offset: usize,
+    pub limit: Option<usize>,
+    pub per_page: Option<usize>,
+    /// The tag of the listing can be picked with a `/-/tag/<tag>/` url.
+    pub tag_pages: bool,
+}
+
+/// The listing a document shows, from the `/-/` suffix of its url:
+/// `tag/<tag>` and `page/<page>`, both optional.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct Listing {
+    pub tag: Option<String>,
+    pub page: usize,
+}
+
+impl Listing {
+    pub(crate) fn from_suffix(suffix: &str) -> Listing {
+        let mut listing = Listing { tag: None, page: 1 };
+        let mut parts = suffix.split('/').filter(|part| !part.is_empty());
+        while let Some(part) = parts.next() {
+            match (part, parts.next()) {
+                ("tag", Some(tag)) => listing.tag = Some(tag.to_string()),
+                ("page", Some(page)) => listing.page = page.parse().unwrap_or(1).max(1),
+                _ => {}
+            }
+        }
+        listing
+    }
+
+    /// The `/-/` suffix of the listing, empty for the first page without a
+    /// tag.
+    pub(crate) fn suffix(&self) -> String {
+        let mut suffix = String::new();
+        if let Some(tag) = self.tag.as_ref() {
+            suffix.push_str(format!("tag/{}/", slug::slugify(tag)).as_str());
+        }
+        if self.page > 1 {
+            suffix.push_str(format!("page/{}/", self.page).as_str());
+        }
+        suffix
+    }
+
+    /// The url of the listing on the document at `base`, like `/blog/`.
+    pub(crate) fn url(&self, base: &str) -> String {
+        match self.suffix() {
+            suffix if suffix.is_empty() => base.to_string(),
+            suffix => format!("{base}-/{suffix}"),
+        }
+    }
+}
+
+/// The url of the document without its `/-/` suffix, and the suffix:
+/// `("/blog/", "page/2")` for `/blog/-/page/2/`.
+pub(crate) fn split_suffix(doc_id: &str) -> (String, String) {
+    let doc_id = format!("/{}", doc_id.trim_start_matches('/'));
+    match doc_id.split_once("/-/") {
+        Some((base, suffix)) => (
+            format!("{}/", base.trim_end_matches('/')),
+            suffix.trim_matches('/').to_string(),
+        ),
+        None => (doc_id, String::new()),
+    }
+}
+
+/// The documents of the package matching the glob of the query, but the one
+/// at `current_url`.
+pub(crate) async fn items(
+    config: &fastn_core::Config,
+    package: &fastn_core::Package,
+    query: &Query,
+    current_url: &str,
+    session_id: &Option<String>,
+) -> fastn_core::Result<Vec<CollectionItem>> {
+    let root = config.get_root_for_package(package);
+    let mut items = vec![];
+    for path in config.get_all_file_paths(package).await? {
+        let Some(id) = path.strip_prefix(&root).map(|id| id.to_string()) else {
+            continue;
+        };
+        let id = id
+            .replace(std::path::MAIN_SEPARATOR, "/")
+            .trim_start_matches('/')
+            .to_string();
+        if id.eq("FASTN.ftd") || !glob_match(query.folder.trim_matches('/'), id.as_str()) {
+            continue;
+        }
+        let item = match id.rsplit_once('.') {
+            Some((_, "ftd")) => {
+                let content = config.ds.read_to_string(&path, session_id).await?;
+                ftd_item(id.as_str(), content.as_str())
+            }
+            Some((_, "md")) => {
+                let content = config.ds.read_to_string(&path, session_id).await?;
+                md_item(id.as_str(), content.as_str())?
+            }
+            _ => continue,
+        };
+        if item.url.ne(current_url) {
+            items.push(item);
+        }
+    }
+    sort(&mut items, query.sort, query.descending);
+    Ok(items)
+}
+
+fn ftd_item(id: &str, content: &str) -> CollectionItem {
+    let mut item = CollectionItem {
+        url: item_url(id),
+        id: id.to_string(),
+        ..Default::default()
+    };
+    // a document which does not parse has no metadata, its error is shown
+    // when it is built
+    let Some(document) = ftd_p1::parse(content, id).ok().and_then(|sections| {
+        sections
+            .into_iter()
+            .find(|section| section.name.eq("ftd.document") && !section.is_commented)
+    }) else {
+        return item;
+    };
+    let header = |key: &str| {
+        document
+            .headers
+            .find(key)
+            .into_iter()
+            .chain(document.caption.as_ref().filter(|_| key.eq("title")))
+            .filter_map(|header| header.get_value(id).ok().flatten())
+            // references are only known when the document is interpreted
+            .find(|value| !value.starts_with('$'))
+    };
+    item.title = header("title");
+    item.description = header("description");
+    item
+}
+
+fn md_item(id: &str, content: &str) -> fastn_core::Result<CollectionItem> {
+    let page = fastn_core::markdown_page::MarkdownPage::parse(id, content).map_err(|message| {
+        fastn_core::Error::PackageError {
+            message: format!("{id}: {message}"),
+        }
+    })?;
+    Ok(CollectionItem {
+        id: page.document,
+        url: page.url,
+        title: page.title,
+        description: page.description,
+        date: page.date,
+        tags: page.tags,
+        extra: page.extra,
+    })
+}
+
+fn item_url(id: &str) -> String {
+    format!(
+        "/{}",
+        fastn_core::utils::id_to_path(id).trim_start_matches('/')
+    )
+}
+
+/// Undated documents go after the dated ones, either way. ISO dates sort as
+/// strings.
+fn sort(items: &mut [CollectionItem], by: Sort, descending: bool) {
+    items.sort_by(|a, b| {
+        let ordering = match by {
+            Sort::Date => a.date.cmp(&b.date),
+            Sort::Title => a.title.cmp(&b.title),
+            Sort::Url => a.url.cmp(&b.url),
+        };
+        let ordering = if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        };
+        let undated = |item: &CollectionItem| by == Sort::Date && item.date.is_none();
+        undated(a)
+            .cmp(&undated(b))
+            .then(ordering)
+            .then_with(|| a.url.cmp(&b.url))
+    });
+}
+
+/// The documents of the listing, and its pagination, for the document at
+/// `base`. `items` are the sorted documents of the collection.
+pub(crate) fn listing(
+    items: Vec<CollectionItem>,
+    query: &Query,
+    listing: &Listing,
+    base: &str,
+) -> (Vec<CollectionItem>, Pagination) {
+    let mut tags: Vec<CollectionTag> = vec![];
+    for tag in items.iter().flat_map(|item| item.tags.iter()) {
+        match tags.iter_mut().find(|t| t.name.eq(tag)) {
+            Some(t) => t.count += 1,
+            None => tags.push(CollectionTag {
+                name: tag.to_string(),
+                url: Listing {
+                    tag: Some(tag.to_string()),
+                    page: 1,
+                }
+                .url(base),
+                count: 1,
+            }),
+        }
+    }
+    tags.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let tag = query.tag.clone().or_else(|| {
+        // the tag of the url is a slug, the name of the tag is shown
+        listing
+            .tag
+            .as_ref()
+            .filter(|_| query.tag_pages)
+            .and_then(|slug| {
+                tags.iter()
+                    .find(|tag| slug::slugify(tag.name.as_str()).eq(slug))
+                    .map(|tag| tag.name.to_string())
+                    .or_else(|| Some(slug.to_string()))
+            })
+    });
+    let items = items
+        .into_iter()
+        .filter(|item| {
+            tag.as_ref()
+                .is_none_or(|tag| item.tags.iter().any(|t| t.eq(tag)))
+        })
+        .skip(query.offset)
+        .take(query.limit.unwrap_or(usize::MAX))
+        .collect::<Vec<_>>();
+
+    let total = items.len();
+    let (page, pages, items) = match query.per_page {
+        Some(per_page) => {
+            let per_page = per_page.max(1);
+            let pages = total.div_ceil(per_page).max(1);
+            let page = listing.page.min(pages);
+            let items = items
+                .into_iter()
+                .skip((page - 1) * per_page)
+                .take(per_page)
+                .collect();
+            (page, pages, items)
+        }
+        None => (1, 1, items),
+    };
+    let url_tag = tag.as_ref().filter(|_| query.tag.is_none());
+    let url = |page: usize| {
+        Listing {
+            tag: url_tag.cloned(),
+            page,
+        }
+        .url(base)
+    };
+    let pagination = Pagination {
+        page,
+        pages,
+        total,
+        previous: (page > 1).then(|| url(page - 1)),
+        next: (page < pages).then(|| url(page + 1)),
+        tag,
+        tags,
+    };
+    (items, pagination)
+}
+
+/// The `/-/` suffixes of the other pages of the listing, the pages after the
+/// first and, with `tag-pages`, the listings of the tags. `fastn build`
+/// builds them next to the document.
+pub(crate) fn other_pages(
+    query: &Query,
+    listing: &Listing,
+    pagination: &Pagination,
+) -> Vec<String> {
+    let mut suffixes = vec![];
+    if listing.page == 1 {
+        let url_tag = pagination.tag.as_ref().filter(|_| query.tag.is_none());
+        for page in 2..=pagination.pages {
+            suffixes.push(
+                Listing {
+                    tag: url_tag.cloned(),
+                    page,
+                }
+                .suffix(),
+            );
+        }
+    }
+    if query.tag_pages && query.tag.is_none() && listing.tag.is_none() {
+        for tag in pagination.tags.iter() {
+            suffixes.push(
+                Listing {
+                    tag: Some(tag.name.to_string()),
+                    page: 1,
+                }
+                .suffix(),
+            );
+        }
+    }
+    suffixes
+}
+
+/// `*` matches a part of a file or folder name, `**` any number of folders,
+/// and a glob ending with `/` everything in the folder.
+fn glob_match(glob: &str, id: &str) -> bool {
+    fn segments(glob: &[&str], id: &[&str]) -> bool {
+        match (glob.first(), id.first()) {
+            (None, None) => true,
+            (Some(&"**"), _) => {
+                segments(&glob[1..], id) || (!id.is_empty() && segments(glob, &id[1..]))
+            }
+            (Some(g), Some(i)) => segment(g, i) && segments(&glob[1..], &id[1..]),
+            _ => false,
+        }
+    }
+
+    fn segment(glob: &str, name: &str) -> bool {
+        match glob.split_once('*') {
+            None => glob.eq(name),
+            Some((prefix, rest)) => {
+                name.starts_with(prefix)
+                    && (0..=name.len() - prefix.len()).any(|i| {
+                        name.is_char_boundary(prefix.len() + i)
+                            && segment(rest, &name[prefix.len() + i..])
+                    })
+            }
+        }
+    }
+
+    let glob = match glob {
+        "" => "**".to_string(),
+        glob if !glob.contains('*') && !glob.contains('.') => format!("{glob}/**"),
+        glob => glob.to_string(),
+    };
+    let glob = glob.split('/').collect::<Vec<_>>();
+    let id = id.split('/').collect::<Vec<_>>();
+    segments(glob.as_slice(), id.as_slice())
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn glob_match() {
+        assert!(super::glob_match("blog/*", "blog/hello.md"));
+        assert!(!super::glob_match("blog/*", "blog/2024/hello.md"));
+        assert!(super::glob_match("blog/**", "blog/2024/hello.md"));
+        assert!(super::glob_match("blog", "blog/2024/hello.md"));
+        assert!(super::glob_match("blog/*.md", "blog/hello.md"));
+        assert!(!super::glob_match("blog/*.md", "blog/hello.ftd"));
+        assert!(super::glob_match("**/*.md", "README.md"));
+        assert!(super::glob_match("changelog/v*.ftd", "changelog/v1.ftd"));
+        assert!(!super::glob_match("blog/*", "news/hello.md"));
+    }
+
+    #[test]
+    fn listing() {
+        let listing = super::Listing::from_suffix("tag/web-dev/page/3");
+        assert_eq!(listing.tag.as_deref(), Some("web-dev"));
+        assert_eq!(listing.page, 3);
+        assert_eq!(listing.url("/blog/"), "/blog/-/tag/web-dev/page/3/");
+        assert_eq!(super::Listing::from_suffix("").url("/blog/"), "/blog/");
+        assert_eq!(
+            super::split_suffix("blog/-/page/2/"),
+            ("/blog/".to_string(), "page/2".to_string())
+        );
+        assert_eq!(
+            super::split_suffix("-/page/2/"),
+            ("/".to_string(), "page/2".to_string())
+        );
+        assert_eq!(
+            super::split_suffix("/blog/"),
+            ("/blog/".to_string(), String::new())
+        );
+    }
+
+    #[test]
+    fn pages() {
+        let item = |url: &str, date: Option<&str>, tags: &[&str]| super::CollectionItem {
+            url: url.to_string(),
+            date: date.map(str::to_string),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..Default::default()
+        };
+        let mut items = vec![
+            item("/blog/a/", Some("2024-01-01"), &["Web Dev"]),
+            item("/blog/b/", None, &[]),
+            item("/blog/c/", Some("2024-03-01"), &["rust", "Web Dev"]),
+            item("/blog/d/", Some("2024-02-01"), &["rust"]),
+        ];
+        super::sort(&mut items, super::Sort::Date, true);
+        assert_eq!(
+            items.iter().map(|i| i.url.as_str()).collect::<Vec<_>>(),
+            vec!["/blog/c/", "/blog/d/", "/blog/a/", "/blog/b/"]
+        );
+
+        let query = super::Query {
+            folder: "blog".to_string(),
+            tag: None,
+            sort: super::Sort::Date,
+            descending: true,
+            offset: 0,
+            limit: None,
+            per_page: Some(1),
+            tag_pages: true,
+        };
+        let listing = super::Listing::from_suffix("tag/web-dev");
+        let (items, pagination) = super::listing(items, &query, &listing, "/blog/");
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].url, "/blog/c/");
+        assert_eq!(pagination.tag.as_deref(), Some("Web Dev"));
+        assert_eq!(
+            (pagination.page, pagination.pages, pagination.total),
+            (1, 2, 2)
+        );
+        assert_eq!(pagination.previous, None);
+        assert_eq!(
+            pagination.next.as_deref(),
+            Some("/blog/-/tag/web-dev/page/2/")
+        );
+        assert_eq!(pagination.tags[0].url, "/blog/-/tag/web-dev/");
+        assert_eq!(
+            super::other_pages(&query, &listing, &pagination),
+            vec!["tag/web-dev/page/2/".to_string()]
+        );
+    }
+}
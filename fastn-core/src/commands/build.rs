@@ -20,15 +20,18 @@ pub async fn build(
     )
     .await?;
 
-    {
+    // the documents that fail do not stop the build, their errors are
+    // reported together at the end
+    let diagnostics = {
         let documents = get_documents_for_current_package(config).await?;
         let zip_url = zip_url.map_or_else(|| config.package.zip.clone(), |z| Some(z.to_string()));
 
         fastn_core::manifest::write_manifest_file(config, &build_dir, zip_url, &None).await?;
 
+        let mut diagnostics = fastn_core::Diagnostics::new(&documents);
         match only_id {
             Some(id) => {
-                return handle_only_id(
+                if let Err(e) = handle_only_id(
                     id,
                     config,
                     base_url,
@@ -37,7 +40,11 @@ pub async fn build(
                     documents,
                     preview_session_id,
                 )
-                .await;
+                .await
+                {
+                    diagnostics.add(id, e);
+                }
+                return diagnostics.finish(config.message_format);
             }
             None => {
                 incremental_build(
@@ -46,18 +53,20 @@ pub async fn build(
                     base_url,
                     ignore_failed,
                     test,
+                    &mut diagnostics,
                     preview_session_id,
                 )
                 .await?;
             }
         }
-    }
+        diagnostics
+    };
 
     // All redirect html files under .build
     if let Some(ref r) = config.package.redirects {
         for (redirect_from, redirect_to) in r.iter() {
-            println!(
-                "Processing redirect {}/{} -> {}... ",
+            fastn_core::progress!(
+                "Processing redirect {}/{} -> {}... \n",
                 config.package.name.as_str(),
                 redirect_from.trim_matches('/'),
                 redirect_to
@@ -83,6 +92,8 @@ pub async fn build(
         config.download_fonts(&None).await?;
     }
 
+    diagnostics.finish(config.message_format)?;
+
     if check_build {
         return fastn_core::post_build_check(config).await;
     }
@@ -231,6 +242,7 @@ async fn handle_dependency_file(
     test: bool,
     name_without_package_name: String,
     processed: &mut Vec<String>,
+    diagnostics: &mut fastn_core::Diagnostics,
     preview_session_id: &Option<String>,
 ) -> fastn_core::Result<()> {
     for document in documents.values() {
@@ -242,7 +254,7 @@ async fn handle_dependency_file(
             if processed.contains(&id) {
                 continue;
             }
-            handle_file(
+            if let Err(e) = handle_file(
                 document,
                 config,
                 base_url,
@@ -252,7 +264,10 @@ async fn handle_dependency_file(
                 Some(cache),
                 preview_session_id,
             )
-            .await?;
+            .await
+            {
+                diagnostics.add(id.as_str(), e);
+            }
             processed.push(id);
         }
     }
@@ -268,14 +283,32 @@ async fn remove_deleted_documents(
 ) -> fastn_core::Result<()> {
     use itertools::Itertools;
 
+    // a markdown page is cached as the `.ftd` document it is built as
+    let documents = documents
+        .values()
+        .map(|document| match document {
+            fastn_core::File::Markdown(doc) => {
+                let page = fastn_core::Document {
+                    package_name: doc.package_name.clone(),
+                    id: fastn_core::markdown_page::ftd_id(doc.id.as_str()),
+                    content: String::new(),
+                    parent_path: doc.parent_path.clone(),
+                };
+                (page.id.clone(), page.id_with_package())
+            }
+            document => (
+                document.get_id().to_string(),
+                document.get_id_with_package(),
+            ),
+        })
+        .collect_vec();
     let removed_documents = c
         .documents
         .keys()
         .filter(|cached_document_id| {
-            for document in documents.values() {
-                if remove_extension(document.get_id()).eq(cached_document_id.as_str())
-                    || remove_extension(&document.get_id_with_package())
-                        .eq(cached_document_id.as_str())
+            for (id, id_with_package) in documents.iter() {
+                if remove_extension(id).eq(cached_document_id.as_str())
+                    || remove_extension(id_with_package).eq(cached_document_id.as_str())
                 {
                     return false;
                 }
@@ -319,6 +352,7 @@ async fn incremental_build(
     base_url: &str,
     ignore_failed: bool,
     test: bool,
+    diagnostics: &mut fastn_core::Diagnostics,
     preview_session_id: &Option<String>,
 ) -> fastn_core::Result<()> {
     // https://fastn.com/rfc/incremental-build/
@@ -336,7 +370,7 @@ async fn incremental_build(
         for file in documents.values() {
             // copy static files
             if file.is_static() {
-                handle_file(
+                if let Err(e) = handle_file(
                     file,
                     config,
                     base_url,
@@ -346,7 +380,10 @@ async fn incremental_build(
                     Some(&mut c),
                     preview_session_id,
                 )
-                .await?;
+                .await
+                {
+                    diagnostics.add(file.get_id(), e);
+                }
                 continue;
             }
 
@@ -395,6 +432,7 @@ async fn incremental_build(
                         test,
                         unresolved_dependency.to_string(),
                         &mut processed,
+                        diagnostics,
                         preview_session_id,
                     )
                     .await?;
@@ -428,6 +466,7 @@ async fn incremental_build(
                         test,
                         unresolved_dependency.to_string(),
                         &mut processed,
+                        diagnostics,
                         preview_session_id,
                     )
                     .await?;
@@ -453,7 +492,7 @@ async fn incremental_build(
             if processed.contains(&id) {
                 continue;
             }
-            handle_file(
+            if let Err(e) = handle_file(
                 document,
                 config,
                 base_url,
@@ -463,7 +502,10 @@ async fn incremental_build(
                 Some(&mut c),
                 preview_session_id,
             )
-            .await?;
+            .await
+            {
+                diagnostics.add(id.as_str(), e);
+            }
             processed.push(id);
         }
     }
@@ -518,7 +560,7 @@ async fn handle_file(
     preview_session_id: &Option<String>,
 ) -> fastn_core::Result<()> {
     let start = std::time::Instant::now();
-    print!("Processing {} ... ", document.get_id_with_package());
+    fastn_core::progress!("Processing {} ... ", document.get_id_with_package());
     let package_name = config.package.name.to_string();
     let process_status = handle_file_(
         document,
@@ -648,6 +690,29 @@ async fn handle_file_(
     cache: Option<&mut cache::Cache>,
     preview_session_id: &Option<String>,
 ) -> fastn_core::Result<()> {
+    // a markdown page is built as the `.ftd` document it is shown with
+    let page;
+    let document = match document {
+        fastn_core::File::Markdown(doc) => {
+            let ftd_document = fastn_core::markdown_page::ftd_document(config, doc)?;
+            if config
+                .ds
+                .exists(
+                    &ftd_document.parent_path.join(ftd_document.id.as_str()),
+                    preview_session_id,
+                )
+                .await
+            {
+                // the `.ftd` document with the same url is built instead
+                fastn_core::progress!("Skipped ");
+                return Ok(());
+            }
+            page = fastn_core::File::Ftd(ftd_document);
+            &page
+        }
+        _ => document,
+    };
+
     match document {
         fastn_core::File::Ftd(doc) => {
             let file_path = if doc.id.eq("404.ftd") {
@@ -675,13 +740,13 @@ async fn handle_file_(
                 return Ok(());
             }
 
-            let resp = {
+            let (resp, listing_pages, cacheable) = {
                 let req = fastn_core::http::Request::default();
                 let mut req_config =
                     fastn_core::RequestConfig::new(config, &req, doc.id.as_str(), base_url);
                 req_config.current_document = Some(document.get_id().to_string());
 
-                fastn_core::package::package_doc::process_ftd(
+                let resp = fastn_core::package::package_doc::process_ftd(
                     &mut req_config,
                     doc,
                     base_url,
@@ -690,7 +755,12 @@ async fn handle_file_(
                     file_path.as_str(),
                     preview_session_id,
                 )
-                .await
+                .await;
+                (
+                    resp,
+                    req_config.listing_pages,
+                    req_config.response_is_cacheable,
+                )
             };
 
             match (resp, ignore_failed) {
@@ -699,21 +769,38 @@ async fn handle_file_(
                     // let dependencies = req_config.dependencies_during_render;
                     let dependencies = vec![];
                     if let Some(cache) = cache {
-                        cache.documents.insert(
-                            remove_extension(doc.id.as_str()),
-                            cache::Document {
-                                html_checksum: r.checksum(),
-                                dependencies,
-                            },
-                        );
-                        cache.file_checksum.insert(
-                            remove_extension(doc.id.as_str()),
-                            fastn_core::utils::generate_hash(doc.content.as_str()),
-                        );
+                        let id = remove_extension(doc.id.as_str());
+                        // a document that is not cacheable, like a listing of
+                        // other documents, is built every time
+                        if cacheable {
+                            cache.documents.insert(
+                                id.clone(),
+                                cache::Document {
+                                    html_checksum: r.checksum(),
+                                    dependencies,
+                                },
+                            );
+                        } else {
+                            cache.documents.remove(&id);
+                        }
+                        cache
+                            .file_checksum
+                            .insert(id, fastn_core::utils::generate_hash(doc.content.as_str()));
                     }
+                    build_listing_pages(
+                        doc,
+                        listing_pages,
+                        config,
+                        base_url,
+                        ignore_failed,
+                        test,
+                        build_static_files,
+                        preview_session_id,
+                    )
+                    .await?;
                 }
                 (_, true) => {
-                    print!("Failed ");
+                    fastn_core::progress!("Failed ");
                     return Ok(());
                 }
                 (Err(e), _) => {
@@ -724,11 +811,7 @@ async fn handle_file_(
         fastn_core::File::Static(sa) => {
             process_static(sa, &config.ds.root(), &config.package, &config.ds).await?
         }
-        fastn_core::File::Markdown(_doc) => {
-            // TODO: bring this feature back
-            print!("Skipped ");
-            return Ok(());
-        }
+        fastn_core::File::Markdown(_) => unreachable!("markdown pages are built as `.ftd`"),
         fastn_core::File::Image(main_doc) => {
             process_static(main_doc, &config.ds.root(), &config.package, &config.ds).await?;
         }
@@ -751,6 +834,103 @@ async fn handle_file_(
     Ok(())
 }
 
+/// Builds the other pages of the paginated `collection`s of `doc`, like
+/// `blog/-/page/2/index.html`. A page can add more pages, like the second
+/// page of a tag.
+#[allow(clippy::too_many_arguments)]
+async fn build_listing_pages(
+    doc: &fastn_core::Document,
+    mut suffixes: Vec<String>,
+    config: &fastn_core::Config,
+    base_url: &str,
+    ignore_failed: bool,
+    test: bool,
+    build_static_files: bool,
+    preview_session_id: &Option<String>,
+) -> fastn_core::Result<()> {
+    let folder = match fastn_core::utils::id_to_path(doc.id.as_str())
+        .replace(std::path::MAIN_SEPARATOR, "/")
+        .trim_matches('/')
+    {
+        "" => "".to_string(),
+        folder => format!("{folder}/"),
+    };
+    let mut built = 0;
+    while let Some(suffix) = suffixes.get(built).cloned() {
+        built += 1;
+        let req = fastn_core::http::Request::default();
+        let mut req_config =
+            fastn_core::RequestConfig::new(config, &req, doc.id.as_str(), base_url);
+        req_config.current_document = Some(format!("{folder}-/{suffix}index.ftd"));
+
+        let resp = fastn_core::package::package_doc::process_ftd(
+            &mut req_config,
+            doc,
+            base_url,
+            build_static_files,
+            test,
+            format!("{folder}-/{suffix}index.html").as_str(),
+            preview_session_id,
+        )
+        .await;
+        match (resp, ignore_failed) {
+            (Ok(_), _) => {
+                for suffix in req_config.listing_pages {
+                    if !suffixes.contains(&suffix) {
+                        suffixes.push(suffix);
+                    }
+                }
+            }
+            (_, true) => fastn_core::progress!("Failed "),
+            (Err(e), _) => return Err(e),
+        }
+    }
+    remove_stale_listing_pages(config, folder.as_str(), &suffixes).await
+}
+
+/// Removes the pages of the listings of `folder` that were built before but
+/// are not among `suffixes` anymore, like `blog/-/page/3/` after some posts
+/// are deleted.
+async fn remove_stale_listing_pages(
+    config: &fastn_core::Config,
+    folder: &str,
+    suffixes: &[String],
+) -> fastn_core::Result<()> {
+    let listing_dir = config.build_dir().join(format!("{folder}-"));
+    for kind in ["page", "tag"] {
+        let dir = listing_dir.join(kind);
+        for file in config.ds.get_all_file_path(&dir, &[]).await {
+            let Some(suffix) = file
+                .parent()
+                .and_then(|parent| parent.strip_prefix(&listing_dir))
+            else {
+                continue;
+            };
+            let suffix = format!(
+                "{}/",
+                suffix
+                    .to_string()
+                    .replace(std::path::MAIN_SEPARATOR, "/")
+                    .trim_matches('/')
+            );
+            if suffixes.contains(&suffix) {
+                continue;
+            }
+            config.ds.remove(&file).await?;
+            // the folders the removed page leaves empty, up to `-/`
+            let mut parent = file.parent();
+            while let Some(folder) = parent.filter(|p| p.strip_prefix(&listing_dir).is_some()) {
+                if !config.ds.get_all_file_path(&folder, &[]).await.is_empty() {
+                    break;
+                }
+                config.ds.remove(&folder).await?;
+                parent = folder.parent();
+            }
+        }
+    }
+    Ok(())
+}
+
 #[tracing::instrument]
 pub async fn default_build_files(
     base_path: fastn_ds::Path,
@@ -816,7 +996,7 @@ pub async fn default_build_files(
 }
 
 #[tracing::instrument(skip(config))]
-async fn get_documents_for_current_package(
+pub(crate) async fn get_documents_for_current_package(
     config: &fastn_core::Config,
 ) -> fastn_core::Result<std::collections::BTreeMap<String, fastn_core::File>> {
     let mut documents = std::collections::BTreeMap::from_iter(
@@ -2,9 +2,49 @@ pub const INDEX_FILE: &str = "index.html";
 pub const BUILD_FOLDER: &str = ".build";
 pub const IGNORED_DIRECTORIES: [&str; 4] = ["-", "images", "static", "assets"];
 
+/// Interprets every document of the package and reports all the errors and
+/// warnings found, without building it.
+pub async fn check(config: &fastn_core::Config) -> fastn_core::Result<()> {
+    let documents = fastn_core::commands::build::get_documents_for_current_package(config).await?;
+    let mut diagnostics = fastn_core::Diagnostics::new(&documents);
+    for file in documents.values() {
+        let document = match file {
+            fastn_core::File::Ftd(doc) if doc.id.ne("FASTN.ftd") => doc.clone(),
+            fastn_core::File::Markdown(doc) => {
+                match fastn_core::markdown_page::ftd_document(config, doc) {
+                    Ok(doc) => doc,
+                    Err(e) => {
+                        diagnostics.add(file.get_id(), e);
+                        continue;
+                    }
+                }
+            }
+            _ => continue,
+        };
+        let req = fastn_core::http::Request::default();
+        let mut req_config =
+            fastn_core::RequestConfig::new(config, &req, document.id.as_str(), "/");
+        req_config.current_document = Some(file.get_id().to_string());
+        if let Err(e) = fastn_core::package::package_doc::read_ftd(
+            &mut req_config,
+            &document,
+            "/",
+            false,
+            false,
+            &None,
+        )
+        .await
+        {
+            diagnostics.add(file.get_id(), e);
+        }
+        diagnostics.warn(file.get_id(), &req_config.warnings);
+    }
+    diagnostics.finish(config.message_format)
+}
+
 pub async fn post_build_check(config: &fastn_core::Config) -> fastn_core::Result<()> {
     let build_path = config.ds.root().join(BUILD_FOLDER);
-    println!("Post build index assertion started ...");
+    fastn_core::progress!("Post build index assertion started ...\n");
 
     // if build_path.is_dir() {
     if !config.ds.exists(&build_path.join(INDEX_FILE), &None).await {
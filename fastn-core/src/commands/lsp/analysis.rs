@@ -0,0 +1,840 @@
+/// What the interpreter found in a document: the things in it and in the
+/// modules it imports, and the files of those modules.
+pub(crate) struct Analysis {
+    pub document: ftd::interpreter::Document,
+    pub module_files: std::collections::BTreeMap<String, fastn_ds::Path>,
+}
+
+/// Where a thing is defined: the line of its module it is on, see
+/// `definition_span` for where it is named on the line.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Target {
+    pub module: String,
+    pub line_number: usize,
+}
+
+/// A completion for the text before the cursor.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Completion {
+    pub label: String,
+    /// the `CompletionItemKind` of LSP
+    pub kind: u8,
+    pub detail: String,
+    pub insert: String,
+}
+
+const COMPLETION_KIND_VARIABLE: u8 = 6;
+const COMPLETION_KIND_CLASS: u8 = 7;
+const COMPLETION_KIND_PROPERTY: u8 = 10;
+const COMPLETION_KIND_VALUE: u8 = 12;
+const COMPLETION_KIND_ENUM_MEMBER: u8 = 20;
+
+impl Analysis {
+    /// The module of the document, like `<package-name>/foo/bar`.
+    pub(crate) fn module(&self) -> &str {
+        self.document.name.trim_end_matches('/')
+    }
+
+    /// The thing `name`, as written in the document, is about, with its full
+    /// name. For `$person.name` it is `person`.
+    pub(crate) fn resolve(&self, name: &str) -> Option<(String, &ftd::interpreter::Thing)> {
+        let name = name.trim_start_matches('*').trim_start_matches('$');
+        let full = if name.contains('#') {
+            name.to_string()
+        } else {
+            match name.split_once('.') {
+                Some((alias, rest)) if self.document.aliases.contains_key(alias) => {
+                    format!("{}#{rest}", self.document.aliases[alias])
+                }
+                _ => format!("{}#{name}", self.module()),
+            }
+        };
+
+        let mut candidate = full.as_str();
+        loop {
+            if let Some(thing) = self.document.data.get(candidate) {
+                return Some((candidate.to_string(), thing));
+            }
+            match candidate.rsplit_once('.') {
+                Some((rest, _)) if rest.contains('#') => candidate = rest,
+                _ => return None,
+            }
+        }
+    }
+
+    /// The name the document refers to the thing `full` with: `card` for the
+    /// things of the document, `ftd.text` for `ftd#text`, and the full name
+    /// when the module of the thing is not imported.
+    pub(crate) fn display_name(&self, full: &str) -> String {
+        let Some((module, name)) = full.split_once('#') else {
+            return full.to_string();
+        };
+        if module.eq(self.module()) {
+            return name.to_string();
+        }
+        match self
+            .document
+            .aliases
+            .iter()
+            .filter(|(_, m)| m.as_str().eq(module))
+            .map(|(alias, _)| alias)
+            .min_by_key(|alias| alias.len())
+        {
+            Some(alias) => format!("{alias}.{name}"),
+            None => full.to_string(),
+        }
+    }
+
+    /// The things the document can refer to, with the names it refers to
+    /// them with.
+    fn visible(&self) -> impl Iterator<Item = (String, &ftd::interpreter::Thing)> {
+        self.document.data.iter().filter_map(|(full, thing)| {
+            let name = self.display_name(full);
+            (!name.contains('#')).then_some((name, thing))
+        })
+    }
+
+    /// The hover text for the text at `offset`, with where the thing it is
+    /// about is defined, so the docs of the thing can be added to it.
+    pub(crate) fn hover(&self, source: &str, offset: usize) -> Option<(String, Target)> {
+        if let Some((component, argument)) = self.argument_at(source, offset) {
+            return Some((
+                format!(
+                    "```ftd\n{}\n```\n\nArgument of `{}`.",
+                    self.field(argument),
+                    self.display_name(component.as_str())
+                ),
+                Target {
+                    module: module_of(component.as_str()).to_string(),
+                    line_number: argument.line_number,
+                },
+            ));
+        }
+
+        let (_, word) = word_at(source, offset)?;
+        let (full, thing) = self.resolve(word)?;
+        Some((
+            format!("```ftd\n{}\n```", self.signature(thing)),
+            target(full.as_str(), thing),
+        ))
+    }
+
+    /// Where the thing the text at `offset` refers to is defined.
+    pub(crate) fn definition(&self, source: &str, offset: usize) -> Option<Target> {
+        if let Some((component, argument)) = self.argument_at(source, offset) {
+            return Some(Target {
+                module: module_of(component.as_str()).to_string(),
+                line_number: argument.line_number,
+            });
+        }
+        let (_, word) = word_at(source, offset)?;
+        let (full, thing) = self.resolve(word)?;
+        Some(target(full.as_str(), thing))
+    }
+
+    /// The completions for the text before `offset`, with the span of the
+    /// text they replace: component names after `--`, variables after `$`,
+    /// the arguments of the component of the section as headers, and the
+    /// variants of or-types as values of the headers.
+    pub(crate) fn completions(
+        &self,
+        source: &str,
+        offset: usize,
+    ) -> (ftd_p1::Span, Vec<Completion>) {
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let before = source[line_start..offset].trim_start();
+        let start = offset
+            - source[line_start..offset]
+                .chars()
+                .rev()
+                .take_while(|c| is_name_char(*c) || *c == '.' || *c == '$')
+                .map(char::len_utf8)
+                .sum::<usize>();
+        let span = ftd_p1::Span::new(start, offset);
+        let word = &source[start..offset];
+
+        if word.starts_with('$') {
+            let completions = self
+                .visible()
+                .filter_map(|(name, thing)| match thing {
+                    ftd::interpreter::Thing::Variable(v) => Some(Completion {
+                        label: format!("${name}"),
+                        kind: COMPLETION_KIND_VARIABLE,
+                        detail: self.kind_data(&v.kind),
+                        insert: format!("${name}"),
+                    }),
+                    _ => None,
+                })
+                .collect();
+            return (span, completions);
+        }
+
+        if let Some(head) = before.strip_prefix("--") {
+            if head.contains(':') {
+                return (span, vec![]);
+            }
+            let completions = self
+                .visible()
+                .filter_map(|(name, thing)| match thing {
+                    ftd::interpreter::Thing::Component(_)
+                    | ftd::interpreter::Thing::WebComponent(_) => Some(Completion {
+                        label: name.to_string(),
+                        kind: COMPLETION_KIND_CLASS,
+                        detail: "component".to_string(),
+                        insert: name,
+                    }),
+                    _ => None,
+                })
+                .collect();
+            return (span, completions);
+        }
+
+        let line_index = source[..line_start].matches('\n').count();
+        let Some(arguments) = self.section_arguments(source, line_index) else {
+            return (span, vec![]);
+        };
+        let completions = match before.split_once(':') {
+            // the value of a header
+            Some((key, _)) => match arguments.iter().find(|a| a.name.eq(key.trim())) {
+                Some(argument) => self.values(&argument.kind.kind),
+                None => vec![],
+            },
+            None => arguments
+                .iter()
+                .map(|argument| Completion {
+                    label: argument.name.to_string(),
+                    kind: COMPLETION_KIND_PROPERTY,
+                    detail: self.kind_data(&argument.kind),
+                    insert: format!("{}: ", argument.name),
+                })
+                .collect(),
+        };
+        (span, completions)
+    }
+
+    /// The values a header of kind `kind` can take that can be listed.
+    fn values(&self, kind: &fastn_resolved::Kind) -> Vec<Completion> {
+        match kind {
+            fastn_resolved::Kind::Optional { kind } => self.values(kind),
+            fastn_resolved::Kind::Boolean => ["true", "false"]
+                .into_iter()
+                .map(|value| Completion {
+                    label: value.to_string(),
+                    kind: COMPLETION_KIND_VALUE,
+                    detail: "boolean".to_string(),
+                    insert: value.to_string(),
+                })
+                .collect(),
+            fastn_resolved::Kind::OrType { name, .. } => match self.document.data.get(name) {
+                Some(ftd::interpreter::Thing::OrType(or_type)) => or_type
+                    .variants
+                    .iter()
+                    .map(|variant| {
+                        let name = variant_name(or_type, variant);
+                        Completion {
+                            label: name.to_string(),
+                            kind: COMPLETION_KIND_ENUM_MEMBER,
+                            detail: self.display_name(or_type.name.as_str()),
+                            insert: name,
+                        }
+                    })
+                    .collect(),
+                _ => vec![],
+            },
+            _ => vec![],
+        }
+    }
+
+    /// The arguments of the component the section of the line
+    /// `line_index`, starting at 0, invokes.
+    fn section_arguments(
+        &self,
+        source: &str,
+        line_index: usize,
+    ) -> Option<&[fastn_resolved::Argument]> {
+        let (_, component) = self.section_component(source, line_index)?;
+        match component {
+            ftd::interpreter::Thing::Component(c) => Some(c.arguments.as_slice()),
+            ftd::interpreter::Thing::WebComponent(c) => Some(c.arguments.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// The component the section of the line `line_index`, starting at 0,
+    /// invokes, with its full name.
+    fn section_component(
+        &self,
+        source: &str,
+        line_index: usize,
+    ) -> Option<(String, &ftd::interpreter::Thing)> {
+        let head = source
+            .split('\n')
+            .take(line_index)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .map(str::trim)
+            .find(|line| line.starts_with("--") && !line.starts_with("-- end:"))?
+            .trim_start_matches('-')
+            .split(':')
+            .next()?
+            .trim();
+        // a section with a kind before the name defines a thing, it does not
+        // invoke a component
+        if head.contains(char::is_whitespace) || head.eq("import") {
+            return None;
+        }
+        self.resolve(head)
+    }
+
+    /// The component and its argument when `offset` is on the key of a header
+    /// of a section invoking a component.
+    fn argument_at(
+        &self,
+        source: &str,
+        offset: usize,
+    ) -> Option<(String, &fastn_resolved::Argument)> {
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line = source[line_start..].split('\n').next()?;
+        let (key, _) = line.split_once(':')?;
+        if line.trim_start().starts_with("--") || offset > line_start + key.len() {
+            return None;
+        }
+        let key = key.trim();
+        let line_index = source[..line_start].matches('\n').count();
+        let (component, thing) = self.section_component(source, line_index)?;
+        let arguments = match thing {
+            ftd::interpreter::Thing::Component(c) => &c.arguments,
+            ftd::interpreter::Thing::WebComponent(c) => &c.arguments,
+            _ => return None,
+        };
+        let argument = arguments.iter().find(|a| a.name.eq(key))?;
+        Some((component, argument))
+    }
+
+    /// The thing, the way it is defined in ftd.
+    fn signature(&self, thing: &ftd::interpreter::Thing) -> String {
+        let fields = |fields: &[fastn_resolved::Field]| {
+            fields
+                .iter()
+                .map(|f| format!("\n{}", self.field(f)))
+                .collect::<String>()
+        };
+        match thing {
+            ftd::interpreter::Thing::Variable(v) => format!(
+                "-- {} {}{}:",
+                self.kind_data(&v.kind),
+                if v.mutable { "$" } else { "" },
+                self.display_name(v.name.as_str())
+            ),
+            ftd::interpreter::Thing::Component(c) => format!(
+                "-- component {}:{}",
+                self.display_name(c.name.as_str()),
+                fields(&c.arguments)
+            ),
+            ftd::interpreter::Thing::WebComponent(c) => format!(
+                "-- web-component {}:{}",
+                self.display_name(c.name.as_str()),
+                fields(&c.arguments)
+            ),
+            ftd::interpreter::Thing::Record(r) => format!(
+                "-- record {}:{}",
+                self.display_name(r.name.as_str()),
+                fields(&r.fields)
+            ),
+            ftd::interpreter::Thing::OrType(o) => format!(
+                "-- or-type {}:{}",
+                self.display_name(o.name.as_str()),
+                o.variants
+                    .iter()
+                    .map(|v| format!("\n{}", self.variant(o, v)))
+                    .collect::<String>()
+            ),
+            ftd::interpreter::Thing::OrTypeWithVariant { or_type, variant } => {
+                match self.document.data.get(or_type) {
+                    Some(ftd::interpreter::Thing::OrType(o)) => format!(
+                        "-- or-type {}:\n{}",
+                        self.display_name(or_type),
+                        self.variant(o, variant)
+                    ),
+                    _ => format!("-- or-type {}:", self.display_name(or_type)),
+                }
+            }
+            ftd::interpreter::Thing::Function(f) => format!(
+                "-- {} {}({}):{}",
+                self.kind_data(&f.return_kind),
+                self.display_name(f.name.as_str()),
+                f.arguments
+                    .iter()
+                    .map(|a| a.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                fields(&f.arguments)
+            ),
+            ftd::interpreter::Thing::Export { from, to, .. } => {
+                format!("{} is {}", self.display_name(to), self.display_name(from))
+            }
+        }
+    }
+
+    fn variant(
+        &self,
+        or_type: &fastn_resolved::OrType,
+        variant: &fastn_resolved::OrTypeVariant,
+    ) -> String {
+        let name = variant_name(or_type, variant);
+        match variant {
+            fastn_resolved::OrTypeVariant::AnonymousRecord(_) => format!("-- record {name}:"),
+            fastn_resolved::OrTypeVariant::Regular(f) => {
+                format!("-- {} {name}:", self.kind_data(&f.kind))
+            }
+            fastn_resolved::OrTypeVariant::Constant(f) => {
+                format!("-- constant {} {name}:", self.kind_data(&f.kind))
+            }
+        }
+    }
+
+    fn field(&self, field: &fastn_resolved::Field) -> String {
+        format!(
+            "{} {}{}:",
+            self.kind_data(&field.kind),
+            if field.mutable { "$" } else { "" },
+            field.name
+        )
+    }
+
+    fn kind_data(&self, kind: &fastn_resolved::KindData) -> String {
+        let kind_name = self.kind(&kind.kind);
+        match (kind.caption, kind.body) {
+            (true, true) => format!("caption or body {kind_name}"),
+            (true, false) => format!("caption {kind_name}"),
+            (false, true) => format!("body {kind_name}"),
+            (false, false) => kind_name,
+        }
+    }
+
+    fn kind(&self, kind: &fastn_resolved::Kind) -> String {
+        match kind {
+            fastn_resolved::Kind::Optional { kind } => format!("optional {}", self.kind(kind)),
+            fastn_resolved::Kind::List { kind } => format!("{} list", self.kind(kind)),
+            fastn_resolved::Kind::Constant { kind } => format!("constant {}", self.kind(kind)),
+            fastn_resolved::Kind::Record { name } | fastn_resolved::Kind::OrType { name, .. } => {
+                self.display_name(name)
+            }
+            fastn_resolved::Kind::UI { .. } => "ftd.ui".to_string(),
+            kind => kind.get_name(),
+        }
+    }
+
+    /// The spans of the thing of this document at `offset`, where it is
+    /// defined and where it is used, to rename it.
+    pub(crate) fn rename(&self, source: &str, offset: usize) -> Result<Vec<ftd_p1::Span>, String> {
+        let (_, word) = word_at(source, offset).ok_or("There is nothing to rename here")?;
+        if let Some(local) = word.strip_prefix('$') {
+            let local = local.split('.').next().unwrap_or(local);
+            if scopes(source)
+                .iter()
+                .any(|(range, names)| range.contains(&offset) && names.iter().any(|n| n == local))
+            {
+                return Err(format!(
+                    "`${local}` is bound by a loop or match here, not defined in this document"
+                ));
+            }
+        }
+        let (full, _) = self
+            .resolve(word)
+            .ok_or_else(|| format!("`{word}` is not found"))?;
+        let name = full
+            .strip_prefix(format!("{}#", self.module()).as_str())
+            .ok_or("Only the things defined in this document can be renamed")?;
+        Ok(occurrences(source, name))
+    }
+}
+
+fn target(full: &str, thing: &ftd::interpreter::Thing) -> Target {
+    Target {
+        module: module_of(full).to_string(),
+        line_number: thing.line_number(),
+    }
+}
+
+/// The name of the section or the key of the header on the line
+/// `line_number` of `source`, what a thing defined on the line is named with.
+pub(crate) fn definition_span(source: &str, line_number: usize) -> Option<ftd_p1::Span> {
+    fn find(sections: &[ftd_p1::Section], line_number: usize) -> Option<ftd_p1::Span> {
+        sections.iter().find_map(|section| {
+            if section.line_number == line_number && !section.span.is_empty() {
+                return Some(section.span);
+            }
+            section
+                .headers
+                .0
+                .iter()
+                .find(|header| header.get_line_number() == line_number)
+                .map(ftd_p1::Header::get_key_span)
+                .filter(|span| !span.is_empty())
+                .or_else(|| find(section.sub_sections.as_slice(), line_number))
+        })
+    }
+    let sections = ftd_p1::parse(source, "").ok()?;
+    find(sections.as_slice(), line_number)
+}
+
+fn module_of(full: &str) -> &str {
+    full.split_once('#').map_or("", |(module, _)| module)
+}
+
+/// The name of the variant, like `center` for `ftd#text-align.center`.
+fn variant_name(
+    or_type: &fastn_resolved::OrType,
+    variant: &fastn_resolved::OrTypeVariant,
+) -> String {
+    let name = variant.name();
+    match name.strip_prefix(format!("{}.", or_type.name).as_str()) {
+        Some(name) => name.to_string(),
+        None => name
+            .rsplit(['#', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+/// The name at `offset`, like `ftd.text` or `$person.name`, with its span.
+pub(crate) fn word_at(source: &str, offset: usize) -> Option<(ftd_p1::Span, &str)> {
+    let offset = offset.min(source.len());
+    let start = offset
+        - source[..offset]
+            .chars()
+            .rev()
+            .take_while(|c| is_name_char(*c) || *c == '.')
+            .map(char::len_utf8)
+            .sum::<usize>();
+    let start = match source[..start].strip_suffix('$') {
+        Some(rest) => rest.len(),
+        None => start,
+    };
+    let end = offset
+        + source[offset..]
+            .chars()
+            .take_while(|c| is_name_char(*c) || *c == '.')
+            .map(char::len_utf8)
+            .sum::<usize>();
+    let word = source[start..end].trim_end_matches('.');
+    if word.trim_start_matches('$').is_empty() {
+        return None;
+    }
+    Some((ftd_p1::Span::new(start, start + word.len()), word))
+}
+
+/// The docs of the thing defined on the line `line_number`, starting at 1,
+/// of `source`: the `;;` comments right above it.
+pub(crate) fn docs(source: &str, line_number: usize) -> Option<String> {
+    let lines = source
+        .split('\n')
+        .take(line_number.checked_sub(1)?)
+        .collect::<Vec<_>>();
+    let mut docs = lines
+        .iter()
+        .rev()
+        .map(|line| line.trim())
+        .take_while(|line| line.starts_with(";;"))
+        .map(|line| line.trim_start_matches(";;").trim_start_matches(' '))
+        .collect::<Vec<_>>();
+    if docs.is_empty() {
+        return None;
+    }
+    docs.reverse();
+    Some(docs.join("\n"))
+}
+
+/// The spans of `name` in `source` where it names the thing of the document
+/// `name`: in the section lines, like `-- person p:` or `-- card:`, in the
+/// kinds of the headers of definitions, like `person list friends:`, and
+/// after `$`. The keys of the headers are not the thing, they are arguments
+/// with the same name, and neither is `$name` in a section that binds `name`
+/// for itself, see `scopes`.
+pub(crate) fn occurrences(source: &str, name: &str) -> Vec<ftd_p1::Span> {
+    let hidden = scopes(source)
+        .into_iter()
+        .filter(|(_, names)| names.iter().any(|n| n == name))
+        .map(|(range, _)| range)
+        .collect::<Vec<_>>();
+    let references = |text: &str, start: usize| {
+        references(text, name)
+            .into_iter()
+            .filter(|i| !hidden.iter().any(|range| range.contains(&(start + i))))
+            .collect::<Vec<_>>()
+    };
+    let mut spans = vec![];
+    let mut line_start = 0;
+    // the headers of `-- component card:` and the like declare arguments
+    // with kinds, till the empty line ending the headers
+    let mut in_definition_headers = false;
+    for line in source.split('\n') {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        let key_start = line_start + indent;
+        let (key, value) = match trimmed.split_once(':') {
+            Some((key, value)) => (key, Some(value)),
+            None => (trimmed, None),
+        };
+        let value_start = key_start + key.len() + 1;
+        let at =
+            |start: usize| move |i: usize| ftd_p1::Span::new(start + i, start + i + name.len());
+
+        if trimmed.trim().is_empty() {
+            in_definition_headers = false;
+        } else if trimmed.starts_with(";;") {
+            // a comment
+        } else if let Some(head) = key.strip_prefix("--") {
+            let head = head.trim();
+            in_definition_headers = head.contains(char::is_whitespace);
+            spans.extend(words(key, name).into_iter().map(at(key_start)));
+            if let Some(value) = value {
+                let matches = if head.eq("end") {
+                    words(value, name)
+                } else {
+                    references(value, value_start)
+                };
+                spans.extend(matches.into_iter().map(at(value_start)));
+            }
+        } else if let (Some(value), true) = (value, in_definition_headers) {
+            // the last word of the key is the name of the header
+            let kind = key.trim_end();
+            let kind = &kind[..kind.rfind(char::is_whitespace).unwrap_or_default()];
+            spans.extend(words(kind, name).into_iter().map(at(key_start)));
+            spans.extend(
+                references(value, value_start)
+                    .into_iter()
+                    .map(at(value_start)),
+            );
+        } else {
+            spans.extend(references(line, line_start).into_iter().map(at(line_start)));
+        }
+        line_start += line.len() + 1;
+    }
+    spans.sort();
+    spans.dedup();
+    spans
+}
+
+/// The byte ranges of the sections of `source` that bind names for
+/// themselves and the sections in them, with the names they bind: the alias
+/// and the counter of a loop, like `for: $item, $index in $items`, and the
+/// payload of a match, like `match: $result is status.error as $reason`. A
+/// section is closed by the first `-- end:` with its name after it, and a
+/// section without an `-- end:` by the next section line.
+fn scopes(source: &str) -> Vec<(std::ops::Range<usize>, Vec<String>)> {
+    let mut offsets = vec![];
+    let mut offset = 0;
+    for line in source.split('\n') {
+        offsets.push(offset);
+        offset += line.len() + 1;
+    }
+    let lines = source.split('\n').collect::<Vec<_>>();
+    let heads = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let (head, caption) = line.trim_start().strip_prefix("-- ")?.split_once(':')?;
+            Some((i, head.trim(), caption.trim()))
+        })
+        .collect::<Vec<_>>();
+
+    let mut ends = std::collections::HashMap::new();
+    let mut open: Vec<(usize, &str)> = vec![];
+    for (i, head, caption) in heads.iter() {
+        if !head.eq(&"end") {
+            open.push((*i, head.rsplit(char::is_whitespace).next().unwrap_or(head)));
+        } else if let Some(position) = open.iter().rposition(|(_, name)| name == caption) {
+            ends.insert(open[position].0, *i + 1);
+            open.truncate(position);
+        }
+    }
+
+    let mut scopes = vec![];
+    for (n, (i, head, _)) in heads.iter().enumerate() {
+        if head.eq(&"end") {
+            continue;
+        }
+        let names = lines[i + 1..]
+            .iter()
+            .take_while(|line| !line.trim().is_empty() && !line.trim_start().starts_with("--"))
+            .filter_map(|line| line.split_once(':'))
+            .flat_map(|(key, value)| bindings(key.trim(), value.trim()))
+            .collect::<Vec<_>>();
+        if names.is_empty() {
+            continue;
+        }
+        let end = ends
+            .get(i)
+            .copied()
+            .or_else(|| heads.get(n + 1).map(|(j, _, _)| *j))
+            .unwrap_or(lines.len());
+        let end = offsets.get(end).copied().unwrap_or(source.len());
+        scopes.push((offsets[*i]..end, names));
+    }
+    scopes
+}
+
+/// The names the header `key: value` binds.
+fn bindings(key: &str, value: &str) -> Vec<String> {
+    let names = match key {
+        "for" => {
+            let pair = value.split_once(" in ").map_or(value, |(pair, _)| pair);
+            pair.split(',').collect::<Vec<_>>()
+        }
+        "$loop$" | "match" => value
+            .rsplit_once(" as ")
+            .map(|(_, alias)| alias)
+            .into_iter()
+            .collect(),
+        _ => vec![],
+    };
+    names
+        .into_iter()
+        .map(|name| name.trim().trim_start_matches('$').to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// The offsets of `name` in `text` as a word of its own.
+fn words(text: &str, name: &str) -> Vec<usize> {
+    text.match_indices(name)
+        .filter(|(i, _)| {
+            let before = text[..*i].chars().next_back();
+            let after = text[i + name.len()..].chars().next();
+            !before.is_some_and(|c| is_name_char(c) || c == '.' || c == '#')
+                && !after.is_some_and(is_name_char)
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// The offsets of `name` in `text` where it follows `$`.
+fn references(text: &str, name: &str) -> Vec<usize> {
+    text.match_indices(name)
+        .filter(|(i, _)| {
+            text[..*i].ends_with('$')
+                && !text[i + name.len()..]
+                    .chars()
+                    .next()
+                    .is_some_and(is_name_char)
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    fn texts<'a>(source: &'a str, spans: &[ftd_p1::Span]) -> Vec<(usize, &'a str)> {
+        spans
+            .iter()
+            .map(|s| (s.start_position(source).0, &source[s.start..s.end]))
+            .collect()
+    }
+
+    #[test]
+    fn word_at() {
+        let source = "-- ftd.text: $person.name\ncolor: red\n";
+        assert_eq!(super::word_at(source, 5).map(|(_, w)| w), Some("ftd.text"));
+        assert_eq!(
+            super::word_at(source, 16).map(|(_, w)| w),
+            Some("$person.name")
+        );
+        assert_eq!(super::word_at(source, 12).map(|(_, w)| w), None);
+    }
+
+    #[test]
+    fn occurrences() {
+        let source = indoc::indoc! {"
+            ;; a person
+            -- record person:
+            caption name:
+
+            -- person list people:
+
+            -- person:
+            name: Alice
+
+            -- end: people
+
+            -- component show:
+            person p:
+
+            -- ftd.text: $show.p.name
+            color if { $person.name == \"x\" }: red
+
+            -- end: show
+        "};
+        assert_eq!(
+            texts(source, &super::occurrences(source, "person")),
+            vec![
+                (2, "person"),
+                (5, "person"),
+                (7, "person"),
+                (13, "person"),
+                (16, "person"),
+            ]
+        );
+        assert_eq!(
+            texts(source, &super::occurrences(source, "people")),
+            vec![(5, "people"), (10, "people")]
+        );
+    }
+
+    #[test]
+    fn occurrences_shadowed() {
+        let source = indoc::indoc! {"
+            -- string item: Hello
+            -- string list items:
+
+            -- ftd.text: $item
+
+            -- ftd.column:
+            for: $item in $items
+
+            -- ftd.text: $item
+
+            -- end: ftd.column
+
+            -- ftd.text: $item
+            for: item, index in $items
+
+            -- ftd.text: $item
+            match: $result is status.error as $item
+
+            -- component card:
+            string item:
+
+            -- ftd.text: $card.item
+
+            -- end: card
+
+            -- ftd.text: $item
+        "};
+        assert_eq!(
+            texts(source, &super::occurrences(source, "item")),
+            vec![(1, "item"), (4, "item"), (26, "item")]
+        );
+        assert_eq!(
+            texts(source, &super::occurrences(source, "items")),
+            vec![(2, "items"), (7, "items"), (14, "items")]
+        );
+    }
+
+    #[test]
+    fn docs() {
+        let source = "-- ftd.text: hi\n\n;; The name\n;; of the user\n-- string name: Bob\n";
+        assert_eq!(
+            super::docs(source, 5).as_deref(),
+            Some("The name\nof the user")
+        );
+        assert_eq!(super::docs(source, 1), None);
+    }
+}
@@ -0,0 +1,753 @@
+//! `fastn lsp`, a language server for the `.ftd` documents of the package,
+//! over stdio.
+//!
+//! A document is interpreted, with its imports and processors, when it is
+//! opened and when it is saved, not on every change: the processors of a
+//! document can query databases and call http apis. Its errors and warnings
+//! are published as diagnostics, and the things the interpreter found in it
+//! answer hover, go-to-definition, completion and rename. When a document has
+//! errors, the things found the last time it had none are used.
+
+mod analysis;
+mod rpc;
+
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const REQUEST_FAILED: i64 = -32803;
+
+pub async fn lsp(config: &fastn_core::Config) -> fastn_core::Result<()> {
+    let mut server = Server::new(config).await?;
+    let mut reader = tokio::io::BufReader::new(tokio::io::stdin());
+    let mut stdout = tokio::io::stdout();
+    while let Some(message) = rpc::read(&mut reader).await? {
+        for message in server.handle(message).await {
+            rpc::write(&mut stdout, &message).await?;
+        }
+        if server.exited {
+            break;
+        }
+    }
+    Ok(())
+}
+
+struct Server {
+    config: fastn_core::Config,
+    /// the documents of the package, with the text the editor has for the
+    /// open ones
+    documents: std::collections::BTreeMap<String, fastn_core::File>,
+    analyses: std::collections::HashMap<String, analysis::Analysis>,
+    /// the files each document published diagnostics for, cleared when the
+    /// document is interpreted again
+    published: std::collections::HashMap<String, std::collections::HashSet<String>>,
+    encoding: PositionEncoding,
+    exited: bool,
+}
+
+impl Server {
+    async fn new(config: &fastn_core::Config) -> fastn_core::Result<Server> {
+        Ok(Server {
+            config: config.clone(),
+            documents: fastn_core::commands::build::get_documents_for_current_package(config)
+                .await?,
+            analyses: Default::default(),
+            published: Default::default(),
+            encoding: Default::default(),
+            exited: false,
+        })
+    }
+
+    /// The responses and the notifications for `message`.
+    async fn handle(&mut self, message: serde_json::Value) -> Vec<serde_json::Value> {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+        let id = match message.get("id") {
+            Some(id) if !method.is_empty() => id.clone(),
+            // a notification, or a response to a request of ours
+            _ => return self.notify(method, params).await,
+        };
+
+        let result = match method {
+            "initialize" => {
+                self.encoding = PositionEncoding::of_client(params);
+                Ok(serde_json::json!({
+                    "capabilities": {
+                        "positionEncoding": self.encoding.name(),
+                        "textDocumentSync": {
+                            "openClose": true,
+                            // the editor sends the whole text on each change
+                            "change": 1,
+                            "save": { "includeText": false },
+                        },
+                        "hoverProvider": true,
+                        "definitionProvider": true,
+                        "completionProvider": { "triggerCharacters": ["$", "-", "."] },
+                        "renameProvider": true,
+                    },
+                    "serverInfo": { "name": "fastn" },
+                }))
+            }
+            "shutdown" => Ok(serde_json::Value::Null),
+            "textDocument/hover" => self.hover(params).await,
+            "textDocument/definition" => self.definition(params).await,
+            "textDocument/completion" => self.completion(params),
+            "textDocument/rename" => self.rename(params),
+            _ => Err((METHOD_NOT_FOUND, format!("Unknown method `{method}`"))),
+        };
+        vec![match result {
+            Ok(result) => rpc::response(id, result),
+            Err((code, message)) => rpc::error(id, code, message.as_str()),
+        }]
+    }
+
+    async fn notify(&mut self, method: &str, params: &serde_json::Value) -> Vec<serde_json::Value> {
+        let Some(id) = self.document_id(params) else {
+            if method.eq("exit") {
+                self.exited = true;
+            }
+            return vec![];
+        };
+        match method {
+            "textDocument/didOpen" => {
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default();
+                self.set_text(id.as_str(), text);
+                self.analyze(id.as_str()).await
+            }
+            "textDocument/didChange" => {
+                if let Some(text) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                {
+                    self.set_text(id.as_str(), text);
+                }
+                vec![]
+            }
+            "textDocument/didSave" => self.analyze(id.as_str()).await,
+            _ => vec![],
+        }
+    }
+
+    /// The id of the document of `params`, like `foo/bar.ftd`, when it is a
+    /// `.ftd` file of the package.
+    fn document_id(&self, params: &serde_json::Value) -> Option<String> {
+        let path = url::Url::parse(params["textDocument"]["uri"].as_str()?)
+            .ok()?
+            .to_file_path()
+            .ok()?;
+        let id = path
+            .strip_prefix(self.config.ds.root().to_string())
+            .ok()?
+            .to_str()?
+            .replace(std::path::MAIN_SEPARATOR, "/");
+        id.ends_with(".ftd").then_some(id)
+    }
+
+    fn uri(&self, path: &fastn_ds::Path) -> Option<String> {
+        url::Url::from_file_path(path.to_string())
+            .ok()
+            .map(|url| url.to_string())
+    }
+
+    fn text(&self, id: &str) -> Option<&str> {
+        match self.documents.get(id) {
+            Some(fastn_core::File::Ftd(document)) => Some(document.content.as_str()),
+            _ => None,
+        }
+    }
+
+    fn set_text(&mut self, id: &str, text: &str) {
+        match self.documents.get_mut(id) {
+            Some(fastn_core::File::Ftd(document)) => document.content = text.to_string(),
+            _ => {
+                self.documents.insert(
+                    id.to_string(),
+                    fastn_core::File::Ftd(fastn_core::Document {
+                        package_name: self.config.package.name.to_string(),
+                        id: id.to_string(),
+                        content: text.to_string(),
+                        parent_path: self.config.ds.root(),
+                    }),
+                );
+            }
+        }
+    }
+
+    /// Interprets the document `id` and publishes its errors.
+    async fn analyze(&mut self, id: &str) -> Vec<serde_json::Value> {
+        let Some(fastn_core::File::Ftd(document)) = self.documents.get(id).cloned() else {
+            return vec![];
+        };
+        let mut diagnostics = fastn_core::Diagnostics::new(&self.documents);
+        match self.interpret(&document).await {
+            Ok(analysis) => {
+                diagnostics.warn(id, &analysis.document.warnings);
+                self.analyses.insert(id.to_string(), analysis);
+            }
+            Err(e) => diagnostics.add(id, e),
+        }
+
+        // the errors of each file, with the ones not in a file of the package
+        // on the first line of the document
+        let mut files: std::collections::BTreeMap<String, Vec<serde_json::Value>> =
+            Default::default();
+        for diagnostic in diagnostics.diagnostics.iter() {
+            let (uri, diagnostic) = match self.documents.get(diagnostic.file.as_str()) {
+                Some(fastn_core::File::Ftd(file)) => (
+                    self.uri(&file.get_full_path()),
+                    self.encoding.diagnostic(
+                        file.content.as_str(),
+                        diagnostic,
+                        diagnostic.message.to_string(),
+                    ),
+                ),
+                _ => (
+                    self.uri(&document.get_full_path()),
+                    self.encoding.diagnostic(
+                        "",
+                        &fastn_core::Diagnostic {
+                            line_number: 0,
+                            ..diagnostic.clone()
+                        },
+                        format!("{}: {}", diagnostic.file, diagnostic.message),
+                    ),
+                ),
+            };
+            if let Some(uri) = uri {
+                files.entry(uri).or_default().push(diagnostic);
+            }
+        }
+
+        let previous = self.published.remove(id).unwrap_or_default();
+        for uri in previous {
+            files.entry(uri).or_default();
+        }
+        self.published.insert(
+            id.to_string(),
+            files
+                .iter()
+                .filter(|(_, diagnostics)| !diagnostics.is_empty())
+                .map(|(uri, _)| uri.to_string())
+                .collect(),
+        );
+        files
+            .into_iter()
+            .map(|(uri, diagnostics)| {
+                rpc::notification(
+                    "textDocument/publishDiagnostics",
+                    serde_json::json!({ "uri": uri, "diagnostics": diagnostics }),
+                )
+            })
+            .collect()
+    }
+
+    /// The things in `document`, interpreted the way `fastn serve` does.
+    async fn interpret(
+        &self,
+        document: &fastn_core::Document,
+    ) -> fastn_core::Result<analysis::Analysis> {
+        let req = fastn_core::http::Request::default();
+        let mut req_config =
+            fastn_core::RequestConfig::new(&self.config, &req, document.id.as_str(), "/");
+        req_config.current_document = Some(document.id.to_string());
+
+        let package = self
+            .config
+            .find_package_else_default(document.package_name.as_str(), None);
+        let content =
+            package.get_prefixed_body(document.content.as_str(), document.id.as_str(), true);
+        let content = package.fix_imports_in_body(content.as_str(), document.id.as_str())?;
+        let line_number = content.split('\n').count() - document.content.split('\n').count();
+        let document = fastn_core::doc::interpret_helper(
+            document.id_with_package().as_str(),
+            content.as_str(),
+            &mut req_config,
+            "/",
+            false,
+            line_number,
+            &None,
+        )
+        .await?;
+        Ok(analysis::Analysis {
+            document,
+            module_files: req_config.module_files,
+        })
+    }
+
+    /// The open document, its text and the offset of the position, of
+    /// `params`.
+    fn position<'a>(
+        &'a self,
+        params: &serde_json::Value,
+    ) -> Result<(String, &'a str, usize), (i64, String)> {
+        let invalid = || (INVALID_PARAMS, "Not a document of the package".to_string());
+        let id = self.document_id(params).ok_or_else(invalid)?;
+        let text = self.text(id.as_str()).ok_or_else(invalid)?;
+        let offset = self.encoding.offset(
+            text,
+            params["position"]["line"].as_u64().unwrap_or_default() as usize,
+            params["position"]["character"].as_u64().unwrap_or_default() as usize,
+        );
+        Ok((id, text, offset))
+    }
+
+    /// The location of `target`, in the open document when it is the
+    /// document, else in the file of its module.
+    async fn location(
+        &self,
+        id: &str,
+        target: &analysis::Target,
+    ) -> Option<(String, String, ftd_p1::Span)> {
+        let analysis = self.analyses.get(id)?;
+        let path = if target.module.eq(analysis.module()) {
+            match self.documents.get(id)? {
+                fastn_core::File::Ftd(document) => document.get_full_path(),
+                _ => return None,
+            }
+        } else {
+            analysis.module_files.get(target.module.as_str())?.clone()
+        };
+        let source = match self
+            .documents
+            .values()
+            .find(|file| file.get_full_path().eq(&path))
+        {
+            Some(fastn_core::File::Ftd(document)) => document.content.to_string(),
+            _ => self.config.ds.read_to_string(&path, &None).await.ok()?,
+        };
+        let span = analysis::definition_span(source.as_str(), target.line_number)
+            .or_else(|| ftd_p1::Span::of_line(source.as_str(), target.line_number))?;
+        Some((self.uri(&path)?, source, span))
+    }
+
+    async fn hover(&self, params: &serde_json::Value) -> Result<serde_json::Value, (i64, String)> {
+        let (id, text, offset) = self.position(params)?;
+        let Some((hover, target)) = self
+            .analyses
+            .get(id.as_str())
+            .and_then(|analysis| analysis.hover(text, offset))
+        else {
+            return Ok(serde_json::Value::Null);
+        };
+        let docs = self
+            .location(id.as_str(), &target)
+            .await
+            .and_then(|(_, source, span)| {
+                analysis::docs(source.as_str(), span.start_position(source.as_str()).0)
+            });
+        Ok(serde_json::json!({
+            "contents": {
+                "kind": "markdown",
+                "value": match docs {
+                    Some(docs) => format!("{hover}\n\n{docs}"),
+                    None => hover,
+                },
+            },
+        }))
+    }
+
+    async fn definition(
+        &self,
+        params: &serde_json::Value,
+    ) -> Result<serde_json::Value, (i64, String)> {
+        let (id, text, offset) = self.position(params)?;
+        let Some(target) = self
+            .analyses
+            .get(id.as_str())
+            .and_then(|analysis| analysis.definition(text, offset))
+        else {
+            return Ok(serde_json::Value::Null);
+        };
+        Ok(match self.location(id.as_str(), &target).await {
+            Some((uri, source, span)) => serde_json::json!({
+                "uri": uri,
+                "range": self.encoding.range(source.as_str(), span),
+            }),
+            None => serde_json::Value::Null,
+        })
+    }
+
+    fn completion(&self, params: &serde_json::Value) -> Result<serde_json::Value, (i64, String)> {
+        let (id, text, offset) = self.position(params)?;
+        let Some(analysis) = self.analyses.get(id.as_str()) else {
+            return Ok(serde_json::json!([]));
+        };
+        let (span, completions) = analysis.completions(text, offset);
+        Ok(serde_json::Value::Array(
+            completions
+                .into_iter()
+                .map(|completion| {
+                    serde_json::json!({
+                        "label": completion.label,
+                        "kind": completion.kind,
+                        "detail": completion.detail,
+                        "textEdit": {
+                            "range": self.encoding.range(text, span),
+                            "newText": completion.insert,
+                        },
+                    })
+                })
+                .collect(),
+        ))
+    }
+
+    fn rename(&self, params: &serde_json::Value) -> Result<serde_json::Value, (i64, String)> {
+        let (id, text, offset) = self.position(params)?;
+        let new_name = params["newName"].as_str().unwrap_or_default();
+        if new_name.is_empty()
+            || !new_name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err((INVALID_PARAMS, format!("`{new_name}` is not a valid name")));
+        }
+        let analysis = self.analyses.get(id.as_str()).ok_or_else(|| {
+            (
+                REQUEST_FAILED,
+                "The document has not been interpreted without errors yet".to_string(),
+            )
+        })?;
+        let spans = analysis
+            .rename(text, offset)
+            .map_err(|message| (REQUEST_FAILED, message))?;
+        let uri = match self.documents.get(id.as_str()) {
+            Some(file) => self.uri(&file.get_full_path()),
+            None => None,
+        }
+        .ok_or_else(|| (INVALID_PARAMS, "Not a document of the package".to_string()))?;
+        let edits = spans
+            .into_iter()
+            .map(|span| {
+                serde_json::json!({ "range": self.encoding.range(text, span), "newText": new_name })
+            })
+            .collect::<Vec<_>>();
+        let changes = serde_json::Map::from_iter([(uri, serde_json::Value::Array(edits))]);
+        Ok(serde_json::json!({ "changes": changes }))
+    }
+}
+
+/// How the characters of a line are counted in the positions of the protocol,
+/// the one the editor prefers of the ones it supports. Editors that do not
+/// say count UTF-16 code units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum PositionEncoding {
+    Utf8,
+    #[default]
+    Utf16,
+    Utf32,
+}
+
+impl PositionEncoding {
+    fn of_client(params: &serde_json::Value) -> PositionEncoding {
+        params["capabilities"]["general"]["positionEncodings"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find_map(|encoding| match encoding.as_str()? {
+                "utf-8" => Some(PositionEncoding::Utf8),
+                "utf-16" => Some(PositionEncoding::Utf16),
+                "utf-32" => Some(PositionEncoding::Utf32),
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            PositionEncoding::Utf8 => "utf-8",
+            PositionEncoding::Utf16 => "utf-16",
+            PositionEncoding::Utf32 => "utf-32",
+        }
+    }
+
+    /// How many characters `c` is.
+    fn len(&self, c: char) -> usize {
+        match self {
+            PositionEncoding::Utf8 => c.len_utf8(),
+            PositionEncoding::Utf16 => c.len_utf16(),
+            PositionEncoding::Utf32 => 1,
+        }
+    }
+
+    /// The byte offset of the `line` and `character`, both starting at 0, of
+    /// `source`.
+    fn offset(&self, source: &str, line: usize, character: usize) -> usize {
+        let line_start = match line {
+            0 => 0,
+            line => match source.match_indices('\n').nth(line - 1) {
+                Some((index, _)) => index + 1,
+                None => return source.len(),
+            },
+        };
+        let line_text = source[line_start..].split('\n').next().unwrap_or_default();
+        let mut characters = 0;
+        for (index, c) in line_text.char_indices() {
+            if characters >= character {
+                return line_start + index;
+            }
+            characters += self.len(c);
+        }
+        line_start + line_text.len()
+    }
+
+    /// The LSP position of the byte offset `offset` of `source`.
+    fn position(&self, source: &str, offset: usize) -> serde_json::Value {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        serde_json::json!({
+            "line": before.matches('\n').count(),
+            "character": before[line_start..].chars().map(|c| self.len(c)).sum::<usize>(),
+        })
+    }
+
+    /// The LSP range of `span`.
+    fn range(&self, source: &str, span: ftd_p1::Span) -> serde_json::Value {
+        serde_json::json!({
+            "start": self.position(source, span.start),
+            "end": self.position(source, span.end),
+        })
+    }
+
+    /// The LSP diagnostic of `diagnostic`, an error of `source`.
+    fn diagnostic(
+        &self,
+        source: &str,
+        diagnostic: &fastn_core::Diagnostic,
+        message: String,
+    ) -> serde_json::Value {
+        // lines of a diagnostic start at 1, and are 0 when it is not on a
+        // line
+        let line =
+            |line: usize| serde_json::json!({ "line": line.saturating_sub(1), "character": 0 });
+        let range = match (diagnostic.line_number, diagnostic.span) {
+            (0, _) => serde_json::json!({ "start": line(0), "end": line(0) }),
+            (_, Some(span)) => self.range(source, span),
+            (line_number, None) => serde_json::json!({
+                "start": line(line_number),
+                "end": line(diagnostic.end_line_number),
+            }),
+        };
+        serde_json::json!({
+            "range": range,
+            "severity": match diagnostic.severity {
+                fastn_core::Severity::Error => 1,
+                fastn_core::Severity::Warning => 2,
+            },
+            "code": diagnostic.code,
+            "source": "fastn",
+            "message": match diagnostic.help {
+                Some(ref help) => format!("{message}\nhelp: {help}"),
+                None => message,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // `𝕏` is one char, and two UTF-16 code units
+    const INDEX: &str = indoc::indoc! {"
+        -- string greeting: Hello
+
+        ;; who is greeted
+        -- string 𝕏-name: World
+
+        -- ftd.text: $greeting
+
+        -- ftd.text: $𝕏-name
+    "};
+
+    /// Runs `test` on a thread with the stack of the main thread, like
+    /// `fastn lsp`: interpreting a document needs more than a test has.
+    fn run<F: std::future::Future<Output = ()>>(test: impl FnOnce() -> F + Send + 'static) {
+        std::thread::Builder::new()
+            .stack_size(8 * 1024 * 1024)
+            .spawn(|| {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .unwrap()
+                    .block_on(test())
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    /// A server for the package `foo`, initialized with the capabilities
+    /// `capabilities`, with its response.
+    async fn initialize(
+        name: &str,
+        capabilities: serde_json::Value,
+    ) -> (super::Server, serde_json::Value, String) {
+        let root = std::env::temp_dir().join(format!("fastn-lsp-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(root.join(".fastn")).unwrap();
+        std::fs::write(
+            root.join("FASTN.ftd"),
+            "-- import: fastn\n\n-- fastn.package: foo\n",
+        )
+        .unwrap();
+        // what `fastn update` writes for a package without dependencies
+        std::fs::write(
+            root.join(".fastn/config.json"),
+            r#"{"package": "foo", "all_packages": {}}"#,
+        )
+        .unwrap();
+        std::fs::write(root.join("index.ftd"), INDEX).unwrap();
+        let ds = fastn_ds::DocumentStore::new(root.to_str().unwrap(), Default::default());
+        let config = fastn_core::Config::read(ds, false, &None).await.unwrap();
+        let mut server = super::Server::new(&config).await.unwrap();
+
+        let mut response = server
+            .handle(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "initialize",
+                "params": { "capabilities": capabilities },
+            }))
+            .await;
+        let uri = url::Url::from_file_path(root.join("index.ftd"))
+            .unwrap()
+            .to_string();
+        (server, response.remove(0), uri)
+    }
+
+    /// The notifications of `method`, with the text `text` of `index.ftd`.
+    async fn notify(
+        server: &mut super::Server,
+        uri: &str,
+        method: &str,
+        text: &str,
+    ) -> Vec<serde_json::Value> {
+        server
+            .handle(serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": method,
+                "params": {
+                    "textDocument": { "uri": uri, "languageId": "ftd", "version": 1, "text": text },
+                    "contentChanges": [{ "text": text }],
+                },
+            }))
+            .await
+    }
+
+    /// The result of the request `method` at `line` and `character`.
+    async fn request(
+        server: &mut super::Server,
+        uri: &str,
+        method: &str,
+        (line, character): (usize, usize),
+    ) -> serde_json::Value {
+        let mut response = server
+            .handle(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 2,
+                "method": method,
+                "params": {
+                    "textDocument": { "uri": uri },
+                    "position": { "line": line, "character": character },
+                    "newName": "who",
+                },
+            }))
+            .await;
+        response.remove(0)["result"].take()
+    }
+
+    fn range((line, start): (usize, usize), end: usize) -> serde_json::Value {
+        serde_json::json!({
+            "start": { "line": line, "character": start },
+            "end": { "line": line, "character": end },
+        })
+    }
+
+    /// `len` is how many characters `𝕏-name` is in the encoding the editor
+    /// uses.
+    async fn session(name: &str, capabilities: serde_json::Value, encoding: &str, len: usize) {
+        let (mut server, response, uri) = initialize(name, capabilities).await;
+        assert_eq!(
+            response["result"]["capabilities"]["positionEncoding"],
+            encoding
+        );
+
+        // nothing is published for a document without errors
+        assert_eq!(
+            notify(&mut server, uri.as_str(), "textDocument/didOpen", INDEX).await,
+            Vec::<serde_json::Value>::new()
+        );
+
+        // the last character of `$𝕏-name`
+        let hover = request(
+            &mut server,
+            uri.as_str(),
+            "textDocument/hover",
+            (7, 13 + len),
+        )
+        .await;
+        assert_eq!(
+            hover["contents"]["value"],
+            "```ftd\n-- string 𝕏-name:\n```\n\nwho is greeted"
+        );
+
+        let rename = request(
+            &mut server,
+            uri.as_str(),
+            "textDocument/rename",
+            (7, 13 + len),
+        )
+        .await;
+        assert_eq!(
+            rename["changes"][uri.as_str()],
+            serde_json::json!([
+                { "range": range((3, 10), 10 + len), "newText": "who" },
+                { "range": range((7, 14), 14 + len), "newText": "who" },
+            ])
+        );
+
+        // the errors are published when the document is saved
+        let text = INDEX.replace("$𝕏-name", "$𝕏-nam");
+        notify(
+            &mut server,
+            uri.as_str(),
+            "textDocument/didChange",
+            text.as_str(),
+        )
+        .await;
+        let published = notify(
+            &mut server,
+            uri.as_str(),
+            "textDocument/didSave",
+            text.as_str(),
+        )
+        .await;
+        assert_eq!(
+            published,
+            vec![serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "textDocument/publishDiagnostics",
+                "params": {
+                    "uri": uri,
+                    "diagnostics": [{
+                        // under `$𝕏-nam`
+                        "range": range((7, 13), 13 + len),
+                        "severity": 1,
+                        "code": "FTD0201",
+                        "source": "fastn",
+                        "message": "foo/: not found (\"foo#𝕏-nam\"), f: search_thing",
+                    }],
+                },
+            })]
+        );
+    }
+
+    #[test]
+    fn utf16() {
+        run(|| session("utf16", serde_json::json!({}), "utf-16", 7));
+    }
+
+    #[test]
+    fn utf32() {
+        let capabilities = serde_json::json!({
+            "general": { "positionEncodings": ["utf-32", "utf-16"] },
+        });
+        run(|| session("utf32", capabilities, "utf-32", 6));
+    }
+}
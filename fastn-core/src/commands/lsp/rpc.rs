@@ -0,0 +1,62 @@
+/// Reads a JSON-RPC message, the `Content-Length` header followed by the
+/// JSON body. `None` when the client closed the input.
+pub(crate) async fn read<R>(reader: &mut R) -> fastn_core::Result<Option<serde_json::Value>>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt};
+
+    let mut length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                length = Some(value.trim().parse::<usize>()?);
+            }
+        }
+    }
+
+    let length = length.ok_or_else(|| {
+        fastn_core::Error::GenericError("LSP message without Content-Length".to_string())
+    })?;
+    let mut content = vec![0; length];
+    reader.read_exact(&mut content).await?;
+    Ok(Some(serde_json::from_slice(&content)?))
+}
+
+pub(crate) async fn write<W>(writer: &mut W, message: &serde_json::Value) -> fastn_core::Result<()>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let content = serde_json::to_string(message)?;
+    writer
+        .write_all(format!("Content-Length: {}\r\n\r\n{content}", content.len()).as_bytes())
+        .await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+pub(crate) fn response(id: serde_json::Value, result: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+pub(crate) fn error(id: serde_json::Value, code: i64, message: &str) -> serde_json::Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+pub(crate) fn notification(method: &str, params: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
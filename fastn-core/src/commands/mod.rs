@@ -1,6 +1,7 @@
 pub mod build;
 pub mod check;
 pub mod fmt;
+pub mod lsp;
 pub mod query;
 pub mod serve;
 pub mod test;
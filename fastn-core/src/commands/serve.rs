@@ -17,7 +17,7 @@ fn handle_redirect(
 async fn serve_file(
     config: &mut fastn_core::RequestConfig,
     path: &camino::Utf8Path,
-    only_js: bool,
+    output: fastn_core::package::package_doc::Output,
     preview_session_id: &Option<String>,
 ) -> fastn_core::http::Response {
     if let Err(e) = config
@@ -67,6 +67,15 @@ async fn serve_file(
 
     let main_document = match f {
         fastn_core::File::Ftd(main_document) => main_document,
+        fastn_core::File::Markdown(page) => {
+            match fastn_core::markdown_page::ftd_document(&config.config, &page) {
+                Ok(main_document) => main_document,
+                Err(e) => {
+                    tracing::error!(msg = "markdown page error", path = path.as_str(), error = %e);
+                    return fastn_core::server_error!("{}", e);
+                }
+            }
+        }
         _ => {
             tracing::error!(msg = "unknown handler", path = path.as_str());
             tracing::info!("file: {f:?}");
@@ -80,12 +89,20 @@ async fn serve_file(
         "/",
         false,
         false,
-        only_js,
+        output,
         preview_session_id,
     )
     .await
     {
         Ok(val) => match val {
+            fastn_core::package::package_doc::FTDResult::Html(body)
+                if output == fastn_core::package::package_doc::Output::SpaPayload =>
+            {
+                fastn_core::http::ok_with_content_type(
+                    body,
+                    mime_guess::mime::APPLICATION_JAVASCRIPT_UTF_8,
+                )
+            }
             fastn_core::package::package_doc::FTDResult::Html(body) => {
                 fastn_core::http::ok_with_content_type(body, mime_guess::mime::TEXT_HTML_UTF_8)
             }
@@ -110,7 +127,27 @@ async fn serve_file(
                 path = path.as_str(),
                 error = e.to_string()
             );
-            fastn_core::server_error!("fastn-Error: path: {}, {:?}", path, e)
+            let mut diagnostics =
+                fastn_core::Diagnostics::new(&std::collections::BTreeMap::from([(
+                    main_document.id.to_string(),
+                    fastn_core::File::Ftd(main_document.clone()),
+                )]));
+            diagnostics.add(main_document.id.as_str(), e);
+            diagnostics.diagnostics.sort();
+            let text = diagnostics
+                .diagnostics
+                .iter()
+                .map(|d| diagnostics.render(d))
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            fastn_core::warning!("server error: path: {}\n{}", path, text);
+            actix_web::HttpResponse::InternalServerError()
+                .content_type(mime_guess::mime::TEXT_HTML_UTF_8)
+                .body(fastn_core::utils::error_page_html(
+                    path.as_str(),
+                    text.as_str(),
+                    &diagnostics.to_json(),
+                ))
         }
     }
 }
@@ -164,10 +201,23 @@ pub fn clear_sid2(req: &fastn_core::http::Request) -> fastn_core::http::Response
 #[tracing::instrument(skip_all)]
 pub async fn serve(
     config: &fastn_core::Config,
-    req: fastn_core::http::Request,
+    mut req: fastn_core::http::Request,
     only_js: bool,
     preview_session_id: &Option<String>,
 ) -> fastn_core::Result<(fastn_core::http::Response, bool)> {
+    let mut output = if only_js {
+        fastn_core::package::package_doc::Output::Js
+    } else {
+        fastn_core::package::package_doc::Output::Html
+    };
+    if let Some(page) = spa_page_path(req.path()).filter(|_| config.package.spa) {
+        // the page is rendered for its own url, so that processors reading the
+        // request path see the page and not the payload
+        req.uri = req.uri.replacen(fastn_core::SPA_PAYLOAD_FILE, "", 1);
+        req.path = page;
+        output = fastn_core::package::package_doc::Output::SpaPayload;
+    }
+
     let mut req_config = fastn_core::RequestConfig::new(config, &req, "", "/");
 
     if req.path() == "/-/auth/logout/" {
@@ -203,20 +253,28 @@ pub async fn serve(
         .map(|r| (r, true));
     }
 
-    serve_helper(&mut req_config, only_js, path, preview_session_id)
+    serve_helper(&mut req_config, output, path, preview_session_id)
         .await
         .map(|r| (r, req_config.response_is_cacheable))
 }
 
+/// `/<page>/index.spa.js` is the js of `/<page>/` for client-side navigation,
+/// only served if the package has `spa` turned on.
+fn spa_page_path(path: &str) -> Option<String> {
+    path.strip_suffix(fastn_core::SPA_PAYLOAD_FILE)
+        .filter(|page| page.ends_with('/'))
+        .map(ToString::to_string)
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn serve_helper(
     req_config: &mut fastn_core::RequestConfig,
-    only_js: bool,
+    output: fastn_core::package::package_doc::Output,
     path: camino::Utf8PathBuf,
     preview_session_id: &Option<String>,
 ) -> fastn_core::Result<fastn_core::http::Response> {
     let mut resp = if req_config.request.path() == "/" {
-        serve_file(req_config, &path.join("/"), only_js, preview_session_id).await
+        serve_file(req_config, &path.join("/"), output, preview_session_id).await
     } else {
         // url is present in config or not
         // If not present than proxy pass it
@@ -268,7 +326,7 @@ pub async fn serve_helper(
         }
 
         let file_response =
-            serve_file(req_config, path.as_path(), only_js, preview_session_id).await;
+            serve_file(req_config, path.as_path(), output, preview_session_id).await;
 
         tracing::info!(
             "before executing proxy: file-status: {}, path: {}",
@@ -592,6 +650,8 @@ async fn handle_endpoints(
 
     tracing::info!("url: {}", url);
 
+    // wasm routes are answered whole, `text/event-stream` ones too, the browser
+    // then asks again after the `retry` of the stream
     if url.starts_with("wasm+proxy://") {
         let app_mounts = match config.app_mounts() {
             Err(e) => return Some(Err(e)),
@@ -610,7 +670,7 @@ async fn handle_endpoints(
 
     let response = match config
         .ds
-        .http(
+        .http_stream(
             url::Url::parse(url.as_str()).unwrap(),
             req,
             &std::collections::HashMap::new(),
@@ -622,6 +682,19 @@ async fn handle_endpoints(
         Err(e) => return Some(Err(e)),
     };
 
+    // `text/event-stream` responses never finish, they are passed on to the
+    // client as they arrive, e.g. for `$sse$` variables.
+    if fastn_core::http::ResponseBuilder::is_event_stream(&response) {
+        return Some(Ok(fastn_core::http::ResponseBuilder::from_reqwest_stream(
+            response,
+        )));
+    }
+
+    let response = match fastn_ds::reqwest_util::to_http_response(response).await {
+        Ok(response) => response,
+        Err(e) => return Some(Err(fastn_core::Error::DSHttpError(e.into()))),
+    };
+
     let actix_response = fastn_core::http::ResponseBuilder::from_reqwest(response).await;
     Some(Ok(actix_response))
 }
@@ -748,3 +821,19 @@ You can try without providing port, it will automatically pick unused port."#,
         .await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn spa_page_path() {
+        assert_eq!(
+            super::spa_page_path("/blog/post/index.spa.js"),
+            Some("/blog/post/".to_string())
+        );
+        assert_eq!(super::spa_page_path("/index.spa.js"), Some("/".to_string()));
+        assert_eq!(super::spa_page_path("/blog/post/"), None);
+        assert_eq!(super::spa_page_path("/blog/post/index.html"), None);
+        // only a file of its own, not a page named like it
+        assert_eq!(super::spa_page_path("/blog/myindex.spa.js"), None);
+    }
+}
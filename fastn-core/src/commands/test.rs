@@ -34,7 +34,7 @@ macro_rules! log_message {
     // When verbose is true, print message
     ($verbose:expr, $($message:expr),*) => {
         if $verbose {
-            $(std::println!($message);)*
+            $(fastn_core::progress!("{}\n", $message);)*
         }
     };
 }
@@ -76,6 +76,12 @@ pub async fn test(
         );
     }
     let ftd_documents = config.get_test_files().await?;
+    let mut diagnostics = fastn_core::Diagnostics::new(
+        &ftd_documents
+            .iter()
+            .map(|d| (d.id.to_string(), fastn_core::File::Ftd(d.clone())))
+            .collect::<std::collections::BTreeMap<_, _>>(),
+    );
 
     for document in ftd_documents {
         if let Some(id) = only_id {
@@ -84,11 +90,15 @@ pub async fn test(
             }
         }
         let mut test_parameters = TestParameters::new(script, verbose);
-        println!("Running test file: {}", document.id.magenta());
-        read_ftd_test_file(document, config, &mut test_parameters).await?;
+        fastn_core::progress!("Running test file: {}\n", document.id.magenta());
+        let id = document.id.to_string();
+        if let Err(e) = read_ftd_test_file(document, config, &mut test_parameters).await {
+            diagnostics.add(id.as_str(), e);
+            break;
+        }
     }
 
-    Ok(())
+    diagnostics.finish(config.message_format)
 }
 
 impl fastn_core::Config {
@@ -340,7 +350,7 @@ async fn get_instructions_from_test(
     let property_values = instruction.get_interpreter_property_value_of_all_arguments(doc)?;
 
     if let Some(title) = get_optional_value_string(TEST_TITLE_HEADER, &property_values, doc)? {
-        println!("Test: {}", title);
+        fastn_core::progress!("Test: {}\n", title);
     }
 
     let fixtures =
@@ -480,7 +490,7 @@ async fn get_post_response_for_id(
     use actix_web::body::MessageBody;
     use colored::Colorize;
 
-    println!("Test: {}", title.yellow());
+    fastn_core::progress!("Test: {}\n", title.yellow());
     log_message!(test_parameters.verbose, "Test type: GET");
     log_variable!(test_parameters.verbose, &test_parameters.script);
 
@@ -579,18 +589,18 @@ async fn get_post_response_for_id(
                 &config.ds,
             )
             .await;
-            println!("{}", "Script file created".green());
+            fastn_core::progress!("{}\n", "Script file created".green());
             return Ok(true);
         }
 
         let test_result = fastn_js::run_test(test_string.as_str())?;
 
         if test_result.iter().any(|v| !(*v)) {
-            println!("{}", "Test Failed".red());
+            fastn_core::progress!("{}\n", "Test Failed".red());
             return Ok(false);
         }
     }
-    println!("{}", "Test Passed".green());
+    fastn_core::progress!("{}\n", "Test Passed".green());
     Ok(true)
 }
 
@@ -704,7 +714,7 @@ async fn get_js_for_id(
     use actix_web::body::MessageBody;
     use colored::Colorize;
 
-    println!("Test: {}", title.yellow());
+    fastn_core::progress!("Test: {}\n", title.yellow());
     log_message!(test_parameters.verbose, "Test type: GET");
     log_variable!(test_parameters.verbose, &test_parameters.script);
 
@@ -788,16 +798,16 @@ async fn get_js_for_id(
                 &config.ds,
             )
             .await;
-            println!("{}", "Script file created".green());
+            fastn_core::progress!("{}\n", "Script file created".green());
             return Ok(true);
         }
         let test_result = fastn_js::run_test(test_string.as_str())?;
         if test_result.iter().any(|v| !(*v)) {
-            println!("{}", "Test Failed".red());
+            fastn_core::progress!("{}\n", "Test Failed".red());
             return Ok(false);
         }
     }
-    println!("{}", "Test Passed".green());
+    fastn_core::progress!("{}\n", "Test Passed".green());
     Ok(true)
 }
 
@@ -166,6 +166,7 @@ impl ConfigTemp {
                     implements: vec![],
                     provided_via: None,
                     required_as: None,
+                    icons: None,
                 });
                 current_package.auto_import.push(fastn_core::AutoImport {
                     path: provided_via.to_string(),
@@ -40,6 +40,7 @@ pub struct Config {
     pub ftd_external_css: Vec<String>,
     pub ftd_inline_css: Vec<String>,
     pub test_command_running: bool,
+    pub message_format: fastn_core::MessageFormat,
 }
 
 #[derive(Debug, Clone)]
@@ -63,6 +64,18 @@ pub struct RequestConfig {
     pub processor_set_response: Option<ft_sys_shared::Request>,
     /// we use this to determine if the response is cacheable or not
     pub response_is_cacheable: bool,
+    /// The `/-/` suffixes of the other pages of the paginated `collection`s of
+    /// the document, `fastn build` builds them too
+    pub listing_pages: Vec<String>,
+    /// The file each module imported while rendering is read from, so
+    /// `fastn lsp` can go to the definitions of the things in them
+    pub module_files: std::collections::BTreeMap<String, fastn_ds::Path>,
+    /// The warnings of the documents interpreted while rendering, `fastn
+    /// check` reports them with the errors
+    pub warnings: Vec<ftd::interpreter::Warning>,
+    /// The markup fastn rendered while interpreting, like the MathML of
+    /// math, the only markup in text the page shows as is
+    pub trusted_markup: Vec<String>,
 }
 
 impl RequestConfig {
@@ -97,6 +110,10 @@ impl RequestConfig {
             processor_set_cookies: Default::default(),
             processor_set_response: None,
             response_is_cacheable: true,
+            listing_pages: vec![],
+            warnings: vec![],
+            trusted_markup: vec![],
+            module_files: Default::default(),
         }
     }
 
@@ -446,7 +463,7 @@ impl Config {
                     continue;
                 }
                 let start = std::time::Instant::now();
-                print!("Processing {} ... ", url);
+                fastn_core::progress!("Processing {} ... ", url);
                 let content = self.get_file_and_resolve(url.as_str(), session_id).await?.1;
                 fastn_core::utils::update(
                     &self.build_dir().join(&url),
@@ -866,6 +883,12 @@ impl Config {
             {
                 return Ok(format!("{}README.md", add_packages));
             }
+            if ds
+                .exists(&root.join(format!("{}index.md", add_packages)), session_id)
+                .await
+            {
+                return Ok(format!("{}index.md", add_packages));
+            }
             return Err(fastn_core::Error::UsageError {
                 message: "File not found".to_string(),
             });
@@ -904,6 +927,15 @@ impl Config {
         {
             return Ok(format!("{}{}/README.md", add_packages, id));
         }
+        if ds
+            .exists(
+                &root.join(format!("{}{}/index.md", add_packages, id)),
+                session_id,
+            )
+            .await
+        {
+            return Ok(format!("{}{}/index.md", add_packages, id));
+        }
         Err(fastn_core::Error::UsageError {
             message: "File not found".to_string(),
         })
@@ -1006,6 +1038,17 @@ impl Config {
         config
     }
 
+    pub fn add_message_format(self, message_format: Option<&str>) -> fastn_core::Result<Self> {
+        match message_format {
+            Some(f) => {
+                let mut config = self;
+                config.message_format = fastn_core::MessageFormat::from_string(f)?;
+                Ok(config)
+            }
+            None => Ok(self),
+        }
+    }
+
     pub fn set_test_command_running(self) -> Self {
         let mut config = self;
         config.test_command_running = true;
@@ -1037,11 +1080,14 @@ impl Config {
             ftd_external_css: Default::default(),
             ftd_inline_css: Default::default(),
             test_command_running: false,
+            message_format: Default::default(),
             ds,
         };
         // Update global_ids map from the current package files
         // config.update_ids_from_package().await?;
 
+        fastn_core::highlighting::load_package_highlighting(&config, session_id).await?;
+
         // TODO: Major refactor, while parsing sitemap of a package why do we need config in it?
         config.package.sitemap = {
             let sitemap = match package.translation_of.as_ref() {
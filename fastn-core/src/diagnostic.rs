@@ -0,0 +1,518 @@
+/// How the errors of a package are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageFormat {
+    /// the lines of the files the errors are about, for people to read
+    #[default]
+    Human,
+    /// one JSON object for each error, for editors and CI to read
+    Json,
+}
+
+impl MessageFormat {
+    pub(crate) fn from_string(s: &str) -> fastn_core::Result<MessageFormat> {
+        match s {
+            "human" => Ok(MessageFormat::Human),
+            "json" => Ok(MessageFormat::Json),
+            t => fastn_core::usage_error(format!(
+                "Unknown message format `{}`. Help use `human` or `json` instead",
+                t
+            )),
+        }
+    }
+}
+
+/// Whether a diagnostic fails the build, or only points at something that is
+/// likely a mistake.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// An error, or a warning, in a file of the package.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Diagnostic {
+    pub file: String,
+    /// `0` when the error is not on a line.
+    pub line_number: usize,
+    /// `0` when the error is not on a line.
+    pub column: usize,
+    pub end_line_number: usize,
+    pub end_column: usize,
+    /// the code of the error, explained by `fastn explain <code>`
+    pub code: Option<String>,
+    pub message: String,
+    /// the text the error is about, in the source of `file`
+    pub span: Option<ftd_p1::Span>,
+    /// the other texts the error is about, with the file they are in
+    pub labels: Vec<(String, ftd_p1::Span, String)>,
+    pub help: Option<String>,
+    /// the text to put in place of `span` to fix the error
+    pub suggestion: Option<String>,
+    pub severity: Severity,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line_number, self.column) {
+            (0, _) => write!(f, "{}: {}", self.file, self.message),
+            (line_number, 0) => write!(f, "{}:{line_number}: {}", self.file, self.message),
+            (line_number, column) => {
+                write!(f, "{}:{line_number}:{column}: {}", self.file, self.message)
+            }
+        }
+    }
+}
+
+/// What an error says about where it is, before it is found in the files.
+struct Found {
+    doc_id: Option<String>,
+    line_number: Option<usize>,
+    /// what the error is about, in the document of the error
+    span: Option<ftd_p1::Span>,
+    labels: Vec<ftd_p1::Label>,
+    help: Option<String>,
+    suggestion: Option<String>,
+    code: Option<String>,
+    message: String,
+    severity: Severity,
+}
+
+impl Found {
+    /// One for each of the errors in `error`.
+    fn from_error<E: ftd_p1::Reportable>(error: E) -> Vec<Found> {
+        error
+            .into_errors()
+            .iter()
+            .map(|e| Found {
+                doc_id: e.doc_id().map(str::to_string),
+                line_number: e.line_number(),
+                span: e.span(),
+                labels: e.labels().to_vec(),
+                help: e.help().map(str::to_string),
+                suggestion: e.suggestion().map(str::to_string),
+                code: Some(e.code().to_string()),
+                message: e.message(),
+                severity: Severity::Error,
+            })
+            .collect()
+    }
+}
+
+/// The errors of the documents of a package, so a build or a check reports
+/// all of them at the end instead of stopping at the first.
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    /// file of each module of the package, like `foo/bar.ftd` for
+    /// `<package-name>/foo/bar`
+    files: std::collections::HashMap<String, String>,
+    /// source of each file of the package
+    sources: std::collections::HashMap<String, String>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    pub fn new(documents: &std::collections::BTreeMap<String, fastn_core::File>) -> Diagnostics {
+        let documents = documents.values().filter_map(|file| match file {
+            fastn_core::File::Ftd(doc) => Some(doc),
+            _ => None,
+        });
+        let mut diagnostics = Diagnostics::default();
+        for doc in documents {
+            diagnostics.files.insert(
+                doc.id_with_package().trim_end_matches('/').to_string(),
+                doc.id.to_string(),
+            );
+            diagnostics
+                .sources
+                .insert(doc.id.to_string(), doc.content.to_string());
+        }
+        diagnostics
+    }
+
+    /// Adds the errors in `error`, the error of building `file`.
+    pub fn add(&mut self, file: &str, error: fastn_core::Error) {
+        let errors: Vec<Found> = match error {
+            fastn_core::Error::FTDInterpreterError(e) => Found::from_error(e),
+            fastn_core::Error::FTDP1Error(e) => Found::from_error(e),
+            fastn_core::Error::FTDAstError(e) => Found::from_error(e),
+            e => vec![Found {
+                doc_id: None,
+                line_number: None,
+                span: None,
+                labels: vec![],
+                help: None,
+                suggestion: None,
+                code: None,
+                message: e.to_string(),
+                severity: Severity::Error,
+            }],
+        };
+        self.push(file, errors);
+    }
+
+    /// Adds the warnings of the document `file`, they do not fail the build.
+    pub fn warn(&mut self, file: &str, warnings: &[ftd::interpreter::Warning]) {
+        let warnings = warnings
+            .iter()
+            .map(|w| Found {
+                doc_id: Some(w.doc_id.to_string()),
+                line_number: Some(w.line_number),
+                span: None,
+                labels: vec![],
+                help: None,
+                suggestion: None,
+                code: Some(w.code.to_string()),
+                message: w.message.to_string(),
+                severity: Severity::Warning,
+            })
+            .collect();
+        self.push(file, warnings);
+    }
+
+    fn push(&mut self, file: &str, found: Vec<Found>) {
+        for found in found {
+            let diagnostic = self.diagnostic(file, found);
+            // an error in an imported module is an error of every document
+            // importing it
+            if !self.diagnostics.contains(&diagnostic) {
+                self.diagnostics.push(diagnostic);
+            }
+        }
+    }
+
+    fn diagnostic(&self, file: &str, found: Found) -> Diagnostic {
+        let file = match found.doc_id {
+            Some(ref doc_id) => self.file(doc_id),
+            None => file.to_string(),
+        };
+        let source = self.sources.get(&file);
+
+        // the span of the error, the whole line of the error when the error
+        // has none, or one that is not in the file as it is now
+        let precise = source
+            .zip(found.span)
+            .and_then(|(source, span)| span_in(source, span, found.line_number));
+        let line_number = match (precise, source) {
+            (Some(span), Some(source)) => span.start_position(source).0,
+            _ => found.line_number.unwrap_or_default(),
+        };
+        let span = precise
+            .or_else(|| source.and_then(|source| ftd_p1::Span::of_line(source, line_number)));
+        let ((line_number, column), (end_line_number, end_column)) = match (span, source) {
+            (Some(span), Some(source)) => (span.start_position(source), span.end_position(source)),
+            _ => ((line_number, 0), (line_number, 0)),
+        };
+
+        // a fix replaces the span of the error, so it is dropped when the
+        // error has no span in the file
+        let suggestion = found.suggestion.filter(|_| precise.is_some());
+
+        let labels = found
+            .labels
+            .iter()
+            .filter_map(|label| {
+                let file = self.file(label.doc_id.as_str());
+                let source = self.sources.get(&file)?;
+                let span = label
+                    .span
+                    .and_then(|span| span_in(source, span, Some(label.line_number)))
+                    .or_else(|| ftd_p1::Span::of_line(source, label.line_number))?;
+                Some((file, span, label.text.to_string()))
+            })
+            .collect();
+
+        Diagnostic {
+            file,
+            line_number,
+            column,
+            end_line_number,
+            end_column,
+            code: found.code,
+            message: found.message,
+            span,
+            labels,
+            help: found.help,
+            suggestion,
+            severity: found.severity,
+        }
+    }
+
+    /// The file of the module `doc_id`.
+    fn file(&self, doc_id: &str) -> String {
+        // `<module>#<thing>` when the thing is not found
+        let module = doc_id.split('#').next().unwrap_or_default();
+        self.files
+            .get(module.trim_end_matches('/'))
+            .cloned()
+            .unwrap_or_else(|| module.to_string())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    fn errors(&self) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == Severity::Error)
+            .count()
+    }
+
+    /// The error with the lines of the files it is about, or just the error
+    /// when it is not on a line of a file of the package.
+    pub fn render(&self, diagnostic: &Diagnostic) -> String {
+        let snippet = |file: &str, span: ftd_p1::Span, text: &str| {
+            self.sources
+                .get_key_value(file)
+                .map(|(file, source)| ftd_p1::Snippet::new(file, source, span, text))
+        };
+        let primary = match diagnostic
+            .span
+            .and_then(|span| snippet(diagnostic.file.as_str(), span, ""))
+        {
+            Some(primary) => primary,
+            None => {
+                let level = diagnostic.severity.as_str();
+                return match diagnostic.code {
+                    Some(ref code) => format!("{level}[{code}]: {diagnostic}"),
+                    None => format!("{level}: {diagnostic}"),
+                };
+            }
+        };
+        ftd_p1::Report {
+            level: diagnostic.severity.as_str(),
+            code: diagnostic.code.as_deref(),
+            message: diagnostic.message.as_str(),
+            primary,
+            secondary: diagnostic
+                .labels
+                .iter()
+                .filter_map(|(file, span, text)| snippet(file, *span, text))
+                .collect(),
+            help: diagnostic.help.as_deref(),
+        }
+        .to_string()
+    }
+
+    /// The error as a JSON object, with the fix of the error when there is
+    /// one:
+    ///
+    /// ```json
+    /// {"file": "index.ftd", "line": 3, "column": 1, "end_line": 3,
+    ///  "end_column": 5, "severity": "error", "code": "FTD0102",
+    ///  "message": "...", "help": "did you mean `color`?", "labels": [],
+    ///  "fix": {"line": 3, "column": 1, "end_line": 3, "end_column": 5,
+    ///          "replacement": "color"}}
+    /// ```
+    pub fn json(&self, diagnostic: &Diagnostic) -> serde_json::Value {
+        let positions = |file: &str, span: ftd_p1::Span| {
+            self.sources
+                .get(file)
+                .map(|source| (span.start_position(source), span.end_position(source)))
+        };
+        let labels = diagnostic
+            .labels
+            .iter()
+            .filter_map(|(file, span, text)| {
+                let ((line, column), (end_line, end_column)) = positions(file, *span)?;
+                Some(serde_json::json!({
+                    "file": file,
+                    "line": line,
+                    "column": column,
+                    "end_line": end_line,
+                    "end_column": end_column,
+                    "message": text,
+                }))
+            })
+            .collect::<Vec<_>>();
+        let fix = diagnostic.suggestion.as_ref().map(|replacement| {
+            serde_json::json!({
+                "line": diagnostic.line_number,
+                "column": diagnostic.column,
+                "end_line": diagnostic.end_line_number,
+                "end_column": diagnostic.end_column,
+                "replacement": replacement,
+            })
+        });
+        // `null` when the error is not on a line
+        let position = |n: usize| (n > 0).then_some(n);
+        serde_json::json!({
+            "file": diagnostic.file,
+            "line": position(diagnostic.line_number),
+            "column": position(diagnostic.column),
+            "end_line": position(diagnostic.end_line_number),
+            "end_column": position(diagnostic.end_column),
+            "severity": diagnostic.severity.as_str(),
+            "code": diagnostic.code,
+            "message": diagnostic.message,
+            "help": diagnostic.help,
+            "labels": labels,
+            "fix": fix,
+        })
+    }
+
+    /// The errors, sorted by file and line, as a JSON array.
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut diagnostics = self.diagnostics.iter().collect::<Vec<_>>();
+        diagnostics.sort();
+        serde_json::Value::Array(diagnostics.into_iter().map(|d| self.json(d)).collect())
+    }
+
+    /// Prints the errors and the warnings, sorted by file and line, and fails
+    /// if there is any error. As JSON, each is a line of its own on stdout, and
+    /// the progress of the command is on stderr, see [fastn_core::progress].
+    pub fn finish(mut self, format: MessageFormat) -> fastn_core::Result<()> {
+        if self.diagnostics.is_empty() {
+            return Ok(());
+        }
+        self.diagnostics.sort();
+        let result = match self.errors() {
+            0 => Ok(()),
+            _ => Err(self.summary()),
+        };
+        if format == MessageFormat::Json {
+            for diagnostic in self.diagnostics.iter() {
+                println!("{}", self.json(diagnostic));
+            }
+            return result;
+        }
+        for diagnostic in self.diagnostics.iter() {
+            eprintln!("{}\n", self.render(diagnostic));
+        }
+        let mut codes = self
+            .diagnostics
+            .iter()
+            .filter_map(|d| d.code.as_deref())
+            .collect::<Vec<_>>();
+        codes.sort();
+        codes.dedup();
+        match codes.as_slice() {
+            [] => {}
+            [code] => {
+                eprintln!("For more information about this error, try `fastn explain {code}`.")
+            }
+            [code, ..] => {
+                eprintln!(
+                    "Some errors have detailed explanations: {}.",
+                    codes.join(", ")
+                );
+                eprintln!("For more information about an error, try `fastn explain {code}`.");
+            }
+        }
+        result
+    }
+
+    fn summary(&self) -> fastn_core::Error {
+        let files = self
+            .diagnostics
+            .iter()
+            .filter(|d| d.severity == Severity::Error)
+            .map(|d| d.file.as_str())
+            .collect::<std::collections::HashSet<_>>()
+            .len();
+        fastn_core::Error::PackageError {
+            message: format!("{} error(s) in {} file(s)", self.errors(), files),
+        }
+    }
+}
+
+/// `span` when it is text of `source` on the line `line_number`, an error
+/// of a document that has changed since can point past its text.
+fn span_in(source: &str, span: ftd_p1::Span, line_number: Option<usize>) -> Option<ftd_p1::Span> {
+    let in_source = !span.is_empty()
+        && span.end <= source.len()
+        && source.is_char_boundary(span.start)
+        && source.is_char_boundary(span.end);
+    let on_line = line_number
+        .filter(|line_number| *line_number > 0)
+        .map_or(true, |line_number| {
+            span.start_position(source).0 == line_number
+        });
+    (in_source && on_line).then_some(span)
+}
+
+#[cfg(test)]
+mod test {
+    const INTRO: &str = indoc::indoc! {"
+        -- ftd.text: Hello
+        colr: red
+    "};
+
+    #[test]
+    fn json() {
+        let document = fastn_core::Document {
+            package_name: "foo".to_string(),
+            id: "docs/intro.ftd".to_string(),
+            content: INTRO.to_string(),
+            parent_path: fastn_ds::Path::new("foo"),
+        };
+        let mut diagnostics = super::Diagnostics::new(
+            &[(document.id.clone(), fastn_core::File::Ftd(document))].into(),
+        );
+        let error = match ftd::interpreter::interpret("foo/docs/intro", INTRO) {
+            Err(e) => e,
+            Ok(_) => panic!("`colr` is not a header of `ftd.text`"),
+        };
+        diagnostics.add("docs/intro.ftd", error.into());
+        diagnostics.warn(
+            "docs/intro.ftd",
+            &[ftd::interpreter::Warning {
+                doc_id: "foo/docs/intro".to_string(),
+                line_number: 1,
+                code: ftd_p1::codes::NON_EXHAUSTIVE_MATCH.to_string(),
+                message: "`match` does not handle every variant".to_string(),
+            }],
+        );
+
+        // sorted by file and line, the warnings with the errors
+        assert_eq!(
+            diagnostics.to_json(),
+            serde_json::json!([
+                {
+                    "file": "docs/intro.ftd",
+                    "line": 1,
+                    "column": 1,
+                    "end_line": 1,
+                    "end_column": 19,
+                    "severity": "warning",
+                    "code": "FTD0205",
+                    "message": "`match` does not handle every variant",
+                    "help": null,
+                    "labels": [],
+                    "fix": null,
+                },
+                {
+                    "file": "docs/intro.ftd",
+                    "line": 2,
+                    "column": 1,
+                    "end_line": 2,
+                    "end_column": 5,
+                    "severity": "error",
+                    "code": "FTD0102",
+                    "message": "Header type `colr` mutable: `false` argument not found for component `ftd.text`",
+                    "help": "did you mean `color`?",
+                    "labels": [],
+                    "fix": {
+                        "line": 2,
+                        "column": 1,
+                        "end_line": 2,
+                        "end_column": 5,
+                        "replacement": "color",
+                    },
+                },
+            ]),
+        );
+        assert!(diagnostics.finish(super::MessageFormat::Json).is_err());
+    }
+}
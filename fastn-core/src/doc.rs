@@ -33,6 +33,7 @@ pub fn package_dependent_builtins(
         fastn_core::host_builtins::app_path(config, req_path),
         fastn_core::host_builtins::main_package(config),
         fastn_core::host_builtins::app_mounts(config),
+        fastn_core::host_builtins::language(config),
     ]
 }
 
@@ -54,7 +55,7 @@ pub async fn interpret_helper(
         name.trim_matches('/').to_string(),
         lib.config.package.name.to_string(),
     );
-    let document;
+    let mut document;
     loop {
         match s {
             ftd::interpreter::Interpreter::Done { document: doc } => {
@@ -66,6 +67,20 @@ pub async fn interpret_helper(
                 state: mut st,
                 caller_module,
             } => {
+                // the things of the document are looked up in its name without
+                // the trailing `/`, the document is not read again, it may not
+                // be a file, like the one a markdown page is shown with
+                if module.trim_end_matches('/') == name.trim_end_matches('/') {
+                    let doc = cached_parse(module.as_str(), source, line_number)?;
+                    s = st.continue_after_import(
+                        module.as_str(),
+                        doc,
+                        vec![],
+                        module_foreign_functions(),
+                        line_number,
+                    )?;
+                    continue;
+                }
                 let (source, path, foreign_variable, foreign_function, ignore_line_numbers) =
                     resolve_import_2022(
                         lib,
@@ -107,8 +122,14 @@ pub async fn interpret_helper(
                         &mut state.tdoc(doc.as_str(), line_number)?,
                         preview_session_id,
                     )
-                    .await?;
-                s = state.continue_after_processor(value, ast)?;
+                    .await;
+                s = match value {
+                    Ok(value) => state.continue_after_processor(value, ast)?,
+                    // the rest of the document is checked too
+                    Err(e) => {
+                        state.continue_after_error(e.with_code(ftd_p1::codes::PROCESSOR_FAILED))?
+                    }
+                };
             }
             ftd::interpreter::Interpreter::StuckOnForeignVariable {
                 state,
@@ -130,6 +151,14 @@ pub async fn interpret_helper(
             }
         }
     }
+    lib.warnings.extend(document.warnings.iter().cloned());
+    fastn_core::icon::inline_icons(&mut document, lib, preview_session_id).await?;
+    let markdown = lib.get_current_package(document.name.as_str()).map_or_else(
+        |_| lib.config.package.markdown.clone(),
+        |package| package.markdown,
+    );
+    let math = fastn_core::math::render_math(&mut document, &markdown)?;
+    lib.trusted_markup.extend(math);
     Ok(document)
 }
 
@@ -165,6 +194,7 @@ pub async fn resolve_import_2022(
                 "sitemap".to_string(),
                 "full-sitemap".to_string(),
                 "request-data".to_string(),
+                "form-fields".to_string(),
                 "document-readers".to_string(),
                 "document-writers".to_string(),
                 "user-groups".to_string(),
@@ -173,6 +203,7 @@ pub async fn resolve_import_2022(
                 "document-id".to_string(),
                 "document-full-id".to_string(),
                 "document-suffix".to_string(),
+                "document-toc".to_string(),
                 "document-name".to_string(),
                 "user-details".to_string(),
                 "fastn-apps".to_string(),
@@ -184,6 +215,10 @@ pub async fn resolve_import_2022(
                 "pg".to_string(),
                 "package-tree".to_string(),
                 "fetch-file".to_string(),
+                "markdown-page".to_string(),
+                "markdown-pages".to_string(),
+                "collection".to_string(),
+                "collection-pagination".to_string(),
                 "query".to_string(),
                 "current-language".to_string(),
                 "current-url".to_string(),
@@ -192,7 +227,7 @@ pub async fn resolve_import_2022(
             0,
         )
     } else if module.ends_with("assets") {
-        let foreign_variable = vec!["files".to_string()];
+        let foreign_variable = vec!["files".to_string(), "svg".to_string()];
 
         if module.starts_with(current_package.name.as_str()) {
             (
@@ -231,49 +266,60 @@ pub async fn resolve_import_2022(
             content,
             path,
             vec![],
-            vec![
-                "figma-typo-token".to_string(),
-                "figma-cs-token".to_string(),
-                "figma-cs-token-old".to_string(),
-                "http".to_string(),
-                "sql-query".to_string(),
-                "sql-execute".to_string(),
-                "sql-batch".to_string(),
-                "package-query".to_string(),
-                "pg".to_string(),
-                "toc".to_string(),
-                "include".to_string(),
-                "get-data".to_string(),
-                "sitemap".to_string(),
-                "full-sitemap".to_string(),
-                "user-groups".to_string(),
-                "document-readers".to_string(),
-                "document-writers".to_string(),
-                "user-group-by-id".to_string(),
-                "get-identities".to_string(),
-                "document-id".to_string(),
-                "document-full-id".to_string(),
-                "document-name".to_string(),
-                "document-suffix".to_string(),
-                "package-id".to_string(),
-                "package-tree".to_string(),
-                "fetch-file".to_string(),
-                "get-version-data".to_string(),
-                "cr-meta".to_string(),
-                "request-data".to_string(),
-                "user-details".to_string(),
-                "fastn-apps".to_string(),
-                "is-reader".to_string(),
-                "current-language".to_string(),
-                "current-url".to_string(),
-                "translation-info".to_string(),
-            ],
+            module_foreign_functions(),
             ignore_line_numbers,
         )
     };
     Ok(source)
 }
 
+/// The processors a module of a package can use.
+fn module_foreign_functions() -> Vec<String> {
+    vec![
+        "figma-typo-token".to_string(),
+        "figma-cs-token".to_string(),
+        "figma-cs-token-old".to_string(),
+        "http".to_string(),
+        "sql-query".to_string(),
+        "sql-execute".to_string(),
+        "sql-batch".to_string(),
+        "package-query".to_string(),
+        "pg".to_string(),
+        "toc".to_string(),
+        "include".to_string(),
+        "get-data".to_string(),
+        "sitemap".to_string(),
+        "full-sitemap".to_string(),
+        "user-groups".to_string(),
+        "document-readers".to_string(),
+        "document-writers".to_string(),
+        "user-group-by-id".to_string(),
+        "get-identities".to_string(),
+        "document-id".to_string(),
+        "document-full-id".to_string(),
+        "document-name".to_string(),
+        "document-suffix".to_string(),
+        "document-toc".to_string(),
+        "package-id".to_string(),
+        "package-tree".to_string(),
+        "fetch-file".to_string(),
+        "markdown-page".to_string(),
+        "markdown-pages".to_string(),
+        "collection".to_string(),
+        "collection-pagination".to_string(),
+        "get-version-data".to_string(),
+        "cr-meta".to_string(),
+        "request-data".to_string(),
+        "form-fields".to_string(),
+        "user-details".to_string(),
+        "fastn-apps".to_string(),
+        "is-reader".to_string(),
+        "current-language".to_string(),
+        "current-url".to_string(),
+        "translation-info".to_string(),
+    ]
+}
+
 #[tracing::instrument(name = "fastn_core::stuck-on-foreign-variable", err, skip(lib))]
 pub async fn resolve_foreign_variable2022(
     variable: &str,
@@ -330,6 +376,27 @@ pub async fn resolve_foreign_variable2022(
         }
     }
 
+    if let Some(svg) = variable.strip_prefix("svg.") {
+        let package_name = doc_name.trim_end_matches("/assets");
+        let svg_package = if package.name.eq(package_name) {
+            Some(package.clone())
+        } else {
+            package.aliases().get(package_name).map(|p| {
+                lib.config
+                    .find_package_else_default(p.name.as_str(), Some((*p).to_owned()))
+            })
+        };
+        if let Some(svg_package) = svg_package {
+            lib.push_package_under_process(doc_name, &svg_package, preview_session_id)
+                .await?;
+            if let Ok(text) =
+                fastn_core::icon::read_svg(lib, &svg_package, svg, preview_session_id).await
+            {
+                return Ok(fastn_resolved::Value::String { text });
+            }
+        }
+    }
+
     return ftd::interpreter::utils::e2(format!("{} not found 2", variable).as_str(), doc_name, 0);
 
     async fn get_assets_value(
@@ -400,7 +467,11 @@ pub async fn resolve_foreign_variable2022(
                             doc_id: lib.document_id.to_string(),
                             line_number: 0,
                         })?;
-                    print!("Processing {}/{} ... ", package.name.as_str(), light_path);
+                    fastn_core::progress!(
+                        "Processing {}/{} ... ",
+                        package.name.as_str(),
+                        light_path
+                    );
                     fastn_core::utils::write(
                         &lib.config.build_dir().join("-").join(package.name.as_str()),
                         light_path.as_str(),
@@ -460,7 +531,11 @@ pub async fn resolve_foreign_variable2022(
                         )
                         .await
                     {
-                        print!("Processing {}/{} ... ", package.name.as_str(), dark_path);
+                        fastn_core::progress!(
+                            "Processing {}/{} ... ",
+                            package.name.as_str(),
+                            dark_path
+                        );
                         fastn_core::utils::write(
                             &lib.config.build_dir().join("-").join(package.name.as_str()),
                             dark_path.as_str(),
@@ -569,7 +644,7 @@ async fn download(
                 doc_id: lib.document_id.to_string(),
                 line_number: 0,
             })?;
-        print!("Processing {}/{} ... ", package.name, path);
+        fastn_core::progress!("Processing {}/{} ... ", package.name, path);
         fastn_core::utils::write(
             &lib.config.build_dir().join("-").join(package.name.as_str()),
             path,
@@ -701,7 +776,11 @@ pub async fn resolve_foreign_variable2(
                             doc_id: lib.document_id.to_string(),
                             line_number: 0,
                         })?;
-                    print!("Processing {}/{} ... ", package.name.as_str(), light_path);
+                    fastn_core::progress!(
+                        "Processing {}/{} ... ",
+                        package.name.as_str(),
+                        light_path
+                    );
                     fastn_core::utils::write(
                         &lib.config
                             .config
@@ -767,7 +846,11 @@ pub async fn resolve_foreign_variable2(
                         )
                         .await
                     {
-                        print!("Processing {}/{} ... ", package.name.as_str(), dark_path);
+                        fastn_core::progress!(
+                            "Processing {}/{} ... ",
+                            package.name.as_str(),
+                            dark_path
+                        );
                         fastn_core::utils::write(
                             &lib.config
                                 .config
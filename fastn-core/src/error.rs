@@ -51,6 +51,9 @@ pub enum Error {
     #[error("UTF8Error: {}", _0)]
     UTF8Error(#[from] std::string::FromUtf8Error),
 
+    #[error("SvgError: {}", _0)]
+    SvgError(#[from] fastn_js::SvgError),
+
     #[error("ParseIntError: {}", _0)]
     ParseIntError(#[from] std::num::ParseIntError),
 
@@ -0,0 +1,118 @@
+//! Grammars and themes for `ftd.code` shipped by packages in their
+//! `highlighting` folder, so a new language does not need a fastn release.
+//! `.sublime-syntax` grammars and `.tmTheme` themes are used when fastn
+//! highlights the code, Prism grammars (`.js`) and themes (`.css`) when the
+//! browser does.
+
+/// The folder, in the root of a package, with the grammars and themes.
+pub const HIGHLIGHTING_FOLDER: &str = "highlighting";
+
+/// A file in the `highlighting` folder of a package, with the url it is
+/// served at, relative to the base url.
+struct HighlightingFile {
+    path: fastn_ds::Path,
+    url: String,
+    /// The file name without the extension, the name of a theme.
+    name: String,
+    extension: String,
+}
+
+async fn highlighting_files(
+    config: &fastn_core::Config,
+    session_id: &Option<String>,
+) -> Vec<HighlightingFile> {
+    let mut packages = vec![];
+    let mut entry = config.all_packages.first_entry();
+    while let Some(package) = entry {
+        packages.push(package.get().clone());
+        entry = package.next();
+    }
+
+    let mut files = vec![];
+    for package in packages {
+        let root = config.get_root_for_package(&package);
+        let folder = root.join(HIGHLIGHTING_FOLDER);
+        if !config.ds.exists(&folder, session_id).await {
+            continue;
+        }
+        // files of the dependencies are served under `-/<package-name>/`
+        let prefix = if package.name.eq(&config.package.name) {
+            String::new()
+        } else {
+            format!("-/{}/", package.name)
+        };
+        for path in config.ds.get_all_file_path(&folder, &[]).await {
+            let (Some(relative), Some(file_name), Some(extension)) =
+                (path.strip_prefix(&root), path.file_name(), path.extension())
+            else {
+                continue;
+            };
+            files.push(HighlightingFile {
+                url: format!("{prefix}{}", relative.to_string().trim_start_matches('/')),
+                name: file_name
+                    .strip_suffix(format!(".{extension}").as_str())
+                    .unwrap_or(file_name.as_str())
+                    .to_string(),
+                extension,
+                path,
+            });
+        }
+    }
+    files.sort_by(|a, b| a.url.cmp(&b.url));
+    files
+}
+
+/// Adds the `.sublime-syntax` grammars and `.tmTheme` themes of the packages
+/// to the ones `ftd.code` knows, the file name is the name of the theme.
+pub(crate) async fn load_package_highlighting(
+    config: &fastn_core::Config,
+    session_id: &Option<String>,
+) -> fastn_core::Result<()> {
+    let mut syntaxes = vec![];
+    for file in highlighting_files(config, session_id).await {
+        match file.extension.as_str() {
+            "sublime-syntax" => syntaxes.push((
+                file.name.clone(),
+                config.ds.read_to_string(&file.path, session_id).await?,
+            )),
+            "tmTheme" => ftd::executor::code::add_package_theme(
+                file.name.as_str(),
+                config
+                    .ds
+                    .read_to_string(&file.path, session_id)
+                    .await?
+                    .as_str(),
+            )?,
+            _ => {}
+        }
+    }
+    ftd::executor::code::add_package_syntaxes(syntaxes.as_slice())?;
+    Ok(())
+}
+
+/// The script tags of the Prism grammars of the packages, and the js which
+/// makes their Prism themes available to `ftd.code`, like
+/// `fastn_core::utils::available_code_themes()`.
+pub(crate) async fn prism_highlighting(
+    config: &fastn_core::Config,
+    base_url: &str,
+    session_id: &Option<String>,
+) -> (String, String) {
+    let base_url = base_url.trim_end_matches('/');
+    let mut scripts = vec![];
+    let mut themes = vec![];
+    for file in highlighting_files(config, session_id).await {
+        match file.extension.as_str() {
+            "js" => scripts.push(format!(
+                "<script src=\"{}/{}\"></script>",
+                base_url, file.url
+            )),
+            "css" => themes.push(format!(
+                "fastn_dom.codeData.availableThemes[\"{}\"] = \"{}/{}\";",
+                file.name, base_url, file.url
+            )),
+            _ => {}
+        }
+    }
+    (scripts.join("\n"), themes.join("\n"))
+}
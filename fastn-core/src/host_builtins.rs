@@ -54,6 +54,7 @@ pub fn app_path(
                 value: None,
                 access_modifier: Default::default(),
                 line_number: 0,
+                is_slot: false,
             },
             fastn_resolved::Argument {
                 name: "app".to_string(),
@@ -68,6 +69,7 @@ pub fn app_path(
                 }),
                 access_modifier: Default::default(),
                 line_number: 0,
+                is_slot: false,
             },
         ],
         expression: vec![fastn_resolved::FunctionExpression {
@@ -101,6 +103,7 @@ pub fn main_package(config: &fastn_core::Config) -> (String, fastn_resolved::Def
         conditional_value: vec![],
         mutable: false,
         is_static: false,
+        sse: None,
         line_number: 0,
     });
 
@@ -132,6 +135,36 @@ pub fn app_mounts(config: &fastn_core::Config) -> (String, fastn_resolved::Defin
         name: name.clone(),
         line_number: 0,
         variants,
+        matched: false,
+    });
+
+    (name, def)
+}
+
+/// Ftd string variable that holds the two letter code of the language the page
+/// is rendered in, `en` if the package has no `fastn.lang`.
+///
+/// `ftd.format_datetime` and `ftd.relative_time` use it when they are not
+/// passed a language, both while rendering and in the browser.
+#[inline]
+pub fn language(config: &fastn_core::Config) -> (String, fastn_resolved::Definition) {
+    let name = "ftd#language".to_string();
+    let language = config.package.selected_language.as_deref().unwrap_or("en");
+    let def = fastn_resolved::Definition::Variable(fastn_resolved::Variable {
+        name: name.clone(),
+        kind: fastn_resolved::Kind::string().into_kind_data(),
+        value: fastn_resolved::PropertyValue::Value {
+            value: fastn_resolved::Value::String {
+                text: language.to_string(),
+            },
+            is_mutable: false,
+            line_number: 0,
+        },
+        conditional_value: vec![],
+        mutable: false,
+        is_static: false,
+        sse: None,
+        line_number: 0,
     });
 
     (name, def)
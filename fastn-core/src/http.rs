@@ -120,6 +120,43 @@ impl ResponseBuilder {
         let content = response.body();
         response_builder.body(content.to_vec())
     }
+
+    /// Forwards the response chunk by chunk instead of reading the whole body
+    /// first, used for `text/event-stream` responses of endpoints.
+    pub fn from_reqwest_stream(response: reqwest::Response) -> actix_web::HttpResponse {
+        let status = response.status().as_u16();
+
+        let mut response_builder =
+            actix_web::HttpResponse::build(actix_web::http::StatusCode::from_u16(status).unwrap());
+        for (k, v) in response
+            .headers()
+            .iter()
+            .filter(|(h, _)| *h != "connection" && *h != "transfer-encoding")
+        {
+            response_builder.insert_header((k.as_str(), v.as_bytes()));
+        }
+
+        let body = futures::stream::unfold(Some(response), |response| async move {
+            let mut response = response?;
+            match response.chunk().await {
+                Ok(Some(chunk)) => {
+                    Some((Ok::<bytes::Bytes, reqwest::Error>(chunk), Some(response)))
+                }
+                Ok(None) => None,
+                Err(e) => Some((Err(e), None)),
+            }
+        });
+        response_builder.streaming(body)
+    }
+
+    pub fn is_event_stream(response: &reqwest::Response) -> bool {
+        response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.starts_with("text/event-stream"))
+            .unwrap_or(false)
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -269,6 +306,37 @@ impl Request {
         Ok(Some(serde_json::from_slice(&self.body)?))
     }
 
+    /// The body of a form: posted as json by `ftd.form`, or urlencoded by the
+    /// browser when there is no js, with every value a string and a key given
+    /// more than once a list of them.
+    pub fn body_as_form(
+        &self,
+    ) -> fastn_core::Result<Option<std::collections::HashMap<String, serde_json::Value>>> {
+        if !self.content_type().is_some_and(|v| {
+            v.type_() == mime_guess::mime::APPLICATION
+                && v.subtype() == mime_guess::mime::WWW_FORM_URLENCODED
+        }) {
+            return self.body_as_json();
+        }
+        if self.body.is_empty() {
+            return Ok(None);
+        }
+        let mut data: std::collections::HashMap<String, serde_json::Value> = Default::default();
+        for (key, value) in url::form_urlencoded::parse(&self.body) {
+            let value = serde_json::Value::String(value.into_owned());
+            match data.get_mut(key.as_ref()) {
+                Some(serde_json::Value::Array(values)) => values.push(value),
+                Some(existing) => {
+                    *existing = serde_json::Value::Array(vec![existing.take(), value])
+                }
+                None => {
+                    data.insert(key.into_owned(), value);
+                }
+            }
+        }
+        Ok(Some(data))
+    }
+
     pub fn x_fastn_request_path(&self) -> Option<String> {
         self.headers
             .get(X_FASTN_REQUEST_PATH)
@@ -645,4 +713,63 @@ mod test {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn from_reqwest_stream() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (send_second, second) = tokio::sync::oneshot::channel::<()>();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0; 1024];
+            let _ = socket.read(&mut request).await.unwrap();
+            socket
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\n\
+                    transfer-encoding: chunked\r\n\r\n9\r\ndata: 1\n\n\r\n",
+                )
+                .await
+                .unwrap();
+            // the second event is only sent once the first one is out
+            second.await.unwrap();
+            socket
+                .write_all(b"9\r\ndata: 2\n\n\r\n0\r\n\r\n")
+                .await
+                .unwrap();
+        });
+
+        let response = reqwest::Client::builder()
+            .no_proxy()
+            .build()
+            .unwrap()
+            .get(format!("http://{addr}/"))
+            .send()
+            .await
+            .unwrap();
+        assert!(super::ResponseBuilder::is_event_stream(&response));
+
+        let response = super::ResponseBuilder::from_reqwest_stream(response);
+        assert_eq!(response.status(), actix_web::http::StatusCode::OK);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "text/event-stream"
+        );
+        assert!(response.headers().get("transfer-encoding").is_none());
+
+        let mut body = response.into_body();
+        // a body that is read whole would wait for the second event forever
+        let first = tokio::time::timeout(std::time::Duration::from_secs(5), next(&mut body));
+        assert_eq!(first.await.unwrap().unwrap(), "data: 1\n\n");
+        send_second.send(()).unwrap();
+        assert_eq!(next(&mut body).await.unwrap(), "data: 2\n\n");
+        assert!(next(&mut body).await.is_none());
+    }
+
+    async fn next(body: &mut actix_web::body::BoxBody) -> Option<bytes::Bytes> {
+        futures::future::poll_fn(|cx| std::pin::Pin::new(&mut *body).poll_next(cx))
+            .await
+            .map(|chunk| chunk.unwrap())
+    }
 }
@@ -0,0 +1,272 @@
+//! `ftd.icon: arrow-right` is looked up in the icon sets of the package, the
+//! dependencies with `icons` in `FASTN.ftd`, and inlined at compile time. Svg
+//! files are inlined without anything that can run scripts, see
+//! `fastn_js::sanitize_svg`.
+
+struct IconRequest {
+    module: String,
+    set: Option<String>,
+    name: String,
+    condition: Option<fastn_resolved::Expression>,
+    line_number: usize,
+}
+
+impl IconRequest {
+    fn key(&self) -> (String, Option<String>, String) {
+        (self.module.clone(), self.set.clone(), self.name.clone())
+    }
+}
+
+/// Fills the `content` of every `ftd.icon` in the document with the svg of the
+/// icon, from the icon sets of the package the icon is used in.
+pub(crate) async fn inline_icons(
+    document: &mut ftd::interpreter::Document,
+    lib: &mut fastn_core::Library2022,
+    session_id: &Option<String>,
+) -> ftd::interpreter::Result<()> {
+    let mut requests = vec![];
+    visit_icons(document, &mut |module, icon| {
+        requests.extend(icon_requests(module, icon)?);
+        Ok(())
+    })?;
+
+    let mut svgs = std::collections::HashMap::new();
+    for request in requests {
+        if let std::collections::hash_map::Entry::Vacant(e) = svgs.entry(request.key()) {
+            e.insert(get_icon(lib, &request, session_id).await?);
+        }
+    }
+
+    if svgs.is_empty() {
+        return Ok(());
+    }
+
+    visit_icons(document, &mut |module, icon| {
+        for request in icon_requests(module, icon)? {
+            icon.properties.push(fastn_resolved::Property {
+                value: fastn_resolved::PropertyValue::Value {
+                    value: fastn_resolved::Value::new_string(svgs[&request.key()].as_str()),
+                    is_mutable: false,
+                    line_number: request.line_number,
+                },
+                source: fastn_resolved::PropertySource::Header {
+                    name: "content".to_string(),
+                    mutable: false,
+                },
+                condition: request.condition,
+                line_number: request.line_number,
+            });
+        }
+        Ok(())
+    })
+}
+
+/// Reads `<path>.svg` of the package for the `svg` foreign variable of the
+/// `assets` module, like `$assets.svg.icons.logo`, see `fastn_js::sanitize_svg`.
+pub(crate) async fn read_svg(
+    lib: &mut fastn_core::Library2022,
+    package: &fastn_core::Package,
+    path: &str,
+    session_id: &Option<String>,
+) -> fastn_core::Result<String> {
+    let content = package
+        .resolve_by_file_name(
+            format!("{}.svg", path.replace('.', "/")).as_str(),
+            None,
+            &lib.config.ds,
+            session_id,
+        )
+        .await?;
+    Ok(fastn_js::sanitize_svg(
+        String::from_utf8(content)?.as_str(),
+    )?)
+}
+
+async fn get_icon(
+    lib: &mut fastn_core::Library2022,
+    request: &IconRequest,
+    session_id: &Option<String>,
+) -> ftd::interpreter::Result<String> {
+    let package = lib
+        .get_current_package(request.module.as_str())
+        .unwrap_or_else(|_| lib.config.package.clone());
+
+    for dependency in package.dependencies.iter() {
+        let icons = match dependency.icons {
+            Some(ref icons) => icons.trim_start_matches("./").trim_matches('/'),
+            None => continue,
+        };
+        if let Some(ref set) = request.set {
+            if !set.eq(&dependency.package.name) && dependency.alias.as_ref() != Some(set) {
+                continue;
+            }
+        }
+
+        lib.push_package_under_process(
+            dependency.package.name.as_str(),
+            &dependency.package,
+            session_id,
+        )
+        .await?;
+        let icon_set = lib.config.find_package_else_default(
+            dependency.package.name.as_str(),
+            Some(dependency.package.to_owned()),
+        );
+        let path = match icons {
+            "" | "." => request.name.to_string(),
+            icons => format!("{icons}/{}", request.name),
+        };
+        if let Ok(content) = icon_set
+            .resolve_by_file_name(
+                format!("{path}.svg").as_str(),
+                None,
+                &lib.config.ds,
+                session_id,
+            )
+            .await
+        {
+            let error = |message: String| ftd::interpreter::Error::ParseError {
+                message: format!("icon `{}` {message}", request.name),
+                doc_id: request.module.to_string(),
+                line_number: request.line_number,
+            };
+            let svg = String::from_utf8(content)
+                .map_err(|e| error(format!("is not valid utf-8: {e}")))?;
+            return fastn_js::sanitize_svg(svg.as_str())
+                .map_err(|e| error(format!("{path}.svg: {e}")));
+        }
+    }
+
+    Err(ftd::interpreter::Error::ParseError {
+        message: match request.set {
+            Some(ref set) => format!("icon `{}` not found in the icon set `{set}`", request.name),
+            None => format!(
+                "icon `{}` not found in the icon sets of `{}`, icon sets are dependencies \
+                with `icons` in FASTN.ftd",
+                request.name, package.name
+            ),
+        },
+        doc_id: request.module.to_string(),
+        line_number: request.line_number,
+    })
+}
+
+/// An `ftd.icon` has a `name` for every condition, like
+/// `ftd.icon: menu if { open }: close`. Icons are inlined at compile time, so
+/// the names have to be literals.
+fn icon_requests(
+    module: &str,
+    icon: &fastn_resolved::ComponentInvocation,
+) -> ftd::interpreter::Result<Vec<IconRequest>> {
+    let mut set = None;
+    let mut names = vec![];
+    for property in icon.properties.iter() {
+        match property.source {
+            fastn_resolved::PropertySource::Header { ref name, .. } if name.eq("content") => {
+                return Ok(vec![]);
+            }
+            fastn_resolved::PropertySource::Header { ref name, .. } if name.eq("set") => {
+                set = Some(literal(module, "set", property)?);
+            }
+            fastn_resolved::PropertySource::Header { ref name, .. } if name.eq("name") => {
+                names.push(property);
+            }
+            fastn_resolved::PropertySource::Caption => names.push(property),
+            _ => {}
+        }
+    }
+
+    names
+        .into_iter()
+        .map(|property| {
+            Ok(IconRequest {
+                module: module.to_string(),
+                set: set.clone(),
+                name: literal(module, "name", property)?,
+                condition: property.condition.clone(),
+                line_number: property.line_number,
+            })
+        })
+        .collect()
+}
+
+fn literal(
+    module: &str,
+    header: &str,
+    property: &fastn_resolved::Property,
+) -> ftd::interpreter::Result<String> {
+    match property.value {
+        fastn_resolved::PropertyValue::Value {
+            value: fastn_resolved::Value::String { ref text },
+            ..
+        } => Ok(text.to_string()),
+        _ => Err(ftd::interpreter::Error::ParseError {
+            message: format!(
+                "`{header}` of `ftd.icon` has to be a literal, icons are inlined at compile \
+                time. Use `ftd.svg` with `$assets.svg.<path>` for other svgs"
+            ),
+            doc_id: module.to_string(),
+            line_number: property.line_number,
+        }),
+    }
+}
+
+type Visitor<'a> =
+    dyn FnMut(&str, &mut fastn_resolved::ComponentInvocation) -> ftd::interpreter::Result<()> + 'a;
+
+fn visit_icons(
+    document: &mut ftd::interpreter::Document,
+    f: &mut Visitor,
+) -> ftd::interpreter::Result<()> {
+    let document_name = document.name.clone();
+    visit(document.tree.as_mut_slice(), document_name.as_str(), f)?;
+    for (name, thing) in document.data.iter_mut() {
+        if let fastn_resolved::Definition::Component(component) = thing {
+            let module = name
+                .split_once('#')
+                .map_or(document_name.as_str(), |(module, _)| module);
+            visit(std::slice::from_mut(&mut component.definition), module, f)?;
+        }
+    }
+    Ok(())
+}
+
+fn visit(
+    invocations: &mut [fastn_resolved::ComponentInvocation],
+    module: &str,
+    f: &mut Visitor,
+) -> ftd::interpreter::Result<()> {
+    for invocation in invocations {
+        if invocation.name.eq("ftd#icon") {
+            f(module, invocation)?;
+        }
+        for property in invocation.properties.iter_mut() {
+            if let fastn_resolved::PropertyValue::Value { ref mut value, .. } = property.value {
+                visit_value(value, module, f)?;
+            }
+        }
+        visit(invocation.children.as_mut_slice(), module, f)?;
+    }
+    Ok(())
+}
+
+fn visit_value(
+    value: &mut fastn_resolved::Value,
+    module: &str,
+    f: &mut Visitor,
+) -> ftd::interpreter::Result<()> {
+    match value {
+        fastn_resolved::Value::UI { component, .. } => {
+            visit(std::slice::from_mut(component), module, f)
+        }
+        fastn_resolved::Value::List { data, .. } => {
+            for item in data.iter_mut() {
+                if let fastn_resolved::PropertyValue::Value { value, .. } = item {
+                    visit_value(value, module, f)?;
+                }
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
@@ -7,12 +7,19 @@ extern crate self as fastn_core;
 #[macro_use]
 pub mod utils;
 mod auto_import;
+mod collection;
 pub mod commands;
 mod config;
+mod diagnostic;
 pub mod doc;
 mod file;
 mod font;
+mod highlighting;
+mod icon;
 pub mod manifest;
+mod markdown;
+mod markdown_page;
+mod math;
 pub mod package;
 #[macro_use]
 pub mod http;
@@ -34,9 +41,11 @@ pub(crate) mod host_builtins;
 
 pub(crate) use auto_import::AutoImport;
 pub use commands::{
-    build::build, check::post_build_check, fmt::fmt, query::query, serve::listen, test::test,
+    build::build, check::check, check::post_build_check, fmt::fmt, lsp::lsp, query::query,
+    serve::listen, test::test,
 };
 pub use config::{config_temp, Config, ConfigTemp, FTDEdition, RequestConfig};
+pub use diagnostic::{Diagnostic, Diagnostics, MessageFormat, Severity};
 pub use doc::resolve_foreign_variable2;
 pub use error::Error;
 pub use file::File;
@@ -59,6 +68,10 @@ pub const IMAGE_EXT: &[&str] = &["jpg", "png", "svg"];
 
 pub const VIDEO_EXT: &[&str] = &["mp4", "ogg", "webm"];
 
+/// The js of a page for client-side navigation is served, and written by
+/// `fastn build`, as this file next to the page's `index.html`.
+pub const SPA_PAYLOAD_FILE: &str = "index.spa.js";
+
 pub fn ftd_html() -> &'static str {
     include_str!("../ftd_2022.html")
 }
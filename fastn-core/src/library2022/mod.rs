@@ -180,6 +180,12 @@ impl Library2022 {
             if !file_path.ends_with(".ftd") {
                 return Ok(None);
             }
+            if let Ok(root) = package.package_root_with_default(None) {
+                lib.module_files.insert(
+                    name.trim_matches('/').to_string(),
+                    root.join(file_path.as_str()),
+                );
+            }
             Ok(String::from_utf8(data).ok().map(|body| {
                 let body_with_prefix =
                     package.get_prefixed_body(body.as_str(), name.as_str(), true);
@@ -274,6 +280,7 @@ impl Library2022 {
             "request-data" => {
                 processor::request_data::process(variable_name, value, kind, doc, self)
             }
+            "form-fields" => processor::form_fields::process(value, kind, doc, self),
             "document-readers" => processor::document::process_readers(
                 value,
                 kind,
@@ -295,12 +302,33 @@ impl Library2022 {
             "current-url" => processor::document::current_url(self),
             "document-full-id" => processor::document::document_full_id(value, kind, doc, self),
             "document-suffix" => processor::document::document_suffix(value, kind, doc, self),
+            "document-toc" => processor::document::document_toc(value, kind, doc),
             "document-name" => {
                 processor::document::document_name(value, kind, doc, self, preview_session_id).await
             }
             "fetch-file" => {
                 processor::fetch_file::fetch_files(value, kind, doc, self, preview_session_id).await
             }
+            "collection" => {
+                processor::collection::process(value, kind, doc, self, preview_session_id).await
+            }
+            "collection-pagination" => {
+                processor::collection::process_pagination(
+                    value,
+                    kind,
+                    doc,
+                    self,
+                    preview_session_id,
+                )
+                .await
+            }
+            "markdown-page" => {
+                processor::markdown_page::process(value, kind, doc, self, preview_session_id).await
+            }
+            "markdown-pages" => {
+                processor::markdown_page::process_list(value, kind, doc, self, preview_session_id)
+                    .await
+            }
             "user-details" => processor::user_details::process(value, kind, doc, self).await,
             "fastn-apps" => processor::apps::process(value, kind, doc, self),
             "is-reader" => processor::user_group::is_reader(value, kind, doc, self).await,
@@ -0,0 +1,121 @@
+/// The documents of the package matching the glob `folder`, with their
+/// metadata, for listings.
+pub async fn process(
+    value: ftd_ast::VariableValue,
+    kind: fastn_resolved::Kind,
+    doc: &ftd::interpreter::TDoc<'_>,
+    req_config: &mut fastn_core::RequestConfig,
+    preview_session_id: &Option<String>,
+) -> ftd::interpreter::Result<fastn_resolved::Value> {
+    let (items, _) = listing(&value, doc, req_config, preview_session_id).await?;
+    doc.from_json(&items, &kind, &value)
+}
+
+/// The page, the number of pages and the tags of the listing the `collection`
+/// processor with the same headers shows.
+pub async fn process_pagination(
+    value: ftd_ast::VariableValue,
+    kind: fastn_resolved::Kind,
+    doc: &ftd::interpreter::TDoc<'_>,
+    req_config: &mut fastn_core::RequestConfig,
+    preview_session_id: &Option<String>,
+) -> ftd::interpreter::Result<fastn_resolved::Value> {
+    let (_, pagination) = listing(&value, doc, req_config, preview_session_id).await?;
+    doc.from_json(&pagination, &kind, &value)
+}
+
+async fn listing(
+    value: &ftd_ast::VariableValue,
+    doc: &ftd::interpreter::TDoc<'_>,
+    req_config: &mut fastn_core::RequestConfig,
+    preview_session_id: &Option<String>,
+) -> ftd::interpreter::Result<(
+    Vec<fastn_core::collection::CollectionItem>,
+    fastn_core::collection::Pagination,
+)> {
+    // a listing changes with the documents it lists
+    req_config.response_is_cacheable = false;
+
+    let line_number = value.line_number();
+    let headers = match value.get_record(doc.name) {
+        Ok(val) => val.2.to_owned(),
+        Err(_e) => ftd_ast::HeaderValues::new(vec![]),
+    };
+    let header = |key: &str| headers.get_optional_string_by_key(key, doc.name, line_number);
+    let number = |key: &str| -> ftd::interpreter::Result<Option<usize>> {
+        header(key)?
+            .map(|v| v.trim().parse::<usize>())
+            .transpose()
+            .or_else(|_| {
+                ftd::interpreter::utils::e2(
+                    format!("`{key}` has to be a positive integer"),
+                    doc.name,
+                    line_number,
+                )
+            })
+    };
+
+    let sort = match header("sort")? {
+        Some(sort) => sort
+            .trim()
+            .parse::<fastn_core::collection::Sort>()
+            .or_else(|e| ftd::interpreter::utils::e2(e, doc.name, line_number))?,
+        None => fastn_core::collection::Sort::Date,
+    };
+    let descending = match header("order")?.as_deref().map(str::trim) {
+        Some("ascending") => false,
+        Some("descending") => true,
+        Some(t) => {
+            return ftd::interpreter::utils::e2(
+                format!("unknown order `{t}`, expected `ascending` or `descending`"),
+                doc.name,
+                line_number,
+            )
+        }
+        None => sort == fastn_core::collection::Sort::Date,
+    };
+    let query = fastn_core::collection::Query {
+        folder: header("folder")?.ok_or(ftd::interpreter::Error::ParseError {
+            message: "`folder` not found".to_string(),
+            doc_id: doc.name.to_string(),
+            line_number,
+        })?,
+        tag: header("tag")?,
+        sort,
+        descending,
+        offset: number("offset")?.unwrap_or_default(),
+        limit: number("limit")?,
+        per_page: number("per-page")?,
+        tag_pages: header("tag-pages")?.is_some_and(|v| v.trim().eq("true")),
+    };
+
+    let (base, suffix) =
+        fastn_core::collection::split_suffix(req_config.doc_id().unwrap_or_default().as_str());
+    let listing = fastn_core::collection::Listing::from_suffix(suffix.as_str());
+    let package = req_config
+        .get_current_package(doc.name)
+        .unwrap_or_else(|_| req_config.config.package.clone());
+
+    let items = fastn_core::collection::items(
+        &req_config.config,
+        &package,
+        &query,
+        base.as_str(),
+        preview_session_id,
+    )
+    .await
+    .map_err(|e| ftd::interpreter::Error::ParseError {
+        message: e.to_string(),
+        doc_id: doc.name.to_string(),
+        line_number,
+    })?;
+    let (items, pagination) =
+        fastn_core::collection::listing(items, &query, &listing, base.as_str());
+
+    for suffix in fastn_core::collection::other_pages(&query, &listing, &pagination) {
+        if !req_config.listing_pages.contains(&suffix) {
+            req_config.listing_pages.push(suffix);
+        }
+    }
+    Ok((items, pagination))
+}
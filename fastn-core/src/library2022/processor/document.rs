@@ -101,6 +101,21 @@ pub fn document_suffix(
     })
 }
 
+/// The headings of the markdown in the `ftd.text`s of the document. Their
+/// `id`s are the ones `heading-ids: true` in `-- fastn.markdown:` gives them.
+pub fn document_toc(
+    value: ftd_ast::VariableValue,
+    kind: fastn_resolved::Kind,
+    doc: &ftd::interpreter::TDoc,
+) -> ftd::interpreter::Result<fastn_resolved::Value> {
+    let headings = doc
+        .state()
+        .and_then(|state| state.parsed_libs.get(doc.name))
+        .map(|parsed| fastn_core::markdown::document_headings(parsed.ast.as_slice()))
+        .unwrap_or_default();
+    doc.from_json(&headings, &kind, &value)
+}
+
 pub async fn document_name(
     value: ftd_ast::VariableValue,
     _kind: fastn_resolved::Kind,
@@ -0,0 +1,108 @@
+/// `$processor$: pr.form-fields` on a `ftd.form-field list`. When the request
+/// has a body (a form posted with or without js), the `value` of every field
+/// is taken from it and the field is validated with the same rules `ftd.js`
+/// uses, so the page can show the errors without js. The `check` of a field
+/// is a js function, it is only run in the browser.
+pub fn process(
+    value: ftd_ast::VariableValue,
+    kind: fastn_resolved::Kind,
+    doc: &ftd::interpreter::TDoc,
+    req_config: &mut fastn_core::RequestConfig,
+) -> ftd::interpreter::Result<fastn_resolved::Value> {
+    req_config.response_is_cacheable = false;
+
+    let items = match value.inner() {
+        Some(ftd_ast::VariableValue::List { value, .. }) => value,
+        _ => {
+            return ftd::interpreter::utils::e2(
+                "Expected a list of `ftd.form-field`",
+                doc.name,
+                value.line_number(),
+            )
+        }
+    };
+
+    let data = match req_config.request.body_as_form() {
+        Ok(data) => data,
+        Err(e) => {
+            return ftd::interpreter::utils::e2(
+                format!("Error while parsing request body: {e:?}"),
+                doc.name,
+                value.line_number(),
+            );
+        }
+    };
+
+    let mut fields = vec![];
+    for item in items {
+        let (_, _, headers, _, _, line_number) = item.value.get_record(doc.name)?;
+        let string = |key: &str| headers.get_optional_string_by_key(key, doc.name, line_number);
+        let parse = |key: &str| -> ftd::interpreter::Result<Option<f64>> {
+            string(key)?
+                .map(|v| {
+                    v.parse::<f64>().or_else(|_| {
+                        ftd::interpreter::utils::e2(
+                            format!("`{key}` of `ftd.form-field` has to be a number, found `{v}`"),
+                            doc.name,
+                            line_number,
+                        )
+                    })
+                })
+                .transpose()
+        };
+
+        let rules = fastn_builtins::form::FieldRules {
+            name: match string("name")? {
+                Some(name) => name,
+                None => {
+                    return ftd::interpreter::utils::e2(
+                        "`name` is required for `ftd.form-field`",
+                        doc.name,
+                        line_number,
+                    )
+                }
+            },
+            required: string("required")?.is_some_and(|v| v.trim().eq("true")),
+            min_length: parse("min-length")?.map(|v| v as i64),
+            max_length: parse("max-length")?.map(|v| v as i64),
+            min: parse("min")?,
+            max: parse("max")?,
+            pattern: string("pattern")?,
+            message: string("message")?,
+        };
+
+        let (field_value, error) = match data {
+            Some(ref data) => {
+                let field_value = match data.get(rules.name.as_str()) {
+                    Some(serde_json::Value::String(v)) => Some(v.to_string()),
+                    // a multiple select posts one value per option
+                    Some(serde_json::Value::Array(values)) => values
+                        .first()
+                        .and_then(|v| v.as_str())
+                        .map(ToString::to_string),
+                    Some(serde_json::Value::Null) | None => None,
+                    Some(v) => Some(v.to_string()),
+                };
+                let error = rules.validate(field_value.as_deref());
+                (field_value, error)
+            }
+            None => (string("value")?, string("error")?),
+        };
+
+        fields.push(serde_json::json!({
+            "name": rules.name,
+            "value": field_value,
+            "error": error,
+            "required": rules.required,
+            "min-length": rules.min_length,
+            "max-length": rules.max_length,
+            "min": rules.min,
+            "max": rules.max,
+            "pattern": rules.pattern,
+            "check": string("check")?,
+            "message": rules.message,
+        }));
+    }
+
+    doc.from_json(&fields, &kind, &value)
+}
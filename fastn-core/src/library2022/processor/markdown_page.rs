@@ -0,0 +1,85 @@
+/// The front matter and the markdown of the `.md` page `file` of the package.
+pub async fn process(
+    value: ftd_ast::VariableValue,
+    kind: fastn_resolved::Kind,
+    doc: &ftd::interpreter::TDoc<'_>,
+    req_config: &fastn_core::RequestConfig,
+    preview_session_id: &Option<String>,
+) -> ftd::interpreter::Result<fastn_resolved::Value> {
+    let headers = match value.get_record(doc.name) {
+        Ok(val) => val.2.to_owned(),
+        Err(_e) => ftd_ast::HeaderValues::new(vec![]),
+    };
+    let file = headers
+        .get_optional_string_by_key("file", doc.name, value.line_number())?
+        .ok_or(ftd::interpreter::Error::ParseError {
+            message: "`file` not found".to_string(),
+            doc_id: doc.name.to_string(),
+            line_number: value.line_number(),
+        })?;
+    let package = req_config
+        .get_current_package(doc.name)
+        .unwrap_or_else(|_| req_config.config.package.clone());
+
+    let content = req_config
+        .config
+        .ds
+        .read_to_string(
+            &req_config
+                .config
+                .get_root_for_package(&package)
+                .join(file.as_str()),
+            preview_session_id,
+        )
+        .await
+        .map_err(|e| ftd::interpreter::Error::ParseError {
+            message: e.to_string(),
+            doc_id: doc.name.to_string(),
+            line_number: value.line_number(),
+        })?;
+    let page = fastn_core::markdown_page::MarkdownPage::parse(file.as_str(), content.as_str())
+        .map_err(|message| ftd::interpreter::Error::ParseError {
+            message: format!("{file}: {message}"),
+            doc_id: doc.name.to_string(),
+            line_number: value.line_number(),
+        })?;
+    doc.from_json(&page, &kind, &value)
+}
+
+/// The `.md` pages of the package, the latest first, for listings. `folder`
+/// keeps the pages in a folder, like `blog`, and `tag` the ones with the tag.
+pub async fn process_list(
+    value: ftd_ast::VariableValue,
+    kind: fastn_resolved::Kind,
+    doc: &ftd::interpreter::TDoc<'_>,
+    req_config: &mut fastn_core::RequestConfig,
+    preview_session_id: &Option<String>,
+) -> ftd::interpreter::Result<fastn_resolved::Value> {
+    // a listing changes with the pages it lists
+    req_config.response_is_cacheable = false;
+
+    let headers = match value.get_record(doc.name) {
+        Ok(val) => val.2.to_owned(),
+        Err(_e) => ftd_ast::HeaderValues::new(vec![]),
+    };
+    let folder = headers.get_optional_string_by_key("folder", doc.name, value.line_number())?;
+    let tag = headers.get_optional_string_by_key("tag", doc.name, value.line_number())?;
+    let package = req_config
+        .get_current_package(doc.name)
+        .unwrap_or_else(|_| req_config.config.package.clone());
+
+    let pages = fastn_core::markdown_page::markdown_pages(
+        &req_config.config,
+        &package,
+        folder.as_deref(),
+        tag.as_deref(),
+        preview_session_id,
+    )
+    .await
+    .map_err(|e| ftd::interpreter::Error::ParseError {
+        message: e.to_string(),
+        doc_id: doc.name.to_string(),
+        line_number: value.line_number(),
+    })?;
+    doc.from_json(&pages, &kind, &value)
+}
@@ -1,13 +1,16 @@
 pub(crate) mod apps;
+pub(crate) mod collection;
 pub(crate) mod document;
 pub(crate) mod fetch_file;
 pub(crate) mod figma_tokens;
 pub(crate) mod figma_typography_tokens;
+pub(crate) mod form_fields;
 pub(crate) mod get_data;
 // pub(crate) mod google_sheets;
 pub(crate) mod http;
 pub(crate) mod lang;
 pub(crate) mod lang_details;
+pub(crate) mod markdown_page;
 // pub(crate) mod package_query;
 // pub(crate) mod pg;
 pub(crate) mod query;
@@ -52,7 +52,7 @@ pub async fn write_manifest_file(
 
     let start = std::time::Instant::now();
 
-    print!(
+    fastn_core::progress!(
         "Processing {}/{} ... ",
         &config.package.name.as_str(),
         fastn_core::manifest::MANIFEST_FILE
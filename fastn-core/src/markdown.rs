@@ -0,0 +1,466 @@
+//! The markdown of `ftd.text` at compile time: links to documents of the
+//! package and the headings of a document, see `ftd::executor::MarkdownProfile`
+//! for the extensions a package turns on.
+
+/// `[title](target)` links whose target is a `.ftd` or `.md` file, with an
+/// optional `#fragment` or `?query` and link title.
+static DOCUMENT_LINK: once_cell::sync::Lazy<regex::Regex> = once_cell::sync::Lazy::new(|| {
+    regex::Regex::new(r#"\]\(([^()\s:#?]+\.(?:ftd|md))([#?][^()\s]*)?(\s+"[^"]*")?\)"#).unwrap()
+});
+
+/// A text with a link, and where it is written.
+struct Text<'a> {
+    text: &'a mut String,
+    /// the module the text is written in, like `<package-name>/docs/intro`
+    module: &'a str,
+    line_number: usize,
+}
+
+/// Links to `.ftd` and `.md` files of the package, `[intro](../intro.ftd)` or
+/// `[setup](/docs/setup.ftd#install)`, are replaced by the url of the
+/// document. The texts are the ones of the components of the document, and of
+/// the components and variables defined in the modules of the package it
+/// imports, each resolved from the module it is written in. With
+/// `check-links`, links to files which do not exist fail the build, with an
+/// error on the line of each of them.
+pub(crate) async fn resolve_links(
+    document: &mut ftd::interpreter::Document,
+    main: &fastn_core::Document,
+    markdown: &ftd::executor::MarkdownProfile,
+    base_url: &str,
+    ds: &fastn_ds::DocumentStore,
+    session_id: &Option<String>,
+) -> fastn_core::Result<()> {
+    let mut texts = vec![];
+    for component in document.tree.iter_mut() {
+        component_texts(component, document.name.as_str(), &mut texts);
+    }
+    for (name, thing) in document.data.iter_mut() {
+        let module = name
+            .split_once('#')
+            .map_or(name.as_str(), |(module, _)| module);
+        if module_id(module, main.package_name.as_str()).is_some() {
+            definition_texts(thing, module, &mut texts);
+        }
+    }
+
+    // the file of each module, the links in it are relative to its folder
+    let mut files = std::collections::HashMap::new();
+    for text in texts.iter() {
+        if !files.contains_key(text.module) {
+            let file = module_file(text.module, document.name.as_str(), main, ds, session_id).await;
+            files.insert(text.module, file);
+        }
+    }
+
+    let mut existing = std::collections::HashSet::new();
+    let mut errors = vec![];
+    for text in texts.iter() {
+        for captures in DOCUMENT_LINK.captures_iter(text.text) {
+            let target = &captures[1];
+            match document_id(target, files[text.module].as_str()) {
+                Some(id) if existing.contains(&id) => {}
+                Some(id) if ds.exists(&main.parent_path.join(&id), session_id).await => {
+                    existing.insert(id);
+                }
+                _ => errors.push(
+                    ftd::interpreter::Error::ParseError {
+                        message: format!("link to a document which does not exist: `{target}`"),
+                        doc_id: text.module.to_string(),
+                        line_number: text.line_number,
+                    }
+                    .with_code(ftd_p1::codes::MISSING_LINK),
+                ),
+            }
+        }
+    }
+
+    if markdown.check_links {
+        if let Some(error) = ftd_p1::Reportable::from_errors(errors) {
+            return Err(error.into());
+        }
+    }
+
+    for text in texts {
+        let resolved = DOCUMENT_LINK.replace_all(text.text, |captures: &regex::Captures| {
+            match document_id(&captures[1], files[text.module].as_str()) {
+                Some(id) if existing.contains(&id) => format!(
+                    "]({}/{}{}{})",
+                    base_url.trim_end_matches('/'),
+                    fastn_core::utils::id_to_path(id.as_str()).trim_start_matches('/'),
+                    captures.get(2).map_or("", |m| m.as_str()),
+                    captures.get(3).map_or("", |m| m.as_str()),
+                ),
+                _ => captures[0].to_string(),
+            }
+        });
+        if let std::borrow::Cow::Owned(resolved) = resolved {
+            *text.text = resolved;
+        }
+    }
+
+    Ok(())
+}
+
+/// The module without the package name, like `docs/intro` for
+/// `<package-name>/docs/intro`. `None` if the module is not in the package.
+fn module_id<'a>(module: &'a str, package_name: &str) -> Option<&'a str> {
+    match module.strip_prefix(package_name)? {
+        "" => Some(""),
+        id => id.strip_prefix('/'),
+    }
+}
+
+/// The file of the module, like `docs/intro.ftd` or `docs/intro/index.ftd` for
+/// `<package-name>/docs/intro`.
+async fn module_file(
+    module: &str,
+    document_name: &str,
+    main: &fastn_core::Document,
+    ds: &fastn_ds::DocumentStore,
+    session_id: &Option<String>,
+) -> String {
+    if module.trim_end_matches('/') == document_name.trim_end_matches('/') {
+        return main.id.to_string();
+    }
+    let id = module_id(module, main.package_name.as_str())
+        .unwrap_or_default()
+        .trim_end_matches('/');
+    if id.is_empty() {
+        return "index.ftd".to_string();
+    }
+    let file = format!("{id}.ftd");
+    if ds.exists(&main.parent_path.join(&file), session_id).await {
+        file
+    } else {
+        format!("{id}/index.ftd")
+    }
+}
+
+/// The id of the document `target` links to, from the document `current`.
+/// `None` if the target is outside the package.
+fn document_id(target: &str, current: &str) -> Option<String> {
+    // the folder of the current document
+    let mut parts: Vec<&str> = current.split('/').collect();
+    parts.pop();
+    if target.starts_with('/') {
+        parts.clear();
+    }
+    for part in target.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            part => parts.push(part),
+        }
+    }
+    Some(parts.join("/"))
+}
+
+fn definition_texts<'a>(
+    thing: &'a mut ftd::interpreter::Thing,
+    module: &'a str,
+    texts: &mut Vec<Text<'a>>,
+) {
+    match thing {
+        fastn_resolved::Definition::Component(component) => {
+            for argument in component.arguments.iter_mut() {
+                if let Some(value) = argument.value.as_mut() {
+                    property_value_texts(value, module, texts);
+                }
+            }
+            component_texts(&mut component.definition, module, texts);
+        }
+        fastn_resolved::Definition::Variable(variable) => {
+            property_value_texts(&mut variable.value, module, texts);
+            for conditional in variable.conditional_value.iter_mut() {
+                property_value_texts(&mut conditional.value, module, texts);
+            }
+        }
+        fastn_resolved::Definition::Record(record) => {
+            for field in record.fields.iter_mut() {
+                if let Some(value) = field.value.as_mut() {
+                    property_value_texts(value, module, texts);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn component_texts<'a>(
+    component: &'a mut fastn_resolved::ComponentInvocation,
+    module: &'a str,
+    texts: &mut Vec<Text<'a>>,
+) {
+    for property in component.properties.iter_mut() {
+        property_value_texts(&mut property.value, module, texts);
+    }
+    for child in component.children.iter_mut() {
+        component_texts(child, module, texts);
+    }
+}
+
+fn property_value_texts<'a>(
+    value: &'a mut fastn_resolved::PropertyValue,
+    module: &'a str,
+    texts: &mut Vec<Text<'a>>,
+) {
+    if let fastn_resolved::PropertyValue::Value {
+        value, line_number, ..
+    } = value
+    {
+        value_texts(value, module, *line_number, texts);
+    }
+}
+
+fn value_texts<'a>(
+    value: &'a mut fastn_resolved::Value,
+    module: &'a str,
+    line_number: usize,
+    texts: &mut Vec<Text<'a>>,
+) {
+    match value {
+        fastn_resolved::Value::String { text } if text.contains("](") => texts.push(Text {
+            text,
+            module,
+            line_number,
+        }),
+        fastn_resolved::Value::Record { fields: values, .. }
+        | fastn_resolved::Value::Object { values }
+        | fastn_resolved::Value::KwArgs { arguments: values }
+        | fastn_resolved::Value::Map { data: values, .. } => {
+            for value in values.values_mut() {
+                property_value_texts(value, module, texts);
+            }
+        }
+        fastn_resolved::Value::List { data, .. } => {
+            for value in data.iter_mut() {
+                property_value_texts(value, module, texts);
+            }
+        }
+        fastn_resolved::Value::OrType { value, .. } => property_value_texts(value, module, texts),
+        fastn_resolved::Value::Optional { data, .. } => {
+            if let Some(value) = data.as_mut() {
+                value_texts(value, module, line_number, texts);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The headings of the markdown in the `ftd.text`s of a document, in the
+/// order they appear, for the `document-toc` processor.
+pub(crate) fn document_headings(ast: &[ftd_ast::Ast]) -> Vec<ftd::executor::Heading> {
+    let mut headings = vec![];
+    for ast in ast {
+        if let ftd_ast::Ast::ComponentInvocation(component) = ast {
+            component_headings(component, &mut headings);
+        }
+    }
+    headings
+}
+
+fn component_headings(
+    component: &ftd_ast::ComponentInvocation,
+    headings: &mut Vec<ftd::executor::Heading>,
+) {
+    if component.name.eq("ftd.text") {
+        for property in component.properties.iter() {
+            let is_text = match property.source {
+                ftd_ast::PropertySource::Caption | ftd_ast::PropertySource::Body => true,
+                ftd_ast::PropertySource::Header { ref name, .. } => name.eq("text"),
+            };
+            if let (true, ftd_ast::VariableValue::String { value, .. }) = (is_text, &property.value)
+            {
+                headings.extend(ftd::executor::headings(value));
+            }
+        }
+    }
+    for child in component.children.iter() {
+        component_headings(child, headings);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const INTRO: &str = indoc::indoc! {"
+        -- import: foo/docs/lib
+
+        -- ftd.text: See [setup](setup.ftd#install) and [lib](lib.ftd)
+
+        -- lib.note:
+
+        -- ftd.text: $lib.missing
+
+        -- ftd.text: Read [the old intro](old.ftd)
+    "};
+
+    const LIB: &str = indoc::indoc! {"
+        -- component note:
+        caption text: Back to [the intro](./intro.ftd)
+
+        -- ftd.text: $note.text
+
+        -- end: note
+
+        -- string missing: [gone](../gone.ftd)
+    "};
+
+    /// `docs/intro.ftd` of the package `foo`, interpreted, with the package
+    /// written to a temporary folder.
+    async fn interpret(
+        name: &str,
+    ) -> (
+        ftd::interpreter::Document,
+        fastn_core::Document,
+        fastn_ds::DocumentStore,
+    ) {
+        let root =
+            std::env::temp_dir().join(format!("fastn-markdown-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        for (file, content) in [
+            ("docs/intro.ftd", INTRO),
+            ("docs/lib.ftd", LIB),
+            ("docs/setup.ftd", ""),
+        ] {
+            std::fs::write(root.join(file), content).unwrap();
+        }
+        let root = root.to_str().unwrap();
+
+        let mut s = ftd::interpreter::interpret("foo/docs/intro", INTRO).unwrap();
+        let document = loop {
+            match s {
+                ftd::interpreter::Interpreter::Done { document } => break document,
+                ftd::interpreter::Interpreter::StuckOnImport { module, state, .. } => {
+                    let source = if module == "foo/docs/lib" { LIB } else { "" };
+                    let document =
+                        ftd::interpreter::ParsedDocument::parse(&module, source).unwrap();
+                    s = state
+                        .continue_after_import(&module, document, vec![], vec![], 0)
+                        .unwrap();
+                }
+                _ => unreachable!(),
+            }
+        };
+        let main = fastn_core::Document {
+            package_name: "foo".to_string(),
+            id: "docs/intro.ftd".to_string(),
+            content: INTRO.to_string(),
+            parent_path: fastn_ds::Path::new(root),
+        };
+        let ds = fastn_ds::DocumentStore::new(root, Default::default());
+        (document, main, ds)
+    }
+
+    #[tokio::test]
+    async fn resolve_links() {
+        let (mut document, main, ds) = interpret("resolve").await;
+        let markdown = ftd::executor::MarkdownProfile::default();
+        super::resolve_links(&mut document, &main, &markdown, "/", &ds, &None)
+            .await
+            .unwrap();
+
+        let text = |v: &fastn_resolved::PropertyValue| match v {
+            fastn_resolved::PropertyValue::Value {
+                value: fastn_resolved::Value::String { text },
+                ..
+            } => text.to_string(),
+            t => panic!("not a string: {t:?}"),
+        };
+        assert_eq!(
+            text(&document.tree[0].properties[0].value),
+            "See [setup](/docs/setup/#install) and [lib](/docs/lib/)"
+        );
+        // links which do not exist are left as they are
+        assert_eq!(
+            text(&document.tree[3].properties[0].value),
+            "Read [the old intro](old.ftd)"
+        );
+        // the caption of `note` is resolved from `docs/lib.ftd`
+        let fastn_resolved::Definition::Component(note) = &document.data["foo/docs/lib#note"]
+        else {
+            panic!("`note` is not a component");
+        };
+        assert_eq!(
+            text(note.arguments[0].value.as_ref().unwrap()),
+            "Back to [the intro](/docs/intro/)"
+        );
+    }
+
+    #[tokio::test]
+    async fn check_links() {
+        use ftd_p1::Reportable;
+
+        let (mut document, main, ds) = interpret("check").await;
+        let markdown = ftd::executor::MarkdownProfile {
+            check_links: true,
+            ..Default::default()
+        };
+        let error = super::resolve_links(&mut document, &main, &markdown, "/", &ds, &None)
+            .await
+            .unwrap_err();
+        let fastn_core::Error::FTDInterpreterError(error) = error else {
+            panic!("not an interpreter error: {error:?}");
+        };
+        let mut errors: Vec<_> = error
+            .into_errors()
+            .into_iter()
+            .map(|e| {
+                (
+                    e.doc_id().map(str::to_string),
+                    e.line_number(),
+                    e.code(),
+                    e.message(),
+                )
+            })
+            .collect();
+        errors.sort();
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    Some("foo/docs/intro".to_string()),
+                    Some(9),
+                    "FTD0302",
+                    "link to a document which does not exist: `old.ftd`".to_string()
+                ),
+                (
+                    Some("foo/docs/lib".to_string()),
+                    Some(8),
+                    "FTD0302",
+                    "link to a document which does not exist: `../gone.ftd`".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn document_id() {
+        assert_eq!(
+            super::document_id("setup.ftd", "docs/intro.ftd"),
+            Some("docs/setup.ftd".to_string())
+        );
+        assert_eq!(
+            super::document_id("../index.ftd", "docs/intro/index.ftd"),
+            Some("docs/index.ftd".to_string())
+        );
+        assert_eq!(
+            super::document_id("/blog/post.md", "docs/intro.ftd"),
+            Some("blog/post.md".to_string())
+        );
+        assert_eq!(super::document_id("../../a.ftd", "docs/intro.ftd"), None);
+    }
+
+    #[test]
+    fn module_id() {
+        assert_eq!(
+            super::module_id("foo/docs/intro", "foo"),
+            Some("docs/intro")
+        );
+        assert_eq!(super::module_id("foo", "foo"), Some(""));
+        assert_eq!(super::module_id("foo-bar/docs", "foo"), None);
+        assert_eq!(super::module_id("ftd", "foo"), None);
+    }
+}
@@ -0,0 +1,347 @@
+//! The `.md` files of a package are pages. The front matter of a page, YAML
+//! between `---` lines or TOML between `+++` lines, has its `title`,
+//! `description`, `date`, `tags` and `layout`. The page is shown with a layout
+//! component, which gets the front matter and the markdown as a
+//! `pr.markdown-page` record, and the `markdown-pages` processor lists the
+//! pages of the package.
+
+/// A markdown page, the `markdown-page` record of `fastn/processors`.
+#[derive(serde::Serialize, Debug, Default)]
+pub(crate) struct MarkdownPage {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub date: Option<String>,
+    pub tags: Vec<String>,
+    #[serde(skip)]
+    pub layout: Option<String>,
+    /// The url of the page, like `/blog/hello/`.
+    pub url: String,
+    /// The file of the page, like `blog/hello.md`.
+    pub document: String,
+    /// The markdown after the front matter.
+    pub body: String,
+    /// The other keys of the front matter.
+    pub extra: Vec<fastn_core::library2022::KeyValueData>,
+}
+
+impl MarkdownPage {
+    /// The page of the markdown `content` of the file `document`.
+    pub(crate) fn parse(document: &str, content: &str) -> Result<MarkdownPage, String> {
+        let (front_matter, body) = split_front_matter(content)?;
+        let mut page = MarkdownPage {
+            url: format!(
+                "/{}",
+                fastn_core::utils::id_to_path(document).trim_start_matches('/')
+            ),
+            document: document.to_string(),
+            body: body.trim().to_string(),
+            ..Default::default()
+        };
+        for (key, value) in front_matter {
+            match (key.as_str(), value) {
+                ("title", FrontMatterValue::String(value)) => page.title = Some(value),
+                ("description", FrontMatterValue::String(value)) => page.description = Some(value),
+                ("date", FrontMatterValue::String(value)) => page.date = Some(value),
+                ("layout", FrontMatterValue::String(value)) => page.layout = Some(value),
+                ("tags", FrontMatterValue::List(tags)) => page.tags = tags,
+                ("tags", FrontMatterValue::String(tag)) => page.tags = vec![tag],
+                ("title" | "description" | "date" | "layout", FrontMatterValue::List(_)) => {
+                    return Err(format!("`{key}` of the front matter has to be a string"));
+                }
+                (_, value) => page.extra.push(fastn_core::library2022::KeyValueData {
+                    key,
+                    value: match value {
+                        FrontMatterValue::String(value) => value,
+                        FrontMatterValue::List(values) => values.join(", "),
+                    },
+                }),
+            }
+        }
+        Ok(page)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum FrontMatterValue {
+    String(String),
+    List(Vec<String>),
+}
+
+type FrontMatter = Vec<(String, FrontMatterValue)>;
+
+/// The keys and values of the front matter, and the markdown after it. The
+/// front matter is the common subset of YAML and TOML: strings, quoted or
+/// not, numbers and dates, and lists of them, inline (`[a, b]`) or, in YAML,
+/// one `- item` per line.
+fn split_front_matter(content: &str) -> Result<(FrontMatter, &str), String> {
+    let content = content.trim_start_matches('\u{feff}');
+    let first_line = content.lines().next().unwrap_or_default().trim_end();
+    let (fence, separator) = match first_line {
+        "---" => ("---", ':'),
+        "+++" => ("+++", '='),
+        _ => return Ok((vec![], content)),
+    };
+
+    let mut front_matter: FrontMatter = vec![];
+    let mut offset = content.find('\n').map_or(content.len(), |i| i + 1);
+    let mut line_number = 1;
+    loop {
+        let Some(line) = content[offset..].split_inclusive('\n').next() else {
+            return Err(format!("the front matter is not closed with `{fence}`"));
+        };
+        offset += line.len();
+        line_number += 1;
+        let line = line.trim_end();
+        let trimmed = line.trim();
+        if line == fence || (fence == "---" && line == "...") {
+            break;
+        }
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or((trimmed == "-").then_some(""))
+        {
+            match front_matter.last_mut() {
+                Some((_, FrontMatterValue::List(items))) if fence == "---" => {
+                    items.push(unquote(item));
+                    continue;
+                }
+                Some((_, value @ FrontMatterValue::String(_)))
+                    if fence == "---" && *value == FrontMatterValue::String(String::new()) =>
+                {
+                    *value = FrontMatterValue::List(vec![unquote(item)]);
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        let Some((key, value)) = line.split_once(separator) else {
+            return Err(format!(
+                "line {line_number} of the front matter is not `key{}value`",
+                if separator == ':' { ": " } else { " = " }
+            ));
+        };
+        let key = key.trim().trim_matches(['"', '\'']).to_string();
+        let value = value.trim();
+        let value = match value
+            .strip_prefix('[')
+            .and_then(|value| value.strip_suffix(']'))
+        {
+            Some(items) => FrontMatterValue::List(
+                items
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(unquote)
+                    .collect(),
+            ),
+            None => FrontMatterValue::String(unquote(value)),
+        };
+        front_matter.push((key, value));
+    }
+    Ok((front_matter, &content[offset..]))
+}
+
+/// A value without its quotes, or without a comment after it.
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    for quote in ['"', '\''] {
+        if let Some(value) = value
+            .strip_prefix(quote)
+            .and_then(|value| value.strip_suffix(quote))
+        {
+            return value.replace("\\\"", "\"");
+        }
+    }
+    value
+        .split_once(" #")
+        .map_or(value, |(value, _)| value)
+        .trim()
+        .to_string()
+}
+
+/// The `.ftd` document a markdown page is shown with: the layout of the page,
+/// or the one in `fastn.markdown`, with the `markdown-page` processor reading
+/// the page. Without a layout the markdown is shown in an `ftd.text`.
+pub(crate) fn ftd_document(
+    config: &fastn_core::Config,
+    document: &fastn_core::Document,
+) -> fastn_core::Result<fastn_core::Document> {
+    let page = MarkdownPage::parse(document.id.as_str(), document.content.as_str()).map_err(
+        |message| fastn_core::Error::PackageError {
+            message: format!("{}: {message}", document.id),
+        },
+    )?;
+    let package_layout = config
+        .find_package_else_default(document.package_name.as_str(), None)
+        .markdown
+        .layout;
+    let layout = match (page.layout, package_layout) {
+        (Some(layout), _) if layout.contains('.') => Some(layout),
+        // a component of the module of the layout of the package
+        (Some(layout), Some(package_layout)) => package_layout
+            .rsplit_once('.')
+            .map(|(module, _)| format!("{module}.{layout}")),
+        (Some(layout), None) => {
+            return Err(fastn_core::Error::PackageError {
+                message: format!(
+                    "{}: the layout `{layout}` needs its module, like `<package>/layouts.{layout}`, \
+                    or a `layout` in `fastn.markdown`",
+                    document.id
+                ),
+            });
+        }
+        (None, layout) => layout,
+    };
+
+    // the checksum of the markdown is in the document so the build cache sees
+    // the changes to the page
+    let mut content = format!(
+        ";; {} {}\n\n-- import: fastn/processors as pr\n",
+        document.id,
+        fastn_core::utils::generate_hash(document.content.as_str())
+    );
+    let component = match layout.as_ref().and_then(|layout| layout.rsplit_once('.')) {
+        Some((module, component)) => {
+            content.push_str(format!("-- import: {module} as markdown-layout\n").as_str());
+            Some(component)
+        }
+        None => None,
+    };
+    content.push_str(
+        format!(
+            "\n-- pr.markdown-page markdown-page:\n$processor$: pr.markdown-page\nfile: {}\n\n",
+            document.id
+        )
+        .as_str(),
+    );
+    match component {
+        Some(component) => content
+            .push_str(format!("-- markdown-layout.{component}:\npage: $markdown-page\n").as_str()),
+        None => content.push_str("-- ftd.text: $markdown-page.body\n"),
+    }
+
+    Ok(fastn_core::Document {
+        package_name: document.package_name.clone(),
+        id: ftd_id(document.id.as_str()),
+        content,
+        parent_path: document.parent_path.clone(),
+    })
+}
+
+/// The id of the `.ftd` document with the url of a markdown page,
+/// `blog/hello.ftd` for `blog/hello.md` and `blog/index.ftd` for
+/// `blog/README.md`.
+pub(crate) fn ftd_id(id: &str) -> String {
+    let id = id.strip_suffix(".md").unwrap_or(id);
+    match id.rsplit_once('/') {
+        Some((folder, "README" | "index")) => format!("{folder}/index.ftd"),
+        None if id == "README" || id == "index" => "index.ftd".to_string(),
+        _ => format!("{id}.ftd"),
+    }
+}
+
+/// The markdown pages of the package in `folder`, the latest first, with the
+/// `tag` if one is given.
+pub(crate) async fn markdown_pages(
+    config: &fastn_core::Config,
+    package: &fastn_core::Package,
+    folder: Option<&str>,
+    tag: Option<&str>,
+    session_id: &Option<String>,
+) -> fastn_core::Result<Vec<MarkdownPage>> {
+    let folder = folder.map(|folder| format!("{}/", folder.trim_matches('/')));
+    let mut pages = vec![];
+    for file in config.get_files(package, session_id).await? {
+        let fastn_core::File::Markdown(document) = file else {
+            continue;
+        };
+        if folder
+            .as_ref()
+            .is_some_and(|folder| folder != "/" && !document.id.starts_with(folder.as_str()))
+        {
+            continue;
+        }
+        let page = MarkdownPage::parse(document.id.as_str(), document.content.as_str()).map_err(
+            |message| fastn_core::Error::PackageError {
+                message: format!("{}: {message}", document.id),
+            },
+        )?;
+        if tag.is_some_and(|tag| !page.tags.iter().any(|t| t == tag)) {
+            continue;
+        }
+        pages.push(page);
+    }
+    // ISO dates sort as strings, pages without a date go last
+    pages.sort_by(|a, b| {
+        b.date
+            .is_some()
+            .cmp(&a.date.is_some())
+            .then_with(|| b.date.cmp(&a.date))
+            .then_with(|| a.url.cmp(&b.url))
+    });
+    Ok(pages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrontMatterValue::{List, String as S};
+
+    #[test]
+    fn front_matter() {
+        let (front_matter, body) = super::split_front_matter(
+            "---\ntitle: \"Hello: world\"\ndate: 2024-05-01\ntags: [rust, 'web']\nauthors:\n  - a\n  - b\n---\n# Hi\n",
+        )
+        .unwrap();
+        assert_eq!(
+            front_matter,
+            vec![
+                ("title".to_string(), S("Hello: world".to_string())),
+                ("date".to_string(), S("2024-05-01".to_string())),
+                (
+                    "tags".to_string(),
+                    List(vec!["rust".to_string(), "web".to_string()])
+                ),
+                (
+                    "authors".to_string(),
+                    List(vec!["a".to_string(), "b".to_string()])
+                ),
+            ]
+        );
+        assert_eq!(body, "# Hi\n");
+
+        let (front_matter, body) =
+            super::split_front_matter("+++\ntitle = \"Hi\"\ntags = [\"a\"]\n+++\nbody").unwrap();
+        assert_eq!(
+            front_matter,
+            vec![
+                ("title".to_string(), S("Hi".to_string())),
+                ("tags".to_string(), List(vec!["a".to_string()])),
+            ]
+        );
+        assert_eq!(body, "body");
+
+        assert_eq!(
+            super::split_front_matter("# No front matter").unwrap(),
+            (vec![], "# No front matter")
+        );
+        assert_eq!(
+            super::split_front_matter("---\ntitle: x\n").unwrap_err(),
+            "the front matter is not closed with `---`"
+        );
+        assert_eq!(
+            super::split_front_matter("---\ntitle x\n---\n").unwrap_err(),
+            "line 2 of the front matter is not `key: value`"
+        );
+    }
+
+    #[test]
+    fn ftd_id() {
+        assert_eq!(super::ftd_id("README.md"), "index.ftd");
+        assert_eq!(super::ftd_id("blog/README.md"), "blog/index.ftd");
+        assert_eq!(super::ftd_id("blog/index.md"), "blog/index.ftd");
+        assert_eq!(super::ftd_id("blog/hello.md"), "blog/hello.ftd");
+    }
+}
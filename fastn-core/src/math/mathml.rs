@@ -0,0 +1,1029 @@
+//! A LaTeX subset to MathML: identifiers, numbers and operators, `^` and `_`,
+//! `\frac`, `\sqrt`, `\binom`, greek letters and the common symbols, large
+//! operators with limits, functions like `\sin`, accents, `\mathbb` and the
+//! other fonts, `\text`, spaces, `\left` / `\right`, and the `matrix`,
+//! `cases`, `aligned` and `array` environments.
+
+/// `latex` as a `<math>` element, a block if `display`.
+pub(crate) fn to_mathml(latex: &str, display: bool) -> Result<String, String> {
+    let mut parser = Parser {
+        tokens: tokenize(latex),
+        position: 0,
+    };
+    let rows = parser.table(None)?;
+    let body = match rows.as_slice() {
+        [row] if row.len() == 1 => row[0].clone(),
+        _ => table(rows, None, None, Some("right left")),
+    };
+    Ok(format!(
+        "<math{}><semantics><mrow>{}</mrow><annotation encoding=\"application/x-tex\">{}</annotation></semantics></math>",
+        if display { " display=\"block\"" } else { "" },
+        body,
+        escape(latex.trim())
+    ))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Char(char),
+    Command(String),
+    Space,
+    BeginGroup,
+    EndGroup,
+    Superscript,
+    Subscript,
+    Ampersand,
+}
+
+fn tokenize(latex: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut chars = latex.chars().peekable();
+    while let Some(c) = chars.next() {
+        tokens.push(match c {
+            '\\' => match chars.next() {
+                Some(c) if c.is_ascii_alphabetic() => {
+                    let mut name = c.to_string();
+                    while let Some(c) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+                        name.push(c);
+                    }
+                    Token::Command(name)
+                }
+                Some(c) => Token::Command(c.to_string()),
+                None => Token::Char('\\'),
+            },
+            '%' => {
+                while chars.next_if(|c| *c != '\n').is_some() {}
+                continue;
+            }
+            '{' => Token::BeginGroup,
+            '}' => Token::EndGroup,
+            '^' => Token::Superscript,
+            '_' => Token::Subscript,
+            '&' => Token::Ampersand,
+            c if c.is_whitespace() => {
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                Token::Space
+            }
+            c => Token::Char(c),
+        });
+    }
+    tokens
+}
+
+/// Why a row of items ended.
+#[derive(Debug, PartialEq)]
+enum Stop {
+    Eof,
+    EndGroup,
+    Right,
+    EndEnvironment(String),
+    Ampersand,
+    NewRow,
+}
+
+/// A parsed element, `limits` if its scripts go below and above it, like
+/// the ones of `\sum`.
+struct Atom {
+    mathml: String,
+    limits: bool,
+}
+
+impl Atom {
+    fn new(mathml: String) -> Atom {
+        Atom {
+            mathml,
+            limits: false,
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.tokens.get(self.position) == Some(&Token::Space) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<&Token> {
+        self.skip_spaces();
+        self.tokens.get(self.position)
+    }
+
+    /// The items up to the end of the input, a group, a cell or a row.
+    fn row(&mut self) -> Result<(Vec<String>, Stop), String> {
+        let mut items: Vec<Atom> = vec![];
+        let stop = loop {
+            self.skip_spaces();
+            let Some(token) = self.next() else {
+                break Stop::Eof;
+            };
+            match token {
+                Token::EndGroup => break Stop::EndGroup,
+                Token::Ampersand => break Stop::Ampersand,
+                Token::Command(ref name) if name == "\\" || name == "cr" => break Stop::NewRow,
+                Token::Command(ref name) if name == "right" => break Stop::Right,
+                Token::Command(ref name) if name == "end" => {
+                    break Stop::EndEnvironment(self.group_text()?);
+                }
+                Token::Command(ref name) if name == "limits" || name == "nolimits" => {
+                    if let Some(last) = items.last_mut() {
+                        last.limits = name == "limits";
+                    }
+                }
+                Token::Command(ref name)
+                    if matches!(
+                        name.as_str(),
+                        "displaystyle" | "textstyle" | "scriptstyle" | "!"
+                    ) => {}
+                Token::Superscript | Token::Subscript => {
+                    self.position -= 1;
+                    let base = items
+                        .pop()
+                        .unwrap_or_else(|| Atom::new("<mrow></mrow>".into()));
+                    items.push(self.scripts(base)?);
+                }
+                Token::Char('\'') => {
+                    let mut primes = 1;
+                    while self.tokens.get(self.position) == Some(&Token::Char('\'')) {
+                        self.position += 1;
+                        primes += 1;
+                    }
+                    let prime = ["′", "″", "‴", "⁗"][primes.min(4) - 1];
+                    let base = items
+                        .pop()
+                        .unwrap_or_else(|| Atom::new("<mrow></mrow>".into()));
+                    items.push(Atom::new(format!(
+                        "<msup>{}<mo>{prime}</mo></msup>",
+                        base.mathml
+                    )));
+                }
+                token => items.push(self.atom(token)?),
+            }
+        };
+        Ok((items.into_iter().map(|atom| atom.mathml).collect(), stop))
+    }
+
+    /// The `^` and `_` after `base`.
+    fn scripts(&mut self, base: Atom) -> Result<Atom, String> {
+        let mut subscript = None;
+        let mut superscript = None;
+        loop {
+            match self.peek() {
+                Some(Token::Superscript) if superscript.is_none() => {
+                    self.position += 1;
+                    superscript = Some(self.argument()?);
+                }
+                Some(Token::Subscript) if subscript.is_none() => {
+                    self.position += 1;
+                    subscript = Some(self.argument()?);
+                }
+                Some(Token::Superscript) => return Err("double superscript".to_string()),
+                Some(Token::Subscript) => return Err("double subscript".to_string()),
+                _ => break,
+            }
+        }
+        let (sub, sup, subsup) = if base.limits {
+            ("munder", "mover", "munderover")
+        } else {
+            ("msub", "msup", "msubsup")
+        };
+        Ok(Atom::new(match (subscript, superscript) {
+            (Some(subscript), Some(superscript)) => {
+                format!(
+                    "<{subsup}>{}{subscript}{superscript}</{subsup}>",
+                    base.mathml
+                )
+            }
+            (Some(subscript), None) => format!("<{sub}>{}{subscript}</{sub}>", base.mathml),
+            (None, Some(superscript)) => format!("<{sup}>{}{superscript}</{sup}>", base.mathml),
+            (None, None) => base.mathml,
+        }))
+    }
+
+    /// The argument of a command or a script: a group or a single token.
+    fn argument(&mut self) -> Result<String, String> {
+        match self.peek() {
+            Some(Token::BeginGroup) => {
+                self.position += 1;
+                self.group()
+            }
+            Some(Token::Char(c)) => {
+                let c = *c;
+                self.position += 1;
+                Ok(char_atom(c))
+            }
+            Some(Token::Command(_)) => {
+                let token = self.next().unwrap();
+                Ok(self.atom(token)?.mathml)
+            }
+            _ => Err("missing argument".to_string()),
+        }
+    }
+
+    /// The items up to the `}` of a group, after its `{`.
+    fn group(&mut self) -> Result<String, String> {
+        match self.row()? {
+            (items, Stop::EndGroup) => Ok(mrow(items)),
+            (_, Stop::Eof) => Err("missing `}`".to_string()),
+            (_, stop) => Err(unexpected(stop)),
+        }
+    }
+
+    /// The text of a `{...}` group, for `\text`, `\begin` and the like, or
+    /// of a single token.
+    fn group_text(&mut self) -> Result<String, String> {
+        match self.peek() {
+            Some(Token::BeginGroup) => {}
+            Some(Token::Char(c)) => {
+                let c = *c;
+                self.position += 1;
+                return Ok(c.to_string());
+            }
+            _ => return Err("missing `{`".to_string()),
+        }
+        self.position += 1;
+        let mut text = String::new();
+        let mut depth = 0;
+        loop {
+            match self.next() {
+                None => return Err("missing `}`".to_string()),
+                Some(Token::EndGroup) if depth == 0 => return Ok(text),
+                Some(Token::EndGroup) => {
+                    depth -= 1;
+                    text.push('}');
+                }
+                Some(Token::BeginGroup) => {
+                    depth += 1;
+                    text.push('{');
+                }
+                Some(Token::Char(c)) => text.push(c),
+                Some(Token::Space) => text.push(' '),
+                Some(Token::Superscript) => text.push('^'),
+                Some(Token::Subscript) => text.push('_'),
+                Some(Token::Ampersand) => text.push('&'),
+                Some(Token::Command(name)) if name.chars().all(|c| c.is_ascii_alphabetic()) => {
+                    text.push('\\');
+                    text.push_str(name.as_str());
+                }
+                Some(Token::Command(name)) => text.push_str(if name == "\\" { " " } else { &name }),
+            }
+        }
+    }
+
+    /// The rows of a table, up to the `\end` of `environment`, or to the end
+    /// of the input. A row is a list of cells.
+    fn table(&mut self, environment: Option<&str>) -> Result<Vec<Vec<String>>, String> {
+        let mut rows = vec![];
+        let mut cells = vec![];
+        loop {
+            let (items, stop) = self.row()?;
+            cells.push(mrow(items));
+            match (stop, environment) {
+                (Stop::Ampersand, _) => {}
+                (Stop::NewRow, _) => rows.push(std::mem::take(&mut cells)),
+                (Stop::Eof, None) => break,
+                (Stop::EndEnvironment(end), Some(environment)) if end == environment => break,
+                (Stop::EndEnvironment(end), Some(environment)) => {
+                    return Err(format!(
+                        "`\\begin{{{environment}}}` ended by `\\end{{{end}}}`"
+                    ));
+                }
+                (Stop::Eof, Some(environment)) => {
+                    return Err(format!("missing `\\end{{{environment}}}`"));
+                }
+                (stop, _) => return Err(unexpected(stop)),
+            }
+        }
+        // a `\\` after the last row
+        if cells.len() > 1 || cells[0] != "<mrow></mrow>" || rows.is_empty() {
+            rows.push(cells);
+        }
+        Ok(rows)
+    }
+
+    fn atom(&mut self, token: Token) -> Result<Atom, String> {
+        Ok(Atom::new(match token {
+            Token::Char(c) if c.is_ascii_digit() => {
+                let mut number = c.to_string();
+                loop {
+                    match (
+                        self.tokens.get(self.position),
+                        self.tokens.get(self.position + 1),
+                    ) {
+                        (Some(Token::Char(c)), _) if c.is_ascii_digit() => number.push(*c),
+                        (Some(Token::Char('.')), Some(Token::Char(c))) if c.is_ascii_digit() => {
+                            number.push('.')
+                        }
+                        _ => break,
+                    }
+                    self.position += 1;
+                }
+                format!("<mn>{number}</mn>")
+            }
+            Token::Char(c) => char_atom(c),
+            Token::BeginGroup => self.group()?,
+            Token::Command(name) => return self.command(name.as_str()),
+            token => return Err(format!("unexpected `{token:?}`")),
+        }))
+    }
+
+    fn command(&mut self, name: &str) -> Result<Atom, String> {
+        if let Some(c) = identifier(name) {
+            // upper case greek letters are upright
+            return Ok(Atom::new(if c.is_uppercase() {
+                format!("<mi mathvariant=\"normal\">{c}</mi>")
+            } else {
+                format!("<mi>{c}</mi>")
+            }));
+        }
+        if let Some(c) = operator(name) {
+            return Ok(Atom::new(format!("<mo>{}</mo>", escape(c))));
+        }
+        if let Some((c, limits)) = large_operator(name) {
+            return Ok(Atom {
+                mathml: format!("<mo largeop=\"true\" movablelimits=\"{limits}\">{c}</mo>"),
+                limits,
+            });
+        }
+        if FUNCTIONS.contains(&name) {
+            return Ok(Atom::new(format!("<mi>{name}</mi>")));
+        }
+        if let Some(text) = LIMIT_FUNCTIONS
+            .iter()
+            .find_map(|(f, text)| (*f == name).then_some(*text))
+        {
+            return Ok(Atom {
+                mathml: format!("<mo movablelimits=\"true\">{text}</mo>"),
+                limits: true,
+            });
+        }
+        if let Some((accent, under)) = accent(name) {
+            let argument = self.argument()?;
+            return Ok(Atom::new(if under {
+                format!("<munder accentunder=\"true\">{argument}<mo>{accent}</mo></munder>")
+            } else {
+                format!("<mover accent=\"true\">{argument}<mo>{accent}</mo></mover>")
+            }));
+        }
+        if let Some(width) = space(name) {
+            return Ok(Atom::new(format!("<mspace width=\"{width}\"></mspace>")));
+        }
+        if let Some(font) = Font::from_command(name) {
+            let text = self.group_text()?;
+            return Ok(Atom::new(font.apply(text.as_str())));
+        }
+
+        Ok(Atom::new(match name {
+            "frac" | "dfrac" | "tfrac" | "cfrac" => {
+                let numerator = self.argument()?;
+                let denominator = self.argument()?;
+                format!("<mfrac>{numerator}{denominator}</mfrac>")
+            }
+            "binom" | "dbinom" | "tbinom" => {
+                let n = self.argument()?;
+                let k = self.argument()?;
+                format!(
+                    "<mrow><mo>(</mo><mfrac linethickness=\"0\">{n}{k}</mfrac><mo>)</mo></mrow>"
+                )
+            }
+            "sqrt" => {
+                let index = if self.peek() == Some(&Token::Char('[')) {
+                    self.position += 1;
+                    Some(self.optional_argument()?)
+                } else {
+                    None
+                };
+                let radicand = self.argument()?;
+                match index {
+                    Some(index) => format!("<mroot>{radicand}{index}</mroot>"),
+                    None => format!("<msqrt>{radicand}</msqrt>"),
+                }
+            }
+            "text" | "textrm" | "textnormal" | "mbox" | "textit" | "textbf" => {
+                let text = self.group_text()?;
+                // spaces at the ends of `mtext` are not shown
+                let text = escape(text.as_str());
+                let start = text.len() - text.trim_start().len();
+                let end = text.len() - text.trim_end().len();
+                format!(
+                    "<mtext>{}{}{}</mtext>",
+                    "&#160;".repeat(start),
+                    text.trim(),
+                    "&#160;".repeat(end)
+                )
+            }
+            "operatorname" => {
+                let text = self.group_text()?;
+                format!("<mi mathvariant=\"normal\">{}</mi>", escape(text.as_str()))
+            }
+            "left" => {
+                let open = self.delimiter()?;
+                let (items, stop) = self.row()?;
+                if stop != Stop::Right {
+                    return Err("`\\left` without `\\right`".to_string());
+                }
+                let close = self.delimiter()?;
+                format!(
+                    "<mrow>{}{}{}</mrow>",
+                    fence(open.as_str(), "prefix"),
+                    items.join(""),
+                    fence(close.as_str(), "postfix")
+                )
+            }
+            "middle" => {
+                let delimiter = self.delimiter()?;
+                format!("<mo stretchy=\"true\">{delimiter}</mo>")
+            }
+            "big" | "bigl" | "bigr" | "Big" | "Bigl" | "Bigr" | "bigg" | "biggl" | "biggr"
+            | "Bigg" | "Biggl" | "Biggr" => {
+                let size = match name.trim_end_matches(['l', 'r']) {
+                    "big" => "1.2em",
+                    "Big" => "1.8em",
+                    "bigg" => "2.4em",
+                    _ => "3em",
+                };
+                let delimiter = self.delimiter()?;
+                format!("<mo minsize=\"{size}\" maxsize=\"{size}\">{delimiter}</mo>")
+            }
+            "not" => {
+                let negated = match self.next() {
+                    Some(Token::Char('=')) => "≠".to_string(),
+                    Some(Token::Char(c)) => format!("{}\u{338}", escape(c.to_string().as_str())),
+                    Some(Token::Command(name)) if name == "in" => "∉".to_string(),
+                    Some(Token::Command(name)) => match operator(name.as_str()) {
+                        Some(c) => format!("{c}\u{338}"),
+                        None => return Err(format!("cannot negate `\\{name}`")),
+                    },
+                    _ => return Err("missing argument of `\\not`".to_string()),
+                };
+                format!("<mo>{negated}</mo>")
+            }
+            "begin" => {
+                let environment = self.group_text()?;
+                self.environment(environment.as_str())?
+            }
+            "{" | "lbrace" => "<mo>{</mo>".to_string(),
+            "}" | "rbrace" => "<mo>}</mo>".to_string(),
+            "|" => "<mo>‖</mo>".to_string(),
+            "#" | "%" | "&" | "$" | "_" => format!("<mo>{}</mo>", escape(name)),
+            name => return Err(format!("unknown command `\\{name}`")),
+        }))
+    }
+
+    /// The `[...]` after `\sqrt`, after its `[`.
+    fn optional_argument(&mut self) -> Result<String, String> {
+        let end = self.tokens[self.position..]
+            .iter()
+            .position(|token| token == &Token::Char(']'))
+            .ok_or("missing `]`")?;
+        let mut parser = Parser {
+            tokens: self.tokens[self.position..self.position + end].to_vec(),
+            position: 0,
+        };
+        self.position += end + 1;
+        match parser.row()? {
+            (items, Stop::Eof) => Ok(mrow(items)),
+            (_, stop) => Err(unexpected(stop)),
+        }
+    }
+
+    /// The delimiter after `\left`, `\right`, `\big` and the like, empty for
+    /// `.`.
+    fn delimiter(&mut self) -> Result<String, String> {
+        Ok(match self.peek().cloned() {
+            Some(Token::Char('.')) => String::new(),
+            Some(Token::Char(c)) => escape(c.to_string().as_str()),
+            Some(Token::Command(name)) => match name.as_str() {
+                "{" | "lbrace" => "{".to_string(),
+                "}" | "rbrace" => "}".to_string(),
+                "|" | "Vert" => "‖".to_string(),
+                "vert" => "&#124;".to_string(),
+                name => match operator(name) {
+                    Some(c) => escape(c),
+                    None => return Err(format!("`\\{name}` is not a delimiter")),
+                },
+            },
+            _ => return Err("missing delimiter".to_string()),
+        })
+        .inspect(|_| self.position += 1)
+    }
+
+    fn environment(&mut self, name: &str) -> Result<String, String> {
+        let (open, close, align) = match name {
+            "matrix" | "smallmatrix" => (None, None, None),
+            "pmatrix" => (Some("("), Some(")"), None),
+            "bmatrix" => (Some("["), Some("]"), None),
+            "Bmatrix" => (Some("{"), Some("}"), None),
+            "vmatrix" => (Some("&#124;"), Some("&#124;"), None),
+            "Vmatrix" => (Some("‖"), Some("‖"), None),
+            "cases" => (Some("{"), None, Some("left left")),
+            "aligned" | "align" | "align*" | "split" => (None, None, Some("right left")),
+            "gathered" | "gather" | "gather*" => (None, None, None),
+            "array" => {
+                let columns = self
+                    .group_text()?
+                    .chars()
+                    .filter_map(|c| match c {
+                        'l' => Some("left"),
+                        'c' => Some("center"),
+                        'r' => Some("right"),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let rows = self.table(Some(name))?;
+                return Ok(table(rows, None, None, Some(columns.as_str())));
+            }
+            name => return Err(format!("unknown environment `{name}`")),
+        };
+        let rows = self.table(Some(name))?;
+        Ok(table(rows, open, close, align))
+    }
+}
+
+fn table(
+    rows: Vec<Vec<String>>,
+    open: Option<&str>,
+    close: Option<&str>,
+    align: Option<&str>,
+) -> String {
+    let rows = rows
+        .into_iter()
+        .map(|cells| {
+            let cells = cells
+                .into_iter()
+                .map(|cell| format!("<mtd>{cell}</mtd>"))
+                .collect::<String>();
+            format!("<mtr>{cells}</mtr>")
+        })
+        .collect::<String>();
+    let align = align
+        .map(|align| format!(" columnalign=\"{align}\""))
+        .unwrap_or_default();
+    let table = format!("<mtable{align}>{rows}</mtable>");
+    if open.is_none() && close.is_none() {
+        return table;
+    }
+    format!(
+        "<mrow>{}{table}{}</mrow>",
+        fence(open.unwrap_or_default(), "prefix"),
+        fence(close.unwrap_or_default(), "postfix")
+    )
+}
+
+fn fence(delimiter: &str, form: &str) -> String {
+    if delimiter.is_empty() {
+        return String::new();
+    }
+    format!("<mo fence=\"true\" form=\"{form}\" stretchy=\"true\">{delimiter}</mo>")
+}
+
+fn mrow(items: Vec<String>) -> String {
+    if items.len() == 1 {
+        return items.into_iter().next().unwrap();
+    }
+    format!("<mrow>{}</mrow>", items.join(""))
+}
+
+fn unexpected(stop: Stop) -> String {
+    match stop {
+        Stop::Eof => "unexpected end".to_string(),
+        Stop::EndGroup => "unexpected `}`".to_string(),
+        Stop::Right => "`\\right` without `\\left`".to_string(),
+        Stop::EndEnvironment(name) => format!("`\\end{{{name}}}` without `\\begin{{{name}}}`"),
+        Stop::Ampersand | Stop::NewRow => {
+            "`&` and `\\\\` are only allowed in environments like `aligned`".to_string()
+        }
+    }
+}
+
+fn char_atom(c: char) -> String {
+    match c {
+        c if c.is_ascii_digit() => format!("<mn>{c}</mn>"),
+        c if c.is_alphabetic() => format!("<mi>{c}</mi>"),
+        '-' => "<mo>−</mo>".to_string(),
+        '*' => "<mo>∗</mo>".to_string(),
+        '~' => "<mspace width=\"0.333em\"></mspace>".to_string(),
+        c => format!("<mo>{}</mo>", escape(c.to_string().as_str())),
+    }
+}
+
+/// Escapes the html, and the characters markdown would change, in text.
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '*' | '_' | '^' | '~' | '`' | '\\' | '|' | '[' | ']' | '$' | '{' | '}' | '\n' => {
+                escaped.push_str(format!("&#{};", c as u32).as_str())
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn identifier(name: &str) -> Option<char> {
+    Some(match name {
+        "alpha" => 'α',
+        "beta" => 'β',
+        "gamma" => 'γ',
+        "delta" => 'δ',
+        "epsilon" => 'ϵ',
+        "varepsilon" => 'ε',
+        "zeta" => 'ζ',
+        "eta" => 'η',
+        "theta" => 'θ',
+        "vartheta" => 'ϑ',
+        "iota" => 'ι',
+        "kappa" => 'κ',
+        "lambda" => 'λ',
+        "mu" => 'μ',
+        "nu" => 'ν',
+        "xi" => 'ξ',
+        "pi" => 'π',
+        "varpi" => 'ϖ',
+        "rho" => 'ρ',
+        "varrho" => 'ϱ',
+        "sigma" => 'σ',
+        "varsigma" => 'ς',
+        "tau" => 'τ',
+        "upsilon" => 'υ',
+        "phi" => 'ϕ',
+        "varphi" => 'φ',
+        "chi" => 'χ',
+        "psi" => 'ψ',
+        "omega" => 'ω',
+        "Gamma" => 'Γ',
+        "Delta" => 'Δ',
+        "Theta" => 'Θ',
+        "Lambda" => 'Λ',
+        "Xi" => 'Ξ',
+        "Pi" => 'Π',
+        "Sigma" => 'Σ',
+        "Upsilon" => 'Υ',
+        "Phi" => 'Φ',
+        "Psi" => 'Ψ',
+        "Omega" => 'Ω',
+        "infty" => '∞',
+        "partial" => '∂',
+        "nabla" => '∇',
+        "emptyset" | "varnothing" => '∅',
+        "ell" => 'ℓ',
+        "hbar" => 'ℏ',
+        "imath" => 'ı',
+        "jmath" => 'ȷ',
+        "Re" => 'ℜ',
+        "Im" => 'ℑ',
+        "aleph" => 'ℵ',
+        "wp" => '℘',
+        _ => return None,
+    })
+}
+
+fn operator(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "times" => "×",
+        "cdot" => "⋅",
+        "div" => "÷",
+        "pm" => "±",
+        "mp" => "∓",
+        "ast" => "∗",
+        "star" => "⋆",
+        "circ" => "∘",
+        "bullet" => "∙",
+        "oplus" => "⊕",
+        "otimes" => "⊗",
+        "leq" | "le" => "≤",
+        "geq" | "ge" => "≥",
+        "neq" | "ne" => "≠",
+        "ll" => "≪",
+        "gg" => "≫",
+        "approx" => "≈",
+        "equiv" => "≡",
+        "sim" => "∼",
+        "simeq" => "≃",
+        "cong" => "≅",
+        "propto" => "∝",
+        "in" => "∈",
+        "notin" => "∉",
+        "ni" => "∋",
+        "subset" => "⊂",
+        "subseteq" => "⊆",
+        "supset" => "⊃",
+        "supseteq" => "⊇",
+        "cup" => "∪",
+        "cap" => "∩",
+        "setminus" => "∖",
+        "forall" => "∀",
+        "exists" => "∃",
+        "neg" | "lnot" => "¬",
+        "land" | "wedge" => "∧",
+        "lor" | "vee" => "∨",
+        "to" | "rightarrow" => "→",
+        "leftarrow" | "gets" => "←",
+        "leftrightarrow" => "↔",
+        "Rightarrow" => "⇒",
+        "Leftarrow" => "⇐",
+        "Leftrightarrow" => "⇔",
+        "implies" => "⟹",
+        "impliedby" => "⟸",
+        "iff" => "⟺",
+        "mapsto" => "↦",
+        "uparrow" => "↑",
+        "downarrow" => "↓",
+        "ldots" | "dots" => "…",
+        "cdots" => "⋯",
+        "vdots" => "⋮",
+        "ddots" => "⋱",
+        "mid" => "∣",
+        "parallel" => "∥",
+        "perp" => "⊥",
+        "angle" => "∠",
+        "triangle" => "△",
+        "langle" => "⟨",
+        "rangle" => "⟩",
+        "lfloor" => "⌊",
+        "rfloor" => "⌋",
+        "lceil" => "⌈",
+        "rceil" => "⌉",
+        "prime" => "′",
+        "degree" => "°",
+        "colon" => ":",
+        _ => return None,
+    })
+}
+
+/// The large operators, and whether their scripts are limits.
+fn large_operator(name: &str) -> Option<(&'static str, bool)> {
+    Some(match name {
+        "sum" => ("∑", true),
+        "prod" => ("∏", true),
+        "coprod" => ("∐", true),
+        "bigcup" => ("⋃", true),
+        "bigcap" => ("⋂", true),
+        "bigoplus" => ("⨁", true),
+        "bigotimes" => ("⨂", true),
+        "bigvee" => ("⋁", true),
+        "bigwedge" => ("⋀", true),
+        "int" => ("∫", false),
+        "iint" => ("∬", false),
+        "iiint" => ("∭", false),
+        "oint" => ("∮", false),
+        _ => return None,
+    })
+}
+
+const FUNCTIONS: &[&str] = &[
+    "sin", "cos", "tan", "cot", "sec", "csc", "arcsin", "arccos", "arctan", "sinh", "cosh", "tanh",
+    "coth", "log", "ln", "lg", "exp", "arg", "deg", "dim", "hom", "ker",
+];
+
+/// The functions whose scripts are limits, and how they are shown.
+const LIMIT_FUNCTIONS: &[(&str, &str)] = &[
+    ("lim", "lim"),
+    ("liminf", "lim inf"),
+    ("limsup", "lim sup"),
+    ("max", "max"),
+    ("min", "min"),
+    ("sup", "sup"),
+    ("inf", "inf"),
+    ("det", "det"),
+    ("gcd", "gcd"),
+    ("Pr", "Pr"),
+];
+
+/// The accent of an accent command, and whether it goes under.
+fn accent(name: &str) -> Option<(&'static str, bool)> {
+    Some(match name {
+        "hat" | "widehat" => ("&#94;", false),
+        "bar" | "overline" => ("‾", false),
+        "vec" | "overrightarrow" => ("→", false),
+        "overleftarrow" => ("←", false),
+        "dot" => ("˙", false),
+        "ddot" => ("¨", false),
+        "tilde" | "widetilde" => ("&#126;", false),
+        "check" => ("ˇ", false),
+        "breve" => ("˘", false),
+        "acute" => ("´", false),
+        "grave" => ("&#96;", false),
+        "underline" => ("&#95;", true),
+        _ => return None,
+    })
+}
+
+fn space(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "," | "thinspace" => "0.1667em",
+        ":" | ">" | "medspace" => "0.2222em",
+        ";" | "thickspace" => "0.2778em",
+        " " => "0.333em",
+        "quad" => "1em",
+        "qquad" => "2em",
+        _ => return None,
+    })
+}
+
+/// The fonts of `\mathbb` and the like, with the unicode mathematical
+/// alphanumeric symbols, as `mathvariant` is only `normal` in MathML Core.
+#[derive(Clone, Copy)]
+enum Font {
+    Normal,
+    Bold,
+    Italic,
+    DoubleStruck,
+    Script,
+    Fraktur,
+    SansSerif,
+    Monospace,
+}
+
+impl Font {
+    fn from_command(name: &str) -> Option<Font> {
+        Some(match name {
+            "mathrm" | "mathup" => Font::Normal,
+            "mathbf" | "boldsymbol" | "bm" => Font::Bold,
+            "mathit" => Font::Italic,
+            "mathbb" => Font::DoubleStruck,
+            "mathcal" | "mathscr" => Font::Script,
+            "mathfrak" => Font::Fraktur,
+            "mathsf" => Font::SansSerif,
+            "mathtt" => Font::Monospace,
+            _ => return None,
+        })
+    }
+
+    /// The first upper case letter, lower case letter and digit of the font.
+    fn starts(self) -> (u32, u32, Option<u32>) {
+        match self {
+            Font::Normal => ('A' as u32, 'a' as u32, None),
+            Font::Bold => (0x1D400, 0x1D41A, Some(0x1D7CE)),
+            Font::Italic => (0x1D434, 0x1D44E, None),
+            Font::DoubleStruck => (0x1D538, 0x1D552, Some(0x1D7D8)),
+            Font::Script => (0x1D49C, 0x1D4B6, None),
+            Font::Fraktur => (0x1D504, 0x1D51E, None),
+            Font::SansSerif => (0x1D5A0, 0x1D5BA, Some(0x1D7E2)),
+            Font::Monospace => (0x1D670, 0x1D68A, Some(0x1D7F6)),
+        }
+    }
+
+    /// The letters which are not in the mathematical alphanumeric symbols
+    /// block, as they were in unicode before it.
+    fn exception(self, c: char) -> Option<char> {
+        Some(match (self, c) {
+            (Font::Italic, 'h') => 'ℎ',
+            (Font::DoubleStruck, 'C') => 'ℂ',
+            (Font::DoubleStruck, 'H') => 'ℍ',
+            (Font::DoubleStruck, 'N') => 'ℕ',
+            (Font::DoubleStruck, 'P') => 'ℙ',
+            (Font::DoubleStruck, 'Q') => 'ℚ',
+            (Font::DoubleStruck, 'R') => 'ℝ',
+            (Font::DoubleStruck, 'Z') => 'ℤ',
+            (Font::Script, 'B') => 'ℬ',
+            (Font::Script, 'E') => 'ℰ',
+            (Font::Script, 'F') => 'ℱ',
+            (Font::Script, 'H') => 'ℋ',
+            (Font::Script, 'I') => 'ℐ',
+            (Font::Script, 'L') => 'ℒ',
+            (Font::Script, 'M') => 'ℳ',
+            (Font::Script, 'R') => 'ℛ',
+            (Font::Script, 'e') => 'ℯ',
+            (Font::Script, 'g') => 'ℊ',
+            (Font::Script, 'o') => 'ℴ',
+            (Font::Fraktur, 'C') => 'ℭ',
+            (Font::Fraktur, 'H') => 'ℌ',
+            (Font::Fraktur, 'I') => 'ℑ',
+            (Font::Fraktur, 'R') => 'ℜ',
+            (Font::Fraktur, 'Z') => 'ℨ',
+            _ => return None,
+        })
+    }
+
+    fn map(self, c: char) -> char {
+        if let Some(c) = self.exception(c) {
+            return c;
+        }
+        let (upper, lower, digit) = self.starts();
+        let code = match c {
+            'A'..='Z' => upper + (c as u32 - 'A' as u32),
+            'a'..='z' => lower + (c as u32 - 'a' as u32),
+            '0'..='9' => match digit {
+                Some(digit) => digit + (c as u32 - '0' as u32),
+                None => return c,
+            },
+            c => return c,
+        };
+        char::from_u32(code).unwrap_or(c)
+    }
+
+    fn apply(self, text: &str) -> String {
+        let text = text.split_whitespace().collect::<String>();
+        let mapped = escape(
+            text.chars()
+                .map(|c| self.map(c))
+                .collect::<String>()
+                .as_str(),
+        );
+        if !text.is_empty() && text.chars().all(|c| c.is_ascii_digit()) {
+            return format!("<mn>{mapped}</mn>");
+        }
+        match self {
+            Font::Normal => format!("<mi mathvariant=\"normal\">{mapped}</mi>"),
+            _ => format!("<mi>{mapped}</mi>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[track_caller]
+    fn p(latex: &str, body: &str) {
+        let mathml = super::to_mathml(latex, false).unwrap();
+        let expected = format!(
+            "<math><semantics><mrow>{body}</mrow><annotation encoding=\"application/x-tex\">{}</annotation></semantics></math>",
+            super::escape(latex)
+        );
+        assert_eq!(mathml, expected);
+    }
+
+    #[track_caller]
+    fn e(latex: &str, error: &str) {
+        assert_eq!(super::to_mathml(latex, false).unwrap_err(), error);
+    }
+
+    #[test]
+    fn mathml() {
+        p("x", "<mi>x</mi>");
+        p(
+            "x^2 + 1",
+            "<mrow><msup><mi>x</mi><mn>2</mn></msup><mo>+</mo><mn>1</mn></mrow>",
+        );
+        p(
+            "a_{i,j}",
+            "<msub><mi>a</mi><mrow><mi>i</mi><mo>,</mo><mi>j</mi></mrow></msub>",
+        );
+        p("3.14", "<mn>3.14</mn>");
+        p("\\frac12", "<mfrac><mn>1</mn><mn>2</mn></mfrac>");
+        p(
+            "\\frac{a}{b+c}",
+            "<mfrac><mi>a</mi><mrow><mi>b</mi><mo>+</mo><mi>c</mi></mrow></mfrac>",
+        );
+        p("\\sqrt[3]{x}", "<mroot><mi>x</mi><mn>3</mn></mroot>");
+        p(
+            "\\sum_{i=1}^n i",
+            "<mrow><munderover><mo largeop=\"true\" movablelimits=\"true\">∑</mo>\
+            <mrow><mi>i</mi><mo>=</mo><mn>1</mn></mrow><mi>n</mi></munderover><mi>i</mi></mrow>",
+        );
+        p(
+            "\\int_0^1",
+            "<msubsup><mo largeop=\"true\" movablelimits=\"false\">∫</mo><mn>0</mn><mn>1</mn></msubsup>",
+        );
+        p("f'", "<msup><mi>f</mi><mo>′</mo></msup>");
+        p(
+            "\\alpha \\Omega",
+            "<mrow><mi>α</mi><mi mathvariant=\"normal\">Ω</mi></mrow>",
+        );
+        p("\\mathbb{R}", "<mi>ℝ</mi>");
+        p(
+            "\\text{if } x",
+            "<mrow><mtext>if&#160;</mtext><mi>x</mi></mrow>",
+        );
+        p(
+            "\\left( x \\right)",
+            "<mrow><mo fence=\"true\" form=\"prefix\" stretchy=\"true\">(</mo><mi>x</mi>\
+            <mo fence=\"true\" form=\"postfix\" stretchy=\"true\">)</mo></mrow>",
+        );
+        p(
+            "\\begin{pmatrix} a & b \\\\ c & d \\end{pmatrix}",
+            "<mrow><mo fence=\"true\" form=\"prefix\" stretchy=\"true\">(</mo><mtable>\
+            <mtr><mtd><mi>a</mi></mtd><mtd><mi>b</mi></mtd></mtr>\
+            <mtr><mtd><mi>c</mi></mtd><mtd><mi>d</mi></mtd></mtr></mtable>\
+            <mo fence=\"true\" form=\"postfix\" stretchy=\"true\">)</mo></mrow>",
+        );
+        p("a < b", "<mrow><mi>a</mi><mo>&lt;</mo><mi>b</mi></mrow>");
+    }
+
+    #[test]
+    fn errors() {
+        e("\\foo", "unknown command `\\foo`");
+        e("{x", "missing `}`");
+        e("x}", "unexpected `}`");
+        e("x^1^2", "double superscript");
+        e("\\begin{matrix} a", "missing `\\end{matrix}`");
+        e("\\left( x", "`\\left` without `\\right`");
+    }
+}
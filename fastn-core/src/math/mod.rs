@@ -0,0 +1,451 @@
+//! Math in documents, rendered to MathML at compile time so pages need no
+//! javascript to show it. The text of `ftd.math` is LaTeX, and with
+//! `math: true` in `fastn.markdown`, so are `$...$` and `$$...$$` in the
+//! markup of `ftd.text`.
+
+mod mathml;
+
+/// What the text given to an argument of a component is.
+#[derive(Clone, Copy, PartialEq)]
+enum Content {
+    /// LaTeX, like the text of `ftd.math`.
+    Math,
+    /// Markup, math is between `$`s.
+    Markup,
+}
+
+/// Component and argument pairs, like `("ftd#text", "text")`.
+type Targets = std::collections::HashMap<(String, String), Content>;
+
+/// The arguments of every component, by its name.
+type Arguments = std::collections::HashMap<String, Vec<fastn_resolved::Argument>>;
+
+/// Replaces the LaTeX given to `ftd.math`, and the math in the markup given
+/// to `ftd.text`, with MathML. Text given to an argument of a component
+/// which passes it on to them, like the `body` of `ds.markdown`, is converted
+/// too. Only literals are converted, math in variables is shown as written.
+/// Returns the MathML of the math, the only `<math>` markup the page shows as
+/// is, see `fastn_utils.trustMarkup`.
+pub(crate) fn render_math(
+    document: &mut ftd::interpreter::Document,
+    markdown: &ftd::executor::MarkdownProfile,
+) -> ftd::interpreter::Result<Vec<String>> {
+    let mut targets = Targets::new();
+    targets.insert(("ftd#math".to_string(), "text".to_string()), Content::Math);
+    if markdown.math {
+        targets.insert(
+            ("ftd#text".to_string(), "text".to_string()),
+            Content::Markup,
+        );
+    }
+    let targets = forwarded_targets(document, targets);
+
+    let document_name = document.name.clone();
+    let arguments = component_arguments(document);
+    let mut rendered = vec![];
+    let mut convert = |module: &str,
+                       component: &str,
+                       argument: &str,
+                       value: &mut fastn_resolved::PropertyValue|
+     -> ftd::interpreter::Result<()> {
+        let Some(content) = targets.get(&(component.to_string(), argument.to_string())) else {
+            return Ok(());
+        };
+        convert_value(value, *content, module, &mut rendered)
+    };
+
+    for component in document.tree.iter_mut() {
+        visit(component, document_name.as_str(), &arguments, &mut convert)?;
+    }
+    for (name, thing) in document.data.iter_mut() {
+        if let fastn_resolved::Definition::Component(definition) = thing {
+            let module = name
+                .split_once('#')
+                .map_or(document_name.as_str(), |(module, _)| module);
+            for argument in definition.arguments.iter_mut() {
+                if let Some(ref mut value) = argument.value {
+                    convert(module, name.as_str(), argument.name.as_str(), value)?;
+                }
+            }
+            visit(&mut definition.definition, module, &arguments, &mut convert)?;
+        }
+    }
+    rendered.sort();
+    rendered.dedup();
+    Ok(rendered)
+}
+
+/// Adds the arguments of the components of the document which are passed on
+/// to a target, `$ds.markdown.body` in the `text` of `ftd.text`, until no
+/// more are found.
+fn forwarded_targets(document: &ftd::interpreter::Document, mut targets: Targets) -> Targets {
+    let arguments = component_arguments(document);
+    loop {
+        let mut found = vec![];
+        for (name, thing) in document.data.iter() {
+            let fastn_resolved::Definition::Component(definition) = thing else {
+                continue;
+            };
+            let prefix = format!("{name}.");
+            visit_properties(
+                &definition.definition,
+                &arguments,
+                &mut |component, argument, value| {
+                    let Some(content) = targets.get(&(component.to_string(), argument.to_string()))
+                    else {
+                        return;
+                    };
+                    if let fastn_resolved::PropertyValue::Reference {
+                        name: reference, ..
+                    } = value
+                    {
+                        if let Some(forwarded) = reference.strip_prefix(prefix.as_str()) {
+                            let key = (name.to_string(), forwarded.to_string());
+                            if !targets.contains_key(&key) {
+                                found.push((key, *content));
+                            }
+                        }
+                    }
+                },
+            );
+        }
+        if found.is_empty() {
+            return targets;
+        }
+        targets.extend(found);
+    }
+}
+
+/// The arguments of the components of the document and of the kernel ones.
+fn component_arguments(document: &ftd::interpreter::Document) -> Arguments {
+    fastn_builtins::builtins()
+        .iter()
+        .chain(document.data.iter())
+        .filter_map(|(name, thing)| match thing {
+            fastn_resolved::Definition::Component(definition) => {
+                Some((name.to_string(), definition.arguments.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// The argument a property of an invocation of `component` sets.
+fn argument_name<'a>(
+    arguments: &'a Arguments,
+    component: &str,
+    property: &fastn_resolved::Property,
+) -> Option<&'a str> {
+    arguments.get(component)?.iter().find_map(|argument| {
+        argument
+            .to_sources()
+            .iter()
+            .any(|source| source.is_equal(&property.source))
+            .then_some(argument.name.as_str())
+    })
+}
+
+fn visit_properties(
+    invocation: &fastn_resolved::ComponentInvocation,
+    arguments: &Arguments,
+    f: &mut dyn FnMut(&str, &str, &fastn_resolved::PropertyValue),
+) {
+    for property in invocation.properties.iter() {
+        if let Some(argument) = argument_name(arguments, invocation.name.as_str(), property) {
+            f(invocation.name.as_str(), argument, &property.value);
+        }
+        if let fastn_resolved::PropertyValue::Value { ref value, .. } = property.value {
+            for component in ui_values(value) {
+                visit_properties(component, arguments, f);
+            }
+        }
+    }
+    for child in invocation.children.iter() {
+        visit_properties(child, arguments, f);
+    }
+}
+
+/// The components in a value, like the ones passed to a `children` argument.
+fn ui_values(value: &fastn_resolved::Value) -> Vec<&fastn_resolved::ComponentInvocation> {
+    match value {
+        fastn_resolved::Value::UI { component, .. } => vec![component],
+        fastn_resolved::Value::List { data, .. } => data
+            .iter()
+            .filter_map(|item| match item {
+                fastn_resolved::PropertyValue::Value { value, .. } => Some(value),
+                _ => None,
+            })
+            .flat_map(ui_values)
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Calls `f` with the module, the component, the argument and the value of
+/// every property.
+fn visit<F>(
+    invocation: &mut fastn_resolved::ComponentInvocation,
+    module: &str,
+    arguments: &Arguments,
+    f: &mut F,
+) -> ftd::interpreter::Result<()>
+where
+    F: FnMut(&str, &str, &str, &mut fastn_resolved::PropertyValue) -> ftd::interpreter::Result<()>,
+{
+    for property in invocation.properties.iter_mut() {
+        if let Some(argument) = argument_name(arguments, invocation.name.as_str(), property) {
+            f(
+                module,
+                invocation.name.as_str(),
+                argument,
+                &mut property.value,
+            )?;
+        }
+        if let fastn_resolved::PropertyValue::Value { ref mut value, .. } = property.value {
+            visit_value(value, module, arguments, f)?;
+        }
+    }
+    for child in invocation.children.iter_mut() {
+        visit(child, module, arguments, f)?;
+    }
+    Ok(())
+}
+
+fn visit_value<F>(
+    value: &mut fastn_resolved::Value,
+    module: &str,
+    arguments: &Arguments,
+    f: &mut F,
+) -> ftd::interpreter::Result<()>
+where
+    F: FnMut(&str, &str, &str, &mut fastn_resolved::PropertyValue) -> ftd::interpreter::Result<()>,
+{
+    match value {
+        fastn_resolved::Value::UI { component, .. } => visit(component, module, arguments, f),
+        fastn_resolved::Value::List { data, .. } => {
+            for item in data.iter_mut() {
+                if let fastn_resolved::PropertyValue::Value { value, .. } = item {
+                    visit_value(value, module, arguments, f)?;
+                }
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Converts the math in `value`, and adds the MathML to `rendered`.
+fn convert_value(
+    value: &mut fastn_resolved::PropertyValue,
+    content: Content,
+    module: &str,
+    rendered: &mut Vec<String>,
+) -> ftd::interpreter::Result<()> {
+    let fastn_resolved::PropertyValue::Value {
+        value, line_number, ..
+    } = value
+    else {
+        return Ok(());
+    };
+    let text = match value {
+        fastn_resolved::Value::String { text } => text,
+        fastn_resolved::Value::Optional { data, .. } => match data.as_mut() {
+            Some(fastn_resolved::Value::String { text }) => text,
+            _ => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+    let converted = match content {
+        Content::Math => mathml::to_mathml(text, true).map(|math| {
+            rendered.push(math.clone());
+            Some(math)
+        }),
+        Content::Markup => render_markup(text, rendered),
+    };
+    match converted {
+        Ok(Some(converted)) => {
+            *text = converted;
+            Ok(())
+        }
+        Ok(None) => Ok(()),
+        Err(message) => Err(ftd::interpreter::Error::ParseError {
+            message: format!("Cannot convert the math to MathML: {message}"),
+            doc_id: module.to_string(),
+            line_number: *line_number,
+        }),
+    }
+}
+
+/// The markup with its `$$...$$` and `$...$` math as MathML, `None` if it has
+/// no math. Math is not looked for in code, and `\$` is a dollar. Like in
+/// pandoc, the opening `$` of inline math is not followed by a space, the
+/// closing one is not preceded by a space nor followed by a digit, so
+/// `$5 and $10` is not math. The MathML is added to `math`.
+fn render_markup(text: &str, math: &mut Vec<String>) -> Result<Option<String>, String> {
+    if !text.contains('$') {
+        return Ok(None);
+    }
+    let mut rendered = String::new();
+    let mut has_math = false;
+    let mut fence: Option<String> = None;
+    let mut prose = String::new();
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        let marker = trimmed
+            .chars()
+            .take_while(|c| *c == '`' || *c == '~')
+            .collect::<String>();
+        let is_fence = line.len() - trimmed.len() < 4
+            && marker.len() >= 3
+            && marker.chars().all(|c| c == marker.chars().next().unwrap());
+        match fence {
+            Some(ref open) => {
+                if is_fence
+                    && marker.starts_with(open.as_str())
+                    && trimmed[marker.len()..].trim().is_empty()
+                {
+                    fence = None;
+                }
+                rendered.push_str(line);
+            }
+            None if is_fence => {
+                has_math |= render_prose(std::mem::take(&mut prose).as_str(), &mut rendered, math)?;
+                fence = Some(marker);
+                rendered.push_str(line);
+            }
+            None => prose.push_str(line),
+        }
+    }
+    has_math |= render_prose(prose.as_str(), &mut rendered, math)?;
+    Ok(has_math.then_some(rendered))
+}
+
+/// Appends the markup outside code blocks to `rendered` with its math as
+/// MathML, added to `math` too, and tells if it had math.
+fn render_prose(text: &str, rendered: &mut String, math: &mut Vec<String>) -> Result<bool, String> {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut has_math = false;
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => {
+                rendered.extend(chars[i..(i + 2).min(chars.len())].iter());
+                i += 2;
+            }
+            '`' => {
+                let run = chars[i..].iter().take_while(|c| **c == '`').count();
+                let end = (i + run..chars.len()).find(|j| {
+                    chars[*j..].iter().take_while(|c| **c == '`').count() == run
+                        && chars[*j - 1] != '`'
+                });
+                let end = end.map_or(i + run, |end| end + run);
+                rendered.extend(chars[i..end].iter());
+                i = end;
+            }
+            '$' if chars.get(i + 1) == Some(&'$') => {
+                let Some(end) = closing(&chars, i + 2, |j| chars.get(j + 1) == Some(&'$')) else {
+                    rendered.push_str("$$");
+                    i += 2;
+                    continue;
+                };
+                let latex = chars[i + 2..end].iter().collect::<String>();
+                let mathml = mathml::to_mathml(latex.as_str(), true)?;
+                rendered.push_str(mathml.as_str());
+                math.push(mathml);
+                has_math = true;
+                i = end + 2;
+            }
+            '$' if chars.get(i + 1).is_some_and(|c| !c.is_whitespace()) => {
+                let end = closing(&chars, i + 1, |j| {
+                    !chars[j - 1].is_whitespace()
+                        && !chars.get(j + 1).is_some_and(|c| c.is_ascii_digit())
+                })
+                .filter(|end| {
+                    // inline math does not span paragraphs
+                    !chars[i..*end]
+                        .iter()
+                        .collect::<String>()
+                        .split('\n')
+                        .skip(1)
+                        .any(|line| line.trim().is_empty())
+                });
+                let Some(end) = end else {
+                    rendered.push('$');
+                    i += 1;
+                    continue;
+                };
+                let latex = chars[i + 1..end].iter().collect::<String>();
+                let mathml = mathml::to_mathml(latex.as_str(), false)?;
+                rendered.push_str(mathml.as_str());
+                math.push(mathml);
+                has_math = true;
+                i = end + 1;
+            }
+            c => {
+                rendered.push(c);
+                i += 1;
+            }
+        }
+    }
+    Ok(has_math)
+}
+
+/// The position of the first unescaped `$` from `start` for which `is_end`
+/// holds.
+fn closing(chars: &[char], start: usize, is_end: impl Fn(usize) -> bool) -> Option<usize> {
+    let mut j = start;
+    while j < chars.len() {
+        match chars[j] {
+            '\\' => j += 2,
+            '$' if is_end(j) => return Some(j),
+            _ => j += 1,
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    #[track_caller]
+    fn p(markup: &str, expected: &str) {
+        let mut math = vec![];
+        let rendered = super::render_markup(markup, &mut math)
+            .unwrap()
+            .unwrap_or(markup.to_string());
+        // the MathML in the markup is the one given back, to be trusted
+        let shown = regex::Regex::new(r"<math[\s>][\s\S]*?</math>")
+            .unwrap()
+            .find_iter(rendered.as_str())
+            .map(|m| m.as_str().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(shown, math);
+        let without_annotations = regex::Regex::new(r"<annotation[^<]*</annotation>")
+            .unwrap()
+            .replace_all(rendered.as_str(), "");
+        assert_eq!(without_annotations, expected);
+    }
+
+    #[test]
+    fn markup() {
+        p(
+            "area $\\pi r^2$ of",
+            "area <math><semantics><mrow><mrow><mi>π</mi><msup><mi>r</mi><mn>2</mn></msup></mrow></mrow></semantics></math> of",
+        );
+        p(
+            "$$x$$",
+            "<math display=\"block\"><semantics><mrow><mi>x</mi></mrow></semantics></math>",
+        );
+        p("costs $5 and $10", "costs $5 and $10");
+        p("a \\$x$ b", "a \\$x$ b");
+        p("`$x$` and\n```\n$x$\n```\n", "`$x$` and\n```\n$x$\n```\n");
+        p("$x\n\ny$", "$x\n\ny$");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            super::render_markup("$\\foo$", &mut vec![]).unwrap_err(),
+            "unknown command `\\foo`"
+        );
+    }
+}
@@ -61,7 +61,7 @@ async fn migrate_(
         find_migrations_to_apply(available_migrations, latest_applied_migration_number)?;
 
     for migration in migrations {
-        println!("Applying Migration for {app_name}: {}", migration.name);
+        fastn_core::progress!("Applying Migration for {app_name}: {}\n", migration.name);
         apply_migration(config, app_name, &migration, now).await?;
     }
 
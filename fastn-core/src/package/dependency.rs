@@ -9,6 +9,9 @@ pub struct Dependency {
     pub implements: Vec<String>,
     pub provided_via: Option<String>,
     pub required_as: Option<String>,
+    /// `icons` is the folder of svg files when the dependency is an icon set,
+    /// `ftd.icon: arrow-right` is `<icons>/arrow-right.svg` of the package.
+    pub icons: Option<String>,
 }
 
 impl Dependency {
@@ -79,6 +82,7 @@ pub(crate) struct DependencyTemp {
     pub provided_via: Option<String>,
     #[serde(rename = "required-as")]
     pub required_as: Option<String>,
+    pub icons: Option<String>,
 }
 
 impl DependencyTemp {
@@ -95,6 +99,7 @@ impl DependencyTemp {
             implements: self.implements,
             provided_via: self.provided_via,
             required_as: self.required_as,
+            icons: self.icons,
         })
     }
 }
@@ -190,7 +195,7 @@ impl fastn_core::Package {
             let path = std::env::temp_dir().join(format!("{}.zip", self.name.replace('/', "__")));
 
             let start = std::time::Instant::now();
-            print!("Downloading {} ... ", self.name.as_str());
+            fastn_core::progress!("Downloading {} ... ", self.name.as_str());
             std::io::stdout().flush()?;
             // Download the zip folder
             {
@@ -69,6 +69,16 @@ pub struct Package {
     pub system: Option<String>,
     pub system_is_confidential: Option<bool>,
 
+    /// Client-side navigation: internal links render the next page in place
+    /// instead of doing a full page load.
+    pub spa: bool,
+    /// Global mutable variables, e.g. `<package-name>/state#user`, which keep
+    /// their value across client-side navigations.
+    pub persist: Vec<String>,
+
+    /// Markdown extensions of `ftd.text`, `-- fastn.markdown:`
+    pub markdown: ftd::executor::MarkdownProfile,
+
     pub lang: Option<Lang>,
 
     /// Migrations
@@ -112,6 +122,9 @@ impl Package {
             redirects: None,
             system: None,
             system_is_confidential: None,
+            spa: false,
+            persist: vec![],
+            markdown: Default::default(),
             migrations: vec![],
         }
     }
@@ -631,6 +644,9 @@ impl Package {
         // Todo: Add `package.files` and fix `fs_fetch_by_id` to check if file is present
         package.fonts = fastn_document.get("fastn#font")?;
         package.sitemap_temp = fastn_document.get("fastn#sitemap")?;
+        package.markdown = fastn_document
+            .get::<Option<ftd::executor::MarkdownProfile>>("fastn#markdown")?
+            .unwrap_or_default();
 
         package.migrations = get_migration_data(&fastn_document)?;
         *self = package;
@@ -725,6 +741,9 @@ impl Package {
         package.fonts = fastn_doc.get("fastn#font")?;
         package.sitemap_temp = fastn_doc.get("fastn#sitemap")?;
         package.dynamic_urls_temp = fastn_doc.get("fastn#dynamic-urls")?;
+        package.markdown = fastn_doc
+            .get::<Option<ftd::executor::MarkdownProfile>>("fastn#markdown")?
+            .unwrap_or_default();
         package.migrations = get_migration_data(fastn_doc)?;
 
         // validation logic TODO: It should be ordered
@@ -985,6 +1004,9 @@ impl PackageTempIntoPackage for fastn_package::old_fastn::PackageTemp {
             redirects: None,
             system: self.system,
             system_is_confidential: self.system_is_confidential,
+            spa: self.spa,
+            persist: self.persist,
+            markdown: Default::default(),
             migrations: vec![],
         }
     }
@@ -310,10 +310,9 @@ pub(crate) fn file_id_to_names(id: &str) -> Vec<String> {
         format!("{}.ftd", id),
         format!("{}/index.ftd", id),
         format!("{}/index.html", id),
-        // Todo: removing `md` file support for now
-        // format!("{}.md", id),
-        // format!("{}/README.md", id),
-        // format!("{}/index.md", id),
+        format!("{}.md", id),
+        format!("{}/README.md", id),
+        format!("{}/index.md", id),
     ]);
     ids
 }
@@ -324,6 +323,19 @@ pub enum FTDResult {
     Json(Vec<u8>),
 }
 
+/// What an `ftd` 2023 document is rendered into, it is always the html for
+/// `ftd` 2022 documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    /// The html of the page.
+    Html,
+    /// The js of `ftd` and of the document, run by `fastn test`.
+    Js,
+    /// The js for a client-side navigation to the page, see
+    /// `fastn_core::SPA_PAYLOAD_FILE`.
+    SpaPayload,
+}
+
 impl FTDResult {
     pub fn html(&self) -> Vec<u8> {
         match self {
@@ -357,7 +369,7 @@ pub async fn read_ftd(
         base_url,
         download_assets,
         test,
-        false,
+        Output::Html,
         preview_session_id,
     )
     .await
@@ -370,7 +382,7 @@ pub(crate) async fn read_ftd_(
     base_url: &str,
     download_assets: bool,
     test: bool,
-    only_js: bool,
+    output: Output,
     preview_session_id: &Option<String>,
 ) -> fastn_core::Result<FTDResult> {
     tracing::info!(document = main.id);
@@ -392,7 +404,7 @@ pub(crate) async fn read_ftd_(
                 main,
                 base_url,
                 download_assets,
-                only_js,
+                output,
                 preview_session_id,
             )
             .await
@@ -426,7 +438,7 @@ pub(crate) async fn read_ftd_2022(
     doc_content = current_package.fix_imports_in_body(doc_content.as_str(), main.id.as_str())?;
 
     let line_number = doc_content.split('\n').count() - main.content.split('\n').count();
-    let main_ftd_doc = match fastn_core::doc::interpret_helper(
+    let mut main_ftd_doc = match fastn_core::doc::interpret_helper(
         main.id_with_package().as_str(),
         doc_content.as_str(),
         config,
@@ -440,9 +452,7 @@ pub(crate) async fn read_ftd_2022(
         Ok(v) => v,
         Err(e) => {
             tracing::error!(msg = "failed to parse", doc = main.id.as_str());
-            return Err(fastn_core::Error::PackageError {
-                message: format!("failed to parse {:?}", &e),
-            });
+            return Err(e.into());
         }
     };
 
@@ -454,7 +464,20 @@ pub(crate) async fn read_ftd_2022(
         return Ok(FTDResult::Json(v));
     }
 
-    let executor = ftd::executor::ExecuteDoc::from_interpreter(main_ftd_doc)?;
+    fastn_core::markdown::resolve_links(
+        &mut main_ftd_doc,
+        main,
+        &current_package.markdown,
+        base_url,
+        &c.ds,
+        preview_session_id,
+    )
+    .await?;
+
+    let executor = ftd::executor::ExecuteDoc::from_interpreter_with_markdown(
+        main_ftd_doc,
+        &current_package.markdown,
+    )?;
     let node = ftd::node::NodeData::from_rt(executor);
     let html_ui = ftd::html::HtmlUI::from_node_data(node, "main", test)?;
 
@@ -472,16 +495,101 @@ pub(crate) async fn read_ftd_2022(
     Ok(FTDResult::Html(file_content.into()))
 }
 
-#[allow(clippy::await_holding_refcell_ref)]
 #[tracing::instrument(name = "read_ftd_2023", skip_all)]
 pub(crate) async fn read_ftd_2023(
     config: &mut fastn_core::RequestConfig,
     main: &fastn_core::Document,
     base_url: &str,
     download_assets: bool,
-    only_js: bool,
+    output: Output,
     preview_session_id: &Option<String>,
 ) -> fastn_core::Result<FTDResult> {
+    match interpret_2023(config, main, base_url, download_assets, preview_session_id).await? {
+        Interpreted::Js(js) => Ok(FTDResult::Html(
+            js.render(config, output, base_url, preview_session_id)
+                .await?
+                .into(),
+        )),
+        Interpreted::Done(result) => Ok(result),
+    }
+}
+
+/// An `ftd` 2023 document, interpreted.
+pub(crate) enum Interpreted {
+    /// The js of the document, to be rendered into an [`Output`].
+    Js(DocumentJs),
+    /// The document is a redirect or json, there is nothing to render.
+    Done(FTDResult),
+}
+
+pub(crate) struct DocumentJs {
+    package_name: String,
+    /// The js of the document.
+    document: String,
+    /// The js of the `ftd` module.
+    ftd: String,
+    /// The external js the document uses, like `ftd.web-component`s.
+    scripts: String,
+}
+
+impl DocumentJs {
+    pub(crate) async fn render(
+        &self,
+        config: &fastn_core::RequestConfig,
+        output: Output,
+        base_url: &str,
+        preview_session_id: &Option<String>,
+    ) -> fastn_core::Result<String> {
+        let c = &config.config;
+        Ok(match output {
+            Output::Js => fastn_js::ssr_raw_string_without_test(
+                &self.package_name,
+                format!("{}\n{}", self.ftd, self.document).as_str(),
+            ),
+            Output::SpaPayload => {
+                fastn_core::utils::spa_payload_2023(self.document.as_str(), self.scripts.as_str())
+            }
+            Output::Html => {
+                let ssr_body = if config.request.is_bot() {
+                    fastn_js::ssr_with_js_string(
+                        &self.package_name,
+                        format!(
+                            "{}{}\n{}",
+                            fastn_core::utils::get_markdown_data(&c.package),
+                            self.ftd,
+                            self.document
+                        )
+                        .as_str(),
+                    )?
+                } else {
+                    EMPTY_HTML_BODY.to_string()
+                };
+
+                fastn_core::utils::replace_markers_2023(
+                    self.document.as_str(),
+                    self.scripts.as_str(),
+                    ssr_body.as_str(),
+                    c.get_font_style().as_str(),
+                    ftd::ftd_js_css(),
+                    base_url,
+                    c,
+                    preview_session_id,
+                )
+                .await
+            }
+        })
+    }
+}
+
+#[allow(clippy::await_holding_refcell_ref)]
+#[tracing::instrument(name = "interpret_2023", skip_all)]
+pub(crate) async fn interpret_2023(
+    config: &mut fastn_core::RequestConfig,
+    main: &fastn_core::Document,
+    base_url: &str,
+    download_assets: bool,
+    preview_session_id: &Option<String>,
+) -> fastn_core::Result<Interpreted> {
     let package_name = config.config.package.name.to_string();
     let c = &config.config.clone();
 
@@ -499,7 +607,7 @@ pub(crate) async fn read_ftd_2023(
     doc_content = current_package.fix_imports_in_body(doc_content.as_str(), main.id.as_str())?;
 
     let line_number = doc_content.split('\n').count() - main.content.split('\n').count();
-    let main_ftd_doc = match fastn_core::doc::interpret_helper(
+    let mut main_ftd_doc = match fastn_core::doc::interpret_helper(
         main.id_with_package().as_str(),
         doc_content.as_str(),
         config,
@@ -513,53 +621,53 @@ pub(crate) async fn read_ftd_2023(
         Ok(v) => v,
         Err(e) => {
             tracing::error!(msg = "failed to parse", doc = main.id.as_str());
-            return Err(fastn_core::Error::PackageError {
-                message: format!("failed to parse {:?}", &e),
-            });
+            return Err(e.into());
         }
     };
     if let Some((url, code)) = main_ftd_doc.get_redirect()? {
-        return Ok(FTDResult::Redirect { url, code });
+        return Ok(Interpreted::Done(FTDResult::Redirect { url, code }));
     }
     if let Some(data) = main_ftd_doc.get_json()? {
-        return Ok(FTDResult::Json(data));
+        return Ok(Interpreted::Done(FTDResult::Json(data)));
     }
 
-    let js_ast_data = ftd::js::document_into_js_ast(main_ftd_doc);
-    let js_document_script = fastn_js::to_js(js_ast_data.asts.as_slice(), package_name.as_str());
-    let js_ftd_script = fastn_js::to_js(
-        ftd::js::default_bag_into_js_ast().as_slice(),
-        package_name.as_str(),
-    );
-    let file_content = if only_js {
-        fastn_js::ssr_raw_string_without_test(
-            &package_name,
-            format!("{js_ftd_script}\n{js_document_script}").as_str(),
-        )
-    } else {
-        let ssr_body = if config.request.is_bot() {
-            fastn_js::ssr_with_js_string(
-                &package_name,
-                format!("{js_ftd_script}\n{js_document_script}").as_str(),
-            )?
-        } else {
-            EMPTY_HTML_BODY.to_string()
-        };
+    fastn_core::markdown::resolve_links(
+        &mut main_ftd_doc,
+        main,
+        &current_package.markdown,
+        base_url,
+        &c.ds,
+        preview_session_id,
+    )
+    .await?;
 
-        fastn_core::utils::replace_markers_2023(
-            js_document_script.as_str(),
-            js_ast_data.scripts.join("").as_str(),
-            ssr_body.as_str(),
-            config.config.get_font_style().as_str(),
-            ftd::ftd_js_css(),
-            base_url,
-            c,
-            preview_session_id,
-        )
-        .await
-    };
+    let js_ast_data = ftd::js::document_into_js_ast(main_ftd_doc);
+    Ok(Interpreted::Js(DocumentJs {
+        document: format!(
+            "{}{}",
+            trust_markup(config.trusted_markup.as_slice())?,
+            fastn_js::to_js(js_ast_data.asts.as_slice(), package_name.as_str())
+        ),
+        ftd: fastn_js::to_js(
+            ftd::js::default_bag_into_js_ast().as_slice(),
+            package_name.as_str(),
+        ),
+        scripts: js_ast_data.scripts.join(""),
+        package_name,
+    }))
+}
 
-    Ok(FTDResult::Html(file_content.into()))
+/// The js which lets the page show the markup fastn rendered, like the MathML
+/// of math, as is, any other markup in text is escaped. `<` is escaped so the
+/// markup can not end the `<script>` the js is in.
+fn trust_markup(markup: &[String]) -> fastn_core::Result<String> {
+    if markup.is_empty() {
+        return Ok("".to_string());
+    }
+    Ok(format!(
+        "fastn_utils.trustMarkup({});\n",
+        serde_json::to_string(markup)?.replace('<', "\\u003c")
+    ))
 }
 
 pub(crate) async fn process_ftd(
@@ -572,19 +680,76 @@ pub(crate) async fn process_ftd(
     preview_session_id: &Option<String>,
 ) -> fastn_core::Result<FTDResult> {
     let build_dir = config.config.build_dir();
-    let response = read_ftd(
+    let spa_dir = file_path.strip_suffix("index.html").filter(|_| {
+        config.config.package.spa && config.config.ftd_edition == fastn_core::FTDEdition::FTD2023
+    });
+    let Some(dir) = spa_dir else {
+        let response = read_ftd(
+            config,
+            main,
+            base_url,
+            build_static_files,
+            test,
+            preview_session_id,
+        )
+        .await?;
+        fastn_core::utils::overwrite(&build_dir, file_path, &response.html(), &config.config.ds)
+            .await?;
+        return Ok(response);
+    };
+
+    // the html and the js payload for client-side navigation are rendered from
+    // the same interpretation, static hosts serve the payload next to the html
+    let (response, payload) = match interpret_2023(
         config,
         main,
         base_url,
         build_static_files,
-        test,
         preview_session_id,
     )
-    .await?;
+    .await?
+    {
+        Interpreted::Js(js) => (
+            FTDResult::Html(
+                js.render(config, Output::Html, base_url, preview_session_id)
+                    .await?
+                    .into(),
+            ),
+            Some(
+                js.render(config, Output::SpaPayload, base_url, preview_session_id)
+                    .await?,
+            ),
+        ),
+        // a client-side navigation to a redirect or json falls back to a
+        // full page load, as there is no payload for it
+        Interpreted::Done(result) => (result, None),
+    };
     fastn_core::utils::overwrite(&build_dir, file_path, &response.html(), &config.config.ds)
         .await?;
+    if let Some(payload) = payload {
+        fastn_core::utils::overwrite(
+            &build_dir,
+            format!("{dir}{}", fastn_core::SPA_PAYLOAD_FILE).as_str(),
+            payload.as_bytes(),
+            &config.config.ds,
+        )
+        .await?;
+    }
 
     Ok(response)
 }
 
 const EMPTY_HTML_BODY: &str = "<body></body><style id=\"styles\"></style>";
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn trust_markup() {
+        assert_eq!(super::trust_markup(&[]).unwrap(), "");
+        // the markup can not close the `<script>` the js is in
+        assert_eq!(
+            super::trust_markup(&["<math><mi>x</mi></math></script>".to_string()]).unwrap(),
+            "fastn_utils.trustMarkup([\"\\u003cmath>\\u003cmi>x\\u003c/mi>\\u003c/math>\\u003c/script>\"]);\n"
+        );
+    }
+}
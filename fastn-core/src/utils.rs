@@ -29,6 +29,20 @@ macro_rules! warning {
     }};
 }
 
+/// Like `print!`, for the progress of a command, like `Processing ...`. It is
+/// printed on stderr when stdout is for the JSON of the errors, see
+/// [fastn_core::utils::stdout_is_json].
+#[macro_export]
+macro_rules! progress {
+    ($($t:tt)*) => {{
+        if fastn_core::utils::stdout_is_json() {
+            eprint!($($t)*);
+        } else {
+            print!($($t)*);
+        }
+    }};
+}
+
 fn id_to_cache_key(id: &str) -> String {
     // TODO: use MAIN_SEPARATOR here
     id.replace(['/', '\\'], "_")
@@ -98,15 +112,60 @@ pub fn redirect_page_html(url: &str) -> String {
     include_str!("../redirect.html").replace("__REDIRECT_URL__", url)
 }
 
+/// The page shown for a document with errors: the errors as text, and as
+/// JSON in `<script id="fastn-diagnostics">` for an overlay to read.
+pub fn error_page_html(path: &str, text: &str, diagnostics: &serde_json::Value) -> String {
+    // the markup in a message, like `</script>`, must not reach the html, and
+    // `<`, `>` and `&` are only ever in the strings of the json
+    let diagnostics = diagnostics
+        .to_string()
+        .replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026");
+    fill(
+        include_str!("../error.html"),
+        &[
+            ("__ERROR_PATH__", escape_html(path).as_str()),
+            ("__ERROR_TEXT__", escape_html(text).as_str()),
+            ("__DIAGNOSTICS__", diagnostics.as_str()),
+        ],
+    )
+}
+
+/// `template` with each placeholder replaced by its value, in one pass, so a
+/// value with a placeholder in it is kept as it is.
+fn fill(template: &str, values: &[(&str, &str)]) -> String {
+    let mut filled = String::new();
+    let mut rest = template;
+    while let Some((start, placeholder, value)) = values
+        .iter()
+        .filter_map(|(placeholder, value)| Some((rest.find(placeholder)?, placeholder, value)))
+        .min_by_key(|(start, _, _)| *start)
+    {
+        filled.push_str(&rest[..start]);
+        filled.push_str(value);
+        rest = &rest[start + placeholder.len()..];
+    }
+    filled.push_str(rest);
+    filled
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 pub fn print_end(msg: &str, start: std::time::Instant) {
     use colored::Colorize;
 
     if fastn_core::utils::is_test() {
-        println!("done in <omitted>");
+        fastn_core::progress!("done in <omitted>\n");
     } else {
-        println!(
+        fastn_core::progress!(
             // TODO: instead of lots of spaces put proper erase current terminal line thing
-            "\r{:?} {} in {:?}.                          ",
+            "\r{:?} {} in {:?}.                          \n",
             std::time::Instant::now(),
             msg.green(),
             start.elapsed()
@@ -159,13 +218,52 @@ mod test {
             "index.ftd/b/index.html"
         );
     }
+
+    #[test]
+    fn error_page_html() {
+        let message = "`</script><b>` and __DIAGNOSTICS__ & __ERROR_PATH__";
+        let html = super::error_page_html(
+            "__ERROR_TEXT__.ftd",
+            message,
+            &serde_json::json!([{ "message": message }]),
+        );
+        assert!(html.contains("<title>Error in __ERROR_TEXT__.ftd</title>"));
+        assert!(html.contains(
+            "<pre id=\"fastn-errors\">`&lt;/script&gt;&lt;b&gt;` and __DIAGNOSTICS__ &amp; \
+            __ERROR_PATH__</pre>"
+        ));
+        assert!(html.contains(
+            r#"id="fastn-diagnostics">[{"message":"`\u003c/script\u003e\u003cb\u003e` and __DIAGNOSTICS__ \u0026 __ERROR_PATH__"}]</script>"#
+        ));
+        let json = html
+            .split("id=\"fastn-diagnostics\">")
+            .nth(1)
+            .and_then(|s| s.strip_suffix("</script>\n</body>\n"))
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(json).unwrap(),
+            serde_json::json!([{ "message": message }])
+        );
+    }
+
+    #[test]
+    fn spa_payload_2023() {
+        let payload = super::spa_payload_2023(
+            "let main = function (parent) {};",
+            "<script src=\"/a.js\"></script>",
+        );
+        assert!(payload
+            .starts_with("ftd.spa_page(\"<script src=\\\"/a.js\\\"></script>\", function () {"));
+        assert!(payload.contains("let main = function (parent) {};"));
+        assert!(payload.contains("main(parenti0);"));
+    }
 }
 
 pub fn print_error(msg: &str, start: std::time::Instant) {
     use colored::Colorize;
 
     if fastn_core::utils::is_test() {
-        println!("done in <omitted>");
+        fastn_core::progress!("done in <omitted>\n");
     } else {
         eprintln!(
             "\r{:?} {} in {:?}.                          ",
@@ -298,7 +396,7 @@ impl Timer<'_> {
 
         if !fastn_core::utils::is_test() {
             let duration = format!("{:?}", self.start.elapsed());
-            println!("{} in {}", self.msg.green(), duration.red());
+            fastn_core::progress!("{} in {}\n", self.msg.green(), duration.red());
         }
 
         a
@@ -716,8 +814,61 @@ pub fn get_fastn_package_data(package: &fastn_core::Package) -> String {
     format!(
         indoc::indoc! {"
         let __fastn_package_name__ = \"{package_name}\";
+        {spa_data}
+        {markdown_data}
+    "},
+        package_name = package.name,
+        spa_data = get_spa_data(package),
+        markdown_data = get_markdown_data(package),
+    )
+}
+
+/// `__fastn_markdown__` holds the `-- fastn.markdown:` extensions, which
+/// `fastn_utils.markdown_inline` turns on in `marked`.
+pub fn get_markdown_data(package: &fastn_core::Package) -> String {
+    if package.markdown == ftd::executor::MarkdownProfile::default() {
+        return "".to_string();
+    }
+    format!(
+        "let __fastn_markdown__ = {};",
+        serde_json::to_string(&package.markdown).unwrap()
+    )
+}
+
+/// `__fastn_spa__` turns on client-side navigation in `postInit.js`, it holds
+/// the js names of the variables listed in `persist`.
+fn get_spa_data(package: &fastn_core::Package) -> String {
+    if !package.spa {
+        return "".to_string();
+    }
+    let persist = package
+        .persist
+        .iter()
+        .map(|v| fastn_js::utils::name_to_js(v.as_str()))
+        .collect::<Vec<_>>();
+    format!(
+        "let __fastn_spa__ = {};",
+        serde_json::to_string(&persist).unwrap()
+    )
+}
+
+/// The js for a client-side navigation to a page. `ftd.spa_page` first loads
+/// the page's `scripts` and then renders it in place of the current page.
+pub fn spa_payload_2023(js_script: &str, scripts: &str) -> String {
+    format!(
+        indoc::indoc! {"
+        ftd.spa_page({scripts}, function () {{
+            {js_script}
+            return function (parent) {{
+                let parenti0 = fastn_dom.createKernel(parent, fastn_dom.ElementKind.Column);
+                parenti0.setProperty(fastn_dom.PropertyKind.Width, fastn_dom.Resizing.FillContainer, inherited);
+                parenti0.setProperty(fastn_dom.PropertyKind.Height, fastn_dom.Resizing.FillContainer, inherited);
+                main(parenti0);
+            }};
+        }});
     "},
-        package_name = package.name
+        scripts = serde_json::to_string(scripts).unwrap(),
+        js_script = js_script,
     )
 }
 
@@ -732,6 +883,8 @@ pub async fn replace_markers_2023(
     config: &fastn_core::Config,
     session_id: &Option<String>,
 ) -> String {
+    let (highlighting_scripts, highlighting_themes) =
+        fastn_core::highlighting::prism_highlighting(config, base_url, session_id).await;
     format!(
         include_str!("../../ftd/ftd-js.html"),
         fastn_package = get_fastn_package_data(&config.package).as_str(),
@@ -750,17 +903,23 @@ pub async fn replace_markers_2023(
         .await
         .unwrap_or_default()
         .as_str(),
-        js_script = format!("{js_script}{}", fastn_core::utils::available_code_themes()).as_str(),
+        js_script = format!(
+            "{js_script}{}\n{highlighting_themes}",
+            fastn_core::utils::available_code_themes()
+        )
+        .as_str(),
         script_file = format!(
             r#"
                 <script src="{}"></script>
                 <script src="{}"></script>
+                {}
                 <script src="{}"></script>
                 <link rel="stylesheet" href="{}">
                 {}
             "#,
             hashed_markdown_js(),
             hashed_prism_js(),
+            highlighting_scripts,
             hashed_default_ftd_js(config.package.name.as_str()),
             hashed_prism_css(),
             scripts,
@@ -785,6 +944,19 @@ pub fn is_test() -> bool {
     cfg!(test) || std::env::args().any(|e| e == "--test")
 }
 
+/// Whether stdout is only for the errors as JSON, `--message-format json`, so
+/// an editor or a CI can read it.
+pub fn stdout_is_json() -> bool {
+    static JSON: once_cell::sync::Lazy<bool> = once_cell::sync::Lazy::new(|| {
+        let args = std::env::args().collect::<Vec<_>>();
+        args.iter().any(|e| e == "--message-format=json")
+            || args
+                .windows(2)
+                .any(|e| e[0] == "--message-format" && e[1] == "json")
+    });
+    *JSON
+}
+
 pub(crate) async fn write(
     root: &fastn_ds::Path,
     file_path: &str,
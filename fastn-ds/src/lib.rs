@@ -524,6 +524,22 @@ impl DocumentStore {
         req: &T,
         extra_headers: &std::collections::HashMap<String, String>,
     ) -> Result<fastn_ds::HttpResponse, HttpError>
+    where
+        T: RequestType,
+    {
+        let response = self.http_stream(url, req, extra_headers).await?;
+        Ok(fastn_ds::reqwest_util::to_http_response(response).await?)
+    }
+
+    /// Same as `http`, but the body of the response is not read, so that
+    /// long-lived responses (e.g. `text/event-stream`) can be streamed to the
+    /// client as they arrive.
+    pub async fn http_stream<T>(
+        &self,
+        url: url::Url,
+        req: &T,
+        extra_headers: &std::collections::HashMap<String, String>,
+    ) -> Result<reqwest::Response, HttpError>
     where
         T: RequestType,
     {
@@ -602,7 +618,7 @@ impl DocumentStore {
 
         tracing::info!(status = ?response.status(),headers = ?response.headers());
 
-        Ok(fastn_ds::reqwest_util::to_http_response(response).await?)
+        Ok(response)
     }
 }
 
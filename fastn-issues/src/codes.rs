@@ -0,0 +1,80 @@
+pub use ftd_p1::codes::{Code, ALL};
+
+/// What each error means and how to fix it, with examples, in markdown, in a
+/// file named after its code.
+static EXPLANATIONS: include_dir::Dir<'_> =
+    include_dir::include_dir!("$CARGO_MANIFEST_DIR/explanations");
+
+/// The error with the code `code`, like `FTD0102` or `ftd0102`.
+pub fn explain(code: &str) -> Option<&'static Code> {
+    ALL.iter()
+        .find(|c| c.code.eq_ignore_ascii_case(code.trim()))
+}
+
+/// What the error with the code `code` means and how to fix it.
+pub fn explanation(code: &Code) -> &'static str {
+    EXPLANATIONS
+        .get_file(format!("{}.md", code.code))
+        .and_then(|f| f.contents_utf8())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn every_code_is_explained() {
+        for code in super::ALL {
+            assert!(
+                !super::explanation(code).is_empty(),
+                "{} is not explained",
+                code.code
+            );
+        }
+        for file in super::EXPLANATIONS.files() {
+            let code = file.path().file_stem().unwrap().to_str().unwrap();
+            assert!(
+                super::explain(code).is_some(),
+                "{code} is not in ftd_p1::codes"
+            );
+        }
+    }
+
+    /// Like `FTD0102"`, a code and the quote that ends the string it is in.
+    fn is_code(s: &str) -> bool {
+        s.ends_with('"') && s.as_bytes()[3..7].iter().all(u8::is_ascii_digit)
+    }
+
+    /// The codes written out in the sources, instead of taken from
+    /// `ftd_p1::codes`, like the ones the tests expect, have to be explained too.
+    #[test]
+    fn every_code_in_the_sources_is_explained() {
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        for krate in ["ftd-p1", "ftd-ast", "ftd", "fastn-core"] {
+            let mut dirs = vec![root.join(krate).join("src")];
+            while let Some(dir) = dirs.pop() {
+                for entry in std::fs::read_dir(&dir).unwrap() {
+                    let path = entry.unwrap().path();
+                    if path.is_dir() {
+                        dirs.push(path);
+                        continue;
+                    }
+                    if path.extension().is_none_or(|e| e != "rs") {
+                        continue;
+                    }
+                    let source = std::fs::read_to_string(&path).unwrap();
+                    for (i, _) in source.match_indices("\"FTD") {
+                        let code = match source.get(i + 1..i + 9) {
+                            Some(code) if is_code(code) => &code[..7],
+                            _ => continue,
+                        };
+                        assert!(
+                            super::explain(code).is_some(),
+                            "{code} in {} is not explained",
+                            path.display()
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -2,5 +2,6 @@
 
 extern crate self as fastn_issues;
 
+pub mod codes;
 pub mod initialization;
 pub mod initialization_display;
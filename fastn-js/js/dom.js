@@ -6,6 +6,13 @@ fastn_dom.InternalClass = {
     FT_COLUMN: "ft_column",
     FT_ROW: "ft_row",
     FT_FULL_SIZE: "ft_full_size",
+    FT_RADIO: "ft_radio",
+    FT_TABLE: "ft_table",
+    FT_TABLE_ROW: "ft_table_row",
+    FT_GRID: "ft_grid",
+    FT_SVG: "ft_svg",
+    FT_ICON: "ft_icon",
+    FT_MATH: "ft_math",
 };
 
 fastn_dom.codeData = {
@@ -19,10 +26,18 @@ fastn_dom.externalJs = new Set();
 // Todo: Object (key, value) pair (counter type key)
 fastn_dom.webComponent = [];
 
+fastn_dom.radioGroupCounter = 0;
+
 fastn_dom.commentNode = "comment";
 fastn_dom.wrapperNode = "wrapper";
 fastn_dom.commentMessage = "***FASTN***";
 fastn_dom.webComponentArgument = "args";
+// meta tags added by `ftd.document`, removed on client-side navigation
+fastn_dom.metaTagAttribute = "data-fastn-meta";
+// nodes holding timers or observers, destroyed on client-side navigation
+fastn_dom.disposableNodes = new Set();
+// `$sse$` subscriptions, closed on client-side navigation
+fastn_dom.eventSources = new Set();
 
 fastn_dom.classes = {};
 fastn_dom.unsanitised_classes = {};
@@ -30,6 +45,7 @@ fastn_dom.class_count = 0;
 fastn_dom.propertyMap = {
     "align-items": "ali",
     "align-self": "as",
+    animation: "anm",
     "background-color": "bgc",
     "background-image": "bgi",
     "background-position": "bgp",
@@ -62,10 +78,20 @@ fastn_dom.propertyMap = {
     cursor: "cur",
     display: "d",
     download: "dw",
+    fill: "fl",
     "flex-wrap": "fw",
     "font-style": "fst",
     "font-weight": "fwt",
     gap: "g",
+    "row-gap": "rg",
+    "column-gap": "cg",
+    "grid-template-columns": "gtc",
+    "grid-template-rows": "gtr",
+    "grid-template-areas": "gta",
+    "grid-auto-flow": "gaf",
+    "grid-column": "gc",
+    "grid-row": "gr",
+    "grid-area": "ga",
     height: "h",
     "justify-content": "jc",
     left: "l",
@@ -99,10 +125,12 @@ fastn_dom.propertyMap = {
     role: "rl",
     right: "r",
     sticky: "s",
+    stroke: "stk",
     "text-align": "ta",
     "text-decoration": "td",
     "text-transform": "tt",
     top: "t",
+    transition: "trn",
     width: "w",
     "z-index": "z",
     "-webkit-box-orient": "wbo",
@@ -138,6 +166,9 @@ fastn_dom.getClassesAsStringWithoutStyleTag = function () {
 };
 
 function getClassAsString(className, obj) {
+    if (obj.property === "@keyframes") {
+        return `${className} { ${obj.value} }`;
+    }
     if (typeof obj.value === "object" && obj.value !== null) {
         let value = "";
         for (let key in obj.value) {
@@ -184,6 +215,17 @@ fastn_dom.ElementKind = {
     },
     Video: 18,
     Audio: 19,
+    Select: 20,
+    Radio: 21,
+    Textarea: 22,
+    FileInput: 23,
+    Form: 24,
+    Table: 25,
+    TableRow: 26,
+    Grid: 27,
+    Svg: 28,
+    Icon: 29,
+    Math: 30,
 };
 
 fastn_dom.PropertyKind = {
@@ -315,6 +357,96 @@ fastn_dom.PropertyKind = {
     FetchPriority: 123,
     Download: 124,
     SrcDoc: 125,
+    Options: 126,
+    SelectedValue: 127,
+    SelectedValues: 128,
+    Multiple: 129,
+    Rows: 130,
+    AutoResize: 131,
+    Accept: 132,
+    Name: 133,
+    FormFields: 134,
+    FormAction: 135,
+    FormMethod: 136,
+    FormEncoding: 137,
+    TableColumns: 138,
+    TableData: 139,
+    StickyHeader: 140,
+    Sortable: 141,
+    PageSize: 142,
+    GridTemplateColumns: 143,
+    GridTemplateRows: 144,
+    GridTemplateAreas: 145,
+    GridAutoFlow: 146,
+    Gap: 147,
+    RowGap: 148,
+    ColumnGap: 149,
+    GridColumn: 150,
+    GridRow: 151,
+    GridArea: 152,
+    SvgContent: 153,
+    Fill: 154,
+    Stroke: 155,
+    Transition: 156,
+    Animation: 157,
+    CodeHighlight: 158,
+    CodeDiff: 159,
+    CodeLineNumberStart: 160,
+    CodeTitle: 161,
+    MathContent: 162,
+};
+
+// `count` is a number of equal tracks, like `repeat(3, minmax(0, 1fr))`
+fastn_dom.GridTemplate = {
+    Fixed: (value) => {
+        return value;
+    },
+    Count: (value) => {
+        if (value instanceof fastn.mutableClass) {
+            return fastn.formula([value], function () {
+                return fastn_dom.GridTemplate.Count(value.get());
+            });
+        }
+        return `repeat(${value}, minmax(0, 1fr))`;
+    },
+    Responsive: (value) => fastn_dom.Length.Responsive(value),
+};
+
+fastn_dom.GridValue = {
+    Fixed: (value) => {
+        return value;
+    },
+    Span: (value) => {
+        if (value instanceof fastn.mutableClass) {
+            return fastn.formula([value], function () {
+                return `span ${value.get()}`;
+            });
+        }
+        return `span ${value}`;
+    },
+    Responsive: (value) => fastn_dom.Length.Responsive(value),
+};
+
+fastn_dom.GridAutoFlow = {
+    Row: "row",
+    Column: "column",
+    Dense: "dense",
+    RowDense: "row dense",
+    ColumnDense: "column dense",
+};
+
+fastn_dom.Easing = {
+    Linear: "linear",
+    Ease: "ease",
+    EaseIn: "ease-in",
+    EaseOut: "ease-out",
+    EaseInOut: "ease-in-out",
+    CubicBezier: (value) => {
+        return `cubic-bezier(${value})`;
+    },
+    Steps: (value) => {
+        return `steps(${value})`;
+    },
 };
 
 fastn_dom.Loading = {
@@ -322,6 +454,11 @@ fastn_dom.Loading = {
     Eager: "eager",
 };
 
+fastn_dom.FormEncoding = {
+    Json: "json",
+    UrlEncoded: "urlencoded",
+};
+
 fastn_dom.LinkRel = {
     NoFollow: "nofollow",
     Sponsored: "sponsored",
@@ -340,6 +477,8 @@ fastn_dom.TextInputType = {
     Week: "week",
     Color: "color",
     File: "file",
+    Number: "number",
+    Submit: "submit",
 };
 
 fastn_dom.AlignContent = {
@@ -814,6 +953,21 @@ fastn_dom.Event = {
     Change: 7,
     Blur: 8,
     Focus: 9,
+    Interval: (ms) => {
+        return [10, ms];
+    },
+    Timeout: (ms) => {
+        return [11, ms];
+    },
+    Visible: 12,
+    Hidden: 13,
+    Scroll: (ms) => {
+        return [14, ms];
+    },
+    Submit: 15,
+    KeyDown: (keys) => {
+        return [16, keys];
+    },
 };
 
 class PropertyValueAsClosure {
@@ -865,6 +1019,7 @@ class Node2 {
 
         this.#mutables = [];
         this.#extraData = {};
+        let node = this.wrapTableCell(parentOrSibiling);
         /*if (!!parent.parent) {
             parent = parent.parent();
         }*/
@@ -879,13 +1034,33 @@ class Node2 {
         }
         if (sibiling) {
             this.#parent.insertBefore(
-                this.#node,
+                node,
                 fastn_utils.nextSibling(sibiling, this.#parent),
             );
         } else {
-            this.#parent.appendChild(this.#node);
+            this.#parent.appendChild(node);
         }
     }
+    // The children of `ftd.table-row` are the cells of the row, each one is
+    // wrapped in a `td`.
+    wrapTableCell(parentOrSibiling) {
+        let owner = parentOrSibiling;
+        while (owner instanceof ParentNodeWithSibiling) {
+            owner = owner.getParent();
+        }
+        if (
+            !(owner instanceof Node2) ||
+            owner.#kind !== fastn_dom.ElementKind.TableRow ||
+            this.#kind === fastn_dom.ElementKind.Comment ||
+            fastn_utils.isWrapperNode(this.#tagName)
+        ) {
+            return this.#node;
+        }
+        let cell = fastnVirtual.document.createElement("td");
+        cell.appendChild(this.#node);
+        this.#extraData.tableCell = cell;
+        return cell;
+    }
     createNode(kind) {
         if (kind === fastn_dom.ElementKind.Code) {
             let [node, classes, attributes] = fastn_utils.htmlNode(kind);
@@ -951,6 +1126,216 @@ class Node2 {
             this.attachAttribute("value", this.#rawInnerValue);
         }
     }
+    // Renders the `options` of `ftd.select` and `ftd.radio` along with the
+    // current selection. The options are rendered as html so it works the
+    // same way in ssr and in the browser.
+    updateOptions() {
+        let form = this.#extraData.form;
+        if (fastn_utils.isNull(form) || fastn_utils.isNull(form.options)) {
+            return;
+        }
+        let selected = new Set(form.values.map((v) => `${v}`));
+        if (!fastn_utils.isNull(form.value)) {
+            selected.add(`${form.value}`);
+        }
+        let escape = fastn_utils.escapeHtml;
+        let html = "";
+        if (
+            this.#kind === fastn_dom.ElementKind.Select &&
+            !fastn_utils.isNull(form.placeholder)
+        ) {
+            html += `<option value="" disabled hidden${
+                selected.size === 0 ? " selected" : ""
+            }>${escape(form.placeholder)}</option>`;
+        }
+        for (let option of form.options) {
+            let record = fastn_utils.getStaticValue(option.item);
+            let label = fastn_utils.getStaticValue(record.get("label"));
+            let value = fastn_utils.getStaticValue(record.get("value"));
+            let disabled = fastn_utils.getStaticValue(record.get("disabled"));
+            let isSelected = selected.has(`${value}`);
+            if (this.#kind === fastn_dom.ElementKind.Radio) {
+                html += `<label><input type="radio" name="${
+                    form.name
+                }" value="${escape(value)}"${isSelected ? " checked" : ""}${
+                    disabled ? " disabled" : ""
+                }>${escape(label)}</label>`;
+            } else {
+                html += `<option value="${escape(value)}"${
+                    isSelected ? " selected" : ""
+                }${disabled ? " disabled" : ""}>${escape(label)}</option>`;
+            }
+        }
+        this.#node.innerHTML = html;
+    }
+    // `ftd.table` keeps its header, `data` rows and pager in `thead`, `tbody`
+    // and `tfoot`. `ftd.table-row` children are appended after these.
+    getTableData() {
+        if (fastn_utils.isNull(this.#extraData.table)) {
+            let table = {
+                columns: [],
+                data: null,
+                sortable: false,
+                pageSize: null,
+                sortColumn: null,
+                descending: false,
+                page: 0,
+            };
+            for (let section of ["thead", "tbody", "tfoot"]) {
+                table[section] = fastnVirtual.document.createElement(section);
+                this.#node.appendChild(table[section]);
+            }
+            this.#extraData.table = table;
+            if (!ssr) {
+                this.attachTableListeners();
+            }
+        }
+        return this.#extraData.table;
+    }
+    attachTableListeners() {
+        this.#node.addEventListener("click", (event) => {
+            let target = event.target.closest(
+                "th[data-column], button[data-page]",
+            );
+            if (!target || target.closest("table") !== this.#node) {
+                return;
+            }
+            let table = this.#extraData.table;
+            if (target.tagName === "BUTTON") {
+                table.page += Number(target.dataset.page);
+                this.updateTableRows();
+                return;
+            }
+            let index = Number(target.dataset.column);
+            if (!table.sortable || !table.columns[index].sortable) {
+                return;
+            }
+            table.descending = table.sortColumn === index && !table.descending;
+            table.sortColumn = index;
+            table.page = 0;
+            this.updateTable();
+        });
+        // `ftd.table-row` children are created after the table properties
+        // are set and can change with the list they are looped over
+        let observer = new MutationObserver(() => this.updateTableRows());
+        observer.observe(this.#node, { childList: true });
+        this.#extraData.tableObserver = observer;
+    }
+    updateTable() {
+        let table = this.getTableData();
+        let header = table.columns.map((column, index) => {
+            let attributes = "";
+            if (table.sortable && column.sortable) {
+                let sort = "none";
+                if (table.sortColumn === index) {
+                    sort = table.descending ? "descending" : "ascending";
+                }
+                attributes = ` class="ft_table_sortable" aria-sort="${sort}"`;
+            }
+            return `<th scope="col" data-column="${index}"${attributes}>${
+                fastn_utils.escapeHtml(column.header)
+            }</th>`;
+        });
+        table.thead.innerHTML =
+            header.length > 0 ? `<tr>${header.join("")}</tr>` : "";
+        this.updateTableRows();
+    }
+    // Renders `data` (or orders the `ftd.table-row` children) sorted by the
+    // selected column and shows only the current page.
+    updateTableRows() {
+        let table = this.getTableData();
+        let column = table.columns[table.sortColumn];
+        let compare = (a, b) => {
+            let order = fastn_utils.compareTableValues(a, b);
+            return table.descending ? -order : order;
+        };
+        let pageSize = table.pageSize > 0 ? Number(table.pageSize) : null;
+        let rows;
+        if (!fastn_utils.isNull(table.data)) {
+            rows = table.data.map((row) =>
+                fastn_utils.getStaticValue(row.item),
+            );
+            if (!fastn_utils.isNull(column)) {
+                let key = (row) => fastn_utils.getTableCellValue(row, column);
+                rows = rows.slice().sort((a, b) => compare(key(a), key(b)));
+            }
+        } else if (!ssr) {
+            rows = Array.from(this.#node.children).filter((row) =>
+                row.classList.contains(fastn_dom.InternalClass.FT_TABLE_ROW),
+            );
+            if (!fastn_utils.isNull(column)) {
+                let key = (row) => {
+                    let cell = row.children[table.sortColumn];
+                    if (fastn_utils.isNull(cell)) return null;
+                    if (cell.hasAttribute("data-sort")) {
+                        return cell.getAttribute("data-sort");
+                    }
+                    return cell.textContent.trim();
+                };
+                rows.sort((a, b) => compare(key(a), key(b)));
+                let observer = this.#extraData.tableObserver;
+                observer.disconnect();
+                rows.forEach((row) => this.#node.appendChild(row));
+                observer.observe(this.#node, { childList: true });
+            }
+        } else {
+            return;
+        }
+
+        let pages = pageSize
+            ? Math.max(Math.ceil(rows.length / pageSize), 1)
+            : 1;
+        table.page = Math.min(Math.max(table.page, 0), pages - 1);
+        let start = pageSize ? table.page * pageSize : 0;
+        let end = pageSize ? start + pageSize : rows.length;
+
+        if (!fastn_utils.isNull(table.data)) {
+            table.tbody.innerHTML = rows
+                .slice(start, end)
+                .map((row) => {
+                    let cells = table.columns.map((column) => {
+                        let value = fastn_utils.getTableCellValue(row, column);
+                        return `<td>${fastn_utils.escapeHtml(
+                            fastn_utils.isNull(value) ? "" : value,
+                        )}</td>`;
+                    });
+                    return `<tr>${cells.join("")}</tr>`;
+                })
+                .join("");
+        } else {
+            rows.forEach((row, index) => {
+                row.hidden = index < start || index >= end;
+            });
+        }
+
+        if (pages === 1) {
+            table.tfoot.innerHTML = "";
+            return;
+        }
+        let button = (page, label, disabled) =>
+            `<button type="button" data-page="${page}"${
+                disabled ? " disabled" : ""
+            }>${label}</button>`;
+        table.tfoot.innerHTML =
+            `<tr><td colspan="${Math.max(table.columns.length, 1)}">` +
+            button(-1, "Previous", table.page === 0) +
+            `<span>Page ${table.page + 1} of ${pages}</span>` +
+            button(1, "Next", table.page === pages - 1) +
+            "</td></tr>";
+    }
+    getFormData() {
+        if (fastn_utils.isNull(this.#extraData.form)) {
+            this.#extraData.form = {
+                options: null,
+                value: null,
+                values: [],
+                placeholder: null,
+                // radio inputs of one group share a name
+                name: `ft_radio_${fastn_dom.radioGroupCounter++}`,
+            };
+        }
+        return this.#extraData.form;
+    }
     // for attaching inline attributes
     attachAttribute(property, value) {
         // If the value is null, undefined, or false, the attribute will be removed.
@@ -961,6 +1346,29 @@ class Node2 {
         }
         this.#node.setAttribute(property, value);
     }
+    // Shows the text of `ftd.code` with its `highlight`, `diff` and
+    // `line-number-start`, these are set before the text.
+    updateCode() {
+        let code = this.#extraData.code;
+        if (code.text === undefined) {
+            return;
+        }
+        let text = code.text;
+        if (!fastn_utils.isNull(text)) {
+            let { modifiedText, highlightedLines, signs, callouts } =
+                fastn_utils.prepareCode(text, code);
+            this.attachAttribute(
+                "data-line",
+                highlightedLines.length !== 0 ? highlightedLines : null,
+            );
+            code.signs = signs;
+            code.callouts = callouts;
+            text = modifiedText;
+        }
+        let codeNode = this.#children[0].getNode();
+        codeNode.innerHTML = fastn_utils.escapeHtmlInCode(text);
+        fastn_utils.highlightCode(codeNode, code);
+    }
     removeAttribute(property) {
         this.#node.removeAttribute(property);
     }
@@ -1040,6 +1448,7 @@ class Node2 {
             const metaTag = window.document.createElement("meta");
             metaTag.setAttribute("name", name);
             metaTag.setAttribute("content", value);
+            metaTag.setAttribute(fastn_dom.metaTagAttribute, "");
             document.head.appendChild(metaTag);
         }
     }
@@ -1052,6 +1461,7 @@ class Node2 {
             const metaTag = window.document.createElement("meta");
             metaTag.setAttribute("property", property);
             metaTag.setAttribute("content", value);
+            metaTag.setAttribute(fastn_dom.metaTagAttribute, "");
             document.head.appendChild(metaTag);
         }
     }
@@ -1192,6 +1602,92 @@ class Node2 {
             );
         }
     }
+    // `body.reduced-motion` is set by `ftd.post_init` when the user prefers
+    // reduced motion, and turns off the transitions and animations.
+    attachReducedMotionCss(property, value) {
+        if (fastn_utils.isNull(value)) {
+            this.attachCss(property, value);
+            return;
+        }
+        let cls = this.attachCss(property, value, true);
+        this.attachCss(property, "none", true, `body.reduced-motion .${cls}`);
+    }
+    attachTransition(value) {
+        if (fastn_utils.isNull(value)) {
+            this.attachReducedMotionCss("transition", value);
+            return;
+        }
+        const duration = fastn_utils.getStaticValue(value.get("duration"));
+        const easing = fastn_utils.getStaticValue(value.get("easing"));
+        const delay = fastn_utils.getStaticValue(value.get("delay"));
+        const transition = fastn_utils
+            .getStaticValue(value.get("property"))
+            .split(",")
+            .map((p) => `${p.trim()} ${duration}ms ${easing} ${delay}ms`)
+            .join(", ");
+        this.attachReducedMotionCss("transition", transition);
+    }
+    attachAnimation(value) {
+        if (fastn_utils.isNull(value)) {
+            this.attachReducedMotionCss("animation", value);
+            return;
+        }
+        const name = this.attachKeyframes(value.get("keyframes").get());
+        const duration = fastn_utils.getStaticValue(value.get("duration"));
+        const easing = fastn_utils.getStaticValue(value.get("easing"));
+        const delay = fastn_utils.getStaticValue(value.get("delay"));
+        const iterations = fastn_utils.getStaticValue(value.get("infinite"))
+            ? "infinite"
+            : fastn_utils.getStaticValue(value.get("iterations"));
+        const direction = fastn_utils.getStaticValue(value.get("alternate"))
+            ? "alternate"
+            : "normal";
+        this.attachReducedMotionCss(
+            "animation",
+            `${name} ${duration}ms ${easing} ${delay}ms ${iterations} ` +
+                `${direction} both`,
+        );
+    }
+    // Keyframes are added to the styles once per name, as `ftd-<name>`.
+    attachKeyframes(keyframes) {
+        const name = `ftd-${fastn_utils
+            .getStaticValue(keyframes.get("name"))
+            .replace(/[^\w-]/g, "-")}`;
+        const frames = keyframes
+            .get("frames")
+            .get()
+            .getList()
+            .map((element) => {
+                const frame = element.item;
+                const value = (key) =>
+                    fastn_utils.getStaticValue(frame.get(key));
+                const moves = ["move_x", "move_y"].some(
+                    (key) => !fastn_utils.isNull(value(key)),
+                );
+                const transform = [
+                    moves
+                        ? `translate(${value("move_x") || 0}, ` +
+                          `${value("move_y") || 0})`
+                        : null,
+                    fastn_utils.isNull(value("scale"))
+                        ? null
+                        : `scale(${value("scale")})`,
+                    fastn_utils.isNull(value("rotate"))
+                        ? null
+                        : `rotate(${value("rotate")}deg)`,
+                ].filter((t) => t !== null);
+                let css = fastn_utils.isNull(value("opacity"))
+                    ? ""
+                    : ` opacity: ${value("opacity")};`;
+                if (transform.length > 0) {
+                    css = `${css} transform: ${transform.join(" ")};`;
+                }
+                return `${value("at")}% {${css} }`;
+            })
+            .join(" ");
+        this.attachCss("@keyframes", frames, true, `@keyframes ${name}`);
+        return name;
+    }
     attachBackdropMultiFilter(value) {
         const filters = {
             blur: fastn_utils.getStaticValue(value.get("blur")),
@@ -1681,7 +2177,10 @@ class Node2 {
             this.attachCss("justify-content", value);
             return;
         }
-        if (node_kind === fastn_dom.ElementKind.Column) {
+        if (
+            node_kind === fastn_dom.ElementKind.Column ||
+            node_kind === fastn_dom.ElementKind.Form
+        ) {
             switch (value) {
                 case "top-left":
                     this.attachCss("justify-content", "start");
@@ -2067,6 +2566,52 @@ class Node2 {
             this.attachCss("border-bottom-style", staticValue);
         } else if (kind === fastn_dom.PropertyKind.ZIndex) {
             this.attachCss("z-index", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridTemplateColumns) {
+            this.attachCss("grid-template-columns", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridTemplateRows) {
+            this.attachCss("grid-template-rows", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridTemplateAreas) {
+            this.attachCss("grid-template-areas", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridAutoFlow) {
+            this.attachCss("grid-auto-flow", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Gap) {
+            this.attachCss("gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.RowGap) {
+            this.attachCss("row-gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.ColumnGap) {
+            this.attachCss("column-gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridColumn) {
+            this.attachCss("grid-column", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridRow) {
+            this.attachCss("grid-row", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridArea) {
+            this.attachCss("grid-area", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.SvgContent) {
+            this.#node.innerHTML = fastn_utils.sanitizeSvg(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.MathContent) {
+            // fastn renders the LaTeX to MathML at compile time, LaTeX in a
+            // variable is shown as written
+            let math = fastn_utils.isNull(staticValue) ? "" : `${staticValue}`;
+            this.#node.innerHTML = fastn_utils.private.trustedMarkup.has(math)
+                ? math
+                : fastn_utils.escapeHtml(math);
+        } else if (
+            kind === fastn_dom.PropertyKind.Fill ||
+            kind === fastn_dom.PropertyKind.Stroke
+        ) {
+            let property =
+                kind === fastn_dom.PropertyKind.Fill ? "fill" : "stroke";
+            this.attachColorCss(property, staticValue);
+            // `data-fill` and `data-stroke` make the colour win over the ones
+            // in the svg markup, see ftd-js.css
+            this.attachAttribute(
+                `data-${property}`,
+                fastn_utils.isNull(staticValue) ? null : "",
+            );
+        } else if (kind === fastn_dom.PropertyKind.Transition) {
+            this.attachTransition(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Animation) {
+            this.attachAnimation(staticValue);
         } else if (kind === fastn_dom.PropertyKind.Shadow) {
             this.attachShadow(staticValue);
         } else if (kind === fastn_dom.PropertyKind.TextShadow) {
@@ -2371,8 +2916,119 @@ class Node2 {
             this.updateTextInputValue();
         } else if (kind === fastn_dom.PropertyKind.InputMaxLength) {
             this.attachAttribute("maxlength", staticValue);
+        } else if (
+            kind === fastn_dom.PropertyKind.Placeholder &&
+            this.#kind === fastn_dom.ElementKind.Select
+        ) {
+            // `select` has no placeholder attribute, it is rendered as a
+            // hidden first option
+            this.getFormData().placeholder = staticValue;
+            this.updateOptions();
         } else if (kind === fastn_dom.PropertyKind.Placeholder) {
             this.attachAttribute("placeholder", staticValue);
+        } else if (
+            kind === fastn_dom.PropertyKind.Name &&
+            this.#kind === fastn_dom.ElementKind.Radio
+        ) {
+            // the name is shared by the radio inputs of the group
+            let form = this.getFormData();
+            if (!fastn_utils.isNull(staticValue)) form.name = staticValue;
+            this.updateOptions();
+        } else if (kind === fastn_dom.PropertyKind.Name) {
+            this.attachAttribute("name", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.FormAction) {
+            this.attachAttribute("action", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.FormMethod) {
+            this.attachAttribute("method", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.FormEncoding) {
+            // Without js the browser always posts the form urlencoded, the
+            // encoding is only used when the form is submitted with js
+            this.#extraData.formEncoding = staticValue;
+        } else if (kind === fastn_dom.PropertyKind.FormFields) {
+            // keeping the list itself so the latest fields are validated
+            this.#extraData.formFields = value;
+            if (ssr || this.#extraData.formSubmitListener) {
+                return;
+            }
+            this.#extraData.formSubmitListener = true;
+            this.#node.addEventListener("submit", (event) => {
+                event.preventDefault();
+                ftd.submit_ftd_form(this.#node, this.#extraData.formFields, {
+                    encoding: this.#extraData.formEncoding,
+                });
+            });
+        } else if (kind === fastn_dom.PropertyKind.TableColumns) {
+            this.getTableData().columns = (
+                fastn_utils.isNull(staticValue) ? [] : staticValue
+            ).map((column) => {
+                let record = fastn_utils.getStaticValue(column.item);
+                return {
+                    header: fastn_utils.getStaticValue(record.get("header")),
+                    field: fastn_utils.getStaticValue(record.get("field")),
+                    sortable:
+                        fastn_utils.getStaticValue(record.get("sortable")) !==
+                        false,
+                };
+            });
+            this.updateTable();
+        } else if (kind === fastn_dom.PropertyKind.TableData) {
+            this.getTableData().data = fastn_utils.isNull(staticValue)
+                ? []
+                : staticValue;
+            this.updateTableRows();
+        } else if (kind === fastn_dom.PropertyKind.Sortable) {
+            this.getTableData().sortable =
+                staticValue === true || staticValue === "true";
+            this.updateTable();
+        } else if (kind === fastn_dom.PropertyKind.PageSize) {
+            let table = this.getTableData();
+            table.pageSize = staticValue;
+            table.page = 0;
+            this.updateTableRows();
+        } else if (kind === fastn_dom.PropertyKind.StickyHeader) {
+            this.attachAttribute(
+                "data-sticky-header",
+                staticValue === true || staticValue === "true" ? "" : null,
+            );
+        } else if (kind === fastn_dom.PropertyKind.Options) {
+            this.getFormData().options = fastn_utils.isNull(staticValue)
+                ? []
+                : staticValue;
+            this.updateOptions();
+        } else if (kind === fastn_dom.PropertyKind.SelectedValue) {
+            this.getFormData().value = staticValue;
+            this.updateOptions();
+        } else if (kind === fastn_dom.PropertyKind.SelectedValues) {
+            this.getFormData().values = fastn_utils.isNull(staticValue)
+                ? []
+                : staticValue.map((obj) =>
+                      fastn_utils.getStaticValue(obj.item),
+                  );
+            this.updateOptions();
+        } else if (kind === fastn_dom.PropertyKind.Multiple) {
+            this.attachAttribute(
+                "multiple",
+                staticValue === true || staticValue === "true" ? "" : null,
+            );
+        } else if (kind === fastn_dom.PropertyKind.Rows) {
+            this.attachAttribute("rows", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Accept) {
+            this.attachAttribute("accept", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.AutoResize) {
+            let autoResize = staticValue === true || staticValue === "true";
+            this.#extraData.autoResize = autoResize;
+            if (ssr || !autoResize || this.#extraData.autoResizeListener) {
+                return;
+            }
+            this.#extraData.autoResizeListener = true;
+            let node = this.#node;
+            let resize = () => {
+                if (!this.#extraData.autoResize) return;
+                node.style.height = "auto";
+                node.style.height = `${node.scrollHeight}px`;
+            };
+            node.addEventListener("input", resize);
+            resize();
         } else if (kind === fastn_dom.PropertyKind.Multiline) {
             switch (staticValue) {
                 case "true":
@@ -2565,21 +3221,38 @@ class Node2 {
         } else if (kind === fastn_dom.PropertyKind.Role) {
             this.attachRoleCss(staticValue);
         } else if (kind === fastn_dom.PropertyKind.Code) {
-            if (!fastn_utils.isNull(staticValue)) {
-                let { modifiedText, highlightedLines } =
-                    fastn_utils.findAndRemoveHighlighter(staticValue);
-                if (highlightedLines.length !== 0) {
-                    this.attachAttribute("data-line", highlightedLines);
-                }
-                staticValue = modifiedText;
-            }
-            let codeNode = this.#children[0].getNode();
-            let codeText = fastn_utils.escapeHtmlInCode(staticValue);
-            codeNode.innerHTML = codeText;
             this.#extraData.code = this.#extraData.code
                 ? this.#extraData.code
                 : {};
-            fastn_utils.highlightCode(codeNode, this.#extraData.code);
+            this.#extraData.code.text = staticValue;
+            this.updateCode();
+        } else if (kind === fastn_dom.PropertyKind.CodeHighlight) {
+            this.#extraData.code = this.#extraData.code
+                ? this.#extraData.code
+                : {};
+            this.#extraData.code.highlight = staticValue;
+            this.updateCode();
+        } else if (kind === fastn_dom.PropertyKind.CodeDiff) {
+            this.#extraData.code = this.#extraData.code
+                ? this.#extraData.code
+                : {};
+            this.#extraData.code.diff = staticValue;
+            this.updateCode();
+        } else if (kind === fastn_dom.PropertyKind.CodeLineNumberStart) {
+            this.#extraData.code = this.#extraData.code
+                ? this.#extraData.code
+                : {};
+            this.#extraData.code.start = staticValue;
+            // `data-line-offset` keeps the highlighted lines in the numbers
+            // shown when the line numbers are not shown
+            this.attachAttribute("data-start", staticValue);
+            this.attachAttribute(
+                "data-line-offset",
+                fastn_utils.isNull(staticValue) ? null : staticValue - 1,
+            );
+            this.updateCode();
+        } else if (kind === fastn_dom.PropertyKind.CodeTitle) {
+            this.attachAttribute("data-title", staticValue);
         } else if (kind === fastn_dom.PropertyKind.CodeShowLineNumber) {
             if (staticValue) {
                 this.#node.classList.add("line-numbers");
@@ -2735,6 +3408,14 @@ class Node2 {
                 },
                 inherited,
             );
+        } else if (
+            value instanceof fastn.mutableListClass &&
+            (kind === fastn_dom.PropertyKind.Options ||
+                kind === fastn_dom.PropertyKind.TableColumns ||
+                kind === fastn_dom.PropertyKind.TableData)
+        ) {
+            // re-render the options or rows when the list changes
+            this.setDynamicProperty(kind, [value], () => value, inherited);
         } else if (value instanceof PropertyValueAsClosure) {
             this.setDynamicProperty(
                 kind,
@@ -2812,8 +3493,71 @@ class Node2 {
         } else if (event === fastn_dom.Event.Focus) {
             let onFocusEvents = this.mergeFnCalls(this.#node.onfocus, func);
             this.#node.onfocus = onFocusEvents;
+        } else if (ssr) {
+            // the rest of the events need the browser, they are added when
+            // the page is rendered again in the browser
+        } else if (!!event[0] && event[0] === fastn_dom.Event.Interval()[0]) {
+            this.addTimer(setInterval(func, event[1]), clearInterval);
+        } else if (!!event[0] && event[0] === fastn_dom.Event.Timeout()[0]) {
+            this.addTimer(setTimeout(func, event[1]), clearTimeout);
+        } else if (
+            event === fastn_dom.Event.Visible ||
+            event === fastn_dom.Event.Hidden
+        ) {
+            this.addVisibilityHandler(event === fastn_dom.Event.Visible, func);
+        } else if (!!event[0] && event[0] === fastn_dom.Event.Scroll()[0]) {
+            this.#node.addEventListener(
+                "scroll",
+                fastn_utils.throttle(func, event[1]),
+                { passive: true },
+            );
+        } else if (event === fastn_dom.Event.Submit) {
+            this.#node.addEventListener("submit", (e) => {
+                // a form with an `action` still posts itself
+                if (!e.target.getAttribute("action")) e.preventDefault();
+                func();
+            });
+        } else if (!!event[0] && event[0] === fastn_dom.Event.KeyDown()[0]) {
+            this.#node.addEventListener("keydown", (e) => {
+                if (fastn_utils.isKeyPressed(e, event[1])) func();
+            });
         }
     }
+    // `on-interval` and `on-timeout` timers are cleared with the element
+    addTimer(id, clear) {
+        if (fastn_utils.isNull(this.#extraData.timers)) {
+            this.#extraData.timers = [];
+        }
+        this.#extraData.timers.push([id, clear]);
+        fastn_dom.disposableNodes.add(this);
+    }
+    // `on-visible` runs every time the element comes into the viewport, and
+    // `on-hidden` every time it leaves the viewport after being visible
+    addVisibilityHandler(visible, func) {
+        if (typeof IntersectionObserver === "undefined") {
+            return;
+        }
+        let visibility = this.#extraData.visibility;
+        if (fastn_utils.isNull(visibility)) {
+            visibility = { visible: [], hidden: [], isVisible: false };
+            visibility.observer = new IntersectionObserver((entries) => {
+                for (const entry of entries) {
+                    if (entry.isIntersecting === visibility.isVisible) {
+                        continue;
+                    }
+                    visibility.isVisible = entry.isIntersecting;
+                    (entry.isIntersecting
+                        ? visibility.visible
+                        : visibility.hidden
+                    ).forEach((f) => f());
+                }
+            });
+            visibility.observer.observe(this.#node);
+            this.#extraData.visibility = visibility;
+            fastn_dom.disposableNodes.add(this);
+        }
+        (visible ? visibility.visible : visibility.hidden).push(func);
+    }
     destroy() {
         for (let i = 0; i < this.#mutables.length; i++) {
             this.#mutables[i].unlinkNode(this);
@@ -2821,6 +3565,19 @@ class Node2 {
         // Todo: We don't need this condition as after destroying this node
         //  ConditionalDom reset this.#conditionUI to null or some different
         //  value. Not sure why this is still needed.
+        if (!fastn_utils.isNull(this.#extraData.tableObserver)) {
+            this.#extraData.tableObserver.disconnect();
+        }
+        if (!fastn_utils.isNull(this.#extraData.tableCell)) {
+            this.#extraData.tableCell.remove();
+        }
+        if (!fastn_utils.isNull(this.#extraData.timers)) {
+            this.#extraData.timers.forEach(([id, clear]) => clear(id));
+        }
+        if (!fastn_utils.isNull(this.#extraData.visibility)) {
+            this.#extraData.visibility.observer.disconnect();
+        }
+        fastn_dom.disposableNodes.delete(this);
         if (!fastn_utils.isNull(this.#node)) {
             this.#node.remove();
         }
@@ -2931,7 +3688,10 @@ class ForLoop {
     #wrapper;
     #parent;
     #nodes;
-    constructor(parent, node_constructor, list) {
+    #items;
+    #key;
+    #end;
+    constructor(parent, node_constructor, list, key) {
         this.#wrapper = fastn_dom.createKernel(
             parent,
             fastn_dom.ElementKind.Comment,
@@ -2940,12 +3700,28 @@ class ForLoop {
         this.#node_constructor = node_constructor;
         this.#list = list;
         this.#nodes = [];
+        this.#items = [];
+        this.#key = key;
 
         fastn_utils.resetFullHeight();
         for (let idx in list.getList()) {
-            this.createNode(idx, false);
+            this.createNode(parseInt(idx), false);
         }
         fastn_utils.setFullHeight();
+
+        // Keyed loops move the nodes of an item around, the nodes of the last
+        // item are the ones before this marker
+        if (!fastn_utils.isNull(key)) {
+            this.#end = fastn_dom.createKernel(
+                new ParentNodeWithSibiling(
+                    parent,
+                    this.#nodes.length > 0
+                        ? this.#nodes[this.#nodes.length - 1]
+                        : this.#wrapper,
+                ),
+                fastn_dom.ElementKind.Comment,
+            );
+        }
     }
     createNode(index, resizeBodyHeight = true) {
         if (resizeBodyHeight) {
@@ -2964,19 +3740,102 @@ class ForLoop {
         let v = this.#list.get(index);
         let node = this.#node_constructor(parentWithSibiling, v.item, v.index);
         this.#nodes.splice(index, 0, node);
+        this.#items.splice(index, 0, v);
         if (resizeBodyHeight) {
             fastn_utils.setFullHeight();
         }
         return node;
     }
     createAllNode() {
+        if (!fastn_utils.isNull(this.#key)) {
+            this.updateAllNode();
+            return;
+        }
         fastn_utils.resetFullHeight();
         this.deleteAllNode(false);
         for (let idx in this.#list.getList()) {
-            this.createNode(idx, false);
+            this.createNode(parseInt(idx), false);
         }
         fastn_utils.setFullHeight();
     }
+    // Keyed loops: `MutableList.set` keeps the items whose key is still in
+    // the list, the nodes of those items are kept, and moved only if they are
+    // not part of the longest run that is already in order. Nodes are created
+    // for new items and removed for the rest.
+    updateAllNode() {
+        fastn_utils.resetFullHeight();
+        let items = this.#list.getList();
+        let current = new Map(this.#items.map((item, idx) => [item, idx]));
+        let oldIndex = items.map((item) =>
+            current.has(item) ? current.get(item) : -1,
+        );
+        let ranges = this.domRanges();
+
+        let kept = new Set(oldIndex);
+        this.#nodes.forEach((node, idx) => {
+            if (!kept.has(idx)) {
+                fastn_utils.flattenArray(node).forEach((n) => n.destroy());
+                ranges[idx].forEach((n) => n.remove());
+            }
+        });
+
+        let stable = fastn_utils.private.longestIncreasingSubsequence(oldIndex);
+        let nodes = [];
+        let anchor = this.#wrapper.getNode();
+        items.forEach((item, idx) => {
+            let range;
+            if (oldIndex[idx] === -1) {
+                let next = anchor.nextSibling;
+                let sibiling = { getNode: () => anchor };
+                nodes.push(
+                    this.#node_constructor(
+                        new ParentNodeWithSibiling(this.#parent, sibiling),
+                        item.item,
+                        item.index,
+                    ),
+                );
+                range = [];
+                let n = anchor.nextSibling;
+                while (n !== next) {
+                    range.push(n);
+                    n = n.nextSibling;
+                }
+            } else {
+                nodes.push(this.#nodes[oldIndex[idx]]);
+                range = ranges[oldIndex[idx]];
+                if (!stable.has(idx)) {
+                    let next = anchor.nextSibling;
+                    let parent = anchor.parentNode;
+                    range.forEach((n) => parent.insertBefore(n, next));
+                }
+            }
+            if (range.length > 0) {
+                anchor = range[range.length - 1];
+            }
+        });
+        this.#nodes = nodes;
+        this.#items = [...items];
+        fastn_utils.setFullHeight();
+    }
+    // The dom nodes of each item: from the first node of the item up to the
+    // first node of the next one. The first node of an item stays the same
+    // even if the item is a conditional dom whose content changes.
+    domRanges() {
+        let starts = this.#nodes.map((node) => {
+            let first = fastn_utils.flattenArray(node)[0];
+            return first.getExtraData().tableCell ?? first.getNode();
+        });
+        starts.push(this.#end.getNode());
+        return this.#nodes.map((_, idx) => {
+            let range = [];
+            let n = starts[idx];
+            while (!fastn_utils.isNull(n) && n !== starts[idx + 1]) {
+                range.push(n);
+                n = n.nextSibling;
+            }
+            return range;
+        });
+    }
     deleteAllNode(resizeBodyHeight = true) {
         if (resizeBodyHeight) {
             fastn_utils.resetFullHeight();
@@ -2984,6 +3843,7 @@ class ForLoop {
         while (this.#nodes.length > 0) {
             this.#nodes.pop().destroy();
         }
+        this.#items = [];
         if (resizeBodyHeight) {
             fastn_utils.setFullHeight();
         }
@@ -2991,9 +3851,13 @@ class ForLoop {
     getWrapper() {
         return this.#wrapper;
     }
+    getKey() {
+        return this.#key;
+    }
     deleteNode(index) {
         fastn_utils.resetFullHeight();
         let node = this.#nodes.splice(index, 1)[0];
+        this.#items.splice(index, 1);
         node.destroy();
         fastn_utils.setFullHeight();
     }
@@ -3002,6 +3866,6 @@ class ForLoop {
     }
 }
 
-fastn_dom.forLoop = function (parent, node_constructor, list) {
-    return new ForLoop(parent, node_constructor, list);
+fastn_dom.forLoop = function (parent, node_constructor, list, key) {
+    return new ForLoop(parent, node_constructor, list, key);
 };
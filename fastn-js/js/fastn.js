@@ -78,10 +78,23 @@ const fastn = (function (fastn) {
         }
 
         get(key) {
+            if (
+                !fastn_utils.isNull(key) &&
+                this.#value instanceof OrTypeValue
+            ) {
+                // `$status.error` is the value of the `error` variant, and has
+                // to follow `$status` as it switches between variants
+                return fastn.formula([this], () =>
+                    this.#value instanceof OrTypeValue
+                        ? this.#value.get(key)
+                        : null,
+                );
+            }
             if (
                 !fastn_utils.isNull(key) &&
                 (this.#value instanceof RecordInstance ||
                     this.#value instanceof MutableList ||
+                    this.#value instanceof MutableMap ||
                     this.#value instanceof Mutable)
             ) {
                 return this.#value.get(key);
@@ -89,13 +102,13 @@ const fastn = (function (fastn) {
             return this.#value;
         }
 
-        forLoop(root, dom_constructor) {
+        forLoop(root, dom_constructor, key) {
             if ((!this.#value) instanceof MutableList) {
                 throw new Error(
                     "`forLoop` can only run for MutableList type object",
                 );
             }
-            this.#value.forLoop(root, dom_constructor);
+            this.#value.forLoop(root, dom_constructor, key);
         }
 
         setWithoutUpdate(value) {
@@ -111,7 +124,10 @@ const fastn = (function (fastn) {
                 // The `this.#value.replace(value);` will replace the value of
                 // `orange-green` with `{light: red, dark: red}`
                 this.#value = value;
-            } else if (this.#value instanceof MutableList) {
+            } else if (
+                this.#value instanceof MutableList ||
+                this.#value instanceof MutableMap
+            ) {
                 if (value instanceof fastn.mutableClass) {
                     value = value.get();
                 }
@@ -206,6 +222,7 @@ const fastn = (function (fastn) {
                 !!key &&
                 (this.#cached_value instanceof RecordInstance ||
                     this.#cached_value instanceof MutableList ||
+                    this.#cached_value instanceof MutableMap ||
                     this.#cached_value instanceof Mutable)
             ) {
                 return this.#cached_value.get(key);
@@ -249,8 +266,8 @@ const fastn = (function (fastn) {
             );
         }
 
-        forLoop(root, dom_constructor) {
-            let l = fastn_dom.forLoop(root, dom_constructor, this);
+        forLoop(root, dom_constructor, key) {
+            let l = fastn_dom.forLoop(root, dom_constructor, this, key);
             this.#watchers.push(l);
             return l;
         }
@@ -289,12 +306,19 @@ const fastn = (function (fastn) {
                 }
 
                 let list = value.#list;
-                this.#list = [];
-                for (let i in list) {
-                    this.#list.push(list[i]);
+                this.deleteEmptyWatchers();
+                let keys = this.#watchers
+                    .map((watcher) => watcher.getKey())
+                    .filter((key) => !fastn_utils.isNull(key));
+                if (keys.length === 0) {
+                    this.#list = [];
+                    for (let i in list) {
+                        this.#list.push(list[i]);
+                    }
+                } else {
+                    this.#list = this.reuseItems(list, keys);
                 }
 
-                this.deleteEmptyWatchers();
                 for (let i in this.#watchers) {
                     this.#watchers[i].createAllNode();
                 }
@@ -306,6 +330,52 @@ const fastn = (function (fastn) {
             this.#closures.forEach((closure) => closure.update());
         }
 
+        // An item of the new list that has the same keys, in every keyed loop
+        // of the list, as an item of the current list takes its place, with
+        // the new value, so the keyed loops can keep the nodes they created
+        // for it. Items with the same keys are matched in their order.
+        reuseItems(list, keys) {
+            let keyOf = (item) =>
+                JSON.stringify(
+                    keys.map((key) =>
+                        fastn_utils.getFlattenStaticValue(
+                            key(item.item, item.index),
+                        ),
+                    ),
+                );
+            let current = new Map();
+            for (let item of this.#list) {
+                let k = keyOf(item);
+                if (!current.has(k)) {
+                    current.set(k, []);
+                }
+                current.get(k).push(item);
+            }
+            return list.map((item, index) => {
+                let old = current.get(keyOf(item))?.shift();
+                if (old === undefined) {
+                    item.index.set(index);
+                    return item;
+                }
+                if (
+                    !fastn_utils.deepEqual(
+                        fastn_utils.mutableToStaticValue(old.item),
+                        fastn_utils.mutableToStaticValue(item.item),
+                    )
+                ) {
+                    let value = item.item;
+                    if (value instanceof Mutable) {
+                        value = value.get();
+                    }
+                    old.item.set(value);
+                }
+                if (old.index.get() !== index) {
+                    old.index.set(index);
+                }
+                return old;
+            });
+        }
+
         // The watcher sometimes doesn't get deleted when the list is wrapped
         // inside some ancestor DOM with if condition,
         // so when if condition is unsatisfied the DOM gets deleted without removing
@@ -413,6 +483,101 @@ const fastn = (function (fastn) {
         }
     }
 
+    class MutableMap {
+        #map;
+        #closures;
+
+        constructor(obj) {
+            this.#map = {};
+            this.#closures = [];
+            for (let key in obj) {
+                this.#map[key] = fastn.wrapMutable(obj[key]);
+            }
+        }
+
+        addClosure(closure) {
+            this.#closures.push(closure);
+        }
+
+        unlinkNode(node) {
+            this.#closures = this.#closures.filter(
+                (closure) => closure.getNode() !== node,
+            );
+        }
+
+        getMap() {
+            return this.#map;
+        }
+
+        get(key) {
+            if (fastn_utils.isNull(key)) {
+                return this.getMap();
+            }
+            return this.#map[fastn_utils.getFlattenStaticValue(key)];
+        }
+
+        contains(key) {
+            return fastn_utils.getFlattenStaticValue(key) in this.#map;
+        }
+
+        keys() {
+            return Object.keys(this.#map);
+        }
+
+        getLength() {
+            return this.keys().length;
+        }
+
+        set(key, value) {
+            if (value === undefined) {
+                value = key;
+                if (value instanceof fastn.mutableClass) {
+                    value = value.get();
+                }
+                if (!(value instanceof MutableMap)) {
+                    value = new MutableMap(value);
+                }
+                this.#map = Object.assign({}, value.#map);
+            } else {
+                this.insert(key, value);
+                return;
+            }
+            this.#closures.forEach((closure) => closure.update());
+        }
+
+        insert(key, value) {
+            key = fastn_utils.getFlattenStaticValue(key);
+            if (this.#map[key] === undefined) {
+                this.#map[key] = fastn.wrapMutable(value);
+            } else {
+                this.#map[key].set(value);
+            }
+            this.#closures.forEach((closure) => closure.update());
+        }
+
+        remove(key) {
+            key = fastn_utils.getFlattenStaticValue(key);
+            if (!(key in this.#map)) {
+                return;
+            }
+            delete this.#map[key];
+            this.#closures.forEach((closure) => closure.update());
+        }
+
+        clearAll() {
+            this.#map = {};
+            this.#closures.forEach((closure) => closure.update());
+        }
+
+        getClone() {
+            let cloned = {};
+            for (let key in this.#map) {
+                cloned[key] = fastn_utils.clone(this.#map[key]);
+            }
+            return new MutableMap(cloned);
+        }
+    }
+
     fastn.mutable = function (val) {
         return new Mutable(val);
     };
@@ -451,7 +616,8 @@ const fastn = (function (fastn) {
         if (
             !(obj instanceof Mutable) &&
             !(obj instanceof RecordInstance) &&
-            !(obj instanceof MutableList)
+            !(obj instanceof MutableList) &&
+            !(obj instanceof MutableMap)
         ) {
             obj = new Mutable(obj);
         }
@@ -462,6 +628,10 @@ const fastn = (function (fastn) {
         return new MutableList(list);
     };
 
+    fastn.mutableMap = function (obj) {
+        return new MutableMap(obj);
+    };
+
     class RecordInstance {
         #fields;
         #closures;
@@ -577,6 +747,34 @@ const fastn = (function (fastn) {
         }
     }
 
+    class OrTypeValue {
+        #variant;
+        #value;
+
+        constructor(variant, value) {
+            this.#variant = variant;
+            this.#value = value;
+        }
+
+        getVariant() {
+            return this.#variant;
+        }
+
+        getValue() {
+            return this.#value;
+        }
+
+        get(variant) {
+            if (
+                this.#variant === variant ||
+                this.#variant.replace(/-/g, "_") === variant
+            ) {
+                return this.#value;
+            }
+            return null;
+        }
+    }
+
     class Module {
         #name;
         #global;
@@ -599,13 +797,19 @@ const fastn = (function (fastn) {
         return new RecordInstance(obj);
     };
 
+    fastn.orType = function (variant) {
+        return (value) => new OrTypeValue(variant, value);
+    };
+
     fastn.color = function (r, g, b) {
         return `rgb(${r},${g},${b})`;
     };
 
     fastn.mutableClass = Mutable;
     fastn.mutableListClass = MutableList;
+    fastn.mutableMapClass = MutableMap;
     fastn.recordInstanceClass = RecordInstance;
+    fastn.orTypeValueClass = OrTypeValue;
     fastn.module = function (name, global) {
         return new Module(name, global);
     };
@@ -18,8 +18,21 @@ const ftd = (function () {
         return fastn_utils.isNull(value) || value.length === 0;
     };
 
+    // Used by `match:` to check which variant of an or-type a value holds
+    exports.is_variant = (value, variant) => {
+        value = fastn_utils.getStaticValue(value);
+        return (
+            value instanceof fastn.orTypeValueClass &&
+            value.getVariant() === fastn_utils.getStaticValue(variant)
+        );
+    };
+
     exports.len = (data) => {
-        if (!!data && data instanceof fastn.mutableListClass) {
+        if (
+            !!data &&
+            (data instanceof fastn.mutableListClass ||
+                data instanceof fastn.mutableMapClass)
+        ) {
             if (data.getLength) return data.getLength();
             return -1;
         }
@@ -230,6 +243,305 @@ const ftd = (function () {
     exports.set_list = function (list, value) {
         list.set(value);
     };
+    exports.insert = function (map, key, value) {
+        map.insert(key, value);
+    };
+    exports.remove = function (map, key) {
+        map.remove(key);
+    };
+    exports.keys = function (map) {
+        return fastn.mutableList(fastn_utils.getter(map).keys());
+    };
+
+    // String and list helpers. These mirror the evalexpr builtins in
+    // `fastn-builtins` and are tested against the same `conformance.json`.
+    const staticValue = (value) => fastn_utils.getStaticValue(value);
+
+    const listItems = (list) => {
+        const value = staticValue(list);
+        if (!Array.isArray(value)) return [];
+        return value.map((item) => fastn_utils.getListItem(item));
+    };
+
+    // Record fields are stored with `-` replaced by `_`.
+    const fieldOf = (item, field) => {
+        item = fastn_utils.getter(item);
+        field = staticValue(field);
+        let value = null;
+        if (
+            item instanceof fastn.recordInstanceClass ||
+            item instanceof fastn.mutableMapClass
+        ) {
+            value = item.get(field);
+            if (value === undefined) {
+                value = item.get(field.split("-").join("_"));
+            }
+        } else if (item !== null && typeof item === "object") {
+            value = item[field];
+        }
+        if (value === undefined) value = null;
+        return fastn_utils.mutableToStaticValue(value);
+    };
+
+    const isEqual = (a, b) =>
+        JSON.stringify(fastn_utils.mutableToStaticValue(a)) ===
+        JSON.stringify(fastn_utils.mutableToStaticValue(b));
+
+    // Strings compare by code point, like Rust, rather than by UTF-16 unit.
+    const compareValues = (a, b) => {
+        a = fastn_utils.mutableToStaticValue(a);
+        b = fastn_utils.mutableToStaticValue(b);
+        if (typeof a === "string" && typeof b === "string") {
+            const [x, y] = [Array.from(a), Array.from(b)];
+            for (let i = 0; i < Math.min(x.length, y.length); i++) {
+                const diff = x[i].codePointAt(0) - y[i].codePointAt(0);
+                if (diff !== 0) return diff;
+            }
+            return x.length - y.length;
+        }
+        if (
+            (typeof a === "number" && typeof b === "number") ||
+            (typeof a === "boolean" && typeof b === "boolean")
+        ) {
+            return a - b;
+        }
+        return 0;
+    };
+
+    const sliceRange = (length, start, end) => {
+        const index = (i) =>
+            i < 0 ? Math.max(length + i, 0) : Math.min(i, length);
+        start = index(start);
+        end = end === undefined || end === null ? length : index(end);
+        return [start, Math.max(end, start)];
+    };
+
+    const pad = (s, width, fill, atStart) => {
+        s = staticValue(s);
+        width = staticValue(width);
+        fill = fill === undefined ? " " : staticValue(fill);
+        const length = Array.from(s).length;
+        if (length >= width || fill === "") return s;
+        const fillChars = Array.from(fill);
+        let padding = "";
+        for (let i = 0; i < width - length; i++) {
+            padding += fillChars[i % fillChars.length];
+        }
+        return atStart ? padding + s : s + padding;
+    };
+
+    exports.split = function (s, separator) {
+        s = staticValue(s);
+        separator = staticValue(separator);
+        return fastn.mutableList(
+            separator === "" ? Array.from(s) : s.split(separator),
+        );
+    };
+
+    exports.join = function (list, separator) {
+        return listItems(list)
+            .map((item) => {
+                item = fastn_utils.mutableToStaticValue(item);
+                return fastn_utils.isNull(item) ? "" : String(item);
+            })
+            .join(staticValue(separator));
+    };
+
+    exports.replace = function (s, from, to) {
+        s = staticValue(s);
+        from = staticValue(from);
+        if (from === "") return s;
+        return s.split(from).join(staticValue(to));
+    };
+
+    exports.contains = function (value, item) {
+        const data = staticValue(value);
+        if (typeof data === "string") return data.includes(staticValue(item));
+        return listItems(value).some((v) => isEqual(v, item));
+    };
+
+    exports.starts_with = function (s, prefix) {
+        return staticValue(s).startsWith(staticValue(prefix));
+    };
+
+    exports.ends_with = function (s, suffix) {
+        return staticValue(s).endsWith(staticValue(suffix));
+    };
+
+    exports.slice = function (value, start, end) {
+        const data = staticValue(value);
+        if (typeof data === "string") {
+            const chars = Array.from(data);
+            const [from, to] = sliceRange(
+                chars.length,
+                staticValue(start),
+                staticValue(end),
+            );
+            return chars.slice(from, to).join("");
+        }
+        const items = listItems(value);
+        const [from, to] = sliceRange(
+            items.length,
+            staticValue(start),
+            staticValue(end),
+        );
+        return fastn.mutableList(
+            items.slice(from, to).map((item) => fastn_utils.clone(item)),
+        );
+    };
+
+    exports.pad_start = function (s, width, fill) {
+        return pad(s, width, fill, true);
+    };
+
+    exports.pad_end = function (s, width, fill) {
+        return pad(s, width, fill, false);
+    };
+
+    exports.format_number = function (number, decimals = 0) {
+        number = staticValue(number);
+        decimals = Math.min(Math.max(staticValue(decimals), 0), 20);
+        const factor = Math.pow(10, decimals);
+        const rounded = Math.round(Math.abs(number) * factor) / factor;
+        const [integer, fraction] = rounded.toFixed(decimals).split(".");
+        let grouped = integer.replace(/\B(?=(\d{3})+(?!\d))/g, ",");
+        if (fraction !== undefined) grouped += "." + fraction;
+        return number < 0 && rounded !== 0 ? "-" + grouped : grouped;
+    };
+
+    exports.sort = function (list) {
+        return fastn.mutableList(
+            listItems(list)
+                .sort(compareValues)
+                .map((item) => fastn_utils.clone(item)),
+        );
+    };
+
+    exports.sort_by = function (list, field) {
+        return fastn.mutableList(
+            listItems(list)
+                .map((item) => [fieldOf(item, field), item])
+                .sort(([a], [b]) => compareValues(a, b))
+                .map(([, item]) => fastn_utils.clone(item)),
+        );
+    };
+
+    exports.filter = function (list, field, value) {
+        return fastn.mutableList(
+            listItems(list)
+                .filter((item) => isEqual(fieldOf(item, field), value))
+                .map((item) => fastn_utils.clone(item)),
+        );
+    };
+
+    exports.map = function (list, field) {
+        return fastn.mutableList(
+            listItems(list).map((item) => fieldOf(item, field)),
+        );
+    };
+
+    exports.sum = function (list) {
+        return listItems(list).reduce(
+            (sum, item) => sum + fastn_utils.mutableToStaticValue(item),
+            0,
+        );
+    };
+
+    exports.unique = function (list) {
+        const items = [];
+        for (const item of listItems(list)) {
+            if (!items.some((v) => isEqual(v, item))) items.push(item);
+        }
+        return fastn.mutableList(items.map((item) => fastn_utils.clone(item)));
+    };
+
+    const MILLIS_PER_DAY = 86400000;
+
+    // `ftd.language` is set by fastn to the language of the page, see
+    // `fastn_core::host_builtins::language`.
+    const pageLanguage = () =>
+        fastn_utils.getStaticValue(exports.language) ?? "en";
+
+    // Supports the same strftime subset that the docs list for
+    // `ftd.format_datetime`. Values are always formatted in UTC.
+    exports.format_datetime = function (datetime, pattern, lang) {
+        datetime = fastn_utils.getStaticValue(datetime);
+        pattern = fastn_utils.getStaticValue(pattern);
+        lang = fastn_utils.getStaticValue(lang) ?? pageLanguage();
+        const date = new Date(datetime);
+        const part = (options) =>
+            new Intl.DateTimeFormat(lang, { timeZone: "UTC", ...options })
+                .format(date);
+        const pad = (n, width = 2) => String(n).padStart(width, "0");
+        const hours = date.getUTCHours();
+        const directives = {
+            Y: () => String(date.getUTCFullYear()),
+            y: () => pad(date.getUTCFullYear() % 100),
+            m: () => pad(date.getUTCMonth() + 1),
+            d: () => pad(date.getUTCDate()),
+            e: () => String(date.getUTCDate()).padStart(2, " "),
+            H: () => pad(hours),
+            I: () => pad(hours % 12 === 0 ? 12 : hours % 12),
+            M: () => pad(date.getUTCMinutes()),
+            S: () => pad(date.getUTCSeconds()),
+            p: () => (hours < 12 ? "AM" : "PM"),
+            B: () => part({ month: "long" }),
+            b: () => part({ month: "short" }),
+            A: () => part({ weekday: "long" }),
+            a: () => part({ weekday: "short" }),
+            "%": () => "%",
+        };
+        return pattern.replace(/%(.)/g, (directive, c) =>
+            directives[c] ? directives[c]() : directive,
+        );
+    };
+
+    exports.relative_time = function (datetime, lang) {
+        datetime = fastn_utils.getStaticValue(datetime);
+        lang = fastn_utils.getStaticValue(lang) ?? pageLanguage();
+        const diff = Math.trunc((datetime - Date.now()) / 1000);
+        const seconds = Math.abs(diff);
+        let value, unit;
+        if (seconds < 60) {
+            [value, unit] = [seconds, "second"];
+        } else if (seconds < 60 * 60) {
+            [value, unit] = [seconds / 60, "minute"];
+        } else if (seconds < 60 * 60 * 24) {
+            [value, unit] = [seconds / (60 * 60), "hour"];
+        } else if (seconds < 60 * 60 * 24 * 30) {
+            [value, unit] = [seconds / (60 * 60 * 24), "day"];
+        } else if (seconds < 60 * 60 * 24 * 365) {
+            [value, unit] = [seconds / (60 * 60 * 24 * 30), "month"];
+        } else {
+            [value, unit] = [seconds / (60 * 60 * 24 * 365), "year"];
+        }
+        value = Math.trunc(value);
+        return new Intl.RelativeTimeFormat(lang, { numeric: "always" }).format(
+            diff < 0 ? -value : value,
+            unit,
+        );
+    };
+
+    exports.add_days = function (datetime, days) {
+        return (
+            fastn_utils.getStaticValue(datetime) +
+            fastn_utils.getStaticValue(days) * MILLIS_PER_DAY
+        );
+    };
+
+    exports.add_seconds = function (datetime, seconds) {
+        return (
+            fastn_utils.getStaticValue(datetime) +
+            fastn_utils.getStaticValue(seconds) * 1000
+        );
+    };
+
+    exports.diff_days = function (a, b) {
+        return Math.trunc(
+            (fastn_utils.getStaticValue(a) - fastn_utils.getStaticValue(b)) /
+                MILLIS_PER_DAY,
+        );
+    };
 
     exports.http = function (url, method, headers, ...body) {
         if (url instanceof fastn.mutableClass) url = url.get();
@@ -536,20 +848,45 @@ const ftd = (function () {
             data[name] = fastn_utils.getFlattenStaticValue(obj.get("value"));
         }
 
+        exports.submit_form_data(url, data, arg_map);
+    };
+
+    /**
+     * Sends `data` to `url` and handles the response of the server:
+     * `{"redirect": url}`, `{"reload": true}` or the field errors,
+     * `{"errors": {"<field name>": "message" or ["message", ...]}}`. The
+     * errors are set on the `error` of the records in `arg_map` (keyed by
+     * field name) and, if `form` is passed, the inputs of that name are marked
+     * invalid.
+     */
+    exports.submit_form_data = function (url, data, arg_map, options) {
+        options = options || {};
+        let method = (options.method || "POST").toUpperCase();
         let init = {
-            method: "POST",
+            method,
             redirect: "error",
             // TODO: set credentials?
             credentials: "same-origin",
-            headers: { "Content-Type": "application/json" },
-            body: JSON.stringify(data),
         };
+        if (method === "GET") {
+            let query = new URLSearchParams(formDataEntries(data)).toString();
+            url = `${url}${url.includes("?") ? "&" : "?"}${query}`;
+        } else if (options.encoding === fastn_dom.FormEncoding.UrlEncoded) {
+            init.headers = {
+                "Content-Type": "application/x-www-form-urlencoded",
+            };
+            init.body = new URLSearchParams(formDataEntries(data)).toString();
+        } else {
+            init.headers = { "Content-Type": "application/json" };
+            init.body = JSON.stringify(data);
+        }
 
         console.log(url, data);
 
         fetch(url, init)
             .then((res) => {
-                if (!res.ok) {
+                // field errors are usually sent with a 400 or 422 status
+                if (!res.ok && res.status !== 400 && res.status !== 422) {
                     return new Error("[http_post]: Request failed: " + res);
                 }
                 return res.json();
@@ -574,6 +911,9 @@ const ftd = (function () {
                         }
                         // @ts-ignore
                         obj.get("error").set(error);
+                        if (!!options.form) {
+                            markInvalid(options.form, key, error);
+                        }
                     }
                 } else if (!!response.data) {
                     console.error("data not yet implemented");
@@ -583,6 +923,143 @@ const ftd = (function () {
             })
             .catch(console.error);
     };
+
+    function formDataEntries(data) {
+        let entries = [];
+        for (let [key, value] of Object.entries(data)) {
+            let values = Array.isArray(value) ? value : [value];
+            for (let v of values) {
+                if (!fastn_utils.isNull(v)) entries.push([key, `${v}`]);
+            }
+        }
+        return entries;
+    }
+
+    function markInvalid(form, name, error) {
+        for (let input of form.querySelectorAll(`[name="${name}"]`)) {
+            if (fastn_utils.isNull(error)) {
+                input.removeAttribute("aria-invalid");
+            } else {
+                input.setAttribute("aria-invalid", "true");
+            }
+        }
+    }
+
+    // Same rules as `fastn_builtins::form::FieldRules::validate`, both are
+    // tested against `fastn-builtins/form-conformance.json`.
+    exports.validate_field = function (field, value) {
+        field = fastn_utils.getStaticValue(field);
+        let get = (key) => fastn_utils.getStaticValue(field.get(key));
+        let error = (message) => {
+            let custom = get("message");
+            return fastn_utils.isNull(custom) ? message : custom;
+        };
+        value = fastn_utils.getStaticValue(value);
+        value = fastn_utils.isNull(value) ? "" : `${value}`;
+        if (value.trim() === "") {
+            return get("required") ? error("This field is required") : null;
+        }
+
+        let length = [...value].length;
+        let min_length = get("min_length");
+        if (!fastn_utils.isNull(min_length) && length < min_length) {
+            return error(`Must be at least ${min_length} characters`);
+        }
+        let max_length = get("max_length");
+        if (!fastn_utils.isNull(max_length) && length > max_length) {
+            return error(`Must be at most ${max_length} characters`);
+        }
+
+        let min = get("min");
+        let max = get("max");
+        if (!fastn_utils.isNull(min) || !fastn_utils.isNull(max)) {
+            let trimmed = value.trim();
+            if (!/^[+-]?(\d+\.?\d*|\.\d+)$/.test(trimmed)) {
+                return error("Must be a number");
+            }
+            let number = Number(trimmed);
+            if (!fastn_utils.isNull(min) && number < min) {
+                return error(`Must be at least ${min}`);
+            }
+            if (!fastn_utils.isNull(max) && number > max) {
+                return error(`Must be at most ${max}`);
+            }
+        }
+
+        let pattern = get("pattern");
+        if (!fastn_utils.isNull(pattern)) {
+            // the pattern has to match the whole value and an invalid
+            // pattern never matches
+            let matches = false;
+            try {
+                matches = new RegExp(`^(?:${pattern})$`, "u").test(value);
+            } catch (e) {
+                matches = false;
+            }
+            if (!matches) {
+                return error("Invalid format");
+            }
+        }
+        return null;
+    };
+
+    /**
+     * Submit handler of `ftd.form`: reads the value of every field from the
+     * form inputs of the same `name` (or the field's own `value` when there
+     * is no such input), validates them and submits the form if all of them
+     * are valid.
+     */
+    exports.submit_ftd_form = function (form, fields, options) {
+        fields = fastn_utils.getStaticValue(fields);
+        let formData = new FormData(form);
+        let data = {};
+        let arg_map = {};
+        let valid = true;
+        for (let obj of fields || []) {
+            let field = fastn_utils.getStaticValue(obj.item);
+            let name = fastn_utils.getStaticValue(field.get("name"));
+            let value = fastn_utils.getStaticValue(field.get("value"));
+            if (formData.has(name)) {
+                let values = formData
+                    .getAll(name)
+                    .map((v) => (v instanceof File ? v.name : v));
+                value = values.length > 1 ? values : values[0];
+                if (!Array.isArray(value)) field.get("value").set(value);
+            }
+            arg_map[name] = field;
+            data[name] = value;
+
+            let error = Array.isArray(value)
+                ? value
+                      .map((v) => exports.validate_field(field, v))
+                      .find((e) => !fastn_utils.isNull(e))
+                : exports.validate_field(field, value);
+            let check = fastn_utils.getStaticValue(field.get("check"));
+            if (fastn_utils.isNull(error) && !fastn_utils.isNull(check)) {
+                // `check` names a js function that returns the error, if any
+                if (typeof window[check] !== "function") {
+                    console.warn(`[ftd.form]: ${check} is not a function`);
+                } else {
+                    error = window[check](value, data);
+                    if (error === "" || error === undefined) error = null;
+                }
+            }
+            field.get("error").set(error);
+            markInvalid(form, name, error);
+            if (!fastn_utils.isNull(error) && valid) {
+                valid = false;
+                let input = form.querySelector(`[name="${name}"]`);
+                if (!fastn_utils.isNull(input)) input.focus();
+            }
+        }
+        if (!valid) return;
+        let url = form.getAttribute("action") || window.location.href;
+        exports.submit_form_data(url, data, arg_map, {
+            form,
+            method: form.getAttribute("method"),
+            encoding: options.encoding,
+        });
+    };
     return exports;
 })();
 
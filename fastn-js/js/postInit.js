@@ -38,6 +38,7 @@ ftd.post_init = function () {
     const COOKIE_DARK_MODE = "dark";
     const COOKIE_LIGHT_MODE = "light";
     const DARK_MODE_CLASS = "dark";
+    const REDUCED_MOTION_CLASS = "reduced-motion";
     let last_device = ftd.device.get();
 
     window.onresize = function () {
@@ -207,6 +208,230 @@ ftd.post_init = function () {
             window.matchMedia("(prefers-color-scheme: dark)").matches
         );
     }
+    // `body.reduced-motion` turns off `transition` and `animation`, see
+    // `attachReducedMotionCss` in dom.js
+    function initialise_reduced_motion() {
+        if (!window.matchMedia) {
+            return;
+        }
+        const query = window.matchMedia("(prefers-reduced-motion: reduce)");
+        const update = () =>
+            document.body.classList.toggle(REDUCED_MOTION_CLASS, query.matches);
+        update();
+        query.addEventListener("change", update);
+    }
+    /*
+        Client-side navigation, turned on by `spa: true` in `fastn.package`.
+
+        A click on an internal link fetches the js of the target page from
+        `<page>/index.spa.js`, which calls `ftd.spa_page` with the scripts
+        the page needs and a function declaring its variables and returning
+        its `main`. The new page then replaces the body, and the variables
+        listed in `__fastn_spa__` (`persist` in `fastn.package`) get the
+        value they had on the previous page.
+
+        If the js can not be fetched we fall back to a full page load.
+     */
+    function initialise_spa() {
+        if (typeof __fastn_spa__ === "undefined" || !window.history) {
+            return;
+        }
+        const SPA_PAYLOAD_FILE = "index.spa.js";
+        let current_page = page_of(window.location);
+        let next_page = null;
+        let navigation = 0;
+
+        window.history.scrollRestoration = "manual";
+        ftd.spa_page = function (scripts, page) {
+            next_page = [scripts, page];
+        };
+
+        document.addEventListener("click", function (event) {
+            let link = event.target.closest && event.target.closest("a");
+            if (
+                !link ||
+                event.defaultPrevented ||
+                event.button !== 0 ||
+                event.metaKey ||
+                event.ctrlKey ||
+                event.shiftKey ||
+                event.altKey ||
+                !is_spa_link(link)
+            ) {
+                return;
+            }
+            event.preventDefault();
+            navigate(new URL(link.href), true, null);
+        });
+        window.addEventListener("popstate", function (event) {
+            // going back to an anchor on the same page only scrolls
+            if (page_of(window.location) === current_page) {
+                return;
+            }
+            navigate(new URL(window.location.href), false, event.state);
+        });
+        window.addEventListener(
+            "scroll",
+            fastn_utils.throttle(save_scroll, 200),
+            { passive: true },
+        );
+
+        function page_of(url) {
+            return `${url.pathname}${url.search}`;
+        }
+        function save_scroll() {
+            let state = { ...window.history.state, scroll: window.scrollY };
+            window.history.replaceState(state, "");
+        }
+        function is_spa_link(link) {
+            if (
+                link.origin !== window.location.origin ||
+                (link.target && link.target !== "_self") ||
+                link.hasAttribute("download")
+            ) {
+                return false;
+            }
+            // `/-/` urls are served by fastn itself, and an extension in the
+            // last segment means a file, neither of them is a page
+            let last = link.pathname.split("/").pop();
+            if (link.pathname.startsWith("/-/") || last.includes(".")) {
+                return false;
+            }
+            return !(page_of(link) === current_page && link.hash);
+        }
+        function payload_url(url) {
+            let path = url.pathname.endsWith("/")
+                ? url.pathname
+                : `${url.pathname}/`;
+            return `${path}${SPA_PAYLOAD_FILE}${url.search}`;
+        }
+        async function navigate(url, push, state) {
+            let id = ++navigation;
+            let response = null;
+            try {
+                response = await fetch(payload_url(url));
+            } catch (e) {
+                console.log("[fastn-spa]: fetch failed", e);
+            }
+            let content_type =
+                (response && response.headers.get("content-type")) || "";
+            if (
+                !response ||
+                !response.ok ||
+                response.redirected ||
+                !content_type.includes("javascript")
+            ) {
+                if (push) window.location.assign(url.href);
+                else window.location.reload();
+                return;
+            }
+            let payload = await response.text();
+            if (id !== navigation) {
+                return;
+            }
+            if (push) {
+                save_scroll();
+                window.history.pushState({ scroll: 0 }, "", url.href);
+            }
+            current_page = page_of(url);
+
+            next_page = null;
+            let script = document.createElement("script");
+            script.text = payload;
+            document.head.appendChild(script).remove();
+            if (next_page === null) {
+                window.location.reload();
+                return;
+            }
+            let [scripts, page] = next_page;
+            await load_scripts(scripts);
+            if (id !== navigation) {
+                return;
+            }
+            render(page);
+            restore_scroll(url, state);
+        }
+        // loads the `<script>` and `<link>` tags of the page which are not
+        // already loaded, scripts run in the order they are listed
+        function load_scripts(html) {
+            let template = document.createElement("template");
+            template.innerHTML = html;
+            let loading = [];
+            for (const tag of template.content.children) {
+                let attribute = tag.tagName === "SCRIPT" ? "src" : "href";
+                let url = tag.getAttribute(attribute);
+                let loaded = Array.from(
+                    document.querySelectorAll(tag.tagName),
+                ).some((t) => t.getAttribute(attribute) === url);
+                if (!url || loaded) {
+                    continue;
+                }
+                let element = document.createElement(tag.tagName);
+                for (const { name, value } of tag.attributes) {
+                    element.setAttribute(name, value);
+                }
+                element.async = false;
+                loading.push(
+                    new Promise((resolve) => {
+                        element.onload = resolve;
+                        element.onerror = resolve;
+                    }),
+                );
+                document.head.appendChild(element);
+            }
+            return Promise.all(loading);
+        }
+        function render(page) {
+            let persisted = __fastn_spa__
+                .filter((name) => global[name] !== undefined)
+                .map((name) => [
+                    name,
+                    fastn_utils.mutableToStaticValue(global[name]),
+                ]);
+
+            fastn_dom.disposableNodes.forEach((node) => node.destroy());
+            fastn_dom.eventSources.forEach((source) => source.close());
+            fastn_dom.eventSources.clear();
+            ftd.clickOutsideEvents = [];
+            ftd.globalKeyEvents = [];
+            ftd.globalKeySeqEvents = [];
+            document
+                .querySelectorAll(`meta[${fastn_dom.metaTagAttribute}]`)
+                .forEach((meta) => meta.remove());
+            window.document.title = "";
+
+            // declares the variables of the new page in `global`
+            let main = page();
+            for (const [name, value] of persisted) {
+                let mutable = global[name];
+                if (mutable === undefined || !mutable.set) {
+                    continue;
+                }
+                let mutableValue = fastn_utils.staticToMutables(value);
+                if (mutableValue instanceof fastn.mutableClass) {
+                    mutableValue = mutableValue.get();
+                }
+                mutable.set(mutableValue);
+            }
+            fastnVirtual.replaceBody(main);
+            fastn_utils.resetFullHeight();
+            fastn_utils.setFullHeight();
+        }
+        function restore_scroll(url, state) {
+            if (state && state.scroll) {
+                window.scrollTo(0, state.scroll);
+                return;
+            }
+            let anchor =
+                url.hash &&
+                document.getElementById(decodeURIComponent(url.hash.slice(1)));
+            if (anchor) {
+                anchor.scrollIntoView();
+            } else {
+                window.scrollTo(0, 0);
+            }
+        }
+    }
     function initialise_dark_mode() {
         update_dark_mode();
         start_watching_dark_mode_system_preference();
@@ -246,8 +471,10 @@ ftd.post_init = function () {
     }
     initialise_device();
     initialise_dark_mode();
+    initialise_reduced_motion();
     initialise_click_outside_events();
     initialise_global_key_events();
+    initialise_spa();
     fastn_utils.resetFullHeight();
     fastn_utils.setFullHeight();
 };
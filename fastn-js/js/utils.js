@@ -33,6 +33,39 @@ let fastn_utils = {
             attributes["type"] = "checkbox";
         } else if (kind === fastn_dom.ElementKind.TextInput) {
             node = "input";
+        } else if (kind === fastn_dom.ElementKind.Form) {
+            node = "form";
+            css.push(fastn_dom.InternalClass.FT_COLUMN);
+            // the fallback without js posts the form to `action`
+            attributes["method"] = "post";
+        } else if (kind === fastn_dom.ElementKind.Select) {
+            node = "select";
+        } else if (kind === fastn_dom.ElementKind.Radio) {
+            node = "fieldset";
+            css.push(fastn_dom.InternalClass.FT_RADIO);
+            attributes["role"] = "radiogroup";
+        } else if (kind === fastn_dom.ElementKind.Textarea) {
+            node = "textarea";
+        } else if (kind === fastn_dom.ElementKind.FileInput) {
+            node = "input";
+            attributes["type"] = "file";
+        } else if (kind === fastn_dom.ElementKind.Grid) {
+            css.push(fastn_dom.InternalClass.FT_GRID);
+        } else if (kind === fastn_dom.ElementKind.Svg) {
+            css.push(fastn_dom.InternalClass.FT_SVG);
+        } else if (kind === fastn_dom.ElementKind.Icon) {
+            node = "span";
+            css.push(fastn_dom.InternalClass.FT_SVG);
+            css.push(fastn_dom.InternalClass.FT_ICON);
+            attributes["aria-hidden"] = "true";
+        } else if (kind === fastn_dom.ElementKind.Math) {
+            css.push(fastn_dom.InternalClass.FT_MATH);
+        } else if (kind === fastn_dom.ElementKind.Table) {
+            node = "table";
+            css.push(fastn_dom.InternalClass.FT_TABLE);
+        } else if (kind === fastn_dom.ElementKind.TableRow) {
+            node = "tr";
+            css.push(fastn_dom.InternalClass.FT_TABLE_ROW);
         } else if (kind === fastn_dom.ElementKind.Comment) {
             node = fastn_dom.commentNode;
         } else if (kind === fastn_dom.ElementKind.Wrapper) {
@@ -71,6 +104,8 @@ let fastn_utils = {
             return this.getStaticValue(obj.get());
         } else if (obj instanceof fastn.mutableListClass) {
             return obj.getList();
+        } else if (obj instanceof fastn.mutableMapClass) {
+            return obj.getMap();
         } /*
         Todo: Make this work
         else if (obj instanceof fastn.recordInstanceClass) {
@@ -130,6 +165,7 @@ let fastn_utils = {
         if (
             !(obj instanceof fastn.mutableClass) &&
             !(obj instanceof fastn.mutableListClass) &&
+            !(obj instanceof fastn.mutableMapClass) &&
             !(obj instanceof fastn.recordInstanceClass)
         ) {
             if (Array.isArray(obj)) {
@@ -160,14 +196,22 @@ let fastn_utils = {
         } else if (obj instanceof fastn.mutableListClass) {
             let list = obj.getList();
             return list.map((func) => this.mutableToStaticValue(func.item));
-        } else if (obj instanceof fastn.recordInstanceClass) {
-            let fields = obj.getAllFields();
+        } else if (
+            obj instanceof fastn.recordInstanceClass ||
+            obj instanceof fastn.mutableMapClass
+        ) {
+            let fields =
+                obj instanceof fastn.mutableMapClass
+                    ? obj.getMap()
+                    : obj.getAllFields();
             return Object.fromEntries(
                 Object.entries(fields).map(([k, v]) => [
                     k,
                     this.mutableToStaticValue(v),
                 ]),
             );
+        } else if (obj instanceof fastn.orTypeValueClass) {
+            return this.mutableToStaticValue(obj.getValue());
         } else {
             return obj;
         }
@@ -208,7 +252,8 @@ let fastn_utils = {
     getterByKey(value, index) {
         if (
             value instanceof fastn.mutableClass ||
-            value instanceof fastn.recordInstanceClass
+            value instanceof fastn.recordInstanceClass ||
+            value instanceof fastn.mutableMapClass
         ) {
             return value.get(index);
         } else if (value instanceof fastn.mutableListClass) {
@@ -261,7 +306,8 @@ let fastn_utils = {
         }
         if (
             value instanceof fastn.mutableClass ||
-            value instanceof fastn.mutableListClass
+            value instanceof fastn.mutableListClass ||
+            value instanceof fastn.mutableMapClass
         ) {
             return value.getClone();
         }
@@ -279,6 +325,120 @@ let fastn_utils = {
         }
         return value;
     },
+    // `keys` of `on-keydown[ctrl-s]`, the modifiers not in the keys must not
+    // be pressed
+    isKeyPressed(event, keys) {
+        const modifiers = {
+            Control: event.ctrlKey,
+            Alt: event.altKey,
+            Shift: event.shiftKey,
+            Meta: event.metaKey,
+        };
+        const eventKey = fastn_utils.getEventKey(event).toLowerCase();
+        return (
+            Object.entries(modifiers).every(
+                ([key, pressed]) => keys.includes(key) === pressed,
+            ) &&
+            keys
+                .filter((key) => !(key in modifiers))
+                .every((key) => key.toLowerCase() === eventKey)
+        );
+    },
+    // Calls `func` at most once in `ms` milliseconds, the last call in the
+    // window is delayed instead of dropped
+    throttle(func, ms) {
+        let last = 0;
+        let timer = null;
+        return () => {
+            const remaining = last + ms - Date.now();
+            if (remaining <= 0) {
+                clearTimeout(timer);
+                timer = null;
+                last = Date.now();
+                func();
+            } else if (timer === null) {
+                timer = setTimeout(() => {
+                    timer = null;
+                    last = Date.now();
+                    func();
+                }, remaining);
+            }
+        };
+    },
+    // `$sse$` variables: the json in the data of a `message` event is the new
+    // value of the variable, the data of a `patch` event is a json patch
+    // (RFC 6902) applied to the current value
+    subscribe(url, variable) {
+        if (ssr || typeof EventSource === "undefined") {
+            return;
+        }
+        const source = new EventSource(url);
+        const update = (func) => (event) => {
+            let data;
+            try {
+                data = JSON.parse(event.data);
+            } catch (e) {
+                console.log(`[fastn-sse]: invalid json from ${url}`, e);
+                return;
+            }
+            fastn_utils.setStaticValue(variable, func(data));
+        };
+        source.onmessage = update((value) => value);
+        source.addEventListener(
+            "patch",
+            update((patch) =>
+                fastn_utils.applyJsonPatch(
+                    fastn_utils.mutableToStaticValue(variable),
+                    patch,
+                ),
+            ),
+        );
+        fastn_dom.eventSources.add(source);
+    },
+    // Sets a mutable variable to a plain js value, json keys like
+    // `user-name` are the `user_name` field of a record
+    setStaticValue(variable, value) {
+        let mutableValue = fastn_utils.staticToMutables(
+            fastn_utils.private.toFieldNames(value),
+        );
+        if (mutableValue instanceof fastn.mutableClass) {
+            mutableValue = mutableValue.get();
+        }
+        variable.set(mutableValue);
+    },
+    // Supports the `add`, `remove` and `replace` operations, `-` as the last
+    // key of the path is the end of a list
+    applyJsonPatch(value, patch) {
+        for (const operation of patch) {
+            const keys = operation.path
+                .split("/")
+                .slice(1)
+                .map((key) => key.replace(/~1/g, "/").replace(/~0/g, "~"))
+                .map((key) => (key === "-" ? key : key.replace(/-/g, "_")));
+            const opValue = fastn_utils.private.toFieldNames(operation.value);
+            if (keys.length === 0) {
+                value = operation.op === "remove" ? null : opValue;
+                continue;
+            }
+            const last = keys.pop();
+            const parent = keys.reduce((current, key) => current[key], value);
+            if (Array.isArray(parent)) {
+                const index = last === "-" ? parent.length : Number(last);
+                if (operation.op === "add") {
+                    parent.splice(index, 0, opValue);
+                } else if (operation.op === "remove") {
+                    parent.splice(index, 1);
+                } else if (operation.op === "replace") {
+                    parent[index] = opValue;
+                }
+            } else if (operation.op === "remove") {
+                parent[last] = null;
+            } else if (operation.op === "add" || operation.op === "replace") {
+                parent[last] = opValue;
+            }
+        }
+        return value;
+    },
     getEventKey(event) {
         if (65 <= event.keyCode && event.keyCode <= 90) {
             return String.fromCharCode(event.keyCode).toLowerCase();
@@ -328,7 +488,7 @@ let fastn_utils = {
         const { space_before, space_after } = fastn_utils.private.spaces(i);
         const o = (() => {
             let g = fastn_utils.private.replace_last_occurrence(
-                marked.parse(i),
+                fastn_utils.private.markdownParser().parse(i),
                 "<p>",
                 "",
             );
@@ -485,8 +645,88 @@ let fastn_utils = {
 
         return result;
     },
+    /**
+     * Prepares the text of `ftd.code` for Prism: removes the `;; <hl>`
+     * highlighters, the callouts (`// <1>` at the end of a line) and, with
+     * `diff`, the `+` / `-` at the start of every line.
+     *
+     * @param {string} text - The text of `ftd.code`.
+     * @param {object} code - The `highlight`, `diff` and `start` of the code.
+     * @returns {{ modifiedText: string, highlightedLines: string,
+     *   signs: string[], callouts: string[] }} The highlighted lines are
+     *   numbered from `start`, signs and callouts are indexed by line.
+     */
+    prepareCode(text, code) {
+        const lines = text.split("\n");
+        const highlighter = ";; <hl>";
+        const start = fastn_utils.isNull(code.start) ? 1 : code.start;
+        const result = {
+            modifiedText: "",
+            highlightedLines: "",
+            signs: [],
+            callouts: [],
+        };
+
+        let highlightedLines = fastn_utils.private.lineNumbersOf(
+            code.highlight,
+        );
+        for (let i = 0; i < lines.length; i++) {
+            let line = lines[i];
+            const highlighterIndex = line.indexOf(highlighter);
+            if (highlighterIndex !== -1) {
+                highlightedLines.push(i + start);
+                line =
+                    line.substring(0, highlighterIndex) +
+                    line.substring(highlighterIndex + highlighter.length);
+            }
+            const callout = line.match(fastn_utils.private.codeCallout);
+            if (callout) {
+                result.callouts[i] = callout[1];
+                line = line.substring(0, callout.index);
+            }
+            // the empty line after the last new line is not shown
+            if (code.diff && (line !== "" || i !== lines.length - 1)) {
+                const sign = line[0];
+                result.signs[i] = sign === "+" || sign === "-" ? sign : " ";
+                if (sign === "+" || sign === "-" || sign === " ") {
+                    line = line.substring(1);
+                }
+            }
+            result.modifiedText += line + "\n";
+        }
+
+        highlightedLines = [...new Set(highlightedLines)].sort((a, b) => a - b);
+        result.highlightedLines =
+            fastn_utils.private.mergeNumbers(highlightedLines);
+
+        return result;
+    },
     getNodeValue(node) {
-        return node.getNode().value;
+        let element = node.getNode();
+        if (element.tagName.toLowerCase() === "fieldset") {
+            // `ftd.radio`: value of the checked option
+            let checked = element.querySelector("input:checked");
+            return fastn_utils.isNull(checked) ? null : checked.value;
+        }
+        if (element.type === "file") {
+            return element.files.length > 0 ? element.files[0].name : null;
+        }
+        return element.value;
+    },
+    // All selected values of a multiple `ftd.select`, or the names of the
+    // files picked in `ftd.file-input`.
+    getNodeValues(node) {
+        let element = node.getNode();
+        if (element.type === "file") {
+            return Array.from(element.files).map((file) => file.name);
+        }
+        if (!fastn_utils.isNull(element.selectedOptions)) {
+            return Array.from(element.selectedOptions).map(
+                (option) => option.value,
+            );
+        }
+        let value = fastn_utils.getNodeValue(node);
+        return fastn_utils.isNull(value) ? [] : [value];
     },
     getNodeCheckedState(node) {
         return node.getNode().checked;
@@ -509,6 +749,9 @@ let fastn_utils = {
         ) {
             Prism.highlightElement(codeElement);
         }
+        if (!ssr) {
+            fastn_utils.private.decorateCodeLines(codeElement, extraCodeData);
+        }
     },
 
     //Taken from: https://byby.dev/js-slugify-string
@@ -538,6 +781,27 @@ let fastn_utils = {
     flattenArray(arr) {
         return fastn_utils.private.flattenArray([arr]);
     },
+    // Compares static values, e.g. the ones returned by
+    // `mutableToStaticValue`
+    deepEqual(a, b) {
+        if (a === b) {
+            return true;
+        }
+        if (
+            typeof a !== "object" ||
+            typeof b !== "object" ||
+            a === null ||
+            b === null ||
+            Array.isArray(a) !== Array.isArray(b)
+        ) {
+            return false;
+        }
+        let keys = Object.keys(a);
+        if (keys.length !== Object.keys(b).length) {
+            return false;
+        }
+        return keys.every((key) => fastn_utils.deepEqual(a[key], b[key]));
+    },
     toSnakeCase(value) {
         return value
             .trim()
@@ -554,10 +818,85 @@ let fastn_utils = {
             })
             .join("");
     },
+    // Escapes a value rendered as html text or as a quoted attribute value
+    escapeHtml(value) {
+        return `${value}`
+            .replace(/&/g, "&amp;")
+            .replace(/"/g, "&quot;")
+            .replace(/</g, "&lt;");
+    },
+    // Inline svg markup of `ftd.svg` and `ftd.icon` reduced to the elements
+    // and attributes that draw, see `fastn_js::svg`. On the server the rust
+    // code does it, in the browser the svg is parsed as xml and the same
+    // lists are applied. Svg that is not well-formed is dropped.
+    sanitizeSvg(svg) {
+        if (fastn_utils.isNull(svg)) {
+            return "";
+        }
+        if (typeof __fastn_sanitize_svg__ === "function") {
+            return __fastn_sanitize_svg__(`${svg}`);
+        }
+        let parsed = new DOMParser().parseFromString(
+            `${svg}`,
+            "image/svg+xml",
+        );
+        let root = parsed.documentElement;
+        if (
+            root.nodeName !== "svg" ||
+            parsed.getElementsByTagName("parsererror").length > 0
+        ) {
+            return "";
+        }
+        fastn_utils.private.sanitizeSvgAttributes(root);
+        fastn_utils.private.sanitizeSvgChildren(root);
+        return new XMLSerializer().serializeToString(root);
+    },
+    // The value of the `field` of an `ftd.table` column for a row of `data`.
+    // `field` is the name of a field of the record, nested records are
+    // reached with `.`, like `address.city`.
+    getTableCellValue(row, column) {
+        if (fastn_utils.isNull(column.field)) {
+            return null;
+        }
+        let value = row;
+        for (let name of `${column.field}`.split(".")) {
+            value = fastn_utils.getStaticValue(value);
+            if (!(value instanceof fastn.recordInstanceClass)) {
+                return null;
+            }
+            value = value.get(name.trim().replace(/-/g, "_"));
+        }
+        return fastn_utils.getStaticValue(value);
+    },
+    // Numbers are compared as numbers and everything else as text. Empty
+    // cells go last.
+    compareTableValues(a, b) {
+        let isEmpty = (v) => fastn_utils.isNull(v) || `${v}`.trim() === "";
+        if (isEmpty(a) || isEmpty(b)) {
+            return isEmpty(a) - isEmpty(b);
+        }
+        if (!isNaN(Number(a)) && !isNaN(Number(b))) {
+            return Number(a) - Number(b);
+        }
+        return `${a}`.localeCompare(`${b}`, undefined, { numeric: true });
+    },
     escapeHtmlInCode(str) {
         return str.replace(/[<]/g, "&lt;");
     },
 
+    /**
+     * Lets text show markup fastn rendered at compile time, like the MathML
+     * of math, as is. The document calls it before it is shown, markup in
+     * text which is not exactly one of these is escaped.
+     *
+     * @param {string[]} markup - The markup fastn rendered.
+     */
+    trustMarkup(markup) {
+        for (const m of markup) {
+            fastn_utils.private.trustedMarkup.add(m);
+        }
+    },
+
     escapeHtmlInMarkdown(str) {
         if (typeof str !== "string") {
             return str;
@@ -578,6 +917,16 @@ let fastn_utils = {
             if (current === "`") {
                 foundBackTick = !foundBackTick;
             }
+            // The markup fastn rendered, like the MathML of `$...$` math, is
+            // kept as is
+            let markup = foundBackTick
+                ? null
+                : fastn_utils.private.trustedMarkupAt(str, i);
+            if (markup !== null) {
+                result += markup;
+                i += markup.length - 1;
+                continue;
+            }
             // Ignore escaping html inside backtick (as marked function
             // escape html for backtick content):
             // For instance: In `hello <title>`, `<` and `>` should not be
@@ -636,6 +985,177 @@ let fastn_utils = {
 };
 
 fastn_utils.private = {
+    // Removes the children of an svg element that are not in
+    // `fastn_svg_allowlist`, and the attributes of the ones that are kept
+    sanitizeSvgChildren(element) {
+        for (let child of Array.from(element.childNodes)) {
+            if (
+                child.nodeType === Node.TEXT_NODE ||
+                child.nodeType === Node.CDATA_SECTION_NODE
+            ) {
+                child.replaceWith(document.createTextNode(child.data));
+            } else if (
+                child.nodeType !== Node.ELEMENT_NODE ||
+                !fastn_svg_allowlist.elements.includes(child.nodeName) ||
+                !fastn_utils.private.sanitizeSvgAttributes(child)
+            ) {
+                child.remove();
+            } else {
+                fastn_utils.private.sanitizeSvgChildren(child);
+            }
+        }
+    },
+
+    // Removes the attributes of an svg element that are not allowed, returns
+    // `false` if the element itself has to go, see `fastn_js::svg`
+    sanitizeSvgAttributes(element) {
+        let clean = (v) =>
+            v.replace(/[\s\x00-\x1f\x7f-\x9f]/g, "").toLowerCase();
+        if (fastn_svg_allowlist.animations.includes(element.nodeName)) {
+            let target = (element.getAttribute("attributeName") ?? "")
+                .trim()
+                .toLowerCase();
+            if (
+                target.endsWith("href") ||
+                target.startsWith("on") ||
+                target === "style"
+            ) {
+                return false;
+            }
+        }
+        for (let attribute of Array.from(element.attributes)) {
+            let name = attribute.name;
+            let value = clean(attribute.value);
+            let allowed = fastn_svg_allowlist.attributes.includes(name);
+            if (allowed && (name === "href" || name === "xlink:href")) {
+                allowed =
+                    value.startsWith("#") ||
+                    (element.nodeName === "image" &&
+                        /^data:image\/(png|jpeg|jpg|gif|webp);/.test(value));
+            } else if (allowed) {
+                allowed =
+                    !value.includes("javascript:") &&
+                    value
+                        .split("url(")
+                        .slice(1)
+                        .every((u) => u.replace(/^["']+/, "").startsWith("#")) &&
+                    (name !== "style" ||
+                        !["expression(", "@import", "\\", "<"].some((s) =>
+                            value.includes(s),
+                        ));
+            }
+            if (!allowed) {
+                element.removeAttribute(name);
+            }
+        }
+        return true;
+    },
+
+    // A callout at the end of a line of code: `// <1>`, `# <2>`, `-- <3>`
+    codeCallout: /\s*(?:\/\/|#|;;|--)\s*<(\d+)>\s*$/,
+
+    // The markup fastn rendered, see `fastn_utils.trustMarkup`
+    trustedMarkup: new Set(),
+
+    // The trusted markup starting at `index` of `str`, or `null`
+    trustedMarkupAt(str, index) {
+        if (str[index] !== "<") {
+            return null;
+        }
+        for (const markup of fastn_utils.private.trustedMarkup) {
+            if (str.startsWith(markup, index)) {
+                return markup;
+            }
+        }
+        return null;
+    },
+
+    // The lines in `3-5,9` of the `highlight` of `ftd.code`: `[3, 4, 5, 9]`
+    lineNumbersOf(ranges) {
+        if (fastn_utils.isNull(ranges)) {
+            return [];
+        }
+        return String(ranges)
+            .split(",")
+            .flatMap((range) => {
+                let [from, to] = range.split("-").map((n) => parseInt(n));
+                if (isNaN(from)) {
+                    return [];
+                }
+                to = isNaN(to) ? from : to;
+                return Array.from(
+                    { length: Math.max(to - from + 1, 0) },
+                    (_, i) => from + i,
+                );
+            });
+    },
+
+    // Splits the html of highlighted code into lines, the `span`s open at
+    // the end of a line are closed, and opened again on the next line.
+    splitHighlightedLines(html) {
+        let open = [];
+        return html.split("\n").map((line) => {
+            const prefix = open.join("");
+            for (const tag of line.matchAll(/<span[^>]*>|<\/span>/g)) {
+                if (tag[0] === "</span>") {
+                    open.pop();
+                } else {
+                    open.push(tag[0]);
+                }
+            }
+            return prefix + line + "</span>".repeat(open.length);
+        });
+    },
+
+    // Wraps the lines of `ftd.code` with a diff sign or a callout. The sign
+    // and the callout number are shown by css, so copying the code does not
+    // copy them.
+    decorateCodeLines(codeElement, code) {
+        const signs = code.signs ?? [];
+        const callouts = code.callouts ?? [];
+        if (signs.length === 0 && callouts.length === 0) {
+            return;
+        }
+        const diffClasses = {
+            "+": "ft_diff_added",
+            "-": "ft_diff_removed",
+            " ": "ft_diff_context",
+        };
+        // the line numbers and highlighted lines added by Prism
+        const extras = Array.from(
+            codeElement.querySelectorAll(
+                ":scope > .line-numbers-rows, :scope > .line-highlight",
+            ),
+        );
+        extras.forEach((extra) => extra.remove());
+        const lines = fastn_utils.private.splitHighlightedLines(
+            codeElement.innerHTML,
+        );
+        codeElement.innerHTML = lines
+            .map((line, i) => {
+                const sign = signs[i];
+                const callout = callouts[i];
+                if (sign === undefined && callout === undefined) {
+                    return line;
+                }
+                const classes = ["ft_code_line"];
+                let attributes = "";
+                if (sign !== undefined) {
+                    classes.push(diffClasses[sign]);
+                    attributes = ` data-sign="${sign}"`;
+                }
+                if (callout !== undefined) {
+                    line +=
+                        '<span class="ft_code_callout" ' +
+                        `data-callout="${callout}"></span>`;
+                }
+                const attribute = `class="${classes.join(" ")}"${attributes}`;
+                return `<span ${attribute}>${line}</span>`;
+            })
+            .join("\n");
+        extras.forEach((extra) => codeElement.appendChild(extra));
+    },
+
     flattenArray(arr) {
         return arr.reduce((acc, item) => {
             return acc.concat(
@@ -645,6 +1165,280 @@ fastn_utils.private = {
             );
         }, []);
     },
+    /**
+     * Positions of a longest increasing subsequence of `arr`, ignoring the
+     * `-1` entries. Used by keyed loops to find the nodes that can stay where
+     * they are.
+     *
+     * @param {number[]} arr
+     * @returns {Set<number>}
+     */
+    longestIncreasingSubsequence(arr) {
+        // tails[k]: position of the smallest last value of a subsequence of
+        // length k + 1
+        let tails = [];
+        let previous = new Array(arr.length).fill(-1);
+        for (let i = 0; i < arr.length; i++) {
+            if (arr[i] === -1) {
+                continue;
+            }
+            let lo = 0;
+            let hi = tails.length;
+            while (lo < hi) {
+                let mid = (lo + hi) >> 1;
+                if (arr[tails[mid]] < arr[i]) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            if (lo > 0) {
+                previous[i] = tails[lo - 1];
+            }
+            tails[lo] = i;
+        }
+        let result = new Set();
+        let i = tails.length > 0 ? tails[tails.length - 1] : -1;
+        while (i !== -1) {
+            result.add(i);
+            i = previous[i];
+        }
+        return result;
+    },
+    /**
+     * Helper function for `fastn_utils.markdown_inline`, the `marked`
+     * instance with the extensions of `-- fastn.markdown:` in `FASTN.ftd`
+     * (`__fastn_markdown__`) turned on. The html is the one comrak renders
+     * for the same extensions.
+     *
+     * @returns {marked.Marked}
+     */
+    markdownParser() {
+        if (fastn_utils.private.markdown_parser) {
+            return fastn_utils.private.markdown_parser;
+        }
+        let options =
+            typeof __fastn_markdown__ === "undefined" ? {} : __fastn_markdown__;
+        let parser = new marked.Marked();
+        if (options.tables === false) {
+            parser.use({ tokenizer: { table: () => undefined } });
+        }
+        if (options["task-lists"] === false) {
+            parser.use({
+                renderer: {
+                    checkbox: (checked) => (checked ? "[x]" : "[ ]"),
+                },
+            });
+        }
+        if (options["heading-ids"]) {
+            parser.use(fastn_utils.private.markdownHeadingIds());
+        }
+        if (options.footnotes) {
+            parser.use(fastn_utils.private.markdownFootnotes());
+        }
+        if (options.admonitions) {
+            parser.use(fastn_utils.private.markdownAdmonitions());
+        }
+        fastn_utils.private.markdown_parser = parser;
+        return parser;
+    },
+    /**
+     * The id of a heading, the way comrak's `Anchorizer` does it: lowercase,
+     * without punctuation, spaces replaced by `-` and a `-<n>` suffix when
+     * the id is already in `seen`.
+     *
+     * @param {string} title - The text of the heading.
+     * @param {Set<string>} seen - The ids given so far.
+     * @returns {string}
+     */
+    anchorize(title, seen) {
+        let id = title
+            .toLowerCase()
+            .replace(/[^\p{L}\p{M}\p{N}\p{Pc} -]/gu, "")
+            .replace(/ /g, "-");
+        let anchor = id;
+        for (let i = 1; seen.has(anchor); i++) {
+            anchor = `${id}-${i}`;
+        }
+        seen.add(anchor);
+        return anchor;
+    },
+    markdownHeadingIds() {
+        let seen = new Set();
+        return {
+            hooks: {
+                preprocess(src) {
+                    seen.clear();
+                    return src;
+                },
+            },
+            renderer: {
+                heading(text, level, raw) {
+                    let id = fastn_utils.private.anchorize(raw, seen);
+                    return (
+                        `<h${level}><a href="#${id}" aria-hidden="true" ` +
+                        `class="anchor" id="${id}"></a>${text}</h${level}>\n`
+                    );
+                },
+            },
+        };
+    },
+    /*
+        `[^label]` references are numbered in the order they first appear,
+        the `[^label]: text` definitions are listed, in that order, in a
+        section at the end. Definitions nobody refers to are dropped.
+     */
+    markdownFootnotes() {
+        // a definition goes on in the indented lines after it
+        let definition =
+            /^\[\^([^\]\s]+)\]:[ \t]*([^\n]*(?:\n[ \t]+[^\n]*)*)(?:\n|$)/;
+        let numbers = new Map();
+        let definitions = new Map();
+        return {
+            hooks: {
+                preprocess(src) {
+                    numbers.clear();
+                    definitions.clear();
+                    return src;
+                },
+                postprocess(html) {
+                    if (numbers.size === 0) {
+                        return html;
+                    }
+                    let items = [...numbers.keys()].map((label) => {
+                        let n = numbers.get(label);
+                        let backref =
+                            `<a href="#fnref-${label}" ` +
+                            `class="footnote-backref" ` +
+                            `aria-label="Back to reference ${n}">↩</a>`;
+                        let text = definitions.get(label) ?? "";
+                        return (
+                            `<li id="fn-${label}">\n` +
+                            `<p>${text} ${backref}</p>\n</li>\n`
+                        );
+                    });
+                    return (
+                        `${html}<section class="footnotes" data-footnotes>\n` +
+                        `<ol>\n${items.join("")}</ol>\n</section>\n`
+                    );
+                },
+            },
+            extensions: [
+                {
+                    name: "footnote",
+                    level: "block",
+                    start(src) {
+                        return src.match(/^\[\^[^\]\s]+\]:/m)?.index;
+                    },
+                    tokenizer(src) {
+                        let match = definition.exec(src);
+                        if (match) {
+                            return {
+                                type: "footnote",
+                                raw: match[0],
+                                label: match[1],
+                                tokens: this.lexer.inlineTokens(
+                                    match[2].replace(/\n[ \t]+/g, "\n").trim(),
+                                ),
+                            };
+                        }
+                    },
+                    renderer(token) {
+                        definitions.set(
+                            token.label,
+                            this.parser.parseInline(token.tokens),
+                        );
+                        return "";
+                    },
+                },
+                {
+                    name: "footnoteRef",
+                    level: "inline",
+                    start(src) {
+                        return src.match(/\[\^/)?.index;
+                    },
+                    tokenizer(src) {
+                        let match = /^\[\^([^\]\s]+)\](?!:)/.exec(src);
+                        if (match) {
+                            return {
+                                type: "footnoteRef",
+                                raw: match[0],
+                                label: match[1],
+                            };
+                        }
+                    },
+                    renderer(token) {
+                        let label = token.label;
+                        if (!numbers.has(label)) {
+                            numbers.set(label, numbers.size + 1);
+                        }
+                        let n = numbers.get(label);
+                        return (
+                            `<sup class="footnote-ref">` +
+                            `<a href="#fn-${label}" id="fnref-${label}">` +
+                            `${n}</a></sup>`
+                        );
+                    },
+                },
+            ],
+        };
+    },
+    /*
+        GitHub style alerts, a blockquote starting with `[!NOTE]`, `[!TIP]`,
+        `[!IMPORTANT]`, `[!WARNING]` or `[!CAUTION]`, optionally followed by
+        a title. `>` is also matched as `&gt;` as the text of `ftd.text` is
+        escaped before it gets here, see `fastn_utils.escapeHtmlInMarkdown`.
+     */
+    markdownAdmonitions() {
+        let quote = " {0,3}(?:>|&gt;) ?";
+        let alert = new RegExp(
+            `^${quote}\\[!(note|tip|important|warning|caution)\\]` +
+                `[ \\t]*([^\\n]*)(?:\\n|$)((?:${quote}[^\\n]*(?:\\n|$))*)`,
+            "i",
+        );
+        let line = new RegExp(`^${quote}`, "gm");
+        return {
+            extensions: [
+                {
+                    name: "admonition",
+                    level: "block",
+                    start(src) {
+                        return src.match(
+                            new RegExp(`^${quote}\\[!`, "m"),
+                        )?.index;
+                    },
+                    tokenizer(src) {
+                        let match = alert.exec(src);
+                        if (match) {
+                            return {
+                                type: "admonition",
+                                raw: match[0],
+                                kind: match[1].toLowerCase(),
+                                title: this.lexer.inlineTokens(match[2].trim()),
+                                tokens: this.lexer.blockTokens(
+                                    match[3].replace(line, ""),
+                                    [],
+                                ),
+                            };
+                        }
+                    },
+                    renderer(token) {
+                        let title =
+                            token.title.length > 0
+                                ? this.parser.parseInline(token.title)
+                                : token.kind[0].toUpperCase() +
+                                  token.kind.slice(1);
+                        return (
+                            `<div class="markdown-alert ` +
+                            `markdown-alert-${token.kind}">\n` +
+                            `<p class="markdown-alert-title">${title}</p>\n` +
+                            `${this.parser.parse(token.tokens)}</div>\n`
+                        );
+                    },
+                },
+            ],
+        };
+    },
     /**
      * Helper function for `fastn_utils.markdown_inline` to find the number of
      * spaces before and after the content.
@@ -745,6 +1539,20 @@ fastn_utils.private = {
 
         return mergedRanges.join(",");
     },
+    toFieldNames(value) {
+        if (Array.isArray(value)) {
+            return value.map((v) => fastn_utils.private.toFieldNames(v));
+        }
+        if (value === null || typeof value !== "object") {
+            return value;
+        }
+        return Object.fromEntries(
+            Object.entries(value).map(([key, v]) => [
+                key.replace(/-/g, "_"),
+                fastn_utils.private.toFieldNames(v),
+            ]),
+        );
+    },
     addUnderscoreToStart(text) {
         if (/^\d/.test(text)) {
             return "_" + text;
@@ -153,6 +153,12 @@ function addClosureToBreakpointWidth() {
 
 fastnVirtual.doubleBuffer = function (main) {
     addClosureToBreakpointWidth();
+    fastnVirtual.replaceBody(main);
+};
+
+// Renders `main` off-screen and then swaps it in as the body, also used for
+// the pages of client-side navigation.
+fastnVirtual.replaceBody = function (main) {
     let parent = document.createElement("div");
     let current_device = ftd.get_device();
     ftd.device = fastn.mutable(current_device);
@@ -8,4 +8,5 @@ pub enum Ast {
     RecordInstance(fastn_js::RecordInstance),
     OrType(fastn_js::OrType),
     Export { from: String, to: String },
+    ServerSentEvents { name: String, url: String }, // `$sse$` variable
 }
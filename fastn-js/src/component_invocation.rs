@@ -35,6 +35,17 @@ pub enum ElementKind {
     Device,
     CheckBox,
     TextInput,
+    Select,
+    Radio,
+    Textarea,
+    FileInput,
+    Form,
+    Table,
+    TableRow,
+    Grid,
+    Svg,
+    Icon,
+    Math,
     Rive,
     Audio,
     Document,
@@ -17,6 +17,13 @@ pub enum Event {
     Change,
     Blur,
     Focus,
+    Interval(i64),
+    Timeout(i64),
+    Visible,
+    Hidden,
+    Scroll(i64),
+    Submit,
+    KeyDown(Vec<String>),
 }
 
 #[derive(Debug)]
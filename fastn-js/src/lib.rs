@@ -17,6 +17,7 @@ mod property;
 mod record;
 mod ssr;
 mod static_variable;
+mod svg;
 mod to_js;
 mod udf;
 mod udf_statement;
@@ -41,6 +42,7 @@ pub use property::{
 pub use record::RecordInstance;
 pub use ssr::{run_test, ssr, ssr_raw_string_without_test, ssr_str, ssr_with_js_string, SSRError};
 pub use static_variable::{static_integer, static_string, StaticVariable};
+pub use svg::{sanitize_svg, SvgError};
 pub use to_js::to_js;
 pub use udf::{udf_with_arguments, UDF};
 pub use udf_statement::UDFStatement;
@@ -69,7 +71,8 @@ pub fn all_js_without_test_and_ftd_langugage_js() -> String {
     let ftd_js = include_str_with_debug!("../js/ftd.js");
     let web_component_js = include_str_with_debug!("../js/web-component.js");
     let post_init_js = include_str_with_debug!("../js/postInit.js");
-    format!("{markdown_js}{fastn_js}{dom_js}{utils_js}{virtual_js}{web_component_js}{ftd_js}{post_init_js}")
+    let svg_js = fastn_js::svg::allowlist_js();
+    format!("{markdown_js}{fastn_js}{dom_js}{utils_js}{svg_js}{virtual_js}{web_component_js}{ftd_js}{post_init_js}")
 }
 
 #[macro_export]
@@ -198,6 +198,9 @@ pub enum Value {
     List {
         value: Vec<SetPropertyValue>,
     },
+    Map {
+        value: Vec<(String, SetPropertyValue)>,
+    },
     Record {
         fields: Vec<(String, SetPropertyValue)>,
         other_references: Vec<String>,
@@ -244,6 +247,17 @@ impl Value {
                     .map(|v| v.to_js_with_element_name(element_name))
                     .join(", ")
             ),
+            Value::Map { value } => format!(
+                "fastn.mutableMap({{{}}})",
+                value
+                    .iter()
+                    .map(|(k, v)| format!(
+                        "{}: {}",
+                        Value::String(k.to_string()).to_js(element_name),
+                        v.to_js_with_element_name(element_name)
+                    ))
+                    .join(", ")
+            ),
             Value::Record {
                 fields,
                 other_references,
@@ -303,6 +317,7 @@ impl Value {
                 .map(|v| v.is_local_value_dependent())
                 .unwrap_or_default(),
             Value::List { value } => value.iter().any(|v| v.is_local_value_dependent()),
+            Value::Map { value } => value.iter().any(|v| v.1.is_local_value_dependent()),
             Value::Record { fields, .. } => fields.iter().any(|v| v.1.is_local_value_dependent()),
             Value::UI { .. } => {
                 //Todo: Check for UI
@@ -428,6 +443,11 @@ pub enum PropertyKind {
     CodeTheme,
     CodeLanguage,
     CodeShowLineNumber,
+    CodeHighlight,
+    CodeDiff,
+    CodeLineNumberStart,
+    CodeTitle,
+    MathContent,
     MetaTitle,
     MetaOGTitle,
     MetaTwitterTitle,
@@ -442,6 +462,38 @@ pub enum PropertyKind {
     Selectable,
     BackdropFilter,
     Mask,
+    Options,
+    SelectedValue,
+    SelectedValues,
+    Multiple,
+    Rows,
+    AutoResize,
+    Accept,
+    Name,
+    FormFields,
+    FormAction,
+    FormMethod,
+    FormEncoding,
+    TableColumns,
+    TableData,
+    StickyHeader,
+    Sortable,
+    PageSize,
+    GridTemplateColumns,
+    GridTemplateRows,
+    GridTemplateAreas,
+    GridAutoFlow,
+    Gap,
+    RowGap,
+    ColumnGap,
+    GridColumn,
+    GridRow,
+    GridArea,
+    SvgContent,
+    Fill,
+    Stroke,
+    Transition,
+    Animation,
 }
 
 impl PropertyKind {
@@ -562,6 +614,11 @@ impl PropertyKind {
             PropertyKind::CodeTheme => "fastn_dom.PropertyKind.CodeTheme",
             PropertyKind::CodeShowLineNumber => "fastn_dom.PropertyKind.CodeShowLineNumber",
             PropertyKind::CodeLanguage => "fastn_dom.PropertyKind.CodeLanguage",
+            PropertyKind::CodeHighlight => "fastn_dom.PropertyKind.CodeHighlight",
+            PropertyKind::CodeDiff => "fastn_dom.PropertyKind.CodeDiff",
+            PropertyKind::CodeLineNumberStart => "fastn_dom.PropertyKind.CodeLineNumberStart",
+            PropertyKind::CodeTitle => "fastn_dom.PropertyKind.CodeTitle",
+            PropertyKind::MathContent => "fastn_dom.PropertyKind.MathContent",
             PropertyKind::MetaTitle => "fastn_dom.PropertyKind.DocumentProperties.MetaTitle",
             PropertyKind::MetaOGTitle => "fastn_dom.PropertyKind.DocumentProperties.MetaOGTitle",
             PropertyKind::MetaTwitterTitle => {
@@ -590,6 +647,38 @@ impl PropertyKind {
             PropertyKind::Selectable => "fastn_dom.PropertyKind.Selectable",
             PropertyKind::BackdropFilter => "fastn_dom.PropertyKind.BackdropFilter",
             PropertyKind::Mask => "fastn_dom.PropertyKind.Mask",
+            PropertyKind::Options => "fastn_dom.PropertyKind.Options",
+            PropertyKind::SelectedValue => "fastn_dom.PropertyKind.SelectedValue",
+            PropertyKind::SelectedValues => "fastn_dom.PropertyKind.SelectedValues",
+            PropertyKind::Multiple => "fastn_dom.PropertyKind.Multiple",
+            PropertyKind::Rows => "fastn_dom.PropertyKind.Rows",
+            PropertyKind::AutoResize => "fastn_dom.PropertyKind.AutoResize",
+            PropertyKind::Accept => "fastn_dom.PropertyKind.Accept",
+            PropertyKind::Name => "fastn_dom.PropertyKind.Name",
+            PropertyKind::FormFields => "fastn_dom.PropertyKind.FormFields",
+            PropertyKind::FormAction => "fastn_dom.PropertyKind.FormAction",
+            PropertyKind::FormMethod => "fastn_dom.PropertyKind.FormMethod",
+            PropertyKind::FormEncoding => "fastn_dom.PropertyKind.FormEncoding",
+            PropertyKind::TableColumns => "fastn_dom.PropertyKind.TableColumns",
+            PropertyKind::TableData => "fastn_dom.PropertyKind.TableData",
+            PropertyKind::StickyHeader => "fastn_dom.PropertyKind.StickyHeader",
+            PropertyKind::Sortable => "fastn_dom.PropertyKind.Sortable",
+            PropertyKind::PageSize => "fastn_dom.PropertyKind.PageSize",
+            PropertyKind::GridTemplateColumns => "fastn_dom.PropertyKind.GridTemplateColumns",
+            PropertyKind::GridTemplateRows => "fastn_dom.PropertyKind.GridTemplateRows",
+            PropertyKind::GridTemplateAreas => "fastn_dom.PropertyKind.GridTemplateAreas",
+            PropertyKind::GridAutoFlow => "fastn_dom.PropertyKind.GridAutoFlow",
+            PropertyKind::Gap => "fastn_dom.PropertyKind.Gap",
+            PropertyKind::RowGap => "fastn_dom.PropertyKind.RowGap",
+            PropertyKind::ColumnGap => "fastn_dom.PropertyKind.ColumnGap",
+            PropertyKind::GridColumn => "fastn_dom.PropertyKind.GridColumn",
+            PropertyKind::GridRow => "fastn_dom.PropertyKind.GridRow",
+            PropertyKind::GridArea => "fastn_dom.PropertyKind.GridArea",
+            PropertyKind::SvgContent => "fastn_dom.PropertyKind.SvgContent",
+            PropertyKind::Fill => "fastn_dom.PropertyKind.Fill",
+            PropertyKind::Stroke => "fastn_dom.PropertyKind.Stroke",
+            PropertyKind::Transition => "fastn_dom.PropertyKind.Transition",
+            PropertyKind::Animation => "fastn_dom.PropertyKind.Animation",
         }
     }
 }
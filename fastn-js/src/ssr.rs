@@ -36,11 +36,20 @@ pub fn ssr_str(js: &str) -> Result<String> {
 
     let js = format!("{all_js}{js}");
 
+    // `fastn_utils.sanitizeSvg` uses it to sanitize `ftd.svg` on the server
+    let sanitize_svg = "__fastn_sanitize_svg__";
+
     #[cfg(target_os = "windows")]
     {
         Ok(rquickjs::Context::full(&rquickjs::Runtime::new().unwrap())
             .unwrap()
-            .with(|ctx| ctx.eval::<String, _>(js).unwrap()))
+            .with(|ctx| {
+                let function =
+                    rquickjs::Function::new(ctx.clone(), fastn_js::svg::sanitize_svg_or_empty)
+                        .unwrap();
+                ctx.globals().set(sanitize_svg, function).unwrap();
+                ctx.eval::<String, _>(js).unwrap()
+            }))
     }
     #[cfg(not(target_os = "windows"))]
     {
@@ -53,6 +62,9 @@ pub fn ssr_str(js: &str) -> Result<String> {
             )
             .build()
             .unwrap();
+        context
+            .add_callback(sanitize_svg, fastn_js::svg::sanitize_svg_or_empty)
+            .unwrap();
         Ok::<std::string::String, SSRError>(context.eval_as::<String>(js.as_str()).unwrap())
     }
 }
@@ -84,3 +96,73 @@ pub fn ssr_raw_string_without_test(package_name: &str, js: &str) -> String {
     let raw_string = ssr_raw_string(package_name, js);
     format!("{all_js}{raw_string}")
 }
+
+#[cfg(test)]
+mod test {
+    /// Runs `fastn-builtins/conformance.json` against `ftd.js`, the evalexpr
+    /// builtins are tested against the same file in `fastn-builtins`.
+    #[test]
+    fn builtins_conformance() {
+        let cases = include_str!("../../fastn-builtins/conformance.json");
+        let harness = r#"
+            (function () {
+                const toFastn = (v) => {
+                    if (Array.isArray(v)) return fastn.mutableList(v.map(toFastn));
+                    if (v !== null && typeof v === "object") {
+                        return fastn.recordInstance(
+                            Object.fromEntries(
+                                Object.entries(v).map(([k, x]) => [k, toFastn(x)]),
+                            ),
+                        );
+                    }
+                    return fastn.mutable(v);
+                };
+                const failures = [];
+                for (const c of cases) {
+                    let result;
+                    try {
+                        result = fastn_utils.mutableToStaticValue(
+                            ftd[c.function](...c.args.map(toFastn)),
+                        );
+                    } catch (e) {
+                        result = String(e);
+                    }
+                    if (JSON.stringify(result) !== JSON.stringify(c.result)) {
+                        failures.push({ ...c, found: result });
+                    }
+                }
+                return JSON.stringify(failures);
+            })()
+        "#;
+        let failures = super::ssr_str(&format!("const cases = {cases};\n{harness}")).unwrap();
+        assert_eq!(failures, "[]");
+    }
+
+    /// Runs `fastn-builtins/form-conformance.json` against
+    /// `ftd.validate_field`, `fastn_builtins::form` is tested against the same
+    /// file.
+    #[test]
+    fn form_conformance() {
+        let cases = include_str!("../../fastn-builtins/form-conformance.json");
+        let harness = r#"
+            (function () {
+                const failures = [];
+                for (const c of cases) {
+                    const field = fastn.recordInstance(c.field);
+                    let error;
+                    try {
+                        error = ftd.validate_field(field, fastn.mutable(c.value));
+                    } catch (e) {
+                        error = String(e);
+                    }
+                    if (error !== c.error) {
+                        failures.push({ ...c, found: error });
+                    }
+                }
+                return JSON.stringify(failures);
+            })()
+        "#;
+        let failures = super::ssr_str(&format!("const cases = {cases};\n{harness}")).unwrap();
+        assert_eq!(failures, "[]");
+    }
+}
@@ -0,0 +1,537 @@
+//! Inline svg markup of `ftd.svg` and `ftd.icon` is reduced to the elements
+//! and attributes that draw, so nothing in it can run scripts. The svg is
+//! parsed as xml, anything not in [`ELEMENTS`] is dropped with its children and
+//! anything not in [`ATTRIBUTES`] is dropped from the element. Links are only
+//! kept when they point within the svg, like `href="#gradient"`.
+//!
+//! The svg files read at compile time are sanitized here, and so is the
+//! content of `ftd.svg` rendered on the server, through `ssr`. In the browser
+//! `fastn_utils.sanitizeSvg` applies the same lists, see [`allowlist_js`].
+
+pub const ELEMENTS: &[&str] = &[
+    "svg",
+    "g",
+    "defs",
+    "symbol",
+    "use",
+    "title",
+    "desc",
+    "switch",
+    "view",
+    "path",
+    "rect",
+    "circle",
+    "ellipse",
+    "line",
+    "polyline",
+    "polygon",
+    "text",
+    "tspan",
+    "textPath",
+    "image",
+    "linearGradient",
+    "radialGradient",
+    "stop",
+    "clipPath",
+    "mask",
+    "pattern",
+    "marker",
+    "filter",
+    "feBlend",
+    "feColorMatrix",
+    "feComponentTransfer",
+    "feComposite",
+    "feConvolveMatrix",
+    "feDiffuseLighting",
+    "feDisplacementMap",
+    "feDistantLight",
+    "feDropShadow",
+    "feFlood",
+    "feFuncA",
+    "feFuncB",
+    "feFuncG",
+    "feFuncR",
+    "feGaussianBlur",
+    "feMerge",
+    "feMergeNode",
+    "feMorphology",
+    "feOffset",
+    "fePointLight",
+    "feSpecularLighting",
+    "feSpotLight",
+    "feTile",
+    "feTurbulence",
+    "animate",
+    "animateMotion",
+    "animateTransform",
+    "set",
+    "mpath",
+];
+
+/// The elements that change an attribute of another element, they are
+/// dropped when the attribute is a link, an event handler or `style`.
+pub const ANIMATIONS: &[&str] = &["animate", "animateMotion", "animateTransform", "set"];
+
+pub const ATTRIBUTES: &[&str] = &[
+    "xmlns",
+    "xmlns:xlink",
+    "xml:space",
+    "version",
+    "id",
+    "class",
+    "style",
+    "role",
+    "aria-hidden",
+    "aria-label",
+    "aria-labelledby",
+    "aria-describedby",
+    "focusable",
+    "lang",
+    "href",
+    "xlink:href",
+    "viewBox",
+    "preserveAspectRatio",
+    "width",
+    "height",
+    "x",
+    "y",
+    "x1",
+    "y1",
+    "x2",
+    "y2",
+    "cx",
+    "cy",
+    "r",
+    "rx",
+    "ry",
+    "fx",
+    "fy",
+    "fr",
+    "d",
+    "points",
+    "pathLength",
+    "transform",
+    "transform-origin",
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "clip-rule",
+    "stroke",
+    "stroke-width",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "stroke-opacity",
+    "opacity",
+    "color",
+    "display",
+    "visibility",
+    "overflow",
+    "clip-path",
+    "mask",
+    "filter",
+    "marker-start",
+    "marker-mid",
+    "marker-end",
+    "paint-order",
+    "vector-effect",
+    "shape-rendering",
+    "text-rendering",
+    "image-rendering",
+    "color-interpolation",
+    "color-interpolation-filters",
+    "mix-blend-mode",
+    "isolation",
+    "gradientUnits",
+    "gradientTransform",
+    "spreadMethod",
+    "offset",
+    "stop-color",
+    "stop-opacity",
+    "patternUnits",
+    "patternContentUnits",
+    "patternTransform",
+    "clipPathUnits",
+    "maskUnits",
+    "maskContentUnits",
+    "markerWidth",
+    "markerHeight",
+    "markerUnits",
+    "refX",
+    "refY",
+    "orient",
+    "filterUnits",
+    "primitiveUnits",
+    "in",
+    "in2",
+    "result",
+    "stdDeviation",
+    "dx",
+    "dy",
+    "mode",
+    "type",
+    "values",
+    "operator",
+    "k1",
+    "k2",
+    "k3",
+    "k4",
+    "scale",
+    "xChannelSelector",
+    "yChannelSelector",
+    "flood-color",
+    "flood-opacity",
+    "lighting-color",
+    "radius",
+    "baseFrequency",
+    "numOctaves",
+    "seed",
+    "stitchTiles",
+    "tableValues",
+    "slope",
+    "intercept",
+    "amplitude",
+    "exponent",
+    "kernelMatrix",
+    "order",
+    "divisor",
+    "bias",
+    "targetX",
+    "targetY",
+    "edgeMode",
+    "preserveAlpha",
+    "surfaceScale",
+    "diffuseConstant",
+    "specularConstant",
+    "specularExponent",
+    "azimuth",
+    "elevation",
+    "pointsAtX",
+    "pointsAtY",
+    "pointsAtZ",
+    "limitingConeAngle",
+    "z",
+    "font-family",
+    "font-size",
+    "font-weight",
+    "font-style",
+    "text-anchor",
+    "dominant-baseline",
+    "alignment-baseline",
+    "baseline-shift",
+    "letter-spacing",
+    "word-spacing",
+    "text-decoration",
+    "writing-mode",
+    "textLength",
+    "lengthAdjust",
+    "startOffset",
+    "method",
+    "spacing",
+    "attributeName",
+    "attributeType",
+    "from",
+    "to",
+    "by",
+    "dur",
+    "begin",
+    "end",
+    "repeatCount",
+    "repeatDur",
+    "calcMode",
+    "keyTimes",
+    "keySplines",
+    "keyPoints",
+    "additive",
+    "accumulate",
+    "rotate",
+    "path",
+    "restart",
+    "min",
+    "max",
+];
+
+#[derive(thiserror::Error, Debug)]
+pub enum SvgError {
+    #[error("svg is not well-formed xml: {0}")]
+    Xml(#[from] quick_xml::Error),
+
+    #[error("svg has a malformed attribute: {0}")]
+    Attribute(#[from] quick_xml::events::attributes::AttrError),
+
+    #[error("expected `<svg>` at the root, found `<{0}>`")]
+    Root(String),
+}
+
+/// Returns the svg without anything that can run scripts, see the module
+/// docs. Malformed xml is an error rather than a guess at what the browser
+/// would make of it.
+pub fn sanitize_svg(svg: &str) -> Result<String, SvgError> {
+    use quick_xml::events::Event;
+
+    let mut reader = quick_xml::Reader::from_str(svg);
+    let mut output = String::new();
+    // the elements that are kept and not closed yet
+    let mut open: Vec<String> = vec![];
+    // how deep we are in an element that is dropped
+    let mut dropped = 0usize;
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => {
+                if dropped > 0 {
+                    dropped += 1;
+                    continue;
+                }
+                match start_tag(&e, open.is_empty())? {
+                    Some((name, tag)) => {
+                        output.push_str(&tag);
+                        output.push('>');
+                        open.push(name);
+                    }
+                    None => dropped = 1,
+                }
+            }
+            Event::Empty(e) => {
+                if dropped > 0 {
+                    continue;
+                }
+                if let Some((_, tag)) = start_tag(&e, open.is_empty())? {
+                    output.push_str(&tag);
+                    output.push_str("/>");
+                }
+            }
+            Event::End(_) => {
+                if dropped > 0 {
+                    dropped -= 1;
+                } else if let Some(name) = open.pop() {
+                    output.push_str(&format!("</{name}>"));
+                }
+            }
+            Event::Text(e) => {
+                if dropped == 0 && !open.is_empty() {
+                    output.push_str(&escape(&e.unescape()?, false));
+                }
+            }
+            Event::CData(e) => {
+                if dropped == 0 && !open.is_empty() {
+                    output.push_str(&escape(&String::from_utf8_lossy(&e), false));
+                }
+            }
+            Event::Comment(_) | Event::Decl(_) | Event::PI(_) | Event::DocType(_) => {}
+            Event::Eof => break,
+        }
+    }
+    for name in open.iter().rev() {
+        output.push_str(&format!("</{name}>"));
+    }
+    Ok(output.trim().to_string())
+}
+
+/// The opening tag of the element, without the closing `>`, and its name.
+/// `None` if the element is dropped.
+fn start_tag(
+    e: &quick_xml::events::BytesStart,
+    is_root: bool,
+) -> Result<Option<(String, String)>, SvgError> {
+    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+    if is_root && name != "svg" {
+        return Err(SvgError::Root(name));
+    }
+    if !ELEMENTS.contains(&name.as_str()) {
+        return Ok(None);
+    }
+    let mut tag = format!("<{name}");
+    for attribute in e.attributes() {
+        let attribute = attribute?;
+        let key = String::from_utf8_lossy(attribute.key.as_ref()).to_string();
+        // `unescape_value` leaves out the predefined entities, like `&quot;`
+        let value = attribute.unescape_value_with(quick_xml::escape::resolve_xml_entity)?;
+        if ANIMATIONS.contains(&name.as_str())
+            && key == "attributeName"
+            && !is_animation_target_allowed(&value)
+        {
+            return Ok(None);
+        }
+        if is_attribute_allowed(&name, &key, &value) {
+            tag.push_str(&format!(" {key}=\"{}\"", escape(&value, true)));
+        }
+    }
+    Ok(Some((name, tag)))
+}
+
+fn is_attribute_allowed(element: &str, key: &str, value: &str) -> bool {
+    if !ATTRIBUTES.contains(&key) {
+        return false;
+    }
+    let value = value
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if key == "href" || key == "xlink:href" {
+        return value.starts_with('#') || (element == "image" && is_raster_data_url(&value));
+    }
+    if value.contains("javascript:")
+        || value
+            .split("url(")
+            .skip(1)
+            .any(|u| !u.trim_start_matches(['"', '\'']).starts_with('#'))
+    {
+        return false;
+    }
+    key != "style"
+        || !["expression(", "@import", "\\", "<"]
+            .iter()
+            .any(|s| value.contains(s))
+}
+
+fn is_animation_target_allowed(value: &str) -> bool {
+    let value = value.trim().to_ascii_lowercase();
+    !(value.ends_with("href") || value.starts_with("on") || value == "style")
+}
+
+fn is_raster_data_url(value: &str) -> bool {
+    ["png", "jpeg", "jpg", "gif", "webp"]
+        .iter()
+        .any(|t| value.starts_with(format!("data:image/{t};").as_str()))
+}
+
+fn escape(value: &str, attribute: bool) -> String {
+    let value = value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    if attribute {
+        value.replace('"', "&quot;")
+    } else {
+        value
+    }
+}
+
+/// The lists used by `fastn_utils.sanitizeSvg` in the browser.
+pub fn allowlist_js() -> String {
+    let list = |items: &[&str]| {
+        items
+            .iter()
+            .map(|v| format!("\"{v}\""))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!(
+        "const fastn_svg_allowlist = {{ elements: [{}], animations: [{}], attributes: [{}] }};\n",
+        list(ELEMENTS),
+        list(ANIMATIONS),
+        list(ATTRIBUTES)
+    )
+}
+
+/// For `ssr`, which calls it from `fastn_utils.sanitizeSvg`.
+pub(crate) fn sanitize_svg_or_empty(svg: String) -> String {
+    sanitize_svg(svg.as_str()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    #[track_caller]
+    fn s(svg: &str) -> String {
+        super::sanitize_svg(svg).unwrap()
+    }
+
+    #[test]
+    fn keeps_drawing() {
+        let svg = r##"<?xml version="1.0"?><!-- logo --><svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><defs><linearGradient id="g"><stop offset="0" stop-color="red"/></linearGradient></defs><path d="M0 0h24v24H0z" fill="url(#g)"/><use href="#g"/><title>A &amp; B</title></svg>"##;
+        assert_eq!(
+            s(svg),
+            r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24"><defs><linearGradient id="g"><stop offset="0" stop-color="red"/></linearGradient></defs><path d="M0 0h24v24H0z" fill="url(#g)"/><use href="#g"/><title>A &amp; B</title></svg>"##
+        );
+    }
+
+    #[test]
+    fn drops_scripts() {
+        assert_eq!(
+            s(
+                r#"<svg><script>alert(1)</script><foreignObject><img src="x" onerror="alert(1)"/></foreignObject><g/></svg>"#
+            ),
+            "<svg><g/></svg>"
+        );
+        assert_eq!(
+            s(
+                r#"<svg onload="alert(1)" OnLoad="alert(1)"><rect onclick="alert(1)" width="1"/></svg>"#
+            ),
+            r#"<svg><rect width="1"/></svg>"#
+        );
+        assert_eq!(
+            s("<svg><style>@import url(https://x)</style><a href=\"javascript:alert(1)\"><text>x</text></a></svg>"),
+            "<svg></svg>"
+        );
+    }
+
+    #[test]
+    fn drops_links_out_of_the_svg() {
+        assert_eq!(
+            s(
+                r#"<svg><use href="javascript:alert(1)"/><use xlink:href=" java&#x09;script:alert(1)"/><image href="https://x/y.png"/></svg>"#
+            ),
+            "<svg><use/><use/><image/></svg>"
+        );
+        assert_eq!(
+            s(
+                r#"<svg><image href="data:image/png;base64,AAAA"/><image href="data:image/svg+xml;base64,AAAA"/></svg>"#
+            ),
+            r#"<svg><image href="data:image/png;base64,AAAA"/><image/></svg>"#
+        );
+        assert_eq!(
+            s(
+                r#"<svg><rect fill="url(https://x)" style="fill: url('#g')"/><rect style="background: url(https://x)"/></svg>"#
+            ),
+            r#"<svg><rect style="fill: url('#g')"/><rect/></svg>"#
+        );
+    }
+
+    #[test]
+    fn drops_animations_of_links() {
+        assert_eq!(
+            s(
+                r##"<svg><a><set attributeName="href" to="javascript:alert(1)"/></a><set attributeName=" xlink:href" to="javascript:alert(1)"/><animate attributeName="href" values="javascript:alert(1)"/><animate attributeName="onbegin" to="alert(1)"/><animate attributeName="opacity" values="0;1" dur="1s"/></svg>"##
+            ),
+            r#"<svg><animate attributeName="opacity" values="0;1" dur="1s"/></svg>"#
+        );
+    }
+
+    #[test]
+    fn escapes_text() {
+        // `title` is html inside an svg in an html page
+        assert_eq!(
+            s("<svg><title><![CDATA[<img src=x onerror=alert(1)>]]></title></svg>"),
+            "<svg><title>&lt;img src=x onerror=alert(1)&gt;</title></svg>"
+        );
+        assert_eq!(
+            s(r#"<svg><rect id="&quot;&gt;&lt;img src=x onerror=alert(1)&gt;"/></svg>"#),
+            r#"<svg><rect id="&quot;&gt;&lt;img src=x onerror=alert(1)&gt;"/></svg>"#
+        );
+    }
+
+    #[test]
+    fn drops_attributes_glued_with_slash() {
+        // xml reads `/onload` as the name of the attribute, which is not allowed,
+        // and the attributes that are kept are written out again with spaces
+        assert_eq!(
+            s(r#"<svg><rect x="x"/onload="alert(1)"/></svg>"#),
+            r#"<svg><rect x="x"/></svg>"#
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_svg() {
+        // html parsers read `/` as a separator, `<svg><g/onload=alert(1)>`
+        assert!(super::sanitize_svg("<svg><g/onload=alert(1)></svg>").is_err());
+        assert!(super::sanitize_svg("<svg><rect onload=alert(1)/></svg>").is_err());
+        assert!(super::sanitize_svg("<img src=x onerror=alert(1)>").is_err());
+        assert!(
+            super::sanitize_svg(r#"<!DOCTYPE svg [<!ENTITY x "<script>">]><svg>&x;</svg>"#)
+                .is_err()
+        );
+    }
+}
@@ -43,6 +43,15 @@ impl fastn_js::Ast {
                 ),
                 true,
             ),
+            fastn_js::Ast::ServerSentEvents { name, url } => text(
+                format!(
+                    "fastn_utils.subscribe({}, {}[\"{}\"]);",
+                    fastn_js::Value::String(url.to_string()).to_js(&None),
+                    &fastn_js::constants::GLOBAL_VARIABLE_MAP,
+                    fastn_js::utils::name_to_js(name)
+                )
+                .as_str(),
+            ),
         }
     }
 }
@@ -129,6 +138,26 @@ impl fastn_js::Event {
             fastn_js::Event::Change => text("fastn_dom.Event.Change"),
             fastn_js::Event::Blur => text("fastn_dom.Event.Blur"),
             fastn_js::Event::Focus => text("fastn_dom.Event.Focus"),
+            fastn_js::Event::Interval(ms) => {
+                text(format!("fastn_dom.Event.Interval({})", ms).as_str())
+            }
+            fastn_js::Event::Timeout(ms) => {
+                text(format!("fastn_dom.Event.Timeout({})", ms).as_str())
+            }
+            fastn_js::Event::Visible => text("fastn_dom.Event.Visible"),
+            fastn_js::Event::Hidden => text("fastn_dom.Event.Hidden"),
+            fastn_js::Event::Scroll(ms) => text(format!("fastn_dom.Event.Scroll({})", ms).as_str()),
+            fastn_js::Event::Submit => text("fastn_dom.Event.Submit"),
+            fastn_js::Event::KeyDown(keys) => text(
+                format!(
+                    "fastn_dom.Event.KeyDown([{}])",
+                    keys.iter()
+                        .map(|v| format!("\"{}\"", v))
+                        .collect_vec()
+                        .join(", ")
+                )
+                .as_str(),
+            ),
         }
     }
 }
@@ -191,6 +220,17 @@ impl fastn_js::ElementKind {
             fastn_js::ElementKind::Device => "fastn_dom.ElementKind.Wrapper".to_string(),
             fastn_js::ElementKind::CheckBox => "fastn_dom.ElementKind.CheckBox".to_string(),
             fastn_js::ElementKind::TextInput => "fastn_dom.ElementKind.TextInput".to_string(),
+            fastn_js::ElementKind::Select => "fastn_dom.ElementKind.Select".to_string(),
+            fastn_js::ElementKind::Radio => "fastn_dom.ElementKind.Radio".to_string(),
+            fastn_js::ElementKind::Textarea => "fastn_dom.ElementKind.Textarea".to_string(),
+            fastn_js::ElementKind::FileInput => "fastn_dom.ElementKind.FileInput".to_string(),
+            fastn_js::ElementKind::Form => "fastn_dom.ElementKind.Form".to_string(),
+            fastn_js::ElementKind::Table => "fastn_dom.ElementKind.Table".to_string(),
+            fastn_js::ElementKind::TableRow => "fastn_dom.ElementKind.TableRow".to_string(),
+            fastn_js::ElementKind::Grid => "fastn_dom.ElementKind.Grid".to_string(),
+            fastn_js::ElementKind::Svg => "fastn_dom.ElementKind.Svg".to_string(),
+            fastn_js::ElementKind::Icon => "fastn_dom.ElementKind.Icon".to_string(),
+            fastn_js::ElementKind::Math => "fastn_dom.ElementKind.Math".to_string(),
             fastn_js::ElementKind::Rive => "fastn_dom.ElementKind.Rive".to_string(),
             fastn_js::ElementKind::Audio => "fastn_dom.ElementKind.Audio".to_string(),
             fastn_js::ElementKind::Document => "fastn_dom.ElementKind.Document".to_string(),
@@ -395,6 +435,11 @@ impl fastn_js::ConditionalComponent {
 
 impl fastn_js::ForLoop {
     pub fn to_js(&self) -> pretty::RcDoc<'static> {
+        // `key: $item.id`
+        let key = match self.key {
+            Some(ref key) => format!(", function (item, index) {{ return {}; }}", key.to_js()),
+            None => "".to_string(),
+        };
         text(
             format!(
                 "{}{}.forLoop(",
@@ -416,7 +461,8 @@ impl fastn_js::ForLoop {
         )
         .append(text(
             format!(
-                "}}){};",
+                "}}{}){};",
+                key,
                 if self.should_return {
                     ".getParent()"
                 } else {
@@ -1220,6 +1220,39 @@ impl Language {
         self.to_2_letter_code()
     }
 
+    /// POSIX locale name (`fr_FR`, `hi_IN`, ...) used for date and number
+    /// formatting. Languages without an obvious default region use the code
+    /// repeated as the region, `xx_XX`.
+    pub fn to_locale(&self) -> String {
+        let region = match self {
+            Language::English => "US",
+            Language::Arabic => "SA",
+            Language::Bengali => "IN",
+            Language::Chinese => "CN",
+            Language::Czech => "CZ",
+            Language::Danish => "DK",
+            Language::Greek => "GR",
+            Language::Persian => "IR",
+            Language::Gujarati => "IN",
+            Language::Hebrew => "IL",
+            Language::Hindi => "IN",
+            Language::Japanese => "JP",
+            Language::Korean => "KR",
+            Language::Marathi => "IN",
+            Language::Malay => "MY",
+            Language::Punjabi => "IN",
+            Language::Portuguese => "BR",
+            Language::Swedish => "SE",
+            Language::Tamil => "IN",
+            Language::Telugu => "IN",
+            Language::Ukrainian => "UA",
+            Language::Urdu => "PK",
+            Language::Vietnamese => "VN",
+            t => return format!("{0}_{1}", t.id(), t.id().to_uppercase()),
+        };
+        format!("{}_{}", self.id(), region)
+    }
+
     pub fn from_accept_language_header(h: Option<String>, default: Self) -> Self {
         if let Some(v) = h {
             for code in accept_language::parse(v.as_str()).iter() {
@@ -1280,6 +1313,13 @@ mod tests {
         )
     }
 
+    #[test]
+    fn locale() {
+        assert_eq!(super::Language::English.to_locale(), "en_US");
+        assert_eq!(super::Language::Hindi.to_locale(), "hi_IN");
+        assert_eq!(super::Language::French.to_locale(), "fr_FR");
+    }
+
     #[test]
     fn test() {
         f("en-US, en-GB;q=0.5", super::Language::English);
@@ -102,6 +102,8 @@ pub struct PackageTemp {
     pub system: Option<String>,
     #[serde(rename = "system-is-confidential")]
     pub system_is_confidential: Option<bool>,
+    pub spa: bool,
+    pub persist: Vec<String>,
     #[serde(rename = "default-language")]
     pub default_language: Option<String>,
     pub lang: Option<String>,
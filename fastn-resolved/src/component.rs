@@ -33,6 +33,9 @@ pub struct Loop {
     pub on: fastn_resolved::PropertyValue,
     pub alias: String,
     pub loop_counter_alias: Option<String>,
+    /// Identifies the item of each node, see `ftd_ast::Loop::key`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub key: Option<fastn_resolved::PropertyValue>,
     pub line_number: usize,
 }
 
@@ -48,6 +51,7 @@ impl Loop {
             alias: alias.to_string(),
             line_number,
             loop_counter_alias,
+            key: None,
         }
     }
 }
@@ -122,6 +126,28 @@ pub enum EventName {
     RivePlay(String),
     RiveStateChange(String),
     RivePause(String),
+    /// `on-interval[1000]`, every given milliseconds
+    Interval(i64),
+    /// `on-timeout[500]`, once, the given milliseconds after the element is
+    /// created
+    Timeout(i64),
+    /// The element comes into the viewport
+    Visible,
+    /// The element leaves the viewport, after being visible
+    Hidden,
+    /// `on-scroll` of the element, at most once in the given milliseconds,
+    /// `on-scroll[200]`
+    Scroll(i64),
+    /// A form submitted in the element
+    Submit,
+    /// `on-keydown[ctrl-enter]`, the keys pressed while the focus is in the
+    /// element
+    KeyDown(Vec<String>),
+}
+
+impl EventName {
+    /// `on-scroll` without the throttle duration
+    pub const DEFAULT_SCROLL_THROTTLE: i64 = 100;
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -5,6 +5,8 @@ pub enum Kind {
     Integer,
     Decimal,
     Boolean,
+    DateTime,
+    Date,
     Record {
         name: String,
     }, // the full name of the record (full document name.record name)
@@ -19,6 +21,10 @@ pub enum Kind {
     Optional {
         kind: Box<Kind>,
     },
+    Map {
+        key: Box<Kind>,
+        value: Box<Kind>,
+    },
     UI {
         name: Option<String>,
         subsection_source: bool,
@@ -39,11 +45,14 @@ impl Kind {
             Kind::Integer { .. } => "integer".to_string(),
             Kind::Boolean { .. } => "boolean".to_string(),
             Kind::Decimal { .. } => "decimal".to_string(),
+            Kind::DateTime => "datetime".to_string(),
+            Kind::Date => "date".to_string(),
             Kind::Constant { .. } => "constant".to_string(),
             Kind::List { .. } => "list".to_string(),
             Kind::Object { .. } => "object".to_string(),
             Kind::OrType { name, .. } => name.clone(),
             Kind::Optional { .. } => "optional".to_string(),
+            Kind::Map { .. } => "map".to_string(),
             Kind::Void { .. } => "void".to_string(),
             Kind::Module => "module".to_string(),
             Kind::KwArgs => "kw-args".to_string(),
@@ -55,10 +64,15 @@ impl Kind {
     pub fn is_same_as(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::UI { .. }, Self::UI { .. }) => true,
+            // `object` takes any record, e.g. the rows of `ftd.table`
+            (Self::Object, Self::Record { .. }) | (Self::Record { .. }, Self::Object) => true,
             (Self::OrType { name: n1, .. }, Self::OrType { name: n2, .. }) => n1.eq(n2),
             (Self::Optional { kind, .. }, _) => kind.is_same_as(other),
             (_, Self::Optional { kind: other, .. }) => self.is_same_as(other),
             (Self::List { kind: k1 }, Self::List { kind: k2 }) => k1.is_same_as(k2),
+            (Self::Map { key: k1, value: v1 }, Self::Map { key: k2, value: v2 }) => {
+                k1.is_same_as(k2) && v1.is_same_as(v2)
+            }
             _ => self.eq(other),
         }
     }
@@ -83,6 +97,14 @@ impl Kind {
         Kind::Boolean
     }
 
+    pub fn datetime() -> Kind {
+        Kind::DateTime
+    }
+
+    pub fn date() -> Kind {
+        Kind::Date
+    }
+
     pub fn module() -> Kind {
         Kind::Module
     }
@@ -159,6 +181,13 @@ impl Kind {
         }
     }
 
+    pub fn map(key: Kind, value: Kind) -> Kind {
+        Kind::Map {
+            key: Box::new(key),
+            value: Box::new(value),
+        }
+    }
+
     pub fn into_optional(self) -> Kind {
         Kind::Optional {
             kind: Box::new(self),
@@ -204,6 +233,17 @@ impl Kind {
         matches!(self, Kind::List { .. })
     }
 
+    pub fn is_map(&self) -> bool {
+        matches!(self, Kind::Map { .. })
+    }
+
+    pub fn get_map_kinds(&self) -> Option<(&Kind, &Kind)> {
+        match self {
+            Kind::Map { key, value } => Some((key, value)),
+            _ => None,
+        }
+    }
+
     pub fn is_subsection_ui(&self) -> bool {
         matches!(
             self,
@@ -254,6 +294,14 @@ impl Kind {
         matches!(self, Kind::Decimal { .. })
     }
 
+    pub fn is_datetime(&self) -> bool {
+        matches!(self, Kind::DateTime)
+    }
+
+    pub fn is_date(&self) -> bool {
+        matches!(self, Kind::Date)
+    }
+
     pub fn is_void(&self) -> bool {
         matches!(self, Kind::Void { .. })
     }
@@ -327,6 +375,10 @@ impl KindData {
         self.kind.is_list()
     }
 
+    pub fn is_map(&self) -> bool {
+        self.kind.is_map()
+    }
+
     pub fn is_or_type(&self) -> bool {
         self.kind.is_or_type()
     }
@@ -375,6 +427,14 @@ impl KindData {
         self.kind.is_decimal()
     }
 
+    pub fn is_datetime(&self) -> bool {
+        self.kind.is_datetime()
+    }
+
+    pub fn is_date(&self) -> bool {
+        self.kind.is_date()
+    }
+
     pub fn is_void(&self) -> bool {
         self.kind.is_void()
     }
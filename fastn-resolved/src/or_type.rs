@@ -3,6 +3,10 @@ pub struct OrType {
     pub name: String,
     pub variants: Vec<fastn_resolved::OrTypeVariant>,
     pub line_number: usize,
+    /// A component of the document `match`es on a value of this or-type, so
+    /// its values carrying a value know their variant at runtime.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub matched: bool,
 }
 
 impl fastn_resolved::OrType {
@@ -15,6 +19,7 @@ impl fastn_resolved::OrType {
             name: name.to_string(),
             variants,
             line_number,
+            matched: false,
         }
     }
 
@@ -22,6 +22,11 @@ pub struct Field {
     pub value: Option<fastn_resolved::PropertyValue>,
     pub line_number: usize,
     pub access_modifier: AccessModifier,
+    /// A `slot` argument of a component, which the caller has to fill even though it
+    /// is a list. Kept here rather than on the kind, so a slot is the same kind as
+    /// any other `ftd.ui list`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_slot: bool,
 }
 
 impl Field {
@@ -39,6 +44,7 @@ impl Field {
             value,
             line_number,
             access_modifier: Default::default(),
+            is_slot: false,
         }
     }
 
@@ -70,6 +76,7 @@ impl Field {
             value: None,
             line_number: 0,
             access_modifier: Default::default(),
+            is_slot: false,
         }
     }
 
@@ -85,6 +92,7 @@ impl Field {
             value: Some(value),
             line_number: 0,
             access_modifier: Default::default(),
+            is_slot: false,
         }
     }
 
@@ -101,7 +109,7 @@ impl Field {
     }
 
     pub fn is_value_required(&self) -> bool {
-        if self.kind.is_optional() || self.kind.is_list() {
+        if self.kind.is_optional() || (self.kind.is_list() && !self.is_slot) {
             return false;
         }
         self.value.is_none()
@@ -162,6 +162,14 @@ pub enum Value {
     Boolean {
         value: bool,
     },
+    /// Milliseconds since the unix epoch, in UTC.
+    DateTime {
+        value: i64,
+    },
+    /// Milliseconds since the unix epoch at UTC midnight of the day.
+    Date {
+        value: i64,
+    },
     Object {
         values: fastn_resolved::Map<PropertyValue>,
     },
@@ -186,6 +194,13 @@ pub enum Value {
         data: Box<Option<Value>>,
         kind: fastn_resolved::KindData,
     },
+    /// Keys are stored in their string form, `key` keeps the declared key kind
+    /// and `kind` is the kind of the values.
+    Map {
+        data: fastn_resolved::Map<PropertyValue>,
+        key: fastn_resolved::Kind,
+        kind: fastn_resolved::KindData,
+    },
     UI {
         name: String,
         kind: fastn_resolved::KindData,
@@ -246,10 +261,15 @@ impl Value {
             Value::Integer { .. } => fastn_resolved::Kind::integer(),
             Value::Decimal { .. } => fastn_resolved::Kind::decimal(),
             Value::Boolean { .. } => fastn_resolved::Kind::boolean(),
+            Value::DateTime { .. } => fastn_resolved::Kind::datetime(),
+            Value::Date { .. } => fastn_resolved::Kind::date(),
             Value::Object { .. } => fastn_resolved::Kind::object(),
             Value::Record { name, .. } => fastn_resolved::Kind::record(name),
             Value::KwArgs { .. } => fastn_resolved::Kind::kwargs(),
             Value::List { kind, .. } => kind.kind.clone().into_list(),
+            Value::Map { key, kind, .. } => {
+                fastn_resolved::Kind::map(key.clone(), kind.kind.clone())
+            }
             Value::Optional { kind, .. } => fastn_resolved::Kind::Optional {
                 kind: Box::new(kind.kind.clone()),
             },
@@ -313,12 +333,11 @@ impl Value {
     }
 
     pub fn is_empty(&self) -> bool {
-        if let Self::List { data, .. } = self {
-            if data.is_empty() {
-                return true;
-            }
+        match self {
+            Self::List { data, .. } => data.is_empty(),
+            Self::Map { data, .. } => data.is_empty(),
+            _ => false,
         }
-        false
     }
 
     pub fn is_equal(&self, other: &Self) -> bool {
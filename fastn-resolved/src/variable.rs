@@ -7,6 +7,10 @@ pub struct Variable {
     pub conditional_value: Vec<ConditionalValue>,
     pub line_number: usize,
     pub is_static: bool,
+    /// `$sse$: <url>`, the server-sent events stream the js runtime subscribes
+    /// to for updates of this variable
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sse: Option<String>,
 }
 
 impl Variable {
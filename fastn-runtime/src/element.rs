@@ -19,7 +19,17 @@ pub enum Element {
     Device(Device),
     CheckBox(CheckBox),
     TextInput(TextInput),
+    Select(Select),
+    Radio(Radio),
+    Textarea(Textarea),
+    FileInput(FileInput),
+    Form(Form),
+    Table(Table),
+    TableRow(TableRow),
+    Grid(Grid),
     Iframe(Iframe),
+    Svg(Svg),
+    Math(Math),
     Code(Code),
     Rive(Rive),
     Document(Document),
@@ -36,6 +46,10 @@ impl Element {
             "ftd#decimal" => Element::Decimal(Decimal::from(component)),
             "ftd#boolean" => Element::Boolean(Boolean::from(component)),
             "ftd#column" => Element::Column(Column::from(component)),
+            "ftd#form" => Element::Form(Form::from(component)),
+            "ftd#table" => Element::Table(Table::from(component)),
+            "ftd#table-row" => Element::TableRow(TableRow::from(component)),
+            "ftd#grid" => Element::Grid(Grid::from(component)),
             "ftd#row" => Element::Row(Row::from(component)),
             "ftd#container" => Element::Container(ContainerElement::from(component)),
             "ftd#image" => Element::Image(Image::from(component)),
@@ -43,7 +57,13 @@ impl Element {
             "ftd#audio" => Element::Audio(Audio::from(component)),
             "ftd#checkbox" => Element::CheckBox(CheckBox::from(component)),
             "ftd#text-input" => Element::TextInput(TextInput::from(component)),
+            "ftd#select" => Element::Select(Select::from(component)),
+            "ftd#radio" => Element::Radio(Radio::from(component)),
+            "ftd#textarea" => Element::Textarea(Textarea::from(component)),
+            "ftd#file-input" => Element::FileInput(FileInput::from(component)),
             "ftd#iframe" => Element::Iframe(Iframe::from(component)),
+            "ftd#svg" | "ftd#icon" => Element::Svg(Svg::from(component)),
+            "ftd#math" => Element::Math(Math::from(component)),
             "ftd#code" => Element::Code(Code::from(component, doc)),
             "ftd#desktop" | "ftd#mobile" => {
                 Element::Device(Device::from(component, component.name.as_str()))
@@ -85,6 +105,38 @@ impl Element {
                 should_return,
                 has_rive_components,
             ),
+            Element::Form(form) => form.to_component_statements(
+                parent,
+                index,
+                doc,
+                &mut rdata,
+                should_return,
+                has_rive_components,
+            ),
+            Element::Table(table) => table.to_component_statements(
+                parent,
+                index,
+                doc,
+                &mut rdata,
+                should_return,
+                has_rive_components,
+            ),
+            Element::TableRow(row) => row.to_component_statements(
+                parent,
+                index,
+                doc,
+                &mut rdata,
+                should_return,
+                has_rive_components,
+            ),
+            Element::Grid(grid) => grid.to_component_statements(
+                parent,
+                index,
+                doc,
+                &mut rdata,
+                should_return,
+                has_rive_components,
+            ),
             Element::Document(document) => document.to_component_statements(
                 parent,
                 index,
@@ -132,9 +184,27 @@ impl Element {
             Element::TextInput(t) => {
                 t.to_component_statements(parent, index, doc, &mut rdata, should_return)
             }
+            Element::Select(s) => {
+                s.to_component_statements(parent, index, doc, &mut rdata, should_return)
+            }
+            Element::Radio(r) => {
+                r.to_component_statements(parent, index, doc, &mut rdata, should_return)
+            }
+            Element::Textarea(t) => {
+                t.to_component_statements(parent, index, doc, &mut rdata, should_return)
+            }
+            Element::FileInput(f) => {
+                f.to_component_statements(parent, index, doc, &mut rdata, should_return)
+            }
             Element::Iframe(i) => {
                 i.to_component_statements(parent, index, doc, &mut rdata, should_return)
             }
+            Element::Svg(s) => {
+                s.to_component_statements(parent, index, doc, &mut rdata, should_return)
+            }
+            Element::Math(m) => {
+                m.to_component_statements(parent, index, doc, &mut rdata, should_return)
+            }
             Element::Code(c) => {
                 c.to_component_statements(parent, index, doc, &mut rdata, should_return)
             }
@@ -147,6 +217,7 @@ impl Element {
 
 #[derive(Debug)]
 pub struct CheckBox {
+    pub name: Option<fastn_runtime::Value>,
     pub enabled: Option<fastn_runtime::Value>,
     pub checked: Option<fastn_runtime::Value>,
     pub common: Common,
@@ -162,6 +233,11 @@ impl CheckBox {
             .unwrap();
 
         CheckBox {
+            name: fastn_runtime::value::get_optional_js_value(
+                "name",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
             enabled: fastn_runtime::value::get_optional_js_value(
                 "enabled",
                 component.properties.as_slice(),
@@ -218,6 +294,17 @@ impl CheckBox {
             ));
         }
 
+        if let Some(ref name) = self.name {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                name.to_set_property(
+                    fastn_js::PropertyKind::Name,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
         if should_return {
             component_statements.push(fastn_js::ComponentStatement::Return {
                 component_name: kernel.name,
@@ -229,6 +316,7 @@ impl CheckBox {
 
 #[derive(Debug)]
 pub struct TextInput {
+    pub name: Option<fastn_runtime::Value>,
     pub placeholder: Option<fastn_runtime::Value>,
     pub multiline: Option<fastn_runtime::Value>,
     pub max_length: Option<fastn_runtime::Value>,
@@ -249,6 +337,11 @@ impl TextInput {
             .unwrap();
 
         TextInput {
+            name: fastn_runtime::value::get_optional_js_value(
+                "name",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
             placeholder: fastn_runtime::value::get_optional_js_value(
                 "placeholder",
                 component.properties.as_slice(),
@@ -380,6 +473,17 @@ impl TextInput {
             ));
         }
 
+        if let Some(ref name) = self.name {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                name.to_set_property(
+                    fastn_js::PropertyKind::Name,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
         if should_return {
             component_statements.push(fastn_js::ComponentStatement::Return {
                 component_name: kernel.name,
@@ -389,49 +493,111 @@ impl TextInput {
     }
 }
 
+/// Form elements pick `color`, `background` and `border-color` from
+/// `ftd#default-colors` (through `inherited.colors`) unless they are set.
+fn default_form_colors(
+    common: &Common,
+    element_name: &str,
+    rdata: &fastn_runtime::ResolverData,
+) -> Vec<fastn_js::ComponentStatement> {
+    let reference = |name: &str| {
+        fastn_js::SetPropertyValue::Reference(fastn_runtime::utils::update_reference(name, rdata))
+    };
+    let set_property = |kind: fastn_js::PropertyKind, value: fastn_js::SetPropertyValue| {
+        fastn_js::ComponentStatement::SetProperty(fastn_js::SetProperty {
+            kind,
+            value,
+            element_name: element_name.to_string(),
+            inherited: rdata.inherited_variable_name.to_string(),
+        })
+    };
+
+    let mut component_statements = vec![];
+    if common.color.is_none() {
+        component_statements.push(set_property(
+            fastn_js::PropertyKind::Color,
+            reference("inherited.colors.text"),
+        ));
+    }
+    if common.background.is_none() {
+        component_statements.push(set_property(
+            fastn_js::PropertyKind::Background,
+            fastn_js::SetPropertyValue::Value(fastn_js::Value::OrType {
+                variant: "fastn_dom.BackgroundStyle.Solid".to_string(),
+                value: Some(Box::new(reference("inherited.colors.background.step-1"))),
+            }),
+        ));
+    }
+    if common.border_color.is_none() {
+        component_statements.push(set_property(
+            fastn_js::PropertyKind::BorderColor,
+            reference("inherited.colors.border"),
+        ));
+    }
+    component_statements
+}
+
 #[derive(Debug)]
-pub struct Iframe {
+pub struct Select {
+    pub name: Option<fastn_runtime::Value>,
+    pub options: Option<fastn_runtime::Value>,
+    pub value: Option<fastn_runtime::Value>,
+    pub values: Option<fastn_runtime::Value>,
+    pub multiple: Option<fastn_runtime::Value>,
+    pub placeholder: Option<fastn_runtime::Value>,
+    pub enabled: Option<fastn_runtime::Value>,
     pub common: Common,
-    pub src: Option<fastn_runtime::Value>,
-    pub srcdoc: Option<fastn_runtime::Value>,
-    pub youtube: Option<fastn_runtime::Value>,
-    pub loading: Option<fastn_runtime::Value>,
 }
 
-impl Iframe {
-    pub fn from(component: &fastn_resolved::ComponentInvocation) -> Iframe {
+impl Select {
+    pub fn from(component: &fastn_resolved::ComponentInvocation) -> Select {
         let component_definition = fastn_builtins::builtins()
-            .get("ftd#iframe")
+            .get("ftd#select")
             .unwrap()
             .clone()
             .component()
             .unwrap();
 
-        Iframe {
-            common: Common::from(
+        Select {
+            name: fastn_runtime::value::get_optional_js_value(
+                "name",
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
-                component.events.as_slice(),
             ),
-            src: fastn_runtime::value::get_optional_js_value(
-                "src",
+            options: fastn_runtime::value::get_optional_js_value(
+                "options",
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
             ),
-            srcdoc: fastn_runtime::value::get_optional_js_value(
-                "srcdoc",
+            value: fastn_runtime::value::get_optional_js_value(
+                "value",
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
             ),
-            loading: fastn_runtime::value::get_optional_js_value(
-                "loading",
+            values: fastn_runtime::value::get_optional_js_value(
+                "values",
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
             ),
-            youtube: fastn_runtime::value::get_optional_js_value(
-                "youtube",
+            multiple: fastn_runtime::value::get_optional_js_value(
+                "multiple",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            placeholder: fastn_runtime::value::get_optional_js_value(
+                "placeholder",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            enabled: fastn_runtime::value::get_optional_js_value(
+                "enabled",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Common::from(
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
+                component.events.as_slice(),
             ),
         }
     }
@@ -445,40 +611,75 @@ impl Iframe {
         should_return: bool,
     ) -> Vec<fastn_js::ComponentStatement> {
         let mut component_statements = vec![];
-        let kernel = create_element(fastn_js::ElementKind::IFrame, parent, index, rdata);
+        let kernel = create_element(fastn_js::ElementKind::Select, parent, index, rdata);
         component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(default_form_colors(
+            &self.common,
+            kernel.name.as_str(),
+            rdata,
+        ));
         component_statements.extend(self.common.to_set_properties(
             kernel.name.as_str(),
             doc,
             rdata,
         ));
 
-        if let Some(ref loading) = self.loading {
+        // `multiple` and `placeholder` are set before the options are rendered and the
+        // selection is set after, as both depend on the options.
+        if let Some(ref multiple) = self.multiple {
             component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                loading.to_set_property(
-                    fastn_js::PropertyKind::Loading,
+                multiple.to_set_property(
+                    fastn_js::PropertyKind::Multiple,
                     doc,
                     kernel.name.as_str(),
                     rdata,
                 ),
             ));
         }
-
-        if let Some(ref src) = self.src {
+        if let Some(ref placeholder) = self.placeholder {
             component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                src.to_set_property(
-                    fastn_js::PropertyKind::Src,
+                placeholder.to_set_property(
+                    fastn_js::PropertyKind::Placeholder,
                     doc,
                     kernel.name.as_str(),
                     rdata,
                 ),
             ));
         }
-
-        if let Some(ref srcdoc) = self.srcdoc {
+        if let Some(ref options) = self.options {
             component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                srcdoc.to_set_property(
-                    fastn_js::PropertyKind::SrcDoc,
+                options.to_set_property(
+                    fastn_js::PropertyKind::Options,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref value) = self.value {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                value.to_set_property(
+                    fastn_js::PropertyKind::SelectedValue,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref values) = self.values {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                values.to_set_property(
+                    fastn_js::PropertyKind::SelectedValues,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref enabled) = self.enabled {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                enabled.to_set_property(
+                    fastn_js::PropertyKind::Enabled,
                     doc,
                     kernel.name.as_str(),
                     rdata,
@@ -486,10 +687,10 @@ impl Iframe {
             ));
         }
 
-        if let Some(ref youtube) = self.youtube {
+        if let Some(ref name) = self.name {
             component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                youtube.to_set_property(
-                    fastn_js::PropertyKind::YoutubeSrc,
+                name.to_set_property(
+                    fastn_js::PropertyKind::Name,
                     doc,
                     kernel.name.as_str(),
                     rdata,
@@ -507,62 +708,780 @@ impl Iframe {
 }
 
 #[derive(Debug)]
-pub struct Code {
+pub struct Radio {
+    pub name: Option<fastn_runtime::Value>,
+    pub options: Option<fastn_runtime::Value>,
+    pub value: Option<fastn_runtime::Value>,
+    pub enabled: Option<fastn_runtime::Value>,
     pub common: Common,
-    pub text_common: TextCommon,
-    pub code: fastn_runtime::Value,
-    pub lang: fastn_runtime::Value,
-    pub theme: fastn_runtime::Value,
-    pub show_line_number: fastn_runtime::Value,
 }
 
-impl Code {
-    pub fn from(
-        component: &fastn_resolved::ComponentInvocation,
-        _doc: &dyn fastn_resolved::tdoc::TDoc,
-    ) -> Code {
+impl Radio {
+    pub fn from(component: &fastn_resolved::ComponentInvocation) -> Radio {
         let component_definition = fastn_builtins::builtins()
-            .get("ftd#code")
+            .get("ftd#radio")
             .unwrap()
             .clone()
             .component()
             .unwrap();
 
-        Code {
-            common: Common::from(
+        Radio {
+            name: fastn_runtime::value::get_optional_js_value(
+                "name",
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
-                component.events.as_slice(),
             ),
-            text_common: TextCommon::from(
+            options: fastn_runtime::value::get_optional_js_value(
+                "options",
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
             ),
-            // code: fastn_runtime::Value::from_str_value(stylized_code.as_str()),
-            code: fastn_runtime::value::get_optional_js_value(
-                "text",
+            value: fastn_runtime::value::get_optional_js_value(
+                "value",
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
-            )
-            .unwrap(),
-            lang: fastn_runtime::value::get_js_value_with_default(
-                "lang",
+            ),
+            enabled: fastn_runtime::value::get_optional_js_value(
+                "enabled",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &dyn fastn_resolved::tdoc::TDoc,
+        rdata: &mut fastn_runtime::ResolverData,
+        should_return: bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::Radio, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        if let Some(ref options) = self.options {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                options.to_set_property(
+                    fastn_js::PropertyKind::Options,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref value) = self.value {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                value.to_set_property(
+                    fastn_js::PropertyKind::SelectedValue,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref enabled) = self.enabled {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                enabled.to_set_property(
+                    fastn_js::PropertyKind::Enabled,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if let Some(ref name) = self.name {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                name.to_set_property(
+                    fastn_js::PropertyKind::Name,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+#[derive(Debug)]
+pub struct Textarea {
+    pub name: Option<fastn_runtime::Value>,
+    pub placeholder: Option<fastn_runtime::Value>,
+    pub value: Option<fastn_runtime::Value>,
+    pub default_value: Option<fastn_runtime::Value>,
+    pub enabled: Option<fastn_runtime::Value>,
+    pub max_length: Option<fastn_runtime::Value>,
+    pub rows: Option<fastn_runtime::Value>,
+    pub auto_resize: Option<fastn_runtime::Value>,
+    pub text_common: TextCommon,
+    pub common: Common,
+}
+
+impl Textarea {
+    pub fn from(component: &fastn_resolved::ComponentInvocation) -> Textarea {
+        let component_definition = fastn_builtins::builtins()
+            .get("ftd#textarea")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        Textarea {
+            name: fastn_runtime::value::get_optional_js_value(
+                "name",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            placeholder: fastn_runtime::value::get_optional_js_value(
+                "placeholder",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            value: fastn_runtime::value::get_optional_js_value(
+                "value",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            default_value: fastn_runtime::value::get_optional_js_value(
+                "default-value",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            enabled: fastn_runtime::value::get_optional_js_value(
+                "enabled",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            max_length: fastn_runtime::value::get_optional_js_value(
+                "max-length",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            rows: fastn_runtime::value::get_optional_js_value(
+                "rows",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            auto_resize: fastn_runtime::value::get_optional_js_value(
+                "auto-resize",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            text_common: TextCommon::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &dyn fastn_resolved::tdoc::TDoc,
+        rdata: &mut fastn_runtime::ResolverData,
+        should_return: bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::Textarea, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(default_form_colors(
+            &self.common,
+            kernel.name.as_str(),
+            rdata,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+        component_statements.extend(self.text_common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        if let Some(ref placeholder) = self.placeholder {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                placeholder.to_set_property(
+                    fastn_js::PropertyKind::Placeholder,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref enabled) = self.enabled {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                enabled.to_set_property(
+                    fastn_js::PropertyKind::Enabled,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref max_length) = self.max_length {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                max_length.to_set_property(
+                    fastn_js::PropertyKind::InputMaxLength,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref rows) = self.rows {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                rows.to_set_property(
+                    fastn_js::PropertyKind::Rows,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref auto_resize) = self.auto_resize {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                auto_resize.to_set_property(
+                    fastn_js::PropertyKind::AutoResize,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref value) = self.value {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                value.to_set_property(
+                    fastn_js::PropertyKind::TextInputValue,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref default_value) = self.default_value {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                default_value.to_set_property(
+                    fastn_js::PropertyKind::DefaultTextInputValue,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if let Some(ref name) = self.name {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                name.to_set_property(
+                    fastn_js::PropertyKind::Name,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+#[derive(Debug)]
+pub struct FileInput {
+    pub name: Option<fastn_runtime::Value>,
+    pub accept: Option<fastn_runtime::Value>,
+    pub multiple: Option<fastn_runtime::Value>,
+    pub enabled: Option<fastn_runtime::Value>,
+    pub common: Common,
+}
+
+impl FileInput {
+    pub fn from(component: &fastn_resolved::ComponentInvocation) -> FileInput {
+        let component_definition = fastn_builtins::builtins()
+            .get("ftd#file-input")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        FileInput {
+            name: fastn_runtime::value::get_optional_js_value(
+                "name",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            accept: fastn_runtime::value::get_optional_js_value(
+                "accept",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            multiple: fastn_runtime::value::get_optional_js_value(
+                "multiple",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            enabled: fastn_runtime::value::get_optional_js_value(
+                "enabled",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &dyn fastn_resolved::tdoc::TDoc,
+        rdata: &mut fastn_runtime::ResolverData,
+        should_return: bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::FileInput, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(default_form_colors(
+            &self.common,
+            kernel.name.as_str(),
+            rdata,
+        ));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        if let Some(ref accept) = self.accept {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                accept.to_set_property(
+                    fastn_js::PropertyKind::Accept,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref multiple) = self.multiple {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                multiple.to_set_property(
+                    fastn_js::PropertyKind::Multiple,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref enabled) = self.enabled {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                enabled.to_set_property(
+                    fastn_js::PropertyKind::Enabled,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if let Some(ref name) = self.name {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                name.to_set_property(
+                    fastn_js::PropertyKind::Name,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+#[derive(Debug)]
+pub struct Iframe {
+    pub common: Common,
+    pub src: Option<fastn_runtime::Value>,
+    pub srcdoc: Option<fastn_runtime::Value>,
+    pub youtube: Option<fastn_runtime::Value>,
+    pub loading: Option<fastn_runtime::Value>,
+}
+
+impl Iframe {
+    pub fn from(component: &fastn_resolved::ComponentInvocation) -> Iframe {
+        let component_definition = fastn_builtins::builtins()
+            .get("ftd#iframe")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        Iframe {
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+            src: fastn_runtime::value::get_optional_js_value(
+                "src",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            srcdoc: fastn_runtime::value::get_optional_js_value(
+                "srcdoc",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            loading: fastn_runtime::value::get_optional_js_value(
+                "loading",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            youtube: fastn_runtime::value::get_optional_js_value(
+                "youtube",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &dyn fastn_resolved::tdoc::TDoc,
+        rdata: &mut fastn_runtime::ResolverData,
+        should_return: bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::IFrame, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        if let Some(ref loading) = self.loading {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                loading.to_set_property(
+                    fastn_js::PropertyKind::Loading,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if let Some(ref src) = self.src {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                src.to_set_property(
+                    fastn_js::PropertyKind::Src,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if let Some(ref srcdoc) = self.srcdoc {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                srcdoc.to_set_property(
+                    fastn_js::PropertyKind::SrcDoc,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if let Some(ref youtube) = self.youtube {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                youtube.to_set_property(
+                    fastn_js::PropertyKind::YoutubeSrc,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+/// `ftd.svg` and `ftd.icon` inline svg markup, `ftd.icon` gets its `content`
+/// from the icon sets at compile time.
+#[derive(Debug)]
+pub struct Svg {
+    pub kind: fastn_js::ElementKind,
+    pub common: Common,
+    pub content: Option<fastn_runtime::Value>,
+    pub fill: Option<fastn_runtime::Value>,
+    pub stroke: Option<fastn_runtime::Value>,
+}
+
+impl Svg {
+    pub fn from(component: &fastn_resolved::ComponentInvocation) -> Svg {
+        let component_definition = fastn_builtins::builtins()
+            .get(component.name.as_str())
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+        let value = |key: &str| {
+            fastn_runtime::value::get_optional_js_value(
+                key,
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            )
+        };
+
+        Svg {
+            kind: if component.name.eq("ftd#icon") {
+                fastn_js::ElementKind::Icon
+            } else {
+                fastn_js::ElementKind::Svg
+            },
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+            content: value("content"),
+            fill: value("fill"),
+            stroke: value("stroke"),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &dyn fastn_resolved::tdoc::TDoc,
+        rdata: &mut fastn_runtime::ResolverData,
+        should_return: bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(self.kind.clone(), parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        for (value, kind) in [
+            (&self.content, fastn_js::PropertyKind::SvgContent),
+            (&self.fill, fastn_js::PropertyKind::Fill),
+            (&self.stroke, fastn_js::PropertyKind::Stroke),
+        ] {
+            if let Some(value) = value {
+                component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                    value.to_set_property(kind, doc, kernel.name.as_str(), rdata),
+                ));
+            }
+        }
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+/// `ftd.math` shows the MathML fastn renders its LaTeX `text` to at compile
+/// time.
+#[derive(Debug)]
+pub struct Math {
+    pub common: Common,
+    pub text: fastn_runtime::Value,
+}
+
+impl Math {
+    pub fn from(component: &fastn_resolved::ComponentInvocation) -> Math {
+        let component_definition = fastn_builtins::builtins()
+            .get("ftd#math")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        Math {
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+            text: fastn_runtime::value::get_optional_js_value(
+                "text",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            )
+            .unwrap(),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &dyn fastn_resolved::tdoc::TDoc,
+        rdata: &mut fastn_runtime::ResolverData,
+        should_return: bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::Math, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+        component_statements.push(fastn_js::ComponentStatement::SetProperty(
+            self.text.to_set_property(
+                fastn_js::PropertyKind::MathContent,
+                doc,
+                kernel.name.as_str(),
+                rdata,
+            ),
+        ));
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+#[derive(Debug)]
+pub struct Code {
+    pub common: Common,
+    pub text_common: TextCommon,
+    pub code: fastn_runtime::Value,
+    pub lang: fastn_runtime::Value,
+    pub theme: fastn_runtime::Value,
+    pub show_line_number: fastn_runtime::Value,
+    pub highlight: Option<fastn_runtime::Value>,
+    pub diff: Option<fastn_runtime::Value>,
+    pub line_number_start: Option<fastn_runtime::Value>,
+    pub title: Option<fastn_runtime::Value>,
+}
+
+impl Code {
+    pub fn from(
+        component: &fastn_resolved::ComponentInvocation,
+        _doc: &dyn fastn_resolved::tdoc::TDoc,
+    ) -> Code {
+        let component_definition = fastn_builtins::builtins()
+            .get("ftd#code")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        Code {
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+            text_common: TextCommon::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            // code: fastn_runtime::Value::from_str_value(stylized_code.as_str()),
+            code: fastn_runtime::value::get_optional_js_value(
+                "text",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            )
+            .unwrap(),
+            lang: fastn_runtime::value::get_js_value_with_default(
+                "lang",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                fastn_runtime::Value::from_str_value("txt"),
+            ),
+            theme: fastn_runtime::value::get_js_value_with_default(
+                "theme",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                fastn_runtime::Value::from_str_value(fastn_runtime::CODE_DEFAULT_THEME),
+            ),
+            show_line_number: fastn_runtime::value::get_optional_js_value_with_default(
+                "show-line-number",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            )
+            .unwrap(),
+            highlight: fastn_runtime::value::get_optional_js_value(
+                "highlight",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            diff: fastn_runtime::value::get_optional_js_value(
+                "diff",
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
-                fastn_runtime::Value::from_str_value("txt"),
             ),
-            theme: fastn_runtime::value::get_js_value_with_default(
-                "theme",
+            line_number_start: fastn_runtime::value::get_optional_js_value(
+                "line-number-start",
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
-                fastn_runtime::Value::from_str_value(fastn_runtime::CODE_DEFAULT_THEME),
             ),
-            show_line_number: fastn_runtime::value::get_optional_js_value_with_default(
-                "show-line-number",
+            title: fastn_runtime::value::get_optional_js_value(
+                "title",
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
-            )
-            .unwrap(),
+            ),
         }
     }
 
@@ -578,6 +1497,51 @@ impl Code {
         let kernel = create_element(fastn_js::ElementKind::Code, parent, index, rdata);
         component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
 
+        // the options which change how the text is shown are set before the text
+        if let Some(ref highlight) = self.highlight {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                highlight.to_set_property(
+                    fastn_js::PropertyKind::CodeHighlight,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if let Some(ref diff) = self.diff {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                diff.to_set_property(
+                    fastn_js::PropertyKind::CodeDiff,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if let Some(ref line_number_start) = self.line_number_start {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                line_number_start.to_set_property(
+                    fastn_js::PropertyKind::CodeLineNumberStart,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        if let Some(ref title) = self.title {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                title.to_set_property(
+                    fastn_js::PropertyKind::CodeTitle,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
         component_statements.push(fastn_js::ComponentStatement::SetProperty(
             self.code.to_set_property(
                 fastn_js::PropertyKind::Code,
@@ -1088,6 +2052,52 @@ pub struct Column {
     pub common: Common,
 }
 
+/// `ftd.form` is a column that validates its `fields` and submits them to
+/// `action`.
+#[derive(Debug)]
+pub struct Form {
+    pub fields: Option<fastn_runtime::Value>,
+    pub action: Option<fastn_runtime::Value>,
+    pub method: Option<fastn_runtime::Value>,
+    pub encoding: Option<fastn_runtime::Value>,
+    pub container: Container,
+    pub container_properties: ContainerProperties,
+    pub common: Common,
+}
+
+/// `ftd.table` renders `data` as the rows of a `<table>`, one cell per entry
+/// of `columns`. Rows can instead be passed as `ftd.table-row` children.
+#[derive(Debug)]
+pub struct Table {
+    pub columns: Option<fastn_runtime::Value>,
+    pub data: Option<fastn_runtime::Value>,
+    pub sticky_header: Option<fastn_runtime::Value>,
+    pub sortable: Option<fastn_runtime::Value>,
+    pub page_size: Option<fastn_runtime::Value>,
+    pub container: Container,
+    pub common: Common,
+}
+
+/// `ftd.grid` lays out its children with css grid.
+#[derive(Debug)]
+pub struct Grid {
+    pub template_columns: Option<fastn_runtime::Value>,
+    pub template_rows: Option<fastn_runtime::Value>,
+    pub template_areas: Option<fastn_runtime::Value>,
+    pub auto_flow: Option<fastn_runtime::Value>,
+    pub gap: Option<fastn_runtime::Value>,
+    pub row_gap: Option<fastn_runtime::Value>,
+    pub column_gap: Option<fastn_runtime::Value>,
+    pub container: Container,
+    pub common: Common,
+}
+
+#[derive(Debug)]
+pub struct TableRow {
+    pub container: Container,
+    pub common: Common,
+}
+
 #[derive(Debug)]
 pub struct InheritedProperties {
     pub colors: Option<fastn_runtime::Value>,
@@ -1735,117 +2745,420 @@ impl DocumentMeta {
             );
         }
 
-        if let Some(ref twitter_title) = self.twitter_title {
-            self.set_property_value_with_self_reference(
-                twitter_title,
-                fastn_js::PropertyKind::MetaTwitterTitle,
-                &self.title,
-                &mut component_statements,
-                doc,
-                rdata,
-                element_name,
-            );
-        }
+        if let Some(ref twitter_title) = self.twitter_title {
+            self.set_property_value_with_self_reference(
+                twitter_title,
+                fastn_js::PropertyKind::MetaTwitterTitle,
+                &self.title,
+                &mut component_statements,
+                doc,
+                rdata,
+                element_name,
+            );
+        }
+
+        if let Some(ref description) = self.description {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                description.to_set_property(
+                    fastn_js::PropertyKind::MetaDescription,
+                    doc,
+                    element_name,
+                    rdata,
+                ),
+            ));
+        }
+
+        if let Some(ref og_description) = self.og_description {
+            self.set_property_value_with_self_reference(
+                og_description,
+                fastn_js::PropertyKind::MetaOGDescription,
+                &self.description,
+                &mut component_statements,
+                doc,
+                rdata,
+                element_name,
+            );
+        }
+
+        if let Some(ref twitter_description) = self.twitter_description {
+            self.set_property_value_with_self_reference(
+                twitter_description,
+                fastn_js::PropertyKind::MetaTwitterDescription,
+                &self.description,
+                &mut component_statements,
+                doc,
+                rdata,
+                element_name,
+            );
+        }
+
+        if let Some(ref og_image) = self.og_image {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                og_image.to_set_property(
+                    fastn_js::PropertyKind::MetaOGImage,
+                    doc,
+                    element_name,
+                    rdata,
+                ),
+            ));
+        }
+
+        if let Some(ref twitter_image) = self.twitter_image {
+            self.set_property_value_with_self_reference(
+                twitter_image,
+                fastn_js::PropertyKind::MetaTwitterImage,
+                &self.og_image,
+                &mut component_statements,
+                doc,
+                rdata,
+                element_name,
+            );
+        }
+
+        if let Some(ref theme_color) = self.theme_color {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                theme_color.to_set_property(
+                    fastn_js::PropertyKind::MetaThemeColor,
+                    doc,
+                    element_name,
+                    rdata,
+                ),
+            ));
+        }
+
+        if let Some(ref facebook_domain_verification) = self.facebook_domain_verification {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                facebook_domain_verification.to_set_property(
+                    fastn_js::PropertyKind::MetaFacebookDomainVerification,
+                    doc,
+                    element_name,
+                    rdata,
+                ),
+            ));
+        }
+
+        component_statements
+    }
+}
+
+impl Column {
+    pub fn from(component: &fastn_resolved::ComponentInvocation) -> Column {
+        let component_definition = fastn_builtins::builtins()
+            .get("ftd#column")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        Column {
+            container: Container::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            container_properties: ContainerProperties::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &dyn fastn_resolved::tdoc::TDoc,
+        rdata: &mut fastn_runtime::ResolverData,
+        should_return: bool,
+        has_rive_components: &mut bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::Column, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        component_statements.extend(self.container_properties.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        component_statements.extend(self.container.to_component_statements(
+            doc,
+            rdata,
+            has_rive_components,
+            false,
+        ));
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+impl Form {
+    pub fn from(component: &fastn_resolved::ComponentInvocation) -> Form {
+        let component_definition = fastn_builtins::builtins()
+            .get("ftd#form")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        Form {
+            fields: fastn_runtime::value::get_optional_js_value(
+                "fields",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            action: fastn_runtime::value::get_optional_js_value(
+                "action",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            method: fastn_runtime::value::get_optional_js_value(
+                "method",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            encoding: fastn_runtime::value::get_optional_js_value(
+                "encoding",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            container: Container::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            container_properties: ContainerProperties::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &dyn fastn_resolved::tdoc::TDoc,
+        rdata: &mut fastn_runtime::ResolverData,
+        should_return: bool,
+        has_rive_components: &mut bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::Form, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
 
-        if let Some(ref description) = self.description {
+        component_statements.extend(self.container_properties.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        if let Some(ref action) = self.action {
             component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                description.to_set_property(
-                    fastn_js::PropertyKind::MetaDescription,
+                action.to_set_property(
+                    fastn_js::PropertyKind::FormAction,
                     doc,
-                    element_name,
+                    kernel.name.as_str(),
                     rdata,
                 ),
             ));
         }
-
-        if let Some(ref og_description) = self.og_description {
-            self.set_property_value_with_self_reference(
-                og_description,
-                fastn_js::PropertyKind::MetaOGDescription,
-                &self.description,
-                &mut component_statements,
-                doc,
-                rdata,
-                element_name,
-            );
-        }
-
-        if let Some(ref twitter_description) = self.twitter_description {
-            self.set_property_value_with_self_reference(
-                twitter_description,
-                fastn_js::PropertyKind::MetaTwitterDescription,
-                &self.description,
-                &mut component_statements,
-                doc,
-                rdata,
-                element_name,
-            );
-        }
-
-        if let Some(ref og_image) = self.og_image {
+        if let Some(ref method) = self.method {
             component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                og_image.to_set_property(
-                    fastn_js::PropertyKind::MetaOGImage,
+                method.to_set_property(
+                    fastn_js::PropertyKind::FormMethod,
                     doc,
-                    element_name,
+                    kernel.name.as_str(),
                     rdata,
                 ),
             ));
         }
-
-        if let Some(ref twitter_image) = self.twitter_image {
-            self.set_property_value_with_self_reference(
-                twitter_image,
-                fastn_js::PropertyKind::MetaTwitterImage,
-                &self.og_image,
-                &mut component_statements,
-                doc,
-                rdata,
-                element_name,
-            );
-        }
-
-        if let Some(ref theme_color) = self.theme_color {
+        if let Some(ref encoding) = self.encoding {
             component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                theme_color.to_set_property(
-                    fastn_js::PropertyKind::MetaThemeColor,
+                encoding.to_set_property(
+                    fastn_js::PropertyKind::FormEncoding,
                     doc,
-                    element_name,
+                    kernel.name.as_str(),
                     rdata,
                 ),
             ));
         }
-
-        if let Some(ref facebook_domain_verification) = self.facebook_domain_verification {
+        if let Some(ref fields) = self.fields {
             component_statements.push(fastn_js::ComponentStatement::SetProperty(
-                facebook_domain_verification.to_set_property(
-                    fastn_js::PropertyKind::MetaFacebookDomainVerification,
+                fields.to_set_property(
+                    fastn_js::PropertyKind::FormFields,
                     doc,
-                    element_name,
+                    kernel.name.as_str(),
                     rdata,
                 ),
             ));
         }
 
+        component_statements.extend(self.container.to_component_statements(
+            doc,
+            rdata,
+            has_rive_components,
+            false,
+        ));
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
         component_statements
     }
 }
 
-impl Column {
-    pub fn from(component: &fastn_resolved::ComponentInvocation) -> Column {
+impl Table {
+    pub fn from(component: &fastn_resolved::ComponentInvocation) -> Table {
         let component_definition = fastn_builtins::builtins()
-            .get("ftd#column")
+            .get("ftd#table")
             .unwrap()
             .clone()
             .component()
             .unwrap();
 
-        Column {
+        Table {
+            columns: fastn_runtime::value::get_optional_js_value(
+                "columns",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            data: fastn_runtime::value::get_optional_js_value(
+                "data",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            sticky_header: fastn_runtime::value::get_optional_js_value(
+                "sticky-header",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            sortable: fastn_runtime::value::get_optional_js_value(
+                "sortable",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            page_size: fastn_runtime::value::get_optional_js_value(
+                "page-size",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
             container: Container::from(
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
             ),
-            container_properties: ContainerProperties::from(
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &dyn fastn_resolved::tdoc::TDoc,
+        rdata: &mut fastn_runtime::ResolverData,
+        should_return: bool,
+        has_rive_components: &mut bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::Table, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+        component_statements.extend(default_form_colors(
+            &self.common,
+            kernel.name.as_str(),
+            rdata,
+        ));
+
+        for (value, kind) in [
+            (&self.sticky_header, fastn_js::PropertyKind::StickyHeader),
+            (&self.sortable, fastn_js::PropertyKind::Sortable),
+            (&self.page_size, fastn_js::PropertyKind::PageSize),
+            (&self.columns, fastn_js::PropertyKind::TableColumns),
+            (&self.data, fastn_js::PropertyKind::TableData),
+        ] {
+            if let Some(value) = value {
+                component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                    value.to_set_property(kind, doc, kernel.name.as_str(), rdata),
+                ));
+            }
+        }
+
+        component_statements.extend(self.container.to_component_statements(
+            doc,
+            rdata,
+            has_rive_components,
+            false,
+        ));
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+impl Grid {
+    pub fn from(component: &fastn_resolved::ComponentInvocation) -> Grid {
+        let component_definition = fastn_builtins::builtins()
+            .get("ftd#grid")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+        let value = |key: &str| {
+            fastn_runtime::value::get_optional_js_value(
+                key,
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            )
+        };
+
+        Grid {
+            template_columns: value("template-columns"),
+            template_rows: value("template-rows"),
+            template_areas: value("template-areas"),
+            auto_flow: value("auto-flow"),
+            gap: value("gap"),
+            row_gap: value("row-gap"),
+            column_gap: value("column-gap"),
+            container: Container::from(
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
             ),
@@ -1867,7 +3180,7 @@ impl Column {
         has_rive_components: &mut bool,
     ) -> Vec<fastn_js::ComponentStatement> {
         let mut component_statements = vec![];
-        let kernel = create_element(fastn_js::ElementKind::Column, parent, index, rdata);
+        let kernel = create_element(fastn_js::ElementKind::Grid, parent, index, rdata);
         component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
         component_statements.extend(self.common.to_set_properties(
             kernel.name.as_str(),
@@ -1875,7 +3188,82 @@ impl Column {
             rdata,
         ));
 
-        component_statements.extend(self.container_properties.to_set_properties(
+        for (value, kind) in [
+            (
+                &self.template_columns,
+                fastn_js::PropertyKind::GridTemplateColumns,
+            ),
+            (
+                &self.template_rows,
+                fastn_js::PropertyKind::GridTemplateRows,
+            ),
+            (
+                &self.template_areas,
+                fastn_js::PropertyKind::GridTemplateAreas,
+            ),
+            (&self.auto_flow, fastn_js::PropertyKind::GridAutoFlow),
+            (&self.gap, fastn_js::PropertyKind::Gap),
+            (&self.row_gap, fastn_js::PropertyKind::RowGap),
+            (&self.column_gap, fastn_js::PropertyKind::ColumnGap),
+        ] {
+            if let Some(value) = value {
+                component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                    value.to_set_property(kind, doc, kernel.name.as_str(), rdata),
+                ));
+            }
+        }
+
+        component_statements.extend(self.container.to_component_statements(
+            doc,
+            rdata,
+            has_rive_components,
+            false,
+        ));
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+impl TableRow {
+    pub fn from(component: &fastn_resolved::ComponentInvocation) -> TableRow {
+        let component_definition = fastn_builtins::builtins()
+            .get("ftd#table-row")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        TableRow {
+            container: Container::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &dyn fastn_resolved::tdoc::TDoc,
+        rdata: &mut fastn_runtime::ResolverData,
+        should_return: bool,
+        has_rive_components: &mut bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::TableRow, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
             kernel.name.as_str(),
             doc,
             rdata,
@@ -2437,6 +3825,11 @@ pub struct Common {
     pub events: Vec<fastn_resolved::Event>,
     pub selectable: Option<fastn_runtime::Value>,
     pub mask: Option<fastn_runtime::Value>,
+    pub grid_column: Option<fastn_runtime::Value>,
+    pub grid_row: Option<fastn_runtime::Value>,
+    pub grid_area: Option<fastn_runtime::Value>,
+    pub transition: Option<fastn_runtime::Value>,
+    pub animation: Option<fastn_runtime::Value>,
 }
 
 impl Common {
@@ -2702,6 +4095,29 @@ impl Common {
                 arguments,
             ),
             mask: fastn_runtime::value::get_optional_js_value("mask", properties, arguments),
+            grid_column: fastn_runtime::value::get_optional_js_value(
+                "grid-column",
+                properties,
+                arguments,
+            ),
+            grid_row: fastn_runtime::value::get_optional_js_value(
+                "grid-row", properties, arguments,
+            ),
+            grid_area: fastn_runtime::value::get_optional_js_value(
+                "grid-area",
+                properties,
+                arguments,
+            ),
+            transition: fastn_runtime::value::get_optional_js_value(
+                "transition",
+                properties,
+                arguments,
+            ),
+            animation: fastn_runtime::value::get_optional_js_value(
+                "animation",
+                properties,
+                arguments,
+            ),
             events: events.to_vec(),
         }
     }
@@ -3309,6 +4725,19 @@ impl Common {
                 mask.to_set_property(fastn_js::PropertyKind::Mask, doc, element_name, rdata),
             ));
         }
+        for (value, kind) in [
+            (&self.grid_column, fastn_js::PropertyKind::GridColumn),
+            (&self.grid_row, fastn_js::PropertyKind::GridRow),
+            (&self.grid_area, fastn_js::PropertyKind::GridArea),
+            (&self.transition, fastn_js::PropertyKind::Transition),
+            (&self.animation, fastn_js::PropertyKind::Animation),
+        ] {
+            if let Some(value) = value {
+                component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                    value.to_set_property(kind, doc, element_name, rdata),
+                ));
+            }
+        }
         component_statements
     }
 
@@ -3354,6 +4783,10 @@ pub fn is_kernel(s: &str) -> bool {
         "ftd#text",
         "ftd#row",
         "ftd#column",
+        "ftd#form",
+        "ftd#table",
+        "ftd#table-row",
+        "ftd#grid",
         "ftd#integer",
         "ftd#decimal",
         "ftd#container",
@@ -3362,7 +4795,14 @@ pub fn is_kernel(s: &str) -> bool {
         "ftd#mobile",
         "ftd#checkbox",
         "ftd#text-input",
+        "ftd#select",
+        "ftd#radio",
+        "ftd#textarea",
+        "ftd#file-input",
         "ftd#iframe",
+        "ftd#svg",
+        "ftd#icon",
+        "ftd#math",
         "ftd#code",
         "ftd#image",
         "ftd#audio",
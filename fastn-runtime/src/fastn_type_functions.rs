@@ -132,7 +132,9 @@ impl fastn_runtime::extensions::ValueExt for fastn_resolved::Value {
             fastn_resolved::Value::String { text } => {
                 fastn_js::SetPropertyValue::Value(fastn_js::Value::String(text.to_string()))
             }
-            fastn_resolved::Value::Integer { value } => {
+            fastn_resolved::Value::Integer { value }
+            | fastn_resolved::Value::DateTime { value }
+            | fastn_resolved::Value::Date { value } => {
                 fastn_js::SetPropertyValue::Value(fastn_js::Value::Integer(*value))
             }
             fastn_resolved::Value::Decimal { value } => {
@@ -144,8 +146,13 @@ impl fastn_runtime::extensions::ValueExt for fastn_resolved::Value {
                 full_variant,
                 variant,
             } => {
-                let (js_variant, has_value) =
-                    fastn_runtime::value::ftd_to_js_variant(name, variant, full_variant, value);
+                let (js_variant, has_value) = fastn_runtime::value::ftd_to_js_variant(
+                    doc,
+                    name,
+                    variant,
+                    full_variant,
+                    value,
+                );
                 if has_value {
                     return fastn_js::SetPropertyValue::Value(fastn_js::Value::OrType {
                         variant: js_variant,
@@ -172,6 +179,24 @@ impl fastn_runtime::extensions::ValueExt for fastn_resolved::Value {
                         .collect_vec(),
                 })
             }
+            fastn_resolved::Value::Map { data, .. } => {
+                fastn_js::SetPropertyValue::Value(fastn_js::Value::Map {
+                    value: data
+                        .iter()
+                        .map(|(k, v)| {
+                            (
+                                k.to_string(),
+                                v.to_fastn_js_value_with_ui(
+                                    doc,
+                                    rdata,
+                                    has_rive_components,
+                                    should_return,
+                                ),
+                            )
+                        })
+                        .collect_vec(),
+                })
+            }
             fastn_resolved::Value::Record {
                 fields: record_fields,
                 name,
@@ -276,6 +301,17 @@ impl fastn_runtime::extensions::EventNameExt for fastn_resolved::EventName {
             fastn_resolved::EventName::Change => Some(fastn_js::Event::Change),
             fastn_resolved::EventName::Blur => Some(fastn_js::Event::Blur),
             fastn_resolved::EventName::Focus => Some(fastn_js::Event::Focus),
+            fastn_resolved::EventName::Interval(ms) => Some(fastn_js::Event::Interval(*ms)),
+            fastn_resolved::EventName::Timeout(ms) => Some(fastn_js::Event::Timeout(*ms)),
+            fastn_resolved::EventName::Visible => Some(fastn_js::Event::Visible),
+            fastn_resolved::EventName::Hidden => Some(fastn_js::Event::Hidden),
+            fastn_resolved::EventName::Scroll(ms) => Some(fastn_js::Event::Scroll(*ms)),
+            fastn_resolved::EventName::Submit => Some(fastn_js::Event::Submit),
+            fastn_resolved::EventName::KeyDown(keys) => Some(fastn_js::Event::KeyDown(
+                keys.iter()
+                    .map(|v| fastn_runtime::utils::to_key(v))
+                    .collect_vec(),
+            )),
             fastn_resolved::EventName::RivePlay(_)
             | fastn_resolved::EventName::RivePause(_)
             | fastn_resolved::EventName::RiveStateChange(_) => None,
@@ -371,6 +407,17 @@ impl fastn_runtime::extensions::ComponentExt for fastn_resolved::ComponentInvoca
                     ),
                     false,
                 ),
+                key: iteration.key.as_ref().map(|key| {
+                    key.to_fastn_js_value(
+                        doc,
+                        &rdata.clone_with_new_loop_alias(
+                            &loop_alias,
+                            &loop_counter_alias,
+                            doc.name().to_string(),
+                        ),
+                        false,
+                    )
+                }),
                 statements: component_statements,
                 parent: parent.to_string(),
                 should_return,
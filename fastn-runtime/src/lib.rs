@@ -77,7 +77,7 @@ impl VariableExt for fastn_resolved::Variable {
                     ),
                     prefix,
                 });
-            } else if self.kind.is_list() {
+            } else if self.kind.is_list() || self.kind.is_map() {
                 // Todo: It should be only for Mutable not Static
                 return fastn_js::Ast::MutableList(fastn_js::MutableList {
                     name: self.name.to_string(),
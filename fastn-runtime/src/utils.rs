@@ -118,6 +118,14 @@ pub(crate) fn update_reference(reference: &str, rdata: &fastn_runtime::ResolverD
         return format!("fastn_utils.getNodeValue({component_name})");
     }
 
+    if fastn_builtins::constants::FTD_SPECIAL_VALUES
+        .trim_start_matches('$')
+        .eq(reference)
+    {
+        let component_name = rdata.component_name.clone().unwrap();
+        return format!("fastn_utils.getNodeValues({component_name})");
+    }
+
     if fastn_builtins::constants::FTD_SPECIAL_CHECKED
         .trim_start_matches('$')
         .eq(reference)
@@ -44,6 +44,7 @@ impl Value {
                     } = value.kind().inner()
                     {
                         let (js_variant, has_value) = ftd_to_js_variant(
+                            doc,
                             name.as_str(),
                             variant.as_str(),
                             full_variant.as_str(),
@@ -209,6 +210,12 @@ impl fastn_runtime::extensions::ArgumentExt for fastn_resolved::Argument {
                 data: vec![],
                 kind: self.kind.clone(),
             }))
+        } else if let Some((key, value)) = self.kind.kind.get_map_kinds() {
+            Some(fastn_runtime::Value::Data(fastn_resolved::Value::Map {
+                data: Default::default(),
+                key: key.to_owned(),
+                kind: value.to_owned().into_kind_data(),
+            }))
         } else if self.kind.is_optional() {
             Some(fastn_runtime::Value::Data(
                 fastn_resolved::Value::Optional {
@@ -272,6 +279,7 @@ pub(crate) fn get_js_value_with_default(
 }
 
 pub(crate) fn ftd_to_js_variant(
+    doc: &dyn fastn_resolved::tdoc::TDoc,
     name: &str,
     variant: &str,
     full_variant: &str,
@@ -395,6 +403,26 @@ pub(crate) fn ftd_to_js_variant(
             let js_variant = loading_variants(variant);
             (format!("fastn_dom.Loading.{}", js_variant), false)
         }
+        "ftd#grid-template" => {
+            let js_variant = grid_template_variants(variant);
+            (format!("fastn_dom.GridTemplate.{}", js_variant), true)
+        }
+        "ftd#grid-value" => {
+            let js_variant = grid_value_variants(variant);
+            (format!("fastn_dom.GridValue.{}", js_variant), true)
+        }
+        "ftd#grid-auto-flow" => {
+            let js_variant = grid_auto_flow_variants(variant);
+            (format!("fastn_dom.GridAutoFlow.{}", js_variant), false)
+        }
+        "ftd#easing" => {
+            let js_variant = easing_variants(variant);
+            (format!("fastn_dom.Easing.{}", js_variant.0), js_variant.1)
+        }
+        "ftd#form-encoding" => {
+            let js_variant = form_encoding_variants(variant);
+            (format!("fastn_dom.FormEncoding.{}", js_variant), false)
+        }
         "ftd#image-fit" => {
             let js_variant = object_fit_variants(variant);
             (format!("fastn_dom.Fit.{}", js_variant), false)
@@ -427,6 +455,24 @@ pub(crate) fn ftd_to_js_variant(
             )
         }
         t => {
+            // Variants carrying a value are tagged so that `match` can tell them apart at
+            // runtime, constants stay plain values. The or-types no component `match`es on
+            // are left as they were.
+            let variant_name = variant.rsplit_once('.').map_or(variant, |(_, v)| v);
+            if let Some(fastn_resolved::Definition::OrType(or_type)) = doc.definitions().get(t) {
+                let is_tagged = or_type.matched
+                    && or_type.variants.iter().any(|v| {
+                        let name = v.name();
+                        !matches!(v, fastn_resolved::OrTypeVariant::Constant(_))
+                            && name
+                                .rsplit_once('.')
+                                .map_or(name.as_str(), |(_, v)| v)
+                                .eq(variant_name)
+                    });
+                if is_tagged {
+                    return (format!("fastn.orType(\"{}\")", variant_name), true);
+                }
+            }
             if let Some(value) = value.value_optional() {
                 return match value {
                     fastn_resolved::Value::Integer { value } => (value.to_string(), false),
@@ -767,6 +813,8 @@ fn text_input_type_variants(name: &str) -> &'static str {
         "week" => "Week",
         "color" => "Color",
         "file" => "File",
+        "number" => "Number",
+        "submit" => "Submit",
         t => todo!("invalid text-input-type variant {}", t),
     }
 }
@@ -779,6 +827,56 @@ fn loading_variants(name: &str) -> &'static str {
     }
 }
 
+fn grid_template_variants(name: &str) -> &'static str {
+    match name {
+        "fixed" => "Fixed",
+        "count" => "Count",
+        "responsive" => "Responsive",
+        t => todo!("invalid grid-template variant {}", t),
+    }
+}
+
+fn grid_value_variants(name: &str) -> &'static str {
+    match name {
+        "fixed" => "Fixed",
+        "span" => "Span",
+        "responsive" => "Responsive",
+        t => todo!("invalid grid-value variant {}", t),
+    }
+}
+
+fn grid_auto_flow_variants(name: &str) -> &'static str {
+    match name {
+        "row" => "Row",
+        "column" => "Column",
+        "dense" => "Dense",
+        "row-dense" => "RowDense",
+        "column-dense" => "ColumnDense",
+        t => todo!("invalid grid-auto-flow variant {}", t),
+    }
+}
+
+fn easing_variants(name: &str) -> (&'static str, bool) {
+    match name {
+        "linear" => ("Linear", false),
+        "ease" => ("Ease", false),
+        "ease-in" => ("EaseIn", false),
+        "ease-out" => ("EaseOut", false),
+        "ease-in-out" => ("EaseInOut", false),
+        "cubic-bezier" => ("CubicBezier", true),
+        "steps" => ("Steps", true),
+        t => todo!("invalid easing variant {}", t),
+    }
+}
+
+fn form_encoding_variants(name: &str) -> &'static str {
+    match name {
+        "json" => "Json",
+        "urlencoded" => "UrlEncoded",
+        t => todo!("invalid form-encoding variant {}", t),
+    }
+}
+
 fn object_fit_variants(name: &str) -> &'static str {
     match name {
         "none" => "none",
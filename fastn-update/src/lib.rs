@@ -424,7 +424,7 @@ pub async fn update(ds: &fastn_ds::DocumentStore, check: bool) -> fastn_core::Re
     let current_package = utils::read_current_package(ds).await?;
 
     if current_package.dependencies.is_empty() {
-        println!("No dependencies to update.");
+        fastn_core::progress!("No dependencies to update.\n");
 
         // Creating Empty config file for packages with no dependencies
         fastn_core::ConfigTemp::write(ds, current_package.name.clone(), Default::default()).await?;
@@ -458,9 +458,9 @@ pub async fn update(ds: &fastn_ds::DocumentStore, check: bool) -> fastn_core::Re
     pb.finish_and_clear();
 
     match updated_packages {
-        0 => println!("No packages updated."),
-        1 => println!("Updated package dependency."),
-        n => println!("Updated {} dependencies.", n),
+        0 => fastn_core::progress!("No packages updated.\n"),
+        1 => fastn_core::progress!("Updated package dependency.\n"),
+        n => fastn_core::progress!("Updated {} dependencies.\n", n),
     }
 
     Ok(())
@@ -56,6 +56,11 @@ async fn fastn_core_commands(matches: &clap::ArgMatches) -> fastn_core::Result<(
         return Ok(());
     }
 
+    if let Some(explain) = matches.subcommand_matches("explain") {
+        explain_code(explain.value_of_("code"));
+        return Ok(());
+    }
+
     let pg_pools: actix_web::web::Data<scc::HashMap<String, deadpool_postgres::Pool>> =
         actix_web::web::Data::new(scc::HashMap::new());
 
@@ -119,6 +124,7 @@ async fn fastn_core_commands(matches: &clap::ArgMatches) -> fastn_core::Result<(
             .add_inline_js(inline_js)
             .add_external_css(external_css)
             .add_inline_css(inline_css)
+            .add_message_format(test.value_of_("message-format"))?
             .set_test_command_running();
 
         return fastn_core::test(
@@ -156,7 +162,8 @@ async fn fastn_core_commands(matches: &clap::ArgMatches) -> fastn_core::Result<(
             .add_external_js(external_js)
             .add_inline_js(inline_js)
             .add_external_css(external_css)
-            .add_inline_css(inline_css);
+            .add_inline_css(inline_css)
+            .add_message_format(build.value_of_("message-format"))?;
 
         return fastn_core::build(
             &config,
@@ -194,13 +201,43 @@ async fn fastn_core_commands(matches: &clap::ArgMatches) -> fastn_core::Result<(
         .await;
     }
 
-    if matches.subcommand_matches("check").is_some() {
-        return fastn_core::post_build_check(&config).await;
+    if let Some(check) = matches.subcommand_matches("check") {
+        let config = config.add_message_format(check.value_of_("message-format"))?;
+        return fastn_core::check(&config).await;
+    }
+
+    if matches.subcommand_matches("lsp").is_some() {
+        return fastn_core::lsp(&config).await;
     }
 
     Ok(())
 }
 
+/// Prints the explanation of the error code `code`, or all the codes.
+fn explain_code(code: Option<&str>) {
+    let code = match code {
+        Some(code) => code,
+        None => {
+            for c in fastn_issues::codes::ALL {
+                println!("{}  {}", c.code, c.title);
+            }
+            return;
+        }
+    };
+    match fastn_issues::codes::explain(code) {
+        Some(c) => println!(
+            "{}: {}\n\n{}",
+            c.code,
+            c.title,
+            fastn_issues::codes::explanation(c).trim_end()
+        ),
+        None => {
+            eprintln!("`{code}` is not a fastn error code, see `fastn explain` for all");
+            std::process::exit(1);
+        }
+    }
+}
+
 async fn check_for_update_cmd(matches: &clap::ArgMatches) -> fastn_core::Result<()> {
     let env_var_set = {
         if let Ok(val) = std::env::var("FASTN_CHECK_FOR_UPDATES") {
@@ -239,13 +276,13 @@ async fn check_for_update(report: bool) -> fastn_core::Result<()> {
     let current_version = version();
 
     if release.tag_name != current_version {
-        println!(
-            "You are using fastn {current_version}, and latest release is {}, visit https://fastn.com/install/ to learn how to upgrade.",
+        fastn_core::progress!(
+            "You are using fastn {current_version}, and latest release is {}, visit https://fastn.com/install/ to learn how to upgrade.\n",
             release.tag_name
         );
     } else if report {
         // log only when -c is passed
-        println!("You are using the latest release of fastn.");
+        fastn_core::progress!("You are using the latest release of fastn.\n");
     }
 
     Ok(())
@@ -276,6 +313,7 @@ fn app(version: &'static str) -> clap::Command {
                 .arg(clap::arg!(--"css" <URL> "CSS text added in ftd files")
                     .action(clap::ArgAction::Append))
                 .arg(clap::arg!(--edition <EDITION> "The FTD edition"))
+                .arg(clap::arg!(--"message-format" <FORMAT> "How errors are printed: `human` or `json`, one object per line"))
                 .arg(clap::arg!(--offline "Disables automatic package update checks to operate in offline mode"))
         )
         .subcommand(
@@ -306,6 +344,7 @@ fn app(version: &'static str) -> clap::Command {
                 .arg(clap::arg!(--edition <EDITION> "The FTD edition"))
                 .arg(clap::arg!(--"script" "Generates a script file (for debugging purposes)"))
                 .arg(clap::arg!(--"verbose" "To provide more better logs (for debugging purposes)"))
+                .arg(clap::arg!(--"message-format" <FORMAT> "How errors are printed: `human` or `json`, one object per line"))
                 .arg(clap::arg!(--offline "Disables automatic package update checks to operate in offline mode"))
         )
         .subcommand(
@@ -318,8 +357,17 @@ fn app(version: &'static str) -> clap::Command {
         )
         .subcommand(
             clap::Command::new("check")
-                .about("Check if everything is fine with current fastn package")
-                .hide(true) // hidden since the feature is not being released yet.
+                .about("Report all the errors in the documents of the current fastn package")
+                .arg(clap::arg!(--"message-format" <FORMAT> "How errors are printed: `human` or `json`, one object per line"))
+        )
+        .subcommand(
+            clap::Command::new("lsp")
+                .about("Start a language server for the .ftd files of the current fastn package, over stdio")
+        )
+        .subcommand(
+            clap::Command::new("explain")
+                .about("Explain an error code, like FTD0102, or list all of them")
+                .arg(clap::arg!(code: [CODE] "The error code"))
         )
         .subcommand(
             clap::Command::new("update")
@@ -403,14 +451,14 @@ FASTN_DANGER_ACCEPT_CHECKED_IN_ENV set"
         std::process::exit(1);
     } else {
         if checked_in && ignore {
-            println!(
+            fastn_core::progress!(
                 "WARN: your .env file has been detected in the version control system! This poses a
-significant security risk in case the source code becomes public."
+significant security risk in case the source code becomes public.\n"
             );
         }
 
         if dotenvy::dotenv().is_ok() && !is_test_running {
-            println!("INFO: loaded environment variables from .env file.");
+            fastn_core::progress!("INFO: loaded environment variables from .env file.\n");
         }
     }
 }
@@ -31,11 +31,32 @@ pub enum Ast {
 
 impl Ast {
     pub fn from_sections(sections: &[ftd_p1::Section], doc_id: &str) -> ftd_ast::Result<Vec<Ast>> {
+        let (di_vec, errors) = Ast::from_sections_with_errors(sections, doc_id);
+        match ftd_p1::Reportable::from_errors(errors) {
+            Some(e) => Err(e),
+            None => Ok(di_vec),
+        }
+    }
+
+    /// The ast of each section that is valid, and an error for each one that
+    /// is not.
+    pub fn from_sections_with_errors(
+        sections: &[ftd_p1::Section],
+        doc_id: &str,
+    ) -> (Vec<Ast>, Vec<ftd_ast::Error>) {
         let mut di_vec = vec![];
+        let mut errors = vec![];
         for section in ignore_comments(sections) {
-            di_vec.push(Ast::from_section(&section, doc_id)?);
+            match Ast::from_section(&section, doc_id) {
+                Ok(ast) => di_vec.push(ast),
+                Err(e) => errors.push(e),
+            }
         }
-        Ok(di_vec)
+        ftd_ast::Match::collect_arms(di_vec.iter_mut().filter_map(|ast| match ast {
+            Ast::ComponentInvocation(c) => Some(c),
+            _ => None,
+        }));
+        (di_vec, errors)
     }
 
     pub fn name(&self) -> String {
@@ -106,6 +127,21 @@ impl Ast {
         }
     }
 
+    /// The span of the name of the thing, of the module for an import.
+    pub fn span(&self) -> ftd_p1::Span {
+        match self {
+            Ast::Import(i) => i.span,
+            Ast::Record(r) => r.span,
+            Ast::VariableDefinition(v) => v.span,
+            Ast::VariableInvocation(v) => v.span,
+            Ast::ComponentDefinition(c) => c.span,
+            Ast::ComponentInvocation(c) => c.span,
+            Ast::FunctionDefinition(f) => f.span,
+            Ast::OrType(o) => o.span,
+            Ast::WebComponentDefinition(w) => w.span,
+        }
+    }
+
     pub fn get_record(self, doc_id: &str) -> ftd_ast::Result<ftd_ast::Record> {
         if let ftd_ast::Ast::Record(r) = self {
             return Ok(r);
@@ -250,7 +286,8 @@ impl Ast {
             self,
             Ast::VariableDefinition(ftd_ast::VariableDefinition {
                 flags: ftd_ast::VariableFlags {
-                    always_include: Some(true)
+                    always_include: Some(true),
+                    ..
                 },
                 ..
             })
@@ -7,6 +7,9 @@ pub struct ComponentDefinition {
     pub definition: ComponentInvocation,
     pub css: Option<String>,
     pub line_number: usize,
+    /// the name
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 pub const COMPONENT: &str = "component";
@@ -18,6 +21,7 @@ impl ComponentDefinition {
         definition: ComponentInvocation,
         css: Option<String>,
         line_number: usize,
+        span: ftd_p1::Span,
     ) -> ComponentDefinition {
         ComponentDefinition {
             name: name.to_string(),
@@ -25,6 +29,7 @@ impl ComponentDefinition {
             definition,
             css,
             line_number,
+            span,
         }
     }
 
@@ -70,6 +75,7 @@ impl ComponentDefinition {
             definition,
             css,
             section.line_number,
+            section.span,
         ))
     }
 
@@ -85,10 +91,15 @@ pub struct ComponentInvocation {
     pub properties: Vec<Property>,
     pub iteration: Option<Loop>,
     pub condition: Option<ftd_ast::Condition>,
+    #[serde(rename = "match", skip_serializing_if = "Option::is_none", default)]
+    pub pattern: Option<Match>,
     pub events: Vec<Event>,
     pub children: Vec<ComponentInvocation>,
     #[serde(rename = "line-number")]
     pub line_number: usize,
+    /// the name
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 impl ComponentInvocation {
@@ -99,9 +110,11 @@ impl ComponentInvocation {
         properties: Vec<Property>,
         iteration: Option<Loop>,
         condition: Option<ftd_ast::Condition>,
+        pattern: Option<Match>,
         events: Vec<Event>,
         children: Vec<ComponentInvocation>,
         line_number: usize,
+        span: ftd_p1::Span,
     ) -> ComponentInvocation {
         ComponentInvocation {
             id,
@@ -109,9 +122,11 @@ impl ComponentInvocation {
             properties,
             iteration,
             condition,
+            pattern,
             events,
             children,
             line_number,
+            span,
         }
     }
 
@@ -131,12 +146,15 @@ impl ComponentInvocation {
             );
         }
 
+        let is_loop = Loop::has_loop_header(&section.headers);
         let properties = {
             let mut properties = vec![];
             for header in section.headers.0.iter() {
                 let name = header.get_key();
                 if name.eq(ftd_ast::utils::LOOP)
                     || name.eq(ftd_ast::utils::FOR)
+                    || name.eq(ftd_ast::utils::MATCH)
+                    || (is_loop && Loop::is_key_header(header))
                     || Event::get_event_name(name.as_str()).is_some()
                     || ftd_ast::utils::is_condition(header.get_key().as_str(), &header.get_kind())
                 {
@@ -164,12 +182,14 @@ impl ComponentInvocation {
             if let Some(ftd_p1::Body {
                 ref value,
                 line_number,
+                span,
             }) = section.body
             {
                 properties.push(Property::from_value(
                     Some(value.to_owned()),
                     PropertySource::Body,
                     line_number,
+                    span,
                 ));
             }
             properties
@@ -180,24 +200,69 @@ impl ComponentInvocation {
             for subsection in section.sub_sections.iter() {
                 children.push(ComponentInvocation::from_p1(subsection, doc_id)?);
             }
+            Match::collect_arms(children.iter_mut());
             children
         };
 
         let iteration = Loop::from_headers(&section.headers, doc_id)?;
         let events = Event::from_headers(&section.headers, doc_id)?;
         let condition = ftd_ast::Condition::from_headers(&section.headers, doc_id)?;
+        let pattern = Match::from_headers(&section.headers, doc_id)?;
         let id = ftd_ast::utils::get_component_id(&section.headers, doc_id)?;
 
-        Ok(ComponentInvocation::new(
+        let mut component_invocation = ComponentInvocation::new(
             id,
             section.name.as_str(),
             properties,
             iteration,
             condition,
+            pattern,
             events,
             children,
             section.line_number,
-        ))
+            section.span,
+        );
+
+        if let Some(Match {
+            on,
+            variant,
+            alias: Some(alias),
+            ..
+        }) = component_invocation.pattern.clone()
+        {
+            // The alias is only a shorthand for the payload of the matched variant
+            component_invocation.rename_reference(
+                alias.as_str(),
+                format!("{}.{}", on, Match::variant_name(variant.as_str())).as_str(),
+            );
+        }
+
+        Ok(component_invocation)
+    }
+
+    /// Replaces every reference to `from` in this invocation and its children with `to`.
+    pub(crate) fn rename_reference(&mut self, from: &str, to: &str) {
+        for property in self.properties.iter_mut() {
+            property.value.rename_reference(from, to);
+            if let Some(condition) = property.condition.as_mut() {
+                *condition = ftd_ast::utils::rename_reference(condition, from, to);
+            }
+        }
+        if let Some(iteration) = self.iteration.as_mut() {
+            iteration.on = ftd_ast::utils::rename_reference(iteration.on.as_str(), from, to);
+            if let Some(key) = iteration.key.as_mut() {
+                *key = ftd_ast::utils::rename_reference(key.as_str(), from, to);
+            }
+        }
+        if let Some(condition) = self.condition.as_mut() {
+            condition.rename_reference(from, to);
+        }
+        for event in self.events.iter_mut() {
+            event.action = ftd_ast::utils::rename_reference(event.action.as_str(), from, to);
+        }
+        for child in self.children.iter_mut() {
+            child.rename_reference(from, to);
+        }
     }
 
     pub fn from_variable_value(
@@ -209,34 +274,39 @@ impl ComponentInvocation {
             ftd_ast::VariableValue::Optional { value, .. } if value.is_some() => {
                 ComponentInvocation::from_variable_value(key, value.unwrap(), doc_id)
             }
-            ftd_ast::VariableValue::Optional { line_number, .. } => {
-                Ok(ftd_ast::ComponentInvocation {
-                    id: None,
-                    name: key.to_string(),
-                    properties: vec![],
-                    iteration: None,
-                    condition: None,
-                    events: vec![],
-                    children: vec![],
-                    line_number,
-                })
-            }
-            ftd_ast::VariableValue::Constant { line_number, .. } => {
-                Ok(ftd_ast::ComponentInvocation {
-                    id: None,
-                    name: key.to_string(),
-                    properties: vec![],
-                    iteration: None,
-                    condition: None,
-                    events: vec![],
-                    children: vec![],
-                    line_number,
-                })
-            }
+            ftd_ast::VariableValue::Optional {
+                line_number, span, ..
+            } => Ok(ftd_ast::ComponentInvocation {
+                id: None,
+                name: key.to_string(),
+                properties: vec![],
+                iteration: None,
+                condition: None,
+                pattern: None,
+                events: vec![],
+                children: vec![],
+                line_number,
+                span,
+            }),
+            ftd_ast::VariableValue::Constant {
+                line_number, span, ..
+            } => Ok(ftd_ast::ComponentInvocation {
+                id: None,
+                name: key.to_string(),
+                properties: vec![],
+                iteration: None,
+                condition: None,
+                pattern: None,
+                events: vec![],
+                children: vec![],
+                line_number,
+                span,
+            }),
             ftd_ast::VariableValue::List {
                 value,
                 line_number,
                 condition,
+                span,
             } => {
                 let mut children = vec![];
                 for val in value {
@@ -252,9 +322,11 @@ impl ComponentInvocation {
                     properties: vec![],
                     iteration: None,
                     condition,
+                    pattern: None,
                     events: vec![],
                     children,
                     line_number,
+                    span,
                 })
             }
             ftd_ast::VariableValue::Record {
@@ -265,6 +337,7 @@ impl ComponentInvocation {
                 line_number,
                 values,
                 condition,
+                span,
             } => {
                 let mut properties = vec![];
                 if let Some(caption) = caption.as_ref() {
@@ -273,11 +346,22 @@ impl ComponentInvocation {
                         source: ftd_ast::PropertySource::Caption,
                         condition: caption.condition_expression(),
                         line_number,
+                        span: caption.span(),
+                        condition_span: caption
+                            .condition()
+                            .as_ref()
+                            .map(|c| c.span)
+                            .unwrap_or_default(),
                     });
                 }
+                let is_loop = headers
+                    .0
+                    .iter()
+                    .any(|v| [ftd_ast::utils::LOOP, ftd_ast::utils::FOR].contains(&v.key.as_str()));
                 for header in headers.0.iter() {
                     if header.key.eq(ftd_ast::utils::LOOP)
                         || header.key.eq(ftd_ast::utils::FOR)
+                        || (is_loop && header.key.eq(ftd_ast::utils::KEY))
                         || Event::get_event_name_from_header_value(header).is_some()
                         || ftd_ast::utils::is_condition(header.key.as_str(), &header.kind)
                     {
@@ -291,6 +375,8 @@ impl ComponentInvocation {
                         },
                         condition: header.condition.to_owned(),
                         line_number,
+                        span: header.span,
+                        condition_span: header.condition_span,
                     });
                 }
                 if let Some(body) = body {
@@ -298,6 +384,7 @@ impl ComponentInvocation {
                         Some(body.value),
                         PropertySource::Body,
                         body.line_number,
+                        body.span,
                     ));
                 }
 
@@ -320,9 +407,11 @@ impl ComponentInvocation {
                     properties,
                     iteration,
                     condition,
+                    pattern: None,
                     events,
                     children,
                     line_number,
+                    span,
                 })
             }
             ftd_ast::VariableValue::String {
@@ -330,6 +419,7 @@ impl ComponentInvocation {
                 line_number,
                 source: value_source,
                 condition,
+                span,
             } => Ok(ftd_ast::ComponentInvocation {
                 id: None,
                 name: key.to_string(),
@@ -337,12 +427,15 @@ impl ComponentInvocation {
                     Some(value),
                     value_source.to_property_source(),
                     line_number,
+                    span,
                 )],
                 iteration: None,
                 condition,
+                pattern: None,
                 events: vec![],
                 children: vec![],
                 line_number,
+                span,
             }),
         }
     }
@@ -361,6 +454,11 @@ pub struct Property {
     pub condition: Option<String>,
     #[serde(rename = "line-number")]
     pub line_number: usize,
+    /// the key of a header, else the value
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub condition_span: ftd_p1::Span,
 }
 
 impl Property {
@@ -373,12 +471,16 @@ impl Property {
         source: PropertySource,
         condition: Option<String>,
         line_number: usize,
+        span: ftd_p1::Span,
+        condition_span: ftd_p1::Span,
     ) -> Property {
         Property {
             value,
             source,
             condition,
             line_number,
+            span,
+            condition_span,
         }
     }
 
@@ -390,6 +492,7 @@ impl Property {
         if !Self::is_property(header)
             || header.get_key().eq(ftd_ast::utils::LOOP)
             || header.get_key().eq(ftd_ast::utils::FOR)
+            || header.get_key().eq(ftd_ast::utils::MATCH)
             || Event::get_event_name(header.get_key().as_str()).is_some()
         {
             return ftd_ast::parse_error(
@@ -400,19 +503,30 @@ impl Property {
         }
 
         let value = ftd_ast::VariableValue::from_p1_header(header, doc_id)?;
+        let span = match source {
+            PropertySource::Header { .. } => header.get_key_span(),
+            _ => header.get_span(),
+        };
 
         Ok(Property::new(
             value,
             source,
             header.get_condition(),
             header.get_line_number(),
+            span,
+            header.get_condition_span().unwrap_or_default(),
         ))
     }
 
-    fn from_value(value: Option<String>, source: PropertySource, line_number: usize) -> Property {
+    fn from_value(
+        value: Option<String>,
+        source: PropertySource,
+        line_number: usize,
+        span: ftd_p1::Span,
+    ) -> Property {
         let value =
-            ftd_ast::VariableValue::from_value(&value, source.to_value_source(), line_number);
-        Property::new(value, source, None, line_number)
+            ftd_ast::VariableValue::from_value(&value, source.to_value_source(), line_number, span);
+        Property::new(value, source, None, line_number, span, Default::default())
     }
 }
 
@@ -455,18 +569,61 @@ pub struct Loop {
     pub on: String,
     pub alias: String,
     pub loop_counter_alias: Option<String>,
+    /// `key: $item.id`, identifies the node of each item so that the js runtime
+    /// can keep it when the list changes.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub key: Option<String>,
     #[serde(rename = "line-number")]
     pub line_number: usize,
+    /// the loop statement
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 impl Loop {
-    fn new(on: &str, alias: &str, loop_counter_alias: Option<String>, line_number: usize) -> Loop {
+    fn new(
+        on: &str,
+        alias: &str,
+        loop_counter_alias: Option<String>,
+        key: Option<String>,
+        line_number: usize,
+        span: ftd_p1::Span,
+    ) -> Loop {
         Loop {
             on: on.to_string(),
             alias: alias.to_string(),
             loop_counter_alias,
+            key,
             line_number,
+            span,
+        }
+    }
+
+    fn has_loop_header(headers: &ftd_p1::Headers) -> bool {
+        headers
+            .0
+            .iter()
+            .any(|v| [ftd_ast::utils::LOOP, ftd_ast::utils::FOR].contains(&v.get_key().as_str()))
+    }
+
+    /// `key` is only the key of the loop when the section has a loop, otherwise
+    /// it is an ordinary property.
+    fn is_key_header(header: &ftd_p1::Header) -> bool {
+        header.get_key().eq(ftd_ast::utils::KEY) && header.get_kind().is_none()
+    }
+
+    fn check_key(key: String, doc_id: &str, line_number: usize) -> ftd_ast::Result<String> {
+        if !key.starts_with(ftd_ast::utils::REFERENCE) {
+            return ftd_ast::parse_error(
+                format!(
+                    "Loop key should be some reference, found: `{}`. Help: use `${}` instead",
+                    key, key
+                ),
+                doc_id,
+                line_number,
+            );
         }
+        Ok(key)
     }
 
     fn get_loop_parameters(
@@ -555,11 +712,22 @@ impl Loop {
             .trim_start_matches(ftd_ast::utils::REFERENCE)
             .to_string();
 
+        let key = match headers.0.iter().find(|v| v.key.eq(ftd_ast::utils::KEY)) {
+            Some(key) => Some(Self::check_key(
+                key.value.string(doc_id)?.to_string(),
+                doc_id,
+                key.line_number,
+            )?),
+            None => None,
+        };
+
         Ok(Some(Loop::new(
             on.as_str(),
             alias.as_str(),
             loop_counter_alias,
+            key,
             loop_header.line_number,
+            loop_header.value.span(),
         )))
     }
 
@@ -606,13 +774,156 @@ impl Loop {
             .trim_start_matches(ftd_ast::utils::REFERENCE)
             .to_string();
 
+        let key = match headers.0.iter().find(|v| Self::is_key_header(v)) {
+            Some(key) => {
+                let line_number = key.get_line_number();
+                let value = key.get_value(doc_id)?.ok_or(ftd_ast::Error::Parse {
+                    message: "Loop key is blank".to_string(),
+                    doc_id: doc_id.to_string(),
+                    line_number,
+                })?;
+                Some(Self::check_key(value, doc_id, line_number)?)
+            }
+            None => None,
+        };
+
         Ok(Some(Loop::new(
             on.as_str(),
             alias.as_str(),
             loop_counter_alias,
+            key,
             loop_header.get_line_number(),
+            loop_header.get_span(),
+        )))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Match {
+    pub on: String,
+    pub variant: String,
+    pub alias: Option<String>,
+    /// Variants matched on `on` by this invocation and its siblings. Only set on the first
+    /// matching sibling, so the interpreter checks exhaustiveness once per group.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub arms: Vec<String>,
+    #[serde(rename = "line-number")]
+    pub line_number: usize,
+    /// the match statement
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
+}
+
+impl Match {
+    fn new(
+        on: &str,
+        variant: &str,
+        alias: Option<String>,
+        line_number: usize,
+        span: ftd_p1::Span,
+    ) -> Match {
+        Match {
+            on: on.to_string(),
+            variant: variant.to_string(),
+            alias,
+            arms: vec![],
+            line_number,
+            span,
+        }
+    }
+
+    /// `status.error` -> `error`
+    pub fn variant_name(variant: &str) -> &str {
+        variant
+            .rsplit_once('.')
+            .map(|(_, name)| name)
+            .unwrap_or(variant)
+    }
+
+    fn from_headers(headers: &ftd_p1::Headers, doc_id: &str) -> ftd_ast::Result<Option<Match>> {
+        let match_header = if let Some(match_header) = headers
+            .0
+            .iter()
+            .find(|v| v.get_key().eq(ftd_ast::utils::MATCH) && v.get_kind().is_none())
+        {
+            match_header
+        } else {
+            return Ok(None);
+        };
+
+        let line_number = match_header.get_line_number();
+        let match_statement = match_header
+            .get_value(doc_id)?
+            .ok_or(ftd_ast::Error::Parse {
+                message: "Match statement is blank".to_string(),
+                doc_id: doc_id.to_string(),
+                line_number,
+            })?;
+
+        let (on, pattern) = ftd_ast::utils::split_at(match_statement.as_str(), ftd_ast::utils::IS);
+        let pattern = pattern.ok_or(ftd_ast::Error::Parse {
+            message: format!(
+                "Statement \"match\" needs a variant to match against. Help: use `{} is <or-type>.<variant>`",
+                on
+            ),
+            doc_id: doc_id.to_string(),
+            line_number,
+        })?;
+
+        if !on.starts_with(ftd_ast::utils::REFERENCE) {
+            return ftd_ast::parse_error(
+                format!(
+                    "Match should be on some reference, found: `{}`. Help: use `${}` instead",
+                    on, on
+                ),
+                doc_id,
+                line_number,
+            );
+        }
+
+        let (variant, alias) = ftd_ast::utils::split_at(pattern.as_str(), ftd_ast::utils::AS);
+        let alias = match alias {
+            Some(alias) if !alias.starts_with(ftd_ast::utils::REFERENCE) => {
+                return ftd_ast::parse_error(
+                    format!(
+                        "Match alias should start with reference, found: `{}`. Help: use `${}` instead",
+                        alias, alias
+                    ),
+                    doc_id,
+                    line_number,
+                );
+            }
+            Some(alias) => Some(
+                alias
+                    .trim_start_matches(ftd_ast::utils::REFERENCE)
+                    .to_string(),
+            ),
+            None => None,
+        };
+
+        Ok(Some(Match::new(
+            on.as_str(),
+            variant.as_str(),
+            alias,
+            line_number,
+            match_header.get_span(),
         )))
     }
+
+    /// Groups sibling invocations matching on the same reference and records the variants of
+    /// each group on its first invocation.
+    pub(crate) fn collect_arms<'a>(invocations: impl Iterator<Item = &'a mut ComponentInvocation>) {
+        let mut invocations: Vec<&mut Match> =
+            invocations.filter_map(|v| v.pattern.as_mut()).collect();
+        let mut first: std::collections::HashMap<String, usize> = Default::default();
+        for index in 0..invocations.len() {
+            let variant = invocations[index].variant.to_string();
+            let first = *first
+                .entry(invocations[index].on.to_string())
+                .or_insert(index);
+            invocations[first].arms.push(variant);
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -621,14 +932,18 @@ pub struct Event {
     pub action: String,
     #[serde(rename = "line-number")]
     pub line_number: usize,
+    /// the action
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 impl Event {
-    fn new(name: &str, action: &str, line_number: usize) -> Event {
+    fn new(name: &str, action: &str, line_number: usize, span: ftd_p1::Span) -> Event {
         Event {
             name: name.to_string(),
             action: action.to_string(),
             line_number,
+            span,
         }
     }
 
@@ -675,6 +990,7 @@ impl Event {
             event_name.as_str(),
             action,
             header.line_number,
+            header.value.span(),
         )))
     }
 
@@ -705,6 +1021,7 @@ impl Event {
             event_name.as_str(),
             action.as_str(),
             header.get_line_number(),
+            header.get_span(),
         )))
     }
 }
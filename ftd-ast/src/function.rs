@@ -6,6 +6,9 @@ pub struct Function {
     pub line_number: usize,
     pub definition: FunctionDefinition,
     pub js: Option<String>,
+    /// the name
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 pub type FunctionDefinition = ftd_p1::Body;
@@ -18,6 +21,7 @@ impl Function {
         line_number: usize,
         definition: FunctionDefinition,
         js: Option<String>,
+        span: ftd_p1::Span,
     ) -> Function {
         Function {
             name: name.to_string(),
@@ -26,6 +30,7 @@ impl Function {
             line_number,
             definition,
             js,
+            span,
         }
     }
 
@@ -77,6 +82,7 @@ impl Function {
             section.line_number,
             definition,
             js,
+            section.span,
         ))
     }
 
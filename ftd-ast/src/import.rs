@@ -6,6 +6,9 @@ pub struct Import {
     pub line_number: usize,
     pub exports: Option<Export>,
     pub exposing: Option<Exposing>,
+    /// the module
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -29,6 +32,7 @@ impl Import {
         line_number: usize,
         exports: Option<Export>,
         exposing: Option<Exposing>,
+        span: ftd_p1::Span,
     ) -> Import {
         Import {
             module: module.to_string(),
@@ -36,6 +40,7 @@ impl Import {
             line_number,
             exports,
             exposing,
+            span,
         }
     }
     pub fn is_import(section: &ftd_p1::Section) -> bool {
@@ -64,7 +69,9 @@ impl Import {
         let exposing = Exposing::get_exposing_from_headers(&section.headers, doc_id)?;
         match &section.caption {
             Some(ftd_p1::Header::KV(ftd_p1::KV {
-                value: Some(value), ..
+                value: Some(value),
+                span,
+                ..
             })) => {
                 let (module, alias) = ftd_ast::utils::get_import_alias(value.as_str());
                 Ok(Import::new(
@@ -73,6 +80,7 @@ impl Import {
                     section.line_number,
                     exports,
                     exposing,
+                    *span,
                 ))
             }
             t => ftd_ast::parse_error(
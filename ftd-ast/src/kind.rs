@@ -94,17 +94,23 @@ pub enum VariableValue {
         value: Box<Option<VariableValue>>,
         line_number: usize,
         condition: Option<ftd_ast::Condition>,
+        #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+        span: ftd_p1::Span,
     },
     Constant {
         value: String,
         line_number: usize,
         source: ValueSource,
         condition: Option<ftd_ast::Condition>,
+        #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+        span: ftd_p1::Span,
     },
     List {
         value: Vec<VariableKeyValue>,
         line_number: usize,
         condition: Option<ftd_ast::Condition>,
+        #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+        span: ftd_p1::Span,
     },
     Record {
         name: String,
@@ -114,6 +120,8 @@ pub enum VariableValue {
         values: Vec<VariableKeyValue>,
         line_number: usize,
         condition: Option<ftd_ast::Condition>,
+        #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+        span: ftd_p1::Span,
     },
     #[serde(rename = "string-value")]
     String {
@@ -122,6 +130,8 @@ pub enum VariableValue {
         line_number: usize,
         source: ValueSource,
         condition: Option<ftd_ast::Condition>,
+        #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+        span: ftd_p1::Span,
     },
 }
 
@@ -162,13 +172,16 @@ pub struct BodyValue {
     pub value: String,
     #[serde(rename = "line-number")]
     pub line_number: usize,
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 impl BodyValue {
-    fn new(value: &str, line_number: usize) -> BodyValue {
+    fn new(value: &str, line_number: usize, span: ftd_p1::Span) -> BodyValue {
         BodyValue {
             value: value.to_string(),
             line_number,
+            span,
         }
     }
 }
@@ -259,9 +272,15 @@ pub struct HeaderValue {
     pub line_number: usize,
     pub kind: Option<String>,
     pub condition: Option<String>,
+    /// the key
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub condition_span: ftd_p1::Span,
 }
 
 impl HeaderValue {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         key: &str,
         mutable: bool,
@@ -269,6 +288,8 @@ impl HeaderValue {
         line_number: usize,
         kind: Option<String>,
         condition: Option<String>,
+        span: ftd_p1::Span,
+        condition_span: ftd_p1::Span,
     ) -> HeaderValue {
         HeaderValue {
             key: key.to_string(),
@@ -277,6 +298,8 @@ impl HeaderValue {
             line_number,
             kind,
             condition,
+            span,
+            condition_span,
         }
     }
 }
@@ -305,6 +328,69 @@ impl VariableValue {
             .map(|condition| condition.expression.clone())
     }
 
+    pub(crate) fn rename_reference(&mut self, from: &str, to: &str) {
+        match self {
+            ftd_ast::VariableValue::Optional {
+                value, condition, ..
+            } => {
+                if let Some(value) = value.as_mut() {
+                    value.rename_reference(from, to);
+                }
+                if let Some(condition) = condition {
+                    condition.rename_reference(from, to);
+                }
+            }
+            ftd_ast::VariableValue::Constant {
+                value, condition, ..
+            }
+            | ftd_ast::VariableValue::String {
+                value, condition, ..
+            } => {
+                *value = ftd_ast::utils::rename_reference(value, from, to);
+                if let Some(condition) = condition {
+                    condition.rename_reference(from, to);
+                }
+            }
+            ftd_ast::VariableValue::List {
+                value, condition, ..
+            } => {
+                for key_value in value.iter_mut() {
+                    key_value.value.rename_reference(from, to);
+                }
+                if let Some(condition) = condition {
+                    condition.rename_reference(from, to);
+                }
+            }
+            ftd_ast::VariableValue::Record {
+                caption,
+                headers,
+                body,
+                values,
+                condition,
+                ..
+            } => {
+                if let Some(caption) = caption.as_mut() {
+                    caption.rename_reference(from, to);
+                }
+                for header in headers.0.iter_mut() {
+                    header.value.rename_reference(from, to);
+                    if let Some(condition) = header.condition.as_mut() {
+                        *condition = ftd_ast::utils::rename_reference(condition, from, to);
+                    }
+                }
+                if let Some(body) = body {
+                    body.value = ftd_ast::utils::rename_reference(body.value.as_str(), from, to);
+                }
+                for key_value in values.iter_mut() {
+                    key_value.value.rename_reference(from, to);
+                }
+                if let Some(condition) = condition {
+                    condition.rename_reference(from, to);
+                }
+            }
+        }
+    }
+
     pub(crate) fn set_condition(self, condition: Option<ftd_ast::Condition>) -> Self {
         let mut variable_value = self;
         let mut_condition = match &mut variable_value {
@@ -360,19 +446,25 @@ impl VariableValue {
         source: ftd_ast::ValueSource,
         line_number: usize,
         condition: Option<ftd_ast::Condition>,
+        span: ftd_p1::Span,
     ) -> ftd_ast::VariableValue {
         use itertools::Itertools;
 
         // Bracket list from string
-        let bracket_removed_value = value
-            .trim_start_matches('[')
-            .trim_end_matches(']')
-            .to_string();
+        let bracket_removed_value = value.trim_start_matches('[').trim_end_matches(']');
         let raw_values = bracket_removed_value
             .split(',')
             .filter(|v| !v.is_empty())
             .map(|v| v.trim())
             .collect_vec();
+        // `span` is the span of `value` only when it is `value` as written
+        let item_span = |v: &str| {
+            if span.end - span.start != value.len() {
+                return span;
+            }
+            let start = span.start + (v.as_ptr() as usize - value.as_ptr() as usize);
+            ftd_p1::Span::new(start, start + v.len())
+        };
         VariableValue::List {
             value: raw_values
                 .iter()
@@ -382,11 +474,13 @@ impl VariableValue {
                         &Some(v.to_string()),
                         source.clone(),
                         line_number,
+                        item_span(v),
                     ),
                 })
                 .collect_vec(),
             line_number,
             condition,
+            span,
         }
     }
 
@@ -411,6 +505,16 @@ impl VariableValue {
         }
     }
 
+    pub fn span(&self) -> ftd_p1::Span {
+        match self {
+            VariableValue::Optional { span, .. }
+            | VariableValue::Constant { span, .. }
+            | VariableValue::List { span, .. }
+            | VariableValue::Record { span, .. }
+            | VariableValue::String { span, .. } => *span,
+        }
+    }
+
     pub fn set_line_number(&mut self, new_line_number: usize) {
         match self {
             VariableValue::Optional { line_number, .. }
@@ -442,6 +546,7 @@ impl VariableValue {
                 line_number,
                 source,
                 condition,
+                span,
             } => {
                 // Bracket list from string
                 let bracket_list = VariableValue::from_string_bracket_list(
@@ -450,6 +555,7 @@ impl VariableValue {
                     source,
                     line_number,
                     condition,
+                    span,
                 );
                 match bracket_list {
                     VariableValue::List { value, .. } => {
@@ -515,7 +621,10 @@ impl VariableValue {
         match self {
             VariableValue::Record { body, .. } => Ok(body.clone()),
             VariableValue::String {
-                value, line_number, ..
+                value,
+                line_number,
+                span,
+                ..
             } => {
                 if value.is_empty() {
                     return Ok(None);
@@ -523,6 +632,7 @@ impl VariableValue {
                 Ok(Some(BodyValue {
                     value: value.to_string(),
                     line_number: *line_number,
+                    span: *span,
                 }))
             }
             VariableValue::List { value, .. } => {
@@ -545,6 +655,7 @@ impl VariableValue {
             t => VariableValue::Optional {
                 line_number: t.line_number(),
                 condition: t.condition().clone(),
+                span: t.span(),
                 value: Box::new(Some(t)),
             },
         }
@@ -583,6 +694,7 @@ impl VariableValue {
                         value: vec![],
                         line_number: self.line_number(),
                         condition: self.condition().clone(),
+                        span: self.span(),
                     })
                 } else if self.is_list() || self.is_record() {
                     // todo: check if `end` exists
@@ -591,6 +703,7 @@ impl VariableValue {
                     ref value,
                     ref condition,
                     ref line_number,
+                    ref span,
                     ..
                 } = self
                 {
@@ -604,10 +717,12 @@ impl VariableValue {
                             body: Some(BodyValue {
                                 value: value.to_string(),
                                 line_number: *line_number,
+                                span: *span,
                             }),
                             values: vec![],
                             line_number: self.line_number(),
                             condition: None,
+                            span: *span,
                         })
                     } else {
                         Ok(VariableValue::from_string_bracket_list(
@@ -616,6 +731,7 @@ impl VariableValue {
                             ftd_ast::ValueSource::Default,
                             self.line_number(),
                             condition.clone(),
+                            *span,
                         ))
                     }
                 } else {
@@ -677,6 +793,8 @@ impl VariableValue {
                     header.get_line_number(),
                     header.get_kind(),
                     header.get_condition(),
+                    header.get_key_span(),
+                    header.get_condition_span().unwrap_or_default(),
                 ))
             })
             .collect::<ftd_ast::Result<Vec<HeaderValue>>>()?;
@@ -685,7 +803,7 @@ impl VariableValue {
         let body = section
             .body
             .as_ref()
-            .map(|v| BodyValue::new(v.get_value().as_str(), v.line_number));
+            .map(|v| BodyValue::new(v.get_value().as_str(), v.line_number, v.span));
 
         if values.is_empty() && headers.is_empty() && !(caption.is_some() && body.is_some()) {
             return Ok(if let Some(caption) = caption {
@@ -696,12 +814,14 @@ impl VariableValue {
                     line_number: body.line_number,
                     source: ftd_ast::ValueSource::Body,
                     condition,
+                    span: body.span,
                 }
             } else {
                 VariableValue::Optional {
                     value: Box::new(None),
                     line_number: section.line_number,
                     condition,
+                    span: section.span,
                 }
             });
         }
@@ -711,6 +831,7 @@ impl VariableValue {
                 value: values,
                 line_number: section.line_number,
                 condition,
+                span: section.span,
             });
         }
 
@@ -722,6 +843,7 @@ impl VariableValue {
             values,
             line_number: section.line_number,
             condition,
+            span: section.span,
         })
     }
 
@@ -731,12 +853,19 @@ impl VariableValue {
     ) -> ftd_ast::Result<VariableValue> {
         Ok(match header {
             ftd_p1::Header::KV(ftd_p1::KV {
-                value, line_number, ..
-            }) => VariableValue::from_value(value, ftd_ast::ValueSource::Default, *line_number),
+                value,
+                line_number,
+                span,
+                ..
+            }) => {
+                VariableValue::from_value(value, ftd_ast::ValueSource::Default, *line_number, *span)
+            }
             ftd_p1::Header::Section(ftd_p1::SectionHeader {
                 section,
                 line_number,
                 condition,
+                span,
+                condition_span,
                 ..
             }) => VariableValue::List {
                 value: section
@@ -749,9 +878,10 @@ impl VariableValue {
                     })
                     .collect::<ftd_ast::Result<Vec<VariableKeyValue>>>()?,
                 line_number: *line_number,
-                condition: condition
-                    .as_ref()
-                    .map(|expr| ftd_ast::Condition::new(expr, *line_number)),
+                condition: condition.as_ref().map(|expr| {
+                    ftd_ast::Condition::new(expr, *line_number, condition_span.unwrap_or_default())
+                }),
+                span: *span,
             },
             ftd_p1::Header::BlockRecordHeader(ftd_p1::BlockRecordHeader {
                 key,
@@ -760,6 +890,8 @@ impl VariableValue {
                 fields,
                 line_number,
                 condition,
+                span,
+                condition_span,
                 ..
             }) => VariableValue::Record {
                 name: key.to_string(),
@@ -768,6 +900,7 @@ impl VariableValue {
                     line_number: *line_number,
                     source: ValueSource::Caption,
                     condition: None,
+                    span: *span,
                 })),
                 headers: {
                     let mut headers = vec![];
@@ -780,6 +913,8 @@ impl VariableValue {
                             header.get_line_number(),
                             header.get_kind(),
                             header.get_condition(),
+                            header.get_key_span(),
+                            header.get_condition_span().unwrap_or_default(),
                         ));
                     }
                     HeaderValues(headers)
@@ -787,12 +922,13 @@ impl VariableValue {
                 body: body
                     .0
                     .as_ref()
-                    .map(|b| BodyValue::new(b.as_str(), body.1.unwrap_or(0))),
+                    .map(|b| BodyValue::new(b.as_str(), body.1.unwrap_or(0), *span)),
                 values: vec![],
                 line_number: *line_number,
-                condition: condition
-                    .as_ref()
-                    .map(|expr| ftd_ast::Condition::new(expr, *line_number)),
+                condition: condition.as_ref().map(|expr| {
+                    ftd_ast::Condition::new(expr, *line_number, condition_span.unwrap_or_default())
+                }),
+                span: *span,
             },
         })
     }
@@ -801,6 +937,7 @@ impl VariableValue {
         value: &Option<String>,
         source: ftd_ast::ValueSource,
         line_number: usize,
+        span: ftd_p1::Span,
     ) -> VariableValue {
         match value {
             Some(value) if value.ne(NULL) && !value.is_empty() => VariableValue::String {
@@ -808,11 +945,13 @@ impl VariableValue {
                 line_number,
                 source,
                 condition: None,
+                span,
             },
             _ => VariableValue::Optional {
                 value: Box::new(None),
                 line_number,
                 condition: None,
+                span,
             },
         }
     }
@@ -838,16 +977,23 @@ pub struct Condition {
     pub expression: String,
     #[serde(rename = "line-number")]
     pub line_number: usize,
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 impl Condition {
-    pub fn new(expression: &str, line_number: usize) -> Condition {
+    pub fn new(expression: &str, line_number: usize, span: ftd_p1::Span) -> Condition {
         Condition {
             expression: expression.to_string(),
             line_number,
+            span,
         }
     }
 
+    pub(crate) fn rename_reference(&mut self, from: &str, to: &str) {
+        self.expression = ftd_ast::utils::rename_reference(self.expression.as_str(), from, to);
+    }
+
     pub(crate) fn from_headers(
         headers: &ftd_p1::Headers,
         doc_id: &str,
@@ -871,6 +1017,7 @@ impl Condition {
         Ok(Some(Condition::new(
             expression.as_str(),
             condition.get_line_number(),
+            condition.get_span(),
         )))
     }
 }
@@ -20,7 +20,8 @@ mod web_component;
 
 pub use ast::Ast;
 pub use component::{
-    Argument, ComponentDefinition, ComponentInvocation, Event, Loop, Property, PropertySource,
+    Argument, ComponentDefinition, ComponentInvocation, Event, Loop, Match, Property,
+    PropertySource,
 };
 pub use constants::ALWAYS_INCLUDE;
 pub use function::Function;
@@ -34,7 +35,7 @@ pub use record::{Field, Record};
 pub use variable::{VariableDefinition, VariableFlags, VariableInvocation};
 pub use web_component::WebComponentDefinition;
 
-#[derive(thiserror::Error, Debug)]
+#[derive(thiserror::Error, Debug, PartialEq)]
 pub enum Error {
     #[error("P1Error: {}", _0)]
     P1(#[from] ftd_p1::Error),
@@ -48,10 +49,71 @@ pub enum Error {
 
     #[error("ParseBoolError: {}", _0)]
     ParseBool(#[from] std::str::ParseBoolError),
+
+    #[error("{}", .errors.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("\n"))]
+    Multiple { errors: Vec<Error> },
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+impl ftd_p1::Reportable for Error {
+    fn multiple(errors: Vec<Error>) -> Error {
+        Error::Multiple { errors }
+    }
+
+    fn into_errors(self) -> Vec<Error> {
+        match self {
+            Error::Multiple { errors } => errors.into_iter().flat_map(Error::into_errors).collect(),
+            Error::P1(e) => e.into_errors().into_iter().map(Error::P1).collect(),
+            e => vec![e],
+        }
+    }
+
+    fn doc_id(&self) -> Option<&str> {
+        match self {
+            Error::P1(e) => e.doc_id(),
+            Error::Parse { doc_id, .. } => Some(doc_id),
+            Error::ParseBool(_) => None,
+            Error::Multiple { errors } => errors.first().and_then(Error::doc_id),
+        }
+    }
+
+    fn line_number(&self) -> Option<usize> {
+        match self {
+            Error::P1(e) => e.line_number(),
+            Error::Parse { line_number, .. } => Some(*line_number),
+            Error::ParseBool(_) => None,
+            Error::Multiple { errors } => errors.first().and_then(Error::line_number),
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Error::P1(e) => e.code(),
+            Error::Parse { .. } | Error::ParseBool(_) => ftd_p1::codes::INVALID_DEFINITION,
+            Error::Multiple { errors } => errors
+                .first()
+                .map_or(ftd_p1::codes::INVALID_DEFINITION, Error::code),
+        }
+    }
+
+    fn span(&self) -> Option<ftd_p1::Span> {
+        match self {
+            Error::P1(e) => e.span(),
+            Error::Multiple { errors } => errors.first().and_then(Error::span),
+            _ => None,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Error::P1(e) => e.message(),
+            Error::Parse { message, .. } => message.to_string(),
+            _ => self.to_string(),
+        }
+    }
+}
+
 pub fn parse_error<T, S1>(m: S1, doc_id: &str, line_number: usize) -> ftd_ast::Result<T>
 where
     S1: Into<String>,
@@ -3,16 +3,25 @@ pub struct OrType {
     pub name: String,
     pub variants: Vec<OrTypeVariant>,
     pub line_number: usize,
+    /// the name
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 pub const ORTYPE: &str = "or-type";
 
 impl OrType {
-    fn new(name: &str, variants: Vec<ftd_ast::OrTypeVariant>, line_number: usize) -> OrType {
+    fn new(
+        name: &str,
+        variants: Vec<ftd_ast::OrTypeVariant>,
+        line_number: usize,
+        span: ftd_p1::Span,
+    ) -> OrType {
         OrType {
             name: name.to_string(),
             variants,
             line_number,
+            span,
         }
     }
 
@@ -37,6 +46,7 @@ impl OrType {
             section.name.as_str(),
             variants,
             section.line_number,
+            section.span,
         ))
     }
 
@@ -77,6 +87,7 @@ impl ftd_ast::Field {
             value,
             section.line_number,
             Default::default(),
+            section.span,
         ))
     }
 }
@@ -119,9 +130,13 @@ impl OrTypeVariant {
     }
 
     pub(crate) fn is_constant(section: &ftd_p1::Section) -> bool {
-        section
-            .name
-            .starts_with(format!("{} ", ftd_ast::constants::CONSTANT).as_str())
+        let constant = format!("{} ", ftd_ast::constants::CONSTANT);
+        // `-- constant string loading: loading` has `constant string` as its kind
+        section.name.starts_with(constant.as_str())
+            || section
+                .kind
+                .as_ref()
+                .is_some_and(|kind| kind.starts_with(constant.as_str()))
     }
 
     pub fn from_p1(section: &ftd_p1::Section, doc_id: &str) -> ftd_ast::Result<OrTypeVariant> {
@@ -140,7 +155,7 @@ impl OrTypeVariant {
                 &section, doc_id,
             )?))
         } else {
-            Ok(OrTypeVariant::new_constant(ftd_ast::Field::from_p1(
+            Ok(OrTypeVariant::new_variant(ftd_ast::Field::from_p1(
                 section, doc_id,
             )?))
         }
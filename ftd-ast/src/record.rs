@@ -3,14 +3,18 @@ pub struct Record {
     pub name: String,
     pub fields: Vec<Field>,
     pub line_number: usize,
+    /// the name
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 impl Record {
-    fn new(name: &str, fields: Vec<Field>, line_number: usize) -> Record {
+    fn new(name: &str, fields: Vec<Field>, line_number: usize, span: ftd_p1::Span) -> Record {
         Record {
             name: name.to_string(),
             fields,
             line_number,
+            span,
         }
     }
 
@@ -35,6 +39,7 @@ impl Record {
             section.name.as_str(),
             fields,
             section.line_number,
+            section.span,
         ))
     }
 
@@ -51,6 +56,9 @@ pub struct Field {
     pub value: Option<ftd_ast::VariableValue>,
     pub line_number: usize,
     pub access_modifier: ftd_p1::AccessModifier,
+    /// the name
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 impl Field {
@@ -85,6 +93,7 @@ impl Field {
             value,
             header.get_line_number(),
             header.get_access_modifier(),
+            header.get_key_span(),
         ))
     }
 
@@ -95,6 +104,7 @@ impl Field {
         value: Option<ftd_ast::VariableValue>,
         line_number: usize,
         access_modifier: ftd_p1::AccessModifier,
+        span: ftd_p1::Span,
     ) -> Field {
         Field {
             name: name.to_string(),
@@ -103,6 +113,7 @@ impl Field {
             value,
             line_number,
             access_modifier,
+            span,
         }
     }
 }
@@ -99,3 +99,29 @@ fn filename_with_second_last_extension_replaced_with_json(
         }
     ))
 }
+
+#[test]
+fn all_errors() {
+    use ftd_p1::Reportable;
+
+    // the sections after a section with an error are checked too
+    let sections = ftd_p1::parse(
+        "-- import:\n\n-- ftd.text: hello\n\n-- record foo:\nbar:\n",
+        "foo",
+    )
+    .unwrap_or_else(|e| panic!("{:?}", e));
+    let (ast, errors) = ftd_ast::Ast::from_sections_with_errors(sections.as_slice(), "foo");
+    assert_eq!(
+        ast.iter().map(|v| v.name()).collect::<Vec<_>>(),
+        vec!["ftd.text".to_string()]
+    );
+    assert_eq!(
+        errors
+            .iter()
+            .map(|e| (e.line_number(), e.doc_id()))
+            .collect::<Vec<_>>(),
+        vec![(Some(1), Some("foo")), (Some(6), Some("foo"))]
+    );
+    assert!(ftd_ast::Ast::from_sections(sections.as_slice(), "foo")
+        .is_err_and(|e| e.into_errors().len() == 2));
+}
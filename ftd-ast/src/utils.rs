@@ -24,6 +24,39 @@ pub fn get_import_alias(input: &str) -> (String, String) {
     (module.to_string(), module)
 }
 
+/// Replaces every reference to `from` in `text` with `to`. References are written as `$from`,
+/// `$from.field` etc; inside expressions (`{ ... }`) bare `from` is a reference too.
+pub(crate) fn rename_reference(text: &str, from: &str, to: &str) -> String {
+    fn is_identifier_char(c: char) -> bool {
+        c.is_alphanumeric() || c == '-' || c == '_'
+    }
+
+    let is_expression = text.trim_start().starts_with('{');
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(position) = rest.find(from) {
+        let before = rest[..position]
+            .chars()
+            .next_back()
+            .or(result.chars().next_back());
+        let after = rest[position + from.len()..].chars().next();
+        let is_reference = match before {
+            Some('$') => true,
+            Some(c) => is_expression && !is_identifier_char(c) && !matches!(c, '.' | '#'),
+            None => is_expression,
+        } && !after.is_some_and(is_identifier_char);
+        result.push_str(&rest[..position]);
+        if is_reference {
+            result.push_str(to.trim_start_matches(REFERENCE));
+        } else {
+            result.push_str(from);
+        }
+        rest = &rest[position + from.len()..];
+    }
+    result.push_str(rest);
+    result
+}
+
 pub(crate) fn is_variable_mutable(name: &str) -> bool {
     name.starts_with(REFERENCE)
         && !name.eq(ftd_ast::utils::PROCESSOR)
@@ -95,5 +128,8 @@ pub const AS: &str = " as ";
 pub const IN: &str = " in ";
 pub const IF: &str = "if";
 pub const FOR: &str = "for";
+pub const KEY: &str = "key";
+pub const MATCH: &str = "match";
+pub const IS: &str = " is ";
 pub const PROCESSOR: &str = "$processor$";
 pub const HEADER_KEY_START: &str = "$header-";
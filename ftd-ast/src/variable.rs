@@ -7,9 +7,13 @@ pub struct VariableDefinition {
     pub processor: Option<String>,
     pub flags: VariableFlags,
     pub line_number: usize,
+    /// the name
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 impl VariableDefinition {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         name: &str,
         kind: ftd_ast::VariableKind,
@@ -18,6 +22,7 @@ impl VariableDefinition {
         processor: Option<String>,
         flags: VariableFlags,
         line_number: usize,
+        span: ftd_p1::Span,
     ) -> VariableDefinition {
         VariableDefinition {
             kind,
@@ -27,6 +32,7 @@ impl VariableDefinition {
             processor,
             flags,
             line_number,
+            span,
         }
     }
 
@@ -78,6 +84,7 @@ impl VariableDefinition {
             processor,
             flags,
             section.line_number,
+            section.span,
         ))
     }
 
@@ -93,6 +100,9 @@ pub struct VariableInvocation {
     pub condition: Option<ftd_ast::Condition>,
     pub processor: Option<String>,
     pub line_number: usize,
+    /// the name
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 impl VariableInvocation {
@@ -102,6 +112,7 @@ impl VariableInvocation {
         condition: Option<ftd_ast::Condition>,
         processor: Option<String>,
         line_number: usize,
+        span: ftd_p1::Span,
     ) -> VariableInvocation {
         VariableInvocation {
             name: name.to_string(),
@@ -109,6 +120,7 @@ impl VariableInvocation {
             condition,
             processor,
             line_number,
+            span,
         }
     }
 
@@ -146,6 +158,7 @@ impl VariableInvocation {
             condition,
             processor,
             section.line_number,
+            section.span,
         ))
     }
 }
@@ -153,12 +166,15 @@ impl VariableInvocation {
 #[derive(Debug, PartialEq, Clone, serde::Serialize, Default, serde::Deserialize)]
 pub struct VariableFlags {
     pub always_include: Option<bool>,
+    /// `$sse$: <url>`, the server-sent events stream that updates the variable
+    pub sse: Option<String>,
 }
 
 impl VariableFlags {
     pub fn new() -> VariableFlags {
         VariableFlags {
             always_include: None,
+            sse: None,
         }
     }
 
@@ -168,14 +184,28 @@ impl VariableFlags {
         variable_flag
     }
 
+    pub fn set_sse(self, url: &str) -> VariableFlags {
+        let mut variable_flag = self;
+        variable_flag.sse = Some(url.to_string());
+        variable_flag
+    }
+
+    fn merge(self, other: VariableFlags) -> VariableFlags {
+        VariableFlags {
+            always_include: other.always_include.or(self.always_include),
+            sse: other.sse.or(self.sse),
+        }
+    }
+
     pub fn from_headers(headers: &ftd_p1::Headers, doc_id: &str) -> VariableFlags {
+        let mut flags = ftd_ast::VariableFlags::new();
         for header in headers.0.iter() {
             if let Ok(flag) = ftd_ast::VariableFlags::from_header(header, doc_id) {
-                return flag;
+                flags = flags.merge(flag);
             }
         }
 
-        ftd_ast::VariableFlags::new()
+        flags
     }
 
     pub fn from_header(header: &ftd_p1::Header, doc_id: &str) -> ftd_ast::Result<VariableFlags> {
@@ -215,6 +245,19 @@ impl VariableFlags {
                     Ok(VariableFlags::new())
                 }
             }
+            ftd_ast::constants::SSE => {
+                let url = kv
+                    .value
+                    .as_ref()
+                    .map(|v| v.trim())
+                    .filter(|v| !v.is_empty())
+                    .ok_or(ftd_ast::Error::Parse {
+                        message: "Url expected for `$sse$` flag found `null`".to_string(),
+                        doc_id: doc_id.to_string(),
+                        line_number: kv.line_number,
+                    })?;
+                Ok(VariableFlags::new().set_sse(url))
+            }
             t => ftd_ast::parse_error(format!("Unknown flag found`{}`", t), doc_id, kv.line_number),
         }
     }
@@ -4,6 +4,9 @@ pub struct WebComponentDefinition {
     pub arguments: Vec<ftd_ast::Argument>,
     pub js: String,
     pub line_number: usize,
+    /// the name
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 pub const WEB_COMPONENT: &str = "web-component";
@@ -14,12 +17,14 @@ impl WebComponentDefinition {
         arguments: Vec<ftd_ast::Argument>,
         js: String,
         line_number: usize,
+        span: ftd_p1::Span,
     ) -> WebComponentDefinition {
         WebComponentDefinition {
             name: name.to_string(),
             arguments,
             js,
             line_number,
+            span,
         }
     }
 
@@ -54,6 +59,7 @@ impl WebComponentDefinition {
                 line_number: section.line_number,
             })?,
             section.line_number,
+            section.span,
         ))
     }
 
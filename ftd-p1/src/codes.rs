@@ -0,0 +1,50 @@
+//! The codes of the errors, shown like `error[FTD0102]: ...`. The codes never
+//! change, so they can be looked up in the docs and by editors, and each one
+//! is explained by `fastn explain <code>`.
+//!
+//! - `FTD00xx`: the sections of a document can not be parsed
+//! - `FTD01xx`: a section is not a valid definition or invocation
+//! - `FTD02xx`: the interpreter can not make sense of the document
+//! - `FTD03xx`: fastn can not build the document
+
+/// A code, with what kind of error it is shown for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Code {
+    pub code: &'static str,
+    pub title: &'static str,
+}
+
+macro_rules! codes {
+    ($($name:ident = $code:literal => $title:literal,)*) => {
+        $(pub const $name: &str = $code;)*
+
+        /// All the codes, every one of them has an explanation.
+        pub const ALL: &[Code] = &[$(Code { code: $code, title: $title },)*];
+    };
+}
+
+codes! {
+    INVALID_SYNTAX = "FTD0001" => "invalid syntax",
+    MORE_THAN_ONE_CAPTION = "FTD0002" => "more than one caption",
+    HEADER_GIVEN_MORE_THAN_ONCE = "FTD0003" => "header given more than once",
+    MISSING_HEADER = "FTD0004" => "missing header",
+    SECTION_NOT_FOUND = "FTD0005" => "section not found",
+    INVALID_DEFINITION = "FTD0101" => "invalid definition",
+    UNKNOWN_HEADER = "FTD0102" => "unknown header",
+    MUTABILITY_MISMATCH = "FTD0103" => "mutability mismatch",
+    CAPTION_OR_BODY_NOT_ACCEPTED = "FTD0104" => "caption or body not accepted",
+    INVALID_DOCUMENT = "FTD0200" => "invalid document",
+    NAME_NOT_FOUND = "FTD0201" => "name not found",
+    INVALID_KIND = "FTD0202" => "invalid kind",
+    CYCLE = "FTD0203" => "cycle in definitions",
+    INVALID_ACCESS = "FTD0204" => "invalid access",
+    NON_EXHAUSTIVE_MATCH = "FTD0205" => "non-exhaustive match",
+    NOT_MUTABLE = "FTD0206" => "not mutable",
+    INVALID_VARIANT = "FTD0207" => "invalid or-type variant",
+    MISSING_VALUE = "FTD0208" => "missing value",
+    INVALID_EVENT = "FTD0209" => "invalid event",
+    NOT_SUPPORTED = "FTD0210" => "not supported",
+    DUPLICATE = "FTD0211" => "given more than once",
+    PROCESSOR_FAILED = "FTD0301" => "processor failed",
+    MISSING_LINK = "FTD0302" => "link to a missing document",
+}
@@ -16,6 +16,11 @@ pub struct BlockRecordHeader {
     pub fields: Vec<Header>,
     pub condition: Option<String>,
     pub line_number: usize,
+    /// the key
+    #[serde(skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition_span: Option<ftd_p1::Span>,
 }
 
 impl BlockRecordHeader {
@@ -36,6 +41,8 @@ impl BlockRecordHeader {
             fields,
             condition,
             line_number,
+            span: Default::default(),
+            condition_span: None,
         }
     }
 }
@@ -58,6 +65,14 @@ pub struct KV {
     pub condition: Option<String>,
     pub access_modifier: AccessModifier,
     pub source: KVSource,
+    /// the value when it is on the line of the key, or the lines of it when
+    /// it is a body, else the key
+    #[serde(skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
+    #[serde(skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub key_span: ftd_p1::Span,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition_span: Option<ftd_p1::Span>,
 }
 
 impl KV {
@@ -84,6 +99,9 @@ impl KV {
             condition,
             access_modifier,
             source: source.unwrap_or_default(),
+            span: Default::default(),
+            key_span: Default::default(),
+            condition_span: None,
         }
     }
 }
@@ -145,6 +163,11 @@ pub struct SectionHeader {
     pub kind: Option<String>,
     pub section: Vec<ftd_p1::Section>,
     pub condition: Option<String>,
+    /// the key
+    #[serde(skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition_span: Option<ftd_p1::Span>,
 }
 
 impl Header {
@@ -183,6 +206,8 @@ impl Header {
             kind,
             section,
             condition,
+            span: Default::default(),
+            condition_span: None,
         })
     }
 
@@ -213,11 +238,16 @@ impl Header {
             Header::KV(kv) => {
                 let mut kv = (*kv).clone();
                 kv.line_number = 0;
+                kv.span = Default::default();
+                kv.key_span = Default::default();
+                kv.condition_span = None;
                 Header::KV(kv)
             }
             Header::Section(s) => {
                 let mut s = (*s).clone();
                 s.line_number = 0;
+                s.span = Default::default();
+                s.condition_span = None;
                 s.section = s
                     .section
                     .iter()
@@ -228,6 +258,8 @@ impl Header {
             Header::BlockRecordHeader(b) => {
                 let mut blockrecord = (*b).clone();
                 blockrecord.line_number = 0;
+                blockrecord.span = Default::default();
+                blockrecord.condition_span = None;
                 Header::BlockRecordHeader(blockrecord)
             }
         }
@@ -329,6 +361,34 @@ impl Header {
         }
     }
 
+    /// The span of the value of the header when it is on the line of the key,
+    /// or of the lines of it when it is a body, else of the key.
+    pub fn get_span(&self) -> ftd_p1::Span {
+        match self {
+            Header::KV(ftd_p1::header::KV { span, .. })
+            | Header::Section(ftd_p1::header::SectionHeader { span, .. })
+            | Header::BlockRecordHeader(ftd_p1::header::BlockRecordHeader { span, .. }) => *span,
+        }
+    }
+
+    pub fn get_key_span(&self) -> ftd_p1::Span {
+        match self {
+            Header::KV(ftd_p1::header::KV { key_span, .. }) => *key_span,
+            Header::Section(ftd_p1::header::SectionHeader { span, .. })
+            | Header::BlockRecordHeader(ftd_p1::header::BlockRecordHeader { span, .. }) => *span,
+        }
+    }
+
+    pub fn get_condition_span(&self) -> Option<ftd_p1::Span> {
+        match self {
+            Header::KV(ftd_p1::header::KV { condition_span, .. })
+            | Header::Section(ftd_p1::header::SectionHeader { condition_span, .. })
+            | Header::BlockRecordHeader(ftd_p1::header::BlockRecordHeader {
+                condition_span, ..
+            }) => *condition_span,
+        }
+    }
+
     pub fn get_kind(&self) -> Option<String> {
         match self {
             Header::KV(ftd_p1::header::KV { kind, .. })
@@ -433,6 +493,7 @@ impl Headers {
                 key: key.to_string(),
                 doc_id: doc_id.to_string(),
                 line_number: header.get_line_number(),
+                span: header.get_key_span(),
             });
         }
         Ok(header)
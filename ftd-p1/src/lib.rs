@@ -8,17 +8,22 @@ pub type Map<T> = std::collections::BTreeMap<String, T>;
 #[macro_use]
 mod test;
 
+pub mod codes;
 pub(crate) mod header;
 mod parser;
 mod section;
+mod snippet;
+mod span;
 pub mod utils;
 
 pub use header::{AccessModifier, BlockRecordHeader, Header, Headers, SectionHeader, KV};
-pub use parser::{parse, parse_with_line_number};
+pub use parser::{parse, parse_with_errors, parse_with_line_number};
 pub use section::Body;
 pub use section::Section;
+pub use snippet::{Report, Snippet};
+pub use span::{Label, Span};
 
-#[derive(thiserror::Error, Debug)]
+#[derive(thiserror::Error, Debug, PartialEq)]
 pub enum Error {
     #[error("{doc_id}:{line_number} -> SectionNotFound")]
     SectionNotFound { doc_id: String, line_number: usize },
@@ -38,6 +43,7 @@ pub enum Error {
         key: String,
         doc_id: String,
         line_number: usize,
+        span: Span,
     },
 
     #[error("{doc_id}:{line_number} -> HeaderNotFound for key {key}")]
@@ -46,6 +52,125 @@ pub enum Error {
         doc_id: String,
         line_number: usize,
     },
+
+    #[error("{}", .errors.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("\n"))]
+    Multiple { errors: Vec<Error> },
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
+
+/// What the errors of the parser, of `ftd-ast` and of the interpreter tell
+/// about themselves, so they are all reported the same way.
+pub trait Reportable: Sized + std::fmt::Display {
+    /// The error made of `errors`, there is more than one of them.
+    fn multiple(errors: Vec<Self>) -> Self;
+
+    /// The error, or the one made of all of them when there is more than one.
+    fn from_errors(mut errors: Vec<Self>) -> Option<Self> {
+        match errors.len() {
+            0 => None,
+            1 => errors.pop(),
+            _ => Some(Self::multiple(errors)),
+        }
+    }
+
+    /// The errors in this error, one for each line they are on.
+    fn into_errors(self) -> Vec<Self>;
+
+    fn doc_id(&self) -> Option<&str>;
+
+    fn line_number(&self) -> Option<usize>;
+
+    /// The code of the error, explained by `fastn explain <code>`.
+    fn code(&self) -> &'static str;
+
+    /// The error without the document and the line it is on.
+    fn message(&self) -> String;
+
+    /// What the error is about, in the document of the error, if it is
+    /// known.
+    fn span(&self) -> Option<Span> {
+        None
+    }
+
+    /// The other lines the error is about.
+    fn labels(&self) -> &[Label] {
+        &[]
+    }
+
+    fn help(&self) -> Option<&str> {
+        None
+    }
+
+    /// The text to put in place of the span of the error to fix it.
+    fn suggestion(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl Reportable for Error {
+    fn multiple(errors: Vec<Error>) -> Error {
+        Error::Multiple { errors }
+    }
+
+    fn into_errors(self) -> Vec<Error> {
+        match self {
+            Error::Multiple { errors } => errors.into_iter().flat_map(Error::into_errors).collect(),
+            e => vec![e],
+        }
+    }
+
+    fn doc_id(&self) -> Option<&str> {
+        match self {
+            Error::SectionNotFound { doc_id, .. }
+            | Error::MoreThanOneCaption { doc_id, .. }
+            | Error::ParseError { doc_id, .. }
+            | Error::MoreThanOneHeader { doc_id, .. }
+            | Error::HeaderNotFound { doc_id, .. } => Some(doc_id),
+            Error::Multiple { errors } => errors.first().and_then(Error::doc_id),
+        }
+    }
+
+    fn line_number(&self) -> Option<usize> {
+        match self {
+            Error::SectionNotFound { line_number, .. }
+            | Error::MoreThanOneCaption { line_number, .. }
+            | Error::ParseError { line_number, .. }
+            | Error::MoreThanOneHeader { line_number, .. }
+            | Error::HeaderNotFound { line_number, .. } => Some(*line_number),
+            Error::Multiple { errors } => errors.first().and_then(Error::line_number),
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Error::ParseError { .. } => ftd_p1::codes::INVALID_SYNTAX,
+            Error::MoreThanOneCaption { .. } => ftd_p1::codes::MORE_THAN_ONE_CAPTION,
+            Error::MoreThanOneHeader { .. } => ftd_p1::codes::HEADER_GIVEN_MORE_THAN_ONCE,
+            Error::HeaderNotFound { .. } => ftd_p1::codes::MISSING_HEADER,
+            Error::SectionNotFound { .. } => ftd_p1::codes::SECTION_NOT_FOUND,
+            Error::Multiple { errors } => errors
+                .first()
+                .map_or(ftd_p1::codes::INVALID_SYNTAX, Error::code),
+        }
+    }
+
+    fn span(&self) -> Option<Span> {
+        match self {
+            Error::MoreThanOneHeader { span, .. } if !span.is_empty() => Some(*span),
+            Error::Multiple { errors } => errors.first().and_then(Error::span),
+            _ => None,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Error::SectionNotFound { .. } => "SectionNotFound".to_string(),
+            Error::MoreThanOneCaption { .. } => "MoreThanOneCaption".to_string(),
+            Error::ParseError { message, .. } => message.to_string(),
+            Error::MoreThanOneHeader { key, .. } => format!("MoreThanOneHeader for key {key}"),
+            Error::HeaderNotFound { key, .. } => format!("HeaderNotFound for key {key}"),
+            Error::Multiple { .. } => self.to_string(),
+        }
+    }
+}
@@ -7,6 +7,7 @@ enum ParsingStateReading {
         kind: Option<String>,
         condition: Option<String>,
         line_number: usize,
+        spans: HeaderSpans,
     },
     Caption,
     Body,
@@ -14,15 +15,16 @@ enum ParsingStateReading {
 }
 
 #[derive(Debug)]
-pub struct State {
+pub struct State<'a> {
     line_number: i32,
     sections: Vec<ftd_p1::Section>,
     content: String,
     doc_id: String,
     state: Vec<(ftd_p1::Section, Vec<ParsingStateReading>)>,
+    lines: &'a ftd_p1::span::Lines<'a>,
 }
 
-impl State {
+impl State<'_> {
     fn next(&mut self) -> ftd_p1::Result<()> {
         use itertools::Itertools;
 
@@ -54,8 +56,16 @@ impl State {
                     condition,
                     caption,
                     line_number,
+                    spans,
                 } => {
-                    self.reading_header_value(key.as_str(), caption, kind, condition, line_number)?;
+                    self.reading_header_value(
+                        key.as_str(),
+                        caption,
+                        kind,
+                        condition,
+                        line_number,
+                        spans,
+                    )?;
                 }
                 ParsingStateReading::Caption => {
                     self.reading_caption_value()?;
@@ -117,16 +127,17 @@ impl State {
                         key,
                         kind,
                         condition,
+                        spans,
                         ..
                     } if caption.eq(format!("{}.{}", section.name, key).as_str()) => {
                         sections.reverse();
-                        section.headers.push(ftd_p1::Header::section(
+                        section.headers.push(spans.set(ftd_p1::Header::section(
                             ftd_p1::utils::i32_to_usize(line_number),
                             key.as_str(),
                             kind,
                             sections,
                             condition,
-                        ));
+                        )));
                         *change_state = Some(ParsingStateReading::Section);
                         break;
                     }
@@ -222,14 +233,15 @@ impl State {
         }
 
         self.line_number += (scan_line_number as i32) + 1;
+        let line_number = ftd_p1::utils::i32_to_usize(self.line_number);
         let section = ftd_p1::Section {
+            span: self.lines.key(line_number, section_name.as_str()),
             name: section_name,
             kind,
             caption: caption.map(|v| {
-                ftd_p1::Header::from_caption(
-                    v.as_str(),
-                    ftd_p1::utils::i32_to_usize(self.line_number),
-                )
+                let span = self.lines.value(line_number, Some(v.as_str()));
+                HeaderSpans::of_lines(span.unwrap_or_default())
+                    .set(ftd_p1::Header::from_caption(v.as_str(), line_number))
             }),
             headers: Default::default(),
             body: None,
@@ -264,14 +276,14 @@ impl State {
                     ftd_p1::Header::BlockRecordHeader(br_header) => {
                         // Existing header is of block record type
                         // So update its fields
-                        let current_field = ftd_p1::Header::kv(
+                        let current_field = header_data.spans.set(ftd_p1::Header::kv(
                             header_data.line_number,
                             field,
                             header_data.kind,
                             header_data.value,
                             header_data.condition,
                             header_data.source,
-                        );
+                        ));
                         br_header.fields.push(current_field);
                     }
                     ftd_p1::Header::KV(kv) => {
@@ -289,55 +301,64 @@ impl State {
                             _ => unimplemented!(),
                         }
 
-                        let block_record_header = ftd_p1::Header::block_record_header(
+                        let block_record_header = HeaderSpans {
+                            span: kv.key_span,
+                            key: kv.key_span,
+                            condition: kv.condition_span,
+                        }
+                        .set(ftd_p1::Header::block_record_header(
                             header,
                             kv.kind.to_owned(),
                             existing_header_caption,
                             existing_header_body,
-                            vec![ftd_p1::Header::kv(
+                            vec![header_data.spans.set(ftd_p1::Header::kv(
                                 header_data.line_number,
                                 field,
                                 header_data.kind,
                                 header_data.value,
                                 header_data.condition,
                                 header_data.source,
-                            )],
+                            ))],
                             kv.condition.to_owned(),
                             kv.line_number,
-                        );
+                        ));
                         *existing_header = block_record_header;
                     }
                     _ => unimplemented!(),
                 }
             } else {
                 // No existing block record header found under section.headers
-                section.headers.push(ftd_p1::Header::block_record_header(
-                    header,
-                    header_data.kind.clone(),
-                    None,
-                    (None, None),
-                    vec![ftd_p1::Header::kv(
+                section
+                    .headers
+                    .push(header_data.spans.set(ftd_p1::Header::block_record_header(
+                        header,
+                        header_data.kind.clone(),
+                        None,
+                        (None, None),
+                        vec![header_data.spans.set(ftd_p1::Header::kv(
+                            header_data.line_number,
+                            field,
+                            header_data.kind,
+                            header_data.value,
+                            header_data.condition.clone(),
+                            header_data.source,
+                        ))],
+                        header_data.condition,
                         header_data.line_number,
-                        field,
-                        header_data.kind,
-                        header_data.value,
-                        header_data.condition.clone(),
-                        header_data.source,
-                    )],
-                    header_data.condition,
-                    header_data.line_number,
-                ));
+                    )));
             }
         } else {
             // Normal header
-            section.headers.push(ftd_p1::Header::kv(
-                header_data.line_number,
-                header_key,
-                header_data.kind,
-                header_data.value,
-                header_data.condition,
-                header_data.source,
-            ));
+            section
+                .headers
+                .push(header_data.spans.set(ftd_p1::Header::kv(
+                    header_data.line_number,
+                    header_key,
+                    header_data.kind,
+                    header_data.value,
+                    header_data.condition,
+                    header_data.source,
+                )));
         }
         Ok(())
     }
@@ -410,11 +431,27 @@ impl State {
         self.content = rest_lines;
         section.block_body = true;
 
+        let key_line_number = ftd_p1::utils::i32_to_usize(self.line_number);
         let condition = get_block_header_condition(
             &mut self.content,
             &mut self.line_number,
             self.doc_id.as_str(),
         )?;
+        let key_span = self.lines.key(key_line_number, key);
+        let spans = HeaderSpans {
+            span: self
+                .lines
+                .value(key_line_number, value.as_deref())
+                .unwrap_or(key_span),
+            key: key_span,
+            // the condition is on the line after the key, `if: { ... }`
+            condition: condition.as_deref().and_then(|condition| {
+                self.lines.value(
+                    ftd_p1::utils::i32_to_usize(self.line_number),
+                    Some(condition),
+                )
+            }),
+        };
 
         if is_caption(key) && kind.is_none() && section.caption.is_some() {
             return Err(ftd_p1::Error::MoreThanOneCaption {
@@ -434,6 +471,7 @@ impl State {
                 condition,
                 Some(ftd_p1::header::KVSource::Caption),
                 ftd_p1::utils::i32_to_usize(self.line_number),
+                spans,
             );
             Self::eval_from_kv_header(key, header_data, section, doc_id.as_str())?;
         } else {
@@ -448,6 +486,7 @@ impl State {
                     kind,
                     condition,
                     line_number: ftd_p1::utils::i32_to_usize(self.line_number),
+                    spans,
                 }
             });
         }
@@ -461,9 +500,11 @@ impl State {
         header_kind: Option<String>,
         header_condition: Option<String>,
         header_line_number: usize,
+        header_spans: HeaderSpans,
     ) -> ftd_p1::Result<()> {
         if let Err(ftd_p1::Error::SectionNotFound { .. }) = self.reading_section() {
             let mut value: (Vec<String>, Option<usize>) = (vec![], None);
+            let mut last_value_line_number = None;
             let mut inline_record_headers: ftd_p1::Map<HeaderData> = ftd_p1::Map::new();
             let mut reading_value = false;
             let mut new_line_number = None;
@@ -502,6 +543,13 @@ impl State {
                         // Caption, kind, condition, line_number
                         let (header_key, kind, condition) =
                             get_name_kind_and_condition(name_with_kind.as_str());
+                        let line_number = ftd_p1::utils::i32_to_usize(self.line_number);
+                        let spans = HeaderSpans::of_header(
+                            self.lines,
+                            line_number,
+                            header_key.as_str(),
+                            caption.as_deref(),
+                        );
                         inline_record_headers.insert(
                             header_key,
                             HeaderData::new(
@@ -509,7 +557,8 @@ impl State {
                                 kind,
                                 condition,
                                 Some(Default::default()),
-                                ftd_p1::utils::i32_to_usize(self.line_number),
+                                line_number,
+                                spans,
                             ),
                         );
                     }
@@ -520,9 +569,17 @@ impl State {
                     if value.1.is_none() {
                         value.1 = Some(ftd_p1::utils::i32_to_usize(self.line_number));
                     }
+                    if !trimmed_line.is_empty() {
+                        last_value_line_number =
+                            Some(ftd_p1::utils::i32_to_usize(self.line_number));
+                    }
                 }
             }
             self.content = content_index(self.content.as_str(), new_line_number);
+            let value_span = match (value.1, last_value_line_number) {
+                (Some(first), Some(last)) => Some(self.lines.lines(first, last)),
+                _ => None,
+            };
             let doc_id = self.doc_id.to_string();
             let _line_number = self.line_number;
             let section = self
@@ -539,29 +596,31 @@ impl State {
                 let fields = inline_record_headers
                     .iter()
                     .map(|(key, data)| {
-                        ftd_p1::Header::kv(
+                        data.spans.set(ftd_p1::Header::kv(
                             data.line_number,
                             key,
                             data.kind.to_owned(),
                             data.value.to_owned(),
                             data.condition.to_owned(),
                             data.source.to_owned(),
-                        )
+                        ))
                     })
                     .collect();
-                section.headers.push(ftd_p1::Header::block_record_header(
-                    header_key,
-                    header_kind,
-                    header_caption,
-                    if value.0.is_empty() {
-                        (None, None)
-                    } else {
-                        (Some(value.0), value.1)
-                    },
-                    fields,
-                    header_condition,
-                    header_line_number,
-                ));
+                section
+                    .headers
+                    .push(header_spans.set(ftd_p1::Header::block_record_header(
+                        header_key,
+                        header_kind,
+                        header_caption,
+                        if value.0.is_empty() {
+                            (None, None)
+                        } else {
+                            (Some(value.0), value.1)
+                        },
+                        fields,
+                        header_condition,
+                        header_line_number,
+                    )));
             } else {
                 let header_data = HeaderData {
                     value: if !value.0.is_empty() {
@@ -573,6 +632,10 @@ impl State {
                     condition: header_condition,
                     source: Some(ftd_p1::header::KVSource::Body),
                     line_number: value.1.unwrap_or(header_line_number),
+                    spans: HeaderSpans {
+                        span: value_span.unwrap_or(header_spans.span),
+                        ..header_spans
+                    },
                 };
                 Self::eval_from_kv_header(header_key, header_data, section, doc_id.as_str())?;
             }
@@ -582,6 +645,7 @@ impl State {
 
     fn reading_caption_value(&mut self) -> ftd_p1::Result<()> {
         let mut value = vec![];
+        let mut value_line_numbers: Option<(usize, usize)> = None;
         let mut new_line_number = None;
         let mut first_line = true;
         let split_content = self.content.as_str().split('\n');
@@ -604,9 +668,19 @@ impl State {
                 }
                 first_line = false;
             }
+            if !line.trim().is_empty() {
+                let line_number = ftd_p1::utils::i32_to_usize(self.line_number);
+                value_line_numbers = Some((
+                    value_line_numbers.map_or(line_number, |(first, _)| first),
+                    line_number,
+                ));
+            }
             value.push(clean_line(line));
         }
         self.content = content_index(self.content.as_str(), new_line_number);
+        let span = value_line_numbers
+            .map(|(first, last)| self.lines.lines(first, last))
+            .unwrap_or_default();
         let doc_id = self.doc_id.to_string();
         let line_number = self.line_number;
         let section = self
@@ -618,15 +692,18 @@ impl State {
             .0;
 
         let value = value.join("\n").trim().to_string();
-        section.caption = Some(ftd_p1::Header::from_caption(
-            value.as_str(),
-            ftd_p1::utils::i32_to_usize(line_number),
-        ));
+        section.caption = Some(
+            HeaderSpans::of_lines(span).set(ftd_p1::Header::from_caption(
+                value.as_str(),
+                ftd_p1::utils::i32_to_usize(line_number),
+            )),
+        );
         Ok(())
     }
 
     fn reading_body_value(&mut self) -> ftd_p1::Result<()> {
         let mut value = vec![];
+        let mut value_line_numbers: Option<(usize, usize)> = None;
         let mut new_line_number = None;
         let mut first_line = true;
         let split_content = self.content.as_str().split('\n');
@@ -649,10 +726,18 @@ impl State {
                 }
                 first_line = false;
             }
+            if !line.trim().is_empty() {
+                let line_number = ftd_p1::utils::i32_to_usize(self.line_number);
+                value_line_numbers = Some((
+                    value_line_numbers.map_or(line_number, |(first, _)| first),
+                    line_number,
+                ));
+            }
 
             value.push(clean_line(line));
         }
         self.content = content_index(self.content.as_str(), new_line_number);
+        let span = value_line_numbers.map(|(first, last)| self.lines.lines(first, last));
         let doc_id = self.doc_id.to_string();
         let line_number = self.line_number;
         let section = self
@@ -664,10 +749,12 @@ impl State {
             .0;
         let value = value.join("\n").to_string();
         if !value.trim().is_empty() {
-            section.body = Some(ftd_p1::Body::new(
+            let mut body = ftd_p1::Body::new(
                 ftd_p1::utils::i32_to_usize(line_number),
                 trim_body(value.as_str()).as_str(),
-            ));
+            );
+            body.span = span.unwrap_or_default();
+            section.body = Some(body);
         }
         let (section, parsing_state) = self.state.last_mut().unwrap();
         if !section.block_body {
@@ -699,14 +786,21 @@ impl State {
                 let (header_key, kind, condition) =
                     get_name_kind_and_condition(name_with_kind.as_str());
                 self.line_number += 1;
-                headers.push(ftd_p1::Header::kv(
-                    ftd_p1::utils::i32_to_usize(self.line_number),
+                let line_number = ftd_p1::utils::i32_to_usize(self.line_number);
+                let spans = HeaderSpans::of_header(
+                    self.lines,
+                    line_number,
+                    header_key.as_str(),
+                    caption.as_deref(),
+                );
+                headers.push(spans.set(ftd_p1::Header::kv(
+                    line_number,
                     header_key.as_str(),
                     kind,
                     caption,
                     condition,
                     Some(ftd_p1::header::KVSource::Header),
-                ));
+                )));
             } else {
                 new_line_number = Some(line_number);
                 break;
@@ -775,6 +869,7 @@ pub struct HeaderData {
     condition: Option<String>,
     source: Option<ftd_p1::header::KVSource>,
     line_number: usize,
+    spans: HeaderSpans,
 }
 
 impl HeaderData {
@@ -784,6 +879,7 @@ impl HeaderData {
         condition: Option<String>,
         source: Option<ftd_p1::header::KVSource>,
         line_number: usize,
+        spans: HeaderSpans,
     ) -> Self {
         HeaderData {
             value,
@@ -791,10 +887,73 @@ impl HeaderData {
             condition,
             source,
             line_number,
+            spans,
         }
     }
 }
 
+/// Where a header is in the document: its value, or the key when the value is
+/// not on the line of the key, the key, and the condition.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeaderSpans {
+    span: ftd_p1::Span,
+    key: ftd_p1::Span,
+    condition: Option<ftd_p1::Span>,
+}
+
+impl HeaderSpans {
+    /// The spans of the header `key: value`, or `key if { ... }: value`, on
+    /// the line `line_number`.
+    fn of_header(
+        lines: &ftd_p1::span::Lines,
+        line_number: usize,
+        key: &str,
+        value: Option<&str>,
+    ) -> HeaderSpans {
+        let key = lines.key(line_number, key);
+        HeaderSpans {
+            span: lines.value(line_number, value).unwrap_or(key),
+            key,
+            condition: lines.condition(line_number),
+        }
+    }
+
+    /// The spans of a caption, which has no key.
+    fn of_lines(span: ftd_p1::Span) -> HeaderSpans {
+        HeaderSpans {
+            span,
+            key: span,
+            condition: None,
+        }
+    }
+
+    /// `header` with these spans, the key is the span of a header that is
+    /// not a `key: value`.
+    fn set(&self, mut header: ftd_p1::Header) -> ftd_p1::Header {
+        match &mut header {
+            ftd_p1::Header::KV(kv) => {
+                kv.span = self.span;
+                kv.key_span = self.key;
+                kv.condition_span = self.condition;
+            }
+            ftd_p1::Header::Section(ftd_p1::SectionHeader {
+                span,
+                condition_span,
+                ..
+            })
+            | ftd_p1::Header::BlockRecordHeader(ftd_p1::BlockRecordHeader {
+                span,
+                condition_span,
+                ..
+            }) => {
+                *span = self.key;
+                *condition_span = self.condition;
+            }
+        }
+        header
+    }
+}
+
 pub fn parse(content: &str, doc_id: &str) -> ftd_p1::Result<Vec<ftd_p1::Section>> {
     parse_with_line_number(content, doc_id, 0)
 }
@@ -803,17 +962,135 @@ pub fn parse_with_line_number(
     content: &str,
     doc_id: &str,
     line_number: usize,
+) -> ftd_p1::Result<Vec<ftd_p1::Section>> {
+    let (sections, errors) = parse_with_errors(content, doc_id, line_number);
+    match ftd_p1::Reportable::from_errors(errors) {
+        Some(e) => Err(e),
+        None => Ok(sections),
+    }
+}
+
+/// Parses `content`, and when a section can not be parsed, carries on with the
+/// sections after it. Returns the sections that could be parsed, and an error
+/// for each one that could not.
+pub fn parse_with_errors(
+    content: &str,
+    doc_id: &str,
+    line_number: usize,
+) -> (Vec<ftd_p1::Section>, Vec<ftd_p1::Error>) {
+    let document = ftd_p1::span::Lines::new(content, line_number);
+    let error = match parse_(content, doc_id, -(line_number as i32), &document) {
+        Ok(sections) => return (sections, vec![]),
+        Err(e) => e,
+    };
+
+    // every top level section, with the sections in it, is parsed on its own,
+    // starting at its own line
+    let lines = content.split('\n').collect::<Vec<_>>();
+    let mut sections = vec![];
+    let mut errors = vec![];
+    for (start, end) in top_level_sections(lines.as_slice()) {
+        let block = lines[start..end].join("\n");
+        match parse_(
+            block.as_str(),
+            doc_id,
+            start as i32 - line_number as i32,
+            &document,
+        ) {
+            Ok(s) => sections.extend(s),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    if errors.is_empty() {
+        // the sections could not be told apart
+        return (vec![], vec![error]);
+    }
+    (sections, errors)
+}
+
+/// The lines, `start..end`, of each top level section of the document. A
+/// section is closed by the first `-- end:` with its name after it, the way
+/// the parser closes it, and a section without an `-- end:` by the next
+/// section.
+fn top_level_sections(lines: &[&str]) -> Vec<(usize, usize)> {
+    let starts = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let line = line.trim_start();
+            let line = line.strip_prefix('/').unwrap_or(line);
+            let (name, caption) = line.strip_prefix("-- ")?.split_once(':')?;
+            Some((i, name.trim(), caption.trim()))
+        })
+        .collect::<Vec<_>>();
+
+    // the line of the `-- end:` of each section that has one
+    let mut ends = std::collections::HashMap::new();
+    let mut open: Vec<(usize, String)> = vec![];
+    for (i, name, caption) in starts.iter() {
+        if !is_end(name) {
+            open.push((*i, get_name_and_kind(name).0));
+        } else if let Some(position) = open.iter().rposition(|(_, name)| name.eq(caption)) {
+            ends.insert(open[position].0, *i);
+            open.truncate(position);
+        }
+    }
+
+    let section_starts = starts
+        .iter()
+        .filter(|(_, name, _)| !is_end(name))
+        .map(|(i, name, _)| (*i, get_name_and_kind(name).0))
+        .collect::<Vec<_>>();
+    let end_of = |i: usize| match ends.get(&i) {
+        Some(end) => end + 1,
+        None => section_starts
+            .iter()
+            .find(|(j, _)| *j > i)
+            .map_or(lines.len(), |(j, _)| *j),
+    };
+    let mut sections: Vec<(usize, usize)> = vec![];
+    let mut top: Option<&str> = None;
+    let mut start = 0;
+    for (i, name) in section_starts.iter() {
+        if *i < start {
+            continue;
+        }
+        // `-- ftd.text.text:` after `-- ftd.text:` is a header of it
+        if let (Some(top), Some(last)) = (top, sections.last_mut()) {
+            if name.starts_with(format!("{top}.").as_str()) {
+                last.1 = end_of(*i);
+                start = last.1;
+                continue;
+            }
+        }
+        let end = end_of(*i);
+        sections.push((start, end));
+        top = Some(name.as_str());
+        start = end;
+    }
+    match sections.last_mut() {
+        Some((_, end)) => *end = lines.len(),
+        None => sections.push((0, lines.len())),
+    }
+    sections
+}
+
+/// `line_number` is the line number of the line before `content`, and `lines`
+/// the lines of the document `content` is a part of.
+fn parse_(
+    content: &str,
+    doc_id: &str,
+    line_number: i32,
+    lines: &ftd_p1::span::Lines,
 ) -> ftd_p1::Result<Vec<ftd_p1::Section>> {
     let mut state = State {
         content: content.to_string(),
         doc_id: doc_id.to_string(),
-        line_number: if line_number > 0 {
-            -(line_number as i32)
-        } else {
-            0
-        },
+        line_number,
         sections: Default::default(),
         state: Default::default(),
+        lines,
     };
     state.next()?;
     Ok(state.sections)
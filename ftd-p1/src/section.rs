@@ -14,6 +14,7 @@ use itertools::Itertools;
  * - `is_commented`: A boolean representing whether the section is commented or not
  * - `line_number`: A usize representing the line number where the section starts in the document
  * - `block_body`: A boolean representing whether the section body is present as a block
+ * - `span`: A `ftd_p1::Span` of the name of the section in the document
  *
  */
 #[derive(Debug, PartialEq, Clone, serde::Serialize, serde::Deserialize, Default)]
@@ -28,6 +29,8 @@ pub struct Section {
     pub is_commented: bool,
     pub line_number: usize,
     pub block_body: bool,
+    #[serde(skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 impl Section {
@@ -42,6 +45,7 @@ impl Section {
             line_number: 0,
             headers: ftd_p1::Headers(vec![]),
             block_body: false,
+            span: Default::default(),
         }
     }
 
@@ -71,6 +75,7 @@ impl Section {
             is_commented: self.is_commented.to_owned(),
             line_number: 0,
             block_body: false,
+            span: Default::default(),
         }
     }
 
@@ -166,6 +171,7 @@ impl Section {
             is_commented: false,
             line_number: self.line_number,
             block_body: self.block_body,
+            span: self.span,
         })
     }
 }
@@ -174,6 +180,8 @@ impl Section {
 pub struct Body {
     pub line_number: usize,
     pub value: String,
+    #[serde(default, skip_serializing_if = "ftd_p1::Span::is_empty")]
+    pub span: ftd_p1::Span,
 }
 
 impl Body {
@@ -181,12 +189,14 @@ impl Body {
         Body {
             line_number,
             value: value.trim().to_string(),
+            span: Default::default(),
         }
     }
     pub fn without_line_number(&self) -> Self {
         Body {
             line_number: 0,
             value: self.value.to_string(),
+            span: Default::default(),
         }
     }
 
@@ -196,6 +206,7 @@ impl Body {
         value.map(|value| Body {
             line_number: self.line_number,
             value,
+            span: self.span,
         })
     }
 
@@ -0,0 +1,106 @@
+/// A span of a file, with the text shown under it.
+#[derive(Debug, Clone)]
+pub struct Snippet<'a> {
+    pub file: &'a str,
+    pub source: &'a str,
+    pub span: ftd_p1::Span,
+    pub text: String,
+}
+
+impl<'a> Snippet<'a> {
+    pub fn new(file: &'a str, source: &'a str, span: ftd_p1::Span, text: &str) -> Snippet<'a> {
+        Snippet {
+            file,
+            source,
+            span,
+            text: text.to_string(),
+        }
+    }
+}
+
+/// An error shown the way `rustc` shows them, with the lines it points at:
+///
+/// ```text
+/// error[FTD0102]: `colr` argument not found for component `ftd#text`
+///  --> index.ftd:3:1
+///   |
+/// 3 | colr: red
+///   | ^^^^
+///   |
+///   = help: did you mean `color`?
+/// ```
+#[derive(Debug, Clone)]
+pub struct Report<'a> {
+    /// `error` or `warning`
+    pub level: &'a str,
+    /// the code of the error, like `FTD0102`
+    pub code: Option<&'a str>,
+    pub message: &'a str,
+    pub primary: Snippet<'a>,
+    /// the other lines the error is about, shown with `-` under them
+    pub secondary: Vec<Snippet<'a>>,
+    pub help: Option<&'a str>,
+}
+
+impl std::fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = std::iter::once(&self.primary)
+            .chain(self.secondary.iter())
+            .map(|s| s.span.start_position(s.source).0.to_string().len())
+            .max()
+            .unwrap_or_default();
+        let gutter = " ".repeat(width);
+
+        let mut lines = vec![match self.code {
+            Some(code) => format!("{}[{code}]: {}", self.level, self.message),
+            None => format!("{}: {}", self.level, self.message),
+        }];
+        lines.extend(snippet_lines(&self.primary, "-->", '^', gutter.as_str()));
+        for snippet in self.secondary.iter() {
+            lines.extend(snippet_lines(snippet, ":::", '-', gutter.as_str()));
+        }
+        if let Some(help) = self.help {
+            lines.push(format!("{gutter} |"));
+            lines.push(format!("{gutter} = help: {help}"));
+        }
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+fn snippet_lines(snippet: &Snippet, arrow: &str, underline: char, gutter: &str) -> Vec<String> {
+    let (line_number, column) = snippet.span.start_position(snippet.source);
+    let line_start = snippet.source[..snippet.span.start]
+        .rfind('\n')
+        .map(|i| i + 1)
+        .unwrap_or_default();
+    let line = snippet.source[line_start..]
+        .split('\n')
+        .next()
+        .unwrap_or_default()
+        .trim_end();
+    // a span over many lines is underlined till the end of its first line
+    let length = snippet.source[snippet.span.start..snippet.span.end]
+        .split('\n')
+        .next()
+        .unwrap_or_default()
+        .trim_end()
+        .chars()
+        .count()
+        .max(1);
+    let marks = format!(
+        "{}{}",
+        " ".repeat(column - 1),
+        underline.to_string().repeat(length)
+    );
+
+    vec![
+        format!("{gutter}{arrow} {}:{line_number}:{column}", snippet.file),
+        format!("{gutter} |"),
+        format!("{line_number:>width$} | {line}", width = gutter.len()),
+        if snippet.text.is_empty() {
+            format!("{gutter} | {marks}")
+        } else {
+            format!("{gutter} | {marks} {}", snippet.text)
+        },
+    ]
+}
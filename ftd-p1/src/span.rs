@@ -0,0 +1,222 @@
+/// A byte range, `start..end`, in the source of a document.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Span {
+        Span { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// The span of the text on the line `line_number`, starting at 1, of
+    /// `source`, without the spaces around it.
+    pub fn of_line(source: &str, line_number: usize) -> Option<Span> {
+        let start = line_start(source, line_number)?;
+        let line = line(source, start);
+        let text = line.trim_start();
+        let index = line.len() - text.len();
+        Some(Span::new(
+            start + index,
+            start + index + text.trim_end().len(),
+        ))
+    }
+
+    /// The line and the column, both starting at 1, where the span starts.
+    pub fn start_position(&self, source: &str) -> (usize, usize) {
+        position(source, self.start)
+    }
+
+    /// The line and the column, both starting at 1, where the span ends.
+    pub fn end_position(&self, source: &str) -> (usize, usize) {
+        position(source, self.end)
+    }
+}
+
+/// The lines of a document being parsed, to find the spans of what the parser
+/// reads on them. The spans are in the document, without the lines put before
+/// it to parse it.
+#[derive(Debug)]
+pub(crate) struct Lines<'a> {
+    source: &'a str,
+    /// the byte offset of each line of `source`
+    starts: Vec<usize>,
+    /// the number of lines put before the document
+    before: usize,
+}
+
+impl<'a> Lines<'a> {
+    pub(crate) fn new(source: &'a str, before: usize) -> Lines<'a> {
+        let starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Lines {
+            source,
+            starts,
+            before,
+        }
+    }
+
+    /// The line `line_number`, starting at 1, of the document, with the byte
+    /// offset of the line in the document.
+    fn line(&self, line_number: usize) -> Option<(usize, &'a str)> {
+        let index = (line_number + self.before).checked_sub(1)?;
+        let start = *self.starts.get(index)?;
+        let first = *self.starts.get(self.before)?;
+        let end = self
+            .starts
+            .get(index + 1)
+            .map_or(self.source.len(), |end| end - 1);
+        let line = &self.source[start..end];
+        Some((
+            start.checked_sub(first)?,
+            line.strip_suffix('\r').unwrap_or(line),
+        ))
+    }
+
+    /// The span of `text`, a slice of `line`, the line `line_number`.
+    fn span(&self, line_number: usize, line: &str, text: &str) -> Span {
+        let Some((start, _)) = self.line(line_number) else {
+            return Span::default();
+        };
+        // `text` is a slice of `line`
+        let index = text.as_ptr() as usize - line.as_ptr() as usize;
+        Span::new(start + index, start + index + text.len())
+    }
+
+    /// The text before the first `:` of the line, without the spaces around
+    /// it and the `-- ` or `/-- ` of a section.
+    fn before_colon(line: &str) -> &str {
+        let line = line.trim_start();
+        let line = line.strip_prefix('/').unwrap_or(line);
+        let line = line.strip_prefix("-- ").unwrap_or(line);
+        line.split(':').next().unwrap_or_default().trim()
+    }
+
+    /// The span of `name`, the name of the section, or the key of the
+    /// header, on the line `line_number`. The kind and the condition are left
+    /// out of it.
+    pub(crate) fn key(&self, line_number: usize, name: &str) -> Span {
+        let Some((_, line)) = self.line(line_number) else {
+            return Span::default();
+        };
+        let before = Lines::before_colon(line);
+        let before = match before.split_once(ftd_p1::utils::INLINE_IF) {
+            Some((key, _)) => key.trim_end(),
+            None => before,
+        };
+        let key = match before.strip_suffix(name) {
+            Some(rest) => &before[rest.len()..],
+            // the spaces of the arguments of a function are taken out of its
+            // name
+            None => before,
+        };
+        self.span(line_number, line, key)
+    }
+
+    /// The span of the condition of the header with `key if { ... }` on the
+    /// line `line_number`.
+    pub(crate) fn condition(&self, line_number: usize) -> Option<Span> {
+        let (_, line) = self.line(line_number)?;
+        let (_, condition) = Lines::before_colon(line).split_once(ftd_p1::utils::INLINE_IF)?;
+        Some(self.span(line_number, line, condition.trim()))
+    }
+
+    /// The span of `value`, the value after the `:` on the line
+    /// `line_number`, when there is one.
+    pub(crate) fn value(&self, line_number: usize, value: Option<&str>) -> Option<Span> {
+        let value = value?;
+        let (_, line) = self.line(line_number)?;
+        let (_, after) = line.split_once(':')?;
+        let after = after.trim_start();
+        let text = match after.get(..value.len()) {
+            Some(text) if text.eq(value) => text,
+            // comments and escapes are taken out of the value
+            _ => after.trim_end(),
+        };
+        Some(self.span(line_number, line, text))
+    }
+
+    /// The span of the text on the lines `first..=last`, without the spaces
+    /// before it and after it.
+    pub(crate) fn lines(&self, first: usize, last: usize) -> Span {
+        match (self.line(first), self.line(last)) {
+            (Some((start, first)), Some((end, last))) => Span::new(
+                start + first.len() - first.trim_start().len(),
+                end + last.trim_end().len(),
+            ),
+            _ => Span::default(),
+        }
+    }
+}
+
+/// The byte offset in `source` of the line `line_number`, starting at 1.
+fn line_start(source: &str, line_number: usize) -> Option<usize> {
+    if line_number == 0 {
+        return None;
+    }
+    if line_number == 1 {
+        return Some(0);
+    }
+    source
+        .match_indices('\n')
+        .nth(line_number - 2)
+        .map(|(index, _)| index + 1)
+}
+
+/// The line of `source` starting at the byte `start`.
+fn line(source: &str, start: usize) -> &str {
+    let line = source[start..].split('\n').next().unwrap_or_default();
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// The line and the column, both starting at 1, of the byte `offset` of
+/// `source`. The column counts characters, not bytes.
+fn position(source: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(source.len());
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or_default();
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// A part of a document an error points at besides where the error is, like
+/// where the thing the error is about is defined.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Label {
+    pub doc_id: String,
+    pub line_number: usize,
+    /// What the label is under, the whole line when `None`.
+    pub span: Option<Span>,
+    pub text: String,
+}
+
+impl Label {
+    pub fn new(doc_id: &str, line_number: usize, span: Option<Span>, text: &str) -> Label {
+        Label {
+            doc_id: doc_id.to_string(),
+            line_number,
+            span,
+            text: text.to_string(),
+        }
+    }
+}
@@ -916,3 +916,189 @@ fn kind() {
             .list(),
     );
 }
+
+#[test]
+fn all_errors() {
+    // the sections after a section with an error are parsed too
+    f(
+        indoc!(
+            "-- some-section:
+            This is body
+
+            -- ftd.column:
+
+            -- ftd.text: hello
+            h1 v1
+
+            -- end: ftd.column
+
+            -- ftd.text:
+            -- ftd.text.text:
+
+            hello
+
+            -- other-section
+            "
+        ),
+        indoc!(
+            "foo:2 -> start section body 'This is body' after a newline!!
+            foo:7 -> start section body 'h1 v1' after a newline!!
+            foo:16 -> : is missing in:  other-section"
+        ),
+    );
+
+    let (sections, errors) = super::parse_with_errors(
+        indoc!(
+            "-- ftd.text: hello
+            h1 v1
+
+            -- ftd.column:
+
+            -- ftd.text: world
+
+            -- end: ftd.column
+            "
+        ),
+        "foo",
+        0,
+    );
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(
+        sections
+            .iter()
+            .map(|v| v.without_line_number())
+            .collect::<Vec<_>>(),
+        ftd_p1::Section::with_name("ftd.column")
+            .add_sub_section(ftd_p1::Section::with_name("ftd.text").and_caption("world"))
+            .list()
+    );
+    assert_eq!(sections[0].line_number, 4);
+    assert_eq!(sections[0].sub_sections[0].line_number, 6);
+}
+
+#[test]
+fn spans() {
+    let source = indoc!(
+        "-- ftd.text: hello
+        color if { $flag }: red
+
+        -- ftd.column:
+        padding.px: 10
+
+        -- ftd.column.border-color:
+        if: { $flag }
+
+        light: red
+        dark: blue
+
+        -- ftd.text:
+
+        the body
+        of the text
+
+        -- end: ftd.column
+        "
+    );
+    let sections = super::parse(source, "foo").unwrap_or_else(|e| panic!("{:?}", e));
+    let text = |span: ftd_p1::Span| &source[span.start..span.end];
+
+    assert_eq!(text(sections[0].span), "ftd.text");
+    assert_eq!(
+        text(sections[0].caption.as_ref().unwrap().get_span()),
+        "hello"
+    );
+    let color = &sections[0].headers.0[0];
+    assert_eq!(text(color.get_span()), "red");
+    assert_eq!(text(color.get_key_span()), "color");
+    assert_eq!(text(color.get_condition_span().unwrap()), "{ $flag }");
+
+    let padding = &sections[1].headers.0[0];
+    assert_eq!(text(padding.get_span()), "10");
+    assert_eq!(text(padding.get_key_span()), "padding.px");
+    let border_color = &sections[1].headers.0[1];
+    assert_eq!(text(border_color.get_key_span()), "border-color");
+    assert_eq!(
+        text(border_color.get_condition_span().unwrap()),
+        "{ $flag }"
+    );
+    assert_eq!(
+        text(sections[1].sub_sections[0].body.as_ref().unwrap().span),
+        "the body\nof the text"
+    );
+
+    let span = color.get_span();
+    assert_eq!(span.start_position(source), (2, 21));
+    assert_eq!(span.end_position(source), (2, 24));
+
+    // the lines put before the document are not in its spans
+    let sections =
+        super::parse_with_line_number(format!("-- import: foo\n{source}").as_str(), "foo", 1)
+            .unwrap_or_else(|e| panic!("{:?}", e));
+    assert_eq!(text(sections[1].span), "ftd.text");
+    assert_eq!(text(sections[1].headers.0[0].get_span()), "red");
+
+    // and the sections parsed after an error are in the document too
+    let source = indoc!(
+        "-- ftd.text: hello
+        h1 v1
+
+        -- ftd.text: world
+        color: red
+        "
+    );
+    let text = |span: ftd_p1::Span| &source[span.start..span.end];
+    let (sections, errors) = super::parse_with_errors(source, "foo", 0);
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(sections[0].span, ftd_p1::Span::new(29, 37));
+    assert_eq!(text(sections[0].headers.0[0].get_span()), "red");
+}
+
+#[test]
+fn report() {
+    let source = indoc!(
+        "-- component foo:
+        string title:
+
+        -- ftd.text: $foo.title
+        -- end: foo
+
+        -- foo:
+        titel: hello
+        "
+    );
+    let sections = super::parse(source, "foo").unwrap_or_else(|e| panic!("{:?}", e));
+    let report = ftd_p1::Report {
+        level: "error",
+        code: Some("FTD0102"),
+        message: "`titel` argument not found for component `foo`",
+        primary: ftd_p1::Snippet::new(
+            "index.ftd",
+            source,
+            sections[1].headers.0[0].get_key_span(),
+            "",
+        ),
+        secondary: vec![ftd_p1::Snippet::new(
+            "index.ftd",
+            source,
+            sections[0].span,
+            "`foo` defined here",
+        )],
+        help: Some("did you mean `title`?"),
+    };
+    assert_eq!(
+        report.to_string(),
+        indoc!(
+            "error[FTD0102]: `titel` argument not found for component `foo`
+             --> index.ftd:8:1
+              |
+            8 | titel: hello
+              | ^^^^^
+             ::: index.ftd:1:14
+              |
+            1 | -- component foo:
+              |              --- `foo` defined here
+              |
+              = help: did you mean `title`?"
+        )
+    );
+}
@@ -49,6 +49,80 @@ pub static TS1: once_cell::sync::Lazy<syntect::highlighting::ThemeSet> =
         // syntect::highlighting::ThemeSet::load_from_folder(&TS_DIR).unwrap()
     });
 
+/// Grammars and themes shipped by packages, in their `highlighting` folder,
+/// see `add_package_syntaxes` and `add_package_theme`.
+#[derive(Default)]
+struct PackageHighlighting {
+    /// The `.sublime-syntax` source of the grammars, by name.
+    syntax_sources: std::collections::BTreeMap<String, String>,
+    /// `SS` with the grammars of the packages.
+    syntax_set: Option<syntect::parsing::SyntaxSet>,
+    /// The `.tmTheme` source of the themes, by name, and the theme.
+    themes: std::collections::BTreeMap<String, (String, syntect::highlighting::Theme)>,
+}
+
+static PACKAGE_HIGHLIGHTING: once_cell::sync::Lazy<std::sync::RwLock<PackageHighlighting>> =
+    once_cell::sync::Lazy::new(Default::default);
+
+/// Adds the `.sublime-syntax` grammars, `(name, source)`, shipped by a package
+/// to the ones `ftd.code` knows. Grammars already added are not parsed again.
+pub fn add_package_syntaxes(syntaxes: &[(String, String)]) -> ftd::executor::Result<()> {
+    let mut package = PACKAGE_HIGHLIGHTING.write().unwrap();
+    if syntaxes
+        .iter()
+        .all(|(name, source)| package.syntax_sources.get(name) == Some(source))
+    {
+        return Ok(());
+    }
+
+    let mut sources = package.syntax_sources.clone();
+    sources.extend(syntaxes.iter().cloned());
+    let mut builder = SS.clone().into_builder();
+    for (name, source) in sources.iter() {
+        builder.add(
+            syntect::parsing::syntax_definition::SyntaxDefinition::load_from_str(
+                source,
+                true,
+                Some(name.as_str()),
+            )
+            .map_err(|e| ftd::executor::Error::ParseError {
+                message: format!("Cannot load the grammar: {}", e),
+                doc_id: name.to_string(),
+                line_number: 0,
+            })?,
+        );
+    }
+    package.syntax_sources = sources;
+    package.syntax_set = Some(builder.build());
+    Ok(())
+}
+
+/// Adds the `.tmTheme` theme `name` shipped by a package to the themes
+/// `ftd.code` knows.
+pub fn add_package_theme(name: &str, source: &str) -> ftd::executor::Result<()> {
+    let mut package = PACKAGE_HIGHLIGHTING.write().unwrap();
+    if package
+        .themes
+        .get(name)
+        .is_some_and(|(existing, _)| existing == source)
+    {
+        return Ok(());
+    }
+
+    let theme = syntect::highlighting::ThemeSet::load_from_reader(&mut std::io::Cursor::new(
+        source.as_bytes(),
+    ))
+    .map_err(|e| ftd::executor::Error::ParseError {
+        message: format!("Cannot load the theme: {}", e),
+        doc_id: name.to_string(),
+        line_number: 0,
+    })?;
+    package
+        .themes
+        .insert(name.to_string(), (source.to_string(), theme));
+    Ok(())
+}
+
 /*fn ts1() -> syntect::highlighting::ThemeSet {
     let mut theme_set = syntect::highlighting::ThemeSet::new();
 
@@ -66,12 +140,61 @@ pub static TS1: once_cell::sync::Lazy<syntect::highlighting::ThemeSet> =
     theme_set
 }*/
 
-pub fn code(code: &str, ext: &str, theme: &str, doc_id: &str) -> ftd::executor::Result<String> {
-    let syntax = SS
+/// How `ftd.code` shows its lines, besides the language and the theme.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CodeOptions {
+    /// `highlight: 3-5,9`: the lines to highlight.
+    pub highlight: Option<String>,
+    /// `diff: true`: lines starting with `+` and `-` are shown as added and
+    /// removed.
+    pub diff: bool,
+    /// Shown above the code, the file name for example.
+    pub title: Option<String>,
+}
+
+/// A callout at the end of a line of code: `// <1>`, `# <2>`, `-- <3>`.
+static CALLOUT: once_cell::sync::Lazy<regex::Regex> =
+    once_cell::sync::Lazy::new(|| regex::Regex::new(r"\s*(?://|#|;;|--)\s*<(\d+)>\s*$").unwrap());
+
+/// What is shown around a line of code, besides its text.
+#[derive(Debug, Default)]
+struct LineDecoration {
+    highlighted: bool,
+    sign: Option<char>,
+    callout: Option<String>,
+}
+
+/// The lines in `3-5,9`: `[3, 4, 5, 9]`.
+fn line_numbers(ranges: &str) -> Vec<usize> {
+    ranges
+        .split(',')
+        .filter_map(|range| {
+            let (from, to) = range.split_once('-').unwrap_or((range, range));
+            Some(from.trim().parse::<usize>().ok()?..=to.trim().parse::<usize>().ok()?)
+        })
+        .flatten()
+        .collect()
+}
+
+pub fn code(
+    code: &str,
+    ext: &str,
+    theme: &str,
+    options: &CodeOptions,
+    doc_id: &str,
+) -> ftd::executor::Result<String> {
+    let package = PACKAGE_HIGHLIGHTING.read().unwrap();
+    let ss = package.syntax_set.as_ref().unwrap_or(&*SS);
+    let syntax = ss
         .find_syntax_by_extension(ext)
-        .unwrap_or_else(|| SS.find_syntax_plain_text());
+        .unwrap_or_else(|| ss.find_syntax_plain_text());
 
-    let theme = if let Some(theme) = TS.themes.get(theme).or(TS1.themes.get(theme)) {
+    let theme = if let Some(theme) = TS
+        .themes
+        .get(theme)
+        .or(TS1.themes.get(theme))
+        .or(package.themes.get(theme).map(|(_, theme)| theme))
+    {
         theme
     } else {
         return Err(ftd::executor::Error::ParseError {
@@ -81,17 +204,52 @@ pub fn code(code: &str, ext: &str, theme: &str, doc_id: &str) -> ftd::executor::
         });
     };
 
+    let highlighted = options
+        .highlight
+        .as_deref()
+        .map(line_numbers)
+        .unwrap_or_default();
+    let mut decorations = vec![];
     let code = code
         .lines()
         .skip_while(|l| l.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            let mut decoration = LineDecoration {
+                highlighted: highlighted.contains(&(i + 1)),
+                ..Default::default()
+            };
+            let mut line = line;
+            if let Some(callout) = CALLOUT.captures(line) {
+                decoration.callout = Some(callout[1].to_string());
+                line = &line[..callout.get(0).unwrap().start()];
+            }
+            if options.diff {
+                let sign = line.chars().next().filter(|c| matches!(c, '+' | '-'));
+                decoration.sign = Some(sign.unwrap_or(' '));
+                if line.starts_with(['+', '-', ' ']) {
+                    line = &line[1..];
+                }
+            }
+            decorations.push(decoration);
+            line
+        })
         .collect::<Vec<_>>()
         .join("\n")
         .trim_end()
         .to_string()
         + "\n";
 
-    // TODO: handle various params
-    Ok(highlighted_html_for_string(code.as_str(), ext, &SS, syntax, theme)?.replacen('\n', "", 1))
+    Ok(highlighted_html_for_string(
+        code.as_str(),
+        ext,
+        ss,
+        syntax,
+        theme,
+        decorations.as_slice(),
+        options.title.as_deref(),
+    )?
+    .replacen('\n', "", 1))
 }
 
 fn highlighted_html_for_string(
@@ -100,11 +258,15 @@ fn highlighted_html_for_string(
     ss: &syntect::parsing::SyntaxSet,
     syntax: &syntect::parsing::SyntaxReference,
     theme: &syntect::highlighting::Theme,
+    decorations: &[LineDecoration],
+    title: Option<&str>,
 ) -> Result<String, syntect::Error> {
     let mut highlighter = syntect::easy::HighlightLines::new(syntax, theme);
-    let mut output = start_highlighted_html_snippet(theme);
+    let mut output = start_highlighted_html_snippet(theme, title);
+    let no_decoration = LineDecoration::default();
 
-    for line in syntect::util::LinesWithEndings::from(s) {
+    for (i, line) in syntect::util::LinesWithEndings::from(s).enumerate() {
+        let decoration = decorations.get(i).unwrap_or(&no_decoration);
         let mut regions = highlighter.highlight_line(line, ss)?;
         let highlighted = ftd::interpreter::FTD_HIGHLIGHTER.is_match(line);
         if ext.eq("ftd") && highlighted {
@@ -124,13 +286,63 @@ fn highlighted_html_for_string(
                     r_style.background = syntect::highlighting::Color::WHITE;
                 }
             }
+        } else if decoration.highlighted {
+            let b = theme
+                .settings
+                .line_highlight
+                .map(|c| color_to_hex(&c))
+                .unwrap_or_else(|| "#8080802e".to_string());
+            output.push_str(
+                format!(
+                    "<span style=\"background-color:{}; display: block; margin: 0 -1.1764705882em; padding: 0 1.1764705882em;\">",
+                    b
+                )
+                .as_str(),
+            );
+        }
+
+        // the sign and the callout are shown by css, `ftd.css`, around the
+        // line without its new line
+        let decorated = decoration.sign.is_some() || decoration.callout.is_some();
+        let mut new_line = "";
+        if decorated {
+            if let Some((_, text)) = regions.last_mut() {
+                let region = *text;
+                if let Some(stripped) = region.strip_suffix('\n') {
+                    *text = stripped;
+                    new_line = "\n";
+                }
+            }
+            output.push_str("<span class=\"ft_code_line");
+            if let Some(sign) = decoration.sign {
+                output.push_str(match sign {
+                    '+' => " ft_diff_added",
+                    '-' => " ft_diff_removed",
+                    _ => " ft_diff_context",
+                });
+                output.push_str(format!("\" data-sign=\"{}", sign).as_str());
+            }
+            output.push_str("\">");
         }
         syntect::html::append_highlighted_html_for_styled_line(
             &regions[..],
             syntect::html::IncludeBackground::IfDifferent(syntect::highlighting::Color::WHITE),
             &mut output,
         )?;
-        if ext.eq("ftd") && highlighted {
+        if decorated {
+            if let Some(ref callout) = decoration.callout {
+                output.push_str(
+                    format!(
+                        "<span class=\"ft_code_callout\" data-callout=\"{}\"></span>",
+                        callout
+                    )
+                    .as_str(),
+                );
+            }
+            output.push_str("</span>");
+            output.push_str(new_line);
+        }
+        if (ext.eq("ftd") && highlighted) || decoration.highlighted {
             output.push_str("</span>");
         }
     }
@@ -138,19 +350,30 @@ fn highlighted_html_for_string(
     Ok(output)
 }
 
-fn start_highlighted_html_snippet(t: &syntect::highlighting::Theme) -> String {
+fn start_highlighted_html_snippet(t: &syntect::highlighting::Theme, title: Option<&str>) -> String {
     let c = t
         .settings
         .background
         .map(|c| format!("background-color:{};", color_to_hex(&c)))
         .unwrap_or_default();
 
+    let title = title
+        .map(|title| format!(" data-title=\"{}\"", escape_attribute(title)))
+        .unwrap_or_default();
+
     format!(
-        "<pre style=\"padding: 0.7720588235em 1.1764705882em; {}\">\n",
-        c
+        "<pre style=\"padding: 0.7720588235em 1.1764705882em; {}\"{}>\n",
+        c, title
     )
 }
 
+fn escape_attribute(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 fn color_to_hex(c: &syntect::highlighting::Color) -> String {
     let a = if c.a != 255 {
         format!("{:02x}", c.a)
@@ -5,6 +5,7 @@ pub enum Element {
     Row(Row),
     Column(Column),
     Container(ContainerElement),
+    Grid(Grid),
     Document(Box<Document>),
     Text(Text),
     Integer(Text),
@@ -28,6 +29,7 @@ impl Element {
             Element::Row(r) => Some(&r.common),
             Element::Column(c) => Some(&c.common),
             Element::Container(e) => Some(&e.common),
+            Element::Grid(g) => Some(&g.common),
             Element::Text(t) => Some(&t.common),
             Element::Integer(i) => Some(&i.common),
             Element::Boolean(b) => Some(&b.common),
@@ -65,6 +67,7 @@ impl Element {
             Element::Row(r) => r.common.line_number,
             Element::Column(c) => c.common.line_number,
             Element::Container(e) => e.common.line_number,
+            Element::Grid(g) => g.common.line_number,
             Element::Document(d) => d.line_number,
             Element::Text(t) => t.common.line_number,
             Element::Integer(i) => i.common.line_number,
@@ -120,6 +123,19 @@ pub struct Column {
     pub common: Common,
 }
 
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Grid {
+    pub common: Common,
+    pub children: Vec<ftd::executor::Element>,
+    pub template_columns: ftd::executor::Value<Option<ftd::executor::GridTemplate>>,
+    pub template_rows: ftd::executor::Value<Option<ftd::executor::GridTemplate>>,
+    pub template_areas: ftd::executor::Value<Option<ftd::executor::GridValue>>,
+    pub auto_flow: ftd::executor::Value<Option<ftd::executor::GridAutoFlow>>,
+    pub gap: ftd::executor::Value<Option<ftd::executor::Length>>,
+    pub row_gap: ftd::executor::Value<Option<ftd::executor::Length>>,
+    pub column_gap: ftd::executor::Value<Option<ftd::executor::Length>>,
+}
+
 #[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
 pub struct Rive {
     pub src: ftd::executor::Value<String>,
@@ -483,11 +499,43 @@ pub fn code_from_properties(
         line_number,
     )?;
 
+    let options = ftd::executor::code::CodeOptions {
+        highlight: ftd::executor::value::optional_string(
+            "highlight",
+            "ftd#code",
+            properties,
+            arguments,
+            doc,
+            line_number,
+        )?
+        .value,
+        diff: ftd::executor::value::bool_with_default(
+            "diff",
+            "ftd#code",
+            properties,
+            arguments,
+            false,
+            doc,
+            line_number,
+        )?
+        .value,
+        title: ftd::executor::value::optional_string(
+            "title",
+            "ftd#code",
+            properties,
+            arguments,
+            doc,
+            line_number,
+        )?
+        .value,
+    };
+
     let text = ftd::executor::Value::new(
         ftd::executor::element::code_with_theme(
             text.value.unwrap_or_default().as_str(),
             lang.value.as_str(),
             theme.value.as_str(),
+            &options,
             doc.name,
         )?,
         text.line_number,
@@ -647,10 +695,10 @@ pub fn iframe_from_properties(
     })
 }
 
-pub fn markup_inline(s: &str) -> Rendered {
+pub fn markup_inline(s: &str, profile: &ftd::executor::MarkdownProfile) -> Rendered {
     Rendered {
         original: s.to_string(),
-        rendered: ftd::executor::markup::markup_inline(s),
+        rendered: ftd::executor::markup::markup_inline(s, profile),
     }
 }
 
@@ -658,6 +706,7 @@ pub fn code_with_theme(
     code: &str,
     ext: &str,
     theme: &str,
+    options: &ftd::executor::code::CodeOptions,
     doc_id: &str,
 ) -> ftd::executor::Result<Rendered> {
     Ok(Rendered {
@@ -668,6 +717,7 @@ pub fn code_with_theme(
                 .as_str(),
             ext,
             theme,
+            options,
             doc_id,
         )?,
     })
@@ -759,6 +809,11 @@ pub struct Common {
     pub border_style_top: ftd::executor::Value<Option<ftd::executor::BorderStyle>>,
     pub border_style_bottom: ftd::executor::Value<Option<ftd::executor::BorderStyle>>,
     pub shadow: ftd::executor::Value<Option<ftd::executor::Shadow>>,
+    pub grid_column: ftd::executor::Value<Option<ftd::executor::GridValue>>,
+    pub grid_row: ftd::executor::Value<Option<ftd::executor::GridValue>>,
+    pub grid_area: ftd::executor::Value<Option<ftd::executor::GridValue>>,
+    pub transition: ftd::executor::Value<Option<ftd::executor::Transition>>,
+    pub animation: ftd::executor::Value<Option<ftd::executor::Animation>>,
     pub device: Option<ftd::executor::Device>,
 }
 
@@ -812,7 +867,9 @@ pub fn text_from_properties(
             line_number,
         );
     }
-    let text = text.map(|v| ftd::executor::element::markup_inline(v.unwrap_or_default().as_str()));
+    let text = text.map(|v| {
+        ftd::executor::element::markup_inline(v.unwrap_or_default().as_str(), doc.markdown)
+    });
     let common = common_from_properties(
         properties,
         events,
@@ -908,9 +965,13 @@ pub fn integer_from_properties(
     .value
     {
         Some(f) => value.map(|v| {
-            ftd::executor::element::markup_inline(num.format(f.as_str(), v as f64).as_str())
+            ftd::executor::element::markup_inline(
+                num.format(f.as_str(), v as f64).as_str(),
+                doc.markdown,
+            )
         }),
-        None => value.map(|v| ftd::executor::element::markup_inline(v.to_string().as_str())),
+        None => value
+            .map(|v| ftd::executor::element::markup_inline(v.to_string().as_str(), doc.markdown)),
     };
     let common = common_from_properties(
         properties,
@@ -1006,10 +1067,11 @@ pub fn decimal_from_properties(
     )?
     .value
     {
-        Some(f) => {
-            value.map(|v| ftd::executor::element::markup_inline(num.format(f.as_str(), v).as_str()))
-        }
-        None => value.map(|v| ftd::executor::element::markup_inline(v.to_string().as_str())),
+        Some(f) => value.map(|v| {
+            ftd::executor::element::markup_inline(num.format(f.as_str(), v).as_str(), doc.markdown)
+        }),
+        None => value
+            .map(|v| ftd::executor::element::markup_inline(v.to_string().as_str(), doc.markdown)),
     };
     let common = common_from_properties(
         properties,
@@ -1094,7 +1156,8 @@ pub fn boolean_from_properties(
         doc,
         line_number,
     )?;
-    let text = value.map(|v| ftd::executor::element::markup_inline(v.to_string().as_str()));
+    let text =
+        value.map(|v| ftd::executor::element::markup_inline(v.to_string().as_str(), doc.markdown));
     let common = common_from_properties(
         properties,
         events,
@@ -1342,6 +1405,88 @@ pub fn container_element_from_properties(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn grid_from_properties(
+    properties: &[fastn_resolved::Property],
+    events: &[fastn_resolved::Event],
+    arguments: &[fastn_resolved::Argument],
+    condition: &Option<fastn_resolved::Expression>,
+    doc: &mut ftd::executor::TDoc,
+    local_container: &[usize],
+    line_number: usize,
+    children: Vec<Element>,
+    inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+    device: Option<ftd::executor::Device>,
+) -> ftd::executor::Result<Grid> {
+    let component_name = "ftd#grid";
+    let common = common_from_properties(
+        properties,
+        events,
+        arguments,
+        condition,
+        doc,
+        local_container,
+        line_number,
+        inherited_variables,
+        component_name,
+        device,
+    )?;
+    let length = |key: &str| {
+        ftd::executor::Length::optional_length(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            key,
+            inherited_variables,
+            component_name,
+        )
+    };
+    Ok(Grid {
+        template_columns: ftd::executor::GridTemplate::optional_grid_template(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "template-columns",
+            inherited_variables,
+            component_name,
+        )?,
+        template_rows: ftd::executor::GridTemplate::optional_grid_template(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "template-rows",
+            inherited_variables,
+            component_name,
+        )?,
+        template_areas: ftd::executor::GridValue::optional_grid_value(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "template-areas",
+            inherited_variables,
+            component_name,
+        )?,
+        auto_flow: ftd::executor::GridAutoFlow::optional_grid_auto_flow(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "auto-flow",
+            inherited_variables,
+            component_name,
+        )?,
+        gap: length("gap")?,
+        row_gap: length("row-gap")?,
+        column_gap: length("column-gap")?,
+        common,
+        children,
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn rive_from_properties(
     properties: &[fastn_resolved::Property],
@@ -1601,6 +1746,23 @@ pub fn common_from_properties(
         .value,
     );
 
+    let animation = ftd::executor::Animation::optional_animation(
+        properties,
+        arguments,
+        doc,
+        line_number,
+        "animation",
+        inherited_variables,
+        component_name,
+    )?;
+
+    if let Some(animation) = animation.value.as_ref() {
+        let keyframes = &animation.keyframes.value;
+        doc.keyframes
+            .entry(keyframes.css_name())
+            .or_insert_with(|| keyframes.to_css_string(&device));
+    }
+
     Ok(Common {
         id: ftd::executor::value::optional_string(
             "id",
@@ -2209,6 +2371,43 @@ pub fn common_from_properties(
             inherited_variables,
             component_name,
         )?,
+        grid_column: ftd::executor::GridValue::optional_grid_value(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "grid-column",
+            inherited_variables,
+            component_name,
+        )?,
+        grid_row: ftd::executor::GridValue::optional_grid_value(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "grid-row",
+            inherited_variables,
+            component_name,
+        )?,
+        grid_area: ftd::executor::GridValue::optional_grid_value(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "grid-area",
+            inherited_variables,
+            component_name,
+        )?,
+        transition: ftd::executor::Transition::optional_transition(
+            properties,
+            arguments,
+            doc,
+            line_number,
+            "transition",
+            inherited_variables,
+            component_name,
+        )?,
+        animation,
     })
 }
 
@@ -12,6 +12,8 @@ pub struct ExecuteDoc<'a> {
     pub js: &'a mut std::collections::HashSet<String>,
     pub css: &'a mut std::collections::HashSet<String>,
     pub rive_data: &'a mut Vec<ftd::executor::RiveData>,
+    pub keyframes: &'a mut ftd::Map<String>,
+    pub markdown: &'a ftd::executor::MarkdownProfile,
 }
 
 #[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
@@ -26,6 +28,7 @@ pub struct RT {
     pub js: std::collections::HashSet<String>,
     pub css: std::collections::HashSet<String>,
     pub rive_data: Vec<ftd::executor::RiveData>,
+    pub keyframes: ftd::Map<String>,
 }
 
 impl Default for RT {
@@ -41,6 +44,7 @@ impl Default for RT {
             js: Default::default(),
             css: Default::default(),
             rive_data: vec![],
+            keyframes: Default::default(),
         }
     }
 }
@@ -48,12 +52,23 @@ impl Default for RT {
 impl ExecuteDoc<'_> {
     #[tracing::instrument(skip_all)]
     pub fn from_interpreter(document: ftd::interpreter::Document) -> ftd::executor::Result<RT> {
+        ExecuteDoc::from_interpreter_with_markdown(document, &Default::default())
+    }
+
+    /// `from_interpreter`, rendering `ftd.text` with the `markdown` extensions
+    /// of the package.
+    #[tracing::instrument(skip_all)]
+    pub fn from_interpreter_with_markdown(
+        document: ftd::interpreter::Document,
+        markdown: &ftd::executor::MarkdownProfile,
+    ) -> ftd::executor::Result<RT> {
         let mut document = document;
         let mut dummy_instructions = ftd::VecMap::new();
         let mut element_constructor = Default::default();
         let mut js: std::collections::HashSet<String> = document.js;
         let mut css: std::collections::HashSet<String> = document.css;
         let mut rive_data: Vec<ftd::executor::RiveData> = vec![];
+        let mut keyframes: ftd::Map<String> = Default::default();
         let execute_doc = ExecuteDoc {
             name: document.name.as_str(),
             aliases: &document.aliases,
@@ -64,6 +79,8 @@ impl ExecuteDoc<'_> {
             js: &mut js,
             css: &mut css,
             rive_data: &mut rive_data,
+            keyframes: &mut keyframes,
+            markdown,
         }
         .execute()?;
 
@@ -108,6 +125,7 @@ impl ExecuteDoc<'_> {
             js,
             css,
             rive_data,
+            keyframes,
         })
     }
     #[tracing::instrument(skip_all)]
@@ -121,6 +139,8 @@ impl ExecuteDoc<'_> {
             js: self.js,
             css: self.css,
             rive_data: self.rive_data,
+            keyframes: self.keyframes,
+            markdown: self.markdown,
         };
 
         ExecuteDoc::execute_from_instructions_loop(self.instructions, &mut doc)
@@ -630,6 +650,7 @@ impl ExecuteDoc<'_> {
                     ftd::executor::Element::Row(r) => &mut r.container.children,
                     ftd::executor::Element::Column(r) => &mut r.container.children,
                     ftd::executor::Element::Container(e) => &mut e.children,
+                    ftd::executor::Element::Grid(g) => &mut g.children,
                     ftd::executor::Element::Document(r) => &mut r.children,
                     t => unreachable!("{:?}", t),
                 };
@@ -793,8 +814,8 @@ impl ExecuteDoc<'_> {
         use itertools::Itertools;
 
         match component_definition.name.as_str() {
-            "ftd#row" | "ftd#column" | "ftd#container" | "ftd#document" | "ftd#desktop"
-            | "ftd#mobile" => {
+            "ftd#row" | "ftd#column" | "ftd#container" | "ftd#grid" | "ftd#document"
+            | "ftd#desktop" | "ftd#mobile" => {
                 doc.insert_local_variables(
                     component_definition.name.as_str(),
                     instruction.properties.as_slice(),
@@ -942,6 +963,20 @@ impl ExecuteDoc<'_> {
                     device,
                 )?,
             ),
+            "ftd#grid" => {
+                ftd::executor::Element::Grid(ftd::executor::element::grid_from_properties(
+                    instruction.properties.as_slice(),
+                    instruction.events.as_slice(),
+                    component_definition.arguments.as_slice(),
+                    instruction.condition.as_ref(),
+                    doc,
+                    local_container,
+                    instruction.line_number,
+                    vec![],
+                    inherited_variables,
+                    device,
+                )?)
+            }
             "ftd#document" => {
                 if !instruction.events.is_empty() {
                     return ftd::executor::utils::parse_error(
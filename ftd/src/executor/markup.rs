@@ -1,27 +1,140 @@
 const MAGIC: &str = "MMMMMMMMMAMMAMSMASMDASMDAMSDMASMDASDMASMDASDMAASD";
+const MATH_MAGIC: &str = "MMMMMMMMMATHMAMSMASMDASMDAMSDMASMDASDMASMDASDMAASD";
 
-pub static MD: once_cell::sync::Lazy<comrak::ComrakOptions> = once_cell::sync::Lazy::new(|| {
-    let mut m = comrak::ComrakOptions::default();
-    m.extension.strikethrough = true;
-    m.extension.table = true;
-    m.extension.autolink = true;
-    m.extension.tasklist = true;
-    m.extension.superscript = true;
-    m.parse.smart = true;
-    m
-});
+/// The markdown extensions of `ftd.text`, configured per package with
+/// `-- fastn.markdown:` in `FASTN.ftd`. The default is what `ftd.text` has
+/// always supported.
+#[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq, Clone)]
+#[serde(default)]
+pub struct MarkdownProfile {
+    pub tables: bool,
+    #[serde(rename = "task-lists")]
+    pub task_lists: bool,
+    pub footnotes: bool,
+    /// GitHub style alerts, e.g. `> [!NOTE]` or `> [!WARNING]`.
+    pub admonitions: bool,
+    /// Headings get an `id`, the one `headings` returns, so they can be
+    /// linked to.
+    #[serde(rename = "heading-ids")]
+    pub heading_ids: bool,
+    /// Links to documents of the package which do not exist fail the build.
+    #[serde(rename = "check-links")]
+    pub check_links: bool,
+    /// `$...$` and `$$...$$` are LaTeX, rendered to MathML at compile time.
+    pub math: bool,
+    /// The component the `.md` pages of the package are shown with, like
+    /// `my-site/layouts.post`.
+    pub layout: Option<String>,
+}
+
+impl Default for MarkdownProfile {
+    fn default() -> MarkdownProfile {
+        MarkdownProfile {
+            tables: true,
+            task_lists: true,
+            footnotes: false,
+            admonitions: false,
+            heading_ids: false,
+            check_links: false,
+            math: false,
+            layout: None,
+        }
+    }
+}
+
+impl MarkdownProfile {
+    pub fn options(&self) -> comrak::ComrakOptions<'_> {
+        let mut m = comrak::ComrakOptions::default();
+        m.extension.strikethrough = true;
+        m.extension.table = self.tables;
+        m.extension.autolink = true;
+        m.extension.tasklist = self.task_lists;
+        m.extension.superscript = true;
+        m.extension.footnotes = self.footnotes;
+        m.extension.alerts = self.admonitions;
+        if self.heading_ids {
+            m.extension.header_ids = Some("".to_string());
+        }
+        m.parse.smart = true;
+        m
+    }
+}
+
+/// A heading of a markdown text, `id` is the one it gets with `heading-ids`.
+#[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq, Clone)]
+pub struct Heading {
+    pub title: String,
+    pub level: u8,
+    pub id: String,
+}
 
-pub fn markup(i: &str) -> String {
-    comrak::markdown_to_html(i.replace("![", MAGIC).trim(), &MD)
+/// The headings of a markdown text, in document order, for a table of
+/// contents.
+pub fn headings(i: &str) -> Vec<Heading> {
+    let arena = comrak::Arena::new();
+    let root = comrak::parse_document(&arena, i, &MarkdownProfile::default().options());
+    let mut anchorizer = comrak::Anchorizer::new();
+    root.descendants()
+        .filter_map(|node| match node.data.borrow().value {
+            comrak::nodes::NodeValue::Heading(ref heading) => Some((node, heading.level)),
+            _ => None,
+        })
+        .map(|(node, level)| {
+            let mut title = String::new();
+            collect_text(node, &mut title);
+            Heading {
+                id: anchorizer.anchorize(title.clone()),
+                title,
+                level,
+            }
+        })
+        .collect()
+}
+
+/// The text of a node, the way comrak collects it for the heading ids.
+fn collect_text<'a>(node: &'a comrak::nodes::AstNode<'a>, output: &mut String) {
+    match node.data.borrow().value {
+        comrak::nodes::NodeValue::Text(ref literal)
+        | comrak::nodes::NodeValue::Code(comrak::nodes::NodeCode { ref literal, .. }) => {
+            output.push_str(literal)
+        }
+        comrak::nodes::NodeValue::LineBreak | comrak::nodes::NodeValue::SoftBreak => {
+            output.push(' ')
+        }
+        _ => {
+            for child in node.children() {
+                collect_text(child, output);
+            }
+        }
+    }
+}
+
+/// The MathML fastn renders `$...$` math to at compile time.
+static MATH: once_cell::sync::Lazy<regex::Regex> =
+    once_cell::sync::Lazy::new(|| regex::Regex::new(r"<math\b.*?</math>").unwrap());
+
+pub fn markup(i: &str, profile: &MarkdownProfile) -> String {
+    // comrak does not keep raw html, the math is put back after it
+    let math = MATH
+        .find_iter(i)
+        .map(|m| m.as_str().to_string())
+        .collect::<Vec<_>>();
+    let i = MATH.replace_all(i, MATH_MAGIC);
+    let mut html = comrak::markdown_to_html(i.replace("![", MAGIC).trim(), &profile.options())
         .trim()
         .replace(MAGIC, "![")
-        .replace('\n', " ")
+        .replace('\n', " ");
+    for math in math {
+        html = html.replacen(MATH_MAGIC, math.as_str(), 1);
+    }
+    html
 }
 
-pub fn markup_inline(i: &str) -> String {
+pub fn markup_inline(i: &str, profile: &MarkdownProfile) -> String {
     let (space_before, space_after) = spaces(i);
     let o = {
-        let mut g = ftd::executor::utils::replace_last_occurrence(markup(i).as_str(), "<p>", "");
+        let mut g =
+            ftd::executor::utils::replace_last_occurrence(markup(i, profile).as_str(), "<p>", "");
         g = ftd::executor::utils::replace_last_occurrence(g.as_str(), "</p>", "");
         g
     };
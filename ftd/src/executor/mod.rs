@@ -19,18 +19,20 @@ pub type FieldWithValue = (fastn_resolved::Field, Option<ftd_ast::VariableValue>
 
 pub use dummy::{DummyElement, ElementConstructor};
 pub use element::{
-    CheckBox, Code, Column, Common, Container, ContainerElement, Document, Element, Event,
+    CheckBox, Code, Column, Common, Container, ContainerElement, Document, Element, Event, Grid,
     HTMLData, Iframe, Image, ImageSrc, IterativeElement, RawElement, RawImage, Rive, Row, Text,
     TextInput, WebComponent,
 };
 pub use main::{Device, ExecuteDoc, RT};
+pub use markup::{headings, Heading, MarkdownProfile};
 pub use rive::RiveData;
 pub use styles::{
-    AlignSelf, Alignment, Anchor, Background, BackgroundImage, BackgroundPosition,
+    AlignSelf, Alignment, Anchor, Animation, Background, BackgroundImage, BackgroundPosition,
     BackgroundRepeat, BackgroundSize, BorderStyle, BreakpointWidth, Color, ColorValue, Cursor,
-    Display, FontSize, ImageFit, Length, LineClamp, LinearGradient, LinearGradientColor,
-    LinearGradientDirection, Loading, Overflow, Region, Resize, Resizing, ResponsiveType, Shadow,
-    Spacing, TextAlign, TextInputType, TextStyle, TextTransform, TextWeight, WhiteSpace,
+    Display, Easing, FontSize, GridAutoFlow, GridTemplate, GridValue, ImageFit, Keyframe,
+    Keyframes, Length, LineClamp, LinearGradient, LinearGradientColor, LinearGradientDirection,
+    Loading, Overflow, Region, Resize, Resizing, ResponsiveType, Shadow, Spacing, TextAlign,
+    TextInputType, TextStyle, TextTransform, TextWeight, Transition, WhiteSpace,
 };
 pub(crate) use tdoc::TDoc;
 pub(crate) use value::Value;
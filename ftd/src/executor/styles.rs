@@ -3266,6 +3266,8 @@ pub enum TextInputType {
     WEEK,
     COLOR,
     FILE,
+    NUMBER,
+    SUBMIT,
 }
 
 impl TextInputType {
@@ -3298,6 +3300,8 @@ impl TextInputType {
             ftd::interpreter::FTD_TEXT_INPUT_TYPE_WEEK => Ok(TextInputType::WEEK),
             ftd::interpreter::FTD_TEXT_INPUT_TYPE_COLOR => Ok(TextInputType::COLOR),
             ftd::interpreter::FTD_TEXT_INPUT_TYPE_FILE => Ok(TextInputType::FILE),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_NUMBER => Ok(TextInputType::NUMBER),
+            ftd::interpreter::FTD_TEXT_INPUT_TYPE_SUBMIT => Ok(TextInputType::SUBMIT),
             t => ftd::executor::utils::parse_error(
                 format!("Unknown variant `{}` for or-type `ftd.text-input-type`", t),
                 doc.name,
@@ -3346,6 +3350,8 @@ impl TextInputType {
             TextInputType::WEEK => "week".to_string(),
             TextInputType::COLOR => "color".to_string(),
             TextInputType::FILE => "file".to_string(),
+            TextInputType::NUMBER => "number".to_string(),
+            TextInputType::SUBMIT => "submit".to_string(),
         }
     }
 }
@@ -3597,6 +3603,420 @@ impl Display {
     }
 }
 
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum GridTemplate {
+    Fixed(String),
+    Count(i64),
+    Responsive(Box<ResponsiveGridTemplate>),
+}
+
+impl GridTemplate {
+    fn from_optional_values(
+        or_type_value: Option<(String, fastn_resolved::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<GridTemplate>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(GridTemplate::from_values(value, doc, line_number)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn from_value(
+        value: fastn_resolved::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<GridTemplate> {
+        use ftd::interpreter::{PropertyValueExt, ValueExt};
+
+        let binding = value.resolve(&doc.itdoc(), line_number)?;
+        let value = binding.get_or_type(doc.name, line_number)?;
+        let value = (value.1.to_owned(), value.2.to_owned());
+        GridTemplate::from_values(value, doc, line_number)
+    }
+
+    fn from_values(
+        or_type_value: (String, fastn_resolved::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<GridTemplate> {
+        use ftd::interpreter::{PropertyValueExt, ValueExt};
+
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_GRID_TEMPLATE_FIXED => Ok(GridTemplate::Fixed(
+                or_type_value
+                    .1
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .string(doc.name, line_number)?,
+            )),
+            ftd::interpreter::FTD_GRID_TEMPLATE_COUNT => Ok(GridTemplate::Count(
+                or_type_value
+                    .1
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .integer(doc.name, line_number)?,
+            )),
+            ftd::interpreter::FTD_GRID_TEMPLATE_RESPONSIVE => {
+                Ok(GridTemplate::Responsive(Box::new(
+                    ResponsiveGridTemplate::from_value(or_type_value.1.clone(), doc, line_number)?,
+                )))
+            }
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.grid-template`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_grid_template(
+        properties: &[fastn_resolved::Property],
+        arguments: &[fastn_resolved::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<GridTemplate>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_GRID_TEMPLATE,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            GridTemplate::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_string(&self, device: &Option<ftd::executor::Device>) -> String {
+        match self {
+            GridTemplate::Fixed(value) => value.to_string(),
+            GridTemplate::Count(count) => format!("repeat({}, minmax(0, 1fr))", count),
+            GridTemplate::Responsive(r) => match device {
+                Some(ftd::executor::Device::Mobile) => r.mobile.to_css_string(device),
+                _ => r.desktop.to_css_string(device),
+            },
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub struct ResponsiveGridTemplate {
+    pub desktop: GridTemplate,
+    pub mobile: GridTemplate,
+}
+
+impl ResponsiveGridTemplate {
+    fn from_value(
+        value: fastn_resolved::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<ResponsiveGridTemplate> {
+        use ftd::interpreter::PropertyValueExt;
+
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        let fields = match value.inner() {
+            Some(fastn_resolved::Value::Record { name, fields })
+                if name.eq(ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE) =>
+            {
+                fields
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected value of type record `{}`, found: {:?}",
+                        ftd::interpreter::FTD_RESPONSIVE_GRID_TEMPLATE,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+
+        let desktop = {
+            let value = fields
+                .get("desktop")
+                .ok_or(ftd::executor::Error::ParseError {
+                    message: "`desktop` field in ftd.responsive-grid-template not found"
+                        .to_string(),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })?;
+            GridTemplate::from_value(value.to_owned(), doc, line_number)?
+        };
+
+        let mobile = {
+            if let Some(value) = fields.get("mobile") {
+                GridTemplate::from_value(value.to_owned(), doc, line_number)?
+            } else {
+                desktop.clone()
+            }
+        };
+
+        Ok(ResponsiveGridTemplate { desktop, mobile })
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum GridValue {
+    Fixed(String),
+    Span(i64),
+    Responsive(Box<ResponsiveGridValue>),
+}
+
+impl GridValue {
+    fn from_optional_values(
+        or_type_value: Option<(String, fastn_resolved::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<GridValue>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(GridValue::from_values(value, doc, line_number)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn from_value(
+        value: fastn_resolved::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<GridValue> {
+        use ftd::interpreter::{PropertyValueExt, ValueExt};
+
+        let binding = value.resolve(&doc.itdoc(), line_number)?;
+        let value = binding.get_or_type(doc.name, line_number)?;
+        let value = (value.1.to_owned(), value.2.to_owned());
+        GridValue::from_values(value, doc, line_number)
+    }
+
+    fn from_values(
+        or_type_value: (String, fastn_resolved::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<GridValue> {
+        use ftd::interpreter::{PropertyValueExt, ValueExt};
+
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_GRID_VALUE_FIXED => Ok(GridValue::Fixed(
+                or_type_value
+                    .1
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .string(doc.name, line_number)?,
+            )),
+            ftd::interpreter::FTD_GRID_VALUE_SPAN => Ok(GridValue::Span(
+                or_type_value
+                    .1
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .integer(doc.name, line_number)?,
+            )),
+            ftd::interpreter::FTD_GRID_VALUE_RESPONSIVE => Ok(GridValue::Responsive(Box::new(
+                ResponsiveGridValue::from_value(or_type_value.1.clone(), doc, line_number)?,
+            ))),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.grid-value`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_grid_value(
+        properties: &[fastn_resolved::Property],
+        arguments: &[fastn_resolved::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<GridValue>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_GRID_VALUE,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            GridValue::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_string(&self, device: &Option<ftd::executor::Device>) -> String {
+        match self {
+            GridValue::Fixed(value) => value.to_string(),
+            GridValue::Span(span) => format!("span {}", span),
+            GridValue::Responsive(r) => match device {
+                Some(ftd::executor::Device::Mobile) => r.mobile.to_css_string(device),
+                _ => r.desktop.to_css_string(device),
+            },
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub struct ResponsiveGridValue {
+    pub desktop: GridValue,
+    pub mobile: GridValue,
+}
+
+impl ResponsiveGridValue {
+    fn from_value(
+        value: fastn_resolved::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<ResponsiveGridValue> {
+        use ftd::interpreter::PropertyValueExt;
+
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        let fields = match value.inner() {
+            Some(fastn_resolved::Value::Record { name, fields })
+                if name.eq(ftd::interpreter::FTD_RESPONSIVE_GRID_VALUE) =>
+            {
+                fields
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected value of type record `{}`, found: {:?}",
+                        ftd::interpreter::FTD_RESPONSIVE_GRID_VALUE,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+
+        let desktop = {
+            let value = fields
+                .get("desktop")
+                .ok_or(ftd::executor::Error::ParseError {
+                    message: "`desktop` field in ftd.responsive-grid-value not found".to_string(),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })?;
+            GridValue::from_value(value.to_owned(), doc, line_number)?
+        };
+
+        let mobile = {
+            if let Some(value) = fields.get("mobile") {
+                GridValue::from_value(value.to_owned(), doc, line_number)?
+            } else {
+                desktop.clone()
+            }
+        };
+
+        Ok(ResponsiveGridValue { desktop, mobile })
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum GridAutoFlow {
+    Row,
+    Column,
+    Dense,
+    RowDense,
+    ColumnDense,
+}
+
+impl GridAutoFlow {
+    fn from_optional_values(
+        or_type_value: Option<(String, fastn_resolved::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<ftd::executor::GridAutoFlow>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(ftd::executor::GridAutoFlow::from_values(
+                value,
+                doc,
+                line_number,
+            )?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn from_values(
+        or_type_value: (String, fastn_resolved::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<ftd::executor::GridAutoFlow> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_GRID_AUTO_FLOW_ROW => Ok(GridAutoFlow::Row),
+            ftd::interpreter::FTD_GRID_AUTO_FLOW_COLUMN => Ok(GridAutoFlow::Column),
+            ftd::interpreter::FTD_GRID_AUTO_FLOW_DENSE => Ok(GridAutoFlow::Dense),
+            ftd::interpreter::FTD_GRID_AUTO_FLOW_ROW_DENSE => Ok(GridAutoFlow::RowDense),
+            ftd::interpreter::FTD_GRID_AUTO_FLOW_COLUMN_DENSE => Ok(GridAutoFlow::ColumnDense),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.grid-auto-flow`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_grid_auto_flow(
+        properties: &[fastn_resolved::Property],
+        arguments: &[fastn_resolved::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<ftd::executor::GridAutoFlow>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_GRID_AUTO_FLOW,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            ftd::executor::GridAutoFlow::from_optional_values(
+                or_type_value.value,
+                doc,
+                line_number,
+            )?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_str(&self) -> &str {
+        match self {
+            GridAutoFlow::Row => "row",
+            GridAutoFlow::Column => "column",
+            GridAutoFlow::Dense => "dense",
+            GridAutoFlow::RowDense => "row dense",
+            GridAutoFlow::ColumnDense => "column dense",
+        }
+    }
+}
+
 #[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
 pub enum TextWeight {
     EXTRABOLD,
@@ -3948,34 +4368,280 @@ impl TextStyle {
                 _ => continue,
             }
         }
-
-        let filtered = result.trim_end();
-        let res = match filtered.is_empty() {
-            true => return ftd::interpreter::FTD_VALUE_UNCHANGED.to_string(),
-            false => filtered.to_string(),
-        };
-        format!("\"{}\"", res)
+
+        let filtered = result.trim_end();
+        let res = match filtered.is_empty() {
+            true => return ftd::interpreter::FTD_VALUE_UNCHANGED.to_string(),
+            false => filtered.to_string(),
+        };
+        format!("\"{}\"", res)
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum TextTransform {
+    NONE,
+    CAPITALIZE,
+    UPPERCASE,
+    LOWERCASE,
+    INITIAL,
+    INHERIT,
+}
+
+impl TextTransform {
+    fn from_optional_values(
+        or_type_value: Option<(String, fastn_resolved::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<Self>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(TextTransform::from_values(value, doc, line_number)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn from_values(
+        or_type_value: (String, fastn_resolved::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Self> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_TEXT_TRANSFORM_NONE => Ok(TextTransform::NONE),
+            ftd::interpreter::FTD_TEXT_TRANSFORM_CAPITALIZE => Ok(TextTransform::CAPITALIZE),
+            ftd::interpreter::FTD_TEXT_TRANSFORM_UPPERCASE => Ok(TextTransform::UPPERCASE),
+            ftd::interpreter::FTD_TEXT_TRANSFORM_LOWERCASE => Ok(TextTransform::LOWERCASE),
+            ftd::interpreter::FTD_TEXT_TRANSFORM_INITIAL => Ok(TextTransform::INITIAL),
+            ftd::interpreter::FTD_TEXT_TRANSFORM_INHERIT => Ok(TextTransform::INHERIT),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.text-transform`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_text_transform(
+        properties: &[fastn_resolved::Property],
+        arguments: &[fastn_resolved::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<TextTransform>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_TEXT_TRANSFORM,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            TextTransform::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_string(&self) -> String {
+        match self {
+            TextTransform::NONE => "none".to_string(),
+            TextTransform::CAPITALIZE => "capitalize".to_string(),
+            TextTransform::UPPERCASE => "uppercase".to_string(),
+            TextTransform::LOWERCASE => "lowercase".to_string(),
+            TextTransform::INHERIT => "inherit".to_string(),
+            TextTransform::INITIAL => "initial".to_string(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum BorderStyle {
+    DOTTED,
+    DASHED,
+    SOLID,
+    DOUBLE,
+    GROOVE,
+    RIDGE,
+    INSET,
+    OUTSET,
+}
+
+impl BorderStyle {
+    fn from_optional_values(
+        or_type_value: Option<(String, fastn_resolved::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<Self>> {
+        if let Some(value) = or_type_value {
+            return Ok(Some(BorderStyle::from_values(value, doc, line_number)?));
+        }
+        Ok(None)
+    }
+
+    fn from_values(
+        or_type_value: (String, fastn_resolved::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Self> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_BORDER_STYLE_DOTTED => Ok(BorderStyle::DOTTED),
+            ftd::interpreter::FTD_BORDER_STYLE_DASHED => Ok(BorderStyle::DASHED),
+            ftd::interpreter::FTD_BORDER_STYLE_SOLID => Ok(BorderStyle::SOLID),
+            ftd::interpreter::FTD_BORDER_STYLE_GROOVE => Ok(BorderStyle::GROOVE),
+            ftd::interpreter::FTD_BORDER_STYLE_RIDGE => Ok(BorderStyle::RIDGE),
+            ftd::interpreter::FTD_BORDER_STYLE_OUTSET => Ok(BorderStyle::OUTSET),
+            ftd::interpreter::FTD_BORDER_STYLE_INSET => Ok(BorderStyle::INSET),
+            ftd::interpreter::FTD_BORDER_STYLE_DOUBLE => Ok(BorderStyle::DOUBLE),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.border-style`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_border_style(
+        properties: &[fastn_resolved::Property],
+        arguments: &[fastn_resolved::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<BorderStyle>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_BORDER_STYLE,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            BorderStyle::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_string(&self) -> String {
+        match self {
+            BorderStyle::DOTTED => "dotted".to_string(),
+            BorderStyle::DASHED => "dashed".to_string(),
+            BorderStyle::SOLID => "solid".to_string(),
+            BorderStyle::DOUBLE => "double".to_string(),
+            BorderStyle::GROOVE => "groove".to_string(),
+            BorderStyle::RIDGE => "ridge".to_string(),
+            BorderStyle::INSET => "inset".to_string(),
+            BorderStyle::OUTSET => "outset".to_string(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
+pub enum ImageFit {
+    NONE,
+    FILL,
+    COVER,
+    CONTAIN,
+    SCALEDOWN,
+}
+
+impl ImageFit {
+    fn from_optional_values(
+        or_type_value: Option<(String, fastn_resolved::PropertyValue)>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<Self>> {
+        if let Some(value) = or_type_value {
+            return Ok(Some(ImageFit::from_values(value, doc, line_number)?));
+        }
+        Ok(None)
+    }
+
+    fn from_values(
+        or_type_value: (String, fastn_resolved::PropertyValue),
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Self> {
+        match or_type_value.0.as_str() {
+            ftd::interpreter::FTD_IMAGE_FIT_NONE => Ok(ImageFit::NONE),
+            ftd::interpreter::FTD_IMAGE_FIT_COVER => Ok(ImageFit::COVER),
+            ftd::interpreter::FTD_IMAGE_FIT_CONTAIN => Ok(ImageFit::CONTAIN),
+            ftd::interpreter::FTD_IMAGE_FIT_FILL => Ok(ImageFit::FILL),
+            ftd::interpreter::FTD_IMAGE_FIT_SCALE_DOWN => Ok(ImageFit::SCALEDOWN),
+            t => ftd::executor::utils::parse_error(
+                format!("Unknown variant `{}` for or-type `ftd.image-fit`", t),
+                doc.name,
+                line_number,
+            ),
+        }
+    }
+
+    pub(crate) fn optional_image_fit(
+        properties: &[fastn_resolved::Property],
+        arguments: &[fastn_resolved::Argument],
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+        key: &str,
+        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
+        component_name: &str,
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<ImageFit>>> {
+        let or_type_value = ftd::executor::value::optional_or_type(
+            key,
+            component_name,
+            properties,
+            arguments,
+            doc,
+            line_number,
+            ftd::interpreter::FTD_IMAGE_FIT,
+            inherited_variables,
+        )?;
+
+        Ok(ftd::executor::Value::new(
+            ImageFit::from_optional_values(or_type_value.value, doc, line_number)?,
+            or_type_value.line_number,
+            or_type_value.properties,
+        ))
+    }
+
+    pub fn to_css_string(&self) -> String {
+        match self {
+            ImageFit::NONE => "none".to_string(),
+            ImageFit::COVER => "cover".to_string(),
+            ImageFit::CONTAIN => "contain".to_string(),
+            ImageFit::FILL => "fill".to_string(),
+            ImageFit::SCALEDOWN => "scale-down".to_string(),
+        }
     }
 }
 
-#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum TextTransform {
-    NONE,
-    CAPITALIZE,
-    UPPERCASE,
-    LOWERCASE,
-    INITIAL,
-    INHERIT,
+/// https://html.spec.whatwg.org/multipage/urls-and-fetching.html#lazy-loading-attributes
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+#[serde(tag = "type")]
+pub enum Loading {
+    #[default]
+    Lazy,
+    Eager,
 }
 
-impl TextTransform {
+impl Loading {
     fn from_optional_values(
         or_type_value: Option<(String, fastn_resolved::PropertyValue)>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
     ) -> ftd::executor::Result<Option<Self>> {
         if let Some(value) = or_type_value {
-            Ok(Some(TextTransform::from_values(value, doc, line_number)?))
+            Ok(Some(Loading::from_values(value, doc, line_number)?))
         } else {
             Ok(None)
         }
@@ -3987,21 +4653,20 @@ impl TextTransform {
         line_number: usize,
     ) -> ftd::executor::Result<Self> {
         match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_TEXT_TRANSFORM_NONE => Ok(TextTransform::NONE),
-            ftd::interpreter::FTD_TEXT_TRANSFORM_CAPITALIZE => Ok(TextTransform::CAPITALIZE),
-            ftd::interpreter::FTD_TEXT_TRANSFORM_UPPERCASE => Ok(TextTransform::UPPERCASE),
-            ftd::interpreter::FTD_TEXT_TRANSFORM_LOWERCASE => Ok(TextTransform::LOWERCASE),
-            ftd::interpreter::FTD_TEXT_TRANSFORM_INITIAL => Ok(TextTransform::INITIAL),
-            ftd::interpreter::FTD_TEXT_TRANSFORM_INHERIT => Ok(TextTransform::INHERIT),
+            ftd::interpreter::FTD_LOADING_LAZY => Ok(Loading::Lazy),
+            ftd::interpreter::FTD_LOADING_EAGER => Ok(Loading::Eager),
             t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.text-transform`", t),
+                format!(
+                    "Unknown variant `{}` for or-type `ftd.loading`. Help: use `lazy` or `eager`",
+                    t
+                ),
                 doc.name,
                 line_number,
             ),
         }
     }
 
-    pub(crate) fn optional_text_transform(
+    pub(crate) fn loading_with_default(
         properties: &[fastn_resolved::Property],
         arguments: &[fastn_resolved::Argument],
         doc: &ftd::executor::TDoc,
@@ -4009,7 +4674,7 @@ impl TextTransform {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<TextTransform>>> {
+    ) -> ftd::executor::Result<ftd::executor::Value<Loading>> {
         let or_type_value = ftd::executor::value::optional_or_type(
             key,
             component_name,
@@ -4017,12 +4682,13 @@ impl TextTransform {
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_TEXT_TRANSFORM,
+            ftd::interpreter::FTD_LOADING,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            TextTransform::from_optional_values(or_type_value.value, doc, line_number)?,
+            Loading::from_optional_values(or_type_value.value, doc, line_number)?
+                .unwrap_or_default(),
             or_type_value.line_number,
             or_type_value.properties,
         ))
@@ -4030,63 +4696,191 @@ impl TextTransform {
 
     pub fn to_css_string(&self) -> String {
         match self {
-            TextTransform::NONE => "none".to_string(),
-            TextTransform::CAPITALIZE => "capitalize".to_string(),
-            TextTransform::UPPERCASE => "uppercase".to_string(),
-            TextTransform::LOWERCASE => "lowercase".to_string(),
-            TextTransform::INHERIT => "inherit".to_string(),
-            TextTransform::INITIAL => "initial".to_string(),
+            Loading::Lazy => "lazy".to_string(),
+            Loading::Eager => "eager".to_string(),
         }
     }
 }
 
-#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum BorderStyle {
-    DOTTED,
-    DASHED,
-    SOLID,
-    DOUBLE,
-    GROOVE,
-    RIDGE,
-    INSET,
-    OUTSET,
+pub struct LineClamp;
+
+impl LineClamp {
+    pub(crate) fn display_pattern() -> (String, bool) {
+        ("-webkit-box".to_string(), false)
+    }
+
+    pub(crate) fn overflow_pattern() -> (String, bool) {
+        ("hidden".to_string(), false)
+    }
+
+    pub(crate) fn webkit_box_orient_pattern() -> (String, bool) {
+        ("vertical".to_string(), false)
+    }
 }
 
-impl BorderStyle {
-    fn from_optional_values(
-        or_type_value: Option<(String, fastn_resolved::PropertyValue)>,
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub enum Easing {
+    Linear,
+    #[default]
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    CubicBezier(String),
+    Steps(i64),
+}
+
+impl Easing {
+    fn from_value(
+        value: fastn_resolved::PropertyValue,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Option<Self>> {
-        if let Some(value) = or_type_value {
-            return Ok(Some(BorderStyle::from_values(value, doc, line_number)?));
-        }
-        Ok(None)
+    ) -> ftd::executor::Result<Easing> {
+        use ftd::interpreter::{PropertyValueExt, ValueExt};
+
+        let binding = value.resolve(&doc.itdoc(), line_number)?;
+        let value = binding.get_or_type(doc.name, line_number)?;
+        let value = (value.1.to_owned(), value.2.to_owned());
+        Easing::from_values(value, doc, line_number)
     }
 
     fn from_values(
         or_type_value: (String, fastn_resolved::PropertyValue),
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Self> {
+    ) -> ftd::executor::Result<Easing> {
+        use ftd::interpreter::{PropertyValueExt, ValueExt};
+
         match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_BORDER_STYLE_DOTTED => Ok(BorderStyle::DOTTED),
-            ftd::interpreter::FTD_BORDER_STYLE_DASHED => Ok(BorderStyle::DASHED),
-            ftd::interpreter::FTD_BORDER_STYLE_SOLID => Ok(BorderStyle::SOLID),
-            ftd::interpreter::FTD_BORDER_STYLE_GROOVE => Ok(BorderStyle::GROOVE),
-            ftd::interpreter::FTD_BORDER_STYLE_RIDGE => Ok(BorderStyle::RIDGE),
-            ftd::interpreter::FTD_BORDER_STYLE_OUTSET => Ok(BorderStyle::OUTSET),
-            ftd::interpreter::FTD_BORDER_STYLE_INSET => Ok(BorderStyle::INSET),
-            ftd::interpreter::FTD_BORDER_STYLE_DOUBLE => Ok(BorderStyle::DOUBLE),
+            ftd::interpreter::FTD_EASING_LINEAR => Ok(Easing::Linear),
+            ftd::interpreter::FTD_EASING_EASE => Ok(Easing::Ease),
+            ftd::interpreter::FTD_EASING_EASE_IN => Ok(Easing::EaseIn),
+            ftd::interpreter::FTD_EASING_EASE_OUT => Ok(Easing::EaseOut),
+            ftd::interpreter::FTD_EASING_EASE_IN_OUT => Ok(Easing::EaseInOut),
+            ftd::interpreter::FTD_EASING_CUBIC_BEZIER => Ok(Easing::CubicBezier(
+                or_type_value
+                    .1
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .string(doc.name, line_number)?,
+            )),
+            ftd::interpreter::FTD_EASING_STEPS => Ok(Easing::Steps(
+                or_type_value
+                    .1
+                    .clone()
+                    .resolve(&doc.itdoc(), line_number)?
+                    .integer(doc.name, line_number)?,
+            )),
             t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.border-style`", t),
+                format!("Unknown variant `{}` for or-type `ftd.easing`", t),
                 doc.name,
                 line_number,
             ),
         }
     }
 
-    pub(crate) fn optional_border_style(
+    pub fn to_css_string(&self) -> String {
+        match self {
+            Easing::Linear => "linear".to_string(),
+            Easing::Ease => "ease".to_string(),
+            Easing::EaseIn => "ease-in".to_string(),
+            Easing::EaseOut => "ease-out".to_string(),
+            Easing::EaseInOut => "ease-in-out".to_string(),
+            Easing::CubicBezier(v) => format!("cubic-bezier({})", v),
+            Easing::Steps(v) => format!("steps({})", v),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Transition {
+    pub property: ftd::executor::Value<String>,
+    pub duration: ftd::executor::Value<i64>,
+    pub easing: ftd::executor::Value<Easing>,
+    pub delay: ftd::executor::Value<i64>,
+}
+
+impl Transition {
+    fn from_values(
+        values: ftd::Map<fastn_resolved::PropertyValue>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<ftd::executor::Transition> {
+        use ftd::executor::fastn_type_functions::{PropertySourceExt, PropertyValueExt as _};
+        use ftd::interpreter::{PropertyValueExt, ValueExt};
+
+        let get_property_value = |field_name: &str| {
+            values
+                .get(field_name)
+                .ok_or_else(|| ftd::executor::Error::ParseError {
+                    message: format!("`{}` field in ftd.transition not found", field_name),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })
+        };
+
+        let property = ftd::executor::Value::new(
+            get_property_value("property")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .string(doc.name, line_number)?,
+            Some(line_number),
+            vec![get_property_value("property")?
+                .to_property(fastn_resolved::PropertySource::header("property"))],
+        );
+
+        let duration = ftd::executor::Value::new(
+            get_property_value("duration")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .integer(doc.name, line_number)?,
+            Some(line_number),
+            vec![get_property_value("duration")?
+                .to_property(fastn_resolved::PropertySource::header("duration"))],
+        );
+
+        let easing = ftd::executor::Value::new(
+            Easing::from_value(get_property_value("easing")?.clone(), doc, line_number)?,
+            Some(line_number),
+            vec![get_property_value("easing")?
+                .to_property(fastn_resolved::PropertySource::header("easing"))],
+        );
+
+        let delay = ftd::executor::Value::new(
+            get_property_value("delay")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .integer(doc.name, line_number)?,
+            Some(line_number),
+            vec![get_property_value("delay")?
+                .to_property(fastn_resolved::PropertySource::header("delay"))],
+        );
+
+        Ok(ftd::executor::Transition {
+            property,
+            duration,
+            easing,
+            delay,
+        })
+    }
+
+    fn from_optional_values(
+        or_type_value: Option<ftd::Map<fastn_resolved::PropertyValue>>,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Option<ftd::executor::Transition>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(ftd::executor::Transition::from_values(
+                value,
+                doc,
+                line_number,
+            )?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub(crate) fn optional_transition(
         properties: &[fastn_resolved::Property],
         arguments: &[fastn_resolved::Argument],
         doc: &ftd::executor::TDoc,
@@ -4094,159 +4888,359 @@ impl BorderStyle {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<BorderStyle>>> {
-        let or_type_value = ftd::executor::value::optional_or_type(
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<ftd::executor::Transition>>> {
+        let record_values = ftd::executor::value::optional_record_inherited(
             key,
             component_name,
             properties,
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_BORDER_STYLE,
+            ftd::interpreter::FTD_TRANSITION,
             inherited_variables,
         )?;
 
-        Ok(ftd::executor::Value::new(
-            BorderStyle::from_optional_values(or_type_value.value, doc, line_number)?,
-            or_type_value.line_number,
-            or_type_value.properties,
-        ))
+        Ok(ftd::executor::Value::new(
+            ftd::executor::Transition::from_optional_values(record_values.value, doc, line_number)?,
+            record_values.line_number,
+            record_values.properties,
+        ))
+    }
+
+    /// `property` can have more than one property, like `background, opacity`,
+    /// and each of them gets the same duration, easing and delay.
+    pub fn to_css_string(&self) -> String {
+        self.property
+            .value
+            .split(',')
+            .map(|property| {
+                format!(
+                    "{} {}ms {} {}ms",
+                    property.trim(),
+                    self.duration.value,
+                    self.easing.value.to_css_string(),
+                    self.delay.value
+                )
+            })
+            .join(", ")
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Keyframe {
+    pub at: i64,
+    pub opacity: Option<f64>,
+    pub scale: Option<f64>,
+    pub rotate: Option<i64>,
+    pub move_x: Option<Length>,
+    pub move_y: Option<Length>,
+}
+
+impl Keyframe {
+    fn from_vec_values(
+        value: fastn_resolved::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Vec<Keyframe>> {
+        use ftd::interpreter::PropertyValueExt;
+
+        let mut result = vec![];
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        match value.inner() {
+            Some(fastn_resolved::Value::List { data, kind })
+                if kind.kind.get_name().eq(ftd::interpreter::FTD_KEYFRAME) =>
+            {
+                for element in data.iter() {
+                    let ln = element.line_number();
+                    result.push(Keyframe::from_value(element.to_owned(), doc, ln)?)
+                }
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected list value of type `{}`, found: {:?}",
+                        ftd::interpreter::FTD_KEYFRAME,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+        Ok(result)
+    }
+
+    fn from_value(
+        value: fastn_resolved::PropertyValue,
+        doc: &ftd::executor::TDoc,
+        line_number: usize,
+    ) -> ftd::executor::Result<Keyframe> {
+        use ftd::interpreter::{PropertyValueExt, ValueExt};
+
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        let fields = match value.inner() {
+            Some(fastn_resolved::Value::Record { name, fields })
+                if name.eq(ftd::interpreter::FTD_KEYFRAME) =>
+            {
+                fields
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected value of type record `{}`, found: {:?}",
+                        ftd::interpreter::FTD_KEYFRAME,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+
+        let get_value = |field_name: &str| -> ftd::executor::Result<Option<fastn_resolved::Value>> {
+            match fields.get(field_name) {
+                Some(value) => Ok(value.clone().resolve(&doc.itdoc(), line_number)?.inner()),
+                None => Ok(None),
+            }
+        };
+
+        Ok(Keyframe {
+            at: match get_value("at")? {
+                Some(value) => value.integer(doc.name, line_number)?,
+                None => {
+                    return ftd::executor::utils::parse_error(
+                        "`at` field in ftd.keyframe not found",
+                        doc.name,
+                        line_number,
+                    )
+                }
+            },
+            opacity: get_value("opacity")?
+                .map(|v| v.decimal(doc.name, line_number))
+                .transpose()?,
+            scale: get_value("scale")?
+                .map(|v| v.decimal(doc.name, line_number))
+                .transpose()?,
+            rotate: get_value("rotate")?
+                .map(|v| v.integer(doc.name, line_number))
+                .transpose()?,
+            move_x: Length::from_optional_value(fields.get("move-x").cloned(), doc, line_number)?,
+            move_y: Length::from_optional_value(fields.get("move-y").cloned(), doc, line_number)?,
+        })
     }
 
-    pub fn to_css_string(&self) -> String {
-        match self {
-            BorderStyle::DOTTED => "dotted".to_string(),
-            BorderStyle::DASHED => "dashed".to_string(),
-            BorderStyle::SOLID => "solid".to_string(),
-            BorderStyle::DOUBLE => "double".to_string(),
-            BorderStyle::GROOVE => "groove".to_string(),
-            BorderStyle::RIDGE => "ridge".to_string(),
-            BorderStyle::INSET => "inset".to_string(),
-            BorderStyle::OUTSET => "outset".to_string(),
+    pub fn to_css_string(&self, device: &Option<ftd::executor::Device>) -> String {
+        let mut css = "".to_string();
+        if let Some(opacity) = self.opacity {
+            css.push_str(format!(" opacity: {};", opacity).as_str());
+        }
+        let mut transform = vec![];
+        if self.move_x.is_some() || self.move_y.is_some() {
+            let length = |l: &Option<Length>| {
+                l.as_ref()
+                    .map_or("0".to_string(), |l| l.to_css_string(device))
+            };
+            transform.push(format!(
+                "translate({}, {})",
+                length(&self.move_x),
+                length(&self.move_y)
+            ));
+        }
+        if let Some(scale) = self.scale {
+            transform.push(format!("scale({})", scale));
+        }
+        if let Some(rotate) = self.rotate {
+            transform.push(format!("rotate({}deg)", rotate));
+        }
+        if !transform.is_empty() {
+            css.push_str(format!(" transform: {};", transform.join(" ")).as_str());
         }
+        format!("{}% {{{} }}", self.at, css)
     }
 }
 
-#[derive(serde::Deserialize, Debug, PartialEq, Clone, serde::Serialize)]
-pub enum ImageFit {
-    NONE,
-    FILL,
-    COVER,
-    CONTAIN,
-    SCALEDOWN,
+#[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
+pub struct Keyframes {
+    pub name: String,
+    pub frames: Vec<Keyframe>,
 }
 
-impl ImageFit {
-    fn from_optional_values(
-        or_type_value: Option<(String, fastn_resolved::PropertyValue)>,
+impl Keyframes {
+    fn from_value(
+        value: fastn_resolved::PropertyValue,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Option<Self>> {
-        if let Some(value) = or_type_value {
-            return Ok(Some(ImageFit::from_values(value, doc, line_number)?));
-        }
-        Ok(None)
-    }
+    ) -> ftd::executor::Result<Keyframes> {
+        use ftd::interpreter::{PropertyValueExt, ValueExt};
 
-    fn from_values(
-        or_type_value: (String, fastn_resolved::PropertyValue),
-        doc: &ftd::executor::TDoc,
-        line_number: usize,
-    ) -> ftd::executor::Result<Self> {
-        match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_IMAGE_FIT_NONE => Ok(ImageFit::NONE),
-            ftd::interpreter::FTD_IMAGE_FIT_COVER => Ok(ImageFit::COVER),
-            ftd::interpreter::FTD_IMAGE_FIT_CONTAIN => Ok(ImageFit::CONTAIN),
-            ftd::interpreter::FTD_IMAGE_FIT_FILL => Ok(ImageFit::FILL),
-            ftd::interpreter::FTD_IMAGE_FIT_SCALE_DOWN => Ok(ImageFit::SCALEDOWN),
-            t => ftd::executor::utils::parse_error(
-                format!("Unknown variant `{}` for or-type `ftd.image-fit`", t),
-                doc.name,
+        let value = value.resolve(&doc.itdoc(), line_number)?;
+        let fields = match value.inner() {
+            Some(fastn_resolved::Value::Record { name, fields })
+                if name.eq(ftd::interpreter::FTD_KEYFRAMES) =>
+            {
+                fields
+            }
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected value of type record `{}`, found: {:?}",
+                        ftd::interpreter::FTD_KEYFRAMES,
+                        t
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+
+        let get_property_value = |field_name: &str| {
+            fields
+                .get(field_name)
+                .ok_or_else(|| ftd::executor::Error::ParseError {
+                    message: format!("`{}` field in ftd.keyframes not found", field_name),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })
+        };
+
+        Ok(Keyframes {
+            name: get_property_value("name")?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?
+                .string(doc.name, line_number)?,
+            frames: Keyframe::from_vec_values(
+                get_property_value("frames")?.clone(),
+                doc,
                 line_number,
-            ),
-        }
+            )?,
+        })
     }
 
-    pub(crate) fn optional_image_fit(
-        properties: &[fastn_resolved::Property],
-        arguments: &[fastn_resolved::Argument],
-        doc: &ftd::executor::TDoc,
-        line_number: usize,
-        key: &str,
-        inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
-        component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Option<ImageFit>>> {
-        let or_type_value = ftd::executor::value::optional_or_type(
-            key,
-            component_name,
-            properties,
-            arguments,
-            doc,
-            line_number,
-            ftd::interpreter::FTD_IMAGE_FIT,
-            inherited_variables,
-        )?;
-
-        Ok(ftd::executor::Value::new(
-            ImageFit::from_optional_values(or_type_value.value, doc, line_number)?,
-            or_type_value.line_number,
-            or_type_value.properties,
-        ))
+    /// The name of the `@keyframes` rule, `fade in` is `ftd-fade-in`.
+    pub fn css_name(&self) -> String {
+        format!(
+            "ftd-{}",
+            self.name
+                .chars()
+                .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '-'
+                })
+                .collect::<String>()
+        )
     }
 
-    pub fn to_css_string(&self) -> String {
-        match self {
-            ImageFit::NONE => "none".to_string(),
-            ImageFit::COVER => "cover".to_string(),
-            ImageFit::CONTAIN => "contain".to_string(),
-            ImageFit::FILL => "fill".to_string(),
-            ImageFit::SCALEDOWN => "scale-down".to_string(),
-        }
+    pub fn to_css_string(&self, device: &Option<ftd::executor::Device>) -> String {
+        format!(
+            "@keyframes {} {{ {} }}",
+            self.css_name(),
+            self.frames
+                .iter()
+                .map(|frame| frame.to_css_string(device))
+                .join(" ")
+        )
     }
 }
 
-/// https://html.spec.whatwg.org/multipage/urls-and-fetching.html#lazy-loading-attributes
 #[derive(serde::Deserialize, Debug, Default, PartialEq, Clone, serde::Serialize)]
-#[serde(tag = "type")]
-pub enum Loading {
-    #[default]
-    Lazy,
-    Eager,
+pub struct Animation {
+    pub keyframes: ftd::executor::Value<Keyframes>,
+    pub duration: ftd::executor::Value<i64>,
+    pub easing: ftd::executor::Value<Easing>,
+    pub delay: ftd::executor::Value<i64>,
+    pub iterations: ftd::executor::Value<i64>,
+    pub infinite: ftd::executor::Value<bool>,
+    pub alternate: ftd::executor::Value<bool>,
 }
 
-impl Loading {
-    fn from_optional_values(
-        or_type_value: Option<(String, fastn_resolved::PropertyValue)>,
+impl Animation {
+    fn from_values(
+        values: ftd::Map<fastn_resolved::PropertyValue>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Option<Self>> {
-        if let Some(value) = or_type_value {
-            Ok(Some(Loading::from_values(value, doc, line_number)?))
-        } else {
-            Ok(None)
-        }
+    ) -> ftd::executor::Result<ftd::executor::Animation> {
+        use ftd::executor::fastn_type_functions::{PropertySourceExt, PropertyValueExt as _};
+        use ftd::interpreter::{PropertyValueExt, ValueExt};
+
+        let get_property_value = |field_name: &str| {
+            values
+                .get(field_name)
+                .ok_or_else(|| ftd::executor::Error::ParseError {
+                    message: format!("`{}` field in ftd.animation not found", field_name),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                })
+        };
+
+        let value = |field_name: &str| -> ftd::executor::Result<fastn_resolved::Value> {
+            Ok(get_property_value(field_name)?
+                .clone()
+                .resolve(&doc.itdoc(), line_number)?)
+        };
+
+        let property = |field_name: &str| -> ftd::executor::Result<Vec<fastn_resolved::Property>> {
+            Ok(vec![get_property_value(field_name)?.to_property(
+                fastn_resolved::PropertySource::header(field_name),
+            )])
+        };
+
+        Ok(ftd::executor::Animation {
+            keyframes: ftd::executor::Value::new(
+                Keyframes::from_value(get_property_value("keyframes")?.clone(), doc, line_number)?,
+                Some(line_number),
+                property("keyframes")?,
+            ),
+            duration: ftd::executor::Value::new(
+                value("duration")?.integer(doc.name, line_number)?,
+                Some(line_number),
+                property("duration")?,
+            ),
+            easing: ftd::executor::Value::new(
+                Easing::from_value(get_property_value("easing")?.clone(), doc, line_number)?,
+                Some(line_number),
+                property("easing")?,
+            ),
+            delay: ftd::executor::Value::new(
+                value("delay")?.integer(doc.name, line_number)?,
+                Some(line_number),
+                property("delay")?,
+            ),
+            iterations: ftd::executor::Value::new(
+                value("iterations")?.integer(doc.name, line_number)?,
+                Some(line_number),
+                property("iterations")?,
+            ),
+            infinite: ftd::executor::Value::new(
+                value("infinite")?.bool(doc.name, line_number)?,
+                Some(line_number),
+                property("infinite")?,
+            ),
+            alternate: ftd::executor::Value::new(
+                value("alternate")?.bool(doc.name, line_number)?,
+                Some(line_number),
+                property("alternate")?,
+            ),
+        })
     }
 
-    fn from_values(
-        or_type_value: (String, fastn_resolved::PropertyValue),
+    fn from_optional_values(
+        or_type_value: Option<ftd::Map<fastn_resolved::PropertyValue>>,
         doc: &ftd::executor::TDoc,
         line_number: usize,
-    ) -> ftd::executor::Result<Self> {
-        match or_type_value.0.as_str() {
-            ftd::interpreter::FTD_LOADING_LAZY => Ok(Loading::Lazy),
-            ftd::interpreter::FTD_LOADING_EAGER => Ok(Loading::Eager),
-            t => ftd::executor::utils::parse_error(
-                format!(
-                    "Unknown variant `{}` for or-type `ftd.loading`. Help: use `lazy` or `eager`",
-                    t
-                ),
-                doc.name,
+    ) -> ftd::executor::Result<Option<ftd::executor::Animation>> {
+        if let Some(value) = or_type_value {
+            Ok(Some(ftd::executor::Animation::from_values(
+                value,
+                doc,
                 line_number,
-            ),
+            )?))
+        } else {
+            Ok(None)
         }
     }
 
-    pub(crate) fn loading_with_default(
+    pub(crate) fn optional_animation(
         properties: &[fastn_resolved::Property],
         arguments: &[fastn_resolved::Argument],
         doc: &ftd::executor::TDoc,
@@ -4254,46 +5248,42 @@ impl Loading {
         key: &str,
         inherited_variables: &ftd::VecMap<(String, Vec<usize>)>,
         component_name: &str,
-    ) -> ftd::executor::Result<ftd::executor::Value<Loading>> {
-        let or_type_value = ftd::executor::value::optional_or_type(
+    ) -> ftd::executor::Result<ftd::executor::Value<Option<ftd::executor::Animation>>> {
+        let record_values = ftd::executor::value::optional_record_inherited(
             key,
             component_name,
             properties,
             arguments,
             doc,
             line_number,
-            ftd::interpreter::FTD_LOADING,
+            ftd::interpreter::FTD_ANIMATION,
             inherited_variables,
         )?;
 
         Ok(ftd::executor::Value::new(
-            Loading::from_optional_values(or_type_value.value, doc, line_number)?
-                .unwrap_or_default(),
-            or_type_value.line_number,
-            or_type_value.properties,
+            ftd::executor::Animation::from_optional_values(record_values.value, doc, line_number)?,
+            record_values.line_number,
+            record_values.properties,
         ))
     }
 
     pub fn to_css_string(&self) -> String {
-        match self {
-            Loading::Lazy => "lazy".to_string(),
-            Loading::Eager => "eager".to_string(),
-        }
-    }
-}
-
-pub struct LineClamp;
-
-impl LineClamp {
-    pub(crate) fn display_pattern() -> (String, bool) {
-        ("-webkit-box".to_string(), false)
-    }
-
-    pub(crate) fn overflow_pattern() -> (String, bool) {
-        ("hidden".to_string(), false)
-    }
-
-    pub(crate) fn webkit_box_orient_pattern() -> (String, bool) {
-        ("vertical".to_string(), false)
+        format!(
+            "{} {}ms {} {}ms {} {} both",
+            self.keyframes.value.css_name(),
+            self.duration.value,
+            self.easing.value.to_css_string(),
+            self.delay.value,
+            if self.infinite.value {
+                "infinite".to_string()
+            } else {
+                self.iterations.value.to_string()
+            },
+            if self.alternate.value {
+                "alternate"
+            } else {
+                "normal"
+            }
+        )
     }
 }
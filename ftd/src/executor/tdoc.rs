@@ -10,6 +10,10 @@ pub struct TDoc<'a> {
     pub js: &'a mut std::collections::HashSet<String>,
     pub css: &'a mut std::collections::HashSet<String>,
     pub rive_data: &'a mut Vec<ftd::executor::RiveData>,
+    /// `@keyframes` rules of the `animation`s in the document, by name
+    pub keyframes: &'a mut ftd::Map<String>,
+    /// The markdown extensions of `ftd.text` in the document
+    pub markdown: &'a ftd::executor::MarkdownProfile,
 }
 
 impl TDoc<'_> {
@@ -243,6 +247,7 @@ impl TDoc<'_> {
             conditional_value: conditions,
             line_number,
             is_static: true,
+            sse: None,
         }
         .set_static(&self.itdoc());
 
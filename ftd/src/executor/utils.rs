@@ -239,7 +239,7 @@ pub(crate) fn update_local_variable_references_in_component_(
         );
     }
 
-    if let Some(fastn_resolved::Loop { on, .. }) = component.iteration.as_mut() {
+    if let Some(fastn_resolved::Loop { on, key, .. }) = component.iteration.as_mut() {
         update_local_variable_reference_in_property_value(
             on,
             local_variable_map,
@@ -249,6 +249,17 @@ pub(crate) fn update_local_variable_references_in_component_(
             doc,
             is_children,
         );
+        if let Some(key) = key.as_mut() {
+            update_local_variable_reference_in_property_value(
+                key,
+                local_variable_map,
+                inherited_variables,
+                replace_property_value,
+                local_container,
+                doc,
+                is_children,
+            );
+        }
     }
 
     for child in component.children.iter_mut() {
@@ -490,6 +501,7 @@ fn update_inherited_reference_in_property_value(
                         line_number: 0,
                         source: ftd_ast::ValueSource::Default,
                         condition: None,
+                        span: Default::default(),
                     },
                     &mut doc.itdoc(),
                     property_value.is_mutable(),
@@ -545,6 +557,7 @@ fn update_inherited_reference_in_property_value(
                     line_number: 0,
                     source: ftd_ast::ValueSource::Default,
                     condition: None,
+                    span: Default::default(),
                 },
                 &mut doc.itdoc(),
                 property_value.is_mutable(),
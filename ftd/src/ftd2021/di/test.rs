@@ -122,14 +122,18 @@ fn di_import() {
         "ASTParseError: foo:1 -> SubSection not expected for import statement \
         `Section { name: \"import\", kind: None, caption: Some(KV(KV { \
         line_number: 1, key: \"$caption$\", kind: None, value: Some(\"foo\"), \
-        condition: None, access_modifier: Public, source: Caption })), \
-        headers: Headers([]), body: None, sub_sections: [Section { name: \
-        \"ftd.text\", kind: None, caption: Some(KV(KV { line_number: 3, key: \
-        \"$caption$\", kind: None, value: Some(\"Hello\"), condition: None, \
-        access_modifier: Public, source: Caption })), headers: Headers([]), \
+        condition: None, access_modifier: Public, source: Caption, span: Span \
+        { start: 11, end: 14 }, key_span: Span { start: 11, end: 14 }, \
+        condition_span: None })), headers: Headers([]), body: None, \
+        sub_sections: [Section { name: \"ftd.text\", kind: None, caption: \
+        Some(KV(KV { line_number: 3, key: \"$caption$\", kind: None, value: \
+        Some(\"Hello\"), condition: None, access_modifier: Public, source: \
+        Caption, span: Span { start: 29, end: 34 }, key_span: Span { start: \
+        29, end: 34 }, condition_span: None })), headers: Headers([]), \
         body: None, sub_sections: [], is_commented: false, line_number: 3, \
-        block_body: false }], is_commented: false, line_number: 1, \
-        block_body: false }`",
+        block_body: false, span: Span { start: 19, end: 27 } }], \
+        is_commented: false, line_number: 1, block_body: false, span: Span \
+        { start: 3, end: 9 } }`",
     )
 }
 
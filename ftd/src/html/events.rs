@@ -151,5 +151,12 @@ fn to_event_name(event_name: &fastn_resolved::EventName) -> String {
         fastn_resolved::EventName::RivePause(timeline) => {
             format!("onrivepause[{}]", timeline)
         }
+        fastn_resolved::EventName::Submit => "onsubmit".to_string(),
+        fastn_resolved::EventName::Scroll(_) => "onscroll".to_string(),
+        fastn_resolved::EventName::KeyDown(keys) => format!("onkeydown[{}]", keys.join("-")),
+        fastn_resolved::EventName::Interval(ms) => format!("oninterval[{}]", ms),
+        fastn_resolved::EventName::Timeout(ms) => format!("ontimeout[{}]", ms),
+        fastn_resolved::EventName::Visible => "onvisible".to_string(),
+        fastn_resolved::EventName::Hidden => "onhidden".to_string(),
     }
 }
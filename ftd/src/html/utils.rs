@@ -766,6 +766,16 @@ pub fn get_css_html(external_css: &[String]) -> String {
     result
 }
 
+pub fn get_keyframes_html(keyframes: &ftd::Map<String>) -> String {
+    if keyframes.is_empty() {
+        return "".to_string();
+    }
+    format!(
+        "<style>{}</style>",
+        keyframes.values().cloned().collect::<Vec<_>>().join("\n")
+    )
+}
+
 pub fn get_meta_data(html_data: &ftd::html::HTMLData) -> String {
     let mut result = vec![];
     if let Some(ref title) = html_data.og_title {
@@ -7,7 +7,7 @@ use ftd::interpreter::FunctionExt;
 use ftd::interpreter::{ComponentExt, VariableExt};
 
 /// Array with the size of how many builtins are provided by the host
-pub type HostBuiltins = [(String, fastn_resolved::Definition); 3];
+pub type HostBuiltins = [(String, fastn_resolved::Definition); 4];
 
 /// The `InterpreterState` struct is a representation of the state of an interpreter. It contains
 /// information about the interpreter's current state and its progress through the code being
@@ -31,7 +31,15 @@ pub type HostBuiltins = [(String, fastn_resolved::Definition); 3];
 ///
 /// - `instructions`: a `Vec` of `fastn_resolved::Component`s that represents the instructions
 ///   that the interpreter has processed.
-#[derive(Debug, Clone, Default, PartialEq)]
+///
+/// - `errors`: the errors of the things that could not be processed so far.
+///
+/// - `warnings`: the things that are likely mistakes but do not stop the document from
+///   rendering, like a non-exhaustive `match`.
+///
+/// - `failed`: the full names of the definitions that could not be processed. The things that
+///   use them fail without an error of their own.
+#[derive(Debug, Default, PartialEq)]
 pub struct InterpreterState {
     pub id: String,
     pub bag: indexmap::IndexMap<String, ftd::interpreter::Thing>,
@@ -42,8 +50,27 @@ pub struct InterpreterState {
     pub parsed_libs: ftd::Map<ParsedDocument>,
     pub instructions: Vec<fastn_resolved::ComponentInvocation>,
     pub in_process: Vec<(String, usize, ftd_ast::Ast)>,
+    pub errors: Errors,
+    pub warnings: Vec<Warning>,
+    pub failed: std::collections::HashSet<String>,
+}
+
+/// Something in a document that is likely a mistake, but does not stop it
+/// from rendering.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Warning {
+    pub doc_id: String,
+    pub line_number: usize,
+    /// the code of the warning, explained by `fastn explain <code>`
+    pub code: String,
+    pub message: String,
 }
 
+/// The errors of the things the interpreter could not process. It carries on
+/// with the rest of the document and returns all of them at the end.
+#[derive(Debug, Default, PartialEq)]
+pub struct Errors(pub Vec<ftd::interpreter::Error>);
+
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct PendingImports {
     pub stack: Vec<PendingImportItem>,
@@ -139,14 +166,14 @@ impl InterpreterState {
         doc_name: &'a str,
         line_number: usize,
     ) -> ftd::interpreter::Result<ftd::interpreter::TDoc<'a>> {
-        let parsed_document =
-            self.parsed_libs
-                .get(doc_name)
-                .ok_or(ftd::interpreter::Error::ParseError {
-                    message: format!("Cannot find this document: `{}`", doc_name),
-                    doc_id: doc_name.to_string(),
-                    line_number,
-                })?;
+        let parsed_document = self.parsed_libs.get(doc_name).ok_or(
+            ftd::interpreter::Error::ParseError {
+                message: format!("Cannot find this document: `{}`", doc_name),
+                doc_id: doc_name.to_string(),
+                line_number,
+            }
+            .with_code(ftd_p1::codes::NAME_NOT_FOUND),
+        )?;
         Ok(ftd::interpreter::TDoc::new(
             &parsed_document.name,
             &parsed_document.doc_aliases,
@@ -261,10 +288,10 @@ impl InterpreterState {
         }
     }
 
-    /// Removes the processed AST, i.e. which are in bag, from the `in_process` field
+    /// Removes the processed AST, i.e. which are in bag or failed, from the `in_process` field
     fn remove_already_processed(&mut self) {
         while let Some((name, _, _)) = self.in_process.last() {
-            if self.bag.contains_key(name) {
+            if self.bag.contains_key(name) || self.failed.contains(name) {
                 self.in_process.pop();
             } else {
                 break;
@@ -273,290 +300,384 @@ impl InterpreterState {
     }
 
     pub fn continue_processing(mut self) -> ftd::interpreter::Result<Interpreter> {
+        while let Some((doc_name, number_of_scan, ast, exports)) = self.get_next_ast() {
+            if self.skip_failed() {
+                continue;
+            }
+            let stacks = (
+                self.to_process.stack.len(),
+                self.pending_imports.stack.len(),
+            );
+            // the errors about the ast itself point at its name
+            let (doc_id, line_number, span) = (doc_name.clone(), ast.line_number(), ast.span());
+            match self.process_ast(doc_name, number_of_scan, ast, exports) {
+                Ok(Some(s)) => return Ok(s.into_interpreter(self)),
+                Ok(None) => {}
+                Err(e) => self.fail(e.or_span(doc_id.as_str(), line_number, span), stacks),
+            }
+        }
+
+        if self.to_process.stack.is_empty() {
+            if let Some(e) = ftd_p1::Reportable::from_errors(std::mem::take(&mut self.errors.0)) {
+                return Err(e);
+            }
+            let document = Document {
+                data: self.bag,
+                aliases: self
+                    .parsed_libs
+                    .get(self.id.as_str())
+                    .unwrap()
+                    .doc_aliases
+                    .clone(),
+                tree: self.instructions,
+                name: self.id,
+                js: self.js,
+                css: self.css,
+                warnings: self.warnings,
+            };
+
+            Ok(Interpreter::Done { document })
+        } else {
+            self.continue_processing()
+        }
+    }
+
+    /// Processes the first ast on the stack. Returns the state when the
+    /// interpreter is stuck on something only the host can give.
+    fn process_ast(
+        &mut self,
+        doc_name: String,
+        number_of_scan: usize,
+        ast: ftd_ast::Ast,
+        exports: Vec<String>,
+    ) -> ftd::interpreter::Result<Option<InterpreterWithoutState>> {
         use ftd::interpreter::{PropertyValueExt, ValueExt};
 
-        while let Some((doc_name, number_of_scan, ast, exports)) = self.get_next_ast() {
-            if let Some(interpreter) = self.resolve_pending_imports::<ftd::interpreter::Thing>()? {
-                match interpreter {
-                    ftd::interpreter::StateWithThing::State(s) => {
-                        return Ok(s.into_interpreter(self))
-                    }
-                    ftd::interpreter::StateWithThing::Thing(t) => {
-                        self.bag.insert(t.name(), t);
-                    }
-                    ftd::interpreter::StateWithThing::Continue => continue,
+        let pending_import = match self.resolve_pending_imports::<ftd::interpreter::Thing>() {
+            Ok(pending_import) => pending_import,
+            Err(e) => {
+                self.remove_pending_import();
+                return Err(e);
+            }
+        };
+        if let Some(interpreter) = pending_import {
+            match interpreter {
+                ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                ftd::interpreter::StateWithThing::Thing(t) => {
+                    self.bag.insert(t.name(), t);
                 }
+                ftd::interpreter::StateWithThing::Continue => return Ok(None),
             }
+        }
 
-            self.increase_scan_count();
-            let parsed_document = self.parsed_libs.get(doc_name.as_str()).unwrap();
-            let name = parsed_document.name.to_string();
-            let aliases = parsed_document.doc_aliases.clone();
+        self.increase_scan_count();
+        let parsed_document = self.parsed_libs.get(doc_name.as_str()).unwrap();
+        let name = parsed_document.name.to_string();
+        let aliases = parsed_document.doc_aliases.clone();
 
-            let ast_full_name = ftd::interpreter::utils::resolve_name(
-                ast.name().as_str(),
-                &parsed_document.name,
-                &parsed_document.doc_aliases,
-            );
+        let ast_full_name = ftd::interpreter::utils::resolve_name(
+            ast.name().as_str(),
+            &parsed_document.name,
+            &parsed_document.doc_aliases,
+        );
 
-            self.detect_cycle(ast_full_name.as_str(), number_of_scan, &ast)?;
-            let is_in_bag = self.bag.contains_key(&ast_full_name);
+        self.detect_cycle(ast_full_name.as_str(), number_of_scan, &ast)?;
+        let is_in_bag = self.bag.contains_key(&ast_full_name);
 
-            if is_in_bag {
-                let line_number = self.bag.get(&ast_full_name).unwrap().line_number();
-                ftd::interpreter::utils::insert_export_thing(
-                    exports.as_slice(),
-                    ast_full_name.as_str(),
-                    &mut self.bag,
-                    doc_name.as_str(),
-                    line_number,
-                );
-            }
-            let state = &mut self;
-
-            let mut doc = ftd::interpreter::TDoc::new_state(&name, &aliases, state);
-            if ast.is_record() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        fastn_resolved::Record::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match fastn_resolved::Record::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(record) => {
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    record.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    record.line_number,
-                                );
-                                self.bag.insert(
-                                    record.name.to_string(),
-                                    ftd::interpreter::Thing::Record(record),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+        if is_in_bag {
+            let line_number = self.bag.get(&ast_full_name).unwrap().line_number();
+            ftd::interpreter::utils::insert_export_thing(
+                exports.as_slice(),
+                ast_full_name.as_str(),
+                &mut self.bag,
+                doc_name.as_str(),
+                line_number,
+            );
+        }
+        let mut doc = ftd::interpreter::TDoc::new_state(&name, &aliases, self);
+        if ast.is_record() {
+            if !is_in_bag {
+                if number_of_scan.eq(&1) {
+                    fastn_resolved::Record::scan_ast(ast, &mut doc)?;
+                    return Ok(None);
+                } else {
+                    match fastn_resolved::Record::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                        ftd::interpreter::StateWithThing::Thing(record) => {
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                record.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                record.line_number,
+                            );
+                            self.bag.insert(
+                                record.name.to_string(),
+                                ftd::interpreter::Thing::Record(record),
+                            );
                         }
+                        ftd::interpreter::StateWithThing::Continue => return Ok(None),
                     }
                 }
-            } else if ast.is_or_type() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        fastn_resolved::OrType::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match fastn_resolved::OrType::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(or_type) => {
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    or_type.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    or_type.line_number,
-                                );
-                                self.bag.insert(
-                                    or_type.name.to_string(),
-                                    ftd::interpreter::Thing::OrType(or_type),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+            }
+        } else if ast.is_or_type() {
+            if !is_in_bag {
+                if number_of_scan.eq(&1) {
+                    fastn_resolved::OrType::scan_ast(ast, &mut doc)?;
+                    return Ok(None);
+                } else {
+                    match fastn_resolved::OrType::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                        ftd::interpreter::StateWithThing::Thing(or_type) => {
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                or_type.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                or_type.line_number,
+                            );
+                            self.bag.insert(
+                                or_type.name.to_string(),
+                                ftd::interpreter::Thing::OrType(or_type),
+                            );
                         }
+                        ftd::interpreter::StateWithThing::Continue => return Ok(None),
                     }
                 }
-            } else if ast.is_function() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        fastn_resolved::Function::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match fastn_resolved::Function::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(function) => {
-                                if let Some(ref js) = function.js {
-                                    let js = js
-                                        .to_owned()
-                                        .resolve(&doc, function.line_number)?
-                                        .string_list(&doc, function.line_number)?;
-
-                                    for js in js.iter() {
-                                        self.js.insert(js.to_string());
-                                    }
+            }
+        } else if ast.is_function() {
+            if !is_in_bag {
+                if number_of_scan.eq(&1) {
+                    fastn_resolved::Function::scan_ast(ast, &mut doc)?;
+                    return Ok(None);
+                } else {
+                    match fastn_resolved::Function::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                        ftd::interpreter::StateWithThing::Thing(function) => {
+                            if let Some(ref js) = function.js {
+                                let js = js
+                                    .to_owned()
+                                    .resolve(&doc, function.line_number)?
+                                    .string_list(&doc, function.line_number)?;
+
+                                for js in js.iter() {
+                                    self.js.insert(js.to_string());
                                 }
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    function.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    function.line_number,
-                                );
-                                self.bag.insert(
-                                    function.name.to_string(),
-                                    ftd::interpreter::Thing::Function(function),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
-                        }
-                    }
-                }
-            } else if ast.is_variable_definition() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        fastn_resolved::Variable::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match fastn_resolved::Variable::from_ast(ast, &mut doc, number_of_scan)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
                             }
-                            ftd::interpreter::StateWithThing::Thing(variable) => {
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    variable.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    variable.line_number,
-                                );
-                                self.bag.insert(
-                                    variable.name.to_string(),
-                                    ftd::interpreter::Thing::Variable(variable),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                function.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                function.line_number,
+                            );
+                            self.bag.insert(
+                                function.name.to_string(),
+                                ftd::interpreter::Thing::Function(function),
+                            );
                         }
+                        ftd::interpreter::StateWithThing::Continue => return Ok(None),
                     }
                 }
-            } else if ast.is_variable_invocation() {
+            }
+        } else if ast.is_variable_definition() {
+            if !is_in_bag {
                 if number_of_scan.eq(&1) {
-                    fastn_resolved::Variable::scan_update_from_ast(ast, &mut doc)?;
-                    continue;
+                    fastn_resolved::Variable::scan_ast(ast, &mut doc)?;
+                    return Ok(None);
                 } else {
-                    match fastn_resolved::Variable::update_from_ast(ast, &mut doc)? {
-                        ftd::interpreter::StateWithThing::State(s) => {
-                            return Ok(s.into_interpreter(self))
-                        }
+                    match fastn_resolved::Variable::from_ast(ast, &mut doc, number_of_scan)? {
+                        ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
                         ftd::interpreter::StateWithThing::Thing(variable) => {
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                variable.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                variable.line_number,
+                            );
                             self.bag.insert(
                                 variable.name.to_string(),
                                 ftd::interpreter::Thing::Variable(variable),
                             );
                         }
-                        ftd::interpreter::StateWithThing::Continue => continue,
+                        ftd::interpreter::StateWithThing::Continue => return Ok(None),
                     }
                 }
-            } else if ast.is_component_definition() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        fastn_resolved::ComponentDefinition::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match fastn_resolved::ComponentDefinition::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(component) => {
-                                if let Some(ref css) = component.css {
-                                    let css = css
-                                        .to_owned()
-                                        .resolve(&doc, component.line_number)?
-                                        .string(doc.name, component.line_number)?;
-                                    self.css.insert(css);
-                                }
-
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    component.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    component.line_number,
-                                );
-
-                                self.bag.insert(
-                                    component.name.to_string(),
-                                    ftd::interpreter::Thing::Component(component),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
-                        }
+            }
+        } else if ast.is_variable_invocation() {
+            if number_of_scan.eq(&1) {
+                fastn_resolved::Variable::scan_update_from_ast(ast, &mut doc)?;
+                return Ok(None);
+            } else {
+                match fastn_resolved::Variable::update_from_ast(ast, &mut doc)? {
+                    ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                    ftd::interpreter::StateWithThing::Thing(variable) => {
+                        self.bag.insert(
+                            variable.name.to_string(),
+                            ftd::interpreter::Thing::Variable(variable),
+                        );
                     }
+                    ftd::interpreter::StateWithThing::Continue => return Ok(None),
                 }
-            } else if ast.is_web_component_definition() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        fastn_resolved::WebComponentDefinition::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match fastn_resolved::WebComponentDefinition::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(web_component) => {
-                                let js = web_component
-                                    .js
+            }
+        } else if ast.is_component_definition() {
+            if !is_in_bag {
+                if number_of_scan.eq(&1) {
+                    fastn_resolved::ComponentDefinition::scan_ast(ast, &mut doc)?;
+                    return Ok(None);
+                } else {
+                    match fastn_resolved::ComponentDefinition::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                        ftd::interpreter::StateWithThing::Thing(component) => {
+                            if let Some(ref css) = component.css {
+                                let css = css
                                     .to_owned()
-                                    .resolve(&doc, web_component.line_number)?
-                                    .string(doc.name, web_component.line_number)?;
-                                self.js.insert(format!("{}:type=\"module\"", js));
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    web_component.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    web_component.line_number,
-                                );
-                                self.bag.insert(
-                                    web_component.name.to_string(),
-                                    ftd::interpreter::Thing::WebComponent(web_component),
-                                );
+                                    .resolve(&doc, component.line_number)?
+                                    .string(doc.name, component.line_number)?;
+                                self.css.insert(css);
                             }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                component.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                component.line_number,
+                            );
+
+                            self.bag.insert(
+                                component.name.to_string(),
+                                ftd::interpreter::Thing::Component(component),
+                            );
                         }
+                        ftd::interpreter::StateWithThing::Continue => return Ok(None),
                     }
                 }
-            } else if ast.is_component() {
+            }
+        } else if ast.is_web_component_definition() {
+            if !is_in_bag {
                 if number_of_scan.eq(&1) {
-                    fastn_resolved::ComponentInvocation::scan_ast(ast, &mut doc)?;
-                    continue;
+                    fastn_resolved::WebComponentDefinition::scan_ast(ast, &mut doc)?;
+                    return Ok(None);
                 } else {
-                    match fastn_resolved::ComponentInvocation::from_ast(ast, &mut doc)? {
-                        ftd::interpreter::StateWithThing::State(s) => {
-                            return Ok(s.into_interpreter(self))
-                        }
-                        ftd::interpreter::StateWithThing::Thing(component) => {
-                            self.instructions.push(component);
+                    match fastn_resolved::WebComponentDefinition::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                        ftd::interpreter::StateWithThing::Thing(web_component) => {
+                            let js = web_component
+                                .js
+                                .to_owned()
+                                .resolve(&doc, web_component.line_number)?
+                                .string(doc.name, web_component.line_number)?;
+                            self.js.insert(format!("{}:type=\"module\"", js));
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                web_component.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                web_component.line_number,
+                            );
+                            self.bag.insert(
+                                web_component.name.to_string(),
+                                ftd::interpreter::Thing::WebComponent(web_component),
+                            );
                         }
-                        ftd::interpreter::StateWithThing::Continue => continue,
+                        ftd::interpreter::StateWithThing::Continue => return Ok(None),
+                    }
+                }
+            }
+        } else if ast.is_component() {
+            if number_of_scan.eq(&1) {
+                fastn_resolved::ComponentInvocation::scan_ast(ast, &mut doc)?;
+                return Ok(None);
+            } else {
+                match fastn_resolved::ComponentInvocation::from_ast(ast, &mut doc)? {
+                    ftd::interpreter::StateWithThing::State(s) => return Ok(Some(s)),
+                    ftd::interpreter::StateWithThing::Thing(component) => {
+                        self.instructions.push(component);
                     }
+                    ftd::interpreter::StateWithThing::Continue => return Ok(None),
                 }
             }
+        }
+        self.remove_last();
+        Ok(None)
+    }
+
+    /// Records `error` of the first ast on the stack and takes the ast off,
+    /// along with the things added to the stacks while processing it, so the
+    /// rest of the document is checked too. `stacks` are the lengths of the
+    /// stacks before it was processed.
+    fn fail(
+        &mut self,
+        error: ftd::interpreter::Error,
+        (to_process, pending_imports): (usize, usize),
+    ) {
+        while self.to_process.stack.len() > to_process {
             self.remove_last();
         }
+        while self.pending_imports.stack.len() > pending_imports {
+            self.remove_pending_import();
+        }
+        self.fail_first();
+        self.errors.0.push(error);
+    }
 
-        if self.to_process.stack.is_empty() {
-            let document = Document {
-                data: self.bag,
-                aliases: self
-                    .parsed_libs
-                    .get(self.id.as_str())
-                    .unwrap()
-                    .doc_aliases
-                    .clone(),
-                tree: self.instructions,
-                name: self.id,
-                js: self.js,
-                css: self.css,
-            };
+    /// Takes the first ast off the stack, a definition is remembered as failed
+    /// so the things using it fail too.
+    fn fail_first(&mut self) {
+        if let Some((full_name, is_definition)) = self.first_full_name() {
+            if is_definition {
+                self.failed.insert(full_name);
+            }
+            self.remove_last();
+        }
+    }
 
-            Ok(Interpreter::Done { document })
-        } else {
-            self.continue_processing()
+    /// A definition that failed is on the stack again, for the thing under it
+    /// that uses it. The thing under it fails too, without another error.
+    fn skip_failed(&mut self) -> bool {
+        match self.first_full_name() {
+            Some((full_name, true)) if self.failed.contains(&full_name) => {}
+            _ => return false,
+        }
+        let len = self.to_process.stack.len();
+        if len == 1 {
+            self.remove_last();
+            return true;
+        }
+        while self.to_process.stack.len() == len {
+            self.remove_last();
+        }
+        self.fail_first();
+        true
+    }
+
+    /// The full name of the first ast on the stack, and if it is a definition.
+    fn first_full_name(&self) -> Option<(String, bool)> {
+        let (doc_name, _, ast) = self.peek_stack()?;
+        let parsed_document = self.parsed_libs.get(doc_name.as_str())?;
+        Some((
+            ftd::interpreter::utils::resolve_name(
+                ast.name().as_str(),
+                &parsed_document.name,
+                &parsed_document.doc_aliases,
+            ),
+            ast.get_definition_name().is_some(),
+        ))
+    }
+
+    fn remove_pending_import(&mut self) {
+        if let Some(item) = self.pending_imports.stack.pop() {
+            let (doc_name, thing_name, _remaining) =
+                ftd::interpreter::utils::get_doc_name_and_thing_name_and_remaining(
+                    item.thing_name.as_str(),
+                    item.module.as_str(),
+                    item.line_number,
+                );
+            self.pending_imports
+                .contains
+                .remove(&(doc_name.to_string(), format!("{}#{}", doc_name, thing_name)));
         }
     }
 
@@ -861,10 +982,11 @@ impl InterpreterState {
                     exports.as_slice(),
                 );
             } else if !found_foreign_variable {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     format!("`{}` not found", name),
                     name,
                     line_number,
+                    ftd_p1::codes::NAME_NOT_FOUND,
                 );
             }
         }
@@ -896,13 +1018,37 @@ impl InterpreterState {
         value: fastn_resolved::Value,
         ast: ftd_ast::Ast,
     ) -> ftd::interpreter::Result<Interpreter> {
+        match self.add_processor_value(value, ast) {
+            Ok(Some(s)) => Ok(s.into_interpreter(self)),
+            Ok(None) => self.continue_processing(),
+            Err(e) => self.continue_after_error(e),
+        }
+    }
+
+    /// Carries on after the host could not give what the interpreter is stuck
+    /// on, like the value of a processor. The thing waiting for it fails with
+    /// `error`.
+    pub fn continue_after_error(
+        mut self,
+        error: ftd::interpreter::Error,
+    ) -> ftd::interpreter::Result<Interpreter> {
+        self.fail_first();
+        self.errors.0.push(error);
+        self.continue_processing()
+    }
+
+    fn add_processor_value(
+        &mut self,
+        value: fastn_resolved::Value,
+        ast: ftd_ast::Ast,
+    ) -> ftd::interpreter::Result<Option<InterpreterWithoutState>> {
         use ftd::interpreter::KindDataExt;
 
         let (id, _ast_to_process) = self.to_process.stack.last().unwrap(); //TODO: remove unwrap & throw error
         let parsed_document = self.parsed_libs.get(id).unwrap();
         let name = parsed_document.name.to_string();
         let aliases = parsed_document.doc_aliases.clone();
-        let mut doc = ftd::interpreter::TDoc::new_state(&name, &aliases, &mut self);
+        let mut doc = ftd::interpreter::TDoc::new_state(&name, &aliases, self);
         let variable_definition = ast.get_variable_definition(doc.name)?;
         let name = doc.resolve_name(variable_definition.name.as_str());
         let kind = match fastn_resolved::KindData::from_ast_kind(
@@ -912,8 +1058,8 @@ impl InterpreterState {
             variable_definition.line_number,
         )? {
             StateWithThing::Thing(t) => t,
-            StateWithThing::State(s) => return Ok(s.into_interpreter(self)),
-            StateWithThing::Continue => return self.continue_processing(),
+            StateWithThing::State(s) => return Ok(Some(s)),
+            StateWithThing::Continue => return Ok(None),
         };
 
         let value =
@@ -927,6 +1073,7 @@ impl InterpreterState {
             conditional_value: vec![],
             line_number: variable_definition.line_number,
             is_static: true,
+            sse: variable_definition.flags.sse,
         }
         .set_static(&doc);
         ftd::interpreter::utils::validate_variable(&variable, &doc)?;
@@ -935,7 +1082,7 @@ impl InterpreterState {
             ftd::interpreter::Thing::Variable(variable),
         );
         self.remove_last();
-        self.continue_processing()
+        Ok(None)
     }
 
     #[tracing::instrument(skip_all)]
@@ -958,6 +1105,7 @@ impl InterpreterState {
             conditional_value: vec![],
             line_number: 0,
             is_static: true,
+            sse: None,
         }
         .set_static(&doc);
         ftd::interpreter::utils::validate_variable(&variable, &doc)?;
@@ -1036,10 +1184,22 @@ impl ParsedDocument {
         source: &str,
         line_number: usize,
     ) -> ftd::interpreter::Result<ParsedDocument> {
-        let ast = ftd_ast::Ast::from_sections(
-            ftd_p1::parse_with_line_number(source, id, line_number)?.as_slice(),
-            id,
-        )?;
+        // the errors of all the sections are returned, not just the first
+        let (sections, p1_errors) = ftd_p1::parse_with_errors(source, id, line_number);
+        let (ast, ast_errors) = ftd_ast::Ast::from_sections_with_errors(sections.as_slice(), id);
+        if let Some(e) = ftd_p1::Reportable::from_errors(
+            p1_errors
+                .into_iter()
+                .map(ftd::interpreter::Error::P1Error)
+                .chain(
+                    ast_errors
+                        .into_iter()
+                        .map(ftd::interpreter::Error::ASTError),
+                )
+                .collect(),
+        ) {
+            return Err(e);
+        }
         let (doc_aliases, re_exports, exposings) = {
             let mut doc_aliases = ftd::interpreter::default::default_aliases();
             let mut re_exports = ReExport {
@@ -1216,6 +1376,9 @@ pub struct Document {
     pub aliases: ftd::Map<String>,
     pub js: std::collections::HashSet<String>,
     pub css: std::collections::HashSet<String>,
+    /// not part of what the document renders to
+    #[serde(skip)]
+    pub warnings: Vec<Warning>,
 }
 
 impl Document {
@@ -1271,10 +1434,11 @@ impl Document {
                 Some(fastn_resolved::Value::KwArgs { arguments }) => arguments,
                 Some(_) => unreachable!("compiler must have verified this"),
                 None => {
-                    return ftd::interpreter::utils::e2(
+                    return ftd::interpreter::utils::e2_with_code(
                         "url not found in redirect",
                         self.name.as_str(),
                         0,
+                        ftd_p1::codes::MISSING_VALUE,
                     )
                 }
             };
@@ -1304,9 +1468,12 @@ impl Document {
 
             return match v.get_interpreter_value_of_argument("url", &tdoc)? {
                 Some(v) => Ok(Some(v.string(self.name.as_str(), 0)?)),
-                None => {
-                    ftd::interpreter::utils::e2("url not found in redirect", self.name.as_str(), 0)
-                }
+                None => ftd::interpreter::utils::e2_with_code(
+                    "url not found in redirect",
+                    self.name.as_str(),
+                    0,
+                    ftd_p1::codes::MISSING_VALUE,
+                ),
             };
         }
 
@@ -1385,6 +1552,12 @@ impl Document {
             fastn_resolved::Value::String { text, .. } => {
                 serde_json::Value::String(text.to_owned())
             }
+            fastn_resolved::Value::DateTime { value } => serde_json::Value::String(
+                fastn_builtins::datetime::to_iso_datetime(*value).unwrap_or_default(),
+            ),
+            fastn_resolved::Value::Date { value } => serde_json::Value::String(
+                fastn_builtins::datetime::to_iso_date(*value).unwrap_or_default(),
+            ),
             fastn_resolved::Value::Record { fields, .. } => self.object_to_json(fields)?,
             fastn_resolved::Value::OrType { variant, value, .. } => {
                 let mut map = serde_json::Map::new();
@@ -127,6 +127,327 @@ pub enum Error {
 
     #[error("Found Cycle: {message}, line_number: {line_number}")]
     FoundCycle { message: String, line_number: usize },
+
+    #[error("{}", .errors.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("\n"))]
+    Multiple { errors: Vec<Error> },
+
+    #[error("{error}")]
+    Labelled {
+        error: Box<Error>,
+        /// what the error is about, in the document of the error
+        span: Option<ftd_p1::Span>,
+        /// the other lines the error is about
+        labels: Vec<ftd_p1::Label>,
+        help: Option<String>,
+        /// the text to put in place of the span to fix the error
+        suggestion: Option<String>,
+        code: Option<&'static str>,
+    },
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// The error, pointing at `span` in its document. An empty `span`, the one
+    /// of a node that was not parsed from a source, is left out.
+    pub fn with_span(self, span: ftd_p1::Span) -> Error {
+        if span.is_empty() {
+            return self;
+        }
+        let mut error = self.into_labelled();
+        if let Error::Labelled { span: s, .. } = &mut error {
+            *s = Some(span);
+        }
+        error
+    }
+
+    /// The error, pointing at `span` when it is about the line `line_number`
+    /// of `doc_id` and does not point at anything yet.
+    pub(crate) fn or_span(self, doc_id: &str, line_number: usize, span: ftd_p1::Span) -> Error {
+        use ftd_p1::Reportable;
+
+        match self {
+            Error::Multiple { errors } => Error::Multiple {
+                errors: errors
+                    .into_iter()
+                    .map(|e| e.or_span(doc_id, line_number, span))
+                    .collect(),
+            },
+            e if e.span().is_some()
+                || e.doc_id() != Some(doc_id)
+                || e.line_number() != Some(line_number) =>
+            {
+                e
+            }
+            e => e.with_span(span),
+        }
+    }
+
+    /// The error, pointing at another line it is about too.
+    pub fn with_label(self, label: ftd_p1::Label) -> Error {
+        let mut error = self.into_labelled();
+        if let Error::Labelled { labels, .. } = &mut error {
+            labels.push(label);
+        }
+        error
+    }
+
+    /// The error, with a hint to fix it.
+    pub fn with_help<S: Into<String>>(self, help: S) -> Error {
+        let mut error = self.into_labelled();
+        if let Error::Labelled { help: h, .. } = &mut error {
+            *h = Some(help.into());
+        }
+        error
+    }
+
+    /// The error, with the text to put in place of its span to fix it.
+    pub fn with_suggestion<S: Into<String>>(self, suggestion: S) -> Error {
+        let mut error = self.into_labelled();
+        if let Error::Labelled { suggestion: s, .. } = &mut error {
+            *s = Some(suggestion.into());
+        }
+        error
+    }
+
+    /// The error, with the code `code`, for the errors that are not told
+    /// apart by their variant.
+    pub fn with_code(self, code: &'static str) -> Error {
+        let mut error = self.into_labelled();
+        if let Error::Labelled { code: c, .. } = &mut error {
+            *c = Some(code);
+        }
+        error
+    }
+
+    fn into_labelled(self) -> Error {
+        match self {
+            e @ Error::Labelled { .. } => e,
+            e => Error::Labelled {
+                error: Box::new(e),
+                span: None,
+                labels: vec![],
+                help: None,
+                suggestion: None,
+                code: None,
+            },
+        }
+    }
+}
+
+/// The errors of the other crates that can not be compared, `std::io::Error`,
+/// `serde_json::Error` and the ftd 0.2021 parser errors, are compared by what
+/// they say.
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Error::OtherError(a), Error::OtherError(b)) => a == b,
+            (Error::P1Error(a), Error::P1Error(b)) => a == b,
+            (Error::IOError(a), Error::IOError(b)) => {
+                a.kind() == b.kind() && a.to_string() == b.to_string()
+            }
+            (Error::OldP1Error(a), Error::OldP1Error(b)) => a.to_string() == b.to_string(),
+            (Error::ASTError(a), Error::ASTError(b)) => a == b,
+            (
+                Error::InvalidKind {
+                    doc_id,
+                    line_number,
+                    message,
+                },
+                Error::InvalidKind {
+                    doc_id: d,
+                    line_number: l,
+                    message: m,
+                },
+            )
+            | (
+                Error::ValueNotFound {
+                    doc_id,
+                    line_number,
+                    message,
+                },
+                Error::ValueNotFound {
+                    doc_id: d,
+                    line_number: l,
+                    message: m,
+                },
+            )
+            | (
+                Error::ParseError {
+                    doc_id,
+                    line_number,
+                    message,
+                },
+                Error::ParseError {
+                    doc_id: d,
+                    line_number: l,
+                    message: m,
+                },
+            ) => doc_id == d && line_number == l && message == m,
+            (Error::ParseIntError(a), Error::ParseIntError(b)) => a == b,
+            (Error::ParseFloatError(a), Error::ParseFloatError(b)) => a == b,
+            (Error::ParseBoolError(a), Error::ParseBoolError(b)) => a == b,
+            (
+                Error::InterpreterIOError { io_error, path },
+                Error::InterpreterIOError {
+                    io_error: e,
+                    path: p,
+                },
+            ) => path == p && io_error.kind() == e.kind() && io_error.to_string() == e.to_string(),
+            (Error::EvalexprError(a), Error::EvalexprError(b)) => a == b,
+            (Error::Serde { source: a }, Error::Serde { source: b }) => {
+                a.to_string() == b.to_string()
+            }
+            (
+                Error::InvalidAccessError {
+                    message,
+                    line_number,
+                },
+                Error::InvalidAccessError {
+                    message: m,
+                    line_number: l,
+                },
+            )
+            | (
+                Error::FoundCycle {
+                    message,
+                    line_number,
+                },
+                Error::FoundCycle {
+                    message: m,
+                    line_number: l,
+                },
+            ) => message == m && line_number == l,
+            (Error::DSHttpError { message }, Error::DSHttpError { message: m }) => message == m,
+            (Error::Multiple { errors }, Error::Multiple { errors: e }) => errors == e,
+            (
+                Error::Labelled {
+                    error,
+                    span,
+                    labels,
+                    help,
+                    suggestion,
+                    code,
+                },
+                Error::Labelled {
+                    error: e,
+                    span: p,
+                    labels: l,
+                    help: h,
+                    suggestion: s,
+                    code: c,
+                },
+            ) => {
+                error == e && span == p && labels == l && help == h && suggestion == s && code == c
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ftd_p1::Reportable for Error {
+    fn multiple(errors: Vec<Error>) -> Error {
+        Error::Multiple { errors }
+    }
+
+    fn into_errors(self) -> Vec<Error> {
+        match self {
+            Error::Multiple { errors } => errors.into_iter().flat_map(Error::into_errors).collect(),
+            Error::P1Error(e) => e.into_errors().into_iter().map(Error::P1Error).collect(),
+            Error::ASTError(e) => e.into_errors().into_iter().map(Error::ASTError).collect(),
+            e => vec![e],
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Error::Labelled {
+                code: Some(code), ..
+            } => code,
+            Error::Labelled { error, .. } => error.code(),
+            Error::P1Error(e) => e.code(),
+            Error::ASTError(e) => e.code(),
+            Error::ValueNotFound { .. } => ftd_p1::codes::NAME_NOT_FOUND,
+            Error::InvalidKind { .. } => ftd_p1::codes::INVALID_KIND,
+            Error::FoundCycle { .. } => ftd_p1::codes::CYCLE,
+            Error::InvalidAccessError { .. } => ftd_p1::codes::INVALID_ACCESS,
+            Error::Multiple { errors } => errors
+                .first()
+                .map_or(ftd_p1::codes::INVALID_DOCUMENT, Error::code),
+            _ => ftd_p1::codes::INVALID_DOCUMENT,
+        }
+    }
+
+    fn span(&self) -> Option<ftd_p1::Span> {
+        match self {
+            Error::P1Error(e) => e.span(),
+            Error::ASTError(e) => e.span(),
+            Error::Labelled { error, span, .. } => span.or_else(|| error.span()),
+            Error::Multiple { errors } => errors.first().and_then(Error::span),
+            _ => None,
+        }
+    }
+
+    fn labels(&self) -> &[ftd_p1::Label] {
+        match self {
+            Error::Labelled { labels, .. } => labels,
+            _ => &[],
+        }
+    }
+
+    fn help(&self) -> Option<&str> {
+        match self {
+            Error::Labelled { help, .. } => help.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn suggestion(&self) -> Option<&str> {
+        match self {
+            Error::Labelled { suggestion, .. } => suggestion.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn doc_id(&self) -> Option<&str> {
+        match self {
+            Error::Labelled { error, .. } => error.doc_id(),
+            Error::P1Error(e) => e.doc_id(),
+            Error::ASTError(e) => e.doc_id(),
+            Error::InvalidKind { doc_id, .. }
+            | Error::ValueNotFound { doc_id, .. }
+            | Error::ParseError { doc_id, .. } => Some(doc_id),
+            Error::Multiple { errors } => errors.first().and_then(Error::doc_id),
+            _ => None,
+        }
+    }
+
+    fn line_number(&self) -> Option<usize> {
+        match self {
+            Error::Labelled { error, .. } => error.line_number(),
+            Error::P1Error(e) => e.line_number(),
+            Error::ASTError(e) => e.line_number(),
+            Error::InvalidKind { line_number, .. }
+            | Error::ValueNotFound { line_number, .. }
+            | Error::ParseError { line_number, .. }
+            | Error::InvalidAccessError { line_number, .. }
+            | Error::FoundCycle { line_number, .. } => Some(*line_number),
+            Error::Multiple { errors } => errors.first().and_then(Error::line_number),
+            _ => None,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            Error::Labelled { error, .. } => error.message(),
+            Error::P1Error(e) => e.message(),
+            Error::ASTError(e) => e.message(),
+            Error::InvalidKind { message, .. }
+            | Error::ValueNotFound { message, .. }
+            | Error::ParseError { message, .. }
+            | Error::InvalidAccessError { message, .. } => message.to_string(),
+            Error::FoundCycle { message, .. } => format!("Found Cycle: {message}"),
+            _ => self.to_string(),
+        }
+    }
+}
@@ -1,8 +1,8 @@
 pub use fastn_builtins::constants::*;
 pub use ftd::interpreter::main::{
-    interpret, interpret_with_line_number, Document, Interpreter, InterpreterState,
+    interpret, interpret_with_line_number, Document, Errors, Interpreter, InterpreterState,
     InterpreterWithoutState, ParsedDocument, PendingImportItem, StateWithThing, ToProcess,
-    ToProcessItem,
+    ToProcessItem, Warning,
 };
 
 pub use fastn_builtins as default;
@@ -50,6 +50,22 @@ impl<'a> TDoc<'a> {
         }
     }
 
+    /// Records a warning about the line `line_number` of the document, once,
+    /// as the things of the document can be processed more than once.
+    pub fn warn(&mut self, line_number: usize, code: &str, message: String) {
+        let warning = ftd::interpreter::Warning {
+            doc_id: self.name.to_string(),
+            line_number,
+            code: code.to_string(),
+            message,
+        };
+        if let BagOrState::State(state) = &mut self.bag {
+            if !state.warnings.contains(&warning) {
+                state.warnings.push(warning);
+            }
+        }
+    }
+
     pub fn resolve_module_name(&self, name: &str) -> String {
         ftd::interpreter::utils::resolve_module_name(name, self.name, self.aliases)
     }
@@ -65,6 +81,32 @@ impl<'a> TDoc<'a> {
         }
     }
 
+    /// The evalexpr context for the expressions of the document. `fastn` sets
+    /// `ftd#language` to the language of the request, `ftd.format_datetime`
+    /// and `ftd.relative_time` use it when they are not passed a language.
+    pub fn evalexpr_context(
+        &self,
+    ) -> ftd::interpreter::Result<fastn_resolved::evalexpr::HashMapContext> {
+        let bag = match &self.bag {
+            BagOrState::Bag(b) => b,
+            BagOrState::State(s) => &s.bag,
+        };
+        let language = match bag.get("ftd#language") {
+            Some(ftd::interpreter::Thing::Variable(fastn_resolved::Variable {
+                value:
+                    fastn_resolved::PropertyValue::Value {
+                        value: fastn_resolved::Value::String { text },
+                        ..
+                    },
+                ..
+            })) => text.as_str(),
+            _ => "en",
+        };
+        Ok(ftd::interpreter::default::default_context_with_language(
+            language,
+        )?)
+    }
+
     pub fn get_record(
         &'a self,
         name: &'a str,
@@ -72,12 +114,14 @@ impl<'a> TDoc<'a> {
     ) -> ftd::interpreter::Result<fastn_resolved::Record> {
         match self.get_thing(name, line_number)? {
             ftd::interpreter::Thing::Record(r) => Ok(r),
-            t => self.err(
-                format!("Expected Record, found: `{:?}`", t).as_str(),
-                name,
-                "get_record",
-                line_number,
-            ),
+            t => self
+                .err(
+                    format!("Expected Record, found: `{:?}`", t).as_str(),
+                    name,
+                    "get_record",
+                    line_number,
+                )
+                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND)),
         }
     }
 
@@ -88,12 +132,14 @@ impl<'a> TDoc<'a> {
     ) -> ftd::interpreter::Result<fastn_resolved::OrType> {
         match self.get_thing(name, line_number)? {
             ftd::interpreter::Thing::OrType(ot) => Ok(ot),
-            t => self.err(
-                format!("Expected OrType, found: `{:?}`", t).as_str(),
-                name,
-                "get_or_type",
-                line_number,
-            ),
+            t => self
+                .err(
+                    format!("Expected OrType, found: `{:?}`", t).as_str(),
+                    name,
+                    "get_or_type",
+                    line_number,
+                )
+                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND)),
         }
     }
 
@@ -109,15 +155,21 @@ impl<'a> TDoc<'a> {
             ftd::interpreter::StateWithThing::Continue => {
                 Ok(ftd::interpreter::StateWithThing::new_continue())
             }
-            ftd::interpreter::StateWithThing::Thing(ftd::interpreter::Thing::Record(r)) => {
-                Ok(ftd::interpreter::StateWithThing::new_thing(r.clone()))
-            }
-            ftd::interpreter::StateWithThing::Thing(t) => self.err(
-                format!("Expected Record, found: `{:?}`", t).as_str(),
-                name,
-                "search_record",
-                line_number,
-            ),
+            ftd::interpreter::StateWithThing::Thing(ftd::interpreter::Thing::Record(r))
+            | ftd::interpreter::StateWithThing::Thing(
+                ftd::interpreter::Thing::OrTypeWithVariant {
+                    variant: fastn_resolved::OrTypeVariant::AnonymousRecord(r),
+                    ..
+                },
+            ) => Ok(ftd::interpreter::StateWithThing::new_thing(r.clone())),
+            ftd::interpreter::StateWithThing::Thing(t) => self
+                .err(
+                    format!("Expected Record, found: `{:?}`", t).as_str(),
+                    name,
+                    "search_record",
+                    line_number,
+                )
+                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND)),
         }
     }
 
@@ -128,12 +180,14 @@ impl<'a> TDoc<'a> {
     ) -> ftd::interpreter::Result<fastn_resolved::Variable> {
         match self.get_thing(name, line_number)? {
             ftd::interpreter::Thing::Variable(r) => Ok(r),
-            t => self.err(
-                format!("Expected Variable, found: `{:?}`", t).as_str(),
-                name,
-                "get_variable",
-                line_number,
-            ),
+            t => self
+                .err(
+                    format!("Expected Variable, found: `{:?}`", t).as_str(),
+                    name,
+                    "get_variable",
+                    line_number,
+                )
+                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND)),
         }
     }
 
@@ -146,7 +200,9 @@ impl<'a> TDoc<'a> {
         // TODO: name can be a.b.c, and a and a.b are records with right fields
         match self.get_thing(name, line_number)? {
             ftd::interpreter::Thing::Variable(v) => v.value.resolve(self, line_number),
-            v => self.err("not a variable", v, "get_value", line_number),
+            v => self
+                .err("not a variable", v, "get_value", line_number)
+                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND)),
         }
     }
 
@@ -165,12 +221,14 @@ impl<'a> TDoc<'a> {
             ftd::interpreter::StateWithThing::Thing(ftd::interpreter::Thing::Variable(r)) => {
                 Ok(ftd::interpreter::StateWithThing::new_thing(r))
             }
-            ftd::interpreter::StateWithThing::Thing(t) => self.err(
-                format!("Expected Variable, found: `{:?}`", t).as_str(),
-                name,
-                "search_variable",
-                line_number,
-            ),
+            ftd::interpreter::StateWithThing::Thing(t) => self
+                .err(
+                    format!("Expected Variable, found: `{:?}`", t).as_str(),
+                    name,
+                    "search_variable",
+                    line_number,
+                )
+                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND)),
         }
     }
 
@@ -237,10 +295,11 @@ impl<'a> TDoc<'a> {
                 None,
             )
         } else {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!("Cannot find 111 {} in get_thing", name),
                 self.name,
                 line_number,
+                ftd_p1::codes::NAME_NOT_FOUND,
             );
         };
         let value = value.resolve_with_inherited(self, line_number, inherited_variables)?;
@@ -270,11 +329,17 @@ impl<'a> TDoc<'a> {
                 } => {
                     let field = fields
                         .get(p1.as_str())
-                        .ok_or(ftd::interpreter::Error::ParseError {
-                            message: format!("Can't find field `{}` in record `{}`", p1, rec_name),
-                            doc_id: doc.name.to_string(),
-                            line_number,
-                        })?
+                        .ok_or(
+                            ftd::interpreter::Error::ParseError {
+                                message: format!(
+                                    "Can't find field `{}` in record `{}`",
+                                    p1, rec_name
+                                ),
+                                doc_id: doc.name.to_string(),
+                                line_number,
+                            }
+                            .with_code(ftd_p1::codes::NAME_NOT_FOUND),
+                        )?
                         .clone()
                         .resolve_with_inherited(doc, line_number, inherited_variables)?;
                     if let Some(p2) = p2 {
@@ -292,14 +357,41 @@ impl<'a> TDoc<'a> {
                     let p1 = p1.parse::<usize>()?;
                     let value = data
                         .get(p1)
-                        .ok_or(ftd::interpreter::Error::ParseError {
-                            message: format!(
-                                "Can't find index `{}` in list of kind `{:?}`",
-                                p1, kind
-                            ),
-                            doc_id: doc.name.to_string(),
+                        .ok_or(
+                            ftd::interpreter::Error::ParseError {
+                                message: format!(
+                                    "Can't find index `{}` in list of kind `{:?}`",
+                                    p1, kind
+                                ),
+                                doc_id: doc.name.to_string(),
+                                line_number,
+                            }
+                            .with_code(ftd_p1::codes::NAME_NOT_FOUND),
+                        )?
+                        .clone()
+                        .resolve_with_inherited(doc, line_number, inherited_variables)?;
+                    if let Some(p2) = p2 {
+                        return resolve_(
+                            p2.as_str(),
+                            &value,
                             line_number,
-                        })?
+                            doc,
+                            inherited_variables,
+                        );
+                    }
+                    Ok(value)
+                }
+                fastn_resolved::Value::Map { data, .. } => {
+                    let value = data
+                        .get(p1.as_str())
+                        .ok_or(
+                            ftd::interpreter::Error::ParseError {
+                                message: format!("Can't find key `{}` in map", p1),
+                                doc_id: doc.name.to_string(),
+                                line_number,
+                            }
+                            .with_code(ftd_p1::codes::NAME_NOT_FOUND),
+                        )?
                         .clone()
                         .resolve_with_inherited(doc, line_number, inherited_variables)?;
                     if let Some(p2) = p2 {
@@ -313,10 +405,11 @@ impl<'a> TDoc<'a> {
                     }
                     Ok(value)
                 }
-                t => ftd::interpreter::utils::e2(
+                t => ftd::interpreter::utils::e2_with_code(
                     format!("Expected record found `{:?}`", t).as_str(),
                     doc.name,
                     line_number,
+                    ftd_p1::codes::INVALID_KIND,
                 ),
             }
         }
@@ -331,7 +424,7 @@ impl<'a> TDoc<'a> {
         let (mut variable, mut remaining) = self.get_initial_variable(name, line_number)?;
 
         if !variable.mutable {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!(
                     "The variable declaration `{}` is not mutable in line number {}",
                     variable.name, variable.line_number
@@ -339,6 +432,7 @@ impl<'a> TDoc<'a> {
                 .as_str(),
                 self.name,
                 line_number,
+                ftd_p1::codes::NOT_MUTABLE,
             );
         }
 
@@ -392,7 +486,8 @@ impl<'a> TDoc<'a> {
                         ),
                         doc_id: doc.name.to_string(),
                         line_number,
-                    },
+                    }
+                    .with_code(ftd_p1::codes::MISSING_VALUE),
                 )?;
 
                 match value {
@@ -402,14 +497,17 @@ impl<'a> TDoc<'a> {
                     } => {
                         let field_value = fields
                             .get(p1.as_str())
-                            .ok_or(ftd::interpreter::Error::ParseError {
-                                message: format!(
-                                    "Expected field {} in record `{}` in line number {}",
-                                    p1, rec_name, line_number
-                                ),
-                                doc_id: doc.name.to_string(),
-                                line_number,
-                            })?
+                            .ok_or(
+                                ftd::interpreter::Error::ParseError {
+                                    message: format!(
+                                        "Expected field {} in record `{}` in line number {}",
+                                        p1, rec_name, line_number
+                                    ),
+                                    doc_id: doc.name.to_string(),
+                                    line_number,
+                                }
+                                .with_code(ftd_p1::codes::MISSING_VALUE),
+                            )?
                             .to_owned();
                         if let Some(variable) =
                             find_variable_reference(&field_value, p2, doc, line_number)?
@@ -418,7 +516,7 @@ impl<'a> TDoc<'a> {
                         }
                     }
                     t => {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!(
                                 "Expected record, found `{:?}` in line number {}",
                                 t, line_number
@@ -426,6 +524,7 @@ impl<'a> TDoc<'a> {
                             .as_str(),
                             doc.name,
                             line_number,
+                            ftd_p1::codes::INVALID_KIND,
                         )
                     }
                 }
@@ -465,15 +564,17 @@ impl<'a> TDoc<'a> {
                                     ),
                                     doc_id: doc.name.to_string(),
                                     line_number,
-                                },
+                                }
+                                .with_code(ftd_p1::codes::NAME_NOT_FOUND),
                             )?;
                             change_value(field, set, p2, doc, line_number)?;
                         }
                         t => {
-                            return ftd::interpreter::utils::e2(
+                            return ftd::interpreter::utils::e2_with_code(
                                 format!("Expected record, found `{:?}`", t).as_str(),
                                 doc.name,
                                 line_number,
+                                ftd_p1::codes::INVALID_KIND,
                             )
                         }
                     },
@@ -505,16 +606,18 @@ impl<'a> TDoc<'a> {
                         ..
                     }) => {
                         let function = doc.get_function(name, line_number)?;
-                        let resolved_value = function
-                            .resolve(kind, values, doc, line_number)?
-                            .ok_or(ftd::interpreter::Error::ParseError {
-                                message: format!(
-                                    "Expected return value of type {:?} for function {}",
-                                    kind, name
-                                ),
-                                doc_id: doc.name.to_string(),
-                                line_number,
-                            })?;
+                        let resolved_value =
+                            function.resolve(kind, values, doc, line_number)?.ok_or(
+                                ftd::interpreter::Error::ParseError {
+                                    message: format!(
+                                        "Expected return value of type {:?} for function {}",
+                                        kind, name
+                                    ),
+                                    doc_id: doc.name.to_string(),
+                                    line_number,
+                                }
+                                .with_code(ftd_p1::codes::INVALID_KIND),
+                            )?;
                         *value = fastn_resolved::PropertyValue::Value {
                             value: resolved_value,
                             line_number,
@@ -526,7 +629,7 @@ impl<'a> TDoc<'a> {
             } else if value.kind().inner().eq(&set.kind()) || value.kind().eq(&set.kind()) {
                 *value = set;
             } else {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     format!(
                         "Expected kind `{:?}`, found: \
                     `{:?}`",
@@ -535,6 +638,7 @@ impl<'a> TDoc<'a> {
                     ),
                     doc.name,
                     line_number,
+                    ftd_p1::codes::INVALID_KIND,
                 );
             }
 
@@ -703,13 +807,51 @@ impl<'a> TDoc<'a> {
                         Ok(state_with_thing)
                     }
                 }
+                fastn_resolved::Kind::Map { value, .. } => {
+                    if let Some(remaining) = remaining {
+                        get_kind_(*value, &remaining, doc, line_number)
+                    } else {
+                        Ok(ftd::interpreter::StateWithThing::new_thing(
+                            fastn_resolved::KindData::new(*value),
+                        ))
+                    }
+                }
                 fastn_resolved::Kind::KwArgs => Ok(ftd::interpreter::StateWithThing::new_thing(
                     fastn_resolved::KindData::new(fastn_resolved::Kind::String),
                 )),
-                t => ftd::interpreter::utils::e2(
+                fastn_resolved::Kind::OrType {
+                    name: or_type_name, ..
+                } => {
+                    // `$status.error` refers to the value of the `error` variant of `$status`
+                    let or_type =
+                        try_ok_state!(doc.search_or_type(or_type_name.as_str(), line_number)?);
+                    let payload_kind = or_type
+                        .variants
+                        .iter()
+                        .find(|variant| ftd_ast::Match::variant_name(&variant.name()).eq(&v))
+                        .ok_or(
+                            ftd::interpreter::Error::ParseError {
+                                message: format!(
+                                    "Expected variant `{}` in or-type `{}`",
+                                    v, or_type_name
+                                ),
+                                doc_id: doc.name.to_string(),
+                                line_number,
+                            }
+                            .with_code(ftd_p1::codes::INVALID_VARIANT),
+                        )?
+                        .payload_kind(doc.name)?;
+                    if let Some(remaining) = remaining {
+                        get_kind_(payload_kind.kind, &remaining, doc, line_number)
+                    } else {
+                        Ok(ftd::interpreter::StateWithThing::new_thing(payload_kind))
+                    }
+                }
+                t => ftd::interpreter::utils::e2_with_code(
                     format!("Expected Record field `{}`, found: `{:?}`", name, t),
                     doc.name,
                     line_number,
+                    ftd_p1::codes::INVALID_KIND,
                 ),
             }
         }
@@ -740,12 +882,14 @@ impl<'a> TDoc<'a> {
     ) -> ftd::interpreter::Result<fastn_resolved::ComponentDefinition> {
         match self.get_thing(name, line_number)? {
             ftd::interpreter::Thing::Component(c) => Ok(c),
-            t => self.err(
-                format!("Expected Component, found: `{:?}`", t).as_str(),
-                name,
-                "get_component",
-                line_number,
-            ),
+            t => self
+                .err(
+                    format!("Expected Component, found: `{:?}`", t).as_str(),
+                    name,
+                    "get_component",
+                    line_number,
+                )
+                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND)),
         }
     }
 
@@ -756,12 +900,14 @@ impl<'a> TDoc<'a> {
     ) -> ftd::interpreter::Result<fastn_resolved::WebComponentDefinition> {
         match self.get_thing(name, line_number)? {
             ftd::interpreter::Thing::WebComponent(c) => Ok(c),
-            t => self.err(
-                format!("Expected web-component, found: `{:?}`", t).as_str(),
-                name,
-                "get_web_component",
-                line_number,
-            ),
+            t => self
+                .err(
+                    format!("Expected web-component, found: `{:?}`", t).as_str(),
+                    name,
+                    "get_web_component",
+                    line_number,
+                )
+                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND)),
         }
     }
 
@@ -782,12 +928,14 @@ impl<'a> TDoc<'a> {
             ftd::interpreter::StateWithThing::Thing(ftd::interpreter::Thing::Component(c)) => {
                 Ok(ftd::interpreter::StateWithThing::new_thing(c))
             }
-            ftd::interpreter::StateWithThing::Thing(t) => self.err(
-                format!("Expected Component, found: `{:?}`", t).as_str(),
-                name,
-                "search_component",
-                line_number,
-            ),
+            ftd::interpreter::StateWithThing::Thing(t) => self
+                .err(
+                    format!("Expected Component, found: `{:?}`", t).as_str(),
+                    name,
+                    "search_component",
+                    line_number,
+                )
+                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND)),
         }
     }
 
@@ -808,12 +956,14 @@ impl<'a> TDoc<'a> {
             ftd::interpreter::StateWithThing::Thing(ftd::interpreter::Thing::WebComponent(c)) => {
                 Ok(ftd::interpreter::StateWithThing::new_thing(c))
             }
-            ftd::interpreter::StateWithThing::Thing(t) => self.err(
-                format!("Expected WebComponent, found: `{:?}`", t).as_str(),
-                name,
-                "search_web_component",
-                line_number,
-            ),
+            ftd::interpreter::StateWithThing::Thing(t) => self
+                .err(
+                    format!("Expected WebComponent, found: `{:?}`", t).as_str(),
+                    name,
+                    "search_web_component",
+                    line_number,
+                )
+                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND)),
         }
     }
 
@@ -832,12 +982,24 @@ impl<'a> TDoc<'a> {
             ftd::interpreter::StateWithThing::Thing(ftd::interpreter::Thing::OrType(c)) => {
                 Ok(ftd::interpreter::StateWithThing::new_thing(c))
             }
-            ftd::interpreter::StateWithThing::Thing(t) => self.err(
-                format!("Expected OrType, found: `{:?}`", t).as_str(),
-                name,
-                "search_or_type",
-                line_number,
-            ),
+            ftd::interpreter::StateWithThing::Thing(t) => self
+                .err(
+                    format!("Expected OrType, found: `{:?}`", t).as_str(),
+                    name,
+                    "search_or_type",
+                    line_number,
+                )
+                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND)),
+        }
+    }
+
+    /// Marks the or-type `name` as `match`ed on, see
+    /// [fastn_resolved::OrType::matched].
+    pub fn set_or_type_matched(&mut self, name: &str) {
+        if let BagOrState::State(state) = &mut self.bag {
+            if let Some(ftd::interpreter::Thing::OrType(or_type)) = state.bag.get_mut(name) {
+                or_type.matched = true;
+            }
         }
     }
 
@@ -860,12 +1022,14 @@ impl<'a> TDoc<'a> {
             ) => Ok(ftd::interpreter::StateWithThing::new_thing((
                 or_type, variant,
             ))),
-            ftd::interpreter::StateWithThing::Thing(t) => self.err(
-                format!("Expected OrTypeWithVariant, found: `{:?}`", t).as_str(),
-                name,
-                "search_or_type_with_variant",
-                line_number,
-            ),
+            ftd::interpreter::StateWithThing::Thing(t) => self
+                .err(
+                    format!("Expected OrTypeWithVariant, found: `{:?}`", t).as_str(),
+                    name,
+                    "search_or_type_with_variant",
+                    line_number,
+                )
+                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND)),
         }
     }
 
@@ -908,21 +1072,25 @@ impl<'a> TDoc<'a> {
                         Some(fastn_resolved::Value::Record { fields, .. }) => fields,
                         Some(fastn_resolved::Value::Object { values }) => values,
                         Some(fastn_resolved::Value::KwArgs { arguments }) => arguments,
+                        Some(fastn_resolved::Value::Map { data, .. }) => data,
+                        Some(fastn_resolved::Value::OrType {
+                            name: or_type,
+                            variant,
+                            value,
+                            ..
+                        }) => or_type_fields(doc, &or_type, &variant, *value, line_number)?,
                         Some(fastn_resolved::Value::List { data, .. }) => data
                             .into_iter()
                             .enumerate()
                             .map(|(index, v)| (index.to_string(), v))
                             .collect::<ftd::Map<fastn_resolved::PropertyValue>>(),
                         None => {
-                            let kind_name = match value_kind.get_record_name() {
+                            let kind_name = match value_kind.ref_inner().get_record_name() {
                                 Some(name) => name,
                                 _ => {
-                                    return doc.err(
-                                        "not an record",
-                                        thing,
-                                        "get_thing",
-                                        line_number,
-                                    );
+                                    return doc
+                                        .err("not an record", thing, "get_thing", line_number)
+                                        .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND));
                                 }
                             };
                             let kind_thing = doc.get_thing(kind_name, line_number)?;
@@ -935,12 +1103,14 @@ impl<'a> TDoc<'a> {
                             {
                                 Some(f) => f,
                                 _ => {
-                                    return doc.err(
-                                        "not an record or or-type",
-                                        thing,
-                                        "get_thing",
-                                        line_number,
-                                    );
+                                    return doc
+                                        .err(
+                                            "not an record or or-type",
+                                            thing,
+                                            "get_thing",
+                                            line_number,
+                                        )
+                                        .map_err(|e| e.with_code(ftd_p1::codes::NAME_NOT_FOUND));
                                 }
                             };
                             let thing =
@@ -959,13 +1129,18 @@ impl<'a> TDoc<'a> {
                                     conditional_value: vec![],
                                     line_number,
                                     is_static: !mutable,
+                                    sse: None,
                                 });
                             if let Some(remaining) = remaining {
                                 return get_thing_(doc, line_number, &remaining, thing);
                             }
                             return Ok(thing);
                         }
-                        _ => return doc.err("not an record", thing, "get_thing", line_number),
+                        _ => {
+                            return doc
+                                .err("not an record", thing, "get_thing", line_number)
+                                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND))
+                        }
                     };
                     match fields.get(&v) {
                         Some(fastn_resolved::PropertyValue::Value {
@@ -988,6 +1163,7 @@ impl<'a> TDoc<'a> {
                             conditional_value: vec![],
                             line_number: *line_number,
                             is_static: !mutable,
+                            sse: None,
                         }),
                         Some(fastn_resolved::PropertyValue::Reference { name, .. })
                         | Some(fastn_resolved::PropertyValue::Clone { name, .. }) => {
@@ -1007,15 +1183,20 @@ impl<'a> TDoc<'a> {
                     let variant = variants
                         .iter()
                         .find_or_first(|variant| variant.name().eq(&format!("{name}.{v}")))
-                        .ok_or(ftd::interpreter::Error::ParseError {
-                            message: format!("Cant't find `{v}` variant in `{name}` or-type"),
-                            doc_id: doc.name.to_string(),
-                            line_number,
-                        })?;
+                        .ok_or(
+                            ftd::interpreter::Error::ParseError {
+                                message: format!("Cant't find `{v}` variant in `{name}` or-type"),
+                                doc_id: doc.name.to_string(),
+                                line_number,
+                            }
+                            .with_code(ftd_p1::codes::NAME_NOT_FOUND),
+                        )?;
                     variant.to_thing(doc.name, line_number)?
                 }
                 _ => {
-                    return doc.err("not an or-type", thing, "get_thing", line_number);
+                    return doc
+                        .err("not an or-type", thing, "get_thing", line_number)
+                        .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND));
                 }
             };
             if let Some(remaining) = remaining {
@@ -1103,7 +1284,7 @@ impl<'a> TDoc<'a> {
         } {
             state
         } else {
-            return self.err("not found", name, "search_thing", line_number);
+            return self.not_found(name, "search_thing", line_number);
         };
 
         if doc_name.eq(ftd::interpreter::FTD_INHERITED) {
@@ -1344,21 +1525,25 @@ impl<'a> TDoc<'a> {
                         Some(fastn_resolved::Value::Record { fields, .. }) => fields,
                         Some(fastn_resolved::Value::Object { values }) => values,
                         Some(fastn_resolved::Value::KwArgs { arguments }) => arguments,
+                        Some(fastn_resolved::Value::Map { data, .. }) => data,
+                        Some(fastn_resolved::Value::OrType {
+                            name: or_type,
+                            variant,
+                            value,
+                            ..
+                        }) => or_type_fields(doc, &or_type, &variant, *value, line_number)?,
                         Some(fastn_resolved::Value::List { data, .. }) => data
                             .into_iter()
                             .enumerate()
                             .map(|(index, v)| (index.to_string(), v))
                             .collect::<ftd::Map<fastn_resolved::PropertyValue>>(),
                         None => {
-                            let kind_name = match value_kind.get_record_name() {
+                            let kind_name = match value_kind.ref_inner().get_record_name() {
                                 Some(name) => name,
                                 _ => {
-                                    return doc.err(
-                                        "not an record",
-                                        thing,
-                                        "search_thing_",
-                                        line_number,
-                                    );
+                                    return doc
+                                        .err("not an record", thing, "search_thing_", line_number)
+                                        .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND));
                                 }
                             };
                             let kind_thing =
@@ -1372,12 +1557,14 @@ impl<'a> TDoc<'a> {
                             {
                                 Some(f) => f,
                                 _ => {
-                                    return doc.err(
-                                        "not an record or or-type",
-                                        thing,
-                                        "search_thing_",
-                                        line_number,
-                                    );
+                                    return doc
+                                        .err(
+                                            "not an record or or-type",
+                                            thing,
+                                            "search_thing_",
+                                            line_number,
+                                        )
+                                        .map_err(|e| e.with_code(ftd_p1::codes::NAME_NOT_FOUND));
                                 }
                             };
                             let thing =
@@ -1396,13 +1583,18 @@ impl<'a> TDoc<'a> {
                                     conditional_value: vec![],
                                     line_number,
                                     is_static: !mutable,
+                                    sse: None,
                                 });
                             if let Some(remaining) = remaining {
                                 return search_thing_(doc, line_number, &remaining, thing);
                             }
                             return Ok(ftd::interpreter::StateWithThing::new_thing(thing));
                         }
-                        _ => return doc.err("not an record", thing, "search_thing_", line_number),
+                        _ => {
+                            return doc
+                                .err("not an record", thing, "search_thing_", line_number)
+                                .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND))
+                        }
                     };
                     match fields.get(&v) {
                         Some(fastn_resolved::PropertyValue::Value {
@@ -1425,6 +1617,7 @@ impl<'a> TDoc<'a> {
                             conditional_value: vec![],
                             line_number: *line_number,
                             is_static: !mutable,
+                            sse: None,
                         }),
                         property_value @ Some(fastn_resolved::PropertyValue::Reference {
                             name,
@@ -1467,11 +1660,10 @@ impl<'a> TDoc<'a> {
                     name, variants, ..
                 }) => {
                     let or_type_name = fastn_resolved::OrType::or_type_name(name.as_str());
+                    // the variants that are anonymous records are named with the full name
+                    // of the or-type
                     if let Some(thing) = variants.into_iter().find(|or_type_variant| {
-                        let variant_name = or_type_variant.name();
-                        variant_name
-                            .trim_start_matches(format!("{}.", or_type_name).as_str())
-                            .eq(&v)
+                        ftd_ast::Match::variant_name(&or_type_variant.name()).eq(&v)
                     }) {
                         // Todo: Handle remaining
                         ftd::interpreter::Thing::OrTypeWithVariant {
@@ -1479,25 +1671,29 @@ impl<'a> TDoc<'a> {
                             variant: thing,
                         }
                     } else {
-                        return doc.err(
-                            format!(
-                                "Can't find variant `{}` in or-type `{}`",
-                                name, or_type_name
+                        return doc
+                            .err(
+                                format!(
+                                    "Can't find variant `{}` in or-type `{}`",
+                                    name, or_type_name
+                                )
+                                .as_str(),
+                                thing,
+                                "search_thing_",
+                                line_number,
                             )
-                            .as_str(),
-                            thing,
-                            "search_thing_",
-                            line_number,
-                        );
+                            .map_err(|e| e.with_code(ftd_p1::codes::INVALID_VARIANT));
                     }
                 }
                 _ => {
-                    return doc.err(
-                        format!("not an or-type `{}`", name).as_str(),
-                        thing,
-                        "search_thing_",
-                        line_number,
-                    );
+                    return doc
+                        .err(
+                            format!("not an or-type `{}`", name).as_str(),
+                            thing,
+                            "search_thing_",
+                            line_number,
+                        )
+                        .map_err(|e| e.with_code(ftd_p1::codes::INVALID_KIND));
                 }
             };
             if let Some(remaining) = remaining {
@@ -1539,7 +1735,7 @@ impl<'a> TDoc<'a> {
         } {
             state
         } else {
-            return self.err("not found", name, "search_thing", line_number);
+            return self.not_found(name, "search_thing", line_number);
         };
 
         let current_parsed_document = state.parsed_libs.get(state.id.as_str()).unwrap();
@@ -1582,7 +1778,7 @@ impl<'a> TDoc<'a> {
                 }
             } else if !current_doc_contains_thing.is_empty() && state.peek_stack().unwrap().1.gt(&4)
             {
-                return self.err("not found", name, "search_thing", line_number);
+                return self.not_found(name, "search_thing", line_number);
             }
         }
 
@@ -1671,7 +1867,7 @@ impl<'a> TDoc<'a> {
                         return Ok(thing);
                     }
                 }*/
-                return self.err("not found", name, "search_thing", line_number);
+                return self.not_found(name, "search_thing", line_number);
             }
 
             state
@@ -1693,7 +1889,7 @@ impl<'a> TDoc<'a> {
         }
 
         if doc_name.eq(self.name) {
-            return self.err("not found", name, "search_thing", line_number);
+            return self.not_found(name, "search_thing", line_number);
         }
 
         state
@@ -1803,7 +1999,7 @@ impl<'a> TDoc<'a> {
             None => match self.bag().get(name).map(|v| (v, None)) {
                 Some(a) => a,
                 None => {
-                    return self.err("not found", splited_name, "get_initial_thing", line_number);
+                    return self.not_found(splited_name, "get_initial_thing", line_number);
                 }
             },
         };
@@ -1859,10 +2055,11 @@ impl<'a> TDoc<'a> {
             let val = match row.get(idx) {
                 Some(v) => v,
                 None => {
-                    return ftd::interpreter::utils::e2(
+                    return ftd::interpreter::utils::e2_with_code(
                         format!("key not found: {}", key.name.as_str()),
                         self.name,
                         value.line_number(),
+                        ftd_p1::codes::NAME_NOT_FOUND,
                     )
                 }
             };
@@ -1890,10 +2087,11 @@ impl<'a> TDoc<'a> {
         }
 
         if row.len() != 1 {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!("expected one column, found: {}", row.len()),
                 self.name,
                 value.line_number(),
+                ftd_p1::codes::INVALID_KIND,
             );
         }
 
@@ -1951,10 +2149,11 @@ impl<'a> TDoc<'a> {
             }
         }
 
-        ftd::interpreter::utils::e2(
+        ftd::interpreter::utils::e2_with_code(
             format!("Can't parse to {kind:?}, found: {o:?}"),
             self.name,
             line_number,
+            ftd_p1::codes::INVALID_KIND,
         )
     }
 
@@ -1974,85 +2173,116 @@ impl<'a> TDoc<'a> {
                         return self.handle_object(kind, o, default_value, record_name, line_number)
                     }
                     _ => {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!("Can't parse to string, found: {json}"),
                             self.name,
                             line_number,
+                            ftd_p1::codes::INVALID_KIND,
                         )
                     }
                 },
             },
             fastn_resolved::Kind::Integer { .. } => fastn_resolved::Value::Integer {
                 value: match json {
-                    serde_json::Value::Number(n) => {
-                        n.as_i64()
-                            .ok_or_else(|| ftd::interpreter::Error::ParseError {
-                                message: format!("Can't parse to integer, found: {json}"),
-                                doc_id: self.name.to_string(),
-                                line_number,
-                            })?
-                    }
-                    serde_json::Value::String(s) => {
-                        s.parse::<i64>()
-                            .map_err(|_| ftd::interpreter::Error::ParseError {
-                                message: format!("Can't parse to integer, found: {json}"),
-                                doc_id: self.name.to_string(),
-                                line_number,
-                            })?
-                    }
+                    serde_json::Value::Number(n) => n.as_i64().ok_or_else(|| {
+                        ftd::interpreter::Error::ParseError {
+                            message: format!("Can't parse to integer, found: {json}"),
+                            doc_id: self.name.to_string(),
+                            line_number,
+                        }
+                        .with_code(ftd_p1::codes::INVALID_KIND)
+                    })?,
+                    serde_json::Value::String(s) => s.parse::<i64>().map_err(|_| {
+                        ftd::interpreter::Error::ParseError {
+                            message: format!("Can't parse to integer, found: {json}"),
+                            doc_id: self.name.to_string(),
+                            line_number,
+                        }
+                        .with_code(ftd_p1::codes::INVALID_KIND)
+                    })?,
                     serde_json::Value::Object(o) => {
                         return self.handle_object(kind, o, default_value, record_name, line_number)
                     }
                     _ => {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!("Can't parse to integer, found: {json}"),
                             self.name,
                             line_number,
+                            ftd_p1::codes::INVALID_KIND,
                         )
                     }
                 },
             },
             fastn_resolved::Kind::Decimal { .. } => fastn_resolved::Value::Decimal {
                 value: match json {
-                    serde_json::Value::Number(n) => {
-                        n.as_f64()
-                            .ok_or_else(|| ftd::interpreter::Error::ParseError {
-                                message: format!("Can't parse to decimal, found: {json}"),
-                                doc_id: self.name.to_string(),
-                                line_number,
-                            })?
-                    }
-                    serde_json::Value::String(s) => {
-                        s.parse::<f64>()
-                            .map_err(|_| ftd::interpreter::Error::ParseError {
-                                message: format!("Can't parse to decimal, found: {json}"),
-                                doc_id: self.name.to_string(),
-                                line_number,
-                            })?
-                    }
+                    serde_json::Value::Number(n) => n.as_f64().ok_or_else(|| {
+                        ftd::interpreter::Error::ParseError {
+                            message: format!("Can't parse to decimal, found: {json}"),
+                            doc_id: self.name.to_string(),
+                            line_number,
+                        }
+                        .with_code(ftd_p1::codes::INVALID_KIND)
+                    })?,
+                    serde_json::Value::String(s) => s.parse::<f64>().map_err(|_| {
+                        ftd::interpreter::Error::ParseError {
+                            message: format!("Can't parse to decimal, found: {json}"),
+                            doc_id: self.name.to_string(),
+                            line_number,
+                        }
+                        .with_code(ftd_p1::codes::INVALID_KIND)
+                    })?,
                     serde_json::Value::Object(o) => {
                         return self.handle_object(kind, o, default_value, record_name, line_number)
                     }
                     _ => {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!("Can't parse to decimal, found: {}", json),
                             self.name,
                             line_number,
+                            ftd_p1::codes::INVALID_KIND,
                         )
                     }
                 },
             },
+            fastn_resolved::Kind::DateTime | fastn_resolved::Kind::Date => {
+                let value = match json {
+                    serde_json::Value::Number(n) => n.as_i64(),
+                    serde_json::Value::String(s) if kind.is_date() => {
+                        fastn_builtins::datetime::parse_date(s)
+                    }
+                    serde_json::Value::String(s) => fastn_builtins::datetime::parse_datetime(s),
+                    serde_json::Value::Object(o) => {
+                        return self.handle_object(kind, o, default_value, record_name, line_number)
+                    }
+                    _ => None,
+                }
+                .ok_or_else(|| {
+                    ftd::interpreter::Error::ParseError {
+                        message: format!("Can't parse to {}, found: {json}", kind.get_name()),
+                        doc_id: self.name.to_string(),
+                        line_number,
+                    }
+                    .with_code(ftd_p1::codes::INVALID_KIND)
+                })?;
+                if kind.is_date() {
+                    fastn_resolved::Value::Date {
+                        value: fastn_builtins::datetime::start_of_day(value),
+                    }
+                } else {
+                    fastn_resolved::Value::DateTime { value }
+                }
+            }
             fastn_resolved::Kind::Boolean { .. } => fastn_resolved::Value::Boolean {
                 value: match json {
                     serde_json::Value::Bool(n) => *n,
-                    serde_json::Value::String(s) => {
-                        s.parse::<bool>()
-                            .map_err(|_| ftd::interpreter::Error::ParseError {
-                                message: format!("Can't parse to boolean, found: {}", json),
-                                doc_id: self.name.to_string(),
-                                line_number,
-                            })?
-                    }
+                    serde_json::Value::String(s) => s.parse::<bool>().map_err(|_| {
+                        ftd::interpreter::Error::ParseError {
+                            message: format!("Can't parse to boolean, found: {}", json),
+                            doc_id: self.name.to_string(),
+                            line_number,
+                        }
+                        .with_code(ftd_p1::codes::INVALID_KIND)
+                    })?,
                     serde_json::Value::Number(n) => match n.as_i64() {
                         Some(0) => false,
                         Some(1) => true,
@@ -2061,17 +2291,19 @@ impl<'a> TDoc<'a> {
                                 message: format!("Can't parse to decimal, found: {json}"),
                                 doc_id: self.name.to_string(),
                                 line_number,
-                            })
+                            }
+                            .with_code(ftd_p1::codes::INVALID_KIND))
                         }
                     },
                     serde_json::Value::Object(o) => {
                         return self.handle_object(kind, o, default_value, record_name, line_number)
                     }
                     _ => {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!("Can't parse to boolean, found: {}", json),
                             self.name,
                             line_number,
+                            ftd_p1::codes::INVALID_KIND,
                         )
                     }
                 },
@@ -2085,11 +2317,15 @@ impl<'a> TDoc<'a> {
                             Some(v) => v.to_owned(),
                             None if field.kind.is_optional() => serde_json::Value::Null,
                             None if field.kind.is_list() => serde_json::Value::Array(vec![]),
+                            None if field.kind.is_map() => {
+                                serde_json::Value::Object(Default::default())
+                            }
                             None => {
-                                return ftd::interpreter::utils::e2(
+                                return ftd::interpreter::utils::e2_with_code(
                                     format!("key not found: {}", field.name.as_str()),
                                     self.name,
                                     line_number,
+                                    ftd_p1::codes::NAME_NOT_FOUND,
                                 )
                             }
                         };
@@ -2125,18 +2361,20 @@ impl<'a> TDoc<'a> {
                             },
                         );
                     } else {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!("expected object of record type, found: {}", json),
                             self.name,
                             line_number,
+                            ftd_p1::codes::INVALID_KIND,
                         );
                     }
                 } else {
                     // Todo: Handle default_value
-                    return ftd::interpreter::utils::e2(
+                    return ftd::interpreter::utils::e2_with_code(
                         format!("expected object of record type, found: {}", json),
                         self.name,
                         line_number,
+                        ftd_p1::codes::INVALID_KIND,
                     );
                 }
                 fastn_resolved::Value::Record {
@@ -2156,10 +2394,11 @@ impl<'a> TDoc<'a> {
                     }
                 } else {
                     // Todo: Handle `default_value`
-                    return ftd::interpreter::utils::e2(
+                    return ftd::interpreter::utils::e2_with_code(
                         format!("expected object of list type, found: {}", json),
                         self.name,
                         line_number,
+                        ftd_p1::codes::INVALID_KIND,
                     );
                 }
                 fastn_resolved::Value::List {
@@ -2167,6 +2406,41 @@ impl<'a> TDoc<'a> {
                     kind: kind.to_owned().into_kind_data(),
                 }
             }
+            fastn_resolved::Kind::Map { key, value } => {
+                let mut data: ftd::Map<fastn_resolved::PropertyValue> = Default::default();
+                if let serde_json::Value::Object(o) = json {
+                    for (k, item) in o {
+                        if key.is_integer() && k.parse::<i64>().is_err() {
+                            return ftd::interpreter::utils::e2_with_code(
+                                format!("expected integer key in map, found: {}", k),
+                                self.name,
+                                line_number,
+                                ftd_p1::codes::INVALID_KIND,
+                            );
+                        }
+                        data.insert(
+                            k.to_string(),
+                            fastn_resolved::PropertyValue::Value {
+                                value: self.as_json_(value, item, None, None, line_number)?,
+                                is_mutable: false,
+                                line_number,
+                            },
+                        );
+                    }
+                } else {
+                    return ftd::interpreter::utils::e2_with_code(
+                        format!("expected object of map type, found: {}", json),
+                        self.name,
+                        line_number,
+                        ftd_p1::codes::INVALID_KIND,
+                    );
+                }
+                fastn_resolved::Value::Map {
+                    data,
+                    key: key.as_ref().to_owned(),
+                    kind: value.as_ref().to_owned().into_kind_data(),
+                }
+            }
             fastn_resolved::Kind::Optional { kind, .. } => {
                 let kind = kind.as_ref();
                 match json {
@@ -2210,6 +2484,53 @@ impl<'a> TDoc<'a> {
             line_number,
         )
     }
+
+    pub(crate) fn not_found<T, T2: std::fmt::Debug>(
+        &self,
+        name: T2,
+        f: &str,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<T> {
+        self.err("not found", name, f, line_number)
+            .map_err(|e| e.with_code(ftd_p1::codes::NAME_NOT_FOUND))
+    }
+}
+
+/// What `$value.<variant>` is for each variant of the or-type value `value`:
+/// the value of the variant it holds, and nothing for the other variants
+/// carrying a value, whose components `match` hides.
+fn or_type_fields(
+    doc: &TDoc,
+    or_type: &str,
+    variant: &str,
+    value: fastn_resolved::PropertyValue,
+    line_number: usize,
+) -> ftd::interpreter::Result<ftd::Map<fastn_resolved::PropertyValue>> {
+    let variant = ftd_ast::Match::variant_name(variant);
+    let mut fields: ftd::Map<fastn_resolved::PropertyValue> = Default::default();
+    if let Ok(ftd::interpreter::Thing::OrType(or_type)) = doc.get_thing(or_type, line_number) {
+        for v in or_type.variants.iter() {
+            let name = v.name();
+            let name = ftd_ast::Match::variant_name(name.as_str());
+            if name.eq(variant) || matches!(v, fastn_resolved::OrTypeVariant::Constant(_)) {
+                continue;
+            }
+            let kind = v.payload_kind(doc.name)?;
+            fields.insert(
+                name.to_string(),
+                fastn_resolved::PropertyValue::Value {
+                    value: fastn_resolved::Value::Optional {
+                        data: Box::new(None),
+                        kind,
+                    },
+                    is_mutable: false,
+                    line_number,
+                },
+            );
+        }
+    }
+    fields.insert(variant.to_string(), value);
+    Ok(fields)
 }
 
 impl fastn_resolved::tdoc::TDoc for TDoc<'_> {
@@ -1,4 +1,5 @@
 use ftd::interpreter::ComponentExt;
+use ftd::interpreter::PropertyValueExt;
 use pretty_assertions::assert_eq;
 
 #[track_caller]
@@ -274,3 +275,303 @@ fn test_extract_kwargs() {
     assert_eq!(data.get("bar"), Some(&String::from("Hello")));
     assert_eq!(data.get("baz"), Some(&String::from("World")));
 }
+
+#[test]
+fn map_from_json() {
+    let doc = ftd::parse_doc("foo", "-- ftd.text: hello").unwrap();
+    let tdoc = doc.tdoc();
+    let kind = fastn_resolved::Kind::map(
+        fastn_resolved::Kind::string(),
+        fastn_resolved::Kind::integer(),
+    );
+    let value = ftd_ast::VariableValue::Optional {
+        value: Box::new(None),
+        line_number: 1,
+        condition: None,
+        span: Default::default(),
+    };
+
+    let map = tdoc
+        .from_json(&serde_json::json!({"alice": 10, "bob": 20}), &kind, &value)
+        .unwrap();
+    let fastn_resolved::Value::Map { data, .. } = map else {
+        panic!("expected map, found: {map:?}");
+    };
+    assert_eq!(data.keys().collect::<Vec<_>>(), vec!["alice", "bob"]);
+    assert_eq!(
+        data.get("bob").unwrap().clone().resolve(&tdoc, 1).unwrap(),
+        fastn_resolved::Value::Integer { value: 20 }
+    );
+
+    assert!(tdoc
+        .from_json(&serde_json::json!([10, 20]), &kind, &value)
+        .is_err());
+    let integer_keys = fastn_resolved::Kind::map(
+        fastn_resolved::Kind::integer(),
+        fastn_resolved::Kind::integer(),
+    );
+    assert!(tdoc
+        .from_json(&serde_json::json!({"alice": 10}), &integer_keys, &value)
+        .is_err());
+}
+
+#[test]
+fn evalexpr_context_language() {
+    use fastn_resolved::evalexpr::{eval_with_context_mut, Value};
+
+    let mut doc = ftd::parse_doc("foo", "-- ftd.text: hello").unwrap();
+    let published = "ftd.format_datetime(1706696430000, \"%d %B %Y\")";
+
+    let mut context = doc.tdoc().evalexpr_context().unwrap();
+    assert_eq!(
+        eval_with_context_mut(published, &mut context),
+        Ok(Value::from("31 January 2024"))
+    );
+
+    // `fastn` sets `ftd#language` to the language of the request
+    doc.data.insert(
+        "ftd#language".to_string(),
+        ftd::interpreter::Thing::Variable(fastn_resolved::Variable {
+            name: "ftd#language".to_string(),
+            kind: fastn_resolved::Kind::string().into_kind_data(),
+            mutable: false,
+            value: fastn_resolved::Value::new_string("fr").into_property_value(false, 0),
+            conditional_value: vec![],
+            line_number: 0,
+            is_static: true,
+            sse: None,
+        }),
+    );
+    let mut context = doc.tdoc().evalexpr_context().unwrap();
+    assert_eq!(
+        eval_with_context_mut(published, &mut context),
+        Ok(Value::from("31 janvier 2024"))
+    );
+    assert_eq!(
+        eval_with_context_mut(
+            "ftd.format_datetime(1706696430000, \"%d %B %Y\", \"de\")",
+            &mut context
+        ),
+        Ok(Value::from("31 Januar 2024"))
+    );
+    let relative = eval_with_context_mut("ftd.relative_time(0)", &mut context).unwrap();
+    assert!(relative.as_string().unwrap().starts_with("il y a "));
+}
+
+#[test]
+fn datetime_to_json() {
+    let doc = ftd::parse_doc(
+        "foo",
+        indoc::indoc!(
+            "
+            -- datetime published: 2024-01-31T10:20:30Z
+
+            -- date due: 2024-02-15
+
+            -- ftd.text: late
+            if: { published > due }
+            "
+        ),
+    )
+    .unwrap();
+
+    assert_eq!(
+        doc.get::<String>("foo#published").unwrap(),
+        "2024-01-31T10:20:30Z"
+    );
+    assert_eq!(doc.get::<String>("foo#due").unwrap(), "2024-02-15");
+}
+
+#[test]
+fn record_from_evalexpr() {
+    let doc = ftd::parse_doc(
+        "foo",
+        indoc::indoc!(
+            "
+            -- record person:
+            string name:
+            integer age:
+            optional string nickname:
+            boolean admin: false
+            date joined: 2024-01-31
+
+            -- person list people:
+
+            -- person:
+            name: Ritesh
+            age: 30
+
+            -- person:
+            name: Amit
+            age: 40
+            nickname: amitu
+
+            -- end: people
+
+            -- person list by-name: $sort-people(a = $people)
+
+            -- person list sort-people(a):
+            person list a:
+
+            ftd.sort_by(a, \"name\")
+
+            -- ftd.text: $p.name
+            for: $p in $by-name
+            "
+        ),
+    )
+    .unwrap();
+
+    // the fields get the kinds the record declares, missing optional fields
+    // and fields with defaults are filled in
+    assert_eq!(
+        doc.get::<serde_json::Value>("foo#by-name").unwrap(),
+        serde_json::json!([
+            {"name": "Amit", "age": 40, "nickname": "amitu", "admin": false, "joined": "2024-01-31"},
+            {"name": "Ritesh", "age": 30, "nickname": null, "admin": false, "joined": "2024-01-31"},
+        ])
+    );
+}
+
+#[test]
+fn or_type_match() {
+    let source = indoc::indoc!(
+        "
+        -- or-type status:
+
+        -- constant string loading: loading
+
+        -- string error:
+
+        -- end: status
+
+        -- status.error failure: timeout
+
+        -- status idle: loading
+
+        -- boolean failed: $is-error(s = $failure)
+
+        -- boolean waiting: $is-error(s = $idle)
+
+        -- boolean is-error(s):
+        status s:
+
+        ftd.is_variant(s, \"error\")
+
+        -- ftd.text: $reason
+        match: $failure is status.error as $reason
+        if: { failed || waiting }
+        "
+    );
+    let doc = ftd::parse_doc("foo", source).unwrap();
+    assert!(doc.get::<bool>("foo#failed").unwrap());
+    assert!(!doc.get::<bool>("foo#waiting").unwrap());
+
+    // `loading` is not matched, the document still renders
+    assert_eq!(
+        doc.warnings,
+        vec![ftd::interpreter::Warning {
+            doc_id: "foo".to_string(),
+            line_number: 23,
+            code: "FTD0205".to_string(),
+            message: "non-exhaustive match on `$failure`, missing variants: `loading`".to_string(),
+        }]
+    );
+
+    let doc = ftd::parse_doc(
+        "foo",
+        format!("{source}\n-- ftd.text: Loading...\nmatch: $failure is status.loading\n").as_str(),
+    )
+    .unwrap();
+    assert!(doc.warnings.is_empty());
+}
+
+#[test]
+fn slots() {
+    let definition = indoc::indoc!(
+        "
+        -- component page:
+        slot header:
+        optional slot sidebar:
+
+        -- ftd.column:
+
+        -- page.header:
+
+        -- ftd.column:
+        children: $page.sidebar
+
+        -- end: ftd.column
+
+        -- end: ftd.column
+
+        -- end: page
+        "
+    );
+
+    let doc = ftd::parse_doc(
+        "foo",
+        format!(
+            "{definition}\n-- page:\n\n-- page.header:\n\n-- ftd.text: hello\n\n-- end: page.header\n\n-- end: page\n"
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let page = match doc.data.get("foo#page") {
+        Some(ftd::interpreter::Thing::Component(page)) => page,
+        t => panic!("expected component, found: {t:?}"),
+    };
+    // a slot is the same kind as any other `ftd.ui list`, only the argument knows it is a slot
+    let ui_list = fastn_resolved::Kind::ui().into_list();
+    let header = page.arguments.iter().find(|a| a.name.eq("header")).unwrap();
+    let sidebar = page
+        .arguments
+        .iter()
+        .find(|a| a.name.eq("sidebar"))
+        .unwrap();
+    assert_eq!(header.kind.kind, ui_list);
+    assert_eq!(sidebar.kind.kind, ui_list);
+    assert!(header.is_slot);
+    assert!(!sidebar.is_slot);
+
+    // the error is under the name of the component missing the slot
+    let source = format!("{definition}\n-- page:\n");
+    let start = source.rfind("-- page:").unwrap() + "-- ".len();
+    match ftd::parse_doc("foo", source.as_str()) {
+        Err(e) => {
+            use ftd_p1::Reportable;
+
+            assert_eq!(
+                e.message(),
+                "Slot `header` of component `page` is not filled, pass it using \
+                `-- page.header:` or declare it as `optional slot`"
+            );
+            assert_eq!(
+                e.span(),
+                Some(ftd_p1::Span::new(start, start + "page".len()))
+            );
+        }
+        r => panic!("expected an error for the unfilled slot, found: {r:?}"),
+    }
+}
+
+#[test]
+fn errors_eq() {
+    let error = || ftd::interpreter::Error::ParseError {
+        message: "not found".to_string(),
+        doc_id: "foo".to_string(),
+        line_number: 1,
+    };
+    assert_eq!(error(), error());
+    // the same text, told apart by the code
+    assert_eq!(
+        error().to_string(),
+        error().with_code("FTD0201").to_string()
+    );
+    assert_ne!(error(), error().with_code("FTD0201"));
+    assert_ne!(error().with_code("FTD0201"), error().with_code("FTD0200"));
+    assert_ne!(
+        ftd::interpreter::Errors(vec![error().with_span(ftd_p1::Span::new(0, 3))]),
+        ftd::interpreter::Errors(vec![error().with_span(ftd_p1::Span::new(4, 7))])
+    );
+}
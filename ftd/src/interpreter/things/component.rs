@@ -67,7 +67,8 @@ impl ComponentDefinitionExt for fastn_resolved::ComponentDefinition {
                         value: css.to_string(),
                         line_number: component_definition.line_number(),
                         source: ftd_ast::ValueSource::Default,
-                        condition: None
+                        condition: None,
+                        span: Default::default(),
                     },
                     doc,
                     false,
@@ -98,7 +99,7 @@ impl ComponentDefinitionExt for fastn_resolved::ComponentDefinition {
                     .to_string(),
                 doc_id: doc.name.to_string(),
                 line_number: iteration.line_number,
-            });
+            }.with_code(ftd_p1::codes::NOT_SUPPORTED));
         }
         Ok(ftd::interpreter::StateWithThing::new_thing(
             fastn_resolved::ComponentDefinition::new(
@@ -136,11 +137,20 @@ pub(crate) fn check_if_property_is_provided_for_required_argument(
             .map(|v| v.is_none())
             .unwrap_or(true)
         {
-            return Err(ftd::interpreter::Error::ParseError {
-                message: format!(
+            let message = if argument.is_slot {
+                format!(
+                    "Slot `{}` of component `{}` is not filled, pass it using \
+                    `-- {}.{}:` or declare it as `optional slot`",
+                    argument.name, component_name, component_name, argument.name
+                )
+            } else {
+                format!(
                     "Property `{}` of component `{}` is not passed",
                     argument.name, component_name
-                ),
+                )
+            };
+            return Err(ftd::interpreter::Error::ParseError {
+                message,
                 doc_id: doc_id.to_string(),
                 line_number,
             });
@@ -170,7 +180,7 @@ pub(crate) fn search_things_for_module(
             argument.line_number,
         )?;
         if property.len() != 1 {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!(
                     "Expected one value for `module` type argument `{}`, found `{}` values",
                     argument.name,
@@ -178,6 +188,7 @@ pub(crate) fn search_things_for_module(
                 ),
                 doc.name,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             );
         }
         let module_property = property.first().unwrap();
@@ -200,7 +211,7 @@ pub(crate) fn search_things_for_module(
             } {
                 state.parsed_libs.get_mut(state.id.as_str()).unwrap()
             } else {
-                return doc.err("not found", m_name, "search_thing", line_number);
+                return doc.not_found(m_name, "search_thing", line_number);
             };
             let (module, alias) = ftd_ast::utils::get_import_alias(m_name.as_str());
             if !current_parsed_document
@@ -297,20 +308,22 @@ fn get_module_name_and_thing(
         let value = if let Some(ref value) = component_argument.value {
             value.clone().resolve(doc, module_property.line_number)?
         } else {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 "Cannot find component argument value for module",
                 doc.name,
                 component_argument.line_number,
+                ftd_p1::codes::MISSING_VALUE,
             );
         };
 
         if let Some(thing) = value.module_thing_optional() {
             thing.clone()
         } else {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 "Cannot find component argument value for module",
                 doc.name,
                 component_argument.line_number,
+                ftd_p1::codes::MISSING_VALUE,
             );
         }
     };
@@ -329,10 +342,11 @@ fn get_module_name_and_thing(
                         thing.extend(default_things);
                         return Ok((name.to_string(), thing.clone()));
                     } else {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!("Expected module, found: {:?}", property_value),
                             doc.name,
                             module_property.line_number,
+                            ftd_p1::codes::INVALID_KIND,
                         );
                     }
                 }
@@ -342,10 +356,11 @@ fn get_module_name_and_thing(
                 {
                     fastn_resolved::Value::Module { name, things } => return Ok((name, things)),
                     t => {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!("Expected module, found: {:?}", t),
                             doc.name,
                             module_property.line_number,
+                            ftd_p1::codes::INVALID_KIND,
                         )
                     }
                 }
@@ -359,10 +374,11 @@ fn get_module_name_and_thing(
         .unwrap()
     {
         fastn_resolved::Value::Module { name, things } => Ok((name, things)),
-        t => ftd::interpreter::utils::e2(
+        t => ftd::interpreter::utils::e2_with_code(
             format!("Expected module, found: {:?}", t),
             doc.name,
             module_property.line_number,
+            ftd_p1::codes::INVALID_KIND,
         ),
     }
 }
@@ -511,26 +527,29 @@ impl PropertyExt for fastn_resolved::Property {
             let first_property = properties.first().unwrap();
 
             if properties.len() > 1 {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     "Can't pass multiple children",
                     doc_id,
                     first_property.line_number,
+                    ftd_p1::codes::NOT_SUPPORTED,
                 );
             }
 
             if !ast_children.is_empty() {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     "Can't have children passed in both subsection and header",
                     doc_id,
                     first_property.line_number,
+                    ftd_p1::codes::DUPLICATE,
                 );
             }
 
             if first_property.condition.is_some() {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     "Not supporting condition for children",
                     doc_id,
                     first_property.line_number,
+                    ftd_p1::codes::NOT_SUPPORTED,
                 );
             }
 
@@ -671,7 +690,7 @@ impl PropertyExt for fastn_resolved::Property {
 
         if let Some(ref v) = ast_property.condition {
             fastn_resolved::Expression::scan_ast_condition(
-                ftd_ast::Condition::new(v, ast_property.line_number),
+                ftd_ast::Condition::new(v, ast_property.line_number, ast_property.condition_span),
                 definition_name_with_arguments,
                 loop_object_name_and_kind,
                 doc,
@@ -709,7 +728,8 @@ impl PropertyExt for fastn_resolved::Property {
                             message: "Can't have multiple kwargs".to_string(),
                             doc_id: doc.name.to_string(),
                             line_number,
-                        });
+                        }
+                        .with_code(ftd_p1::codes::DUPLICATE));
                     }
                     found = true;
                     kw_args = Some(a.to_owned());
@@ -794,13 +814,15 @@ impl PropertyExt for fastn_resolved::Property {
     ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<fastn_resolved::Property>> {
         use ftd::interpreter::PropertyValueExt;
 
+        let (doc_id, line_number, span) = (doc.name, ast_property.line_number, ast_property.span);
         let argument = try_ok_state!(fastn_resolved::Property::get_argument_for_property(
             &ast_property,
             component_name,
             component_arguments,
             kw_args,
             doc,
-        )?);
+        )
+        .map_err(|e| e.or_span(doc_id, line_number, span))?);
 
         let value = try_ok_state!(fastn_resolved::PropertyValue::from_ast_value_with_argument(
             ast_property.value.to_owned(),
@@ -814,7 +836,11 @@ impl PropertyExt for fastn_resolved::Property {
         let condition = if let Some(ref v) = ast_property.condition {
             Some(try_ok_state!(
                 fastn_resolved::Expression::from_ast_condition(
-                    ftd_ast::Condition::new(v, ast_property.line_number),
+                    ftd_ast::Condition::new(
+                        v,
+                        ast_property.line_number,
+                        ast_property.condition_span,
+                    ),
                     definition_name_with_arguments,
                     loop_object_name_and_kind,
                     doc,
@@ -825,13 +851,14 @@ impl PropertyExt for fastn_resolved::Property {
         };
 
         if ast_property.value.is_null() && !argument.kind.is_optional() {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!(
                     "Excepted Value for argument {} in component {}",
                     argument.name, component_name
                 ),
                 doc.name,
                 ast_property.line_number,
+                ftd_p1::codes::MISSING_VALUE,
             );
         }
 
@@ -867,13 +894,16 @@ impl PropertyExt for fastn_resolved::Property {
                 component_arguments
                     .iter()
                     .find(|v| v.is_caption())
-                    .ok_or(ftd::interpreter::Error::ParseError {
-                        message: format!(
-                            "Caption type argument not found for component `{}`",
-                            component_name
-                        ),
-                        doc_id: doc.name.to_string(),
-                        line_number: ast_property.line_number,
+                    .ok_or_else(|| {
+                        ftd::interpreter::Error::ParseError {
+                            message: format!(
+                                "Caption type argument not found for component `{}`",
+                                component_name
+                            ),
+                            doc_id: doc.name.to_string(),
+                            line_number: ast_property.line_number,
+                        }
+                        .with_code(ftd_p1::codes::CAPTION_OR_BODY_NOT_ACCEPTED)
                     })
                     .map(ToOwned::to_owned)?,
             )),
@@ -881,42 +911,71 @@ impl PropertyExt for fastn_resolved::Property {
                 component_arguments
                     .iter()
                     .find(|v| v.is_body())
-                    .ok_or(ftd::interpreter::Error::ParseError {
-                        message: format!(
-                            "Body type argument not found for component `{}`",
-                            component_name
-                        ),
-                        doc_id: doc.name.to_string(),
-                        line_number: ast_property.line_number,
+                    .ok_or_else(|| {
+                        ftd::interpreter::Error::ParseError {
+                            message: format!(
+                                "Body type argument not found for component `{}`",
+                                component_name
+                            ),
+                            doc_id: doc.name.to_string(),
+                            line_number: ast_property.line_number,
+                        }
+                        .with_code(ftd_p1::codes::CAPTION_OR_BODY_NOT_ACCEPTED)
                     })
                     .map(ToOwned::to_owned)?,
             )),
             ftd_ast::PropertySource::Header { name, mutable } => {
                 let (name, remaining) = ftd::interpreter::utils::split_at(name, ".");
-                let mut argument = component_arguments
+                let mut argument = match component_arguments
                     .iter()
                     .find(|v| v.name.eq(name.as_str()))
                     .or(kw_args.as_ref())
-                    .ok_or(ftd::interpreter::Error::ParseError {
-                        message: format!(
-                            "Header type `{}` mutable: `{}` argument not found for component `{}`",
-                            name, mutable, component_name
-                        ),
-                        doc_id: doc.name.to_string(),
-                        line_number: ast_property.line_number,
-                    })?
-                    .to_owned();
+                {
+                    Some(argument) => argument.to_owned(),
+                    None => {
+                        let error = ftd::interpreter::Error::ParseError {
+                            message: format!(
+                                "Header type `{}` mutable: `{}` argument not found for component `{}`",
+                                name, mutable, component_name
+                            ),
+                            doc_id: doc.name.to_string(),
+                            line_number: ast_property.line_number,
+                        }
+                        .with_span(ast_property.span)
+                        .with_code(ftd_p1::codes::UNKNOWN_HEADER);
+                        return Err(
+                            match ftd::interpreter::utils::closest(
+                                name.as_str(),
+                                component_arguments.iter().map(|v| v.name.as_str()),
+                            ) {
+                                Some(closest) => error
+                                    .with_help(format!("did you mean `{closest}`?"))
+                                    .with_suggestion(closest),
+                                None => error,
+                            },
+                        );
+                    }
+                };
                 if !argument.mutable.eq(mutable) {
                     let mutable = if argument.mutable {
                         "mutable"
                     } else {
                         "immutable"
                     };
-                    return ftd::interpreter::utils::e2(
-                        format!("Expected `{}` for {}", mutable, argument.name),
-                        doc.name,
-                        ast_property.line_number,
-                    );
+                    let (component_doc, _) = ftd::interpreter::utils::split_at(component_name, "#");
+                    return Err(ftd::interpreter::Error::ParseError {
+                        message: format!("Expected `{}` for {}", mutable, argument.name),
+                        doc_id: doc.name.to_string(),
+                        line_number: ast_property.line_number,
+                    }
+                    .with_span(ast_property.span)
+                    .with_code(ftd_p1::codes::MUTABILITY_MISMATCH)
+                    .with_label(ftd_p1::Label::new(
+                        component_doc.as_str(),
+                        argument.line_number,
+                        None,
+                        format!("`{}` is {} here", argument.name, mutable).as_str(),
+                    )));
                 }
 
                 if let Some(variant) = remaining {
@@ -1039,12 +1098,18 @@ impl ComponentExt for fastn_resolved::ComponentInvocation {
     }
 
     fn from_ast_component(
-        ast_component: ftd_ast::ComponentInvocation,
+        mut ast_component: ftd_ast::ComponentInvocation,
         definition_name_with_arguments: &mut Option<(&str, &mut [fastn_resolved::Argument])>,
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<
         ftd::interpreter::StateWithThing<fastn_resolved::ComponentInvocation>,
     > {
+        // the errors about the invocation itself point at its name
+        let (doc_id, line_number, span) = (doc.name, ast_component.line_number, ast_component.span);
+        let at_name = |e: ftd::interpreter::Error| e.or_span(doc_id, line_number, span);
+
+        slot_as_loop(&mut ast_component, definition_name_with_arguments, doc).map_err(at_name)?;
+
         let name = doc.resolve_name(ast_component.name.as_str());
 
         // If the component is from `module` type argument
@@ -1060,11 +1125,13 @@ impl ComponentExt for fastn_resolved::ComponentInvocation {
 
         let mut loop_object_name_and_kind = None;
         let iteration = if let Some(v) = ast_component.iteration {
+            let (loop_line_number, loop_span) = (v.line_number, v.span);
             let iteration = try_ok_state!(fastn_resolved::Loop::from_ast_loop(
                 v,
                 definition_name_with_arguments,
                 doc
-            )?);
+            )
+            .map_err(|e| e.or_span(doc_id, loop_line_number, loop_span))?);
             loop_object_name_and_kind = Some((
                 iteration.alias.to_string(),
                 iteration.loop_object_as_argument(doc)?,
@@ -1075,7 +1142,21 @@ impl ComponentExt for fastn_resolved::ComponentInvocation {
             None
         };
 
-        let condition = if let Some(v) = ast_component.condition {
+        let ast_condition = if let Some(pattern) = ast_component.pattern {
+            let (match_line_number, match_span) = (pattern.line_number, pattern.span);
+            Some(try_ok_state!(match_condition(
+                pattern,
+                ast_component.condition,
+                definition_name_with_arguments,
+                &loop_object_name_and_kind,
+                doc,
+            )
+            .map_err(|e| e.or_span(doc_id, match_line_number, match_span))?))
+        } else {
+            ast_component.condition
+        };
+
+        let condition = if let Some(v) = ast_condition {
             Some(try_ok_state!(
                 fastn_resolved::Expression::from_ast_condition(
                     v,
@@ -1107,7 +1188,8 @@ impl ComponentExt for fastn_resolved::ComponentInvocation {
                 &ast_component.properties,
                 &ast_component.children,
                 ast_component.line_number
-            )?
+            )
+            .map_err(at_name)?
         ) {
             return Ok(ftd::interpreter::StateWithThing::new_thing(component));
         }
@@ -1120,14 +1202,16 @@ impl ComponentExt for fastn_resolved::ComponentInvocation {
             &loop_object_name_and_kind,
             doc,
             ast_component.line_number,
-        )?);
+        )
+        .map_err(at_name)?);
         if let Some((_name, arguments)) = definition_name_with_arguments {
             fastn_resolved::ComponentInvocation::assert_no_private_properties_while_invocation(
                 &properties,
                 arguments,
             )?;
-        } else if let ftd::interpreter::Thing::Component(c) =
-            doc.get_thing(name.as_str(), ast_component.line_number)?
+        } else if let ftd::interpreter::Thing::Component(c) = doc
+            .get_thing(name.as_str(), ast_component.line_number)
+            .map_err(at_name)?
         {
             Self::assert_no_private_properties_while_invocation(&properties, &c.arguments)?;
         }
@@ -1154,6 +1238,7 @@ impl ComponentExt for fastn_resolved::ComponentInvocation {
         definition_name_with_arguments: Option<(&str, &[String])>,
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<()> {
+        let doc_id = doc.name;
         fastn_resolved::Property::scan_ast_children(
             ast_component.children,
             definition_name_with_arguments,
@@ -1165,13 +1250,17 @@ impl ComponentExt for fastn_resolved::ComponentInvocation {
                     || ast_component
                         .name
                         .starts_with(format!("{definition}.").as_str()) => {}
-            _ => doc.scan_thing(ast_component.name.as_str(), ast_component.line_number)?,
+            _ => doc
+                .scan_thing(ast_component.name.as_str(), ast_component.line_number)
+                .map_err(|e| e.or_span(doc_id, ast_component.line_number, ast_component.span))?,
         }
 
         let mut loop_object_name_and_kind = None;
         if let Some(v) = ast_component.iteration {
             loop_object_name_and_kind = Some(doc.resolve_name(v.alias.as_str()));
-            fastn_resolved::Loop::scan_ast_loop(v, definition_name_with_arguments, doc)?;
+            let (line_number, span) = (v.line_number, v.span);
+            fastn_resolved::Loop::scan_ast_loop(v, definition_name_with_arguments, doc)
+                .map_err(|e| e.or_span(doc_id, line_number, span))?;
         };
 
         if let Some(v) = ast_component.condition {
@@ -1183,6 +1272,23 @@ impl ComponentExt for fastn_resolved::ComponentInvocation {
             )?;
         }
 
+        if let Some(v) = ast_component.pattern {
+            fastn_resolved::Expression::scan_ast_condition(
+                ftd_ast::Condition::new(
+                    format!(
+                        "{{ {} }}",
+                        v.on.trim_start_matches(ftd::interpreter::utils::REFERENCE)
+                    )
+                    .as_str(),
+                    v.line_number,
+                    v.span,
+                ),
+                definition_name_with_arguments,
+                &loop_object_name_and_kind,
+                doc,
+            )?;
+        }
+
         fastn_resolved::Event::scan_ast_events(
             ast_component.events,
             definition_name_with_arguments,
@@ -1330,7 +1436,8 @@ impl ComponentExt for fastn_resolved::ComponentInvocation {
                                 .to_string(),
                             doc_id: doc.name.clone(),
                             line_number: value.line_number(),
-                        });
+                        }
+                        .with_code(ftd_p1::codes::INVALID_KIND));
                     }
                 };
 
@@ -1405,10 +1512,11 @@ impl ComponentExt for fastn_resolved::ComponentInvocation {
                             {
                                 fastn_resolved::Value::Module { name, things } => (name, things),
                                 t => {
-                                    return ftd::interpreter::utils::e2(
+                                    return ftd::interpreter::utils::e2_with_code(
                                         format!("Expected module, found: {:?}", t),
                                         doc.name,
                                         line_number,
+                                        ftd_p1::codes::INVALID_KIND,
                                     );
                                 }
                             };
@@ -1512,16 +1620,35 @@ impl LoopExt for fastn_resolved::Loop {
             on.set_mutable(true);
         }
 
-        Ok(ftd::interpreter::StateWithThing::new_thing(
-            fastn_resolved::Loop::new(
-                on,
-                doc.resolve_name(ast_loop.alias.as_str()).as_str(),
-                ast_loop
-                    .loop_counter_alias
-                    .map(|loop_counter_alias| doc.resolve_name(loop_counter_alias.as_str())),
-                ast_loop.line_number,
-            ),
-        ))
+        let mut iteration = fastn_resolved::Loop::new(
+            on,
+            doc.resolve_name(ast_loop.alias.as_str()).as_str(),
+            ast_loop
+                .loop_counter_alias
+                .map(|loop_counter_alias| doc.resolve_name(loop_counter_alias.as_str())),
+            ast_loop.line_number,
+        );
+
+        if let Some(key) = ast_loop.key {
+            let loop_object_name_and_kind = Some((
+                iteration.alias.to_string(),
+                iteration.loop_object_as_argument(doc)?,
+                iteration.loop_counter_alias.to_owned(),
+            ));
+            iteration.key = Some(try_ok_state!(
+                fastn_resolved::PropertyValue::from_string_with_argument(
+                    key.as_str(),
+                    doc,
+                    None,
+                    false,
+                    ast_loop.line_number,
+                    definition_name_with_arguments,
+                    &loop_object_name_and_kind,
+                )?
+            ));
+        }
+
+        Ok(ftd::interpreter::StateWithThing::new_thing(iteration))
     }
     fn loop_object_as_argument(
         &self,
@@ -1535,6 +1662,7 @@ impl LoopExt for fastn_resolved::Loop {
             value: Some(self.on.to_owned()),
             line_number: self.on.line_number(),
             access_modifier: Default::default(),
+            is_slot: false,
         })
     }
 
@@ -1542,10 +1670,11 @@ impl LoopExt for fastn_resolved::Loop {
         let kind = self.on.kind();
         match kind {
             fastn_resolved::Kind::List { kind } => Ok(kind.as_ref().to_owned()),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected list kind, found: {:?}", t),
                 doc_id,
                 self.line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1565,6 +1694,17 @@ impl LoopExt for fastn_resolved::Loop {
             &None,
         )?;
 
+        if let Some(key) = ast_loop.key {
+            let alias = doc.resolve_name(ast_loop.alias.as_str());
+            fastn_resolved::PropertyValue::scan_string_with_argument(
+                key.as_str(),
+                doc,
+                ast_loop.line_number,
+                definition_name_with_arguments,
+                &Some(alias),
+            )?;
+        }
+
         Ok(())
     }
     fn children(
@@ -1578,10 +1718,11 @@ impl LoopExt for fastn_resolved::Loop {
         if let fastn_resolved::Value::List { data, kind } = value {
             Ok((data, kind))
         } else {
-            ftd::interpreter::utils::e2(
+            ftd::interpreter::utils::e2_with_code(
                 format!("Expected list type data, found: {:?}", self.on),
                 doc.name,
                 self.line_number,
+                ftd_p1::codes::INVALID_KIND,
             )
         }
     }
@@ -1672,14 +1813,17 @@ impl EventExt for fastn_resolved::Event {
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<Vec<fastn_resolved::Event>>>
     {
+        let doc_id = doc.name;
         let mut events = vec![];
         for event in ast_events {
+            let (line_number, span) = (event.line_number, event.span);
             events.push(try_ok_state!(fastn_resolved::Event::from_ast_event(
                 event,
                 definition_name_with_arguments,
                 loop_object_name_and_kind,
                 doc,
-            )?));
+            )
+            .map_err(|e| e.or_span(doc_id, line_number, span))?));
         }
         Ok(ftd::interpreter::StateWithThing::new_thing(events))
     }
@@ -1690,13 +1834,16 @@ impl EventExt for fastn_resolved::Event {
         loop_object_name_and_kind: &Option<String>,
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<()> {
+        let doc_id = doc.name;
         for event in ast_events {
+            let (line_number, span) = (event.line_number, event.span);
             fastn_resolved::Event::scan_ast_event(
                 event,
                 definition_name_with_arguments,
                 loop_object_name_and_kind,
                 doc,
-            )?;
+            )
+            .map_err(|e| e.or_span(doc_id, line_number, span))?;
         }
         Ok(())
     }
@@ -1744,6 +1891,51 @@ impl EventNameExt for fastn_resolved::EventName {
             "change" => Ok(fastn_resolved::EventName::Change),
             "blur" => Ok(fastn_resolved::EventName::Blur),
             "focus" => Ok(fastn_resolved::EventName::Focus),
+            "visible" => Ok(fastn_resolved::EventName::Visible),
+            "hidden" => Ok(fastn_resolved::EventName::Hidden),
+            "submit" => Ok(fastn_resolved::EventName::Submit),
+            "scroll" => Ok(fastn_resolved::EventName::Scroll(
+                fastn_resolved::EventName::DEFAULT_SCROLL_THROTTLE,
+            )),
+            t if t.starts_with("scroll[") && t.ends_with(']') => Ok(
+                fastn_resolved::EventName::Scroll(duration("scroll", t, doc_id, line_number)?),
+            ),
+            t if t.starts_with("interval[") && t.ends_with(']') => Ok(
+                fastn_resolved::EventName::Interval(duration("interval", t, doc_id, line_number)?),
+            ),
+            t if t.starts_with("timeout[") && t.ends_with(']') => Ok(
+                fastn_resolved::EventName::Timeout(duration("timeout", t, doc_id, line_number)?),
+            ),
+            t if t.starts_with("keydown[") && t.ends_with(']') => {
+                let keys = t
+                    .trim_start_matches("keydown[")
+                    .trim_end_matches(']')
+                    .split('-')
+                    .filter(|v| !v.is_empty())
+                    .map(|v| v.to_string())
+                    .collect_vec();
+                if keys.is_empty() {
+                    return ftd::interpreter::utils::e2_with_code(
+                        "`on-keydown` needs the keys, like `on-keydown[ctrl-enter]`",
+                        doc_id,
+                        line_number,
+                        ftd_p1::codes::INVALID_EVENT,
+                    );
+                }
+                Ok(fastn_resolved::EventName::KeyDown(keys))
+            }
+            "interval" | "timeout" => ftd::interpreter::utils::e2_with_code(
+                format!("`on-{e}` needs a duration in milliseconds, like `on-{e}[1000]`"),
+                doc_id,
+                line_number,
+                ftd_p1::codes::INVALID_EVENT,
+            ),
+            "keydown" => ftd::interpreter::utils::e2_with_code(
+                "`on-keydown` needs the keys, like `on-keydown[ctrl-enter]`",
+                doc_id,
+                line_number,
+                ftd_p1::codes::INVALID_EVENT,
+            ),
             t if t.starts_with("global-key[") && t.ends_with(']') => {
                 let keys = t
                     .trim_start_matches("global-key[")
@@ -1783,13 +1975,42 @@ impl EventNameExt for fastn_resolved::EventName {
                     .to_string();
                 Ok(fastn_resolved::EventName::RivePause(pause))
             }
-            t => {
-                ftd::interpreter::utils::e2(format!("`{}` event not found", t), doc_id, line_number)
-            }
+            t => ftd::interpreter::utils::e2_with_code(
+                format!("`{}` event not found", t),
+                doc_id,
+                line_number,
+                ftd_p1::codes::INVALID_EVENT,
+            ),
         }
     }
 }
 
+/// The duration in milliseconds of an event like `interval[1000]`.
+fn duration(
+    event: &str,
+    e: &str,
+    doc_id: &str,
+    line_number: usize,
+) -> ftd::interpreter::Result<i64> {
+    let value = e
+        .trim_start_matches(event)
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .trim();
+    match value.parse::<i64>() {
+        Ok(duration) if duration > 0 => Ok(duration),
+        _ => ftd::interpreter::utils::e2_with_code(
+            format!(
+                "`on-{event}` takes a duration in milliseconds, like `on-{event}[1000]`, found: \
+                `{value}`"
+            ),
+            doc_id,
+            line_number,
+            ftd_p1::codes::INVALID_EVENT,
+        ),
+    }
+}
+
 pub trait PropertySourceExt {
     fn from_ast(item: ftd_ast::PropertySource) -> Self;
 }
@@ -1806,6 +2027,196 @@ impl PropertySourceExt for fastn_resolved::PropertySource {
     }
 }
 
+const SLOT_ITEM: &str = "SLOT-ITEM";
+
+/// Desugars `-- my-page.header:` inside the definition of `my-page`, where `header` is a `slot`
+/// (or any `ftd.ui list`) argument, into a loop rendering every item of the slot. Going through
+/// the loop keeps the rendered items in sync with the list.
+fn slot_as_loop(
+    ast_component: &mut ftd_ast::ComponentInvocation,
+    definition_name_with_arguments: &Option<(&str, &mut [fastn_resolved::Argument])>,
+    doc: &ftd::interpreter::TDoc,
+) -> ftd::interpreter::Result<()> {
+    let (definition_name, arguments) = match definition_name_with_arguments {
+        Some((definition_name, arguments)) => (definition_name, arguments),
+        None => return Ok(()),
+    };
+    let slot = match ast_component
+        .name
+        .strip_prefix(format!("{definition_name}.").as_str())
+    {
+        Some(slot) => slot,
+        None => return Ok(()),
+    };
+    if !arguments
+        .iter()
+        .any(|a| a.name.eq(slot) && a.kind.is_list() && a.kind.kind.ref_inner_list().is_ui())
+    {
+        return Ok(());
+    }
+    if ast_component.iteration.is_some() {
+        return ftd::interpreter::utils::e2_with_code(
+            format!(
+                "Can't use `for` while rendering slot `{}`",
+                ast_component.name
+            ),
+            doc.name,
+            ast_component.line_number,
+            ftd_p1::codes::NOT_SUPPORTED,
+        );
+    }
+    ast_component.iteration = Some(ftd_ast::Loop {
+        on: format!(
+            "{}{}",
+            ftd::interpreter::utils::REFERENCE,
+            ast_component.name
+        ),
+        alias: SLOT_ITEM.to_string(),
+        loop_counter_alias: None,
+        key: None,
+        line_number: ast_component.line_number,
+        span: ast_component.span,
+    });
+    ast_component.name = SLOT_ITEM.to_string();
+    Ok(())
+}
+
+/// Desugars `match: $status is status.error` into the condition under which the component is
+/// shown, and-ed with the component's own `if` condition, if any. Constant variants are
+/// compared by value, the others are checked with `ftd.is_variant`.
+fn match_condition(
+    pattern: ftd_ast::Match,
+    condition: Option<ftd_ast::Condition>,
+    definition_name_with_arguments: &mut Option<(&str, &mut [fastn_resolved::Argument])>,
+    loop_object_name_and_kind: &Option<(String, fastn_resolved::Argument, Option<String>)>,
+    doc: &mut ftd::interpreter::TDoc,
+) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<ftd_ast::Condition>> {
+    let line_number = pattern.line_number;
+    let on = pattern
+        .on
+        .trim_start_matches(ftd::interpreter::utils::REFERENCE);
+    let reference = doc.resolve_reference_name(pattern.on.as_str(), line_number)?;
+    let (_, kind, _) = try_ok_state!(doc.get_kind_with_argument(
+        reference.as_str(),
+        line_number,
+        definition_name_with_arguments,
+        loop_object_name_and_kind,
+    )?);
+    let or_type_name = match kind.kind.inner() {
+        fastn_resolved::Kind::OrType { name, .. } => name,
+        t => {
+            return ftd::interpreter::utils::e2_with_code(
+                format!(
+                    "Expected or-type for `match`, found `{}`: `{:?}`",
+                    pattern.on, t
+                ),
+                doc.name,
+                line_number,
+                ftd_p1::codes::INVALID_KIND,
+            )
+        }
+    };
+    let or_type = try_ok_state!(doc.search_or_type(or_type_name.as_str(), line_number)?);
+    doc.set_or_type_matched(or_type.name.as_str());
+    let variant_name = ftd_ast::Match::variant_name(pattern.variant.as_str());
+    if variant_name.eq(&pattern.variant) {
+        return ftd::interpreter::utils::e2_with_code(
+            format!(
+                "Expected variant as `<or-type>.<variant>`, found: `{}`. Help: use `{}.{}`",
+                pattern.variant,
+                or_type_name
+                    .rsplit_once('#')
+                    .map_or(or_type_name.as_str(), |(_, name)| name),
+                pattern.variant
+            ),
+            doc.name,
+            line_number,
+            ftd_p1::codes::INVALID_VARIANT,
+        );
+    }
+    let variant = match or_type
+        .variants
+        .iter()
+        .find(|v| ftd_ast::Match::variant_name(&v.name()).eq(variant_name))
+    {
+        Some(variant) => variant,
+        None => {
+            return ftd::interpreter::utils::e2_with_code(
+                format!(
+                    "`{}` is not a variant of or-type `{}`",
+                    pattern.variant, or_type.name
+                ),
+                doc.name,
+                line_number,
+                ftd_p1::codes::INVALID_VARIANT,
+            )
+        }
+    };
+
+    if !pattern.arms.is_empty() {
+        let missing = or_type
+            .variants
+            .iter()
+            .map(|v| ftd_ast::Match::variant_name(&v.name()).to_string())
+            .filter(|v| {
+                !pattern
+                    .arms
+                    .iter()
+                    .any(|arm| ftd_ast::Match::variant_name(arm).eq(v))
+            })
+            .map(|v| format!("`{v}`"))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            doc.warn(
+                line_number,
+                ftd_p1::codes::NON_EXHAUSTIVE_MATCH,
+                format!(
+                    "non-exhaustive match on `{}`, missing variants: {}",
+                    pattern.on,
+                    missing.join(", ")
+                ),
+            );
+        }
+    }
+
+    let expression = match variant {
+        fastn_resolved::OrTypeVariant::Constant(c) => {
+            if let Some(alias) = pattern.alias {
+                return ftd::interpreter::utils::e2_with_code(
+                    format!(
+                        "Constant variant `{}` doesn't carry a value to bind to `${}`",
+                        c.name, alias
+                    ),
+                    doc.name,
+                    line_number,
+                    ftd_p1::codes::INVALID_VARIANT,
+                );
+            }
+            format!("{} == {}", on, pattern.variant)
+        }
+        fastn_resolved::OrTypeVariant::AnonymousRecord(_)
+        | fastn_resolved::OrTypeVariant::Regular(_) => {
+            format!("ftd.is_variant({}, \"{}\")", on, variant_name)
+        }
+    };
+
+    let expression = match condition {
+        Some(condition) => {
+            let condition_expression = condition.expression.trim();
+            let condition_expression = condition_expression
+                .strip_prefix('{')
+                .and_then(|v| v.strip_suffix('}'))
+                .unwrap_or(condition_expression);
+            format!("{{ ({}) && {} }}", condition_expression.trim(), expression)
+        }
+        None => format!("{{ {} }}", expression),
+    };
+
+    Ok(ftd::interpreter::StateWithThing::new_thing(
+        ftd_ast::Condition::new(expression.as_str(), line_number, pattern.span),
+    ))
+}
+
 fn get_kw_args_name(
     component_arguments: &[fastn_resolved::Field],
     property: &fastn_resolved::Property,
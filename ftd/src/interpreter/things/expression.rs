@@ -39,26 +39,31 @@ impl ExpressionExt for fastn_resolved::Expression {
         loop_object_name_and_kind: &Option<String>,
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<()> {
+        let (doc_id, line_number, span) = (doc.name, condition.line_number, condition.span);
         if let Some(expression_mode) = get_expression_mode(condition.expression.as_str()) {
-            let mut node = fastn_resolved::evalexpr::build_operator_tree(expression_mode.as_str())?;
+            let mut node = fastn_resolved::evalexpr::build_operator_tree(expression_mode.as_str())
+                .map_err(|e| ftd::interpreter::Error::from(e).with_span(span))?;
             fastn_resolved::Expression::scan_references(
                 &mut node,
                 definition_name_with_arguments,
                 loop_object_name_and_kind,
                 doc,
                 condition.line_number,
-            )?;
+            )
+            .map_err(|e| e.or_span(doc_id, line_number, span))?;
 
             return Ok(());
         }
-        ftd::interpreter::utils::e2(
+        ftd::interpreter::utils::e2_with_code(
             format!(
                 "Expected condition in expression mode, found: {}",
                 condition.expression
             ),
             doc.name,
             condition.line_number,
+            ftd_p1::codes::INVALID_KIND,
         )
+        .map_err(|e| e.with_span(span))
     }
 
     fn from_ast_condition(
@@ -68,28 +73,33 @@ impl ExpressionExt for fastn_resolved::Expression {
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<fastn_resolved::Expression>>
     {
+        let (doc_id, line_number, span) = (doc.name, condition.line_number, condition.span);
         if let Some(expression_mode) = get_expression_mode(condition.expression.as_str()) {
-            let mut node = fastn_resolved::evalexpr::build_operator_tree(expression_mode.as_str())?;
+            let mut node = fastn_resolved::evalexpr::build_operator_tree(expression_mode.as_str())
+                .map_err(|e| ftd::interpreter::Error::from(e).with_span(span))?;
             let references = try_ok_state!(fastn_resolved::Expression::get_references(
                 &mut node,
                 definition_name_with_arguments,
                 loop_object_name_and_kind,
                 doc,
                 condition.line_number,
-            )?);
+            )
+            .map_err(|e| e.or_span(doc_id, line_number, span))?);
 
             return Ok(ftd::interpreter::StateWithThing::new_thing(
                 fastn_resolved::Expression::new(node, references, condition.line_number),
             ));
         }
-        ftd::interpreter::utils::e2(
+        ftd::interpreter::utils::e2_with_code(
             format!(
                 "Expected condition in expression mode, found: {}",
                 condition.expression
             ),
             doc.name,
             condition.line_number,
+            ftd_p1::codes::INVALID_KIND,
         )
+        .map_err(|e| e.with_span(span))
     }
 
     fn scan_references(
@@ -151,10 +161,15 @@ impl ExpressionExt for fastn_resolved::Expression {
                             ftd::interpreter::StateWithThing::Thing(thing)
                                 if infer_from_value.kind().inner().is_or_type() =>
                             {
-                                if thing.kind().inner().eq(&infer_from_value.kind().inner()) {
+                                // the variants of an or-type compare with each other
+                                if thing
+                                    .kind()
+                                    .inner()
+                                    .is_same_as(&infer_from_value.kind().inner())
+                                {
                                     ftd::interpreter::StateWithThing::new_thing(thing)
                                 } else {
-                                    return ftd::interpreter::utils::e2(format!("Invalid value on the right-hand side. Expected \"{}\" but found \"{}\".", infer_from_value.kind().inner().get_name(), thing.kind().inner().get_name()), doc.name, line_number);
+                                    return ftd::interpreter::utils::e2_with_code(format!("Invalid value on the right-hand side. Expected \"{}\" but found \"{}\".", infer_from_value.kind().inner().get_name(), thing.kind().inner().get_name()), doc.name, line_number, ftd_p1::codes::INVALID_KIND);
                                 }
                             }
                             t => t,
@@ -218,7 +233,7 @@ impl ExpressionExt for fastn_resolved::Expression {
             );
         }
         let node = update_node_with_value(&self.expression, &values);
-        let mut context = ftd::interpreter::default::default_context()?;
+        let mut context = doc.evalexpr_context()?;
         Ok(node.eval_boolean_with_context_mut(&mut context)?)
     }
 
@@ -56,7 +56,8 @@ impl FunctionExt for fastn_resolved::Function {
                         value: js.to_string(),
                         line_number: function.line_number(),
                         source: ftd_ast::ValueSource::Default,
-                        condition: None
+                        condition: None,
+                        span: Default::default(),
                     },
                     doc,
                     false,
@@ -117,29 +118,30 @@ impl FunctionExt for fastn_resolved::Function {
 
         let mut context: ftd::Map<VariableContext> = Default::default();
         for argument in self.arguments.iter() {
-            let function_value =
-                values
-                    .get(argument.name.as_str())
-                    .ok_or(ftd::interpreter::Error::ParseError {
-                        message: format!(
-                            "{} argument not found for function call `{}`",
-                            argument.name, self.name
-                        ),
-                        doc_id: doc.name.to_string(),
-                        line_number,
-                    })?;
+            let function_value = values.get(argument.name.as_str()).ok_or(
+                ftd::interpreter::Error::ParseError {
+                    message: format!(
+                        "{} argument not found for function call `{}`",
+                        argument.name, self.name
+                    ),
+                    doc_id: doc.name.to_string(),
+                    line_number,
+                }
+                .with_code(ftd_p1::codes::NAME_NOT_FOUND),
+            )?;
             if !argument.mutable.eq(&function_value.is_mutable()) {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     format!(
                         "Mutability conflict for argument `{}` in function `{}`",
                         argument.name, self.name
                     ),
                     doc.name,
                     line_number,
+                    ftd_p1::codes::NOT_MUTABLE,
                 );
             }
             if !argument.kind.kind.is_same_as(&function_value.kind()) {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     format!(
                         "Expected kind: `{:?}` found: `{:?}`",
                         argument.kind.kind,
@@ -147,6 +149,7 @@ impl FunctionExt for fastn_resolved::Function {
                     ),
                     doc.name,
                     line_number,
+                    ftd_p1::codes::INVALID_KIND,
                 );
             }
 
@@ -162,7 +165,7 @@ impl FunctionExt for fastn_resolved::Function {
             );
         }
 
-        let mut evalexpr_context = ftd::interpreter::default::default_context()?;
+        let mut evalexpr_context = doc.evalexpr_context()?;
         for (key, context) in context.iter() {
             evalexpr_context.set_value(key.to_string(), context.value.to_owned())?;
         }
@@ -180,7 +183,7 @@ impl FunctionExt for fastn_resolved::Function {
                     let value = fastn_resolved::Value::from_evalexpr_value(
                         evalexpr_context.get_value(key.as_str()).unwrap().clone(),
                         &context.kind,
-                        doc.name,
+                        doc,
                         line_number,
                     )?;
                     // TODO: insert new value in doc.bag
@@ -202,7 +205,7 @@ impl FunctionExt for fastn_resolved::Function {
             return Ok(Some(fastn_resolved::Value::from_evalexpr_value(
                 eval,
                 &self.return_kind.kind,
-                doc.name,
+                doc,
                 line_number,
             )?));
         }
@@ -307,17 +310,19 @@ impl FunctionCallExt for fastn_resolved::FunctionCall {
                         ));
                         resolved_function_name = format!("{name}#{function}");
                     } else {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!("No function found: {}", expression),
                             doc.name,
                             argument.line_number,
+                            ftd_p1::codes::NAME_NOT_FOUND,
                         );
                     }
                 } else {
-                    return ftd::interpreter::utils::e2(
+                    return ftd::interpreter::utils::e2_with_code(
                         format!("Default value not found for module {}", argument.name),
                         doc.name,
                         argument.line_number,
+                        ftd_p1::codes::MISSING_VALUE,
                     );
                 }
             }
@@ -344,13 +349,14 @@ impl FunctionCallExt for fastn_resolved::FunctionCall {
                     }
                 }) {
                 if !(mutable.eq(&argument.mutable)) {
-                    return ftd::interpreter::utils::e2(
+                    return ftd::interpreter::utils::e2_with_code(
                         format!(
                             "Mutability conflict in argument `{}` for function `{}`",
                             property_key, resolved_function_name
                         ),
                         doc.name,
                         line_number,
+                        ftd_p1::codes::NOT_MUTABLE,
                     );
                 }
                 try_ok_state!(fastn_resolved::PropertyValue::from_ast_value_with_argument(
@@ -358,7 +364,8 @@ impl FunctionCallExt for fastn_resolved::FunctionCall {
                         value: property,
                         line_number,
                         source: ftd_ast::ValueSource::Default,
-                        condition: None
+                        condition: None,
+                        span: Default::default(),
                     },
                     doc,
                     mutable,
@@ -374,13 +381,14 @@ impl FunctionCallExt for fastn_resolved::FunctionCall {
                         argument.line_number,
                     ),
                     _ => {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!(
                                 "Cannot find argument `{}` in function `{}`",
                                 argument.name, function_name
                             ),
                             doc.name,
                             line_number,
+                            ftd_p1::codes::NAME_NOT_FOUND,
                         )
                     }
                 }
@@ -13,10 +13,11 @@ impl KindExt for fastn_resolved::Kind {
     ) -> ftd::interpreter::Result<fastn_resolved::Kind> {
         match &self {
             fastn_resolved::Kind::List { kind } => Ok(kind.as_ref().clone()),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected List, found: `{:?}`", t),
                 doc_name,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -46,9 +47,20 @@ impl KindDataExt for fastn_resolved::KindData {
         line_number: usize,
     ) -> ftd::interpreter::Result<()> {
         let ast_kind = var_kind.kind;
+        if let Some((key, value)) = get_map_kinds(ast_kind.as_str()) {
+            for k in [key, value] {
+                fastn_resolved::KindData::scan_ast_kind(
+                    ftd_ast::VariableKind::get_kind(k.as_str(), doc.name, line_number)?,
+                    known_kinds,
+                    doc,
+                    line_number,
+                )?;
+            }
+            return Ok(());
+        }
         match ast_kind.as_ref() {
-            "string" | "object" | "integer" | "decimal" | "boolean" | "void" | "ftd.ui"
-            | "children" => Ok(()),
+            "string" | "object" | "integer" | "decimal" | "boolean" | "datetime" | "date"
+            | "void" | "ftd.ui" | "children" | "slot" => Ok(()),
             k if known_kinds.contains_key(k) => Ok(()),
             k => doc.scan_thing(k, line_number),
         }
@@ -85,27 +97,81 @@ impl KindDataExt for fastn_resolved::KindData {
             return Ok(ftd::interpreter::StateWithThing::new_thing(kind_data));
         }
         let kind = match ast_kind.as_ref() {
+            k if get_map_kinds(k).is_some() => {
+                let (key, value) = get_map_kinds(k).unwrap();
+                let key = try_ok_state!(fastn_resolved::KindData::from_ast_kind(
+                    ftd_ast::VariableKind::get_kind(key.as_str(), doc.name, line_number)?,
+                    known_kinds,
+                    doc,
+                    line_number,
+                )?)
+                .kind;
+                if !key.is_string() && !key.is_integer() {
+                    return ftd::interpreter::utils::e2_with_code(
+                        format!("Map key can only be `string` or `integer`, found: `{}`", k),
+                        doc.name,
+                        line_number,
+                        ftd_p1::codes::INVALID_KIND,
+                    );
+                }
+                let value = try_ok_state!(fastn_resolved::KindData::from_ast_kind(
+                    ftd_ast::VariableKind::get_kind(value.as_str(), doc.name, line_number)?,
+                    known_kinds,
+                    doc,
+                    line_number,
+                )?)
+                .kind;
+                fastn_resolved::Kind::map(key, value)
+            }
             "string" => fastn_resolved::Kind::string(),
             "object" => fastn_resolved::Kind::object(),
             "integer" => fastn_resolved::Kind::integer(),
             "decimal" => fastn_resolved::Kind::decimal(),
             "boolean" => fastn_resolved::Kind::boolean(),
+            "datetime" => fastn_resolved::Kind::datetime(),
+            "date" => fastn_resolved::Kind::date(),
             "void" => fastn_resolved::Kind::void(),
             "ftd.ui" => fastn_resolved::Kind::ui(),
             "module" => fastn_resolved::Kind::module(),
             "kw-args" => fastn_resolved::Kind::kwargs(),
             "children" => {
                 if let Some(modifier) = var_kind.modifier {
-                    return ftd::interpreter::utils::e2(
+                    return ftd::interpreter::utils::e2_with_code(
                         format!("Can't add modifier `{:?}`", modifier),
                         doc.name,
                         line_number,
+                        ftd_p1::codes::INVALID_KIND,
                     );
                 }
                 fastn_resolved::Kind::List {
                     kind: Box::new(fastn_resolved::Kind::subsection_ui()),
                 }
             }
+            "slot" => {
+                // An `optional slot` is a plain `ftd.ui list`, which defaults to
+                // an empty list, while a `slot` has to be filled by the caller, see
+                // `fastn_resolved::Field::is_slot`.
+                if let Some(modifier) = var_kind
+                    .modifier
+                    .filter(|m| !matches!(m, ftd_ast::VariableModifier::Optional))
+                {
+                    return ftd::interpreter::utils::e2_with_code(
+                        format!("Can't add modifier `{:?}` to `slot`", modifier),
+                        doc.name,
+                        line_number,
+                        ftd_p1::codes::INVALID_KIND,
+                    );
+                }
+                return Ok(ftd::interpreter::StateWithThing::new_thing(
+                    fastn_resolved::KindData {
+                        kind: fastn_resolved::Kind::List {
+                            kind: Box::new(fastn_resolved::Kind::ui()),
+                        },
+                        caption,
+                        body,
+                    },
+                ));
+            }
             k if known_kinds.contains_key(k) => known_kinds.get(k).unwrap().to_owned(),
             k => match try_ok_state!(doc.search_thing(k, line_number)?) {
                 ftd::interpreter::Thing::Record(r) => fastn_resolved::Kind::record(r.name.as_str()),
@@ -122,10 +188,11 @@ impl KindDataExt for fastn_resolved::KindData {
                 }
                 ftd::interpreter::Thing::Variable(v) => v.kind.kind,
                 t => {
-                    return ftd::interpreter::utils::e2(
+                    return ftd::interpreter::utils::e2_with_code(
                         format!("Can't get find for `{:?}`", t),
                         doc.name,
                         line_number,
+                        ftd_p1::codes::INVALID_KIND,
                     )
                 }
             },
@@ -153,6 +220,31 @@ impl KindDataExt for fastn_resolved::KindData {
     }
 }
 
+/// Splits `map<K, V>` into `K` and `V`. Only the first top level comma is
+/// considered, so `map<string, map<string, integer>>` works as expected.
+pub fn get_map_kinds(s: &str) -> Option<(String, String)> {
+    let inner = s
+        .trim()
+        .strip_prefix("map<")
+        .and_then(|v| v.strip_suffix('>'))?;
+    let mut depth = 0;
+    for (index, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                let (key, value) = (inner[..index].trim(), inner[index + 1..].trim());
+                if key.is_empty() || value.is_empty() {
+                    return None;
+                }
+                return Some((key.to_string(), value.to_string()));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
 pub fn check_for_caption_and_body(s: &mut String) -> (bool, bool) {
     use itertools::Itertools;
 
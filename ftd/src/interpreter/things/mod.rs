@@ -41,10 +41,11 @@ impl ThingExt for Thing {
     ) -> ftd::interpreter::Result<fastn_resolved::Variable> {
         match self {
             ftd::interpreter::Thing::Variable(v) => Ok(v),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected Variable, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -56,10 +57,15 @@ impl ThingExt for Thing {
     ) -> ftd::interpreter::Result<fastn_resolved::Record> {
         match self {
             ftd::interpreter::Thing::Record(v) => Ok(v.clone()),
-            t => ftd::interpreter::utils::e2(
+            ftd::interpreter::Thing::OrTypeWithVariant {
+                variant: fastn_resolved::OrTypeVariant::AnonymousRecord(v),
+                ..
+            } => Ok(v.clone()),
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected Record, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -71,10 +77,11 @@ impl ThingExt for Thing {
     ) -> ftd::interpreter::Result<fastn_resolved::WebComponentDefinition> {
         match self {
             ftd::interpreter::Thing::WebComponent(v) => Ok(v.to_owned()),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected WebComponent, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -86,10 +93,11 @@ impl ThingExt for Thing {
     ) -> ftd::interpreter::Result<fastn_resolved::Function> {
         match self {
             ftd::interpreter::Thing::Function(v) => Ok(v.to_owned()),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected Function, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -60,16 +60,18 @@ pub trait OrTypeVariantExt {
         doc_name: &str,
         line_number: usize,
     ) -> ftd::interpreter::Result<ftd::interpreter::Thing>;
+    fn payload_kind(&self, doc_id: &str) -> ftd::interpreter::Result<fastn_resolved::KindData>;
 }
 
 impl OrTypeVariantExt for fastn_resolved::OrTypeVariant {
     fn ok_constant(&self, doc_id: &str) -> ftd::interpreter::Result<&fastn_resolved::Field> {
         match self {
             fastn_resolved::OrTypeVariant::Constant(c) => Ok(c),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected constant, found: {:?}", t),
                 doc_id,
                 t.line_number(),
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -144,6 +146,22 @@ impl OrTypeVariantExt for fastn_resolved::OrTypeVariant {
             }
         }
     }
+
+    /// Kind of the value carried by the variant, i.e. what `$value.<variant>` refers to
+    fn payload_kind(&self, doc_id: &str) -> ftd::interpreter::Result<fastn_resolved::KindData> {
+        match self {
+            fastn_resolved::OrTypeVariant::AnonymousRecord(r) => {
+                Ok(fastn_resolved::Kind::record(r.name.as_str()).into_kind_data())
+            }
+            fastn_resolved::OrTypeVariant::Regular(r) => Ok(r.kind.to_owned()),
+            fastn_resolved::OrTypeVariant::Constant(c) => ftd::interpreter::utils::e2_with_code(
+                format!("Constant variant `{}` doesn't carry a value", c.name),
+                doc_id,
+                c.line_number,
+                ftd_p1::codes::INVALID_VARIANT,
+            ),
+        }
+    }
 }
 
 fn validate_constant_variant(
@@ -153,10 +171,11 @@ fn validate_constant_variant(
     if variant.value.is_none()
         && !(variant.kind.is_void() || variant.kind.is_optional() || variant.kind.is_list())
     {
-        return ftd::interpreter::utils::e2(
+        return ftd::interpreter::utils::e2_with_code(
             format!("The constant variant `{}` can't be empty", variant.name),
             doc.name,
             variant.line_number,
+            ftd_p1::codes::INVALID_VARIANT,
         );
     }
     Ok(())
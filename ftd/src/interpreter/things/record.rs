@@ -87,7 +87,7 @@ impl RecordExt for fastn_resolved::Record {
 
         let field = self.fields.iter().filter(|v| v.name.eq(name)).collect_vec();
         if field.is_empty() {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!(
                     "Cannot find the field `{}` for record `{}`",
                     name, self.name
@@ -95,11 +95,12 @@ impl RecordExt for fastn_resolved::Record {
                 .as_str(),
                 doc_id,
                 line_number,
+                ftd_p1::codes::NAME_NOT_FOUND,
             );
         }
 
         if field.len() > 1 {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!(
                     "Multiple fields `{}` for record `{}` found",
                     name, self.name
@@ -107,6 +108,7 @@ impl RecordExt for fastn_resolved::Record {
                 .as_str(),
                 doc_id,
                 line_number,
+                ftd_p1::codes::DUPLICATE,
             );
         }
 
@@ -232,8 +234,11 @@ impl FieldExt for fastn_resolved::Field {
         doc: &mut ftd::interpreter::TDoc,
         known_kinds: &ftd::Map<fastn_resolved::Kind>,
     ) -> ftd::interpreter::Result<()> {
+        let doc_id = doc.name;
         for field in fields {
-            fastn_resolved::Field::scan_ast_field(field, doc, known_kinds)?;
+            let (line_number, span) = (field.line_number, field.span);
+            fastn_resolved::Field::scan_ast_field(field, doc, known_kinds)
+                .map_err(|e| e.or_span(doc_id, line_number, span))?;
         }
         Ok(())
     }
@@ -245,10 +250,13 @@ impl FieldExt for fastn_resolved::Field {
     ) -> ftd::interpreter::Result<
         ftd::interpreter::StateWithThing<Vec<ftd::executor::FieldWithValue>>,
     > {
+        let doc_id = doc.name;
         let mut fields_with_resolved_kinds = vec![];
         for field in ast_fields {
+            let (line_number, span) = (field.line_number, field.span);
             fields_with_resolved_kinds.push(try_ok_state!(
-                fastn_resolved::Field::from_ast_field_kind(field, doc, known_kinds)?
+                fastn_resolved::Field::from_ast_field_kind(field, doc, known_kinds)
+                    .map_err(|e| e.or_span(doc_id, line_number, span))?
             ));
         }
         Ok(ftd::interpreter::StateWithThing::new_thing(
@@ -343,6 +351,7 @@ impl FieldExt for fastn_resolved::Field {
     ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<fastn_resolved::Field>> {
         use ftd::interpreter::{KindDataExt, PropertyValueExt};
 
+        let is_slot = is_slot(&field.kind);
         let kind = try_ok_state!(fastn_resolved::KindData::from_ast_kind(
             field.kind,
             known_kinds,
@@ -371,6 +380,7 @@ impl FieldExt for fastn_resolved::Field {
                 value,
                 line_number: field.line_number,
                 access_modifier: access_modifier(field.access_modifier),
+                is_slot,
             },
         ))
     }
@@ -384,6 +394,7 @@ impl FieldExt for fastn_resolved::Field {
     > {
         use ftd::interpreter::KindDataExt;
 
+        let is_slot = is_slot(&field.kind);
         let kind = try_ok_state!(fastn_resolved::KindData::from_ast_kind(
             field.kind,
             known_kinds,
@@ -399,6 +410,7 @@ impl FieldExt for fastn_resolved::Field {
                 value: None,
                 line_number: field.line_number,
                 access_modifier: access_modifier(field.access_modifier),
+                is_slot,
             },
             field.value,
         )))
@@ -486,14 +498,17 @@ impl FieldExt for fastn_resolved::Field {
                             .trim_start_matches(format!("{}.", name).as_str())
                             .eq(variant_name.as_str())
                     })
-                    .ok_or(ftd::interpreter::Error::ParseError {
-                        message: format!(
-                            "Cannot find variant `{}` for or-type `{}`",
-                            variant, name
-                        ),
-                        doc_id: doc.name.to_string(),
-                        line_number,
-                    })?;
+                    .ok_or(
+                        ftd::interpreter::Error::ParseError {
+                            message: format!(
+                                "Cannot find variant `{}` for or-type `{}`",
+                                variant, name
+                            ),
+                            doc_id: doc.name.to_string(),
+                            line_number,
+                        }
+                        .with_code(ftd_p1::codes::INVALID_VARIANT),
+                    )?;
 
                 check_variant_if_constant(or_variant, remaining, doc)?;
                 let variant = Some(format!("{}.{}", name, variant));
@@ -502,13 +517,14 @@ impl FieldExt for fastn_resolved::Field {
                 *full_variant = variant;
                 Ok(ftd::interpreter::StateWithThing::new_thing(()))
             }
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!(
                     "Expected or-type for variant `{}`, found: `{:?}`",
                     variant, t
                 ),
                 doc.name,
                 line_number,
+                ftd_p1::codes::INVALID_VARIANT,
             ),
         }
     }
@@ -521,13 +537,19 @@ fn access_modifier(am: ftd_p1::AccessModifier) -> fastn_resolved::AccessModifier
     }
 }
 
+/// A `slot` argument, unlike an `optional slot`, has to be filled by the caller.
+fn is_slot(kind: &ftd_ast::VariableKind) -> bool {
+    kind.modifier.is_none()
+        && ftd_p1::AccessModifier::remove_modifiers(kind.kind.as_str()).eq("slot")
+}
+
 fn validate_record_fields(
     rec_name: &str,
     fields: &[fastn_resolved::Field],
     doc_id: &str,
 ) -> ftd::interpreter::Result<()> {
     if let Some(field) = fields.iter().find(|v| v.mutable) {
-        return ftd::interpreter::utils::e2(
+        return ftd::interpreter::utils::e2_with_code(
             format!(
                 "Currently, mutable field `{}` in record `{}` is not supported.",
                 field.name, rec_name
@@ -535,6 +557,7 @@ fn validate_record_fields(
             .as_str(),
             doc_id,
             field.line_number,
+            ftd_p1::codes::NOT_SUPPORTED,
         );
     }
     Ok(())
@@ -549,10 +572,11 @@ fn check_variant_if_constant(
         fastn_resolved::OrTypeVariant::AnonymousRecord(_r) => {} // Todo: check on remaining for constant and throw error if found
         fastn_resolved::OrTypeVariant::Regular(_r) => {} // Todo: check on remaining for constant and throw error if found
         fastn_resolved::OrTypeVariant::Constant(c) => {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!("Cannot pass deconstructed constant variant `{}`", c.name),
                 doc.name,
                 c.line_number,
+                ftd_p1::codes::INVALID_VARIANT,
             );
         }
     }
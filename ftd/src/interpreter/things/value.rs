@@ -166,7 +166,8 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                         ),
                         doc_id: doc.name.to_string(),
                         line_number,
-                    },
+                    }
+                    .with_code(ftd_p1::codes::INVALID_KIND),
                 )
             }
         }
@@ -198,6 +199,7 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
         loop_object_name_and_kind: &Option<(String, fastn_resolved::Argument, Option<String>)>,
     ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<fastn_resolved::PropertyValue>>
     {
+        let (doc_id, line_number, span) = (doc.name, value.line_number(), value.span());
         if let Some(reference) =
             try_ok_state!(fastn_resolved::PropertyValue::reference_from_ast_value(
                 value.clone(),
@@ -206,7 +208,8 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                 expected_kind,
                 definition_name_with_arguments,
                 loop_object_name_and_kind,
-            )?)
+            )
+            .map_err(|e| e.or_span(doc_id, line_number, span))?)
         {
             Ok(ftd::interpreter::StateWithThing::new_thing(reference))
         } else {
@@ -218,6 +221,7 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                 definition_name_with_arguments,
                 loop_object_name_and_kind,
             )
+            .map_err(|e| e.or_span(doc_id, line_number, span))
         }
     }
 
@@ -272,7 +276,7 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                                 .map(Some));
                             }
                             Some(ekind) if !ekind.kind.is_same_as(&found_kind.kind) => {
-                                return ftd::interpreter::utils::e2(
+                                return ftd::interpreter::utils::e2_with_code(
                                     format!(
                                         "3.1 Expected kind `{:?}`, found: `{:?}`",
                                         ekind, found_kind
@@ -280,6 +284,7 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                                     .as_str(),
                                     doc.name,
                                     value.line_number(),
+                                    ftd_p1::codes::INVALID_KIND,
                                 );
                             }
                             _ => {}
@@ -308,7 +313,12 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                         },
                     )))
                 } else {
-                    ftd::interpreter::utils::e2("Kind not found", doc.name, value.line_number())
+                    ftd::interpreter::utils::e2_with_code(
+                        "Kind not found",
+                        doc.name,
+                        value.line_number(),
+                        ftd_p1::codes::INVALID_KIND,
+                    )
                 }
             }
             Ok(expression) if expression.eq(ftd::interpreter::FTD_SPECIAL_VALUE) => {
@@ -324,6 +334,17 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                     },
                 )))
             }
+            Ok(expression) if expression.eq(ftd::interpreter::FTD_SPECIAL_VALUES) => {
+                Ok(ftd::interpreter::StateWithThing::new_thing(Some(
+                    fastn_resolved::PropertyValue::Reference {
+                        name: "VALUES".to_string(),
+                        kind: fastn_resolved::Kind::string().into_list().into_kind_data(),
+                        source: fastn_resolved::PropertyValueSource::Global,
+                        is_mutable: false,
+                        line_number: 0,
+                    },
+                )))
+            }
             Ok(expression) if expression.eq(ftd::interpreter::FTD_SPECIAL_CHECKED) => {
                 Ok(ftd::interpreter::StateWithThing::new_thing(Some(
                     fastn_resolved::PropertyValue::Reference {
@@ -378,11 +399,12 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                         .map(Some));
                     }
                     Some(ekind) if !ekind.kind.is_same_as(&found_kind.kind) => {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!("Expected kind `{:?}`, found: `{:?}`", ekind, found_kind)
                                 .as_str(),
                             doc.name,
                             value.line_number(),
+                            ftd_p1::codes::INVALID_KIND,
                         )
                     }
                     _ => {}
@@ -448,11 +470,12 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                         .map(Some));
                     }
                     Some(ekind) if !ekind.kind.is_same_as(&found_kind.kind) => {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!("Expected kind `{:?}`, found: `{:?}`", ekind, found_kind)
                                 .as_str(),
                             doc.name,
                             value.line_number(),
+                            ftd_p1::codes::INVALID_KIND,
                         )
                     }
                     _ => {}
@@ -538,11 +561,12 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                         return Ok(ftd::interpreter::StateWithThing::new_thing(None));
                     }
                     Some(ekind) if !ekind.kind.is_same_as(&found_kind.kind) => {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!("3.2 Expected kind `{:?}`, found: `{:?}`", ekind, found_kind)
                                 .as_str(),
                             doc.name,
                             value.line_number(),
+                            ftd_p1::codes::INVALID_KIND,
                         )
                     }
                     _ => {}
@@ -566,13 +590,14 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                     };
 
                     if !is_variable_mutable {
-                        return ftd::interpreter::utils::e2(
+                        return ftd::interpreter::utils::e2_with_code(
                             format!(
                                 "Cannot have mutable reference of immutable variable `{}`",
                                 reference
                             ),
                             doc.name,
                             value.line_number(),
+                            ftd_p1::codes::NOT_MUTABLE,
                         );
                     }
                 }
@@ -720,6 +745,39 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                         line_number: value.line_number(),
                     },
                 ),
+                fastn_resolved::Kind::DateTime | fastn_resolved::Kind::Date => {
+                    let line_number = value.line_number();
+                    let text = value.string(doc.name)?;
+                    let parsed = if expected_kind.kind.is_date() {
+                        fastn_builtins::datetime::parse_date(text)
+                            .map(|value| fastn_resolved::Value::Date { value })
+                    } else {
+                        fastn_builtins::datetime::parse_datetime(text)
+                            .map(|value| fastn_resolved::Value::DateTime { value })
+                    };
+                    let value = match parsed {
+                        Some(value) => value,
+                        None => {
+                            return ftd::interpreter::utils::e2_with_code(
+                                format!(
+                                    "Expected ISO-8601 `{}`, found: `{}`",
+                                    expected_kind.kind.get_name(),
+                                    text
+                                ),
+                                doc.name,
+                                line_number,
+                                ftd_p1::codes::INVALID_KIND,
+                            )
+                        }
+                    };
+                    ftd::interpreter::StateWithThing::new_thing(
+                        fastn_resolved::PropertyValue::Value {
+                            value,
+                            is_mutable,
+                            line_number,
+                        },
+                    )
+                }
                 fastn_resolved::Kind::List { kind } => {
                     let line_number = value.line_number();
                     let value_list = value.into_list(doc.name, kind.get_name())?;
@@ -731,10 +789,11 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                             doc,
                             value.line_number(),
                         )?) {
-                            return ftd::interpreter::utils::e2(
+                            return ftd::interpreter::utils::e2_with_code(
                                 format!("Expected list of `{:?}`, found: `{}`", kind, key),
                                 doc.name,
                                 value.line_number(),
+                                ftd_p1::codes::INVALID_KIND,
                             );
                         }
                         values.push(if kind.is_ui() {
@@ -769,6 +828,70 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                         },
                     )
                 }
+                fastn_resolved::Kind::Map {
+                    key,
+                    value: value_kind,
+                } => {
+                    let line_number = value.line_number();
+                    let headers = match value {
+                        ftd_ast::VariableValue::Record {
+                            headers,
+                            caption,
+                            body,
+                            values,
+                            ..
+                        } if caption.is_none() && body.is_none() && values.is_empty() => headers.0,
+                        ftd_ast::VariableValue::Optional { value: v, .. } if v.is_none() => vec![],
+                        t => {
+                            return ftd::interpreter::utils::e2_with_code(
+                                format!(
+                                    "Expected map entries as `key: value` headers, found: `{:?}`",
+                                    t
+                                ),
+                                doc.name,
+                                line_number,
+                                ftd_p1::codes::INVALID_KIND,
+                            )
+                        }
+                    };
+                    let mut data: ftd::Map<fastn_resolved::PropertyValue> = Default::default();
+                    for header in headers {
+                        if key.is_integer() && header.key.parse::<i64>().is_err() {
+                            return ftd::interpreter::utils::e2_with_code(
+                                format!("Expected integer key, found: `{}`", header.key),
+                                doc.name,
+                                header.line_number,
+                                ftd_p1::codes::INVALID_KIND,
+                            );
+                        }
+                        if data.contains_key(&header.key) {
+                            return ftd::interpreter::utils::e2_with_code(
+                                format!("Duplicate map key: `{}`", header.key),
+                                doc.name,
+                                header.line_number,
+                                ftd_p1::codes::DUPLICATE,
+                            );
+                        }
+                        let value = try_ok_state!(fastn_resolved::PropertyValue::from_ast_value(
+                            header.value,
+                            doc,
+                            is_mutable,
+                            Some(&value_kind.as_ref().clone().into_kind_data()),
+                        )?);
+                        data.insert(header.key, value);
+                    }
+                    ftd::interpreter::StateWithThing::new_thing(
+                        fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::Map {
+                                data,
+                                key: key.as_ref().clone(),
+                                kind: value_kind.as_ref().clone().into_kind_data(),
+                            },
+                            is_mutable,
+                            line_number,
+                        },
+                    )
+                }
                 fastn_resolved::Kind::Record { name } if value.is_record() || value.is_string() => {
                     let record = try_ok_state!(doc.search_record(name, value.line_number())?);
                     fastn_resolved::PropertyValue::from_record(
@@ -792,16 +915,19 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                                 v.name().eq(variant_name)
                                     || variant_name.starts_with(format!("{}.", v.name()).as_str())
                             })
-                            .ok_or(ftd::interpreter::Error::ParseError {
-                                message: format!(
-                                    "Expected variant `{}` in or-type `{}`",
-                                    variant_name, name
-                                ),
-                                doc_id: doc.name.to_string(),
-                                line_number: value.line_number(),
-                            })?;
+                            .ok_or(
+                                ftd::interpreter::Error::ParseError {
+                                    message: format!(
+                                        "Expected variant `{}` in or-type `{}`",
+                                        variant_name, name
+                                    ),
+                                    doc_id: doc.name.to_string(),
+                                    line_number: value.line_number(),
+                                }
+                                .with_code(ftd_p1::codes::INVALID_VARIANT),
+                            )?;
                         let value = match &variant {
-                            fastn_resolved::OrTypeVariant::Constant(c) => return ftd::interpreter::utils::e2(format!("Cannot pass constant variant as property, variant: `{}`. Help: Pass variant as value instead", c.name), doc.name, c.line_number),
+                            fastn_resolved::OrTypeVariant::Constant(c) => return ftd::interpreter::utils::e2_with_code(format!("Cannot pass constant variant as property, variant: `{}`. Help: Pass variant as value instead", c.name), doc.name, c.line_number, ftd_p1::codes::INVALID_VARIANT),
                             fastn_resolved::OrTypeVariant::AnonymousRecord(record) =>
                                 try_ok_state!(fastn_resolved::PropertyValue::from_record(
                         record,
@@ -855,29 +981,29 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                             )?);
 
                         if or_type.name.ne(&found_or_type_name) {
-                            return ftd::interpreter::utils::e2(
+                            return ftd::interpreter::utils::e2_with_code(
                                 format!(
                                     "Expected or-type is `{}`, found: `{}`",
                                     or_type.name, found_or_type_name
                                 ),
                                 doc.name,
                                 value.line_number(),
+                                ftd_p1::codes::INVALID_KIND,
                             );
                         }
 
                         let constant = or_type_variant.ok_constant(doc.name)?;
-                        let value =
-                            constant
-                                .value
-                                .clone()
-                                .ok_or(ftd::interpreter::Error::ParseError {
-                                    message: format!(
-                                        "Expected value for constant variant `{}`",
-                                        constant.name
-                                    ),
-                                    doc_id: doc.name.to_string(),
-                                    line_number: constant.line_number,
-                                })?;
+                        let value = constant.value.clone().ok_or(
+                            ftd::interpreter::Error::ParseError {
+                                message: format!(
+                                    "Expected value for constant variant `{}`",
+                                    constant.name
+                                ),
+                                doc_id: doc.name.to_string(),
+                                line_number: constant.line_number,
+                            }
+                            .with_code(ftd_p1::codes::INVALID_VARIANT),
+                        )?;
 
                         ftd::interpreter::StateWithThing::new_thing(
                             fastn_resolved::Value::new_or_type(
@@ -937,10 +1063,11 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
     ) -> ftd::interpreter::Result<&fastn_resolved::Value> {
         match self {
             fastn_resolved::PropertyValue::Value { value, .. } => Ok(value),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected value found `{:?}`", t).as_str(),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -956,10 +1083,11 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
     ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<fastn_resolved::PropertyValue>>
     {
         if !(value.is_record() || value.is_string()) {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!("`{:?}` value is npt supported yet", value),
                 doc.name,
                 value.line_number(),
+                ftd_p1::codes::NOT_SUPPORTED,
             );
         }
         let name = record.name.as_str();
@@ -1005,7 +1133,8 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                             value: body.value.to_string(),
                             line_number: body.line_number,
                             source: ftd_ast::ValueSource::Body,
-                            condition: None
+                            condition: None,
+                            span: body.span,
                         },
                         doc,
                         field.mutable || is_mutable,
@@ -1038,6 +1167,7 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                     value: vec![],
                     line_number: value.line_number(),
                     condition: None,
+                    span: value.span(),
                 };
                 if let Some(header) = headers {
                     variable = header.value.clone();
@@ -1070,14 +1200,17 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                             // Todo: field_name is empty throw error
                             let property_value = result_field
                                 .get(field_name)
-                                .ok_or(ftd::interpreter::Error::ParseError {
-                                    message: format!(
-                                        "field `{}` not found in record: `{}`",
-                                        field_name, name
-                                    ),
-                                    doc_id: doc.name.to_string(),
-                                    line_number,
-                                })?
+                                .ok_or(
+                                    ftd::interpreter::Error::ParseError {
+                                        message: format!(
+                                            "field `{}` not found in record: `{}`",
+                                            field_name, name
+                                        ),
+                                        doc_id: doc.name.to_string(),
+                                        line_number,
+                                    }
+                                    .with_code(ftd_p1::codes::NAME_NOT_FOUND),
+                                )?
                                 .clone();
                             result_field.insert(field.name.to_string(), property_value);
                         }
@@ -1089,25 +1222,27 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                 continue;
             }
             if headers.is_none() {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     format!(
                         "Expected `{}` of type `{:?}`, found: `{:?}`",
                         field.name, field.kind, headers
                     ),
                     doc.name,
                     value.line_number(),
+                    ftd_p1::codes::MISSING_VALUE,
                 );
             }
             let first_header = headers.unwrap();
 
             if field.mutable.ne(&first_header.mutable) {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     format!(
                         "Mutability conflict in field `{}` for record `{}`",
                         field.name, name
                     ),
                     doc.name,
                     first_header.line_number,
+                    ftd_p1::codes::NOT_MUTABLE,
                 );
             }
 
@@ -1198,7 +1333,11 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                     )?;
                     if let Some(condition) = header.condition {
                         fastn_resolved::Expression::scan_ast_condition(
-                            ftd_ast::Condition::new(condition.as_str(), header.line_number),
+                            ftd_ast::Condition::new(
+                                condition.as_str(),
+                                header.line_number,
+                                header.condition_span,
+                            ),
                             definition_name_with_arguments,
                             loop_object_name_and_kind,
                             doc,
@@ -1235,12 +1374,15 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
         definition_name_with_arguments: Option<(&str, &[String])>,
         loop_object_name_and_kind: &Option<String>,
     ) -> ftd::interpreter::Result<()> {
+        let (doc_id, line_number, span) = (doc.name, value.line_number(), value.span());
         if fastn_resolved::PropertyValue::scan_reference_from_ast_value(
             value.clone(),
             doc,
             definition_name_with_arguments,
             loop_object_name_and_kind,
-        )? {
+        )
+        .map_err(|e| e.or_span(doc_id, line_number, span))?
+        {
             Ok(())
         } else {
             fastn_resolved::PropertyValue::scan_value_from_ast_value(
@@ -1249,6 +1391,7 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
                 definition_name_with_arguments,
                 loop_object_name_and_kind,
             )
+            .map_err(|e| e.or_span(doc_id, line_number, span))
         }
     }
 
@@ -1264,6 +1407,7 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
             line_number,
             source: ftd_ast::ValueSource::Default,
             condition: None,
+            span: Default::default(),
         };
 
         fastn_resolved::PropertyValue::scan_ast_value_with_argument(
@@ -1352,6 +1496,7 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
             line_number,
             source: ftd_ast::ValueSource::Default,
             condition: None,
+            span: Default::default(),
         };
 
         fastn_resolved::PropertyValue::from_ast_value_with_argument(
@@ -1395,10 +1540,11 @@ impl PropertyValueExt for fastn_resolved::PropertyValue {
     ) -> ftd::interpreter::Result<&mut fastn_resolved::Value> {
         match self {
             fastn_resolved::PropertyValue::Value { value, .. } => Ok(value),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected value found `{:?}`", t).as_str(),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1517,7 +1663,7 @@ pub trait ValueExt {
     fn from_evalexpr_value(
         value: fastn_resolved::evalexpr::Value,
         expected_kind: &fastn_resolved::Kind,
-        doc_name: &str,
+        doc: &ftd::interpreter::TDoc,
         line_number: usize,
     ) -> ftd::interpreter::Result<fastn_resolved::Value>;
     fn is_static(&self, doc: &ftd::interpreter::TDoc) -> bool;
@@ -1556,10 +1702,11 @@ impl ValueExt for fastn_resolved::Value {
     fn string(&self, doc_id: &str, line_number: usize) -> ftd::interpreter::Result<String> {
         match self {
             fastn_resolved::Value::String { text } => Ok(text.to_string()),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected String, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1567,10 +1714,11 @@ impl ValueExt for fastn_resolved::Value {
     fn decimal(&self, doc_id: &str, line_number: usize) -> ftd::interpreter::Result<f64> {
         match self {
             fastn_resolved::Value::Decimal { value } => Ok(*value),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected Decimal, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1578,10 +1726,11 @@ impl ValueExt for fastn_resolved::Value {
     fn integer(&self, doc_id: &str, line_number: usize) -> ftd::interpreter::Result<i64> {
         match self {
             fastn_resolved::Value::Integer { value } => Ok(*value),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected Integer, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1589,10 +1738,11 @@ impl ValueExt for fastn_resolved::Value {
     fn bool(&self, doc_id: &str, line_number: usize) -> ftd::interpreter::Result<bool> {
         match self {
             fastn_resolved::Value::Boolean { value } => Ok(*value),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected Boolean, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1611,10 +1761,11 @@ impl ValueExt for fastn_resolved::Value {
                 }
             }
             fastn_resolved::Value::Integer { value } => Ok(Some(*value)),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected Optional Integer, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1638,10 +1789,11 @@ impl ValueExt for fastn_resolved::Value {
                 Ok(values)
             }
             fastn_resolved::Value::String { text } => Ok(vec![text.to_string()]),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected String list, found: `{:?}`", t),
                 doc.name,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1658,10 +1810,11 @@ impl ValueExt for fastn_resolved::Value {
                 value,
                 ..
             } => Ok((name, variant, value)),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected or-type, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1673,10 +1826,11 @@ impl ValueExt for fastn_resolved::Value {
     ) -> ftd::interpreter::Result<fastn_resolved::ComponentInvocation> {
         match self {
             fastn_resolved::Value::UI { component, .. } => Ok(component.to_owned()),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected UI, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1688,10 +1842,11 @@ impl ValueExt for fastn_resolved::Value {
     ) -> ftd::interpreter::Result<ftd::Map<fastn_resolved::PropertyValue>> {
         match self {
             Self::Record { fields, .. } => Ok(fields.to_owned()),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected record, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1703,10 +1858,11 @@ impl ValueExt for fastn_resolved::Value {
     ) -> ftd::interpreter::Result<ftd::Map<fastn_resolved::PropertyValue>> {
         match self {
             Self::KwArgs { arguments } => Ok(arguments.to_owned()),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected kwargs, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1719,7 +1875,9 @@ impl ValueExt for fastn_resolved::Value {
             fastn_resolved::Value::String { text } => {
                 Ok(fastn_resolved::evalexpr::Value::String(text))
             }
-            fastn_resolved::Value::Integer { value } => {
+            fastn_resolved::Value::Integer { value }
+            | fastn_resolved::Value::DateTime { value }
+            | fastn_resolved::Value::Date { value } => {
                 Ok(fastn_resolved::evalexpr::Value::Int(value))
             }
             fastn_resolved::Value::Decimal { value } => {
@@ -1754,6 +1912,17 @@ impl ValueExt for fastn_resolved::Value {
                 }
                 Ok(fastn_resolved::evalexpr::Value::Tuple(values))
             }
+            fastn_resolved::Value::Map { data, .. } => {
+                let mut entries = vec![];
+                for (key, item) in data {
+                    let line_number = item.line_number();
+                    entries.push((
+                        key,
+                        item.resolve(doc, line_number)?.into_evalexpr_value(doc)?,
+                    ));
+                }
+                Ok(fastn_builtins::map_to_evalexpr_value(entries))
+            }
             t => unimplemented!("{:?}", t),
         }
     }
@@ -1767,9 +1936,9 @@ impl ValueExt for fastn_resolved::Value {
             fastn_resolved::Value::String { text } => {
                 fastn_resolved::evalexpr::Value::String(text.to_string())
             }
-            fastn_resolved::Value::Integer { value } => {
-                fastn_resolved::evalexpr::Value::Int(*value)
-            }
+            fastn_resolved::Value::Integer { value }
+            | fastn_resolved::Value::DateTime { value }
+            | fastn_resolved::Value::Date { value } => fastn_resolved::evalexpr::Value::Int(*value),
             fastn_resolved::Value::Decimal { value } => {
                 fastn_resolved::evalexpr::Value::Float(*value)
             }
@@ -1787,6 +1956,18 @@ impl ValueExt for fastn_resolved::Value {
                 }
                 fastn_resolved::evalexpr::Value::Tuple(values)
             }
+            fastn_resolved::Value::Map { data, .. }
+            | fastn_resolved::Value::Record { fields: data, .. } => {
+                let mut entries = vec![];
+                for (key, value) in data {
+                    let v = value
+                        .clone()
+                        .resolve(doc, line_number)?
+                        .to_evalexpr_value(doc, value.line_number())?;
+                    entries.push((key.to_string(), v));
+                }
+                fastn_builtins::map_to_evalexpr_value(entries)
+            }
             fastn_resolved::Value::Optional { data, .. } => {
                 if let Some(data) = data.as_ref() {
                     data.to_evalexpr_value(doc, line_number)?
@@ -1794,6 +1975,30 @@ impl ValueExt for fastn_resolved::Value {
                     fastn_resolved::evalexpr::Value::Empty
                 }
             }
+            fastn_resolved::Value::OrType {
+                name,
+                variant,
+                value,
+                ..
+            } => {
+                let variant = ftd_ast::Match::variant_name(variant);
+                let value = value
+                    .clone()
+                    .resolve(doc, line_number)?
+                    .to_evalexpr_value(doc, line_number)?;
+                let is_constant = match doc.get_thing(name, line_number)? {
+                    ftd::interpreter::Thing::OrType(or_type) => or_type.variants.iter().any(|v| {
+                        matches!(v, fastn_resolved::OrTypeVariant::Constant(_))
+                            && ftd_ast::Match::variant_name(&v.name()).eq(variant)
+                    }),
+                    _ => false,
+                };
+                if is_constant {
+                    value
+                } else {
+                    fastn_builtins::or_type_to_evalexpr_value(variant, value)
+                }
+            }
             t => unimplemented!("{:?}", t),
         })
     }
@@ -1801,7 +2006,7 @@ impl ValueExt for fastn_resolved::Value {
     fn from_evalexpr_value(
         value: fastn_resolved::evalexpr::Value,
         expected_kind: &fastn_resolved::Kind,
-        doc_name: &str,
+        doc: &ftd::interpreter::TDoc,
         line_number: usize,
     ) -> ftd::interpreter::Result<fastn_resolved::Value> {
         Ok(match value {
@@ -1814,18 +2019,26 @@ impl ValueExt for fastn_resolved::Value {
             fastn_resolved::evalexpr::Value::Int(value) if expected_kind.is_integer() => {
                 fastn_resolved::Value::Integer { value }
             }
+            fastn_resolved::evalexpr::Value::Int(value) if expected_kind.is_datetime() => {
+                fastn_resolved::Value::DateTime { value }
+            }
+            fastn_resolved::evalexpr::Value::Int(value) if expected_kind.is_date() => {
+                fastn_resolved::Value::Date {
+                    value: fastn_builtins::datetime::start_of_day(value),
+                }
+            }
             fastn_resolved::evalexpr::Value::Boolean(value) if expected_kind.is_boolean() => {
                 fastn_resolved::Value::Boolean { value }
             }
             fastn_resolved::evalexpr::Value::Tuple(data) if expected_kind.is_list() => {
                 let mut values = vec![];
-                let val_kind = expected_kind.list_type(doc_name, line_number)?;
+                let val_kind = expected_kind.list_type(doc.name, line_number)?;
                 for val in data {
                     values.push(fastn_resolved::PropertyValue::Value {
                         value: fastn_resolved::Value::from_evalexpr_value(
                             val,
                             &val_kind,
-                            doc_name,
+                            doc,
                             line_number,
                         )?,
                         is_mutable: false,
@@ -1837,17 +2050,110 @@ impl ValueExt for fastn_resolved::Value {
                     kind: fastn_resolved::KindData::new(val_kind),
                 }
             }
+            value @ (fastn_resolved::evalexpr::Value::Tuple(_)
+            | fastn_resolved::evalexpr::Value::Empty)
+                if expected_kind.is_map() =>
+            {
+                let (key, val_kind) = expected_kind.get_map_kinds().unwrap();
+                let mut data: ftd::Map<fastn_resolved::PropertyValue> = Default::default();
+                for (k, val) in fastn_builtins::map_entries(&value)? {
+                    data.insert(
+                        k,
+                        fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::from_evalexpr_value(
+                                val,
+                                val_kind,
+                                doc,
+                                line_number,
+                            )?,
+                            is_mutable: false,
+                            line_number,
+                        },
+                    );
+                }
+                fastn_resolved::Value::Map {
+                    data,
+                    key: key.to_owned(),
+                    kind: fastn_resolved::KindData::new(val_kind.to_owned()),
+                }
+            }
+            value @ fastn_resolved::evalexpr::Value::Tuple(_) if expected_kind.is_record() => {
+                // Records are passed to evalexpr like maps, see `to_evalexpr_value`.
+                let name = expected_kind.get_record_name().unwrap();
+                let record = doc.get_record(name, line_number)?;
+                let mut entries: ftd::Map<fastn_resolved::evalexpr::Value> =
+                    fastn_builtins::map_entries(&value)?.into_iter().collect();
+                let mut fields: ftd::Map<fastn_resolved::PropertyValue> = Default::default();
+                for field in record.fields {
+                    let value = match entries.remove(field.name.as_str()) {
+                        Some(val) => fastn_resolved::PropertyValue::Value {
+                            value: fastn_resolved::Value::from_evalexpr_value(
+                                val,
+                                &field.kind.kind,
+                                doc,
+                                line_number,
+                            )?,
+                            is_mutable: false,
+                            line_number,
+                        },
+                        None => match field.value {
+                            Some(value) => value,
+                            None if field.kind.is_optional() => {
+                                fastn_resolved::PropertyValue::Value {
+                                    value: fastn_resolved::Value::Optional {
+                                        data: Box::new(None),
+                                        kind: field.kind.clone(),
+                                    },
+                                    is_mutable: false,
+                                    line_number,
+                                }
+                            }
+                            None => {
+                                return ftd::interpreter::utils::e2_with_code(
+                                    format!("`{}` is missing field `{}`", name, field.name),
+                                    doc.name,
+                                    line_number,
+                                    ftd_p1::codes::MISSING_VALUE,
+                                )
+                            }
+                        },
+                    };
+                    fields.insert(field.name, value);
+                }
+                if let Some(key) = entries.keys().next() {
+                    return ftd::interpreter::utils::e2_with_code(
+                        format!("`{}` has no field `{}`", name, key),
+                        doc.name,
+                        line_number,
+                        ftd_p1::codes::NAME_NOT_FOUND,
+                    );
+                }
+                fastn_resolved::Value::Record {
+                    name: name.to_string(),
+                    fields,
+                }
+            }
             fastn_resolved::evalexpr::Value::Empty if expected_kind.is_optional() => {
                 fastn_resolved::Value::Optional {
                     data: Box::new(None),
                     kind: fastn_resolved::KindData::new(expected_kind.clone()),
                 }
             }
+            value if expected_kind.is_optional() => fastn_resolved::Value::Optional {
+                data: Box::new(Some(fastn_resolved::Value::from_evalexpr_value(
+                    value,
+                    expected_kind.ref_inner(),
+                    doc,
+                    line_number,
+                )?)),
+                kind: fastn_resolved::KindData::new(expected_kind.ref_inner().clone()),
+            },
             t => {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     format!("Expected kind: `{:?}`, found: `{:?}`", expected_kind, t),
-                    doc_name,
+                    doc.name,
                     line_number,
+                    ftd_p1::codes::INVALID_KIND,
                 )
             }
         })
@@ -1869,6 +2175,7 @@ impl ValueExt for fastn_resolved::Value {
                 is_static
             }
             fastn_resolved::Value::Record { fields, .. }
+            | fastn_resolved::Value::Map { data: fields, .. }
             | fastn_resolved::Value::Object { values: fields, .. }
             | fastn_resolved::Value::KwArgs {
                 arguments: fields, ..
@@ -1901,10 +2208,11 @@ impl ValueExt for fastn_resolved::Value {
     ) -> ftd::interpreter::Result<&ftd::Map<fastn_resolved::PropertyValue>> {
         match self {
             Self::KwArgs { arguments } => Ok(arguments),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected kw-args, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1923,10 +2231,11 @@ impl ValueExt for fastn_resolved::Value {
                 }
             }
             fastn_resolved::Value::String { text } => Ok(Some(text.to_string())),
-            t => ftd::interpreter::utils::e2(
+            t => ftd::interpreter::utils::e2_with_code(
                 format!("Expected Optional String, found: `{:?}`", t),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             ),
         }
     }
@@ -1956,6 +2265,12 @@ impl ValueExt for fastn_resolved::Value {
             fastn_resolved::Value::Boolean { value } => {
                 Ok(Some(serde_json::Value::Bool(value.to_owned())))
             }
+            fastn_resolved::Value::DateTime { value } => Ok(
+                fastn_builtins::datetime::to_iso_datetime(*value).map(serde_json::Value::String),
+            ),
+            fastn_resolved::Value::Date { value } => {
+                Ok(fastn_builtins::datetime::to_iso_date(*value).map(serde_json::Value::String))
+            }
             fastn_resolved::Value::Optional { data, .. } => {
                 if let Some(data) = data.as_ref() {
                     data.to_serde_value(doc)
@@ -1973,9 +2288,10 @@ impl ValueExt for fastn_resolved::Value {
                 }
                 Ok(Some(serde_json::to_value(&new_values)?))
             }
-            fastn_resolved::Value::KwArgs { arguments } => {
+            fastn_resolved::Value::KwArgs { arguments: values }
+            | fastn_resolved::Value::Map { data: values, .. } => {
                 let mut new_values: ftd::Map<serde_json::Value> = Default::default();
-                for (k, v) in arguments {
+                for (k, v) in values {
                     let resolved_value = v.clone().resolve(doc, 0)?;
                     if let Some(v) = resolved_value.to_serde_value(doc)? {
                         new_values.insert(k.to_owned(), v);
@@ -2048,9 +2364,19 @@ impl ValueExt for fastn_resolved::Value {
                     Ok(Some("".to_string()))
                 }
             }
+            fastn_resolved::Value::DateTime { .. } | fastn_resolved::Value::Date { .. } => {
+                Ok(self.to_serde_value(doc)?.and_then(|value| {
+                    if use_quotes {
+                        Some(value.to_string())
+                    } else {
+                        value.as_str().map(ToString::to_string)
+                    }
+                }))
+            }
             fastn_resolved::Value::Object { .. }
             | fastn_resolved::Value::Record { .. }
             | fastn_resolved::Value::List { .. }
+            | fastn_resolved::Value::Map { .. }
             | fastn_resolved::Value::KwArgs { .. } => {
                 Ok(Some(serde_json::to_string(&self.to_serde_value(doc)?)?))
             }
@@ -45,10 +45,11 @@ impl VariableExt for fastn_resolved::Variable {
             } {
                 state
             } else {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     format!("Processor: `{}` not found", processor),
                     doc.name,
                     variable_definition.line_number,
+                    ftd_p1::codes::NAME_NOT_FOUND,
                 );
             };
             let (doc_name, thing_name, _remaining) =
@@ -98,20 +99,6 @@ impl VariableExt for fastn_resolved::Variable {
         )?);
 
         if let Some(processor) = variable_definition.processor {
-            let state = if let Some(state) = {
-                match &mut doc.bag {
-                    ftd::interpreter::tdoc::BagOrState::Bag(_) => None,
-                    ftd::interpreter::tdoc::BagOrState::State(s) => Some(s),
-                }
-            } {
-                (*state).clone()
-            } else {
-                return ftd::interpreter::utils::e2(
-                    format!("Processor: `{}` not found", processor),
-                    doc.name,
-                    variable_definition.line_number,
-                );
-            };
             let (doc_name, thing_name, remaining) =
                 ftd::interpreter::utils::get_doc_name_and_thing_name_and_remaining(
                     doc.resolve_name(processor.as_str()).as_str(),
@@ -119,23 +106,34 @@ impl VariableExt for fastn_resolved::Variable {
                     variable_definition.line_number,
                 );
 
-            let parsed_document = match state.parsed_libs.get(doc_name.as_str()) {
-                Some(p) => p,
-                None => {
-                    return Ok(ftd::interpreter::StateWithThing::new_state(
-                        ftd::interpreter::InterpreterWithoutState::StuckOnImport {
-                            module: doc_name,
-                            caller_module: doc.name.to_string(),
-                        },
-                    ));
+            let is_foreign_function = match &doc.bag {
+                ftd::interpreter::tdoc::BagOrState::Bag(_) => {
+                    return ftd::interpreter::utils::e2_with_code(
+                        format!("Processor: `{}` not found", processor),
+                        doc.name,
+                        variable_definition.line_number,
+                        ftd_p1::codes::NAME_NOT_FOUND,
+                    );
+                }
+                ftd::interpreter::tdoc::BagOrState::State(state) => {
+                    match state.parsed_libs.get(doc_name.as_str()) {
+                        Some(parsed_document) => parsed_document
+                            .foreign_function
+                            .iter()
+                            .any(|v| thing_name.eq(v)),
+                        None => {
+                            return Ok(ftd::interpreter::StateWithThing::new_state(
+                                ftd::interpreter::InterpreterWithoutState::StuckOnImport {
+                                    module: doc_name,
+                                    caller_module: doc.name.to_string(),
+                                },
+                            ));
+                        }
+                    }
                 }
             };
 
-            return if parsed_document
-                .foreign_function
-                .iter()
-                .any(|v| thing_name.eq(v))
-            {
+            return if is_foreign_function {
                 if number_of_scan.lt(&1) {
                     fastn_resolved::PropertyValue::scan_ast_value(variable_definition.value, doc)?;
                     return Ok(ftd::interpreter::StateWithThing::new_continue());
@@ -163,8 +161,7 @@ impl VariableExt for fastn_resolved::Variable {
                 }
                 Ok(result)
             } else {
-                doc.err(
-                    "not found",
+                doc.not_found(
                     processor,
                     "Variable::from_ast",
                     variable_definition.line_number,
@@ -187,6 +184,7 @@ impl VariableExt for fastn_resolved::Variable {
             conditional_value: vec![],
             line_number: variable_definition.line_number,
             is_static: true,
+            sse: variable_definition.flags.sse,
         }
         .set_static(doc);
 
@@ -45,7 +45,8 @@ impl WebComponentDefinitionExt for fastn_resolved::WebComponentDefinition {
                 line_number: web_component_definition.line_number(),
                 value: web_component_definition.js,
                 source: ftd_ast::ValueSource::Default,
-                condition: None
+                condition: None,
+                span: Default::default(),
             },
             doc,
             false,
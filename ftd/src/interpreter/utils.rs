@@ -41,6 +41,36 @@ pub fn resolve_module_name(name: &str, doc_name: &str, aliases: &ftd::Map<String
     name
 }
 
+/// The name in `names` closest to `name`, if one is close enough to be what
+/// `name` was meant to be.
+pub fn closest<'a>(name: &str, names: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    names
+        .map(|v| (edit_distance(name, v), v))
+        .filter(|(distance, v)| *distance <= name.len().max(v.len()) / 3 + 1)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, v)| v)
+}
+
+/// The number of characters to add, remove or change to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                previous.min(row[j]).min(row[j + 1]) + 1
+            };
+            previous = current;
+        }
+    }
+    row[b.len()]
+}
+
 pub fn split_module(id: &str) -> (Option<&str>, &str, Option<&str>) {
     match id.split_once('.') {
         Some((p1, p2)) => match p2.split_once('.') {
@@ -62,6 +92,19 @@ where
     })
 }
 
+/// [e2], with the code `code` from [ftd_p1::codes].
+pub fn e2_with_code<T, S1>(
+    m: S1,
+    doc_id: &str,
+    line_number: usize,
+    code: &'static str,
+) -> ftd::interpreter::Result<T>
+where
+    S1: Into<String>,
+{
+    e2(m, doc_id, line_number).map_err(|e| e.with_code(code))
+}
+
 pub(crate) fn invalid_kind_error<S>(
     message: S,
     doc_id: &str,
@@ -116,10 +159,11 @@ pub(crate) fn get_function_name_and_properties(
     let (si, ei) = match (s.find('('), s.find(')')) {
         (Some(si), Some(ei)) if si < ei => (si, ei),
         _ => {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!("{} is not a function", s),
                 doc_id,
                 line_number,
+                ftd_p1::codes::INVALID_KIND,
             )
         }
     };
@@ -180,10 +224,11 @@ pub fn split(
     line_number: usize,
 ) -> ftd::interpreter::Result<(String, String)> {
     if !name.contains(split_at) {
-        return ftd::interpreter::utils::e2(
+        return ftd::interpreter::utils::e2_with_code(
             format!("{} is not found in {}", split_at, name),
             doc_id,
             line_number,
+            ftd_p1::codes::NAME_NOT_FOUND,
         );
     }
     let mut part = name.splitn(2, split_at);
@@ -262,10 +307,11 @@ pub fn get_mut_argument_for_reference<'a>(
             return if let Some(argument) = arguments.iter_mut().find(|v| v.name.eq(p1.as_str())) {
                 Ok(Some((component_name.to_string(), argument)))
             } else {
-                ftd::interpreter::utils::e2(
+                ftd::interpreter::utils::e2_with_code(
                     format!("{} is not the argument in {}", p1, component_name),
                     doc_name,
                     line_number,
+                    ftd_p1::codes::NAME_NOT_FOUND,
                 )
             };
         }
@@ -312,10 +358,11 @@ pub fn get_component_argument_for_reference_and_remaining<'a>(
             fastn_resolved::PropertyValueSource::Local(component_name.to_string()),
         )))
     } else {
-        ftd::interpreter::utils::e2(
+        ftd::interpreter::utils::e2_with_code(
             format!("{} is not the argument in {}", p1, component_name),
             doc_name,
             line_number,
+            ftd_p1::codes::NAME_NOT_FOUND,
         )
     }
 }
@@ -346,10 +393,11 @@ pub fn get_argument_for_reference_and_remaining(
                     fastn_resolved::PropertyValueSource::Local(component_name.to_string()),
                 )))
             } else {
-                ftd::interpreter::utils::e2(
+                ftd::interpreter::utils::e2_with_code(
                     format!("{} is not the argument in {}", p1, component_name),
                     doc.name,
                     line_number,
+                    ftd_p1::codes::NAME_NOT_FOUND,
                 )
             };
         }
@@ -406,16 +454,28 @@ pub fn validate_variable(
     doc: &ftd::interpreter::TDoc,
 ) -> ftd::interpreter::Result<()> {
     if !variable.mutable {
+        if variable.sse.is_some() {
+            return ftd::interpreter::utils::e2_with_code(
+                format!(
+                    "`$sse$` is only supported for mutable variables, `{}` is not mutable",
+                    variable.name,
+                ),
+                doc.name,
+                variable.line_number,
+                ftd_p1::codes::NOT_MUTABLE,
+            );
+        }
         return Ok(());
     }
     if !variable.conditional_value.is_empty() {
-        return ftd::interpreter::utils::e2(
+        return ftd::interpreter::utils::e2_with_code(
             format!(
                 "conditional properties are not supported for mutable argument `{}`",
                 variable.name,
             ),
             doc.name,
             variable.line_number,
+            ftd_p1::codes::NOT_SUPPORTED,
         );
     }
 
@@ -440,9 +500,9 @@ pub fn validate_record_value(
     ) -> ftd::interpreter::Result<()> {
         for value in fields.iter() {
             if let Some(reference_name) = value.reference_name() {
-                return ftd::interpreter::utils::e2(format!(
+                return ftd::interpreter::utils::e2_with_code(format!(
                     "Currently, reference `{}` to record field  is not supported. Use clone (*) instead", reference_name
-                ), doc.name, value.line_number());
+                ), doc.name, value.line_number(), ftd_p1::codes::NOT_SUPPORTED);
             }
 
             if let fastn_resolved::PropertyValue::Value { value, .. } = value {
@@ -471,13 +531,14 @@ pub fn validate_property_value_for_mutable(
     if let Some(name) = value.reference_name() {
         if let Ok(ref_variable) = doc.get_variable(name, value.line_number()) {
             if !ref_variable.mutable {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     format!(
                         "Cannot pass immutable reference `{}` to mutable",
                         ref_variable.name
                     ),
                     doc.name,
                     value.line_number(),
+                    ftd_p1::codes::NOT_MUTABLE,
                 );
             }
         }
@@ -493,13 +554,14 @@ pub fn validate_property_value_for_mutable(
     ) -> ftd::interpreter::Result<()> {
         for (key, value) in function_call.values.iter() {
             if let Some(ref_name) = value.reference_name() {
-                return ftd::interpreter::utils::e2(
+                return ftd::interpreter::utils::e2_with_code(
                     format!(
                         "Cannot pass reference `{}`:`{}` to mutable: Hint: Use *${} instead.",
                         key, ref_name, ref_name
                     ),
                     doc.name,
                     value.line_number(),
+                    ftd_p1::codes::NOT_MUTABLE,
                 );
             } else if let Some(function_call) = value.get_function() {
                 validate_function_call(function_call, doc)?;
@@ -803,24 +865,26 @@ pub(crate) fn validate_properties_and_set_default(
     for property in properties.iter_mut() {
         let found_kind = property.value.kind();
         if !found_kind.is_same_as(expected_kind) {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!(
                     "Expected kind is `{:?}`, found: `{:?}`",
                     expected_kind, found_kind,
                 ),
                 doc_id,
                 property.line_number,
+                ftd_p1::codes::INVALID_KIND,
             );
         }
 
         if found_default.is_some() && property.condition.is_none() {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!(
                     "Already found default property in line number {:?}",
                     found_default
                 ),
                 doc_id,
                 property.line_number,
+                ftd_p1::codes::DUPLICATE,
             );
         }
         if property.condition.is_none() {
@@ -836,10 +900,11 @@ pub(crate) fn validate_properties_and_set_default(
             {
                 fastn_resolved::Value::Module { name, things } => (name, things),
                 t => {
-                    return ftd::interpreter::utils::e2(
+                    return ftd::interpreter::utils::e2_with_code(
                         format!("Expected module, found: {:?}", t),
                         doc_id,
                         line_number,
+                        ftd_p1::codes::INVALID_KIND,
                     )
                 }
             };
@@ -862,13 +927,14 @@ pub(crate) fn validate_properties_and_set_default(
                 line_number: argument.line_number,
             });
         } else if !expected_kind.is_optional() && !expected_kind.is_list() {
-            return ftd::interpreter::utils::e2(
+            return ftd::interpreter::utils::e2_with_code(
                 format!(
                     "Need value of kind: `{:?}` for `{}`",
                     expected_kind, argument.name
                 ),
                 doc_id,
                 line_number,
+                ftd_p1::codes::MISSING_VALUE,
             );
         }
     }
@@ -73,7 +73,7 @@ pub fn default_bag_into_js_ast() -> Vec<fastn_js::Ast> {
     ftd_asts
 }
 
-const IGNORE_GLOBAL: [&str; 2] = ["ftd#main-package", "ftd#app-urls"];
+const IGNORE_GLOBAL: [&str; 3] = ["ftd#main-package", "ftd#app-urls", "ftd#language"];
 
 #[derive(Debug)]
 pub struct JSAstData {
@@ -103,6 +103,7 @@ pub fn document_into_js_ast(document: ftd::interpreter::Document) -> JSAstData {
         .collect_vec();
 
     let mut export_asts = vec![];
+    let mut sse_asts = vec![];
 
     for (key, thing) in document.data.iter() {
         if default_thing_name.contains(&key) {
@@ -118,6 +119,12 @@ pub fn document_into_js_ast(document: ftd::interpreter::Document) -> JSAstData {
             };
 
             document_asts.push(v.to_ast(&doc, prefix, &mut has_rive_components));
+            if let Some(url) = v.sse.as_ref() {
+                sse_asts.push(fastn_js::Ast::ServerSentEvents {
+                    name: v.name.to_string(),
+                    url: url.to_string(),
+                });
+            }
         } else if let ftd::interpreter::Thing::WebComponent(web_component) = thing {
             document_asts.push(web_component.to_ast(&doc));
         } else if let ftd::interpreter::Thing::Function(f) = thing {
@@ -166,6 +173,8 @@ pub fn document_into_js_ast(document: ftd::interpreter::Document) -> JSAstData {
     }
 
     document_asts.extend(export_asts);
+    // subscribed once every variable, and the ones it depends on, is declared
+    document_asts.extend(sse_asts);
     let mut scripts = fastn_runtime::utils::get_external_scripts(has_rive_components);
     scripts.push(fastn_runtime::utils::get_js_html(
         document.js.into_iter().collect_vec().as_slice(),
@@ -233,6 +233,7 @@ impl ftd::executor::Element {
             ftd::executor::Element::Row(r) => r.to_node(doc_id, anchor_ids),
             ftd::executor::Element::Column(c) => c.to_node(doc_id, anchor_ids),
             ftd::executor::Element::Container(e) => e.to_node(doc_id, anchor_ids),
+            ftd::executor::Element::Grid(g) => g.to_node(doc_id, anchor_ids),
             ftd::executor::Element::Text(t) => t.to_node(doc_id, anchor_ids),
             ftd::executor::Element::Integer(t) => t.to_node(doc_id, anchor_ids),
             ftd::executor::Element::Decimal(t) => t.to_node(doc_id, anchor_ids),
@@ -484,6 +485,104 @@ impl ftd::executor::ContainerElement {
     }
 }
 
+impl ftd::executor::Grid {
+    pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
+        use ftd::node::utils::CheckMap;
+
+        let mut n = Node::from_children(&self.common, &self.children, doc_id, "grid", anchor_ids);
+        if !self.common.is_not_visible {
+            n.style
+                .insert(s("display"), ftd::node::Value::from_string("grid"));
+        }
+
+        let device = &self.common.device;
+        for (key, value) in [
+            (
+                "grid-template-columns",
+                ftd::node::Value::from_executor_value(
+                    self.template_columns
+                        .value
+                        .as_ref()
+                        .map(|v| v.to_css_string(device)),
+                    self.template_columns.to_owned(),
+                    None,
+                    doc_id,
+                ),
+            ),
+            (
+                "grid-template-rows",
+                ftd::node::Value::from_executor_value(
+                    self.template_rows
+                        .value
+                        .as_ref()
+                        .map(|v| v.to_css_string(device)),
+                    self.template_rows.to_owned(),
+                    None,
+                    doc_id,
+                ),
+            ),
+            (
+                "grid-template-areas",
+                ftd::node::Value::from_executor_value(
+                    self.template_areas
+                        .value
+                        .as_ref()
+                        .map(|v| v.to_css_string(device)),
+                    self.template_areas.to_owned(),
+                    None,
+                    doc_id,
+                ),
+            ),
+            (
+                "grid-auto-flow",
+                ftd::node::Value::from_executor_value(
+                    self.auto_flow
+                        .value
+                        .as_ref()
+                        .map(|v| v.to_css_str().to_string()),
+                    self.auto_flow.to_owned(),
+                    None,
+                    doc_id,
+                ),
+            ),
+            (
+                "gap",
+                ftd::node::Value::from_executor_value(
+                    self.gap.value.as_ref().map(|v| v.to_css_string(device)),
+                    self.gap.to_owned(),
+                    None,
+                    doc_id,
+                ),
+            ),
+            (
+                "row-gap",
+                ftd::node::Value::from_executor_value(
+                    self.row_gap.value.as_ref().map(|v| v.to_css_string(device)),
+                    self.row_gap.to_owned(),
+                    None,
+                    doc_id,
+                ),
+            ),
+            (
+                "column-gap",
+                ftd::node::Value::from_executor_value(
+                    self.column_gap
+                        .value
+                        .as_ref()
+                        .map(|v| v.to_css_string(device)),
+                    self.column_gap.to_owned(),
+                    None,
+                    doc_id,
+                ),
+            ),
+        ] {
+            n.style.check_and_insert(key, value);
+        }
+
+        n
+    }
+}
+
 impl ftd::executor::Text {
     pub fn to_node(&self, doc_id: &str, anchor_ids: &mut Vec<String>) -> Node {
         use ftd::node::utils::CheckMap;
@@ -1215,6 +1314,42 @@ impl ftd::executor::Common {
             }
         }
 
+        for (key, value) in [
+            ("grid-column", &self.grid_column),
+            ("grid-row", &self.grid_row),
+            ("grid-area", &self.grid_area),
+        ] {
+            d.check_and_insert(
+                key,
+                ftd::node::Value::from_executor_value(
+                    value.value.as_ref().map(|v| v.to_css_string(&self.device)),
+                    value.to_owned(),
+                    None,
+                    doc_id,
+                ),
+            );
+        }
+
+        d.check_and_insert(
+            "transition",
+            ftd::node::Value::from_executor_value(
+                self.transition.value.as_ref().map(|v| v.to_css_string()),
+                self.transition.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
+        d.check_and_insert(
+            "animation",
+            ftd::node::Value::from_executor_value(
+                self.animation.value.as_ref().map(|v| v.to_css_string()),
+                self.animation.to_owned(),
+                None,
+                doc_id,
+            ),
+        );
+
         d.check_and_insert(
             "box-shadow",
             ftd::node::Value::from_executor_value(
@@ -12,6 +12,7 @@ pub struct NodeData {
     pub js: std::collections::HashSet<String>,
     pub css: std::collections::HashSet<String>,
     pub rive_data: Vec<ftd::executor::RiveData>,
+    pub keyframes: ftd::Map<String>,
 }
 
 impl NodeData {
@@ -35,6 +36,7 @@ impl NodeData {
             js: rt.js,
             css: rt.css,
             rive_data: rt.rive_data,
+            keyframes: rt.keyframes,
         }
     }
 }
@@ -36,3 +36,50 @@ vim.api.nvim_create_autocmd({ "BufRead", "BufNewFile" }, {
   pattern = "*.ftd",
   callback = SetupFtdSyntax,
 })
+
+-- Show the errors `fastn check` finds in the package of the file
+local namespace = vim.api.nvim_create_namespace('fastn')
+
+local function FastnCheck(args)
+  local fastn_ftd = vim.fs.find('FASTN.ftd', { upward = true, path = vim.fs.dirname(vim.fn.fnamemodify(args.file, ':p')) })[1]
+  if fastn_ftd == nil then
+    return
+  end
+  local root = vim.fs.dirname(fastn_ftd)
+  vim.fn.jobstart({ 'fastn', 'check', '--message-format', 'json' }, {
+    cwd = root,
+    stdout_buffered = true,
+    on_stdout = function(_, lines)
+      local by_file = {}
+      for _, line in ipairs(lines) do
+        local ok, d = pcall(vim.json.decode, line)
+        if ok and type(d) == 'table' and d.severity then
+          local file = root .. '/' .. d.file
+          by_file[file] = by_file[file] or {}
+          table.insert(by_file[file], {
+            lnum = (d.line ~= vim.NIL and d.line or 1) - 1,
+            col = (d.column ~= vim.NIL and d.column or 1) - 1,
+            end_lnum = (d.end_line ~= vim.NIL and d.end_line or 1) - 1,
+            end_col = (d.end_column ~= vim.NIL and d.end_column or 1) - 1,
+            severity = vim.diagnostic.severity.ERROR,
+            source = 'fastn',
+            code = d.code ~= vim.NIL and d.code or nil,
+            message = d.help ~= vim.NIL and (d.message .. '\nhelp: ' .. d.help) or d.message,
+          })
+        end
+      end
+      vim.diagnostic.reset(namespace)
+      for _, buf in ipairs(vim.api.nvim_list_bufs()) do
+        local diagnostics = by_file[vim.api.nvim_buf_get_name(buf)]
+        if diagnostics then
+          vim.diagnostic.set(namespace, buf, diagnostics)
+        end
+      end
+    end,
+  })
+end
+
+vim.api.nvim_create_autocmd({ "BufReadPost", "BufWritePost" }, {
+  pattern = "*.ftd",
+  callback = FastnCheck,
+})